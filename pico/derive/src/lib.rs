@@ -25,17 +25,38 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericParam};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Data, DataEnum, DeriveInput, Fields, GenericParam, Generics, Ident, ItemFn,
+    ReturnType,
+};
 
+/// Derives `Borrow` and `BorrowMut` from `[T]` for a `#[repr(C)]` column struct.
+///
+/// On an enum whose variants all have named fields, this instead generates:
+/// - a `#[repr(C)]` struct `{Enum}{Variant}<T>` holding the fields of each variant;
+/// - a `#[repr(C)]` struct `{Enum}Cols<T>` with a `discriminant` column holding the variant index,
+///   followed by a `payload` as wide as the widest variant.
+///
+/// Both are borrowable from `[T]`. `{Enum}Cols` has `as_{variant}` and `as_{variant}_mut` views
+/// into its payload, and `select_{variant}`, which sets the discriminant and zeroes the payload
+/// before returning the mutable view, so that the columns of inactive variants are zero. The enum
+/// itself cannot be borrowed from a slice, as its layout is chosen by the compiler.
 #[proc_macro_derive(AlignedBorrow)]
 pub fn aligned_borrow_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    let name = &ast.ident;
 
+    let methods = match &ast.data {
+        Data::Enum(data) => aligned_borrow_enum(&ast, data),
+        _ => aligned_borrow_impls(&ast.ident, &ast.generics),
+    };
+
+    TokenStream::from(methods)
+}
+
+fn aligned_borrow_impls(name: &Ident, generics: &Generics) -> proc_macro2::TokenStream {
     // Get first generic which must be type (ex. `T`) for input <T, N: NumLimbs, const M: usize>
-    let type_generic = ast
-        .generics
+    let type_generic = generics
         .params
         .iter()
         .map(|param| match param {
@@ -47,8 +68,7 @@ pub fn aligned_borrow_derive(input: TokenStream) -> TokenStream {
 
     // Get generics after the first (ex. `N: NumLimbs, const M: usize`)
     // We need this because when we assert the size, we want to substitute u8 for T.
-    let non_first_generics = ast
-        .generics
+    let non_first_generics = generics
         .params
         .iter()
         .skip(1)
@@ -60,9 +80,9 @@ pub fn aligned_borrow_derive(input: TokenStream) -> TokenStream {
         .collect::<Vec<_>>();
 
     // Get impl generics (`<T, N: NumLimbs, const M: usize>`), type generics (`<T, N>`), where clause (`where T: Clone`)
-    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
-    let methods = quote! {
+    quote! {
         impl #impl_generics core::borrow::Borrow<#name #type_generics> for [#type_generic] #where_clause {
             fn borrow(&self) -> &#name #type_generics {
                 debug_assert_eq!(self.len(), std::mem::size_of::<#name<u8 #(, #non_first_generics)*>>());
@@ -82,9 +102,139 @@ pub fn aligned_borrow_derive(input: TokenStream) -> TokenStream {
                 &mut shorts[0]
             }
         }
+    }
+}
+
+fn aligned_borrow_enum(ast: &DeriveInput, data: &DataEnum) -> proc_macro2::TokenStream {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let attrs = ast.attrs.iter().filter(|attr| attr.path.is_ident("doc"));
+
+    // The payload width is computed with `T = u8`, so no other generics are allowed.
+    let type_generic = match ast.generics.params.iter().collect::<Vec<_>>().as_slice() {
+        [GenericParam::Type(type_param)] => &type_param.ident,
+        _ => panic!("AlignedBorrow enums must have exactly one generic, which must be a type"),
     };
 
-    TokenStream::from(methods)
+    let cols_name = format_ident!("{}Cols", name);
+    let num_variants = data.variants.len();
+
+    let mut variant_structs = Vec::new();
+    let mut variant_sizes = Vec::new();
+    let mut accessors = Vec::new();
+    for (index, variant) in data.variants.iter().enumerate() {
+        let fields = match &variant.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => panic!("AlignedBorrow enum variants must have named fields"),
+        };
+
+        let struct_name = format_ident!("{}{}", name, variant.ident);
+        let variant_attrs = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"));
+        let field_defs = fields.iter().map(|f| {
+            let field_attrs = &f.attrs;
+            let fname = &f.ident;
+            let ftype = &f.ty;
+            quote! {
+                #(#field_attrs)*
+                pub #fname: #ftype
+            }
+        });
+        let borrow_impls = aligned_borrow_impls(&struct_name, &ast.generics);
+        variant_structs.push(quote! {
+            #(#variant_attrs)*
+            #[repr(C)]
+            #vis struct #struct_name<#type_generic> {
+                #(#field_defs,)*
+            }
+
+            #borrow_impls
+        });
+
+        let size = quote! { core::mem::size_of::<#struct_name<u8>>() };
+        let snake = to_snake_case(&variant.ident.to_string());
+        let as_fn = format_ident!("as_{}", snake);
+        let as_mut_fn = format_ident!("as_{}_mut", snake);
+        let select_fn = format_ident!("select_{}", snake);
+        accessors.push(quote! {
+            pub fn #as_fn(&self) -> &#struct_name<#type_generic> {
+                core::borrow::Borrow::borrow(&self.payload[..#size])
+            }
+
+            pub fn #as_mut_fn(&mut self) -> &mut #struct_name<#type_generic> {
+                core::borrow::BorrowMut::borrow_mut(&mut self.payload[..#size])
+            }
+
+            pub fn #select_fn(&mut self) -> &mut #struct_name<#type_generic>
+            where
+                #type_generic: p3_field::FieldAlgebra,
+            {
+                self.discriminant = #type_generic::from_canonical_usize(#index);
+                self.payload.iter_mut().for_each(|x| *x = #type_generic::ZERO);
+                self.#as_mut_fn()
+            }
+        });
+        variant_sizes.push(size);
+    }
+
+    let cols_borrow_impls = aligned_borrow_impls(&cols_name, &ast.generics);
+
+    quote! {
+        #(#variant_structs)*
+
+        #(#attrs)*
+        #[repr(C)]
+        #vis struct #cols_name<#type_generic> {
+            /// The index of the active variant.
+            pub discriminant: #type_generic,
+            /// The fields of the active variant, followed by zeros.
+            pub payload: [#type_generic; {
+                let mut width = 0;
+                #(if #variant_sizes > width { width = #variant_sizes; })*
+                width
+            }],
+        }
+
+        #cols_borrow_impls
+
+        impl<#type_generic> #cols_name<#type_generic> {
+            pub const NUM_VARIANTS: usize = #num_variants;
+
+            /// The index of the active variant.
+            pub fn variant(&self) -> usize
+            where
+                #type_generic: p3_field::PrimeField32,
+            {
+                let index = self.discriminant.as_canonical_u32() as usize;
+                debug_assert!(
+                    index < Self::NUM_VARIANTS,
+                    "discriminant {} of {} is out of range",
+                    index,
+                    stringify!(#cols_name),
+                );
+                index
+            }
+
+            #(#accessors)*
+        }
+    }
+}
+
+fn to_snake_case(ident: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in ident.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
 }
 
 // used for recursion
@@ -230,3 +380,52 @@ pub fn derive_variable(input: TokenStream) -> TokenStream {
 
     gen.into()
 }
+
+/// Marks a guest function as callable by name through `pico_sdk::entrypoint!(dispatch: ...)`.
+///
+/// The function must take no arguments and return nothing; it reads its inputs from and commits
+/// its outputs to the usual io streams. Next to the function, the attribute emits a module of the
+/// same name holding its dispatch table entry, which `entrypoint!` collects. The function is given
+/// an unmangled symbol, so two exported functions with the same name fail to link.
+#[proc_macro_attribute]
+pub fn pico_export(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`pico_export` takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let func = parse_macro_input!(item as ItemFn);
+    let sig = &func.sig;
+    if !sig.inputs.is_empty()
+        || !matches!(sig.output, ReturnType::Default)
+        || !sig.generics.params.is_empty()
+        || sig.asyncness.is_some()
+    {
+        return syn::Error::new_spanned(
+            sig,
+            "`pico_export` functions must be non-generic, non-async and have the signature `fn()`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let vis = &func.vis;
+    let name = &sig.ident;
+    let name_str = name.to_string();
+    quote! {
+        #[no_mangle]
+        #func
+
+        // Functions and modules live in different namespaces, so the entry can share the name.
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        #vis mod #name {
+            pub const PICO_EXPORT: (&str, fn()) = (#name_str, super::#name);
+        }
+    }
+    .into()
+}
@@ -1,4 +1,3 @@
-use alloy_sol_types::SolType;
 use fibonacci_lib::{fibonacci, load_elf, PublicValuesStruct};
 use pico_sdk::{client::DefaultProverClient, init_logger};
 
@@ -19,12 +18,10 @@ fn main() {
     let n = 100u32;
     stdin_builder.borrow_mut().write(&n);
 
-    // Generate proof
-    let proof = client.prove_fast().expect("Failed to generate proof");
-
-    // Decodes public values from the proof's public value stream.
-    let public_buffer = proof.pv_stream.unwrap();
-    let public_values = PublicValuesStruct::abi_decode(&public_buffer, true).unwrap();
+    // Generate proof and decode public values from the proof's public value stream.
+    let (_proof, public_values) = client
+        .prove_with_abi::<PublicValuesStruct>()
+        .expect("Failed to generate proof");
 
     // Verify the public values
     verify_public_values(n, &public_values);
@@ -1,6 +1,6 @@
 #![no_main]
 
-use pico_sdk::io::{commit_bytes, read_vec};
+use pico_sdk::io::{commit_bytes, read_borrowed};
 use std::time::Duration;
 use tendermint_light_client_verifier::{
     options::Options, types::LightBlock, ProdVerifier, Verdict, Verifier,
@@ -11,15 +11,15 @@ pico_sdk::entrypoint!(main);
 fn main() {
     println!("cycle-tracker-start: io");
     println!("cycle-tracker-start: reading bytes");
-    let encoded_1 = read_vec();
-    let encoded_2 = read_vec();
+    // SAFETY: there is no arena scope, so the inputs stay valid until the program halts.
+    let (encoded_1, encoded_2) = unsafe { (read_borrowed(), read_borrowed()) };
     println!("cycle-tracker-end: reading bytes");
     println!("first 10 bytes: {:?}", &encoded_1[..10]);
     println!("first 10 bytes: {:?}", &encoded_2[..10]);
 
     println!("cycle-tracker-start: serde");
-    let light_block_1: LightBlock = serde_cbor::from_slice(&encoded_1).unwrap();
-    let light_block_2: LightBlock = serde_cbor::from_slice(&encoded_2).unwrap();
+    let light_block_1: LightBlock = serde_cbor::from_slice(encoded_1).unwrap();
+    let light_block_2: LightBlock = serde_cbor::from_slice(encoded_2).unwrap();
     println!("cycle-tracker-end: serde");
     println!("cycle-tracker-end: io");
 
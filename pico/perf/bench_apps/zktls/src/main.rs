@@ -1,22 +1,33 @@
 #![no_main]
 
 pico_sdk::entrypoint!(main);
-use pico_sdk::io::{commit, read_as};
+use p3_baby_bear::BabyBear;
+use p3_koala_bear::KoalaBear;
+use pico_sdk::io::{commit, commit_hash, read_as};
 use zktls_att_verification::verification_data::VerifyingDataOpt;
 
 // load verifying data
-fn get_verifying_data(json_content: String) -> VerifyingDataOpt {
-    let verifying_data: VerifyingDataOpt = serde_json::from_str(&json_content).unwrap();
+fn get_verifying_data(json_content: &str) -> VerifyingDataOpt {
+    let verifying_data: VerifyingDataOpt = serde_json::from_str(json_content).unwrap();
     verifying_data
 }
 
 pub fn main() {
+    // The Poseidon2 precompile permutes over the field of the prover, which the guest cannot
+    // detect, so the host passes it in: "bb" or "kb".
+    let field: String = read_as();
     let verifying_key: String = read_as();
     let verifying_raw_data: String = read_as();
-    let verifying_data = get_verifying_data(verifying_raw_data);
+    let verifying_data = get_verifying_data(&verifying_raw_data);
 
     let _ = verifying_data.verify(&verifying_key).is_ok();
 
     commit(&verifying_key);
-    commit(&verifying_data.get_records());
+    // The verifying data is large, so commit only its hash, which the host recomputes from the
+    // data it wrote.
+    match field.as_str() {
+        "bb" => commit_hash::<BabyBear>(verifying_raw_data.as_bytes()),
+        "kb" => commit_hash::<KoalaBear>(verifying_raw_data.as_bytes()),
+        field => panic!("unsupported field: {field}"),
+    };
 }
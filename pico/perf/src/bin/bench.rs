@@ -13,6 +13,7 @@ use p3_baby_bear::BabyBear;
 use p3_field::PrimeField32;
 use p3_koala_bear::KoalaBear;
 use p3_symmetric::Permutation;
+use pico_sdk::poseidon2_hash::Poseidon2;
 use pico_vm::{
     chips::{
         chips::riscv_poseidon2::FieldSpecificPoseidon2Chip,
@@ -20,7 +21,6 @@ use pico_vm::{
     },
     configs::{
         config::{Com, Dom, PcsProverData, StarkGenericConfig, Val},
-        field_config::{BabyBearBn254, KoalaBearBn254},
         stark_config::{
             bb_bn254_poseidon2::BabyBearBn254Poseidon2, kb_bn254_poseidon2::KoalaBearBn254Poseidon2,
         },
@@ -28,13 +28,7 @@ use pico_vm::{
     emulator::{opts::EmulatorOpts, stdin::EmulatorStdin},
     instances::{
         chiptype::recursion_chiptype::RecursionChipType,
-        compiler::{
-            onchain_circuit::{
-                gnark::builder::OnchainVerifierCircuit, stdin::OnchainStdin,
-                utils::build_gnark_config_with_str,
-            },
-            shapes::{recursion_shape::RecursionShapeConfig, riscv_shape::RiscvShapeConfig},
-        },
+        compiler::shapes::{recursion_shape::RecursionShapeConfig, riscv_shape::RiscvShapeConfig},
         configs::{
             riscv_config::StarkConfig as RiscvBBSC, riscv_kb_config::StarkConfig as RiscvKBSC,
         },
@@ -57,7 +51,7 @@ use serde::Serialize;
 use std::{
     fs::File,
     io::{BufRead, BufReader, Read},
-    path::PathBuf,
+    path::Path,
     process::{Command, Stdio},
     thread,
     thread::sleep,
@@ -132,8 +126,45 @@ const PROGRAMS: &[Benchmark] = &[
     },
 ];
 
+/// The verifying data of a zktls benchmark input.
+fn zktls_data_path(input: &str) -> Option<&'static str> {
+    match input {
+        "zktls-verify16" => Some("./perf/bench_data/data/bench16.json"),
+        "zktls-verify256" => Some("./perf/bench_data/data/bench256.json"),
+        "zktls-verify1024" => Some("./perf/bench_data/data/bench1024.json"),
+        "zktls-verify2048" => Some("./perf/bench_data/data/bench2048.json"),
+        _ => None,
+    }
+}
+
+/// Checks that a zktls proof committed the Poseidon2 hash of the verifying data it was given,
+/// which the guest computes over the field `F` of the prover.
+fn check_zktls_digest<F>(bench: &Benchmark, pv_stream: Option<&Vec<u8>>) -> Result<()>
+where
+    F: PrimeField32 + Poseidon2Init,
+    F::Poseidon2: Permutation<[F; 16]>,
+{
+    let Some(path) = bench.input.and_then(zktls_data_path) else {
+        return Ok(());
+    };
+    let verifying_data = std::fs::read_to_string(path)?;
+    let expected = Poseidon2::<F>::hash_bytes(verifying_data.as_bytes())
+        .iter()
+        .flat_map(|element| element.as_canonical_u32().to_le_bytes())
+        .collect::<Vec<_>>();
+    let pv_stream = pv_stream.context("proof has no public values stream")?;
+    anyhow::ensure!(
+        pv_stream.ends_with(&expected),
+        "{} did not commit the hash of its verifying data",
+        bench.name
+    );
+    Ok(())
+}
+
+/// Loads the ELF and the stdin of `bench`. `field` is the field of the prover, "bb" or "kb",
+/// which the zktls guest needs to hash its verifying data the same way as the host.
 #[allow(clippy::type_complexity)]
-fn load<P>(bench: &Benchmark) -> Result<(Vec<u8>, EmulatorStdin<P, Vec<u8>>)> {
+fn load<P>(bench: &Benchmark, field: &str) -> Result<(Vec<u8>, EmulatorStdin<P, Vec<u8>>)> {
     let elf = std::fs::read(bench.elf)?;
     let mut stdin_builder = EmulatorStdin::<P, Vec<u8>>::new_builder();
 
@@ -143,39 +174,16 @@ fn load<P>(bench: &Benchmark) -> Result<(Vec<u8>, EmulatorStdin<P, Vec<u8>>)> {
             let input_bytes = bincode::serialize(&300_000u32).expect("failed to serialize");
             stdin_builder.write_slice(&input_bytes);
         }
-        Some("zktls-verify16") => {
-            let verifying_key = std::fs::read_to_string("./perf/bench_data/verifying_k256.key").unwrap();
-            let bytes = bincode::serialize(&verifying_key).expect("failed to serialize");
+        Some(input) if zktls_data_path(input).is_some() => {
+            let bytes = bincode::serialize(field).expect("failed to serialize");
             stdin_builder.write_slice(&bytes);
 
-            let verifying_data = std::fs::read_to_string("./perf/bench_data/data/bench16.json").unwrap();
-            let bytes = bincode::serialize(&verifying_data).expect("failed to serialize");
-            stdin_builder.write_slice(&bytes);
-        }
-        Some("zktls-verify256") => {
-            let verifying_key = std::fs::read_to_string("./perf/bench_data/verifying_k256.key").unwrap();
+            let verifying_key =
+                std::fs::read_to_string("./perf/bench_data/verifying_k256.key").unwrap();
             let bytes = bincode::serialize(&verifying_key).expect("failed to serialize");
             stdin_builder.write_slice(&bytes);
 
-            let verifying_data = std::fs::read_to_string("./perf/bench_data/data/bench256.json").unwrap();
-            let bytes = bincode::serialize(&verifying_data).expect("failed to serialize");
-            stdin_builder.write_slice(&bytes);
-        }
-        Some("zktls-verify1024") => {
-            let verifying_key = std::fs::read_to_string("./perf/bench_data/verifying_k256.key").unwrap();
-            let bytes = bincode::serialize(&verifying_key).expect("failed to serialize");
-            stdin_builder.write_slice(&bytes);
-
-            let verifying_data = std::fs::read_to_string("./perf/bench_data/data/bench1024.json").unwrap();
-            let bytes = bincode::serialize(&verifying_data).expect("failed to serialize");
-            stdin_builder.write_slice(&bytes);
-        }
-        Some("zktls-verify2048") => {
-            let verifying_key = std::fs::read_to_string("./perf/bench_data/verifying_k256.key").unwrap();
-            let bytes = bincode::serialize(&verifying_key).expect("failed to serialize");
-            stdin_builder.write_slice(&bytes);
-
-            let verifying_data = std::fs::read_to_string("./perf/bench_data/data/bench2048.json").unwrap();
+            let verifying_data = std::fs::read_to_string(zktls_data_path(input).unwrap()).unwrap();
             let bytes = bincode::serialize(&verifying_data).expect("failed to serialize");
             stdin_builder.write_slice(&bytes);
         }
@@ -276,7 +284,7 @@ fn time_operation<T, F: FnOnce() -> T>(operation: F) -> (T, Duration) {
 }
 
 fn bench_bb(bench: &Benchmark) -> Result<PerformanceReport> {
-    let (elf, stdin) = load(bench)?;
+    let (elf, stdin) = load(bench, "bb")?;
     let riscv_opts = EmulatorOpts::bench_riscv_ops();
     let recursion_opts = EmulatorOpts::bench_recursion_opts();
     info!(
@@ -289,7 +297,7 @@ fn bench_bb(bench: &Benchmark) -> Result<PerformanceReport> {
     );
 
     let riscv = RiscvProver::new_initial_prover((RiscvBBSC::new(), &elf), riscv_opts, None);
-    let convert = ConvertProver::new_with_prev(&riscv, recursion_opts, None);
+    let convert = ConvertProver::new_with_prev(&riscv, recursion_opts.clone(), None);
     let combine = CombineProver::new_with_prev(&convert, recursion_opts, None);
     let compress = CompressProver::new_with_prev(&combine, (), None);
     let embed = EmbedProver::<_, _, Vec<u8>>::new_with_prev(&compress, (), None);
@@ -303,6 +311,7 @@ fn bench_bb(bench: &Benchmark) -> Result<PerformanceReport> {
     let ((proof, cycles), riscv_duration) = time_operation(|| riscv.prove_cycles(stdin));
     info!("Verifying RISCV proof..");
     assert!(riscv.verify(&proof, riscv_vk));
+    check_zktls_digest::<BabyBear>(bench, proof.pv_stream.as_ref())?;
 
     info!("╔═══════════════════════╗");
     info!("║     CONVERT PHASE     ║");
@@ -372,7 +381,7 @@ fn bench_bb(bench: &Benchmark) -> Result<PerformanceReport> {
 }
 
 fn bench_bb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
-    let (elf, stdin) = load(bench)?;
+    let (elf, stdin) = load(bench, "bb")?;
     let riscv_opts = EmulatorOpts::bench_riscv_ops();
     let recursion_opts = EmulatorOpts::bench_recursion_opts();
     let riscv_shape_config = RiscvShapeConfig::<BabyBear>::default();
@@ -394,13 +403,14 @@ fn bench_bb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
         Some(riscv_shape_config),
     );
     let convert =
-        ConvertProver::new_with_prev(&riscv, recursion_opts, Some(recursion_shape_config));
+        ConvertProver::new_with_prev(&riscv, recursion_opts.clone(), Some(recursion_shape_config));
     let recursion_shape_config =
         RecursionShapeConfig::<BabyBear, RecursionChipType<BabyBear>>::default();
     let combine =
         CombineProver::new_with_prev(&convert, recursion_opts, Some(recursion_shape_config));
     let compress = CompressProver::new_with_prev(&combine, (), None);
-    let embed = EmbedProver::<_, _, Vec<u8>>::new_with_prev(&compress, (), None);
+    let embed =
+        EmbedProver::<_, BabyBearBn254Poseidon2, Vec<u8>>::new_with_prev(&compress, (), None);
 
     let riscv_vk = riscv.vk();
 
@@ -411,6 +421,7 @@ fn bench_bb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
     let ((proof, cycles), riscv_duration) = time_operation(|| riscv.prove_cycles(stdin));
     info!("Verifying RISCV proof..");
     assert!(riscv.verify(&proof, riscv_vk));
+    check_zktls_digest::<BabyBear>(bench, proof.pv_stream.as_ref())?;
 
     info!("╔═══════════════════════╗");
     info!("║     CONVERT PHASE     ║");
@@ -448,17 +459,10 @@ fn bench_bb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
     info!("║     ONCHAIN PHASE     ║");
     info!("╚═══════════════════════╝");
     let (_, evm_duration) = time_operation(|| {
-        let onchain_stdin = OnchainStdin {
-            machine: embed.machine().clone(),
-            vk: proof.vks().first().unwrap().clone(),
-            proof: proof.proofs().first().unwrap().clone(),
-            flag_complete: true,
-        };
-
         // generate gnark data
-        let (constraints, witness) =
-            OnchainVerifierCircuit::<BabyBearBn254, BabyBearBn254Poseidon2>::build(&onchain_stdin);
-        let gnark_witness = build_gnark_config_with_str(constraints, witness, PathBuf::from("./"));
+        let gnark_inputs = embed.export_gnark(&proof);
+        gnark_inputs.save(Path::new("./")).unwrap();
+        let gnark_witness = serde_json::to_string(&gnark_inputs.witness).unwrap();
 
         let gnark_proof_data = send_gnark_prove_task(gnark_witness);
         info!("gnark prove success with proof data {}", gnark_proof_data);
@@ -504,7 +508,7 @@ fn bench_bb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
 }
 
 fn bench_kb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
-    let (elf, stdin) = load(bench)?;
+    let (elf, stdin) = load(bench, "kb")?;
     let riscv_opts = EmulatorOpts::bench_riscv_ops();
     let recursion_opts = EmulatorOpts::bench_recursion_opts();
     let riscv_shape_config = RiscvShapeConfig::<KoalaBear>::default();
@@ -526,13 +530,14 @@ fn bench_kb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
         Some(riscv_shape_config),
     );
     let convert =
-        ConvertProver::new_with_prev(&riscv, recursion_opts, Some(recursion_shape_config));
+        ConvertProver::new_with_prev(&riscv, recursion_opts.clone(), Some(recursion_shape_config));
     let recursion_shape_config =
         RecursionShapeConfig::<KoalaBear, RecursionChipType<KoalaBear>>::default();
     let combine =
         CombineProver::new_with_prev(&convert, recursion_opts, Some(recursion_shape_config));
     let compress = CompressProver::new_with_prev(&combine, (), None);
-    let embed = EmbedProver::<_, _, Vec<u8>>::new_with_prev(&compress, (), None);
+    let embed =
+        EmbedProver::<_, KoalaBearBn254Poseidon2, Vec<u8>>::new_with_prev(&compress, (), None);
 
     let riscv_vk = riscv.vk();
 
@@ -543,6 +548,7 @@ fn bench_kb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
     let ((proof, cycles), riscv_duration) = time_operation(|| riscv.prove_cycles(stdin));
     info!("Verifying RISCV proof..");
     assert!(riscv.verify(&proof, riscv_vk));
+    check_zktls_digest::<KoalaBear>(bench, proof.pv_stream.as_ref())?;
 
     info!("╔═══════════════════════╗");
     info!("║     CONVERT PHASE     ║");
@@ -580,19 +586,10 @@ fn bench_kb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
     info!("║     ONCHAIN PHASE     ║");
     info!("╚═══════════════════════╝");
     let (_, evm_duration) = time_operation(|| {
-        let onchain_stdin = OnchainStdin {
-            machine: embed.machine().clone(),
-            vk: proof.vks().first().unwrap().clone(),
-            proof: proof.proofs().first().unwrap().clone(),
-            flag_complete: true,
-        };
-
         // generate gnark data
-        let (constraints, witness) = OnchainVerifierCircuit::<
-            KoalaBearBn254,
-            KoalaBearBn254Poseidon2,
-        >::build(&onchain_stdin);
-        let gnark_witness = build_gnark_config_with_str(constraints, witness, PathBuf::from("./"));
+        let gnark_inputs = embed.export_gnark(&proof);
+        gnark_inputs.save(Path::new("./")).unwrap();
+        let gnark_witness = serde_json::to_string(&gnark_inputs.witness).unwrap();
         let gnark_proof_data = send_gnark_prove_task(gnark_witness);
         info!("gnark prove success with proof data {}", gnark_proof_data);
 
@@ -637,7 +634,7 @@ fn bench_kb_vk(bench: &Benchmark) -> Result<PerformanceReport> {
 }
 
 fn bench_kb(bench: &Benchmark) -> Result<PerformanceReport> {
-    let (elf, stdin) = load(bench)?;
+    let (elf, stdin) = load(bench, "kb")?;
     let riscv_opts = EmulatorOpts::bench_riscv_ops();
     let recursion_opts = EmulatorOpts::bench_recursion_opts();
 
@@ -651,7 +648,7 @@ fn bench_kb(bench: &Benchmark) -> Result<PerformanceReport> {
     );
 
     let riscv = RiscvProver::new_initial_prover((RiscvKBSC::new(), &elf), riscv_opts, None);
-    let convert = ConvertProver::new_with_prev(&riscv, recursion_opts, None);
+    let convert = ConvertProver::new_with_prev(&riscv, recursion_opts.clone(), None);
     let combine = CombineProver::new_with_prev(&convert, recursion_opts, None);
     let compress = CompressProver::new_with_prev(&combine, (), None);
     let embed = EmbedProver::<_, _, Vec<u8>>::new_with_prev(&compress, (), None);
@@ -665,6 +662,7 @@ fn bench_kb(bench: &Benchmark) -> Result<PerformanceReport> {
     let ((proof, cycles), riscv_duration) = time_operation(|| riscv.prove_cycles(stdin));
     info!("Verifying RISCV proof..");
     assert!(riscv.verify(&proof, riscv_vk));
+    check_zktls_digest::<KoalaBear>(bench, proof.pv_stream.as_ref())?;
 
     info!("╔═══════════════════════╗");
     info!("║     CONVERT PHASE     ║");
@@ -733,7 +731,7 @@ fn bench_kb(bench: &Benchmark) -> Result<PerformanceReport> {
     })
 }
 
-fn bench_tracegen<SC>(bench: &Benchmark) -> Result<PerformanceReport>
+fn bench_tracegen<SC>(bench: &Benchmark, field: &str) -> Result<PerformanceReport>
 where
     SC: Send + StarkGenericConfig + 'static,
     Com<SC>: Send + Sync,
@@ -746,7 +744,7 @@ where
     FieldSpecificPoseidon2Chip<Val<SC>>: Air<ProverConstraintFolder<SC>>,
     FieldSpecificPrecompilePoseidon2Chip<Val<SC>>: Air<ProverConstraintFolder<SC>>,
 {
-    let (elf, stdin) = load(bench)?;
+    let (elf, stdin) = load(bench, field)?;
     let riscv_opts = EmulatorOpts::bench_riscv_ops();
 
     info!(
@@ -831,8 +829,8 @@ fn main() -> Result<()> {
     if args.noprove {
         let mut results = Vec::with_capacity(programs.len());
         let run_bench = match args.field.as_str() {
-            "bb" | "bb_vk" => |bench| bench_tracegen::<RiscvBBSC>(bench),
-            "kb" | "kb_vk" => |bench| bench_tracegen::<RiscvKBSC>(bench),
+            "bb" | "bb_vk" => |bench| bench_tracegen::<RiscvBBSC>(bench, "bb"),
+            "kb" | "kb_vk" => |bench| bench_tracegen::<RiscvKBSC>(bench, "kb"),
             _ => panic!("bad field, use bb or kb"),
         };
 
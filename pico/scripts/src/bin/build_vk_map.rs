@@ -282,15 +282,14 @@ macro_rules! define_generate_all_shapes {
                 .generate_all_allowed_shapes()
                 .map(|shape| PicoRecursionProgramShape::Convert(shape.into()));
 
-            let combine_shapes_2 =
-                recursion_shape_config
-                    .get_all_shape_combinations(2)
-                    .map(|shape| {
-                        PicoRecursionProgramShape::Combine(RecursionVkShape::from_proof_shapes(
-                            shape,
-                            merkle_tree_height,
-                        ))
-                    });
+            let combine_shapes_2 = recursion_shape_config
+                .get_all_shape_combinations(recursion_shape_config.combine_arity())
+                .map(|shape| {
+                    PicoRecursionProgramShape::Combine(RecursionVkShape::from_proof_shapes(
+                        shape,
+                        merkle_tree_height,
+                    ))
+                });
 
             let combine_shapes_1 =
                 recursion_shape_config
@@ -1,6 +1,9 @@
 use anyhow::Result;
 use clap::{crate_version, Parser, Subcommand};
-use pico_cli::subcommand::{build::BuildCmd, new::NewCmd, prove::ProveCmd};
+use pico_cli::subcommand::{
+    build::BuildCmd, disasm::DisasmCmd, info::InfoCmd, memtrace::MemtraceCmd, new::NewCmd,
+    prove::ProveCmd, recursion_profile::RecursionProfileCmd, replay::ReplayCmd,
+};
 use pico_sdk::init_logger;
 
 #[derive(Parser)]
@@ -21,6 +24,11 @@ pub enum SubCommands {
     Build(BuildCmd),
     Prove(ProveCmd),
     New(NewCmd),
+    RecursionProfile(RecursionProfileCmd),
+    Disasm(DisasmCmd),
+    Memtrace(MemtraceCmd),
+    Info(InfoCmd),
+    Replay(ReplayCmd),
 }
 
 fn main() -> Result<()> {
@@ -32,5 +40,10 @@ fn main() -> Result<()> {
         SubCommands::Build(cmd) => cmd.run(),
         SubCommands::Prove(cmd) => cmd.run(),
         SubCommands::New(cmd) => cmd.run(),
+        SubCommands::RecursionProfile(cmd) => cmd.run(),
+        SubCommands::Disasm(cmd) => cmd.run(),
+        SubCommands::Memtrace(cmd) => cmd.run(),
+        SubCommands::Info(cmd) => cmd.run(),
+        SubCommands::Replay(cmd) => cmd.run(),
     }
 }
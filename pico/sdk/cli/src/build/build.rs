@@ -1,17 +1,19 @@
-use anyhow::{Error, Result};
-use cargo_metadata::{MetadataCommand, Package};
+use anyhow::{Context, Error, Result};
+use cargo_metadata::{Metadata, MetadataCommand, Node, Package, PackageId};
 use std::{
+    collections::{HashMap, HashSet},
     env, fs,
     path::{Path, PathBuf},
     process::Command,
+    sync::Mutex,
 };
 
 use crate::{
     clean_command_env, get_rustc_path, get_target_directory, log_command,
-    subcommand::build::BuildArgs,
+    subcommand::build::BuildArgs, DEFAULT_ELF_DIR,
 };
 
-use super::{execute_command, find_target_file};
+use super::{execute_command, find_target_file, find_target_file_named, RUSTC_TARGET};
 
 const RUSTUP_TOOLCHAIN_NAME: &str = "nightly-2024-11-27";
 const TARGET_ELF: &str = "riscv32im-pico-zkvm-elf";
@@ -23,6 +25,126 @@ pub fn build_program(args: &BuildArgs, program_dir: Option<PathBuf>) -> Result<P
 
     // get build directory by the manifest path
     let target_dir: PathBuf = get_target_directory(pkg.manifest_path.as_ref())?;
+    let output_dir = program_dir.join(&args.output_directory);
+
+    build_package(args, pkg, target_dir, output_dir)
+}
+
+/// Build every zkVM crate of the workspace containing `workspace_dir`, i.e. every member with a
+/// `[package.metadata.pico]` table, and copy the ELFs to the shared ELF directory.
+///
+/// Crates are built in waves: a crate starts once all zkVM crates it depends on, directly or
+/// through other packages, have been built. The crates of a wave are built in parallel, each
+/// in its own target directory so the cargo invocations do not wait on each other's lock.
+pub fn build_workspace(
+    args: &BuildArgs,
+    workspace_dir: Option<PathBuf>,
+) -> Result<Vec<PathBuf>, Error> {
+    let workspace_dir = workspace_dir.unwrap_or_else(|| std::env::current_dir().unwrap());
+    let metadata = MetadataCommand::new()
+        .manifest_path(workspace_dir.join("Cargo.toml"))
+        .exec()?;
+
+    let programs: Vec<Package> = metadata
+        .workspace_packages()
+        .into_iter()
+        .filter(|pkg| pkg.metadata.get("pico").is_some())
+        .cloned()
+        .collect();
+    if programs.is_empty() {
+        return Err(Error::msg(format!(
+            "No package with [package.metadata.pico] found in {}",
+            workspace_dir.display()
+        )));
+    }
+
+    let elf_dir = metadata
+        .workspace_root
+        .as_std_path()
+        .join(args.elf_dir.as_deref().unwrap_or(DEFAULT_ELF_DIR));
+    let target_dir = metadata.target_directory.as_std_path().join("pico");
+
+    let mut pending = program_dependencies(&metadata, &programs)?;
+    let mut elf_paths = Vec::with_capacity(programs.len());
+    while !pending.is_empty() {
+        let (ready, blocked): (Vec<_>, Vec<_>) =
+            pending.into_iter().partition(|(_, deps)| deps.is_empty());
+        if ready.is_empty() {
+            return Err(Error::msg("zkVM crates have cyclic dependencies"));
+        }
+
+        let results = Mutex::new(Vec::with_capacity(ready.len()));
+        rayon::scope(|scope| {
+            for (pkg, _) in &ready {
+                let results = &results;
+                let target_dir = target_dir.join(&pkg.name);
+                let elf_dir = elf_dir.clone();
+                let mut args = args.clone();
+                args.elf_name = pkg.name.clone();
+                scope.spawn(move |_| {
+                    println!("Building {}...", pkg.name);
+                    let result = build_package(&args, pkg.clone(), target_dir, elf_dir);
+                    results.lock().unwrap().push((pkg.name.clone(), result));
+                });
+            }
+        });
+
+        for (name, result) in results.into_inner().unwrap() {
+            elf_paths.push(result.with_context(|| format!("failed to build {name}"))?);
+        }
+
+        let built: HashSet<_> = ready.into_iter().map(|(pkg, _)| pkg.id).collect();
+        pending = blocked
+            .into_iter()
+            .map(|(pkg, deps)| {
+                let deps = deps.into_iter().filter(|id| !built.contains(id)).collect();
+                (pkg, deps)
+            })
+            .collect();
+    }
+
+    Ok(elf_paths)
+}
+
+/// Pair each zkVM crate with the other zkVM crates it transitively depends on, following the
+/// resolved dependency graph.
+pub(crate) fn program_dependencies(
+    metadata: &Metadata,
+    programs: &[Package],
+) -> Result<Vec<(Package, HashSet<PackageId>)>, Error> {
+    let resolve = metadata
+        .resolve
+        .as_ref()
+        .ok_or_else(|| Error::msg("cargo metadata did not resolve dependencies"))?;
+    let graph: HashMap<&PackageId, &Node> = resolve.nodes.iter().map(|n| (&n.id, n)).collect();
+    let program_ids: HashSet<&PackageId> = programs.iter().map(|pkg| &pkg.id).collect();
+
+    Ok(programs
+        .iter()
+        .map(|pkg| {
+            let mut visited = HashSet::new();
+            let mut stack = vec![&pkg.id];
+            while let Some(id) = stack.pop() {
+                if let Some(node) = graph.get(id) {
+                    stack.extend(node.dependencies.iter().filter(|dep| visited.insert(*dep)));
+                }
+            }
+            let deps = visited
+                .into_iter()
+                .filter(|id| *id != &pkg.id && program_ids.contains(id))
+                .cloned()
+                .collect();
+            (pkg.clone(), deps)
+        })
+        .collect())
+}
+
+pub(crate) fn build_package(
+    args: &BuildArgs,
+    pkg: Package,
+    target_dir: PathBuf,
+    output_dir: PathBuf,
+) -> Result<PathBuf, Error> {
     fs::create_dir_all(&target_dir).unwrap();
 
     let rust_flags = vec![];
@@ -33,6 +155,10 @@ pub fn build_program(args: &BuildArgs, program_dir: Option<PathBuf>) -> Result<P
         build_command.args(["--features", &args.features.join(",")]);
     }
 
+    if !args.binary.is_empty() {
+        build_command.args(["--bin", &args.binary]);
+    }
+
     build_command.args([
         "--manifest-path",
         pkg.manifest_path.as_str(),
@@ -47,24 +173,19 @@ pub fn build_program(args: &BuildArgs, program_dir: Option<PathBuf>) -> Result<P
         .filter(|v| v.starts_with("CARGO_FEATURE_") || v.starts_with("CARGO_CFG_"))
         .fold(&mut build_command, Command::env_remove);
 
-    match execute_command(build_command, target_dir.clone()) {
-        Ok(build_dir) => {
-            println!("Build directory: {:?}", build_dir.display());
+    let build_dir = execute_command(build_command, target_dir)?;
+    println!("Build directory: {:?}", build_dir.display());
 
-            let binary_file = find_target_file(pkg, build_dir)?;
-            println!("Found binary file: {:?}", binary_file.display());
+    let binary_file = if args.binary.is_empty() {
+        find_target_file(pkg, build_dir)?
+    } else {
+        find_target_file_named(pkg, build_dir, &args.binary)?
+    };
+    println!("Found binary file: {:?}", binary_file.display());
 
-            let output_dir = program_dir.join(&args.output_directory);
-            println!("Copying binary file to {:?}", output_dir);
+    println!("Copying binary file to {:?}", output_dir);
 
-            copy_elf_file(args, binary_file, output_dir)
-        }
-        Err(Some(code)) => Err(Error::msg(format!(
-            "Cargo build failed with code: {}",
-            code
-        ))),
-        Err(None) => Err(Error::msg("Cargo build failed")),
-    }
+    copy_elf_file(args, binary_file, output_dir)
 }
 
 pub fn create_cargo_build_command(subcmd: &str, rust_flags: &[&str]) -> Command {
@@ -74,13 +195,7 @@ pub fn create_cargo_build_command(subcmd: &str, rust_flags: &[&str]) -> Command
     println!("rustc version: {rustc}");
 
     let mut cmd = clean_command_env("cargo");
-    let mut args = vec![
-        &toolchain,
-        subcmd,
-        "--release",
-        "--target",
-        "riscv32im-risc0-zkvm-elf",
-    ];
+    let mut args = vec![&toolchain, subcmd, "--release", "--target", RUSTC_TARGET];
 
     args.extend_from_slice(&[
         "-Z",
@@ -1,30 +1,56 @@
-use anyhow::{Context, Error};
 use cargo_metadata::Package;
 use std::{
-    io::{BufRead, BufReader},
+    io::{self, BufRead, BufReader},
     path::{Path, PathBuf},
     process::{Command, Stdio},
     thread,
 };
+use thiserror::Error;
 
 #[allow(clippy::module_inception)]
 pub mod build;
 
 pub mod client;
 
+pub mod watch;
+
+/// The rustc target guest programs are compiled for. Cargo places their output under a directory
+/// of the same name, while the copied ELF is named after the Pico target.
+pub(crate) const RUSTC_TARGET: &str = "riscv32im-risc0-zkvm-elf";
+
+#[derive(Error, Debug)]
+pub enum BuildError {
+    #[error("failed to spawn the build command: {0}")]
+    Spawn(#[source] io::Error),
+    #[error("failed to wait for the build command: {0}")]
+    Wait(#[source] io::Error),
+    #[error("cargo build failed with code: {0:?}")]
+    CommandFailed(Option<i32>),
+    #[error("build output directory does not exist: {}", .0.display())]
+    MissingOutputDir(PathBuf),
+    #[error("expected 1 bin target, got {}: {:?}", .0.len(), .0)]
+    AmbiguousTarget(Vec<String>),
+    #[error("bin target `{name}` not found, available: {available:?}")]
+    BinNotFound {
+        name: String,
+        available: Vec<String>,
+    },
+    #[error("ELF file does not exist: {}", .0.display())]
+    MissingElf(PathBuf),
+}
+
 // Execute the command and handle the output depending on the context.
 pub(crate) fn execute_command(
     mut command: Command,
     target_dir: impl AsRef<Path>,
-) -> Result<PathBuf, Option<i32>> {
+) -> Result<PathBuf, BuildError> {
     println!("Start to execute command...");
     // Add necessary tags for stdout and stderr from the command.
     let mut child = command
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
-        .context("failed to spawn command")
-        .expect("cargo build failed");
+        .map_err(BuildError::Spawn)?;
     let stdout = BufReader::new(child.stdout.take().unwrap());
     let stderr = BufReader::new(child.stderr.take().unwrap());
 
@@ -43,39 +69,63 @@ pub(crate) fn execute_command(
     stdout_handle.join().unwrap();
 
     // Wait for the child process to finish and check the result.
-    let result = child.wait().expect("execution of the command failed");
+    let result = child.wait().map_err(BuildError::Wait)?;
     if !result.success() {
-        println!("error: {:?}", result.code());
-        Err(result.code())
-    } else {
-        Ok(target_dir
-            .as_ref()
-            .join("riscv32im-risc0-zkvm-elf")
-            .join("release"))
+        return Err(BuildError::CommandFailed(result.code()));
+    }
+
+    let build_dir = target_dir.as_ref().join(RUSTC_TARGET).join("release");
+    if !build_dir.is_dir() {
+        return Err(BuildError::MissingOutputDir(build_dir));
     }
+    Ok(build_dir)
 }
 
-/// Find the target file in the target directory.
+/// Find the target file in the target directory. The package must have exactly one bin target.
 pub fn find_target_file(
     program_pkg: Package,
     target_dir: impl AsRef<Path>,
-) -> anyhow::Result<PathBuf> {
-    let target_dir: &Path = target_dir.as_ref();
-    let elf_paths = program_pkg
+) -> Result<PathBuf, BuildError> {
+    let mut bin_names = bin_target_names(program_pkg);
+    if bin_names.len() != 1 {
+        return Err(BuildError::AmbiguousTarget(bin_names));
+    }
+
+    located_elf(target_dir.as_ref().join(bin_names.pop().unwrap()))
+}
+
+/// Find the target file of the bin target `bin_name`, for packages with several bin targets.
+pub fn find_target_file_named(
+    program_pkg: Package,
+    target_dir: impl AsRef<Path>,
+    bin_name: &str,
+) -> Result<PathBuf, BuildError> {
+    let bin_names = bin_target_names(program_pkg);
+    if !bin_names.iter().any(|name| name == bin_name) {
+        return Err(BuildError::BinNotFound {
+            name: bin_name.to_string(),
+            available: bin_names,
+        });
+    }
+
+    located_elf(target_dir.as_ref().join(bin_name))
+}
+
+fn bin_target_names(program_pkg: Package) -> Vec<String> {
+    program_pkg
         .targets
         .into_iter()
         .filter(move |target| {
             target.kind.contains(&"bin".to_owned())
                 && target.crate_types.contains(&"bin".to_owned())
         })
-        .collect::<Vec<_>>();
-    if elf_paths.len() != 1 {
-        Err(Error::msg(format!(
-            "Expected 1 target, got {}: {:#?}",
-            elf_paths.len(),
-            elf_paths
-        )))
-    } else {
-        Ok(target_dir.join(&elf_paths[0].name))
+        .map(|target| target.name)
+        .collect()
+}
+
+fn located_elf(elf_path: PathBuf) -> Result<PathBuf, BuildError> {
+    if !elf_path.is_file() {
+        return Err(BuildError::MissingElf(elf_path));
     }
+    Ok(elf_path)
 }
@@ -0,0 +1,357 @@
+use anyhow::{Context, Error, Result};
+use cargo_metadata::{Metadata, MetadataCommand, Package, PackageId};
+use notify::{Event, RecursiveMode, Watcher};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use crate::{subcommand::build::BuildArgs, DEFAULT_ELF_DIR};
+
+use super::build::{build_package, get_package, program_dependencies};
+
+/// How long to wait for more changes after the first one, so that saving several files or an
+/// editor writing a file in steps triggers a single rebuild.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A zkVM crate and the directories of the workspace packages it is built from.
+struct WatchedProgram {
+    pkg: Package,
+    target_dir: PathBuf,
+    output_dir: PathBuf,
+    source_dirs: Vec<PathBuf>,
+}
+
+/// Build the zkVM crates like `pico build`, then rebuild them whenever a file of the workspace
+/// changes, until interrupted.
+///
+/// Only the crates built from the changed files are rebuilt: a change inside a workspace package
+/// rebuilds the zkVM crates depending on it, and a change outside every package, such as in the
+/// workspace manifest, rebuilds all of them. Changes that [`is_ignored`] filters out, such as
+/// under `.git` or a target directory, rebuild nothing. After each successful rebuild
+/// `args.run_after`, if set, is run from the current directory.
+pub fn watch(args: &BuildArgs) -> Result<(), Error> {
+    let current_dir = std::env::current_dir()?;
+    let metadata = MetadataCommand::new()
+        .manifest_path(current_dir.join("Cargo.toml"))
+        .exec()?;
+    let programs = watched_programs(args, &metadata, &current_dir)?;
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        // The receiver only goes away when watching stops.
+        let _ = sender.send(event);
+    })?;
+    let workspace_root = metadata.workspace_root.as_std_path();
+    watcher.watch(workspace_root, RecursiveMode::Recursive)?;
+
+    // Changes under the target and ELF directories come from the builds themselves.
+    let ignored = [metadata.target_directory.as_std_path().to_path_buf()]
+        .into_iter()
+        .chain(programs.iter().map(|program| program.output_dir.clone()))
+        .collect::<Vec<_>>();
+
+    let package_dirs = metadata
+        .workspace_packages()
+        .into_iter()
+        .filter_map(|pkg| Some(pkg.manifest_path.as_std_path().parent()?.to_path_buf()))
+        .collect::<Vec<_>>();
+
+    rebuild(args, &metadata, programs.iter().collect());
+    println!("Watching {} for changes...", workspace_root.display());
+
+    while let Ok(event) = receiver.recv() {
+        let mut changed = HashSet::new();
+        collect_paths(event, workspace_root, &ignored, &mut changed);
+        let deadline = Instant::now() + DEBOUNCE;
+        while let Ok(event) =
+            receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        {
+            collect_paths(event, workspace_root, &ignored, &mut changed);
+        }
+        if changed.is_empty() {
+            continue;
+        }
+
+        let rebuild_all = changed
+            .iter()
+            .any(|path| !package_dirs.iter().any(|dir| path.starts_with(dir)));
+        let affected = programs
+            .iter()
+            .filter(|program| {
+                rebuild_all
+                    || changed
+                        .iter()
+                        .any(|path| program.source_dirs.iter().any(|dir| path.starts_with(dir)))
+            })
+            .collect::<Vec<_>>();
+        if affected.is_empty() {
+            continue;
+        }
+        rebuild(args, &metadata, affected);
+        println!("Watching {} for changes...", workspace_root.display());
+    }
+
+    Ok(())
+}
+
+/// The zkVM crates `pico build` would build with `args`: the crate in `current_dir`, or every
+/// crate with `[package.metadata.pico]` with `--all`.
+fn watched_programs(
+    args: &BuildArgs,
+    metadata: &Metadata,
+    current_dir: &Path,
+) -> Result<Vec<WatchedProgram>, Error> {
+    let packages = if args.all {
+        metadata
+            .workspace_packages()
+            .into_iter()
+            .filter(|pkg| pkg.metadata.get("pico").is_some())
+            .cloned()
+            .collect()
+    } else {
+        vec![get_package(current_dir)]
+    };
+    if packages.is_empty() {
+        return Err(Error::msg(format!(
+            "No package with [package.metadata.pico] found in {}",
+            current_dir.display()
+        )));
+    }
+
+    let elf_dir = metadata
+        .workspace_root
+        .as_std_path()
+        .join(args.elf_dir.as_deref().unwrap_or(DEFAULT_ELF_DIR));
+    Ok(packages
+        .into_iter()
+        .map(|pkg| {
+            let (target_dir, output_dir) = if args.all {
+                let target_dir = metadata.target_directory.as_std_path().join("pico");
+                (target_dir.join(&pkg.name), elf_dir.clone())
+            } else {
+                let target_dir = metadata.target_directory.as_std_path().to_path_buf();
+                (target_dir, current_dir.join(&args.output_directory))
+            };
+            let source_dirs = source_dirs(metadata, &pkg.id);
+            WatchedProgram {
+                pkg,
+                target_dir,
+                output_dir,
+                source_dirs,
+            }
+        })
+        .collect())
+}
+
+/// The directories of `id` and of every workspace package it transitively depends on.
+fn source_dirs(metadata: &Metadata, id: &PackageId) -> Vec<PathBuf> {
+    let workspace_dirs: HashMap<&PackageId, &Path> = metadata
+        .workspace_packages()
+        .into_iter()
+        .filter_map(|pkg| Some((&pkg.id, pkg.manifest_path.as_std_path().parent()?)))
+        .collect();
+    let nodes: HashMap<&PackageId, _> = metadata
+        .resolve
+        .iter()
+        .flat_map(|resolve| &resolve.nodes)
+        .map(|node| (&node.id, &node.dependencies))
+        .collect();
+
+    let mut visited = HashSet::from([id]);
+    let mut stack = vec![id];
+    while let Some(id) = stack.pop() {
+        if let Some(deps) = nodes.get(id) {
+            stack.extend(deps.iter().filter(|dep| visited.insert(*dep)));
+        }
+    }
+    visited
+        .into_iter()
+        .filter_map(|id| workspace_dirs.get(id).map(|dir| dir.to_path_buf()))
+        .collect()
+}
+
+fn collect_paths(
+    event: notify::Result<Event>,
+    workspace_root: &Path,
+    ignored: &[PathBuf],
+    changed: &mut HashSet<PathBuf>,
+) {
+    match event {
+        Ok(event) if !event.kind.is_access() => changed.extend(
+            event
+                .paths
+                .into_iter()
+                .filter(|path| !is_ignored(path, workspace_root, ignored)),
+        ),
+        Ok(_) => {}
+        Err(err) => eprintln!("Watch error: {err}"),
+    }
+}
+
+/// Whether a change to `path` should not trigger a rebuild: it is under one of the `ignored`
+/// directories, under a hidden file or directory of the workspace such as `.git` or an editor
+/// swap file, under a `target` directory, or an editor backup ending in `~`.
+fn is_ignored(path: &Path, workspace_root: &Path, ignored: &[PathBuf]) -> bool {
+    if ignored.iter().any(|dir| path.starts_with(dir)) {
+        return true;
+    }
+    let relative = path.strip_prefix(workspace_root).unwrap_or(path);
+    relative.components().any(|component| {
+        let name = component.as_os_str().to_string_lossy();
+        name.starts_with('.') || name == "target"
+    }) || path
+        .file_name()
+        .is_some_and(|name| name.to_string_lossy().ends_with('~'))
+}
+
+/// Rebuild `affected`, the zkVM crates they depend on first, then run `args.run_after` if every
+/// build succeeded. Failures are reported and do not stop watching.
+fn rebuild(args: &BuildArgs, metadata: &Metadata, affected: Vec<&WatchedProgram>) {
+    let start = Instant::now();
+    let result = build_in_order(args, metadata, affected);
+    let elapsed = start.elapsed().as_secs_f64();
+    match result {
+        Ok(elf_paths) => {
+            for elf_path in elf_paths {
+                println!("ELF binary built at: {:?}", elf_path.display());
+            }
+            println!("Build finished in {elapsed:.2}s");
+        }
+        Err(err) => {
+            eprintln!("Build failed after {elapsed:.2}s: {err:?}");
+            return;
+        }
+    }
+
+    if let Some(run_after) = &args.run_after {
+        println!("Running {run_after}...");
+        match Command::new(run_after).status() {
+            Ok(status) if status.success() => println!("{run_after} finished"),
+            Ok(status) => eprintln!("{run_after} failed with {status}"),
+            Err(err) => eprintln!("Failed to run {run_after}: {err}"),
+        }
+    }
+}
+
+/// Build `affected` in dependency order, as `build_workspace` does.
+///
+/// Unlike `build_workspace`, the crates of a wave are built one after the other, so the output of
+/// a failing build is not interleaved with the others.
+fn build_in_order(
+    args: &BuildArgs,
+    metadata: &Metadata,
+    affected: Vec<&WatchedProgram>,
+) -> Result<Vec<PathBuf>, Error> {
+    let packages = affected
+        .iter()
+        .map(|program| program.pkg.clone())
+        .collect::<Vec<_>>();
+    let mut pending = program_dependencies(metadata, &packages)?;
+    let mut elf_paths = Vec::with_capacity(affected.len());
+    while !pending.is_empty() {
+        let (ready, blocked): (Vec<_>, Vec<_>) =
+            pending.into_iter().partition(|(_, deps)| deps.is_empty());
+        if ready.is_empty() {
+            return Err(Error::msg("zkVM crates have cyclic dependencies"));
+        }
+        for (pkg, _) in &ready {
+            let program = affected
+                .iter()
+                .find(|program| program.pkg.id == pkg.id)
+                .unwrap();
+            let mut args = args.clone();
+            if args.all {
+                args.elf_name = pkg.name.clone();
+            }
+            println!("Building {}...", pkg.name);
+            elf_paths.push(
+                build_package(
+                    &args,
+                    pkg.clone(),
+                    program.target_dir.clone(),
+                    program.output_dir.clone(),
+                )
+                .with_context(|| format!("failed to build {}", pkg.name))?,
+            );
+        }
+        let built: HashSet<_> = ready.into_iter().map(|(pkg, _)| pkg.id).collect();
+        pending = blocked
+            .into_iter()
+            .map(|(pkg, deps)| {
+                (
+                    pkg,
+                    deps.into_iter().filter(|id| !built.contains(id)).collect(),
+                )
+            })
+            .collect();
+    }
+    Ok(elf_paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, EventKind, ModifyKind};
+
+    fn changed_paths(events: Vec<Event>) -> HashSet<PathBuf> {
+        let ignored = [PathBuf::from("/ws/elf")];
+        let mut changed = HashSet::new();
+        for event in events {
+            collect_paths(Ok(event), Path::new("/ws"), &ignored, &mut changed);
+        }
+        changed
+    }
+
+    #[test]
+    fn test_is_ignored() {
+        let root = Path::new("/ws");
+        let ignored = [PathBuf::from("/ws/elf"), PathBuf::from("/build/target")];
+        for path in [
+            "/ws/elf/riscv32im-pico-zkvm-elf",
+            "/build/target/debug/app",
+            "/ws/.git/index",
+            "/ws/app/src/.main.rs.swp",
+            "/ws/app/target/release/app",
+            "/ws/app/src/main.rs~",
+        ] {
+            assert!(is_ignored(Path::new(path), root, &ignored), "{path}");
+        }
+        for path in [
+            "/ws/Cargo.toml",
+            "/ws/app/src/main.rs",
+            "/ws/lib/src/targets.rs",
+        ] {
+            assert!(!is_ignored(Path::new(path), root, &ignored), "{path}");
+        }
+
+        // Only the components under the workspace root are checked.
+        assert!(!is_ignored(
+            Path::new("/home/.cache/ws/src/lib.rs"),
+            Path::new("/home/.cache/ws"),
+            &[],
+        ));
+    }
+
+    #[test]
+    fn test_collect_paths() {
+        let modify = |path: &str| {
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from(path))
+        };
+        let changed = changed_paths(vec![
+            modify("/ws/app/src/main.rs"),
+            modify("/ws/.git/HEAD"),
+            modify("/ws/elf/riscv32im-pico-zkvm-elf"),
+            modify("/ws/app/src/main.rs"),
+            Event::new(EventKind::Access(AccessKind::Any))
+                .add_path(PathBuf::from("/ws/lib/src/lib.rs")),
+        ]);
+        assert_eq!(
+            changed,
+            HashSet::from([PathBuf::from("/ws/app/src/main.rs")])
+        );
+    }
+}
@@ -1,7 +1,13 @@
 use anyhow::Result;
 use clap::Parser;
 
-use crate::{build::build::build_program, DEFAULT_ELF_DIR};
+use crate::{
+    build::{
+        build::{build_program, build_workspace},
+        watch::watch,
+    },
+    DEFAULT_ELF_DIR,
+};
 
 #[derive(Parser)]
 #[command(name = "build", about = "Build the ELF binary")]
@@ -12,6 +18,18 @@ pub struct BuildCmd {
 
 impl BuildCmd {
     pub fn run(&self) -> Result<()> {
+        if self.build_args.watch {
+            return watch(&self.build_args);
+        }
+
+        if self.build_args.all {
+            println!("Building all zkVM crates in the workspace...");
+            for elf_path in build_workspace(&self.build_args, None)? {
+                println!("ELF binary built at: {:?}", elf_path.display());
+            }
+            return Ok(());
+        }
+
         println!("Building ELF binary...");
         let elf_path = build_program(&self.build_args, None)?;
         println!("ELF binary built at: {:?}", elf_path.display());
@@ -63,4 +81,35 @@ pub struct BuildArgs {
         default_value = DEFAULT_ELF_DIR
     )]
     pub output_directory: String,
+
+    #[clap(
+        long,
+        action,
+        help = "Build every crate with [package.metadata.pico] in the workspace, in parallel"
+    )]
+    pub all: bool,
+
+    #[clap(
+        long,
+        action,
+        requires = "all",
+        help = "Copy the ELFs built with --all to this directory under the workspace root [default: elf]"
+    )]
+    pub elf_dir: Option<String>,
+
+    #[clap(
+        long,
+        action,
+        help = "Rebuild the affected zkVM crates whenever a file of the workspace changes"
+    )]
+    pub watch: bool,
+
+    #[clap(
+        long,
+        action,
+        requires = "watch",
+        value_name = "BINARY",
+        help = "Run this binary after every successful rebuild in --watch mode"
+    )]
+    pub run_after: Option<String>,
 }
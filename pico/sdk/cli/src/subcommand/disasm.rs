@@ -0,0 +1,63 @@
+use anyhow::{Error, Result};
+use clap::Parser;
+use pico_vm::compiler::riscv::compiler::{Compiler, SourceType};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Parser)]
+#[command(name = "disasm", about = "disassemble the instructions of an ELF")]
+pub struct DisasmCmd {
+    #[clap(help = "ELF file path")]
+    elf: PathBuf,
+
+    #[clap(
+        long,
+        help = "JSON object mapping pcs (decimal or 0x-prefixed hex) to cycle counts, shown next to each instruction"
+    )]
+    profile: Option<PathBuf>,
+}
+
+impl DisasmCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf = fs::read(&self.elf)?;
+        let lines = Compiler::new(SourceType::RISCV, &elf).disassemble();
+        let profile = match self.profile {
+            Some(ref path) => Some(load_profile(path)?),
+            None => None,
+        };
+
+        for line in lines {
+            let text = format!(
+                "{:08x}:  {:08x}  {:<8} {}",
+                line.addr, line.hex, line.mnemonic, line.operands
+            );
+            match profile {
+                Some(ref profile) => {
+                    let cycles = profile.get(&line.addr).copied().unwrap_or(0);
+                    println!("{:<56} ; {}", text, cycles);
+                }
+                None => println!("{}", text),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn load_profile(path: &Path) -> Result<HashMap<u32, u64>> {
+    let entries: HashMap<String, u64> = serde_json::from_slice(&fs::read(path)?)?;
+    entries
+        .into_iter()
+        .map(|(pc, cycles)| {
+            let parsed = match pc.strip_prefix("0x") {
+                Some(hex) => u32::from_str_radix(hex, 16),
+                None => pc.parse(),
+            };
+            parsed
+                .map(|pc| (pc, cycles))
+                .map_err(|_| Error::msg(format!("invalid pc in profile: {}", pc)))
+        })
+        .collect()
+}
@@ -0,0 +1,64 @@
+use anyhow::{Error, Result};
+use clap::Parser;
+use p3_baby_bear::BabyBear;
+use p3_koala_bear::KoalaBear;
+use pico_vm::{
+    compiler::riscv::compiler::{Compiler, SourceType},
+    configs::{
+        config::StarkGenericConfig,
+        stark_config::{BabyBearPoseidon2, KoalaBearPoseidon2},
+    },
+    instances::compiler::shapes::riscv_shape::RiscvShapeConfig,
+    proverchain::{InitialProverSetup, RiscvProver},
+};
+use std::{fs, path::PathBuf};
+
+#[derive(Parser)]
+#[command(
+    name = "info",
+    about = "show information about a program, such as its vk fingerprint"
+)]
+pub struct InfoCmd {
+    #[clap(help = "ELF file path")]
+    elf: PathBuf,
+
+    // Field to work on.
+    // bb | kb
+    #[clap(long, default_value = "kb")]
+    field: String,
+}
+
+impl InfoCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf = fs::read(&self.elf)?;
+        let program = Compiler::new(SourceType::RISCV, &elf).compile();
+        println!("elf:           {}", self.elf.display());
+        println!("entry:         {:#010x}", program.pc_start);
+        println!("instructions:  {}", program.instructions.len());
+
+        // The vk is set up the same way as in the prover client, so that the fingerprint matches
+        // the one in its logs.
+        let (fingerprint, digest) = match self.field.as_str() {
+            "kb" => {
+                let riscv = RiscvProver::new_initial_prover(
+                    (KoalaBearPoseidon2::new(), &elf),
+                    Default::default(),
+                    Some(RiscvShapeConfig::<KoalaBear>::default()),
+                );
+                (riscv.vk().to_string(), riscv.vk_digest())
+            }
+            "bb" => {
+                let riscv = RiscvProver::new_initial_prover(
+                    (BabyBearPoseidon2::new(), &elf),
+                    Default::default(),
+                    Some(RiscvShapeConfig::<BabyBear>::default()),
+                );
+                (riscv.vk().to_string(), riscv.vk_digest())
+            }
+            field => return Err(Error::msg(format!("unsupported field: {}", field))),
+        };
+        println!("vk:            {}", fingerprint);
+        println!("vk digest:     {:?}", digest);
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::subcommand::prove::{parse_input, Input, ProveCmd};
+use anyhow::Result;
+use clap::Parser;
+use p3_koala_bear::KoalaBear;
+use pico_vm::{
+    compiler::riscv::{
+        compiler::{Compiler, SourceType},
+        program::Program,
+    },
+    emulator::{
+        opts::EmulatorOpts,
+        riscv::emulator::{RiscvEmulator, RiscvEmulatorMode},
+        stdin::EmulatorStdin,
+    },
+};
+use std::{fs, path::PathBuf};
+
+/// The top of the guest stack, as set up by the `pico-sdk` entrypoint. The stack grows down
+/// towards the registers.
+const STACK_TOP: u32 = 0x0020_0400;
+
+#[derive(Parser)]
+#[command(
+    name = "memtrace",
+    about = "count the memory accesses of a program and list the most accessed words"
+)]
+pub struct MemtraceCmd {
+    #[clap(help = "ELF file path")]
+    elf: PathBuf,
+
+    #[clap(long, value_parser = parse_input, help = "Input bytes or file path")]
+    input: Option<Input>,
+
+    #[clap(long, default_value_t = 50, help = "number of words to list")]
+    top: usize,
+}
+
+impl MemtraceCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf = fs::read(&self.elf)?;
+        let compiler = Compiler::new(SourceType::RISCV, &elf);
+        let program = compiler.compile();
+
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        stdin.write_slice(&ProveCmd::get_input_bytes(&self.input)?);
+
+        // Only the counts are needed, so skip tracing and drop the records.
+        let mut emulator = RiscvEmulator::new::<KoalaBear>(program, EmulatorOpts::default());
+        emulator.mode = RiscvEmulatorMode::Simple;
+        emulator.count_memory_accesses();
+        emulator.write_stdin(&stdin.finalize());
+        while !emulator.emulate_batch(&mut |_| {})? {}
+
+        // The heap starts at `_end` and grows up, towards the stack.
+        let heap_start = compiler
+            .symbols()
+            .iter()
+            .find(|symbol| symbol.name == "_end")
+            .map(|symbol| symbol.addr);
+
+        println!(
+            "{:<10}  {:>12}  {:>12}  symbol",
+            "address", "reads", "writes"
+        );
+        for (addr, count) in emulator.hottest_memory_words(self.top) {
+            let symbol = if addr < STACK_TOP {
+                "[stack]".to_string()
+            } else if heap_start.is_some_and(|start| addr >= start) {
+                "[heap]".to_string()
+            } else {
+                compiler
+                    .symbol_at(addr)
+                    .map(|symbol| format!("{}+{:#x}", symbol.name, addr - symbol.addr))
+                    .unwrap_or_default()
+            };
+            println!(
+                "{:#010x}  {:>12}  {:>12}  {}",
+                addr, count.reads, count.writes, symbol
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,8 @@
 pub mod build;
+pub mod disasm;
+pub mod info;
+pub mod memtrace;
 pub mod new;
 pub mod prove;
+pub mod recursion_profile;
+pub mod replay;
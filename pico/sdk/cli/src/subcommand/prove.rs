@@ -12,7 +12,7 @@ use crate::{
     get_target_directory, DEFAULT_ELF_DIR,
 };
 
-fn parse_input(s: &str) -> Result<Input, String> {
+pub(crate) fn parse_input(s: &str) -> Result<Input, String> {
     // First try to parse as hex if it starts with 0x
     #[allow(clippy::manual_strip)]
     if s.starts_with("0x") {
@@ -33,7 +33,7 @@ fn parse_input(s: &str) -> Result<Input, String> {
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
-enum Input {
+pub(crate) enum Input {
     FilePath(PathBuf),
     HexBytes(Vec<u8>),
 }
@@ -66,7 +66,7 @@ pub struct ProveCmd {
 }
 
 impl ProveCmd {
-    fn get_input_bytes(input: &Option<Input>) -> Result<Vec<u8>> {
+    pub(crate) fn get_input_bytes(input: &Option<Input>) -> Result<Vec<u8>> {
         match input {
             Some(Input::FilePath(path)) => {
                 let mut file = File::open(path)?;
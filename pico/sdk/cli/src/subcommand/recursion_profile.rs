@@ -0,0 +1,107 @@
+use anyhow::{Error, Result};
+use clap::Parser;
+use pico_vm::{
+    compiler::recursion::{ir::Block, program::RecursionProgram},
+    configs::stark_config::{bb_poseidon2, kb_poseidon2},
+    emulator::recursion::emulator::{MemoryProfile, Runtime},
+    primitives::{
+        consts::{BABYBEAR_S_BOX_DEGREE, KOALABEAR_S_BOX_DEGREE},
+        Poseidon2Init,
+    },
+};
+use serde::de::DeserializeOwned;
+use std::{fs, path::PathBuf, sync::Arc};
+
+#[derive(Parser)]
+#[command(
+    name = "recursion-profile",
+    about = "run a recursion program and report its memory usage"
+)]
+pub struct RecursionProfileCmd {
+    #[clap(help = "bincode-serialized RecursionProgram file path")]
+    program: PathBuf,
+
+    #[clap(
+        long,
+        help = "bincode-serialized witness stream (Vec<Block>) file path"
+    )]
+    witness: Option<PathBuf>,
+
+    #[clap(
+        long,
+        help = "fail if the program writes to a memory address at or above this limit"
+    )]
+    memory_limit: Option<usize>,
+
+    // Field to work on.
+    // bb | kb
+    #[clap(long, default_value = "kb")]
+    pub field: String,
+}
+
+impl RecursionProfileCmd {
+    pub fn run(&self) -> Result<()> {
+        let profile = match self.field.as_str() {
+            "kb" => {
+                let (program, witness) = self.load::<kb_poseidon2::SC_Val>()?;
+                let mut runtime =
+                    Runtime::<_, kb_poseidon2::SC_Challenge, _, _, KOALABEAR_S_BOX_DEGREE>::new(
+                        Arc::new(program),
+                        kb_poseidon2::SC_Val::init(),
+                    );
+                runtime.witness_stream = witness.into();
+                if let Some(limit) = self.memory_limit {
+                    runtime.set_memory_limit(limit);
+                }
+                runtime.run()?;
+                runtime.profile_memory_usage()
+            }
+            "bb" => {
+                let (program, witness) = self.load::<bb_poseidon2::SC_Val>()?;
+                let mut runtime =
+                    Runtime::<_, bb_poseidon2::SC_Challenge, _, _, BABYBEAR_S_BOX_DEGREE>::new(
+                        Arc::new(program),
+                        bb_poseidon2::SC_Val::init(),
+                    );
+                runtime.witness_stream = witness.into();
+                if let Some(limit) = self.memory_limit {
+                    runtime.set_memory_limit(limit);
+                }
+                runtime.run()?;
+                runtime.profile_memory_usage()
+            }
+            field => {
+                return Err(Error::msg(format!(
+                    "unsupported field for recursion profiling: {}",
+                    field
+                )))
+            }
+        };
+
+        print_profile(&profile);
+        Ok(())
+    }
+
+    fn load<F: DeserializeOwned>(&self) -> Result<(RecursionProgram<F>, Vec<Block<F>>)> {
+        let program = bincode::deserialize(&fs::read(&self.program)?)?;
+        let witness = match self.witness {
+            Some(ref path) => bincode::deserialize(&fs::read(path)?)?,
+            None => Vec::new(),
+        };
+        Ok((program, witness))
+    }
+}
+
+fn print_profile(profile: &MemoryProfile) {
+    println!("peak memory entries: {}", profile.peak_entries);
+    println!(
+        "peak memory bytes (estimate): {}",
+        profile.peak_bytes_estimate
+    );
+
+    let mut ops = profile.ops_by_type.iter().collect::<Vec<_>>();
+    ops.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    for (name, count) in ops {
+        println!("  {}: {}", name, count);
+    }
+}
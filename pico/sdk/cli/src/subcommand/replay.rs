@@ -0,0 +1,62 @@
+use anyhow::{Error, Result};
+use clap::Parser;
+use p3_baby_bear::BabyBear;
+use p3_koala_bear::KoalaBear;
+use pico_vm::{
+    compiler::riscv::{
+        compiler::{Compiler, SourceType},
+        program::Program,
+    },
+    emulator::{
+        opts::EmulatorOpts,
+        riscv::emulator::{RiscvEmulator, RiscvEmulatorMode},
+        stdin::EmulatorStdin,
+    },
+};
+use std::{fs, path::PathBuf};
+
+#[derive(Parser)]
+#[command(
+    name = "replay",
+    about = "run a program on a saved stdin and print its public values and cycle count"
+)]
+pub struct ReplayCmd {
+    #[clap(help = "ELF file path")]
+    elf: PathBuf,
+
+    #[clap(
+        help = "stdin file written by EmulatorStdin::save_to_file or ProofWithInputs::save_inputs_to_file"
+    )]
+    stdin: PathBuf,
+
+    // Field to work on.
+    // bb | kb
+    #[clap(long, default_value = "kb")]
+    field: String,
+}
+
+impl ReplayCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf = fs::read(&self.elf)?;
+        let program = Compiler::new(SourceType::RISCV, &elf).compile();
+        let stdin = EmulatorStdin::<Program, Vec<u8>>::replay_from_file(&self.stdin)?;
+
+        // Only the final state is needed, so skip tracing and drop the records.
+        let opts = EmulatorOpts::default();
+        let mut emulator = match self.field.as_str() {
+            "kb" => RiscvEmulator::new::<KoalaBear>(program, opts),
+            "bb" => RiscvEmulator::new::<BabyBear>(program, opts),
+            field => return Err(Error::msg(format!("unsupported field: {}", field))),
+        };
+        emulator.mode = RiscvEmulatorMode::Simple;
+        emulator.write_stdin(&stdin);
+        while !emulator.emulate_batch(&mut |_| {})? {}
+
+        println!("cycles:         {}", emulator.state.global_clk);
+        println!(
+            "public values:  0x{}",
+            hex::encode(&emulator.state.public_values_stream)
+        );
+        Ok(())
+    }
+}
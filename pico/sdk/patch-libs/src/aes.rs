@@ -0,0 +1,47 @@
+use crate::{syscall_aes128_encrypt_block, syscall_aes256_encrypt_block, syscall_aes_key_schedule};
+
+/// Encrypts one block in place with AES-128, using the `AES128_ENCRYPT_BLOCK` precompile.
+pub fn aes128_encrypt_block(block: &mut [u8; 16], key: &[u8; 16]) {
+    let mut block_words = to_words(block);
+    let key_words = to_words(key);
+    unsafe {
+        syscall_aes128_encrypt_block(&mut block_words, &key_words);
+    }
+    for (bytes, word) in block.chunks_exact_mut(4).zip(block_words) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+/// An AES-256 key expanded once with the `AES_KEY_SCHEDULE` precompile, for encrypting any
+/// number of blocks without expanding it again.
+#[derive(Clone)]
+pub struct Aes256KeySchedule([u32; 60]);
+
+impl Aes256KeySchedule {
+    /// Expands `key`, using the `AES_KEY_SCHEDULE` precompile.
+    pub fn new(key: &[u8; 32]) -> Self {
+        let key_words: [u32; 8] =
+            core::array::from_fn(|i| u32::from_le_bytes(key[4 * i..4 * i + 4].try_into().unwrap()));
+        let mut schedule = [0u32; 60];
+        unsafe {
+            syscall_aes_key_schedule(&key_words, &mut schedule);
+        }
+        Self(schedule)
+    }
+
+    /// Encrypts one block in place, using the `AES256_ENCRYPT_BLOCK` precompile.
+    pub fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let mut block_words = to_words(block);
+        unsafe {
+            syscall_aes256_encrypt_block(&mut block_words, &self.0);
+        }
+        for (bytes, word) in block.chunks_exact_mut(4).zip(block_words) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+}
+
+/// Packs 16 bytes into word-aligned storage, keeping their order in memory.
+fn to_words(bytes: &[u8; 16]) -> [u32; 4] {
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
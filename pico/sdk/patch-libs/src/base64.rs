@@ -0,0 +1,27 @@
+use crate::syscall_base64_decode;
+
+/// Decodes base64 text with the `BASE64_DECODE` precompile.
+///
+/// Both the standard and the URL-safe alphabet are accepted, and missing padding is added, so
+/// unpadded URL-safe text decodes as well. Panics on invalid input, including padding in the
+/// middle of the text and non-zero bits before the padding.
+pub fn base64_decode(text: &[u8]) -> Vec<u8> {
+    assert!(text.len() % 4 != 1, "invalid base64 length {}", text.len());
+    let padded_len = text.len().div_ceil(4) * 4;
+
+    // The length word, followed by the padded text.
+    let mut input = vec![0u32; 1 + padded_len / 4];
+    input[0] = padded_len as u32;
+    let input_bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut input[1..]);
+    input_bytes[..text.len()].copy_from_slice(text);
+    input_bytes[text.len()..].fill(b'=');
+
+    // The decoded length, followed by the decoded bytes.
+    let mut output = vec![0u32; 1 + (3 * padded_len / 4).div_ceil(4)];
+    unsafe {
+        syscall_base64_decode(input.as_ptr(), output.as_mut_ptr());
+    }
+    let decoded_len = output[0] as usize;
+    let output_bytes: &[u8] = bytemuck::cast_slice(&output[1..]);
+    output_bytes[..decoded_len].to_vec()
+}
@@ -0,0 +1,151 @@
+//! Host implementations of the precompiles the guest library calls, so that its tests run outside
+//! the zkVM. Each one follows the semantics of the emulator's syscall of the same name.
+
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+fn secp256k1_p() -> BigUint {
+    BigUint::parse_bytes(
+        b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        16,
+    )
+    .unwrap()
+}
+
+fn from_words(words: &[u32]) -> BigUint {
+    BigUint::from_slice(words)
+}
+
+fn write_words(words: &mut [u32], value: &BigUint) {
+    let digits = value.to_u32_digits();
+    words.fill(0);
+    words[..digits.len()].copy_from_slice(&digits);
+}
+
+fn inverse(a: &BigUint, p: &BigUint) -> BigUint {
+    a.modpow(&(p - 2u32), p)
+}
+
+/// Writes `(x, y)` into the 16 limbs of an affine point.
+fn write_point(limbs: &mut [u32; 16], x: &BigUint, y: &BigUint) {
+    write_words(&mut limbs[..8], x);
+    write_words(&mut limbs[8..], y);
+}
+
+#[no_mangle]
+extern "C" fn syscall_secp256k1_add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    let (p, q) = unsafe { (&mut *p, &*q) };
+    let modulus = secp256k1_p();
+    let (x1, y1) = (from_words(&p[..8]), from_words(&p[8..]));
+    let (x2, y2) = (from_words(&q[..8]), from_words(&q[8..]));
+    assert_ne!(x1, x2, "the add precompile takes points with distinct x");
+
+    let lambda =
+        (&y2 + &modulus - &y1) * inverse(&((&x2 + &modulus - &x1) % &modulus), &modulus) % &modulus;
+    let x3 = (&lambda * &lambda + &modulus * 2u32 - &x1 - &x2) % &modulus;
+    let y3 = (&lambda * ((&x1 + &modulus - &x3) % &modulus) + &modulus - &y1) % &modulus;
+    write_point(p, &x3, &y3);
+}
+
+#[no_mangle]
+extern "C" fn syscall_secp256k1_double(p: *mut [u32; 16]) {
+    let p = unsafe { &mut *p };
+    let modulus = secp256k1_p();
+    let (x1, y1) = (from_words(&p[..8]), from_words(&p[8..]));
+
+    // The curve has a = 0.
+    let lambda = (&x1 * &x1 * 3u32) * inverse(&(&y1 * 2u32 % &modulus), &modulus) % &modulus;
+    let x3 = (&lambda * &lambda + &modulus * 2u32 - &x1 * 2u32) % &modulus;
+    let y3 = (&lambda * ((&x1 + &modulus - &x3) % &modulus) + &modulus - &y1) % &modulus;
+    write_point(p, &x3, &y3);
+}
+
+#[no_mangle]
+extern "C" fn syscall_secp256k1_fp_addmod(p: *mut u32, q: *const u32) {
+    let (p, q) = unsafe {
+        (
+            core::slice::from_raw_parts_mut(p, 8),
+            core::slice::from_raw_parts(q, 8),
+        )
+    };
+    let sum = (from_words(p) + from_words(q)) % secp256k1_p();
+    write_words(p, &sum);
+}
+
+#[no_mangle]
+extern "C" fn syscall_secp256k1_fp_submod(p: *mut u32, q: *const u32) {
+    let (p, q) = unsafe {
+        (
+            core::slice::from_raw_parts_mut(p, 8),
+            core::slice::from_raw_parts(q, 8),
+        )
+    };
+    let modulus = secp256k1_p();
+    let difference = (from_words(p) + &modulus - from_words(q) % &modulus) % &modulus;
+    write_words(p, &difference);
+}
+
+#[no_mangle]
+extern "C" fn syscall_secp256k1_fp_mulmod(p: *mut u32, q: *const u32) {
+    let (p, q) = unsafe {
+        (
+            core::slice::from_raw_parts_mut(p, 8),
+            core::slice::from_raw_parts(q, 8),
+        )
+    };
+    let product = (from_words(p) * from_words(q)) % secp256k1_p();
+    write_words(p, &product);
+}
+
+#[no_mangle]
+extern "C" fn syscall_sha256(input: *const u32, digest: *mut [u32; 8]) {
+    // The message follows its length in bytes, and the digest bytes are the words in order.
+    let len = unsafe { *input } as usize;
+    let message = unsafe { core::slice::from_raw_parts(input.add(1) as *const u8, len) };
+    let hash = Sha256::digest(message);
+    let digest = unsafe { &mut *digest };
+    for (word, bytes) in digest.iter_mut().zip(hash.chunks_exact(4)) {
+        *word = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+}
+
+#[no_mangle]
+extern "C" fn syscall_uint256_mulmod(x: *mut [u32; 8], y: *const [u32; 8]) {
+    // The modulus follows `y` in memory, and zero stands for 2^256.
+    let (x, y, modulus) = unsafe { (&mut *x, &*y, &*y.add(1)) };
+    let mut modulus = from_words(modulus);
+    if modulus.bits() == 0 {
+        modulus = BigUint::from(1u32) << 256;
+    }
+    let product = (from_words(x) * from_words(y)) % modulus;
+    write_words(x, &product);
+}
+
+#[no_mangle]
+extern "C" fn syscall_keccak_absorb(state: *mut [u64; 25], block: *const [u64; 17]) {
+    let (state, block) = unsafe { (&mut *state, &*block) };
+    for (lane, word) in state.iter_mut().zip(block) {
+        *lane ^= word;
+    }
+    tiny_keccak::keccakf(state);
+}
+
+#[no_mangle]
+extern "C" fn syscall_rlp_decode_item(input: *const u8, output: *mut [u32; 3]) {
+    let output = unsafe { &mut *output };
+    let prefix = unsafe { *input };
+    let long_len = |len_of_len: u8| {
+        assert!(len_of_len <= 4, "RLP length of {len_of_len} bytes");
+        let len_bytes = unsafe { core::slice::from_raw_parts(input.add(1), len_of_len as usize) };
+        len_bytes
+            .iter()
+            .fold(0u32, |len, &byte| (len << 8) | byte as u32)
+    };
+    *output = match prefix {
+        0x00..=0x7f => [0, 1, 0],
+        0x80..=0xb7 => [1, (prefix - 0x80) as u32, 0],
+        0xb8..=0xbf => [1 + (prefix - 0xb7) as u32, long_len(prefix - 0xb7), 0],
+        0xc0..=0xf7 => [1, (prefix - 0xc0) as u32, 1],
+        0xf8..=0xff => [1 + (prefix - 0xf7) as u32, long_len(prefix - 0xf7), 1],
+    };
+}
@@ -1,5 +1,9 @@
 #![allow(unused_unsafe)]
-use crate::{syscall_hint_len, syscall_hint_read, syscall_write};
+use crate::{
+    sys_skip_reclaimed_heap, syscall_args_len, syscall_args_read, syscall_cycle_count,
+    syscall_fuel_remaining, syscall_hint_len, syscall_hint_read, syscall_host_call,
+    syscall_host_call_read, syscall_write,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     alloc::Layout,
@@ -24,6 +28,20 @@ pub const FD_EDDECOMPRESS: u32 = 8;
 /// The file descriptor for brevis coprocessor outputs.
 pub const FD_COPROCESSOR_OUTPUTS: u32 = 9;
 
+/// The file descriptor for log lines, routed to the host's `tracing` output.
+pub const FD_LOG: u32 = 10;
+
+/// The level of a log line written to [`FD_LOG`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum LogLevel {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
 /// A writer that writes to a file descriptor inside the zkVM.
 pub struct SyscallWriter {
     pub fd: u32,
@@ -44,6 +62,15 @@ impl Write for SyscallWriter {
     }
 }
 
+/// Allocates memory the program has never accessed, which `HINT_READ` requires. Memory reclaimed
+/// by `arena_scope` has been accessed, so the allocation is placed past it.
+unsafe fn alloc_fresh(layout: Layout) -> *mut u8 {
+    unsafe {
+        sys_skip_reclaimed_heap();
+        std::alloc::alloc(layout)
+    }
+}
+
 /// Read a buffer from the input stream.
 ///
 /// ### Examples
@@ -57,7 +84,7 @@ pub fn read_vec() -> Vec<u8> {
 
     // Allocate a buffer of the required length that is 4 byte aligned
     let layout = Layout::from_size_align(capacity, 4).expect("vec is too large");
-    let ptr = unsafe { std::alloc::alloc(layout) };
+    let ptr = unsafe { alloc_fresh(layout) };
 
     // SAFETY:
     // 1. `ptr` was allocated using alloc
@@ -68,7 +95,7 @@ pub fn read_vec() -> Vec<u8> {
     let mut vec = unsafe { Vec::from_raw_parts(ptr, 0, capacity) };
 
     // Read the vec into uninitialized memory. The syscall assumes the memory is uninitialized,
-    // which should be true because the allocator does not dealloc, so a new alloc should be fresh.
+    // which `alloc_fresh` ensures even inside an arena scope.
     unsafe {
         syscall_hint_read(ptr, len);
         vec.set_len(len);
@@ -76,6 +103,156 @@ pub fn read_vec() -> Vec<u8> {
     vec
 }
 
+/// Returns the arguments the host set with `EmulatorStdinBuilder::set_args`, in the same order.
+///
+/// The arguments are separate from the input stream and can be read any number of times.
+pub fn args() -> Vec<String> {
+    let len = unsafe { syscall_args_len() };
+    if len == 0 {
+        return Vec::new();
+    }
+
+    // Read into fresh memory, as in `read_vec`.
+    let layout = Layout::from_size_align(len.next_multiple_of(4), 4).expect("args are too large");
+    let encoded = unsafe {
+        let ptr = alloc_fresh(layout);
+        syscall_args_read(ptr, len);
+        std::slice::from_raw_parts(ptr, len)
+    };
+
+    let mut args = Vec::new();
+    let mut rest = encoded;
+    while !rest.is_empty() {
+        let (arg_len, tail) = rest.split_at(4);
+        let arg_len = u32::from_le_bytes(arg_len.try_into().unwrap()) as usize;
+        let (arg, tail) = tail.split_at(arg_len);
+        args.push(String::from_utf8(arg.to_vec()).expect("argument is not valid utf-8"));
+        rest = tail;
+    }
+    args
+}
+
+/// Returns the fuel left of the budget set on the host with `EmulatorOpts::fuel`, in cycles, or
+/// `u64::MAX` without a budget. It reaches zero when the budget is spent, and the program keeps
+/// running.
+pub fn fuel_remaining() -> u64 {
+    read_u64(syscall_fuel_remaining)
+}
+
+/// Returns the number of cycles the program has run so far.
+pub fn cycle_count() -> u64 {
+    read_u64(syscall_cycle_count)
+}
+
+/// Reads a counter whose low and high words are returned by `syscall(0)` and `syscall(1)`.
+fn read_u64(syscall: unsafe extern "C" fn(u32) -> u32) -> u64 {
+    // The two words are read by separate syscalls, so retry if the high word changed in between.
+    loop {
+        let high = unsafe { syscall(1) };
+        let low = unsafe { syscall(0) };
+        if unsafe { syscall(1) } == high {
+            return ((high as u64) << 32) | low as u64;
+        }
+    }
+}
+
+/// Runs the handler the host registered for `tag` on `args` and returns its response.
+///
+/// The response is not constrained by the proof.
+pub fn host_call(tag: u32, args: &[u8]) -> Vec<u8> {
+    let request = [args.as_ptr() as u32, args.len() as u32];
+    let len = unsafe { syscall_host_call(tag, &request) };
+    if len == 0 {
+        return Vec::new();
+    }
+
+    // Read into fresh memory, as in `read_vec`.
+    let layout =
+        Layout::from_size_align(len.next_multiple_of(4), 4).expect("response is too large");
+    unsafe {
+        let ptr = alloc_fresh(layout);
+        syscall_host_call_read(ptr, len);
+        Vec::from_raw_parts(ptr, len, len.next_multiple_of(4))
+    }
+}
+
+/// Read a buffer from the input stream as a slice that lives for the rest of the program.
+///
+/// `HINT_READ` places the input in fresh memory as its initial value, so the slice refers to the
+/// input in place. Unlike [`read_vec`] there is no owning `Vec`, so the slice can be shared or
+/// deserialized into types that borrow from it without copying.
+///
+/// # Safety
+///
+/// The slice is only valid for the rest of the program outside `arena_scope`. Inside a scope it
+/// is reclaimed when the scope returns, and must not be used after that.
+///
+/// ### Examples
+/// ```ignore
+/// let block: &'static [u8] = unsafe { pico_sdk::io::read_borrowed() };
+/// ```
+pub unsafe fn read_borrowed() -> &'static [u8] {
+    let len = unsafe { syscall_hint_len() };
+    if len == 0 {
+        skip_empty_input();
+        return &[];
+    }
+
+    let capacity = (len + 3) / 4 * 4;
+    let layout = Layout::from_size_align(capacity, 4).expect("slice is too large");
+    let ptr = unsafe { alloc_fresh(layout) };
+
+    // SAFETY: the allocator never deallocates, so the memory stays valid and unaliased until it
+    // is reclaimed by an enclosing arena scope, which the caller guarantees outlives every use.
+    // It is fresh, as the syscall requires, and holds `len` initialized bytes once the syscall
+    // returns. Nothing else has a pointer to it.
+    unsafe {
+        syscall_hint_read(ptr, len);
+        std::slice::from_raw_parts(ptr, len)
+    }
+}
+
+/// Consumes the next input, which is empty, without allocating for it.
+fn skip_empty_input() {
+    // The syscall writes no words, but still checks that the address is word aligned.
+    unsafe { syscall_hint_read(std::ptr::NonNull::<u32>::dangling().as_ptr() as *mut u8, 0) };
+}
+
+/// Read a buffer of plain-old-data values from the input stream, as written on the host by
+/// `EmulatorStdinBuilder::write_pod_slice`. The whole buffer is read with one `HINT_READ`.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u64> = pico_sdk::io::read_slice();
+/// ```
+pub fn read_slice<T: bytemuck::Pod>() -> Vec<T> {
+    let size = std::mem::size_of::<T>();
+    assert!(size > 0, "cannot read a slice of zero-sized values");
+
+    let len = unsafe { syscall_hint_len() };
+    assert!(
+        len % size == 0,
+        "input of {len} bytes is not a whole number of {size}-byte values"
+    );
+    if len == 0 {
+        skip_empty_input();
+        return Vec::new();
+    }
+
+    // The syscall writes whole words, so leave room for the last partial word.
+    let capacity = ((len + 3) / 4 * 4).div_ceil(size);
+    let layout = Layout::from_size_align(capacity * size, std::mem::align_of::<T>().max(4))
+        .expect("vec is too large");
+    let ptr = unsafe { alloc_fresh(layout) };
+
+    // SAFETY: as in `read_vec`, the allocator never deallocates and the fresh memory is filled
+    // by the syscall before the length is set. Any bytes are a valid `T` since it is `Pod`.
+    unsafe {
+        syscall_hint_read(ptr, len);
+        Vec::from_raw_parts(ptr as *mut T, len / size, capacity)
+    }
+}
+
 /// Read a deserializable object from the input stream.
 ///
 /// ### Examples
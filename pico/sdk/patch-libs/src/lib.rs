@@ -1,12 +1,19 @@
 //! Wrapper syscall API for the Pico patches.
+pub mod aes;
+pub mod base64;
 pub mod bls12381;
 pub mod bn254;
 pub mod ed25519;
 pub mod io;
+pub mod mpt;
+pub mod rlp;
 pub mod secp256k1;
 pub mod unconstrained;
 pub mod utils;
 
+#[cfg(all(test, not(target_os = "zkvm")))]
+mod host_syscalls;
+
 /// Enum representing the different Precompile Poseidon2 permutation syscall types.
 #[repr(u32)]
 pub enum SyscallType {
@@ -22,6 +29,12 @@ extern "C" {
     /// Writes the bytes in the given buffer to the given file descriptor.
     pub fn syscall_write(fd: u32, write_buf: *const u8, nbytes: usize);
 
+    /// Commits a length-prefixed UTF-8 string to the public values stream.
+    pub fn syscall_commit_str(ptr: *const u8, len: usize);
+
+    /// Commits an array of words to the public values stream.
+    pub fn syscall_commit_array(ptr: *const u32, len: usize);
+
     /// Reads the bytes from the given file descriptor into the given buffer.
     pub fn syscall_read(fd: u32, read_buf: *mut u8, nbytes: usize);
 
@@ -61,6 +74,12 @@ extern "C" {
     /// Executes the Keccak-256 permutation on the given state.
     pub fn syscall_keccak_permute(state: *mut [u64; 25]);
 
+    /// XORs a 136-byte block into the rate of the Keccak-256 state and permutes the state.
+    pub fn syscall_keccak_absorb(state: *mut [u64; 25], block: *const [u64; 17]);
+
+    /// Copies the 136-byte rate of the Keccak-256 state to the output.
+    pub fn syscall_keccak_squeeze(state: *const [u64; 25], out: *mut [u64; 17]);
+
     /// Executes an uint256 multiplication on the given inputs.
     pub fn syscall_uint256_mulmod(x: *mut [u32; 8], y: *const [u32; 8]);
 
@@ -79,9 +98,34 @@ extern "C" {
     /// Reads the next element in the hint stream into the given buffer.
     pub fn syscall_hint_read(ptr: *mut u8, len: usize);
 
+    /// Writes the verifying key digest of the running program into the given buffer.
+    pub fn syscall_self_vk_digest(ptr: *mut u32);
+
+    /// Returns the length of the encoded program arguments.
+    pub fn syscall_args_len() -> usize;
+
+    /// Reads the encoded program arguments into the given buffer.
+    pub fn syscall_args_read(ptr: *mut u8, len: usize);
+
+    /// Runs a host call and returns the length of its response.
+    pub fn syscall_host_call(tag: u32, args: *const [u32; 2]) -> usize;
+
+    /// Reads the response of the last host call into the given buffer.
+    pub fn syscall_host_call_read(ptr: *mut u8, len: usize);
+
+    /// Returns the low (`word = 0`) or high (`word = 1`) word of the fuel left.
+    pub fn syscall_fuel_remaining(word: u32) -> u32;
+
+    /// Returns the low (`word = 0`) or high (`word = 1`) word of the cycles run so far.
+    pub fn syscall_cycle_count(word: u32) -> u32;
+
     /// Allocates a buffer aligned to the given alignment.
     pub fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u8;
 
+    /// Moves the heap past all memory reclaimed by `arena_scope`, so that the next allocation is
+    /// in memory the program has never accessed.
+    pub fn sys_skip_reclaimed_heap();
+
     /// Decompresses a BLS12-381 point.
     pub fn syscall_bls12381_decompress(point: &mut [u8; 96], is_odd: bool);
 
@@ -94,6 +138,17 @@ extern "C" {
         modulus: *const [u32; 8],
     );
 
+    /// Computes a square root modulo a prime and checks it, returning 1 if one exists.
+    pub fn sys_bigint_sqrt(a: *const u8, p: *const u8, len: usize, out: *mut u8) -> u8;
+
+    /// Divides two 256-bit integers and checks the quotient and remainder.
+    pub fn sys_bigint_divrem(
+        quotient: *mut [u32; 8],
+        remainder: *mut [u32; 8],
+        n: *const [u32; 8],
+        d: *const [u32; 8],
+    );
+
     /// Executes a BLS12-381 field addition on the given inputs.
     pub fn syscall_bls12381_fp_addmod(p: *mut u32, q: *const u32);
 
@@ -103,6 +158,12 @@ extern "C" {
     /// Executes a BLS12-381 field multiplication on the given inputs.
     pub fn syscall_bls12381_fp_mulmod(p: *mut u32, q: *const u32);
 
+    /// Executes a BLS12-381 field inversion on the given input.
+    pub fn syscall_bls12381_fp_inv(a: *const u32, result: *mut u32);
+
+    /// Executes a BLS12-381 field batch inversion on the given inputs.
+    pub fn syscall_bls12381_fp_batch_inv(a: *mut u32, len: u32);
+
     /// Executes a BLS12-381 Fp2 addition on the given inputs.
     pub fn syscall_bls12381_fp2_addmod(p: *mut u32, q: *const u32);
 
@@ -121,6 +182,12 @@ extern "C" {
     /// Executes a BN254 field multiplication on the given inputs.
     pub fn syscall_bn254_fp_mulmod(p: *mut u32, q: *const u32);
 
+    /// Executes a BN254 field inversion on the given input.
+    pub fn syscall_bn254_fp_inv(a: *const u32, result: *mut u32);
+
+    /// Executes a BN254 field batch inversion on the given inputs.
+    pub fn syscall_bn254_fp_batch_inv(a: *mut u32, len: u32);
+
     /// Executes a BN254 Fp2 addition on the given inputs.
     pub fn syscall_bn254_fp2_addmod(p: *mut u32, q: *const u32);
 
@@ -139,7 +206,63 @@ extern "C" {
     /// Executes a Secp256k1 field multiplication on the given inputs.
     pub fn syscall_secp256k1_fp_mulmod(p: *mut u32, q: *const u32);
 
+    /// Executes a Secp256k1 field inversion on the given input.
+    pub fn syscall_secp256k1_fp_inv(a: *const u32, result: *mut u32);
+
+    /// Executes a Secp256k1 field batch inversion on the given inputs.
+    pub fn syscall_secp256k1_fp_batch_inv(a: *mut u32, len: u32);
+
     /// Executes an poseidon2 permute on the given inputs.
     pub fn syscall_poseidon2_permute(x: *const [u32; 16], y: *mut [u32; 16]);
 
+    /// Compresses two Poseidon2 digests into their Merkle parent.
+    pub fn syscall_poseidon2_compress(x: *const [u32; 16], y: *mut [u32; 8]);
+
+    /// Evaluates the polynomial with the given coefficients at the given point.
+    pub fn syscall_poly_eval(
+        coeffs: *const u32,
+        degree: u32,
+        point: *const u32,
+        result: *mut core::mem::MaybeUninit<u32>,
+    );
+
+    /// Compares two word buffers for equality without early exit.
+    pub fn syscall_ct_eq(a: *const u32, b: *const u32, num_words: u32, result: *mut u32);
+
+    /// Computes the SHA-256 digest of a length-prefixed buffer.
+    pub fn syscall_sha256(input: *const u32, digest: *mut [u32; 8]);
+
+    /// Computes several independent digests of length-prefixed buffers.
+    pub fn syscall_multi_hash(hash_type: u32, descriptors: *const u32);
+
+    /// Sorts an array of words in place.
+    pub fn syscall_sort_u32(values: *mut u32, len: u32);
+
+    /// Sorts an array of canonical field elements in place.
+    pub fn syscall_field_sort(values: *mut u32, len: u32);
+
+    /// Continues a CRC-32 checksum over a buffer.
+    pub fn syscall_crc32(data: *const u32, len: u32, crc: *mut u32);
+
+    /// Checks that every one of `n` words fits in `bits` bits.
+    pub fn syscall_range_check_batch(values: *const u32, n: u32, bits: u32);
+
+    /// Encrypts a block in place with AES-128.
+    pub fn syscall_aes128_encrypt_block(block: *mut [u32; 4], key: *const [u32; 4]);
+
+    /// Expands an AES-256 key into its key schedule.
+    pub fn syscall_aes_key_schedule(key: *const [u32; 8], schedule: *mut [u32; 60]);
+
+    /// Encrypts a block in place with AES-256 under an expanded key schedule.
+    pub fn syscall_aes256_encrypt_block(block: *mut [u32; 4], schedule: *const [u32; 60]);
+
+    /// Decodes the header of the RLP item starting at `input`.
+    pub fn syscall_rlp_decode_item(input: *const u8, output: *mut [u32; 3]);
+
+    /// Decodes a length-prefixed base64 text.
+    pub fn syscall_base64_decode(input: *const u32, output: *mut u32);
+
+    /// Hints the emulator to reserve memory for the bytes in `[start, end)`.
+    pub fn syscall_memory_prefetch(start: *const u8, end: *const u8);
+
 }
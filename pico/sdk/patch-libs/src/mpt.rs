@@ -0,0 +1,466 @@
+use crate::{rlp::rlp_decode_item, syscall_keccak_absorb, syscall_rlp_decode_item};
+
+/// The rate of Keccak-256 in bytes.
+const KECCAK256_RATE: usize = 136;
+
+/// Hashes `bytes` with Keccak-256, absorbing one block per `KECCAK_ABSORB` precompile call.
+pub fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut state = [0u64; 25];
+    let mut block = [0u64; 17];
+
+    let mut chunks = bytes.chunks_exact(KECCAK256_RATE);
+    for chunk in &mut chunks {
+        load_block(&mut block, chunk);
+        unsafe {
+            syscall_keccak_absorb(&mut state, &block);
+        }
+    }
+
+    // The last block carries the Keccak padding, which takes a whole block when the input fills
+    // the previous one.
+    let rest = chunks.remainder();
+    let mut last = [0u8; KECCAK256_RATE];
+    last[..rest.len()].copy_from_slice(rest);
+    last[rest.len()] ^= 0x01;
+    last[KECCAK256_RATE - 1] ^= 0x80;
+    load_block(&mut block, &last);
+    unsafe {
+        syscall_keccak_absorb(&mut state, &block);
+    }
+
+    // The precompile writes the state back to memory, so the digest is read from it directly.
+    let mut digest = [0u8; 32];
+    for (bytes, lane) in digest.chunks_exact_mut(8).zip(state) {
+        bytes.copy_from_slice(&lane.to_le_bytes());
+    }
+    digest
+}
+
+fn load_block(block: &mut [u64; 17], bytes: &[u8]) {
+    for (lane, bytes) in block.iter_mut().zip(bytes.chunks_exact(8)) {
+        *lane = u64::from_le_bytes(bytes.try_into().unwrap());
+    }
+}
+
+/// How a Merkle Patricia Trie node is referenced from its parent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MptNodeRef<'a> {
+    /// Nodes shorter than 32 bytes are embedded in their parent as they are.
+    Inline(&'a [u8]),
+    /// Other nodes are referenced by their Keccak-256 hash.
+    Hash([u8; 32]),
+}
+
+/// Hashes the RLP-encoded trie node `node` with Keccak-256.
+///
+/// `node` must be exactly one RLP list, which is checked with the `RLP_DECODE_ITEM` precompile.
+/// The root of a trie is always referenced by this hash, whatever its length.
+pub fn mpt_node_hash(node: &[u8]) -> [u8; 32] {
+    check_node(node);
+    keccak256(node)
+}
+
+/// The reference to the RLP-encoded trie node `node` from its parent: the node itself if it is
+/// shorter than 32 bytes, so that no hash is computed, and its hash otherwise.
+pub fn mpt_node_ref(node: &[u8]) -> MptNodeRef<'_> {
+    if node.len() < 32 {
+        check_node(node);
+        MptNodeRef::Inline(node)
+    } else {
+        MptNodeRef::Hash(mpt_node_hash(node))
+    }
+}
+
+fn check_node(node: &[u8]) {
+    let header = rlp_decode_item(node);
+    assert!(header.is_list, "a trie node must be an RLP list");
+    assert_eq!(
+        header.header_len + header.payload_len,
+        node.len(),
+        "a trie node must be exactly one RLP item"
+    );
+}
+
+/// Why [`verify_proof`] rejected a proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MptProofError {
+    /// A node does not match the hash or the inline encoding its parent references it by.
+    NodeMismatch,
+    /// A node is not a well-formed branch, extension or leaf node.
+    MalformedNode,
+    /// The proof ends before the key is resolved.
+    MissingNode,
+    /// The proof has nodes left after the key is resolved.
+    UnusedNodes,
+}
+
+/// Verifies a Merkle Patricia Trie proof for `key` against the trie root `root`.
+///
+/// `proof` holds the RLP-encoded nodes on the path from the root, in order, as in the
+/// `accountProof` and `storageProof` of `eth_getProof`; nodes embedded in their parent are not
+/// part of it. `key` is the path in the trie, e.g. the Keccak-256 of an address for the state
+/// trie. Returns the value stored at `key`, or `None` if the proof shows there is none.
+pub fn verify_proof<'a>(
+    root: &[u8; 32],
+    key: &[u8],
+    proof: &[&'a [u8]],
+) -> Result<Option<&'a [u8]>, MptProofError> {
+    let path = key
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0xf])
+        .collect::<Vec<_>>();
+    let mut path = path.as_slice();
+    let mut num_used = 0;
+    let mut next = MptNodeRef::Hash(*root);
+
+    // Checks that the proof ends with the node that resolved the key.
+    let resolved = |value, num_used: usize| match num_used < proof.len() {
+        true => Err(MptProofError::UnusedNodes),
+        false => Ok(value),
+    };
+
+    loop {
+        let node = match next {
+            MptNodeRef::Inline(node) => node,
+            MptNodeRef::Hash(hash) => {
+                let node = *proof.get(num_used).ok_or(MptProofError::MissingNode)?;
+                num_used += 1;
+                if keccak256(node) != hash {
+                    return Err(MptProofError::NodeMismatch);
+                }
+                node
+            }
+        };
+
+        let (list, rest) = split_item(node)?;
+        if !list.is_list || !rest.is_empty() {
+            return Err(MptProofError::MalformedNode);
+        }
+        let mut items = Vec::with_capacity(17);
+        let mut payload = list.payload;
+        while !payload.is_empty() {
+            let (item, rest) = split_item(payload)?;
+            items.push(item);
+            payload = rest;
+        }
+
+        let child = match items.len() {
+            17 => match path.split_first() {
+                None => {
+                    let value = items[16];
+                    if value.is_list {
+                        return Err(MptProofError::MalformedNode);
+                    }
+                    return resolved(
+                        (!value.payload.is_empty()).then_some(value.payload),
+                        num_used,
+                    );
+                }
+                Some((&nibble, rest)) => {
+                    path = rest;
+                    items[nibble as usize]
+                }
+            },
+            2 => {
+                let (is_leaf, node_path) = decode_hex_prefix(&items[0])?;
+                if is_leaf {
+                    if items[1].is_list {
+                        return Err(MptProofError::MalformedNode);
+                    }
+                    return resolved(
+                        (path == node_path.as_slice()).then_some(items[1].payload),
+                        num_used,
+                    );
+                }
+                match path.strip_prefix(node_path.as_slice()) {
+                    Some(rest) => {
+                        path = rest;
+                        items[1]
+                    }
+                    None => return resolved(None, num_used),
+                }
+            }
+            _ => return Err(MptProofError::MalformedNode),
+        };
+
+        next = if child.is_list {
+            if child.encoded.len() >= 32 {
+                return Err(MptProofError::MalformedNode);
+            }
+            MptNodeRef::Inline(child.encoded)
+        } else {
+            match child.payload.len() {
+                0 => return resolved(None, num_used),
+                32 => MptNodeRef::Hash(child.payload.try_into().unwrap()),
+                _ => return Err(MptProofError::MalformedNode),
+            }
+        };
+    }
+}
+
+/// An RLP item inside a trie node.
+#[derive(Clone, Copy)]
+struct RlpItem<'a> {
+    is_list: bool,
+    payload: &'a [u8],
+    /// The whole item, header included.
+    encoded: &'a [u8],
+}
+
+/// Splits the RLP item at the start of `bytes` from the bytes after it. Unlike
+/// [`rlp_decode_item`], an item that runs past the end of `bytes` is an error rather than a
+/// panic, as proofs come from the host.
+fn split_item(bytes: &[u8]) -> Result<(RlpItem<'_>, &[u8]), MptProofError> {
+    if bytes.is_empty() {
+        return Err(MptProofError::MalformedNode);
+    }
+    let mut output = [0u32; 3];
+    unsafe {
+        syscall_rlp_decode_item(bytes.as_ptr(), &mut output);
+    }
+    let header_len = output[0] as usize;
+    let len = header_len
+        .checked_add(output[1] as usize)
+        .filter(|&len| len <= bytes.len())
+        .ok_or(MptProofError::MalformedNode)?;
+    let item = RlpItem {
+        is_list: output[2] != 0,
+        payload: &bytes[header_len..len],
+        encoded: &bytes[..len],
+    };
+    Ok((item, &bytes[len..]))
+}
+
+/// Decodes the hex-prefix encoded path of a leaf or extension node into whether the node is a
+/// leaf and the path nibbles.
+fn decode_hex_prefix(item: &RlpItem) -> Result<(bool, Vec<u8>), MptProofError> {
+    let Some((&first, rest)) = item.payload.split_first().filter(|_| !item.is_list) else {
+        return Err(MptProofError::MalformedNode);
+    };
+    let flag = first >> 4;
+    if flag > 3 || (flag & 1 == 0 && first & 0xf != 0) {
+        return Err(MptProofError::MalformedNode);
+    }
+    let mut nibbles = Vec::with_capacity(2 * rest.len() + 1);
+    if flag & 1 == 1 {
+        nibbles.push(first & 0xf);
+    }
+    nibbles.extend(rest.iter().flat_map(|byte| [byte >> 4, byte & 0xf]));
+    Ok((flag & 2 == 2, nibbles))
+}
+
+#[cfg(all(test, not(target_os = "zkvm")))]
+mod tests {
+    use super::*;
+    use tiny_keccak::{Hasher, Keccak};
+
+    fn reference_keccak256(bytes: &[u8]) -> [u8; 32] {
+        let mut hasher = Keccak::v256();
+        hasher.update(bytes);
+        let mut digest = [0u8; 32];
+        hasher.finalize(&mut digest);
+        digest
+    }
+
+    fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+        match bytes.len() {
+            1 if bytes[0] < 0x80 => bytes.to_vec(),
+            len if len < 56 => [&[0x80 + len as u8], bytes].concat(),
+            len => [&[0xb8, len as u8], bytes].concat(),
+        }
+    }
+
+    fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+        let payload = items.concat();
+        match payload.len() {
+            len if len < 56 => [vec![0xc0 + len as u8], payload].concat(),
+            len => [vec![0xf8, len as u8], payload].concat(),
+        }
+    }
+
+    fn hex_prefix(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+        let flag = 2 * is_leaf as u8 + nibbles.len() as u8 % 2;
+        let (first, rest) = match nibbles.len() % 2 {
+            1 => ((flag << 4) | nibbles[0], &nibbles[1..]),
+            _ => (flag << 4, nibbles),
+        };
+        let mut path = vec![first];
+        path.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+        encode_bytes(&path)
+    }
+
+    fn node_ref(node: &[u8]) -> Vec<u8> {
+        match node.len() {
+            len if len < 32 => node.to_vec(),
+            _ => encode_bytes(&reference_keccak256(node)),
+        }
+    }
+
+    fn leaf(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+        encode_list(&[hex_prefix(nibbles, true), encode_bytes(value)])
+    }
+
+    fn extension(nibbles: &[u8], child: &[u8]) -> Vec<u8> {
+        encode_list(&[hex_prefix(nibbles, false), node_ref(child)])
+    }
+
+    fn branch(children: &[(usize, &[u8])], value: &[u8]) -> Vec<u8> {
+        let mut items = vec![vec![0x80]; 16];
+        for &(nibble, child) in children {
+            items[nibble] = node_ref(child);
+        }
+        items.push(encode_bytes(value));
+        encode_list(&items)
+    }
+
+    /// A trie holding
+    ///
+    /// - `12 34 56`: 40 bytes of `0x11`, in a hashed leaf,
+    /// - `12 34 78`: `short`, in a leaf embedded in its branch,
+    /// - `12 9a bc`: 40 bytes of `0x33`,
+    /// - `12`: `branch`, in the value slot of a branch,
+    ///
+    /// so that its proofs go through every kind of node.
+    struct Trie {
+        root: Vec<u8>,
+        branch1: Vec<u8>,
+        extension4: Vec<u8>,
+        branch2: Vec<u8>,
+        leaf1: Vec<u8>,
+        leaf3: Vec<u8>,
+    }
+
+    impl Trie {
+        fn new() -> Self {
+            let leaf1 = leaf(&[0x6], &[0x11; 40]);
+            let leaf2 = leaf(&[0x8], b"short");
+            let leaf3 = leaf(&[0xa, 0xb, 0xc], &[0x33; 40]);
+            let branch2 = branch(&[(0x5, &leaf1), (0x7, &leaf2)], &[]);
+            let extension4 = extension(&[0x4], &branch2);
+            let branch1 = branch(&[(0x3, &extension4), (0x9, &leaf3)], b"branch");
+            let root = extension(&[0x1, 0x2], &branch1);
+            assert!(leaf2.len() < 32 && leaf1.len() >= 32 && extension4.len() >= 32);
+            Self {
+                root,
+                branch1,
+                extension4,
+                branch2,
+                leaf1,
+                leaf3,
+            }
+        }
+
+        fn root_hash(&self) -> [u8; 32] {
+            reference_keccak256(&self.root)
+        }
+
+        fn proof(&self, len: usize) -> Vec<&[u8]> {
+            let path = [
+                &self.root,
+                &self.branch1,
+                &self.extension4,
+                &self.branch2,
+                &self.leaf1,
+            ];
+            path[..len].iter().map(|node| node.as_slice()).collect()
+        }
+    }
+
+    #[test]
+    fn test_keccak256() {
+        for len in [0, 1, 55, 135, 136, 137, 272, 300] {
+            let bytes = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+            assert_eq!(keccak256(&bytes), reference_keccak256(&bytes), "len {len}");
+        }
+        // The root of the empty trie.
+        assert_eq!(
+            hex::encode(keccak256(&[0x80])),
+            "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+        );
+    }
+
+    #[test]
+    fn test_verify_inclusion() {
+        let trie = Trie::new();
+        let root = trie.root_hash();
+        let check = |key: &[u8], proof: &[&[u8]], value: &[u8]| {
+            assert_eq!(verify_proof(&root, key, proof), Ok(Some(value)), "{key:x?}");
+        };
+        check(&[0x12, 0x34, 0x56], &trie.proof(5), &[0x11; 40]);
+        check(&[0x12, 0x34, 0x78], &trie.proof(4), b"short");
+        check(
+            &[0x12, 0x9a, 0xbc],
+            &[&trie.root, &trie.branch1, &trie.leaf3],
+            &[0x33; 40],
+        );
+        check(&[0x12], &trie.proof(2), b"branch");
+    }
+
+    #[test]
+    fn test_verify_exclusion() {
+        let trie = Trie::new();
+        let root = trie.root_hash();
+        let cases: [(&[u8], usize); 5] = [
+            // The path diverges from the root extension.
+            (&[0x13, 0x34, 0x56], 1),
+            // The path diverges from the extension under the first branch.
+            (&[0x12, 0x35, 0x56], 3),
+            // The path reaches an empty slot of the second branch.
+            (&[0x12, 0x34, 0xa0], 4),
+            // The path ends at the second branch, which holds no value.
+            (&[0x12, 0x34], 4),
+            // The path diverges from a leaf.
+            (&[0x12, 0x34, 0x59], 5),
+        ];
+        for (key, len) in cases {
+            assert_eq!(
+                verify_proof(&root, key, &trie.proof(len)),
+                Ok(None),
+                "{key:x?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_malformed_proof() {
+        let trie = Trie::new();
+        let root = trie.root_hash();
+        let key = [0x12, 0x34, 0x56];
+
+        assert_eq!(
+            verify_proof(&root, &key, &trie.proof(4)),
+            Err(MptProofError::MissingNode)
+        );
+        let mut proof = trie.proof(5);
+        proof.push(&trie.leaf3);
+        assert_eq!(
+            verify_proof(&root, &key, &proof),
+            Err(MptProofError::UnusedNodes)
+        );
+
+        let mut tampered = trie.branch2.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        let mut proof = trie.proof(5);
+        proof[3] = &tampered;
+        assert_eq!(
+            verify_proof(&root, &key, &proof),
+            Err(MptProofError::NodeMismatch)
+        );
+        assert_eq!(
+            verify_proof(&[0; 32], &key, &trie.proof(5)),
+            Err(MptProofError::NodeMismatch)
+        );
+
+        let not_a_list = encode_bytes(b"not a trie node");
+        let truncated = vec![0xc5, 0x01];
+        let three_items = encode_list(&[vec![0x01], vec![0x02], vec![0x03]]);
+        let bad_hex_prefix = encode_list(&[encode_bytes(&[0x41]), encode_bytes(b"value")]);
+        for node in [not_a_list, truncated, three_items, bad_hex_prefix] {
+            assert_eq!(
+                verify_proof(&reference_keccak256(&node), &key, &[node.as_slice()]),
+                Err(MptProofError::MalformedNode),
+                "{node:x?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::syscall_rlp_decode_item;
+
+/// The header of an RLP item, as decoded by the `RLP_DECODE_ITEM` precompile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RlpHeader {
+    /// The number of bytes taken by the prefix and any length bytes.
+    pub header_len: usize,
+    /// The number of bytes of the payload that follows the header.
+    pub payload_len: usize,
+    /// Whether the item is a list rather than a string.
+    pub is_list: bool,
+}
+
+/// Decodes the header of the RLP item at the start of `bytes`.
+///
+/// The header is not checked for canonical encoding, and the payload is not checked to fit in
+/// `bytes`; callers slicing the payload should bounds-check it themselves.
+pub fn rlp_decode_item(bytes: &[u8]) -> RlpHeader {
+    assert!(!bytes.is_empty(), "cannot decode an empty RLP item");
+    let mut output = [0u32; 3];
+    unsafe {
+        syscall_rlp_decode_item(bytes.as_ptr(), &mut output);
+    }
+    let header = RlpHeader {
+        header_len: output[0] as usize,
+        payload_len: output[1] as usize,
+        is_list: output[2] != 0,
+    };
+    assert!(
+        header.header_len <= bytes.len(),
+        "RLP header runs past the end of the input"
+    );
+    header
+}
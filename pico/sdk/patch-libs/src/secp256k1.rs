@@ -3,6 +3,9 @@ use crate::{
     utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
 };
 
+pub mod ecdsa;
+pub mod hash_to_curve;
+
 /// The number of limbs in [Secp256k1Point].
 pub const N: usize = 16;
 
@@ -68,3 +71,84 @@ impl AffinePoint<N> for Secp256k1Point {
         }
     }
 }
+
+#[cfg(all(test, not(target_os = "zkvm")))]
+mod tests {
+    use super::*;
+    use crate::utils::MulAssignError;
+
+    /// The group order `n` as little endian words.
+    const ORDER: [u32; 8] = [
+        0xd0364141, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff,
+        0xffffffff,
+    ];
+    /// The base field prime `p` as little endian words.
+    const PRIME: [u32; 8] = [
+        0xfffffc2f, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+        0xffffffff,
+    ];
+
+    fn generator() -> Secp256k1Point {
+        Secp256k1Point::new(Secp256k1Point::GENERATOR)
+    }
+
+    fn double_and_add(scalar: &[u32; 8]) -> [u32; N] {
+        let mut point = generator();
+        point.mul_assign(scalar).unwrap();
+        *point.limbs_ref()
+    }
+
+    fn windowed(scalar: &[u32; 8]) -> [u32; N] {
+        let mut point = generator();
+        point.windowed_mul_assign(scalar).unwrap();
+        *point.limbs_ref()
+    }
+
+    #[test]
+    fn test_windowed_mul_matches_double_and_add() {
+        // A fixed xorshift stream, so that failures reproduce.
+        let mut state = 0x9e3779b97f4a7c15u64;
+        let mut next_word = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u32
+        };
+
+        let mut scalars = vec![[1, 0, 0, 0, 0, 0, 0, 0], [15, 0, 0, 0, 0, 0, 0, 0]];
+        scalars.extend((0..8).map(|_| {
+            let mut scalar = [0u32; 8];
+            scalar.iter_mut().for_each(|word| *word = next_word());
+            // Keep the scalar below n.
+            scalar[7] &= 0x7fffffff;
+            scalar
+        }));
+        for scalar in scalars {
+            assert_eq!(windowed(&scalar), double_and_add(&scalar), "{scalar:x?}");
+        }
+    }
+
+    #[test]
+    fn test_windowed_mul_edge_scalars() {
+        assert!(matches!(
+            generator().windowed_mul_assign(&[0; 8]),
+            Err(MulAssignError::ScalarIsZero)
+        ));
+
+        // (n - 1) * G = -G, which has the x of G and the y of G negated.
+        let mut n_minus_one = ORDER;
+        n_minus_one[0] -= 1;
+        let result = windowed(&n_minus_one);
+        assert_eq!(result, double_and_add(&n_minus_one));
+
+        let mut negated = Secp256k1Point::GENERATOR;
+        let mut borrow = false;
+        for (out, &p) in negated[8..].iter_mut().zip(&PRIME) {
+            let (diff, b1) = p.overflowing_sub(*out);
+            let (diff, b2) = diff.overflowing_sub(borrow as u32);
+            *out = diff;
+            borrow = b1 || b2;
+        }
+        assert_eq!(result, negated);
+    }
+}
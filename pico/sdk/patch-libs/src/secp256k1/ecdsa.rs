@@ -0,0 +1,282 @@
+//! ECDSA signature verification over secp256k1.
+//!
+//! The double scalar multiplication `u1 * G + u2 * Q` runs on the curve add and double
+//! precompiles, arithmetic modulo the group order on `UINT256_MUL`, and the on-curve check of the
+//! public key on the secp256k1 `Fp` precompiles.
+
+use super::Secp256k1Point;
+use crate::{
+    syscall_secp256k1_fp_addmod, syscall_secp256k1_fp_mulmod, syscall_uint256_mulmod,
+    utils::{AffinePoint, WeierstrassAffinePoint},
+};
+
+/// A 256-bit integer as little endian words.
+type U256 = [u32; 8];
+
+/// The base field prime `p`.
+const P: U256 = [
+    0xfffffc2f, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+/// The group order `n`.
+const N: U256 = [
+    0xd0364141, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+/// `n / 2`, rounded down. Signatures with a larger `s` are rejected.
+const N_HALF: U256 = [
+    0x681b20a0, 0xdfe92f46, 0x57a4501d, 0x5d576e73, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff,
+];
+/// `n - 2`, the exponent of inversion modulo `n`.
+const N_MINUS_2: U256 = [
+    0xd036413f, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+/// The `b` coefficient of `y^2 = x^3 + b`.
+const B: U256 = [7, 0, 0, 0, 0, 0, 0, 0];
+
+/// Verifies an ECDSA signature over secp256k1.
+///
+/// `msg_hash` is the 32-byte message digest, `signature` is `r || s` and `public_key` is the
+/// uncompressed point `x || y` without the `0x04` prefix, all big endian. Returns `false` for
+/// out-of-range `r` or `s` and for a public key that is not on the curve, instead of panicking.
+/// As in `k256`, only low-`s` signatures are accepted: `(r, n - s)` is just as valid as `(r, s)`,
+/// and accepting both would make signatures malleable.
+pub fn verify(msg_hash: &[u8; 32], signature: &[u8; 64], public_key: &[u8; 64]) -> bool {
+    let r = from_be_bytes(&signature[..32]);
+    let s = from_be_bytes(&signature[32..]);
+    if is_zero(&r) || !lt(&r, &N) || is_zero(&s) || lt(&N_HALF, &s) {
+        return false;
+    }
+
+    let x = from_be_bytes(&public_key[..32]);
+    let y = from_be_bytes(&public_key[32..]);
+    if !lt(&x, &P) || !lt(&y, &P) || fp_mul(&y, &y) != fp_add(&fp_mul(&fp_mul(&x, &x), &x), &B) {
+        return false;
+    }
+    let mut limbs = [0u32; 16];
+    limbs[..8].copy_from_slice(&x);
+    limbs[8..].copy_from_slice(&y);
+    let q = Secp256k1Point::new(limbs);
+
+    // The digest is below 2^256 < 2n, so one subtraction reduces it.
+    let mut z = from_be_bytes(msg_hash);
+    if !lt(&z, &N) {
+        z = sub(&z, &N);
+    }
+
+    let w = pow_mod_n(&s, &N_MINUS_2);
+    let u1 = mul_mod_n(&z, &w);
+    let u2 = mul_mod_n(&r, &w);
+
+    let Some(point) = Secp256k1Point::multi_scalar_multiplication(
+        &bits_le(&u1),
+        Secp256k1Point::new(Secp256k1Point::GENERATOR),
+        &bits_le(&u2),
+        q,
+    ) else {
+        return false;
+    };
+    if point.is_infinity() {
+        return false;
+    }
+
+    // Likewise `x < p < 2n`.
+    let mut x: U256 = point.limbs_ref()[..8].try_into().unwrap();
+    if !lt(&x, &N) {
+        x = sub(&x, &N);
+    }
+    x == r
+}
+
+fn from_be_bytes(bytes: &[u8]) -> U256 {
+    let mut words = [0u32; 8];
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        words[i / 4] |= (*byte as u32) << (8 * (i % 4));
+    }
+    words
+}
+
+fn bits_le(a: &U256) -> [bool; 256] {
+    core::array::from_fn(|i| (a[i / 32] >> (i % 32)) & 1 == 1)
+}
+
+fn is_zero(a: &U256) -> bool {
+    a.iter().all(|&word| word == 0)
+}
+
+fn lt(a: &U256, b: &U256) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+fn sub(a: &U256, b: &U256) -> U256 {
+    let mut result = [0u32; 8];
+    let mut borrow = false;
+    for ((out, &a), &b) in result.iter_mut().zip(a).zip(b) {
+        let (diff, b1) = a.overflowing_sub(b);
+        let (diff, b2) = diff.overflowing_sub(borrow as u32);
+        *out = diff;
+        borrow = b1 || b2;
+    }
+    result
+}
+
+fn mul_mod_n(a: &U256, b: &U256) -> U256 {
+    // The precompile takes the modulus right after the second operand.
+    let mut y_and_modulus = [0u32; 16];
+    y_and_modulus[..8].copy_from_slice(b);
+    y_and_modulus[8..].copy_from_slice(&N);
+
+    let mut result = *a;
+    unsafe {
+        syscall_uint256_mulmod(&mut result, y_and_modulus.as_ptr() as *const U256);
+    }
+    result
+}
+
+fn pow_mod_n(a: &U256, exp: &U256) -> U256 {
+    let mut result = [1, 0, 0, 0, 0, 0, 0, 0];
+    for i in (0..256).rev() {
+        result = mul_mod_n(&result, &result);
+        if (exp[i / 32] >> (i % 32)) & 1 == 1 {
+            result = mul_mod_n(&result, a);
+        }
+    }
+    result
+}
+
+fn fp_add(a: &U256, b: &U256) -> U256 {
+    let mut result = *a;
+    unsafe {
+        syscall_secp256k1_fp_addmod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+fn fp_mul(a: &U256, b: &U256) -> U256 {
+    let mut result = *a;
+    unsafe {
+        syscall_secp256k1_fp_mulmod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+#[cfg(all(test, not(target_os = "zkvm")))]
+mod tests {
+    use super::*;
+
+    /// A signature made with the `cryptography` Python package over the SHA-256 of
+    /// "pico ecdsa known answer", normalized to low `s`.
+    const MSG_HASH: &str = "0f920f2f37af3ccd35f51e6ab08d1cff6c59505e8212f9e617cbd08935be1cee";
+    const SIGNATURE: &str = concat!(
+        "9cc3ff7215e5f95ac00e84668fa1c6aff281216df687be736c4361c3945f31e9",
+        "0570249c3bfd72df1981d9f88a1daad6a250f450e4004311bec08ed1d6b4ee54",
+    );
+    const PUBLIC_KEY: &str = concat!(
+        "53189c62fd824b4214dde2e669f557ea288da48bd3efba8ffb68f013566f0985",
+        "c8b320e4232db79e5f659ad291110d4eeec25a9beb6f0352cb42175a68571034",
+    );
+    /// A signature by the same key over an all-ones digest, which is above `n`.
+    const MAX_HASH_SIGNATURE: &str = concat!(
+        "4205f3d95e95e51892c02c8394f6759bee637e2fd0eb886cc4fc9423b9612758",
+        "2b8823d48c4e6f7d289fdc6a8b3dda149eb6891b488eec8ea6c40e395c07bcb0",
+    );
+
+    fn decode<const LEN: usize>(hex_str: &str) -> [u8; LEN] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    fn to_be_bytes(words: &U256) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words.iter().rev()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        bytes
+    }
+
+    fn with_s(signature: &[u8; 64], s: &U256) -> [u8; 64] {
+        let mut signature = *signature;
+        signature[32..].copy_from_slice(&to_be_bytes(s));
+        signature
+    }
+
+    fn with_r(signature: &[u8; 64], r: &U256) -> [u8; 64] {
+        let mut signature = *signature;
+        signature[..32].copy_from_slice(&to_be_bytes(r));
+        signature
+    }
+
+    #[test]
+    fn test_verify_known_answer() {
+        let msg_hash = decode::<32>(MSG_HASH);
+        let public_key = decode::<64>(PUBLIC_KEY);
+        assert!(verify(&msg_hash, &decode(SIGNATURE), &public_key));
+        assert!(verify(
+            &[0xff; 32],
+            &decode(MAX_HASH_SIGNATURE),
+            &public_key
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_bad_signature() {
+        let msg_hash = decode::<32>(MSG_HASH);
+        let signature = decode::<64>(SIGNATURE);
+        let public_key = decode::<64>(PUBLIC_KEY);
+
+        let mut other_hash = msg_hash;
+        other_hash[31] ^= 1;
+        assert!(!verify(&other_hash, &signature, &public_key));
+
+        let mut other_signature = signature;
+        other_signature[0] ^= 1;
+        assert!(!verify(&msg_hash, &other_signature, &public_key));
+
+        // A valid signature for another message.
+        assert!(!verify(&msg_hash, &decode(MAX_HASH_SIGNATURE), &public_key));
+
+        // The negated key, which is on the curve.
+        let mut negated_key = public_key;
+        let y = from_be_bytes(&public_key[32..]);
+        negated_key[32..].copy_from_slice(&to_be_bytes(&sub(&P, &y)));
+        assert!(!verify(&msg_hash, &signature, &negated_key));
+
+        // A key off the curve.
+        let mut off_curve_key = public_key;
+        off_curve_key[63] ^= 1;
+        assert!(!verify(&msg_hash, &signature, &off_curve_key));
+    }
+
+    #[test]
+    fn test_verify_rejects_high_s() {
+        let msg_hash = decode::<32>(MSG_HASH);
+        let signature = decode::<64>(SIGNATURE);
+        let public_key = decode::<64>(PUBLIC_KEY);
+
+        // (r, n - s) verifies under the textbook equation, but is the high-s twin.
+        let s = from_be_bytes(&signature[32..]);
+        assert!(!verify(
+            &msg_hash,
+            &with_s(&signature, &sub(&N, &s)),
+            &public_key
+        ));
+
+        // n / 2 is still low, one more is not.
+        let mut above_half = N_HALF;
+        above_half[0] += 1;
+        assert!(!verify(
+            &msg_hash,
+            &with_s(&signature, &above_half),
+            &public_key
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_out_of_range() {
+        let msg_hash = decode::<32>(MSG_HASH);
+        let signature = decode::<64>(SIGNATURE);
+        let public_key = decode::<64>(PUBLIC_KEY);
+
+        for bound in [[0; 8], N, [u32::MAX; 8]] {
+            assert!(!verify(&msg_hash, &with_r(&signature, &bound), &public_key));
+            assert!(!verify(&msg_hash, &with_s(&signature, &bound), &public_key));
+        }
+    }
+}
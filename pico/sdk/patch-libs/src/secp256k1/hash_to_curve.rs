@@ -0,0 +1,359 @@
+//! Hashing to secp256k1 with the `secp256k1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380.
+//!
+//! The message is expanded with `expand_message_xmd` on top of the `SHA256` precompile, and the
+//! two resulting field elements are mapped with the simplified SWU map to the 3-isogenous curve
+//! `E'`, then to secp256k1 through the isogeny. All field arithmetic goes through the secp256k1
+//! `Fp` precompiles, and the final addition through the curve precompiles, so every step is
+//! proven by existing chips.
+//!
+//! There is no dedicated `SECP256K1_HASH_TO_CURVE` syscall: its chip would repeat the SHA-256 and
+//! field constraints in one AIR, and an ecall cannot pass the message, the tag and the output in
+//! its two argument registers. A patched `k256` forwards its `hash2curve` implementation of this
+//! suite to [`hash_to_curve`].
+
+use super::Secp256k1Point;
+use crate::{
+    syscall_secp256k1_fp_addmod, syscall_secp256k1_fp_mulmod, syscall_secp256k1_fp_submod,
+    syscall_sha256,
+    utils::{AffinePoint, WeierstrassAffinePoint},
+};
+
+/// A field element as little endian words, reduced modulo the secp256k1 base field prime.
+type Fe = [u32; 8];
+
+const ZERO: Fe = [0; 8];
+const ONE: Fe = [1, 0, 0, 0, 0, 0, 0, 0];
+
+/// `p - 2`, the exponent of inversion.
+const P_MINUS_2: Fe = [
+    0xfffffc2d, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+/// `(p + 1) / 4`, the exponent of the square root since `p = 3 mod 4`.
+const SQRT_EXP: Fe = [
+    0xbfffff0c, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x3fffffff,
+];
+/// `2^256 mod p`.
+const R: Fe = [0x000003d1, 0x00000001, 0, 0, 0, 0, 0, 0];
+
+/// The `A'` coefficient of the isogenous curve `E'`.
+const A: Fe = [
+    0x1a444533, 0x405447c0, 0xcb6f0e5d, 0xe953d363, 0xf0f5d272, 0xa08a5558, 0xdd661adc, 0x3f8731ab,
+];
+/// The `B'` coefficient of the isogenous curve `E'`.
+const B: Fe = [1771, 0, 0, 0, 0, 0, 0, 0];
+/// The non-square `Z = -11` of the SWU map.
+const Z: Fe = [
+    0xfffffc24, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+/// `-B' / A'`.
+const MINUS_B_OVER_A: Fe = [
+    0x8d2345c3, 0xaa01d58e, 0xe82d74fd, 0x6df4ff98, 0xd3e87b0c, 0xa00c4df5, 0x718538b2, 0x0bc56cee,
+];
+/// `B' / (Z * A')`.
+const B_OVER_ZA: Fe = [
+    0xde490391, 0x6c8bcd97, 0x7232ad8b, 0x7e5c173c, 0x59152275, 0x6ba40716, 0x38dd90ca, 0xbb407e44,
+];
+
+/// The coefficients of the 3-isogeny map, from RFC 9380 appendix E.1, lowest degree first.
+const X_NUM: [Fe; 4] = [
+    [
+        0xaaaaa8c7, 0x8e38e38d, 0xe38e38e3, 0x38e38e38, 0x8e38e38e, 0xe38e38e3, 0x38e38e38,
+        0x8e38e38e,
+    ],
+    [
+        0xf17c6581, 0xdfff1044, 0x0bf63b92, 0xd595d2fc, 0xa7fd44c5, 0xb9f315ce, 0x0bc321d5,
+        0x07d3d4c8,
+    ],
+    [
+        0x3d9dd262, 0x4ecbd0b5, 0x037c4031, 0xe4506144, 0xca25caec, 0xe2a413de, 0x23f234e6,
+        0x534c328d,
+    ],
+    [
+        0xaaaaa88c, 0x8e38e38d, 0xe38e38e3, 0x38e38e38, 0x8e38e38e, 0xe38e38e3, 0x38e38e38,
+        0x8e38e38e,
+    ],
+];
+const X_DEN: [Fe; 3] = [
+    [
+        0x781eb49b, 0x9fe6b745, 0x42f8487d, 0x86cd4095, 0xb7b640dd, 0x9ca34ccb, 0x3d94918a,
+        0xd3577119,
+    ],
+    [
+        0x2a8c6d14, 0xc52a5661, 0x1f5e41bb, 0x06d36b64, 0x1b542254, 0xf7c4b2d5, 0x4383dc1d,
+        0xedadc6f6,
+    ],
+    ONE,
+];
+const Y_NUM: [Fe; 4] = [
+    [
+        0x8e38e23c, 0xa12f684b, 0x12f684bd, 0x2f684bda, 0xf684bda1, 0x684bda12, 0x84bda12f,
+        0x4bda12f6,
+    ],
+    [
+        0x201d71a3, 0xdffc90fc, 0xd686da6f, 0x647ab046, 0x12a0a6d5, 0xa9d0a54b, 0xd5cb7c0f,
+        0xc75e0c32,
+    ],
+    [
+        0x9ecee931, 0xa765e85a, 0x01be2018, 0x722830a2, 0x6512e576, 0x715209ef, 0x91f91a73,
+        0x29a61946,
+    ],
+    [
+        0x38e38d84, 0x84bda12f, 0x4bda12f6, 0xbda12f68, 0xda12f684, 0xa12f684b, 0x12f684bd,
+        0x2f684bda,
+    ],
+];
+const Y_DEN: [Fe; 4] = [
+    [
+        0xfffff93b, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+        0xffffffff,
+    ],
+    [
+        0x685c2573, 0xdfb425d2, 0xc8e8d978, 0x9467c1bf, 0x2722c298, 0xd5e9e663, 0xb8bdb49f,
+        0x7a06534b,
+    ],
+    [
+        0xbfd2a76f, 0xa7bf8192, 0x2f0d6299, 0x0a3d2116, 0xa8fe337e, 0xf3a70c3f, 0x6545ca2c,
+        0x6484aa71,
+    ],
+    ONE,
+];
+
+/// The number of bytes hashed into each field element, `ceil((ceil(log2(p)) + k) / 8)` for
+/// `k = 128`.
+const L: usize = 48;
+
+/// Hashes `msg` to a secp256k1 point under the domain separation tag `dst`, following the
+/// `secp256k1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380.
+///
+/// The result is the point at infinity only with negligible probability. Tags longer than 255
+/// bytes must be reduced by the caller as described in section 5.3.3 of the RFC.
+pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Secp256k1Point {
+    let uniform_bytes = expand_message_xmd(msg, dst);
+    let u0 = fe_from_be_bytes(&uniform_bytes[..L]);
+    let u1 = fe_from_be_bytes(&uniform_bytes[L..]);
+
+    let mut point = map_to_curve(&u0);
+    // The cofactor of secp256k1 is 1, so the sum needs no clearing.
+    point.complete_add_assign(&map_to_curve(&u1));
+    point
+}
+
+/// `expand_message_xmd` with SHA-256, producing the `2 * L` bytes of two field elements.
+fn expand_message_xmd(msg: &[u8], dst: &[u8]) -> [u8; 2 * L] {
+    assert!(dst.len() <= 255, "domain separation tag is too long");
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    // b_0 = H(Z_pad || msg || I2OSP(len_in_bytes, 2) || I2OSP(0, 1) || DST_prime)
+    let b_0 = sha256(
+        &[
+            &[0u8; 64][..],
+            msg,
+            &(2 * L as u16).to_be_bytes(),
+            &[0],
+            &dst_prime,
+        ]
+        .concat(),
+    );
+
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime), with b_1 = H(b_0 || 1 || ...).
+    let mut uniform_bytes = [0u8; 2 * L];
+    let mut b_i = [0u8; 32];
+    for (i, chunk) in uniform_bytes.chunks_mut(32).enumerate() {
+        let mut input = b_0;
+        for (byte, prev) in input.iter_mut().zip(b_i) {
+            *byte ^= prev;
+        }
+        b_i = sha256(&[&input[..], &[i as u8 + 1], &dst_prime].concat());
+        chunk.copy_from_slice(&b_i);
+    }
+    uniform_bytes
+}
+
+/// The simplified SWU map to `E'` of RFC 9380 section 6.6.2, followed by the 3-isogeny to
+/// secp256k1.
+fn map_to_curve(u: &Fe) -> Secp256k1Point {
+    let z_u2 = fe_mul(&Z, &fe_mul(u, u));
+    let tv1 = fe_inv(&fe_mul(&z_u2, &fe_add(&z_u2, &ONE)));
+    let x1 = if tv1 == ZERO {
+        B_OVER_ZA
+    } else {
+        fe_mul(&MINUS_B_OVER_A, &fe_add(&ONE, &tv1))
+    };
+
+    let (x, mut y) = match fe_sqrt(&curve_rhs(&x1)) {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = fe_mul(&z_u2, &x1);
+            // `g(x2) = Z^3 * u^6 * g(x1)` is a square whenever `g(x1)` is not.
+            let y2 = fe_sqrt(&curve_rhs(&x2)).expect("g(x2) is a square");
+            (x2, y2)
+        }
+    };
+    if sgn0(u) != sgn0(&y) {
+        y = fe_sub(&ZERO, &y);
+    }
+
+    iso_map(&x, &y)
+}
+
+/// Maps a point of `E'` to secp256k1.
+fn iso_map(x: &Fe, y: &Fe) -> Secp256k1Point {
+    let x_den = fe_eval(&X_DEN, x);
+    let y_den = fe_eval(&Y_DEN, x);
+    if x_den == ZERO || y_den == ZERO {
+        return Secp256k1Point::infinity();
+    }
+
+    let x_out = fe_mul(&fe_eval(&X_NUM, x), &fe_inv(&x_den));
+    let y_out = fe_mul(y, &fe_mul(&fe_eval(&Y_NUM, x), &fe_inv(&y_den)));
+
+    let mut limbs = [0u32; 16];
+    limbs[..8].copy_from_slice(&x_out);
+    limbs[8..].copy_from_slice(&y_out);
+    Secp256k1Point::new(limbs)
+}
+
+/// `x^3 + A' * x + B'`.
+fn curve_rhs(x: &Fe) -> Fe {
+    fe_add(&fe_mul(&fe_add(&fe_mul(x, x), &A), x), &B)
+}
+
+/// Evaluates the polynomial with the given coefficients, lowest degree first, at `x`.
+fn fe_eval(coeffs: &[Fe], x: &Fe) -> Fe {
+    coeffs
+        .iter()
+        .rev()
+        .fold(ZERO, |acc, coeff| fe_add(&fe_mul(&acc, x), coeff))
+}
+
+/// The sign of a field element: the parity of its canonical representative.
+fn sgn0(a: &Fe) -> u32 {
+    a[0] & 1
+}
+
+/// Reduces big endian bytes of any length up to 64 modulo `p`.
+fn fe_from_be_bytes(bytes: &[u8]) -> Fe {
+    debug_assert!(bytes.len() <= 64);
+    let mut words = [0u32; 16];
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        words[i / 4] |= (*byte as u32) << (8 * (i % 4));
+    }
+    let lo: Fe = words[..8].try_into().unwrap();
+    let hi: Fe = words[8..].try_into().unwrap();
+    // hi * 2^256 + lo. The precompiles reduce their inputs, so `lo` may exceed `p`.
+    fe_add(&fe_mul(&hi, &R), &lo)
+}
+
+fn fe_add(a: &Fe, b: &Fe) -> Fe {
+    let mut result = *a;
+    unsafe {
+        syscall_secp256k1_fp_addmod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+fn fe_sub(a: &Fe, b: &Fe) -> Fe {
+    let mut result = *a;
+    unsafe {
+        syscall_secp256k1_fp_submod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+fn fe_mul(a: &Fe, b: &Fe) -> Fe {
+    let mut result = *a;
+    unsafe {
+        syscall_secp256k1_fp_mulmod(result.as_mut_ptr(), b.as_ptr());
+    }
+    result
+}
+
+fn fe_pow(a: &Fe, exp: &Fe) -> Fe {
+    let mut result = ONE;
+    for i in (0..256).rev() {
+        result = fe_mul(&result, &result);
+        if (exp[i / 32] >> (i % 32)) & 1 == 1 {
+            result = fe_mul(&result, a);
+        }
+    }
+    result
+}
+
+/// The inverse of `a`, or zero if `a` is zero.
+fn fe_inv(a: &Fe) -> Fe {
+    fe_pow(a, &P_MINUS_2)
+}
+
+fn fe_sqrt(a: &Fe) -> Option<Fe> {
+    let root = fe_pow(a, &SQRT_EXP);
+    (fe_mul(&root, &root) == *a).then_some(root)
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    // The precompile takes the length word followed by the message, word aligned.
+    let mut input = vec![0u32; 1 + data.len().div_ceil(4)];
+    input[0] = data.len() as u32;
+    for (i, byte) in data.iter().enumerate() {
+        input[1 + i / 4] |= (*byte as u32) << (8 * (i % 4));
+    }
+
+    let mut digest = [0u32; 8];
+    unsafe {
+        syscall_sha256(input.as_ptr(), &mut digest);
+    }
+
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(digest) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+#[cfg(all(test, not(target_os = "zkvm")))]
+mod tests {
+    use super::*;
+
+    /// The domain separation tag of the RFC 9380 test vectors in appendix J.8.1.
+    const DST: &[u8] = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+
+    /// The affine coordinates of `point` as big endian hex.
+    fn to_hex(point: &Secp256k1Point) -> (String, String) {
+        let coordinate = |words: &[u32]| {
+            hex::encode(
+                words
+                    .iter()
+                    .rev()
+                    .flat_map(|word| word.to_be_bytes())
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let limbs = point.limbs_ref();
+        (coordinate(&limbs[..8]), coordinate(&limbs[8..]))
+    }
+
+    #[test]
+    fn test_hash_to_curve_vectors() {
+        let vectors: [(&[u8], &str, &str); 2] = [
+            (
+                b"",
+                "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+            ),
+            (
+                b"abc",
+                "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+            ),
+        ];
+        for (msg, x, y) in vectors {
+            let point = hash_to_curve(msg, DST);
+            assert_eq!(to_hex(&point), (x.to_string(), y.to_string()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "domain separation tag is too long")]
+    fn test_hash_to_curve_rejects_long_tag() {
+        hash_to_curve(b"abc", &[0; 256]);
+    }
+}
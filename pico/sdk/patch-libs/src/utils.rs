@@ -85,6 +85,56 @@ pub trait AffinePoint<const N: usize>: Clone + Sized {
         Ok(())
     }
 
+    /// Multiplies `self` by the given scalar, four bits at a time.
+    ///
+    /// The multiples `P, 2P, ..., 15P` are computed up front, after which each nibble of the
+    /// scalar, from the most significant one, costs four doublings and at most one addition. For a
+    /// 256-bit scalar this takes at most 78 additions against the 256 of [`Self::mul_assign`], at
+    /// the price of keeping the table in memory. Additions go through
+    /// [`Self::complete_add_assign`], as the accumulator may meet a multiple from the table.
+    fn windowed_mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        debug_assert!(scalar.len() == N / 2);
+
+        let scalar_is_zero = scalar.iter().all(|&words| words == 0);
+        if scalar_is_zero {
+            return Err(MulAssignError::ScalarIsZero);
+        }
+
+        // table[i] = (i + 1) * self.
+        let mut table = Vec::with_capacity(15);
+        table.push(self.clone());
+        let mut multiple = self.clone();
+        multiple.double();
+        table.push(multiple.clone());
+        for _ in 2..15 {
+            multiple.complete_add_assign(self);
+            table.push(multiple.clone());
+        }
+
+        let mut res: Option<Self> = None;
+        for &words in scalar.iter().rev() {
+            for shift in (0..32).step_by(4).rev() {
+                if let Some(res) = res.as_mut() {
+                    for _ in 0..4 {
+                        res.double();
+                    }
+                }
+
+                let nibble = ((words >> shift) & 0xf) as usize;
+                if nibble != 0 {
+                    let term = &table[nibble - 1];
+                    match res.as_mut() {
+                        Some(res) => res.complete_add_assign(term),
+                        None => res = Some(term.clone()),
+                    };
+                }
+            }
+        }
+
+        *self = res.unwrap();
+        Ok(())
+    }
+
     /// Performs multi-scalar multiplication (MSM) on slices of bit vectors and points. Note:
     /// a_bits_le and b_bits_le should be in little endian order.
     fn multi_scalar_multiplication(
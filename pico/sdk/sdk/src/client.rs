@@ -1,36 +1,45 @@
 use crate::command::execute_command;
+use alloy_sol_types::SolType;
 use anyhow::{Error, Ok, Result};
 use log::{debug, info};
 use p3_baby_bear::BabyBear;
 use p3_koala_bear::KoalaBear;
 use pico_vm::{
-    compiler::riscv::program::Program,
+    compiler::riscv::{
+        compiler::{Compiler, SourceType},
+        program::Program,
+    },
     configs::{
         config::StarkGenericConfig,
         field_config::{BabyBearBn254, KoalaBearBn254},
         stark_config::{BabyBearPoseidon2, KoalaBearPoseidon2},
     },
-    emulator::stdin::{EmulatorStdin, EmulatorStdinBuilder},
+    emulator::{
+        opts::EmulatorOpts,
+        riscv::{
+            record::EmulationRecord,
+            riscv_emulator::RiscvEmulator,
+            syscalls::{HostCallHandler, HostCallHandlers},
+        },
+        stdin::{EmulatorStdin, EmulatorStdinBuilder},
+    },
     instances::{
         chiptype::recursion_chiptype::RecursionChipType,
         compiler::{
-            onchain_circuit::{
-                gnark::builder::OnchainVerifierCircuit,
-                stdin::OnchainStdin,
-                utils::{build_gnark_config, generate_contract_inputs, save_embed_proof_data},
-            },
+            onchain_circuit::utils::{generate_contract_inputs, save_embed_proof_data},
             shapes::{recursion_shape::RecursionShapeConfig, riscv_shape::RiscvShapeConfig},
             vk_merkle::vk_verification_enabled,
         },
         configs::{embed_config::BabyBearBn254Poseidon2, embed_kb_config::KoalaBearBn254Poseidon2},
     },
-    machine::{machine::MachineBehavior, proof::MetaProof},
+    machine::{progress::ProveProgress, proof::MetaProof},
     proverchain::{
         CombineProver, CompressProver, ConvertProver, EmbedProver, InitialProverSetup,
         MachineProver, ProverChain, RiscvProver,
     },
 };
-use std::{cell::RefCell, path::PathBuf, process::Command, rc::Rc};
+use serde::de::DeserializeOwned;
+use std::{cell::RefCell, path::PathBuf, process::Command, rc::Rc, sync::Arc};
 
 #[macro_export]
 macro_rules! create_sdk_prove_client {
@@ -103,60 +112,160 @@ macro_rules! create_sdk_prove_client {
                 Rc::clone(&self.stdin_builder)
             }
 
+            /// answers the guest's `pico_sdk::io::host_call` requests with `tag` using `handler`.
+            /// the handler must be deterministic, as the program may be emulated more than once.
+            pub fn register_host_call(
+                &mut self,
+                tag: u32,
+                handler: impl HostCallHandler + 'static,
+            ) {
+                self.riscv.register_host_call(tag, handler);
+            }
+
             /// prove and serialize embed proof, which provided to next step gnark verifier.
             /// the constraints.json and groth16_witness.json will be generated in output dir.
             pub fn prove(
                 &self,
                 output: PathBuf,
             ) -> Result<(MetaProof<$sc>, MetaProof<$bn254_sc>), Error> {
+                self.prove_with_progress(output, |_| {})
+            }
+
+            /// same as `prove`, reporting riscv chunks and recursion stages to `cb` as they start
+            pub fn prove_with_progress(
+                &self,
+                output: PathBuf,
+                cb: impl FnMut(ProveProgress),
+            ) -> Result<(MetaProof<$sc>, MetaProof<$bn254_sc>), Error> {
+                let (riscv_proof, proof, _) = self.prove_cycles(output, cb)?;
+                Ok((riscv_proof, proof))
+            }
+
+            /// same as `prove_with_progress`, also returning the number of cycles emulated
+            pub fn prove_cycles(
+                &self,
+                output: PathBuf,
+                mut cb: impl FnMut(ProveProgress),
+            ) -> Result<(MetaProof<$sc>, MetaProof<$bn254_sc>, u64), Error> {
                 let stdin = self.stdin_builder.borrow().clone().finalize();
-                let riscv_proof = self.riscv.prove(stdin);
+                let (riscv_proof, cycles) = self.riscv.prove_with_progress(stdin, &mut cb);
                 let riscv_vk = self.riscv.vk();
                 if !self.riscv.verify(&riscv_proof.clone(), riscv_vk) {
                     return Err(Error::msg("verify riscv proof failed"));
                 }
+                cb(ProveProgress::Folding { stage: "convert" });
                 let proof = self.convert.prove(riscv_proof.clone());
                 if !self.convert.verify(&proof, riscv_vk) {
                     return Err(Error::msg("verify convert proof failed"));
                 }
+                cb(ProveProgress::Folding { stage: "combine" });
                 let proof = self.combine.prove(proof);
                 if !self.combine.verify(&proof, riscv_vk) {
                     return Err(Error::msg("verify combine proof failed"));
                 }
+                cb(ProveProgress::Folding { stage: "compress" });
                 let proof = self.compress.prove(proof);
                 if !self.compress.verify(&proof, riscv_vk) {
                     return Err(Error::msg("verify compress proof failed"));
                 }
+                cb(ProveProgress::Folding { stage: "embed" });
                 let proof = self.embed.prove(proof);
                 if !self.embed.verify(&proof, riscv_vk) {
                     return Err(Error::msg("verify embed proof failed"));
                 }
 
-                let onchain_stdin = OnchainStdin {
-                    machine: self.embed.machine.base_machine().clone(),
-                    vk: proof.vks().first().unwrap().clone(),
-                    proof: proof.proofs().first().unwrap().clone(),
-                    flag_complete: true,
-                };
-                let (constraints, witness) =
-                    OnchainVerifierCircuit::<$fc, $bn254_sc>::build(&onchain_stdin);
+                let gnark_inputs = self.embed.export_gnark(&proof);
                 save_embed_proof_data(&riscv_proof, &proof, output.clone())?;
-                build_gnark_config(constraints, witness, output.clone());
-                Ok((riscv_proof, proof))
+                gnark_inputs.save(&output)?;
+                Ok((riscv_proof, proof, cycles))
+            }
+
+            /// emulate the riscv program without proving and return the number of chunks it splits into
+            pub fn estimate_chunks(&self) -> usize {
+                let stdin = self.stdin_builder.borrow().clone().finalize();
+                self.riscv.estimate_chunk_count(&stdin)
             }
 
             /// prove and verify riscv program. default not include convert, combine, compress, embed
             pub fn prove_fast(&self) -> Result<MetaProof<$sc>, Error> {
+                self.prove_fast_with_progress(|_| {})
+            }
+
+            /// same as `prove_fast`, reporting each emulated and proven chunk to `cb`
+            pub fn prove_fast_with_progress(
+                &self,
+                cb: impl FnMut(ProveProgress),
+            ) -> Result<MetaProof<$sc>, Error> {
+                self.prove_fast_cycles(cb).map(|(proof, _)| proof)
+            }
+
+            /// same as `prove_fast_with_progress`, also returning the number of cycles emulated
+            pub fn prove_fast_cycles(
+                &self,
+                mut cb: impl FnMut(ProveProgress),
+            ) -> Result<(MetaProof<$sc>, u64), Error> {
                 let stdin = self.stdin_builder.borrow().clone().finalize();
                 info!("stdin length: {}", stdin.inputs.len());
-                let proof = self.riscv.prove(stdin);
+                let (proof, cycles) = self.riscv.prove_with_progress(stdin, &mut cb);
                 let riscv_vk = self.riscv.vk();
                 info!("riscv_prover prove success");
                 if !self.riscv.verify(&proof, riscv_vk) {
                     return Err(Error::msg("riscv_prover verify failed"));
                 }
                 info!("riscv_prover proof verify success");
-                Ok(proof)
+                Ok((proof, cycles))
+            }
+
+            /// same as `prove_fast`, also returning the emulation record of every chunk, in chunk
+            /// order, so that the execution can be analyzed without emulating it again.
+            /// `prove_fast` only holds the records of the chunks being proven, while this keeps
+            /// every record until the end, so memory grows with the number of chunks
+            pub fn prove_fast_with_records(
+                &self,
+            ) -> Result<(MetaProof<$sc>, Vec<EmulationRecord>), Error> {
+                let stdin = self.stdin_builder.borrow().clone().finalize();
+                info!("stdin length: {}", stdin.inputs.len());
+                let (proof, _, records) = self.riscv.prove_with_records(stdin, &mut |_| {});
+                let riscv_vk = self.riscv.vk();
+                info!("riscv_prover prove success");
+                if !self.riscv.verify(&proof, riscv_vk) {
+                    return Err(Error::msg("riscv_prover verify failed"));
+                }
+                info!("riscv_prover proof verify success");
+                Ok((proof, records))
+            }
+
+            /// check the constraints and lookups of every riscv chunk instead of proving, failing
+            /// with the first failing chip and row. the details are logged
+            pub fn prove_fast_debug(&self) -> Result<(), Error> {
+                let stdin = self.stdin_builder.borrow().clone().finalize();
+                self.riscv.debug(stdin)
+            }
+
+            /// same as `prove_fast`, also decoding the value committed with `pico_sdk::io::commit`
+            /// from the public values stream
+            pub fn prove_with_public_values<O: DeserializeOwned>(
+                &self,
+            ) -> Result<(MetaProof<$sc>, O), Error> {
+                let proof = self.prove_fast()?;
+                let pv_stream = proof.pv_stream.as_deref().unwrap_or_default();
+                let public_values = bincode::deserialize(pv_stream).map_err(|e| {
+                    Error::msg(format!("public value decode failed: {}", e))
+                })?;
+                Ok((proof, public_values))
+            }
+
+            /// same as `prove_fast`, also ABI-decoding the public values stream as `S`, as
+            /// committed with `pico_sdk::io::commit_abi!`
+            pub fn prove_with_abi<S: SolType>(
+                &self,
+            ) -> Result<(MetaProof<$sc>, S::RustType), Error> {
+                let proof = self.prove_fast()?;
+                let pv_stream = proof.pv_stream.as_deref().unwrap_or_default();
+                let public_values = S::abi_decode(pv_stream, true).map_err(|e| {
+                    Error::msg(format!("public value decode failed: {}", e))
+                })?;
+                Ok((proof, public_values))
             }
 
             /// prove and generate gnark proof and contract inputs. must install docker first
@@ -212,3 +321,110 @@ create_sdk_prove_client!(
 );
 
 pub use KoalaBearProverClient as DefaultProverClient;
+
+/// The result of a [`MockProverClient`] run: the public values stream of the program, without a
+/// proof of it.
+#[derive(Clone, Debug)]
+pub struct MockProof {
+    pub pv_stream: Vec<u8>,
+    pub cycles: u64,
+}
+
+/// A stand-in for [`DefaultProverClient`] when unit testing host code. It only emulates the
+/// program, so the public values it returns are the same as in a real proof but nothing is
+/// proven, and `verify` accepts every proof.
+///
+/// No keys are set up, which is most of the time `DefaultProverClient::new` takes. As a
+/// consequence the guest cannot read its `self_vk_digest`.
+pub struct MockProverClient {
+    program: Arc<Program>,
+    host_call_handlers: HostCallHandlers,
+    stdin_builder: Rc<RefCell<EmulatorStdinBuilder<Vec<u8>>>>,
+}
+
+impl MockProverClient {
+    pub fn new(elf: &[u8]) -> Self {
+        let program = Compiler::new(SourceType::RISCV, elf).compile();
+        let stdin_builder = Rc::new(RefCell::new(
+            EmulatorStdin::<Program, Vec<u8>>::new_builder(),
+        ));
+        Self {
+            program,
+            host_call_handlers: Default::default(),
+            stdin_builder,
+        }
+    }
+
+    pub fn get_stdin_builder(&self) -> Rc<RefCell<EmulatorStdinBuilder<Vec<u8>>>> {
+        Rc::clone(&self.stdin_builder)
+    }
+
+    /// answers the guest's `pico_sdk::io::host_call` requests with `tag` using `handler`
+    pub fn register_host_call(&mut self, tag: u32, handler: impl HostCallHandler + 'static) {
+        self.host_call_handlers.insert(tag, Arc::new(handler));
+    }
+
+    /// emulate the riscv program in place of `DefaultProverClient::prove_fast`
+    pub fn prove_fast(&self) -> Result<MockProof, Error> {
+        let stdin = self.stdin_builder.borrow().clone().finalize();
+        let mut emulator =
+            RiscvEmulator::new::<KoalaBear>(self.program.clone(), EmulatorOpts::default());
+        emulator.host_call_handlers = self.host_call_handlers.clone();
+        emulator.run_fast(Some(stdin))?;
+        Ok(MockProof {
+            pv_stream: emulator.state.public_values_stream,
+            cycles: emulator.state.global_clk,
+        })
+    }
+
+    /// same as `prove_fast`, also decoding the value committed with `pico_sdk::io::commit`
+    pub fn prove_with_public_values<O: DeserializeOwned>(&self) -> Result<(MockProof, O), Error> {
+        let proof = self.prove_fast()?;
+        let public_values = bincode::deserialize(&proof.pv_stream)
+            .map_err(|e| Error::msg(format!("public value decode failed: {}", e)))?;
+        Ok((proof, public_values))
+    }
+
+    /// same as `prove_fast`, also ABI-decoding the public values stream as `S`
+    pub fn prove_with_abi<S: SolType>(&self) -> Result<(MockProof, S::RustType), Error> {
+        let proof = self.prove_fast()?;
+        let public_values = S::abi_decode(&proof.pv_stream, true)
+            .map_err(|e| Error::msg(format!("public value decode failed: {}", e)))?;
+        Ok((proof, public_values))
+    }
+
+    /// always succeeds, as there is nothing to verify
+    pub fn verify(&self, _proof: &MockProof) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MockProverClient;
+    use p3_koala_bear::KoalaBear;
+    use pico_vm::{
+        compiler::riscv::compiler::{Compiler, SourceType},
+        emulator::{opts::EmulatorOpts, riscv::riscv_emulator::RiscvEmulator},
+    };
+
+    const FIBONACCI_ELF: &[u8] =
+        include_bytes!("../../../vm/src/compiler/test_elf/riscv32im-pico-fibonacci-elf");
+
+    #[test]
+    fn test_mock_prover_client() {
+        let client = MockProverClient::new(FIBONACCI_ELF);
+        client.get_stdin_builder().borrow_mut().write(&10u32);
+        let proof = client.prove_fast().unwrap();
+        assert!(client.verify(&proof));
+
+        // The mock reports what emulating the program does.
+        let program = Compiler::new(SourceType::RISCV, FIBONACCI_ELF).compile();
+        let mut emulator = RiscvEmulator::new::<KoalaBear>(program, EmulatorOpts::default());
+        let stdin = client.get_stdin_builder().borrow().clone().finalize();
+        emulator.run_fast(Some(stdin)).unwrap();
+        assert!(!proof.pv_stream.is_empty());
+        assert_eq!(proof.pv_stream, emulator.state.public_values_stream);
+        assert_eq!(proof.cycles, emulator.state.global_clk);
+    }
+}
@@ -0,0 +1,134 @@
+//! Hash collections with a fixed, portable hasher.
+//!
+//! The `RandomState` behind `std::collections::HashMap` draws its keys from `getrandom`, which the
+//! zkVM only stubs out. Iteration order then depends on that stub and on the std hash algorithm,
+//! neither of which is guaranteed to stay the same. [`PicoHashMap`] and [`PicoHashSet`] hash with
+//! SipHash-2-4 under [`PICO_HASH_SEED`] instead, so a guest iterates them in the same order on
+//! every run and with every toolchain.
+//!
+//! The seed is public, so these collections offer no protection against adversarial keys.
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasher, Hasher},
+};
+
+/// The SipHash key of [`PicoHasher`]: the ASCII bytes of "pico-zkvm-hasher" as two little endian
+/// words. Changing it changes the iteration order of every [`PicoHashMap`].
+pub const PICO_HASH_SEED: [u64; 2] = [0x766b7a2d6f636970, 0x7265687361682d6d];
+
+/// A [`HashMap`] hashed with [`PicoHasher`].
+pub type PicoHashMap<K, V> = HashMap<K, V, PicoBuildHasher>;
+
+/// A [`HashSet`] hashed with [`PicoHasher`].
+pub type PicoHashSet<T> = HashSet<T, PicoBuildHasher>;
+
+/// Builds a [`PicoHasher`] keyed with [`PICO_HASH_SEED`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PicoBuildHasher;
+
+impl BuildHasher for PicoBuildHasher {
+    type Hasher = PicoHasher;
+
+    fn build_hasher(&self) -> PicoHasher {
+        PicoHasher::new_with_keys(PICO_HASH_SEED[0], PICO_HASH_SEED[1])
+    }
+}
+
+/// SipHash-2-4, written out here so that the output does not depend on the std implementation.
+#[derive(Clone, Debug)]
+pub struct PicoHasher {
+    v: [u64; 4],
+    tail: u64,
+    ntail: usize,
+    length: usize,
+}
+
+impl PicoHasher {
+    /// Creates a hasher with the given SipHash keys.
+    pub fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            v: [
+                k0 ^ 0x736f6d6570736575,
+                k1 ^ 0x646f72616e646f6d,
+                k0 ^ 0x6c7967656e657261,
+                k1 ^ 0x7465646279746573,
+            ],
+            tail: 0,
+            ntail: 0,
+            length: 0,
+        }
+    }
+
+    fn round(&mut self) {
+        let [v0, v1, v2, v3] = &mut self.v;
+        *v0 = v0.wrapping_add(*v1);
+        *v1 = v1.rotate_left(13) ^ *v0;
+        *v0 = v0.rotate_left(32);
+        *v2 = v2.wrapping_add(*v3);
+        *v3 = v3.rotate_left(16) ^ *v2;
+        *v0 = v0.wrapping_add(*v3);
+        *v3 = v3.rotate_left(21) ^ *v0;
+        *v2 = v2.wrapping_add(*v1);
+        *v1 = v1.rotate_left(17) ^ *v2;
+        *v2 = v2.rotate_left(32);
+    }
+
+    fn compress(&mut self, word: u64) {
+        self.v[3] ^= word;
+        self.round();
+        self.round();
+        self.v[0] ^= word;
+    }
+}
+
+impl Default for PicoHasher {
+    fn default() -> Self {
+        PicoBuildHasher.build_hasher()
+    }
+}
+
+impl Hasher for PicoHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.length += bytes.len();
+        for &byte in bytes {
+            self.tail |= (byte as u64) << (8 * self.ntail);
+            self.ntail += 1;
+            if self.ntail == 8 {
+                self.compress(self.tail);
+                self.tail = 0;
+                self.ntail = 0;
+            }
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.clone();
+        state.compress(((self.length as u64 & 0xff) << 56) | self.tail);
+        state.v[2] ^= 0xff;
+        for _ in 0..4 {
+            state.round();
+        }
+        let [v0, v1, v2, v3] = state.v;
+        v0 ^ v1 ^ v2 ^ v3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pico_hasher_reference_vectors() {
+        // From the SipHash paper, with the key 00 01 .. 0f and the message 00 01 .. (len - 1).
+        let hash = |len: u8, chunk: usize| {
+            let mut hasher = PicoHasher::new_with_keys(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+            let message = (0..len).collect::<Vec<_>>();
+            message.chunks(chunk).for_each(|bytes| hasher.write(bytes));
+            hasher.finish()
+        };
+        assert_eq!(hash(0, 1), 0x726fdb47dd0e0e31);
+        assert_eq!(hash(15, 4), 0xa129ca6149be45e5);
+        assert_eq!(hash(63, 7), 0x958a324ceb064572);
+    }
+}
@@ -0,0 +1,73 @@
+//! Public value encodings compatible with other zkVMs, for programs migrating to Pico that need
+//! their existing verifiers to keep accepting the same public values.
+//!
+//! # SP1
+//!
+//! The layout matched is the one of SP1's `sp1_zkvm::io::commit` and `commit_slice`, and of the
+//! `SP1Verifier` contracts:
+//!
+//! - The public values are the raw bytes written to the public values stream, in order. Values
+//!   committed with [`commit`] are bincode-encoded with its default options, exactly like SP1.
+//! - The public values digest is the SHA-256 of those bytes, see [`public_values_digest`].
+//! - The on-chain Groth16 and PLONK verifiers bind the digest with its 3 most significant bits
+//!   cleared, so that it fits in a BN254 scalar, see [`public_values_digest_bn254`].
+//!
+//! Pico binds its own public values the same way: every write to the stream also feeds the
+//! SHA-256 `PUBLIC_VALUES_HASHER`, and the embed circuit clears the same 3 bits. These helpers pin
+//! the layout down, so that a program committing through this module produces the
+//! `publicValues` bytes and digest an SP1 verifier expects. The proof format is unchanged.
+//!
+//! Risc0 journals are not matched. They are encoded with Risc0's word-based serde format and are
+//! bound through the receipt claim digest rather than a plain hash of the journal.
+//!
+//! This does not hold for programs built with the `coprocessor` feature, which appends the
+//! coprocessor output digest to the public values.
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Commit a value to the public values stream, bincode-encoded like SP1's `io::commit`.
+pub fn commit<T: Serialize>(value: &T) {
+    crate::io::commit(value);
+}
+
+/// Commit raw bytes to the public values stream, like SP1's `io::commit_slice`.
+pub fn commit_slice(buf: &[u8]) {
+    crate::io::commit_bytes(buf);
+}
+
+/// The SHA-256 digest of the public values, as SP1's `SP1PublicValues::hash`.
+pub fn public_values_digest(public_values: &[u8]) -> [u8; 32] {
+    Sha256::digest(public_values).into()
+}
+
+/// The public values digest with its top 3 bits cleared, as SP1's
+/// `SP1PublicValues::hash_bn254` and the `hashPublicValues` function of the SP1 verifier
+/// contracts. This is the value exposed by the Groth16 and PLONK proofs.
+pub fn public_values_digest_bn254(public_values: &[u8]) -> [u8; 32] {
+    let mut digest = public_values_digest(public_values);
+    digest[0] &= 0x1f;
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_values_digest_bn254() {
+        let public_values = bincode::serialize(&(10u32, 55u32)).unwrap();
+        assert_eq!(public_values, [10, 0, 0, 0, 55, 0, 0, 0]);
+
+        let digest = public_values_digest(&public_values);
+        let digest_bn254 = public_values_digest_bn254(&public_values);
+        assert_eq!(digest_bn254[0], digest[0] & 0x1f);
+        assert_eq!(digest_bn254[1..], digest[1..]);
+
+        assert_eq!(
+            hex::encode(public_values_digest(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(public_values_digest_bn254(b"")[0], 0x03);
+    }
+}
@@ -1,16 +1,160 @@
 use core::alloc::{GlobalAlloc, Layout};
 
+use crate::riscv_ecalls::{heap_pos, set_heap_pos};
 use pico_patch_libs::sys_alloc_aligned;
 
 /// A simple heap allocator.
 ///
-/// Allocates memory from left to right, without any deallocation.
+/// Allocates memory from left to right, without any deallocation. Memory can still be reclaimed
+/// in bulk with [`arena_scope`].
 pub struct SimpleAlloc;
 
 unsafe impl GlobalAlloc for SimpleAlloc {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        #[cfg(debug_assertions)]
+        scopes::on_alloc();
         sys_alloc_aligned(layout.size(), layout.align())
     }
 
-    unsafe fn dealloc(&self, _: *mut u8, _: Layout) {}
+    unsafe fn dealloc(&self, _ptr: *mut u8, _: Layout) {
+        #[cfg(debug_assertions)]
+        scopes::on_dealloc(_ptr as usize);
+    }
+}
+
+/// Runs `f`, then reclaims everything it allocated by moving the heap back to where it was.
+///
+/// Guests that process inputs in a loop can wrap each iteration in a scope so that the
+/// iterations reuse the same memory. Nothing allocated inside `f` may outlive the scope: the
+/// result must be `Copy`, so it cannot own heap memory, and values must not be moved out of the
+/// scope through captured references. Debug builds assert that every allocation made inside the
+/// scope has been freed by the time `f` returns.
+///
+/// Inputs can be read inside the scope. `HINT_READ` can only place an input in memory the program
+/// has never accessed, so the `io` readers allocate past all memory reclaimed so far. The memory of
+/// the inputs is still reclaimed, but only reused for other allocations: a loop that reads a batch
+/// per iteration grows the heap by the size of the batch each time. Slices from the unsafe
+/// `io::read_borrowed` are never freed, and must not be used once the scope returns.
+pub fn arena_scope<R: Copy>(f: impl FnOnce() -> R) -> R {
+    let start = heap_pos();
+    #[cfg(debug_assertions)]
+    let mut scope = scopes::Scope::new(start);
+    #[cfg(debug_assertions)]
+    unsafe {
+        scopes::enter(&mut scope)
+    };
+
+    let result = f();
+
+    #[cfg(debug_assertions)]
+    {
+        let live = unsafe { scopes::exit(&mut scope) };
+        assert_eq!(live, 0, "{} allocations escaped arena_scope", live);
+    }
+    // SAFETY: Everything allocated since `start` has been freed.
+    unsafe { set_heap_pos(start) };
+    result
+}
+
+/// Counts the live allocations of each open [`arena_scope`], to catch allocations escaping it.
+#[cfg(debug_assertions)]
+mod scopes {
+    use core::{
+        ptr,
+        sync::atomic::{AtomicPtr, Ordering},
+    };
+
+    pub(super) struct Scope {
+        start: usize,
+        live: usize,
+        parent: *mut Scope,
+    }
+
+    impl Scope {
+        pub(super) fn new(start: usize) -> Self {
+            Self {
+                start,
+                live: 0,
+                parent: ptr::null_mut(),
+            }
+        }
+    }
+
+    // The innermost open scope. Scopes live on the stack of `arena_scope` and are linked to the
+    // scope they are nested in, with increasing start addresses. The guest is single threaded, so
+    // relaxed loads and stores are enough.
+    static INNERMOST: AtomicPtr<Scope> = AtomicPtr::new(ptr::null_mut());
+
+    /// Opens `scope` inside the current innermost one.
+    pub(super) unsafe fn enter(scope: &mut Scope) {
+        scope.parent = INNERMOST.load(Ordering::Relaxed);
+        INNERMOST.store(scope, Ordering::Relaxed);
+    }
+
+    /// Closes `scope`, which must be the innermost one, and returns its live allocations.
+    pub(super) unsafe fn exit(scope: &mut Scope) -> usize {
+        INNERMOST.store(scope.parent, Ordering::Relaxed);
+        scope.live
+    }
+
+    /// Allocations are bumped past every open scope, so they belong to the innermost one.
+    pub(super) fn on_alloc() {
+        unsafe {
+            if let Some(scope) = INNERMOST.load(Ordering::Relaxed).as_mut() {
+                scope.live += 1;
+            }
+        }
+    }
+
+    /// Frees `addr` from the innermost scope that starts below it, if any.
+    pub(super) fn on_dealloc(addr: usize) {
+        unsafe {
+            let mut scope = INNERMOST.load(Ordering::Relaxed);
+            while let Some(current) = scope.as_mut() {
+                if current.start <= addr {
+                    current.live -= 1;
+                    return;
+                }
+                scope = current.parent;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arena_scope_reclaims_loop_allocations() {
+        // Start from a fake heap, as the host has no `_end` below the zkVM memory limit.
+        // Allocations are never written to, so the addresses need not be mapped.
+        let saved = heap_pos();
+        unsafe { set_heap_pos(0x1000) };
+
+        let layout = Layout::from_size_align(100, 8).unwrap();
+        for i in 0..1000 {
+            let result = arena_scope(|| unsafe {
+                let a = SimpleAlloc.alloc(layout);
+                let inner = arena_scope(|| {
+                    let b = SimpleAlloc.alloc(layout);
+                    SimpleAlloc.dealloc(b, layout);
+                    b as usize
+                });
+                // The inner scope has been reclaimed, so it is reused.
+                assert_eq!(SimpleAlloc.alloc(layout) as usize, inner);
+                SimpleAlloc.dealloc(inner as *mut u8, layout);
+                SimpleAlloc.dealloc(a, layout);
+                i
+            });
+            assert_eq!(result, i);
+            assert_eq!(heap_pos(), 0x1000);
+        }
+
+        // An input read now is placed past the memory the scopes reclaimed.
+        unsafe { pico_patch_libs::sys_skip_reclaimed_heap() };
+        assert_eq!(heap_pos(), 0x10cc);
+
+        unsafe { set_heap_pos(saved) };
+    }
 }
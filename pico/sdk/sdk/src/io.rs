@@ -6,7 +6,15 @@ use coprocessor_sdk::{data_types::hash_out::HashBytes, sdk::SDK};
 #[cfg(feature = "coprocessor")]
 use pico_patch_libs::io::FD_COPROCESSOR_OUTPUTS;
 
-use pico_patch_libs::io::{SyscallWriter, FD_PUBLIC_VALUES};
+use crate::poseidon2_hash::Poseidon2;
+use alloy_sol_types::{SolType, SolValue};
+use p3_field::PrimeField32;
+use p3_symmetric::Permutation;
+pub use pico_patch_libs::io::LogLevel;
+use pico_patch_libs::io::{SyscallWriter, FD_LOG, FD_PUBLIC_VALUES};
+use pico_vm::{
+    emulator::stdin::EmulatorStdinBuilder, machine::proof::OutputSegment, primitives::Poseidon2Init,
+};
 use serde::{de::DeserializeOwned, Serialize};
 
 /// Read a deserializable object from the input stream.
@@ -27,6 +35,138 @@ pub fn read_vec() -> Vec<u8> {
     pico_patch_libs::io::read_vec()
 }
 
+/// Returns the command line style arguments of the program, as set on the host with
+/// `EmulatorStdinBuilder::set_args`, in the same order. This stands in for `std::env::args` in
+/// ported programs.
+///
+/// ### Examples
+/// ```ignore
+/// let args = pico_sdk::io::args();
+/// let verbose = args.iter().any(|arg| arg == "--verbose");
+/// ```
+pub fn args() -> Vec<String> {
+    pico_patch_libs::io::args()
+}
+
+/// Returns the fuel left of the budget set on the host with `EmulatorOpts::fuel`, in cycles. The
+/// budget does not stop the program; a long running program can check it to stop early and
+/// commit a partial result. Returns `u64::MAX` if no budget was set.
+///
+/// Like the hints, the value is not constrained by the proof.
+///
+/// ### Examples
+/// ```ignore
+/// let mut i = 0;
+/// while i < items.len() && pico_sdk::io::fuel_remaining() > 10_000 {
+///     process(&items[i]);
+///     i += 1;
+/// }
+/// pico_sdk::io::commit(&i);
+/// ```
+pub fn fuel_remaining() -> u64 {
+    pico_patch_libs::io::fuel_remaining()
+}
+
+/// Returns the number of cycles the program has run so far. Like [`fuel_remaining`], the value
+/// is not constrained by the proof.
+pub fn cycle_count() -> u64 {
+    pico_patch_libs::io::cycle_count()
+}
+
+/// Run `f` and log the number of cycles it took under `name`, at the debug level.
+///
+/// The count is inclusive: a span nested in `f` is counted in both spans. See [`span_commit`]
+/// to read the count on the host.
+///
+/// ### Examples
+/// ```ignore
+/// let header = pico_sdk::io::span("decode", || decode(&bytes));
+/// ```
+pub fn span<T>(name: &str, f: impl FnOnce() -> T) -> T {
+    let (value, cycles) = measure(f);
+    log(LogLevel::Debug, &format!("span {name}: {cycles} cycles"));
+    value
+}
+
+/// Run `f` and commit the number of cycles it took as the `uint64` output segment
+/// `span:<name>`, which the host reads with `MetaProof::spans`.
+///
+/// The count becomes part of the public values, so the names of committed spans must be unique
+/// and the program should commit its other public values as segments too, see
+/// [`commit_segment`]. As with [`cycle_count`], the proof does not constrain the count.
+///
+/// ### Examples
+/// ```ignore
+/// let valid = pico_sdk::io::span_commit("verify", || {
+///     let header = pico_sdk::io::span_commit("verify/decode", || decode(&bytes));
+///     verify(&header)
+/// });
+/// ```
+pub fn span_commit<T>(name: &str, f: impl FnOnce() -> T) -> T {
+    let (value, cycles) = measure(f);
+    commit_segment(&format!("{}{name}", OutputSegment::SPAN_PREFIX), &cycles);
+    value
+}
+
+fn measure<T>(f: impl FnOnce() -> T) -> (T, u64) {
+    let start = cycle_count();
+    let value = f();
+    (value, cycle_count() - start)
+}
+
+/// Asks the host for the result of a computation: the handler the host registered for `tag`
+/// with `register_host_call` runs on `args`, and its response is returned. Unlike the inputs,
+/// which are all written before the program starts, the request is only made when the guest
+/// reaches this call, so the arguments can depend on earlier results.
+///
+/// The response comes from the host and is not constrained by the proof. The guest must check
+/// that it is correct before using it, for example that a claimed factorization multiplies back
+/// to the input, as a malicious prover can return any bytes here.
+///
+/// ### Examples
+/// ```ignore
+/// const FACTOR: u32 = 1;
+/// let factor = pico_sdk::io::host_call(FACTOR, &n.to_le_bytes());
+/// let p = u64::from_le_bytes(factor.try_into().unwrap());
+/// assert!(p > 1 && p < n && n % p == 0);
+/// ```
+pub fn host_call(tag: u32, args: &[u8]) -> Vec<u8> {
+    pico_patch_libs::io::host_call(tag, args)
+}
+
+/// Read a buffer from the input stream without taking ownership of it.
+///
+/// The slice points at the input where the VM placed it and stays valid and unchanged until the
+/// program halts, since guest memory is only reclaimed by [`crate::heap::arena_scope`]. Each call
+/// reads the next input, so two slices never overlap. Prefer it over [`read_vec`] for large inputs
+/// that are only scanned, hashed or deserialized by reference.
+///
+/// # Safety
+///
+/// When called inside an `arena_scope`, the slice lives in memory that is reclaimed when the
+/// scope returns, so it must not be used after that.
+///
+/// ### Examples
+/// ```ignore
+/// // SAFETY: not called inside an arena_scope.
+/// let encoded = unsafe { pico_sdk::io::read_borrowed() };
+/// let block: LightBlock = serde_cbor::from_slice(encoded).unwrap();
+/// ```
+pub unsafe fn read_borrowed() -> &'static [u8] {
+    pico_patch_libs::io::read_borrowed()
+}
+
+/// Read a buffer of plain-old-data values from the input stream in one syscall. The host writes
+/// it with `EmulatorStdinBuilder::write_pod_slice`.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u32> = pico_sdk::io::read_slice();
+/// ```
+pub fn read_slice<T: bytemuck::Pod>() -> Vec<T> {
+    pico_patch_libs::io::read_slice()
+}
+
 /// Reads a buffer from the input stream and deserializes it into a type `T`.
 ///
 /// ### Examples
@@ -38,6 +178,22 @@ pub fn read_as<T: DeserializeOwned>() -> T {
     bincode::deserialize(&vec).expect("deserialization failed")
 }
 
+/// Reads an optional value written by the host with `EmulatorStdinBuilder::write_option`, in
+/// place of a separate flag input followed by a conditional read.
+///
+/// ### Examples
+/// ```ignore
+/// let upgrade_proof: Option<Vec<u8>> = pico_sdk::io::read_option();
+/// ```
+pub fn read_option<T: DeserializeOwned>() -> Option<T> {
+    let vec = read_vec();
+    match vec.split_first() {
+        Some((0, [])) => None,
+        Some((1, data)) => Some(bincode::deserialize(data).expect("deserialization failed")),
+        _ => panic!("malformed optional input"),
+    }
+}
+
 /// Commit a serializable object to the public values stream.
 ///
 /// ### Examples
@@ -63,6 +219,246 @@ pub fn commit<T: Serialize>(value: &T) {
     bincode::serialize_into(writer, value).expect("serialization failed");
 }
 
+/// Runs `f` in an unconstrained block. The code in `f` is emulated but not proven, and any change
+/// it makes to the VM state is reset when the block exits, so results are passed back to the
+/// constrained program through `pico_patch_libs::io::hint`.
+///
+/// The block is exited when `f` returns, even through an early return. If `f` panics, the block
+/// is exited instead of halting in unconstrained mode, and the program continues after the call.
+///
+/// ### Examples
+/// ```ignore
+/// pico_sdk::io::unconstrained(|| {
+///     let signer = recover_signer(&signature, &message);
+///     pico_patch_libs::io::hint(&signer);
+/// });
+/// let signer: Address = pico_sdk::io::read_as();
+/// ```
+pub fn unconstrained<F: FnOnce()>(f: F) {
+    if unsafe { pico_patch_libs::syscall_enter_unconstrained() } {
+        let _guard = UnconstrainedGuard;
+        f();
+    }
+}
+
+/// Exits the unconstrained block when dropped.
+struct UnconstrainedGuard;
+
+impl Drop for UnconstrainedGuard {
+    fn drop(&mut self) {
+        unsafe { pico_patch_libs::syscall_exit_unconstrained() }
+    }
+}
+
+/// The maximum number of words the `CT_EQ` precompile compares in a single call.
+const CT_EQ_MAX_WORDS: usize = 1 << 20;
+
+/// Compare two byte slices for equality without an early exit.
+///
+/// The comparison is done by the `CT_EQ` precompile, which XOR-accumulates over every word of
+/// both slices, so the result cannot be affected by the compiler turning the comparison into a
+/// short-circuiting loop. Slices of different lengths are never equal. Slices over 4 MiB take
+/// several precompile calls.
+///
+/// ### Examples
+/// ```ignore
+/// let mac = [0u8; 32];
+/// let expected = [0u8; 32];
+/// assert!(pico_sdk::io::ct_eq(&mac, &expected));
+/// ```
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    if a.is_empty() {
+        return true;
+    }
+
+    // Pack both slices into zero-padded little-endian words.
+    let to_words = |bytes: &[u8]| -> Vec<u32> {
+        bytes
+            .chunks(4)
+            .map(|chunk| {
+                let mut word = [0u8; 4];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u32::from_le_bytes(word)
+            })
+            .collect()
+    };
+    let a_words = to_words(a);
+    let b_words = to_words(b);
+
+    // A single call compares at most `CT_EQ_MAX_WORDS` words, so longer slices are compared
+    // chunk by chunk. Every chunk is compared, even after a difference is found.
+    let mut equal = 1u32;
+    for (a_chunk, b_chunk) in a_words
+        .chunks(CT_EQ_MAX_WORDS)
+        .zip(b_words.chunks(CT_EQ_MAX_WORDS))
+    {
+        let mut result = 0u32;
+        unsafe {
+            pico_patch_libs::syscall_ct_eq(
+                a_chunk.as_ptr(),
+                b_chunk.as_ptr(),
+                a_chunk.len() as u32,
+                &mut result,
+            );
+        }
+        equal &= result;
+    }
+    equal == 1
+}
+
+/// Assert that every value fits in `bits` bits, with one `RANGE_CHECK_BATCH` precompile call.
+///
+/// The check is part of the proof: a value of `bits` bits or more fails the emulation, so no
+/// proof can be made for it. `bits` must be at most 32.
+///
+/// ### Examples
+/// ```ignore
+/// let limbs = [0x1234u32, 0xffff, 0];
+/// pico_sdk::io::range_check_batch(&limbs, 16);
+/// ```
+pub fn range_check_batch(values: &[u32], bits: u32) {
+    if values.is_empty() {
+        return;
+    }
+    unsafe {
+        pico_patch_libs::syscall_range_check_batch(values.as_ptr(), values.len() as u32, bits);
+    }
+}
+
+/// Computes the CRC-32 (IEEE) checksum of `bytes` with the `CRC32` precompile, the same value
+/// as `crc32fast::hash`.
+///
+/// ### Examples
+/// ```ignore
+/// assert_eq!(pico_sdk::io::crc32(b"123456789"), 0xcbf43926);
+/// ```
+pub fn crc32(bytes: &[u8]) -> u32 {
+    // The precompile reads whole words and at most 4096 bytes per call.
+    let mut crc = 0u32;
+    for chunk in bytes.chunks(4096) {
+        let words = chunk
+            .chunks(4)
+            .map(|word| {
+                let mut padded = [0u8; 4];
+                padded[..word.len()].copy_from_slice(word);
+                u32::from_le_bytes(padded)
+            })
+            .collect::<Vec<_>>();
+        unsafe {
+            pico_patch_libs::syscall_crc32(words.as_ptr(), chunk.len() as u32, &mut crc);
+        }
+    }
+    crc
+}
+
+/// Computes the SHA-256 digests of several independent messages, with one `MULTI_HASH`
+/// precompile call per 32 messages.
+///
+/// ### Examples
+/// ```ignore
+/// let digests = pico_sdk::io::sha256_batch(&[b"abc", b""]);
+/// assert_eq!(digests.len(), 2);
+/// ```
+pub fn sha256_batch(messages: &[&[u8]]) -> Vec<[u8; 32]> {
+    // The precompile reads each message after a word holding its length.
+    let inputs = messages
+        .iter()
+        .map(|message| {
+            let mut words = vec![message.len() as u32];
+            words.extend(message.chunks(4).map(|word| {
+                let mut padded = [0u8; 4];
+                padded[..word.len()].copy_from_slice(word);
+                u32::from_le_bytes(padded)
+            }));
+            words
+        })
+        .collect::<Vec<_>>();
+    let mut digests = vec![[0u32; 8]; messages.len()];
+
+    for (inputs, digests) in inputs.chunks(32).zip(digests.chunks_mut(32)) {
+        let mut descriptors = vec![inputs.len() as u32];
+        for (input, digest) in inputs.iter().zip(digests.iter_mut()) {
+            descriptors.push(input.as_ptr() as u32);
+            descriptors.push(digest.as_mut_ptr() as u32);
+        }
+        unsafe {
+            pico_patch_libs::syscall_multi_hash(0, descriptors.as_ptr());
+        }
+    }
+
+    digests
+        .into_iter()
+        .map(|digest| {
+            let mut bytes = [0u8; 32];
+            for (chunk, word) in bytes.chunks_exact_mut(4).zip(digest) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+            }
+            bytes
+        })
+        .collect()
+}
+
+/// Returns the digest of this program's riscv verifying key, as injected by the host at the
+/// start of emulation. It equals `RiscvProver::vk_digest` for the same ELF.
+///
+/// The value is a hint and is not constrained by the proof, so a guest that commits it only
+/// makes a claim; the verifier must compare it against the vk it verified the proof with.
+///
+/// ### Examples
+/// ```ignore
+/// let vk_digest = pico_sdk::io::self_vk_digest();
+/// pico_sdk::io::commit(&vk_digest);
+/// ```
+pub fn self_vk_digest() -> [u32; 8] {
+    // The digest is written into fresh memory, so allocate instead of using the stack, past any
+    // memory reclaimed by an arena scope.
+    let layout = std::alloc::Layout::new::<[u32; 8]>();
+    unsafe {
+        pico_patch_libs::sys_skip_reclaimed_heap();
+        let ptr = std::alloc::alloc(layout) as *mut u32;
+        pico_patch_libs::syscall_self_vk_digest(ptr);
+        *(ptr as *const [u32; 8])
+    }
+}
+
+/// Returns the SHA-256 of every input read so far, each prefixed with its length as a
+/// little-endian `u32`. The host computes the expected value with
+/// `EmulatorStdinBuilder::input_digest`.
+///
+/// The guest hashes each input with the SHA-256 precompiles as it reads it, so the digest is
+/// constrained by the proof. This costs cycles on every read, which is why it needs the
+/// `input-digest` feature. Inputs hinted from an [`unconstrained`] block and read afterwards are
+/// part of the digest as well.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u8> = pico_sdk::io::read_vec();
+/// pico_sdk::io::commit_input_digest();
+/// ```
+#[cfg(feature = "input-digest")]
+pub fn input_digest() -> [u8; 32] {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "zkvm")] {
+            use sha2::Digest;
+            #[allow(static_mut_refs)]
+            let hasher = unsafe { crate::zkvm::INPUT_HASHER.clone().unwrap() };
+            hasher.finalize().into()
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+/// Commits [`input_digest`] to the public values, binding the proof to the exact inputs. The
+/// committed bytes are the 32 bytes of the digest.
+#[cfg(feature = "input-digest")]
+pub fn commit_input_digest() {
+    commit(&input_digest());
+}
+
 // commit a coprocessor serializable object to the coprocessor output stream
 #[cfg(feature = "coprocessor")]
 fn commit_coprocessor_output<T: Serialize>(value: &T) {
@@ -72,6 +468,22 @@ fn commit_coprocessor_output<T: Serialize>(value: &T) {
     bincode::serialize_into(writer, value).expect("serialization failed");
 }
 
+/// Write a diagnostic line that the host emits through `tracing` at `level`, under the
+/// `pico_guest` target, instead of printing it with the program's stdout.
+///
+/// ### Examples
+/// ```ignore
+/// use pico_sdk::io::LogLevel;
+///
+/// pico_sdk::io::log(LogLevel::Debug, "starting the second pass");
+/// ```
+pub fn log(level: LogLevel, msg: &str) {
+    let mut line = Vec::with_capacity(1 + msg.len());
+    line.push(level as u8);
+    line.extend_from_slice(msg.as_bytes());
+    SyscallWriter { fd: FD_LOG }.write_all(&line).unwrap();
+}
+
 /// Commit bytes to the public values stream.
 ///
 /// ### Examples
@@ -86,6 +498,132 @@ pub fn commit_bytes(buf: &[u8]) {
     my_writer.write_all(buf).unwrap();
 }
 
+/// Commit the Poseidon2 hash of `data` to the public values stream instead of `data` itself, and
+/// return the hash. This keeps a large private input, written on the host with
+/// [`write_hash_preimage`], out of the public values while still binding the proof to it.
+///
+/// The hash is [`Poseidon2::hash_bytes`] over the field `F` of the prover, committed as the 8
+/// elements in little endian bytes.
+///
+/// ### Examples
+/// ```ignore
+/// let records = pico_sdk::io::read_vec();
+/// let digest = pico_sdk::io::commit_hash::<BabyBear>(&records);
+/// ```
+pub fn commit_hash<F>(data: &[u8]) -> [u8; 32]
+where
+    F: PrimeField32 + Poseidon2Init,
+    F::Poseidon2: Permutation<[F; 16]>,
+{
+    let mut hash = [0u8; 32];
+    for (bytes, element) in hash
+        .chunks_exact_mut(4)
+        .zip(Poseidon2::<F>::hash_bytes(data))
+    {
+        bytes.copy_from_slice(&element.as_canonical_u32().to_le_bytes());
+    }
+    commit_bytes(&hash);
+    hash
+}
+
+/// Write the preimage of a hash committed with [`commit_hash`] to `stdin`, to be read in the
+/// guest with [`read_vec`].
+pub fn write_hash_preimage(stdin: &mut EmulatorStdinBuilder<Vec<u8>>, data: &[u8]) {
+    stdin.write_slice(data);
+}
+
+/// Commit a string to the public values stream as its length in bytes, a little-endian `u32`,
+/// followed by its UTF-8 bytes, in a single syscall. Read it back on the host with
+/// `MetaProof::read_committed_str`.
+///
+/// ### Examples
+/// ```ignore
+/// pico_sdk::io::commit_str("verified");
+/// ```
+pub fn commit_str(s: &str) {
+    unsafe { pico_patch_libs::syscall_commit_str(s.as_ptr(), s.len()) }
+}
+
+/// Commit words to the public values stream, each as 4 little-endian bytes, in a single
+/// syscall. This commits the same bytes as [`commit_bytes`] on the words, e.g. a digest or a
+/// curve point, without going through the byte writer.
+///
+/// ### Examples
+/// ```ignore
+/// let root: [u32; 8] = merkle_root();
+/// pico_sdk::io::commit_array(&root);
+/// ```
+pub fn commit_array(words: &[u32]) {
+    unsafe { pico_patch_libs::syscall_commit_array(words.as_ptr(), words.len()) }
+}
+
+/// Commit `value` ABI-encoded as the output segment `name`, to be read back on the host with
+/// `MetaProof::outputs`. The segment records the Solidity name of `T` as its schema.
+///
+/// A program that uses segments should commit all of its public values through them; plain
+/// commits in the same stream make `MetaProof::outputs` return `None`.
+///
+/// ### Examples
+/// ```ignore
+/// pico_sdk::io::commit_segment("result", &(n, fib_n));
+/// pico_sdk::io::commit_segment("audit_log", &log_entries);
+/// ```
+pub fn commit_segment<T: SolValue>(name: &str, value: &T) {
+    commit_segment_bytes(name, <T::SolType as SolType>::SOL_NAME, &value.abi_encode());
+}
+
+/// Commit pre-encoded `data` as the output segment `name` with the given schema.
+pub fn commit_segment_bytes(name: &str, schema: &str, data: &[u8]) {
+    commit_bytes(&OutputSegment::encode(name, schema, data));
+}
+
+pub use crate::{commit_abi, decode_abi};
+#[doc(hidden)]
+pub use alloy_sol_types as __alloy_sol_types;
+
+/// ABI-encode local variables as a Solidity struct and commit the encoding to the public
+/// values stream. Each `name: type` pair names a variable in scope and its Solidity type.
+/// The generated struct carries its Solidity definition as a doc comment, so the verifier
+/// contract can copy it verbatim.
+///
+/// ### Examples
+/// ```ignore
+/// let (n, a, b) = (10u32, 34u32, 55u32);
+/// pico_sdk::io::commit_abi!(n: uint32, a: uint32, b: uint32);
+/// ```
+#[macro_export]
+macro_rules! commit_abi {
+    ($($field:ident : $ty:ident),+ $(,)?) => {{
+        $crate::io::__alloy_sol_types::sol! {
+            #[sol(alloy_sol_types = $crate::io::__alloy_sol_types)]
+            #[doc = concat!(
+                "struct PublicValuesStruct {",
+                $(" ", stringify!($ty), " ", stringify!($field), ";",)+
+                " }"
+            )]
+            struct PublicValuesStruct {
+                $($ty $field;)+
+            }
+        }
+        let value = PublicValuesStruct { $($field,)+ };
+        $crate::io::commit_bytes(&$crate::io::__alloy_sol_types::SolValue::abi_encode(&value));
+    }};
+}
+
+/// Decode ABI-encoded public values, typically produced by [`commit_abi!`], into a
+/// `sol!` struct type. Returns the `alloy_sol_types` decoding result.
+///
+/// ### Examples
+/// ```ignore
+/// let public_values = pico_sdk::io::decode_abi!(&pv_stream, PublicValuesStruct).unwrap();
+/// ```
+#[macro_export]
+macro_rules! decode_abi {
+    ($bytes:expr, $ty:ty) => {
+        <$ty as $crate::io::__alloy_sol_types::SolType>::abi_decode($bytes, true)
+    };
+}
+
 // Commit bytes to the coprocessor output stream.
 #[cfg(feature = "coprocessor")]
 fn commit_coprocessor_output_bytes(buf: &[u8]) {
@@ -152,3 +690,24 @@ pub fn commit_coprocessor_bytes(coprocessor_sdk: &mut SDK, buf: &mut [u8]) {
         commit_coprocessor_output_bytes(buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::riscv_ecalls::is_unconstrained;
+
+    #[test]
+    fn test_unconstrained_exits_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            unconstrained(|| {
+                assert!(is_unconstrained());
+                panic!("failed inside the unconstrained block");
+            })
+        });
+        assert!(result.is_err());
+        assert!(!is_unconstrained());
+
+        unconstrained(|| assert!(is_unconstrained()));
+        assert!(!is_unconstrained());
+    }
+}
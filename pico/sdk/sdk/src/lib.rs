@@ -3,15 +3,19 @@
 //! Documentation for these syscalls can be found in the zkVM entrypoint
 //! `pico_sdk::riscv_ecalls` module.
 
+pub use pico_derive::pico_export;
 use pico_vm::machine::logger::setup_logger;
 
 extern crate alloc;
 
 pub mod client;
+pub mod collections;
 pub mod command;
+pub mod compat;
 pub mod heap;
 pub mod io;
 pub mod m31_client;
+pub mod merkle;
 
 #[cfg(all(target_os = "zkvm", feature = "libm"))]
 mod libm;
@@ -36,6 +40,10 @@ mod zkvm {
     #[allow(static_mut_refs)]
     pub static mut COPROCESSOR_OUTPUT_VALUES_HASHER: Option<Sha256> = None;
 
+    #[cfg(feature = "input-digest")]
+    #[allow(static_mut_refs)]
+    pub static mut INPUT_HASHER: Option<Sha256> = None;
+
     #[no_mangle]
     unsafe extern "C" fn __start() {
         {
@@ -43,6 +51,11 @@ mod zkvm {
 
             COPROCESSOR_OUTPUT_VALUES_HASHER = Some(Sha256::new());
 
+            #[cfg(feature = "input-digest")]
+            {
+                INPUT_HASHER = Some(Sha256::new());
+            }
+
             extern "C" {
                 fn main();
             }
@@ -73,19 +86,52 @@ mod zkvm {
         sym STACK_TOP
     );
 
-    pub fn zkvm_getrandom(_s: &mut [u8]) -> Result<(), getrandom::Error> {
-        // unsafe {
-        //     crate::riscv_ecalls::sys_rand(s.as_mut_ptr(), s.len());
-        // }
-
+    /// Fills `s` with zeros: the zkVM has no source of randomness that the prover could not pick.
+    ///
+    /// Hash maps seeded through `getrandom` therefore see a fixed seed only as long as this stub
+    /// stays as it is. Guests that rely on iteration order should use
+    /// [`crate::collections::PicoHashMap`], whose seed is fixed on its own.
+    pub fn zkvm_getrandom(s: &mut [u8]) -> Result<(), getrandom::Error> {
+        s.fill(0);
         Ok(())
     }
 
     getrandom::register_custom_getrandom!(zkvm_getrandom);
 }
 
+/// Runs the function of `exports` named by `selector`, the dispatch behind
+/// `entrypoint!(dispatch: ...)`.
+///
+/// # Panics
+/// Panics if no function has that name.
+#[doc(hidden)]
+pub fn dispatch(exports: &[(&str, fn())], selector: &[u8]) {
+    match exports.iter().find(|(name, _)| name.as_bytes() == selector) {
+        Some((_, func)) => func(),
+        None => panic!(
+            "no exported function named {:?}",
+            String::from_utf8_lossy(selector)
+        ),
+    }
+}
+
+/// Declares the guest's entry point.
+///
+/// `entrypoint!(main)` runs `main`. `entrypoint!(dispatch: prove_add, ops::prove_mul)` builds one
+/// ELF serving several functions marked with [`pico_export`]: its `main` reads the name of the
+/// function to run from the hint stream, where the host writes it with
+/// `EmulatorStdinBuilder::select_function`, and looks it up in the table of the listed exports.
+/// Listing a function that is not marked with [`pico_export`] fails to compile.
 #[macro_export]
 macro_rules! entrypoint {
+    (dispatch: $($($export:ident)::+),+ $(,)?) => {
+        fn zkvm_dispatch() {
+            const EXPORTS: &[(&str, fn())] = &[$($($export)::+::PICO_EXPORT),+];
+            $crate::dispatch(EXPORTS, &$crate::io::read_vec());
+        }
+
+        $crate::entrypoint!(zkvm_dispatch);
+    };
     ($path:path) => {
         const ZKVM_ENTRY: fn() = $path;
 
@@ -115,3 +161,40 @@ macro_rules! entrypoint {
 pub fn init_logger() {
     setup_logger();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{dispatch, pico_export};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static CALLED: AtomicU32 = AtomicU32::new(0);
+
+    #[pico_export]
+    fn pico_test_export_one() {
+        CALLED.store(1, Ordering::SeqCst);
+    }
+
+    #[pico_export]
+    fn pico_test_export_two() {
+        CALLED.store(2, Ordering::SeqCst);
+    }
+
+    const EXPORTS: &[(&str, fn())] = &[
+        pico_test_export_one::PICO_EXPORT,
+        pico_test_export_two::PICO_EXPORT,
+    ];
+
+    #[test]
+    fn test_dispatch() {
+        dispatch(EXPORTS, b"pico_test_export_two");
+        assert_eq!(CALLED.load(Ordering::SeqCst), 2);
+        dispatch(EXPORTS, b"pico_test_export_one");
+        assert_eq!(CALLED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "no exported function named \"pico_test_export\"")]
+    fn test_dispatch_unknown_function() {
+        dispatch(EXPORTS, b"pico_test_export");
+    }
+}
@@ -6,7 +6,7 @@ use pico_vm::{
     compiler::riscv::program::Program,
     configs::{config::StarkGenericConfig, stark_config::m31_poseidon2::M31Poseidon2},
     emulator::stdin::{EmulatorStdin, EmulatorStdinBuilder},
-    machine::proof::MetaProof,
+    machine::{progress::ProveProgress, proof::MetaProof},
     proverchain::{InitialProverSetup, MachineProver, RiscvProver},
 };
 
@@ -34,11 +34,25 @@ impl M31RiscvProverClient {
         Rc::clone(&self.stdin_builder)
     }
 
+    /// emulate the riscv program without proving and return the number of chunks it splits into
+    pub fn estimate_chunks(&self) -> usize {
+        let stdin = self.stdin_builder.borrow().clone().finalize();
+        self.riscv.estimate_chunk_count(&stdin)
+    }
+
     /// prove and verify riscv program. default not include convert, combine, compress, embed
     pub fn prove_fast(&self) -> Result<MetaProof<M31Poseidon2>, Error> {
+        self.prove_fast_with_progress(|_| {})
+    }
+
+    /// same as `prove_fast`, reporting each emulated and proven chunk to `cb`
+    pub fn prove_fast_with_progress(
+        &self,
+        mut cb: impl FnMut(ProveProgress),
+    ) -> Result<MetaProof<M31Poseidon2>, Error> {
         let stdin = self.stdin_builder.borrow().clone().finalize();
         info!("stdin length: {}", stdin.inputs.len());
-        let proof = self.riscv.prove(stdin);
+        let (proof, _) = self.riscv.prove_with_progress(stdin, &mut cb);
         let riscv_vk = self.riscv.vk();
         info!("riscv_prover prove success");
         if !self.riscv.verify(&proof, riscv_vk) {
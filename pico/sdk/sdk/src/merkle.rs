@@ -0,0 +1,162 @@
+use crate::poseidon2_hash::poseidon2_hash_pair;
+use p3_field::PrimeField32;
+use p3_symmetric::Permutation;
+use pico_vm::primitives::Poseidon2Init;
+
+/// The largest supported tree depth, enough for 2^32 leaves.
+pub const MAX_MERKLE_DEPTH: usize = 32;
+
+/// A node of the tree: 8 canonical field elements, as in the Merkle trees of the Poseidon2 stark
+/// configs.
+pub type MerkleDigest = [u32; 8];
+
+/// An append-only Merkle tree of fixed depth.
+///
+/// Only the frontier (one node per level) is kept, so appending a leaf and recomputing the root
+/// both cost `depth` compressions. Empty leaves are zero. By default nodes are compressed with
+/// [`poseidon2_hash_pair`], which is one `POSEIDON2_COMPRESS` precompile call inside the zkVM.
+///
+/// There is no `MERKLE_APPEND` precompile: the tree is kept in guest memory and only the node
+/// compressions are proven by a chip. Proving a guest that uses the default compression needs
+/// the `unreleased-precompiles` feature of `pico-vm`, like any other `POSEIDON2_COMPRESS` call.
+pub struct MerkleAccumulator {
+    depth: usize,
+    len: u64,
+    branch: Vec<MerkleDigest>,
+    zero_hashes: Vec<MerkleDigest>,
+    compress: fn(&MerkleDigest, &MerkleDigest) -> MerkleDigest,
+}
+
+impl MerkleAccumulator {
+    /// Creates an empty tree of the given depth using Poseidon2 compression over `F`, which must
+    /// be the field of the prover.
+    pub fn new<F>(depth: usize) -> Self
+    where
+        F: PrimeField32 + Poseidon2Init,
+        F::Poseidon2: Permutation<[F; 16]>,
+    {
+        Self::with_compress(depth, poseidon2_hash_pair::<F>)
+    }
+
+    /// Creates an empty tree of the given depth with a custom two-to-one compression function.
+    pub fn with_compress(
+        depth: usize,
+        compress: fn(&MerkleDigest, &MerkleDigest) -> MerkleDigest,
+    ) -> Self {
+        assert!(
+            (1..=MAX_MERKLE_DEPTH).contains(&depth),
+            "merkle depth must be in 1..={}",
+            MAX_MERKLE_DEPTH
+        );
+
+        let mut zero_hashes = Vec::with_capacity(depth);
+        zero_hashes.push([0; 8]);
+        for i in 1..depth {
+            let zero = zero_hashes[i - 1];
+            zero_hashes.push(compress(&zero, &zero));
+        }
+
+        Self {
+            depth,
+            len: 0,
+            branch: vec![[0; 8]; depth],
+            zero_hashes,
+            compress,
+        }
+    }
+
+    /// Number of leaves appended so far.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a leaf and returns the new root.
+    pub fn append(&mut self, leaf: MerkleDigest) -> MerkleDigest {
+        assert!(self.len < 1 << self.depth, "merkle tree is full");
+
+        // Fold the leaf into the frontier up to the first level where the new index is a left
+        // child, which is where it waits for its right sibling.
+        let mut node = leaf;
+        let mut index = self.len;
+        for level in 0..self.depth {
+            if index & 1 == 0 {
+                self.branch[level] = node;
+                break;
+            }
+            node = (self.compress)(&self.branch[level], &node);
+            index >>= 1;
+        }
+        self.len += 1;
+
+        self.root()
+    }
+
+    /// The current root, treating all leaves past `len` as zero.
+    pub fn root(&self) -> MerkleDigest {
+        let mut node = [0; 8];
+        let mut size = self.len;
+        for level in 0..self.depth {
+            node = if size & 1 == 1 {
+                (self.compress)(&self.branch[level], &node)
+            } else {
+                (self.compress)(&node, &self.zero_hashes[level])
+            };
+            size >>= 1;
+        }
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p3_baby_bear::BabyBear;
+    use p3_field::FieldAlgebra;
+    use p3_symmetric::{PseudoCompressionFunction, TruncatedPermutation};
+
+    /// The root of the full tree over `leaves` padded with zero leaves, with the compression of
+    /// the Merkle trees of the BabyBear Poseidon2 stark config.
+    fn reference_root(leaves: &[MerkleDigest], depth: usize) -> MerkleDigest {
+        let compress = TruncatedPermutation::<_, 2, 8, 16>::new(BabyBear::init());
+        let mut level = leaves
+            .iter()
+            .map(|leaf| leaf.map(BabyBear::from_canonical_u32))
+            .collect::<Vec<_>>();
+        level.resize(1 << depth, [BabyBear::ZERO; 8]);
+        for _ in 0..depth {
+            level = level
+                .chunks(2)
+                .map(|pair| compress.compress([pair[0], pair[1]]))
+                .collect();
+        }
+        level[0].map(|f| f.as_canonical_u32())
+    }
+
+    #[test]
+    fn test_merkle_accumulator_matches_reference() {
+        let depth = 4;
+        let mut acc = MerkleAccumulator::new::<BabyBear>(depth);
+        assert_eq!(acc.root(), reference_root(&[], depth));
+
+        let mut leaves = vec![];
+        for i in 0..1 << depth {
+            let leaf = core::array::from_fn(|j| i * 31 + j as u32 + 5);
+            leaves.push(leaf);
+            assert_eq!(acc.append(leaf), reference_root(&leaves, depth));
+        }
+        assert_eq!(acc.len(), 1 << depth);
+    }
+
+    #[test]
+    #[should_panic(expected = "merkle tree is full")]
+    fn test_merkle_accumulator_full() {
+        let mut acc = MerkleAccumulator::new::<BabyBear>(1);
+        acc.append([1; 8]);
+        acc.append([2; 8]);
+        acc.append([3; 8]);
+    }
+}
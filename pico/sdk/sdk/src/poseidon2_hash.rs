@@ -1,5 +1,44 @@
 use p3_field::PrimeField32;
+use p3_symmetric::Permutation;
 use pico_patch_libs::syscall_poseidon2_permute;
+use pico_vm::primitives::Poseidon2Init;
+
+/// Compresses two digests of 8 field elements into one with a single Poseidon2 permutation.
+///
+/// The output is the first half of the permuted `[left, right]`, as with the
+/// `TruncatedPermutation<_, 2, 8, 16>` compression of the Merkle trees of the Poseidon2 stark
+/// configs and of `FieldHasher::constant_compress` in recursion. This costs one
+/// `POSEIDON2_COMPRESS` call per node, where hashing the 16 elements with [`Poseidon2::hash_many`]
+/// takes two.
+///
+/// Inside the zkVM the permutation runs on the field of the prover, which must be `F`. Outside
+/// it is computed in software over `F`. Every word must be a canonical element of `F`.
+pub fn poseidon2_hash_pair<F>(left: &[u32; 8], right: &[u32; 8]) -> [u32; 8]
+where
+    F: PrimeField32 + Poseidon2Init,
+    F::Poseidon2: Permutation<[F; 16]>,
+{
+    let mut state = [0u32; 16];
+    state[..8].copy_from_slice(left);
+    state[8..].copy_from_slice(right);
+
+    #[cfg(target_os = "zkvm")]
+    {
+        use pico_patch_libs::syscall_poseidon2_compress;
+
+        let mut parent = [0u32; 8];
+        unsafe {
+            syscall_poseidon2_compress(&state as *const _, &mut parent as *mut _);
+        }
+        parent
+    }
+    #[cfg(not(target_os = "zkvm"))]
+    {
+        let permuted = F::init().permute(state.map(F::from_canonical_u32));
+        core::array::from_fn(|i| permuted[i].as_canonical_u32())
+    }
+}
+
 /// A stateful hasher for Poseidon2.
 pub struct Poseidon2<F: PrimeField32> {
     state: [F; 16],      // Poseidon2 works with a 16-element state.
@@ -96,8 +135,98 @@ impl<F: PrimeField32> Poseidon2<F> {
     }
 }
 
+impl<F> Poseidon2<F>
+where
+    F: PrimeField32 + Poseidon2Init,
+    F::Poseidon2: Permutation<[F; 16]>,
+{
+    /// Hashes a byte string to 8 field elements.
+    ///
+    /// The bytes are packed 3 per element in little endian order, below any 31-bit modulus, and
+    /// prefixed with their length. The elements are then hashed with the rate 8 sponge of
+    /// `PaddingFreeSponge<_, 16, 8, 8>`, the hasher of the Poseidon2 stark configs, so the host
+    /// can recompute the digest with `StarkGenericConfig::hash_slice` on the same elements, or
+    /// with this function outside the zkVM.
+    pub fn hash_bytes(data: &[u8]) -> [F; 8] {
+        let len = F::from_canonical_u32(data.len() as u32);
+        let packed = data.chunks(3).map(|chunk| {
+            let mut word = [0u8; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            F::from_canonical_u32(u32::from_le_bytes(word))
+        });
+        let mut inputs = core::iter::once(len).chain(packed).peekable();
+
+        let mut state = [F::ZERO; 16];
+        while inputs.peek().is_some() {
+            for (slot, input) in state[..8].iter_mut().zip(&mut inputs) {
+                *slot = input;
+            }
+            state = Self::permute_state(state);
+        }
+        state[..8].try_into().unwrap()
+    }
+
+    fn permute_state(state: [F; 16]) -> [F; 16] {
+        #[cfg(target_os = "zkvm")]
+        {
+            let mut ret = [0_u32; 16];
+            unsafe {
+                syscall_poseidon2_permute(
+                    &state.map(|f| f.as_canonical_u32()) as *const _,
+                    &mut ret as *mut _,
+                );
+            }
+            ret.map(F::from_wrapped_u32)
+        }
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            F::init().permute(state)
+        }
+    }
+}
+
 impl<F: PrimeField32> Default for Poseidon2<F> {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p3_baby_bear::BabyBear;
+    use p3_field::FieldAlgebra;
+    use p3_symmetric::{
+        CryptographicHasher, PaddingFreeSponge, PseudoCompressionFunction, TruncatedPermutation,
+    };
+
+    #[test]
+    fn test_hash_pair_matches_merkle_compression() {
+        let left: [u32; 8] = core::array::from_fn(|i| i as u32);
+        let right: [u32; 8] = core::array::from_fn(|i| 1000 + i as u32);
+
+        let compress = TruncatedPermutation::<_, 2, 8, 16>::new(BabyBear::init());
+        let expected = compress.compress([
+            left.map(BabyBear::from_canonical_u32),
+            right.map(BabyBear::from_canonical_u32),
+        ]);
+        assert_eq!(
+            poseidon2_hash_pair::<BabyBear>(&left, &right),
+            expected.map(|f| f.as_canonical_u32())
+        );
+    }
+
+    #[test]
+    fn test_hash_bytes_matches_config_hasher() {
+        let data = b"the records of a zktls attestation";
+        let hasher = PaddingFreeSponge::<_, 16, 8, 8>::new(BabyBear::init());
+        let len = BabyBear::from_canonical_usize(data.len());
+        let packed = data.chunks(3).map(|chunk| {
+            let mut word = [0u8; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            BabyBear::from_canonical_u32(u32::from_le_bytes(word))
+        });
+        let expected = hasher.hash_iter(core::iter::once(len).chain(packed));
+        assert_eq!(Poseidon2::<BabyBear>::hash_bytes(data), expected);
+    }
+}
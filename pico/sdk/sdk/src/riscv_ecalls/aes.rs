@@ -0,0 +1,73 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Encrypts the 16-byte block at `block` in place with AES-128 under the 16-byte key at `key`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `block` and `key` point to four words each and that both are
+/// aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes128_encrypt_block(block: *mut [u32; 4], key: *const [u32; 4]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::AES128_ENCRYPT_BLOCK,
+            in("a0") block,
+            in("a1") key,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Expands the 32-byte AES-256 key at `key` into the 240-byte key schedule at `schedule`. The
+/// schedule starts with the key, so `schedule` may point at the key itself.
+///
+/// ### Safety
+///
+/// The caller must ensure that `key` points to eight words, that `schedule` points to sixty
+/// words, and that both are aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes_key_schedule(key: *const [u32; 8], schedule: *mut [u32; 60]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::AES_KEY_SCHEDULE,
+            in("a0") key,
+            in("a1") schedule,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Encrypts the 16-byte block at `block` in place with AES-256, under the key schedule at
+/// `schedule` written by [`syscall_aes_key_schedule`].
+///
+/// ### Safety
+///
+/// The caller must ensure that `block` points to four words, that `schedule` points to sixty
+/// words, and that both are aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes256_encrypt_block(block: *mut [u32; 4], schedule: *const [u32; 60]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::AES256_ENCRYPT_BLOCK,
+            in("a0") block,
+            in("a1") schedule,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
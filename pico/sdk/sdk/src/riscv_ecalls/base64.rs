@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Decodes a length-prefixed base64 text and writes the decoded length and bytes to `output`.
+///
+/// `input` points to a word holding the text length in bytes, followed by the text in the
+/// standard or URL-safe alphabet. `output` receives the decoded length, followed by the decoded
+/// bytes, zero-padded to a whole word.
+///
+/// ### Safety
+///
+/// The caller must ensure that `input` points to the length word followed by at least that many
+/// bytes, that the length is a multiple of 4 and below 2^24, that `output` has room for
+/// `1 + ceil(3 * len / 16)` words, and that both are aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_base64_decode(input: *const u32, output: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::BASE64_DECODE,
+            in("a0") input,
+            in("a1") output,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -1,4 +1,7 @@
-use super::syscall_uint256_mulmod;
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+use super::{memory::sys_alloc_aligned, syscall_uint256_mulmod};
 
 /// The number of limbs in a "uint256".
 const N: usize = 8;
@@ -46,3 +49,251 @@ pub extern "C" fn sys_bigint(
         syscall_uint256_mulmod(result_ptr, concat_ptr);
     }
 }
+
+/// Writes a square root of `a` modulo `p` to the 8 words at `out`, taking `[a, p]` as 16 words at
+/// `a_and_p`. Returns 1 if a root was found and 0 otherwise.
+///
+/// The result comes from the emulator and is not constrained, use [`sys_bigint_sqrt`] instead.
+///
+/// ### Safety
+///
+/// The caller must ensure that both pointers are aligned along a four byte boundary, and that
+/// `out` points to freshly allocated memory that was never written.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bigint_sqrt(a_and_p: *const [u32; N * 2], out: *mut [u32; N]) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let found;
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::BIGINT_SQRT,
+            in("a0") a_and_p,
+            in("a1") out,
+            lateout("t0") found,
+        );
+        found
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Sets `out` to the smaller square root of `a` modulo the prime `p`, and returns 1, or returns 0
+/// and zeroes `out` if `a` is not a square. All three are `len` bytes in little endian, with `len`
+/// at most 32.
+///
+/// The root is computed by the emulator and checked here, `out <= (p - 1) / 2` and
+/// `out^2 = a (mod p)` with `UINT256_MUL`. When no root is reported, `a^((p - 1) / 2) = -1 (mod p)`
+/// is checked instead, so a wrong answer from the emulator panics. Both checks assume that `p` is
+/// an odd prime.
+///
+/// ### Safety
+///
+/// The caller must ensure that `a`, `p` and `out` are valid pointers to `len` bytes.
+#[no_mangle]
+pub extern "C" fn sys_bigint_sqrt(a: *const u8, p: *const u8, len: usize, out: *mut u8) -> u8 {
+    assert!(
+        len <= N * 4,
+        "bigint sqrt operands are longer than 32 bytes"
+    );
+
+    let mut a_and_p = [0u32; N * 2];
+    unsafe {
+        let bytes = a_and_p.as_mut_ptr() as *mut u8;
+        core::ptr::copy_nonoverlapping(a, bytes, len);
+        core::ptr::copy_nonoverlapping(p, bytes.add(N * 4), len);
+    }
+    let modulus: [u32; N] = a_and_p[N..].try_into().unwrap();
+    let mut a: [u32; N] = a_and_p[..N].try_into().unwrap();
+    // Reduce `a` by multiplying it with one, since the root is only checked modulo `p`.
+    a = mul_mod(&a, &[1, 0, 0, 0, 0, 0, 0, 0], &modulus);
+
+    // The emulator writes the root as the initial value of its memory, so it must be fresh.
+    let root = unsafe {
+        let root = sys_alloc_aligned(N * 4, 4) as *mut [u32; N];
+        let found = syscall_bigint_sqrt(&a_and_p, root);
+        (found == 1).then(|| *root)
+    };
+
+    let minus_one = sub_one(&modulus);
+    let half: [u32; N] = core::array::from_fn(|i| {
+        (minus_one[i] >> 1) | minus_one.get(i + 1).map_or(0, |next| next << 31)
+    });
+    let result = match root {
+        Some(root) => {
+            // For an odd `p`, this is `root <= p - root`, which also makes the root reduced.
+            assert!(!lt(&half, &root), "bigint sqrt root is not the smaller one");
+            assert!(
+                mul_mod(&root, &root, &modulus) == a,
+                "bigint sqrt root is wrong"
+            );
+            root
+        }
+        None => {
+            assert!(
+                pow_mod(&a, &half, &modulus) == minus_one,
+                "bigint sqrt reported no root for a square"
+            );
+            [0; N]
+        }
+    };
+    unsafe {
+        core::ptr::copy_nonoverlapping(result.as_ptr() as *const u8, out, len);
+    }
+    root.is_some() as u8
+}
+
+/// Writes the quotient of the division of `n` by `d` to the 8 words at `out`, taking `[n, d]` as
+/// 16 words at `n_and_d`. The quotient of a division by zero is `2^256 - 1`.
+///
+/// The result comes from the emulator and is not constrained, use [`sys_bigint_divrem`] instead.
+///
+/// ### Safety
+///
+/// The caller must ensure that both pointers are aligned along a four byte boundary, and that
+/// `out` points to freshly allocated memory that was never written.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_div(n_and_d: *const [u32; N * 2], out: *mut [u32; N]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::UINT256_DIV,
+            in("a0") n_and_d,
+            in("a1") out,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Writes the remainder of the division of `n` by `d` to the 8 words at `out`, taking `[n, d]`
+/// as 16 words at `n_and_d`. The remainder of a division by zero is `n`.
+///
+/// The result comes from the emulator and is not constrained, use [`sys_bigint_divrem`] instead.
+///
+/// ### Safety
+///
+/// The caller must ensure that both pointers are aligned along a four byte boundary, and that
+/// `out` points to freshly allocated memory that was never written.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_rem(n_and_d: *const [u32; N * 2], out: *mut [u32; N]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::UINT256_REM,
+            in("a0") n_and_d,
+            in("a1") out,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Sets `quotient` and `remainder` to the results of the division of `n` by the nonzero `d`.
+///
+/// Both are computed by the emulator and checked here with `UINT256_MUL`. The remainder must be
+/// `n mod d`, and the quotient must satisfy `quotient * d + remainder = n` modulo `2^256`. With
+/// `2^k` the largest power of two dividing `d`, the quotient is below `2^(256 - k)`, and the
+/// equation has a single solution in that range, so a wrong answer from the emulator panics.
+#[no_mangle]
+pub extern "C" fn sys_bigint_divrem(
+    quotient: *mut [u32; N],
+    remainder: *mut [u32; N],
+    n: *const [u32; N],
+    d: *const [u32; N],
+) {
+    let mut n_and_d = [0u32; N * 2];
+    unsafe {
+        core::ptr::copy_nonoverlapping(n as *const u32, n_and_d.as_mut_ptr(), N);
+        core::ptr::copy_nonoverlapping(d as *const u32, n_and_d.as_mut_ptr().add(N), N);
+    }
+    let n: [u32; N] = n_and_d[..N].try_into().unwrap();
+    let d: [u32; N] = n_and_d[N..].try_into().unwrap();
+    assert!(d != [0; N], "bigint division by zero");
+
+    // The emulator writes the results as the initial value of their memory, so it must be fresh.
+    let (q, r) = unsafe {
+        let q = sys_alloc_aligned(N * 4, 4) as *mut [u32; N];
+        let r = sys_alloc_aligned(N * 4, 4) as *mut [u32; N];
+        syscall_uint256_div(&n_and_d, q);
+        syscall_uint256_rem(&n_and_d, r);
+        (*q, *r)
+    };
+
+    assert!(
+        mul_mod(&n, &[1, 0, 0, 0, 0, 0, 0, 0], &d) == r,
+        "bigint division remainder is wrong"
+    );
+    let k = d
+        .iter()
+        .enumerate()
+        .find(|(_, &word)| word != 0)
+        .map(|(i, word)| 32 * i + word.trailing_zeros() as usize)
+        .unwrap();
+    assert!(
+        q.iter().enumerate().all(|(i, &word)| {
+            let bits = (N * 32 - k).saturating_sub(32 * i).min(32);
+            bits == 32 || word >> bits == 0
+        }),
+        "bigint division quotient is too large"
+    );
+    let product = mul_mod(&q, &d, &[0; N]);
+    assert!(add(&product, &r) == n, "bigint division quotient is wrong");
+
+    unsafe {
+        core::ptr::copy_nonoverlapping(q.as_ptr(), quotient as *mut u32, N);
+        core::ptr::copy_nonoverlapping(r.as_ptr(), remainder as *mut u32, N);
+    }
+}
+
+fn add(a: &[u32; N], b: &[u32; N]) -> [u32; N] {
+    let mut result = [0u32; N];
+    let mut carry = false;
+    for ((sum, a), b) in result.iter_mut().zip(a).zip(b) {
+        let (partial, c1) = a.overflowing_add(*b);
+        let (total, c2) = partial.overflowing_add(carry as u32);
+        *sum = total;
+        carry = c1 || c2;
+    }
+    result
+}
+
+fn lt(a: &[u32; N], b: &[u32; N]) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+fn sub_one(a: &[u32; N]) -> [u32; N] {
+    let mut result = *a;
+    for word in result.iter_mut() {
+        let (diff, borrow) = word.overflowing_sub(1);
+        *word = diff;
+        if !borrow {
+            break;
+        }
+    }
+    result
+}
+
+fn mul_mod(x: &[u32; N], y: &[u32; N], modulus: &[u32; N]) -> [u32; N] {
+    let mut result = [0u32; N];
+    sys_bigint(&mut result, 0, x, y, modulus);
+    result
+}
+
+fn pow_mod(a: &[u32; N], exp: &[u32; N], modulus: &[u32; N]) -> [u32; N] {
+    let mut result = [1, 0, 0, 0, 0, 0, 0, 0];
+    for i in (0..N * 32).rev() {
+        result = mul_mod(&result, &result, modulus);
+        if (exp[i / 32] >> (i % 32)) & 1 == 1 {
+            result = mul_mod(&result, a, modulus);
+        }
+    }
+    result
+}
@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Continues the CRC-32 checksum at `crc` over the first `len` bytes at `data`, replacing it with
+/// the result. A checksum of 0 starts a new one.
+///
+/// ### Safety
+///
+/// The caller must ensure that `data` points to `len.div_ceil(4)` words, that `crc` is a valid
+/// pointer, that both are aligned along a four byte boundary, and that `len` is between 1 and
+/// 4096.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_crc32(data: *const u32, len: u32, crc: *mut u32) {
+    // The precompile only takes two arguments, so the remaining ones are passed through memory.
+    let args: [u32; 2] = [len, crc as u32];
+
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::CRC32,
+            in("a0") data,
+            in("a1") args.as_ptr(),
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Compares the `num_words` words at `a` and `b`, writing 1 to `result` if they are equal and 0
+/// otherwise. Every word is compared, regardless of where the first difference occurs.
+///
+/// ### Safety
+///
+/// The caller must ensure that `a` and `b` point to `num_words` words, that `result` is a valid
+/// pointer, that all of them are aligned along a four byte boundary, and that `num_words` is
+/// between 1 and 2^20.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_ct_eq(a: *const u32, b: *const u32, num_words: u32, result: *mut u32) {
+    // The precompile only takes two arguments, so the remaining ones are passed through memory.
+    let args: [u32; 3] = [b as u32, num_words, result as u32];
+
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::CT_EQ,
+            in("a0") a,
+            in("a1") args.as_ptr(),
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
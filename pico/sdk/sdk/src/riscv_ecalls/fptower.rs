@@ -61,6 +61,47 @@ pub extern "C" fn syscall_bls12381_fp_mulmod(x: *mut u32, y: *const u32) {
     unreachable!()
 }
 
+/// Fp inversion operation.
+///
+/// Writes `a^{-1} mod p` to `result`; `a` must be non-zero. The two pointers may be equal.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_fp_inv(a: *const u32, result: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::BLS12381_FP_INV,
+            in("a0") a,
+            in("a1") result,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Fp batch inversion operation.
+///
+/// Replaces each of the `len` consecutive field elements at `a` with its inverse. Every element
+/// must be non-zero.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_fp_batch_inv(a: *mut u32, len: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::BLS12381_FP_BATCH_INV,
+            in("a0") a,
+            in("a1") len,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
 /// BLS12-381 Fp2 addition operation.
 ///
 /// The result is written over the first input.
@@ -181,6 +222,47 @@ pub extern "C" fn syscall_bn254_fp_mulmod(x: *mut u32, y: *const u32) {
     unreachable!()
 }
 
+/// Fp inversion operation.
+///
+/// Writes `a^{-1} mod p` to `result`; `a` must be non-zero. The two pointers may be equal.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_fp_inv(a: *const u32, result: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::BN254_FP_INV,
+            in("a0") a,
+            in("a1") result,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Fp batch inversion operation.
+///
+/// Replaces each of the `len` consecutive field elements at `a` with its inverse. Every element
+/// must be non-zero.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_fp_batch_inv(a: *mut u32, len: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::BN254_FP_BATCH_INV,
+            in("a0") a,
+            in("a1") len,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
 /// BN254 Fp2 addition operation.
 ///
 /// The result is written over the first input.
@@ -300,3 +382,44 @@ pub extern "C" fn syscall_secp256k1_fp_mulmod(x: *mut u32, y: *const u32) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Fp inversion operation.
+///
+/// Writes `a^{-1} mod p` to `result`; `a` must be non-zero. The two pointers may be equal.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_fp_inv(a: *const u32, result: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::SECP256K1_FP_INV,
+            in("a0") a,
+            in("a1") result,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Fp batch inversion operation.
+///
+/// Replaces each of the `len` consecutive field elements at `a` with its inverse. Every element
+/// must be non-zero.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_fp_batch_inv(a: *mut u32, len: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::SECP256K1_FP_BATCH_INV,
+            in("a0") a,
+            in("a1") len,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -45,6 +45,61 @@ pub extern "C" fn syscall_write(fd: u32, write_buf: *const u8, nbytes: usize) {
     }
 }
 
+/// Commits `len` bytes of UTF-8 from `ptr` to the public values stream, prefixed with `len` as a
+/// little-endian `u32`.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_commit_str(ptr: *const u8, len: usize) {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "zkvm")] {
+            unsafe {
+                asm!(
+                    "ecall",
+                    in("t0") crate::riscv_ecalls::STRING_COMMIT,
+                    in("a0") ptr,
+                    in("a1") len,
+                );
+            }
+
+            // The emulator appends the length prefix as well, so the digest must cover it too.
+            let bytes: &[u8] = unsafe { core::slice::from_raw_parts(ptr, len) };
+            #[allow(static_mut_refs)]
+            unsafe {
+                let hasher = zkvm::PUBLIC_VALUES_HASHER.as_mut().unwrap();
+                hasher.update(&(len as u32).to_le_bytes());
+                hasher.update(bytes);
+            }
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+/// Commits `len` words from `ptr` to the public values stream, each as 4 little-endian bytes.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_commit_array(ptr: *const u32, len: usize) {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "zkvm")] {
+            unsafe {
+                asm!(
+                    "ecall",
+                    in("t0") crate::riscv_ecalls::COMMIT_ARRAY,
+                    in("a0") ptr,
+                    in("a1") len,
+                );
+            }
+
+            // RISC-V is little-endian, so the words are already laid out as the committed bytes.
+            let bytes: &[u8] = unsafe { core::slice::from_raw_parts(ptr as *const u8, 4 * len) };
+            #[allow(static_mut_refs)]
+            unsafe { zkvm::PUBLIC_VALUES_HASHER.as_mut().unwrap().update(bytes) };
+        } else {
+            unreachable!()
+        }
+    }
+}
+
 /// Returns the length of the next element in the hint stream.
 #[allow(unused_variables)]
 #[no_mangle]
@@ -76,6 +131,153 @@ pub extern "C" fn syscall_hint_read(ptr: *mut u8, len: usize) {
             in("a0") ptr,
             in("a1") len,
         );
+
+        // Every input read goes through here, so the running input digest is updated here too,
+        // each input prefixed with its length.
+        #[cfg(feature = "input-digest")]
+        {
+            let input: &[u8] = core::slice::from_raw_parts(ptr, len);
+            #[allow(static_mut_refs)]
+            let hasher = zkvm::INPUT_HASHER.as_mut().unwrap();
+            hasher.update(&(len as u32).to_le_bytes());
+            hasher.update(input);
+        }
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Writes the verifying key digest of the running program into the given 8-word buffer.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_self_vk_digest(ptr: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::SELF_VK_DIGEST,
+            in("a0") ptr,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Returns the length of the program arguments, encoded as `ARGS_READ` writes them.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_args_len() -> usize {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let len;
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::ARGS_LEN,
+            lateout("t0") len,
+        );
+        len
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Reads the program arguments into the given buffer, each as its length as a little endian u32
+/// followed by its bytes.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_args_read(ptr: *mut u8, len: usize) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::ARGS_READ,
+            in("a0") ptr,
+            in("a1") len,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Returns the low word of the fuel left if `word` is 0, or the high word if it is 1.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_fuel_remaining(word: u32) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let fuel;
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::FUEL_REMAINING,
+            in("a0") word,
+            lateout("t0") fuel,
+        );
+        fuel
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Returns the low word of the cycles run so far if `word` is 0, or the high word if it is 1.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_cycle_count(word: u32) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let cycles;
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::CYCLE_COUNT,
+            in("a0") word,
+            lateout("t0") cycles,
+        );
+        cycles
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Asks the host to run the handler registered for `tag` on the arguments described by the two
+/// words `[args_ptr, args_len]` at `args`, and returns the length of the response.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_host_call(tag: u32, args: *const [u32; 2]) -> usize {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let len;
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::HOST_CALL,
+            in("a0") tag,
+            in("a1") args,
+            lateout("t0") len,
+        );
+        len
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Reads the response of the last host call into the given buffer.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_host_call_read(ptr: *mut u8, len: usize) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::HOST_CALL_READ,
+            in("a0") ptr,
+            in("a1") len,
+        );
     }
 
     #[cfg(not(target_os = "zkvm"))]
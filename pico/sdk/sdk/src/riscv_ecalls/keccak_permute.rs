@@ -23,3 +23,50 @@ pub extern "C" fn syscall_keccak_permute(state: *mut [u64; 25]) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// XORs a block into the first 17 lanes, the rate of Keccak-256, of the given state and executes
+/// the Keccak256 permutation on it.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `block` are valid pointers to data that is aligned
+/// along a four byte boundary, and that they do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_keccak_absorb(state: *mut [u64; 25], block: *const [u64; 17]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::KECCAK_ABSORB,
+            in("a0") state,
+            in("a1") block
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Copies the first 17 lanes, the rate of Keccak-256, of the given state to `out`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `out` are valid pointers to data that is aligned along
+/// a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_keccak_squeeze(state: *const [u64; 25], out: *mut [u64; 17]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::KECCAK_SQUEEZE,
+            in("a0") state,
+            in("a1") out
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -15,6 +15,14 @@
 // Memory addresses must be lower than BabyBear prime.
 const MAX_MEMORY: usize = 0x78000000;
 
+// Pointer to next heap address to use, or 0 if the heap has not yet been
+// initialized.
+static mut HEAP_POS: usize = 0;
+
+// The highest heap address reached before the heap was last moved back by `arena_scope`, or 0.
+// The program has never accessed the memory from here on.
+static mut HEAP_FRESH: usize = 0;
+
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u8 {
@@ -23,10 +31,6 @@ pub unsafe extern "C" fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u
         static _end: u8;
     }
 
-    // Pointer to next heap address to use, or 0 if the heap has not yet been
-    // initialized.
-    static mut HEAP_POS: usize = 0;
-
     // SAFETY: Single threaded, so nothing else can touch this while we're working.
     let mut heap_pos = unsafe { HEAP_POS };
 
@@ -49,3 +53,31 @@ pub unsafe extern "C" fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u
     unsafe { HEAP_POS = heap_pos };
     ptr
 }
+
+/// Returns the address the next allocation will start from, or 0 if the heap has not yet been
+/// initialized.
+pub(crate) fn heap_pos() -> usize {
+    // SAFETY: Single threaded, so nothing else can touch this while we're working.
+    unsafe { HEAP_POS }
+}
+
+/// Moves the next heap address back to `pos`, as returned by [`heap_pos`].
+///
+/// ### Safety
+///
+/// Nothing allocated at or above `pos` may be used afterwards.
+pub(crate) unsafe fn set_heap_pos(pos: usize) {
+    unsafe {
+        HEAP_FRESH = HEAP_FRESH.max(HEAP_POS);
+        HEAP_POS = pos;
+    }
+}
+
+/// Moves the next heap address past all memory reclaimed by `arena_scope`, so that the next
+/// allocation is in memory the program has never accessed. `HINT_READ` and the other syscalls
+/// that place an input in memory require it. Outside a scope the heap is already there.
+#[no_mangle]
+pub extern "C" fn sys_skip_reclaimed_heap() {
+    // SAFETY: Single threaded, so nothing else can touch this while we're working.
+    unsafe { HEAP_POS = HEAP_POS.max(HEAP_FRESH) };
+}
@@ -1,24 +1,36 @@
+mod aes;
+mod base64;
 mod bigint;
 mod bls12381;
 mod bn254;
+mod crc32;
+mod ct_eq;
 mod ed25519;
 mod fptower;
 mod halt;
 mod io;
 mod keccak_permute;
 mod memory;
+mod poly_eval;
 mod poseidon2;
+mod prefetch;
+mod range_check;
+mod rlp;
 mod secp256k1;
+mod sha256;
 mod sha_compress;
 mod sha_extend;
+mod sort;
 mod sys;
 mod uint256_mul;
 mod unconstrained;
 
 pub use halt::*;
 pub use io::*;
+pub(crate) use memory::{heap_pos, set_heap_pos};
 pub use sys::*;
 pub use uint256_mul::*;
+pub use unconstrained::is_unconstrained;
 
 // These codes MUST match the codes in `core/src/runtime/syscall.rs`. There is a derived test
 // that checks that the enum is consistent with the syscalls.
@@ -68,12 +80,51 @@ pub const BN254_DOUBLE: u32 = 0x00_00_01_0F;
 /// Executes the `COMMIT` precompile.
 pub const COMMIT: u32 = 0x00_00_00_10;
 
+/// Executes `STRING_COMMIT`.
+pub const STRING_COMMIT: u32 = 0x00_00_00_11;
+
+/// Executes `COMMIT_ARRAY`.
+pub const COMMIT_ARRAY: u32 = 0x00_00_00_12;
+
 /// Executes `HINT_LEN`.
 pub const HINT_LEN: u32 = 0x00_00_00_F0;
 
 /// Executes `HINT_READ`.
 pub const HINT_READ: u32 = 0x00_00_00_F1;
 
+/// Executes `SELF_VK_DIGEST`.
+pub const SELF_VK_DIGEST: u32 = 0x00_00_00_F2;
+
+/// Executes `MEMORY_PREFETCH`.
+pub const MEMORY_PREFETCH: u32 = 0x00_00_00_F4;
+
+/// Executes `ARGS_LEN`.
+pub const ARGS_LEN: u32 = 0x00_00_00_F6;
+
+/// Executes `ARGS_READ`.
+pub const ARGS_READ: u32 = 0x00_00_00_F7;
+
+/// Executes `FUEL_REMAINING`.
+pub const FUEL_REMAINING: u32 = 0x00_00_00_F8;
+
+/// Executes `BIGINT_SQRT`.
+pub const BIGINT_SQRT: u32 = 0x00_00_00_F9;
+
+/// Executes `UINT256_DIV`.
+pub const UINT256_DIV: u32 = 0x00_00_00_FA;
+
+/// Executes `UINT256_REM`.
+pub const UINT256_REM: u32 = 0x00_00_00_FB;
+
+/// Executes `HOST_CALL`.
+pub const HOST_CALL: u32 = 0x00_00_00_FC;
+
+/// Executes `HOST_CALL_READ`.
+pub const HOST_CALL_READ: u32 = 0x00_00_00_FD;
+
+/// Executes `CYCLE_COUNT`.
+pub const CYCLE_COUNT: u32 = 0x00_00_00_FE;
+
 /// Executes `BLS12381_DECOMPRESS`.
 pub const BLS12381_DECOMPRESS: u32 = 0x00_00_01_1C;
 
@@ -131,5 +182,71 @@ pub const SECP256K1_FP_SUB: u32 = 0x00_01_01_2D;
 /// Executes the `BLS12381_FP_MUL` precompile.
 pub const SECP256K1_FP_MUL: u32 = 0x00_01_01_2E;
 
+/// Executes the `SECP256K1_FP_INV` precompile.
+pub const SECP256K1_FP_INV: u32 = 0x00_01_01_45;
+
+/// Executes the `SECP256K1_FP_BATCH_INV` precompile.
+pub const SECP256K1_FP_BATCH_INV: u32 = 0x00_01_01_46;
+
 /// Executes the `POSEIDON2_PERMUTE` precompile.
 pub const POSEIDON2_PERMUTE: u32 = 0x00_01_01_2F;
+
+/// Executes the `POLY_EVAL` precompile.
+pub const POLY_EVAL: u32 = 0x00_01_01_30;
+
+/// Executes the `CT_EQ` precompile.
+pub const CT_EQ: u32 = 0x00_01_01_31;
+
+/// Executes the `BN254_FP_INV` precompile.
+pub const BN254_FP_INV: u32 = 0x00_01_01_32;
+
+/// Executes the `BLS12381_FP_INV` precompile.
+pub const BLS12381_FP_INV: u32 = 0x00_01_01_33;
+
+/// Executes the `SHA256` precompile.
+pub const SHA256: u32 = 0x00_01_01_34;
+
+/// Executes the `SORT_U32` precompile.
+pub const SORT_U32: u32 = 0x00_01_01_35;
+
+/// Executes the `AES128_ENCRYPT_BLOCK` precompile.
+pub const AES128_ENCRYPT_BLOCK: u32 = 0x00_01_01_36;
+
+/// Executes the `RLP_DECODE_ITEM` precompile.
+pub const RLP_DECODE_ITEM: u32 = 0x00_01_01_37;
+
+/// Executes the `BASE64_DECODE` precompile.
+pub const BASE64_DECODE: u32 = 0x00_01_01_38;
+
+/// Executes the `BN254_FP_BATCH_INV` precompile.
+pub const BN254_FP_BATCH_INV: u32 = 0x00_01_01_3A;
+
+/// Executes the `BLS12381_FP_BATCH_INV` precompile.
+pub const BLS12381_FP_BATCH_INV: u32 = 0x00_01_01_3B;
+
+/// Executes the `KECCAK_ABSORB` precompile.
+pub const KECCAK_ABSORB: u32 = 0x00_01_01_3C;
+
+/// Executes the `KECCAK_SQUEEZE` precompile.
+pub const KECCAK_SQUEEZE: u32 = 0x00_01_01_3D;
+
+/// Executes the `AES_KEY_SCHEDULE` precompile.
+pub const AES_KEY_SCHEDULE: u32 = 0x00_01_01_3E;
+
+/// Executes the `AES256_ENCRYPT_BLOCK` precompile.
+pub const AES256_ENCRYPT_BLOCK: u32 = 0x00_01_01_3F;
+
+/// Executes the `POSEIDON2_COMPRESS` precompile.
+pub const POSEIDON2_COMPRESS: u32 = 0x00_01_01_40;
+
+/// Executes the `RANGE_CHECK_BATCH` precompile.
+pub const RANGE_CHECK_BATCH: u32 = 0x00_01_01_41;
+
+/// Executes the `FIELD_SORT` precompile.
+pub const FIELD_SORT: u32 = 0x00_01_01_42;
+
+/// Executes the `CRC32` precompile.
+pub const CRC32: u32 = 0x00_01_01_43;
+
+/// Executes the `MULTI_HASH` precompile.
+pub const MULTI_HASH: u32 = 0x00_01_01_44;
@@ -0,0 +1,38 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+use core::mem::MaybeUninit;
+
+/// Evaluates the polynomial `coeffs[0] + coeffs[1] * x + ... + coeffs[degree] * x^degree` at the
+/// point `x = *point` over the prime field of the prover, using Horner's method.
+///
+/// The result is written to `result`, which does not need to be initialized beforehand.
+///
+/// ### Safety
+///
+/// The caller must ensure that `coeffs` points to `degree + 1` words, that `point` and `result`
+/// are valid pointers, that all of them are aligned along a four byte boundary, and that the
+/// coefficients, the point and the result do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_poly_eval(
+    coeffs: *const u32,
+    degree: u32,
+    point: *const u32,
+    result: *mut MaybeUninit<u32>,
+) {
+    // The precompile only takes two arguments, so the remaining ones are passed through memory.
+    let args: [u32; 3] = [degree, point as u32, result as u32];
+
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::POLY_EVAL,
+            in("a0") coeffs,
+            in("a1") args.as_ptr(),
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
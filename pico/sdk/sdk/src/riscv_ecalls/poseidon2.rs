@@ -25,3 +25,29 @@ pub extern "C" fn syscall_poseidon2_permute(x: *const [u32; 16], y: *mut [u32; 1
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Compresses the two digests `[left, right]` at `x` into the first 8 elements of their Poseidon2
+/// permutation, written to `y`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_poseidon2_compress(x: *const [u32; 16], y: *mut [u32; 8]) {
+    let syscall_id = crate::riscv_ecalls::POSEIDON2_COMPRESS;
+
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") syscall_id,
+            in("a0") x,
+            in("a1") y
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
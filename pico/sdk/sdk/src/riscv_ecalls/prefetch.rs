@@ -0,0 +1,21 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Hints the emulator that the bytes in `[start, end)` are about to be accessed, so that it can
+/// reserve room for them up front. This has no effect on the program or on the proof.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_memory_prefetch(start: *const u8, end: *const u8) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::MEMORY_PREFETCH,
+            in("a0") start,
+            in("a1") end,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Checks that every one of the `n` words at `values` fits in `bits` bits.
+///
+/// ### Safety
+///
+/// The caller must ensure that `values` points to `n` words, that it is aligned along a four byte
+/// boundary, that `n` is at least 1 and that `bits` is at most 32.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_range_check_batch(values: *const u32, n: u32, bits: u32) {
+    // The precompile only takes two arguments, so the remaining ones are passed through memory.
+    let args: [u32; 2] = [n, bits];
+
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::RANGE_CHECK_BATCH,
+            in("a0") values,
+            in("a1") args.as_ptr(),
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,26 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Decodes the header of the RLP item starting at `input` and writes
+/// `[header_len, payload_len, is_list]` to `output`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `input` points to a complete RLP header and that `output` points
+/// to three words aligned along a four byte boundary. `input` may have any alignment.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_rlp_decode_item(input: *const u8, output: *mut [u32; 3]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::RLP_DECODE_ITEM,
+            in("a0") input,
+            in("a1") output,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
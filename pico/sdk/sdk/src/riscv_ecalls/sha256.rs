@@ -0,0 +1,57 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Computes the SHA-256 digest of a length-prefixed buffer and writes it to `digest`.
+///
+/// `input` points to a word holding the message length in bytes, followed by the message. The
+/// digest bytes are written in the standard big-endian order.
+///
+/// ### Safety
+///
+/// The caller must ensure that `input` points to the length word followed by at least that many
+/// bytes, that `digest` is a valid pointer, that both are aligned along a four byte boundary, and
+/// that the length is below 2^24.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_sha256(input: *const u32, digest: *mut [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::SHA256,
+            in("a0") input,
+            in("a1") digest,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Computes several independent digests in one call.
+///
+/// `descriptors` points to the number of digests `n`, followed by `n` pairs of an input and a
+/// digest pointer, each laid out as for [`syscall_sha256`]. `hash_type` selects the hash
+/// function; only SHA-256 (`0`) is supported.
+///
+/// ### Safety
+///
+/// The caller must ensure that every pair satisfies the requirements of [`syscall_sha256`], that
+/// `n` is between 1 and 32, and that the pairs share no memory with each other or with the
+/// descriptors.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_multi_hash(hash_type: u32, descriptors: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::MULTI_HASH,
+            in("a0") hash_type,
+            in("a1") descriptors,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
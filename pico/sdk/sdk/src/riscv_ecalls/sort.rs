@@ -0,0 +1,48 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Sorts the `len` words at `values` in place, in ascending order.
+///
+/// ### Safety
+///
+/// The caller must ensure that `values` points to `len` words, that it is aligned along a four
+/// byte boundary, and that `len` is between 1 and 256.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_sort_u32(values: *mut u32, len: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::SORT_U32,
+            in("a0") values,
+            in("a1") len,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Sorts the `len` canonical field elements at `values` in place, in ascending order.
+///
+/// ### Safety
+///
+/// The caller must ensure that `values` points to `len` words, that it is aligned along a four
+/// byte boundary, that `len` is between 1 and 256, and that every word is below the field order.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_field_sort(values: *mut u32, len: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::riscv_ecalls::FIELD_SORT,
+            in("a0") values,
+            in("a1") len,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
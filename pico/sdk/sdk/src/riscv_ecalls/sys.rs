@@ -3,7 +3,9 @@ use std::sync::Mutex;
 use lazy_static::lazy_static;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
-use crate::riscv_ecalls::{syscall_halt, syscall_write};
+use crate::riscv_ecalls::{
+    is_unconstrained, syscall_halt, syscall_write, unconstrained::syscall_exit_unconstrained,
+};
 
 /// The random number generator seed for the zkVM.
 ///
@@ -39,6 +41,11 @@ pub unsafe extern "C" fn sys_rand(recv_buf: *mut u8, words: usize) {
 #[no_mangle]
 pub unsafe extern "C" fn sys_panic(msg_ptr: *const u8, len: usize) -> ! {
     sys_write(2, msg_ptr, len);
+    // Guests are built with `panic=abort`, so a panic inside an unconstrained block never reaches
+    // the block's exit. Leave the block here instead of halting in unconstrained mode.
+    if is_unconstrained() {
+        syscall_exit_unconstrained();
+    }
     syscall_halt(1);
 }
 
@@ -1,5 +1,15 @@
 #[cfg(target_os = "zkvm")]
 use core::arch::asm;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Set while an unconstrained block runs. Memory writes made inside the block are rolled back
+/// when it exits, so in the VM this never stays set past the block.
+static IN_UNCONSTRAINED: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether the program is currently inside an unconstrained block.
+pub fn is_unconstrained() -> bool {
+    IN_UNCONSTRAINED.load(Ordering::Relaxed)
+}
 
 #[no_mangle]
 pub fn syscall_enter_unconstrained() -> bool {
@@ -20,11 +30,17 @@ pub fn syscall_enter_unconstrained() -> bool {
         continue_unconstrained = 1;
     }
 
-    continue_unconstrained == 1
+    let continue_unconstrained = continue_unconstrained == 1;
+    if continue_unconstrained {
+        IN_UNCONSTRAINED.store(true, Ordering::Relaxed);
+    }
+    continue_unconstrained
 }
 
 #[no_mangle]
 pub fn syscall_exit_unconstrained() {
+    IN_UNCONSTRAINED.store(false, Ordering::Relaxed);
+
     #[cfg(target_os = "zkvm")]
     unsafe {
         asm!(
@@ -14,16 +14,12 @@ use pico_vm::{
     instances::{
         chiptype::recursion_chiptype::RecursionChipType,
         compiler::{
-            onchain_circuit::{
-                gnark::builder::OnchainVerifierCircuit,
-                stdin::OnchainStdin,
-                utils::{build_gnark_config, generate_contract_inputs, save_embed_proof_data},
-            },
+            onchain_circuit::utils::{generate_contract_inputs, save_embed_proof_data},
             shapes::{recursion_shape::RecursionShapeConfig, riscv_shape::RiscvShapeConfig},
         },
         configs::{embed_config::BabyBearBn254Poseidon2, embed_kb_config::KoalaBearBn254Poseidon2},
     },
-    machine::{machine::MachineBehavior, proof::MetaProof},
+    machine::proof::MetaProof,
     proverchain::{
         CombineVkProver, CompressVkProver, ConvertProver, EmbedVkProver, InitialProverSetup,
         MachineProver, ProverChain, RiscvProver,
@@ -118,19 +114,18 @@ macro_rules! create_sdk_prove_vk_client {
                     return Err(Error::msg("verify embed proof failed"));
                 }
 
-                let onchain_stdin = OnchainStdin {
-                    machine: self.embed.machine.base_machine().clone(),
-                    vk: proof.vks().first().unwrap().clone(),
-                    proof: proof.proofs().first().unwrap().clone(),
-                    flag_complete: true,
-                };
-                let (constraints, witness) =
-                    OnchainVerifierCircuit::<$fc, $bn254_sc>::build(&onchain_stdin);
+                let gnark_inputs = self.embed.export_gnark(&proof);
                 save_embed_proof_data(&riscv_proof, &proof, output.clone())?;
-                build_gnark_config(constraints, witness, output.clone());
+                gnark_inputs.save(&output)?;
                 Ok((riscv_proof, proof))
             }
 
+            /// emulate the riscv program without proving and return the number of chunks it splits into
+            pub fn estimate_chunks(&self) -> usize {
+                let stdin = self.stdin_builder.borrow().clone().finalize();
+                self.riscv.estimate_chunk_count(&stdin)
+            }
+
             /// prove and verify riscv program. default not include convert, combine, compress, embed
             pub fn prove_fast(&self) -> Result<MetaProof<$sc>, Error> {
                 let stdin = self.stdin_builder.borrow().clone().finalize();
@@ -0,0 +1,300 @@
+use crate::prover;
+use axum::{
+    extract::{DefaultBodyLimit, Request, State},
+    http::{header::AUTHORIZATION, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use tokio::sync::Semaphore;
+use utoipa::{
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi, ToSchema,
+};
+
+/// State shared by all request handlers.
+#[derive(Clone)]
+pub struct AppState {
+    /// Permits for running proofs; requests beyond the limit wait for one in arrival order.
+    pub provers: Arc<Semaphore>,
+    /// The token clients must present as `Authorization: Bearer <token>`, if any.
+    pub api_token: Option<Arc<str>>,
+}
+
+/// How far to take the proof.
+#[derive(Clone, Copy, Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ProveMode {
+    /// Prove the riscv chunks only, without recursion.
+    #[default]
+    Fast,
+    /// Run the whole recursion chain down to the embed proof.
+    Full,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ProveRequest {
+    /// The guest program ELF, base64-encoded.
+    pub elf_base64: String,
+    /// The bytes written to the guest stdin, base64-encoded.
+    #[serde(default)]
+    pub stdin_base64: String,
+    #[serde(default)]
+    pub mode: ProveMode,
+    /// The field to prove over, `kb` or `bb`.
+    #[serde(default = "default_field")]
+    #[schema(default = "kb")]
+    pub field: String,
+}
+
+fn default_field() -> String {
+    "kb".to_string()
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ProveResponse {
+    /// The bincode-serialized `MetaProof`, base64-encoded.
+    pub proof_base64: String,
+    /// The public values committed by the guest, base64-encoded.
+    pub public_values_base64: String,
+    /// The number of cycles the guest ran for.
+    pub cycles: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+pub struct ApiError(StatusCode, String);
+
+/// The routes of the server. Request bodies over `max_body_bytes` are rejected with
+/// `413 Payload Too Large`.
+pub fn router(state: AppState, max_body_bytes: usize) -> Router {
+    Router::new()
+        .route("/prove", post(prove))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_token))
+        .route("/openapi.json", get(openapi))
+        .layer(DefaultBodyLimit::max(max_body_bytes))
+        .with_state(state)
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(ErrorResponse { error: self.1 })).into_response()
+    }
+}
+
+/// Proves a guest program on the given input.
+#[utoipa::path(
+    post,
+    path = "/prove",
+    request_body = ProveRequest,
+    responses(
+        (status = 200, description = "The proof was generated", body = ProveResponse),
+        (status = 400, description = "The request could not be decoded", body = ErrorResponse),
+        (status = 401, description = "The bearer token is missing or wrong", body = ErrorResponse),
+        (status = 413, description = "The request body is over the size limit"),
+        (status = 422, description = "The request body is not a valid ProveRequest"),
+        (status = 500, description = "Proving failed", body = ErrorResponse),
+    ),
+    security(("bearer" = []))
+)]
+pub async fn prove(
+    State(state): State<AppState>,
+    Json(request): Json<ProveRequest>,
+) -> Result<Json<ProveResponse>, ApiError> {
+    let elf = decode(&request.elf_base64, "elf_base64")?;
+    let stdin = decode(&request.stdin_base64, "stdin_base64")?;
+    // Reject bad requests before they wait in the queue.
+    if !matches!(request.field.as_str(), "kb" | "bb") {
+        return Err(ApiError(
+            StatusCode::BAD_REQUEST,
+            format!("unsupported field: {}", request.field),
+        ));
+    }
+
+    let _permit = state
+        .provers
+        .acquire_owned()
+        .await
+        .expect("prover semaphore is never closed");
+    info!(
+        "proving {} byte elf in {:?} mode over {}",
+        elf.len(),
+        request.mode,
+        request.field
+    );
+
+    let result = tokio::task::spawn_blocking(move || {
+        prover::prove(&elf, &stdin, request.mode, &request.field)
+    })
+    .await;
+    match result {
+        Ok(Ok(response)) => Ok(Json(response)),
+        Ok(Err(err)) => Err(ApiError(StatusCode::INTERNAL_SERVER_ERROR, err.to_string())),
+        Err(err) => {
+            error!("prover task failed: {}", err);
+            Err(ApiError(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "prover panicked".to_string(),
+            ))
+        }
+    }
+}
+
+fn decode(value: &str, name: &str) -> Result<Vec<u8>, ApiError> {
+    STANDARD.decode(value).map_err(|err| {
+        ApiError(
+            StatusCode::BAD_REQUEST,
+            format!("invalid {}: {}", name, err),
+        )
+    })
+}
+
+/// Rejects requests without the configured bearer token. Does nothing when no token is set.
+pub async fn require_token(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    if let Some(token) = &state.api_token {
+        let presented = request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        // Compare in constant time so the token cannot be guessed byte by byte from timings.
+        let valid =
+            presented.is_some_and(|presented| presented.as_bytes().ct_eq(token.as_bytes()).into());
+        if !valid {
+            return Err(ApiError(
+                StatusCode::UNAUTHORIZED,
+                "missing or invalid bearer token".to_string(),
+            ));
+        }
+    }
+    Ok(next.run(request).await)
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(prove),
+    components(schemas(ProveRequest, ProveResponse, ProveMode, ErrorResponse)),
+    modifiers(&BearerAuth)
+)]
+pub struct ApiDoc;
+
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        openapi
+            .components
+            .get_or_insert_with(Default::default)
+            .add_security_scheme(
+                "bearer",
+                SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+            );
+    }
+}
+
+pub async fn openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::{SocketAddr, TcpStream},
+        time::Duration,
+    };
+
+    const TOKEN: &str = "secret";
+
+    /// Serves the routes on a free loopback port, with one prover and the bearer token `TOKEN`.
+    async fn serve(max_body_bytes: usize) -> (SocketAddr, AppState) {
+        let state = AppState {
+            provers: Arc::new(Semaphore::new(1)),
+            api_token: Some(Arc::from(TOKEN)),
+        };
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = router(state.clone(), max_body_bytes);
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (addr, state)
+    }
+
+    /// Posts `body` to `/prove` with the bearer token `token` and returns the response status.
+    async fn post_prove(addr: SocketAddr, token: Option<&str>, body: &str) -> u16 {
+        let auth = token
+            .map(|token| format!("Authorization: Bearer {token}\r\n"))
+            .unwrap_or_default();
+        let request = format!(
+            "POST /prove HTTP/1.1\r\nHost: {addr}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n{auth}\r\n{body}",
+            body.len()
+        );
+        tokio::task::spawn_blocking(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(request.as_bytes()).unwrap();
+            // The server may reset the connection after an early rejection, which does not lose
+            // the response it already sent.
+            let mut response = Vec::new();
+            let _ = stream.read_to_end(&mut response);
+            String::from_utf8_lossy(&response[9..12]).parse().unwrap()
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_prove_requires_token() {
+        let (addr, _) = serve(1 << 20).await;
+        let body = r#"{"elf_base64": "AAAA", "field": "xx"}"#;
+        assert_eq!(post_prove(addr, None, body).await, 401);
+        assert_eq!(post_prove(addr, Some("wrong"), body).await, 401);
+        // With the right token the request gets as far as the field check.
+        assert_eq!(post_prove(addr, Some(TOKEN), body).await, 400);
+    }
+
+    #[tokio::test]
+    async fn test_prove_rejects_bad_requests() {
+        let (addr, _) = serve(1 << 10).await;
+        let bad_mode = r#"{"elf_base64": "AAAA", "mode": "slow"}"#;
+        assert_eq!(post_prove(addr, Some(TOKEN), bad_mode).await, 422);
+        let bad_elf = r#"{"elf_base64": "not base64!"}"#;
+        assert_eq!(post_prove(addr, Some(TOKEN), bad_elf).await, 400);
+        let too_large = format!(r#"{{"elf_base64": "{}"}}"#, "A".repeat(1 << 10));
+        assert_eq!(post_prove(addr, Some(TOKEN), &too_large).await, 413);
+    }
+
+    #[tokio::test]
+    async fn test_prove_waits_for_a_prover() {
+        let (addr, state) = serve(1 << 20).await;
+        let permit = state.provers.clone().acquire_owned().await.unwrap();
+
+        let queued = tokio::spawn(async move {
+            post_prove(addr, Some(TOKEN), r#"{"elf_base64": "AAAA"}"#).await
+        });
+        // Bad requests are rejected without waiting for the busy prover.
+        let bad_field = r#"{"elf_base64": "AAAA", "field": "xx"}"#;
+        assert_eq!(post_prove(addr, Some(TOKEN), bad_field).await, 400);
+        tokio::task::spawn_blocking(|| std::thread::sleep(Duration::from_millis(200)))
+            .await
+            .unwrap();
+        assert!(!queued.is_finished());
+
+        // Once the prover is free the request runs, and fails as its ELF is not one.
+        drop(permit);
+        assert_eq!(queued.await.unwrap(), 500);
+    }
+}
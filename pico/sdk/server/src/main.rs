@@ -0,0 +1,74 @@
+//! An HTTP server that proves guest programs on request.
+//!
+//! `POST /prove` takes a base64-encoded ELF and stdin and returns the proof; the OpenAPI spec is
+//! served at `GET /openapi.json`. When `PICO_API_TOKEN` is set, `/prove` requires it as a bearer
+//! token. The server listens on loopback by default and refuses to listen on any other address
+//! without a token. Request bodies are limited to `PICO_MAX_BODY_BYTES`, 64 MiB by default.
+mod api;
+mod prover;
+
+use anyhow::{bail, Result};
+use api::AppState;
+use clap::Parser;
+use log::info;
+use pico_sdk::init_logger;
+use std::{net::SocketAddr, sync::Arc};
+use tokio::sync::Semaphore;
+
+#[derive(Parser)]
+#[command(name = "pico-server", about = "serve pico proofs over HTTP")]
+struct Args {
+    #[clap(long, env = "PICO_SERVER_ADDR", default_value = "127.0.0.1:3000")]
+    addr: SocketAddr,
+
+    #[clap(
+        long,
+        env = "PICO_MAX_CONCURRENT_PROOFS",
+        default_value_t = 1,
+        help = "number of proofs to run at once; further requests are queued"
+    )]
+    max_concurrent_proofs: usize,
+
+    #[clap(
+        long,
+        env = "PICO_MAX_BODY_BYTES",
+        default_value_t = 64 << 20,
+        help = "largest request body to accept, in bytes; the base64 ELF and stdin count toward it"
+    )]
+    max_body_bytes: usize,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    init_logger();
+    let args = Args::parse();
+
+    let api_token = std::env::var("PICO_API_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+        .map(Arc::from);
+    if api_token.is_none() {
+        if !args.addr.ip().is_loopback() {
+            bail!(
+                "PICO_API_TOKEN must be set to listen on the non-loopback address {}",
+                args.addr
+            );
+        }
+        info!("PICO_API_TOKEN is not set, /prove accepts unauthenticated requests");
+    }
+    let state = AppState {
+        provers: Arc::new(Semaphore::new(args.max_concurrent_proofs.max(1))),
+        api_token,
+    };
+
+    let app = api::router(state, args.max_body_bytes);
+
+    let listener = tokio::net::TcpListener::bind(args.addr).await?;
+    info!("pico-server listening on {}", args.addr);
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::api::{ProveMode, ProveResponse};
+use anyhow::{Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use pico_sdk::client::{BabyBearProverClient, KoalaBearProverClient};
+use serde::Serialize;
+use std::{
+    fs,
+    path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// A proof to be returned to the client, along with the public values it commits to.
+struct Proved<P> {
+    proof: P,
+    public_values: Vec<u8>,
+    cycles: u64,
+}
+
+impl<P: Serialize> Proved<P> {
+    fn into_response(self) -> Result<ProveResponse> {
+        Ok(ProveResponse {
+            proof_base64: STANDARD.encode(bincode::serialize(&self.proof)?),
+            public_values_base64: STANDARD.encode(self.public_values),
+            cycles: self.cycles,
+        })
+    }
+}
+
+/// Proves `elf` on `stdin` with the prover for `field`.
+///
+/// This builds a fresh prover client and runs the whole proof on the calling thread, so it must
+/// be called from a blocking task.
+pub fn prove(elf: &[u8], stdin: &[u8], mode: ProveMode, field: &str) -> Result<ProveResponse> {
+    macro_rules! prove_with {
+        ($client:ty) => {{
+            let client = <$client>::new(elf);
+            client.get_stdin_builder().borrow_mut().write_slice(stdin);
+            match mode {
+                ProveMode::Fast => {
+                    let (proof, cycles) = client.prove_fast_cycles(|_| {})?;
+                    Proved {
+                        public_values: proof.pv_stream.clone().unwrap_or_default(),
+                        proof,
+                        cycles,
+                    }
+                    .into_response()
+                }
+                ProveMode::Full => {
+                    // The gnark inputs written alongside the embed proof are not needed here.
+                    let output = scratch_dir()?;
+                    let result = client.prove_cycles(output.clone(), |_| {});
+                    let _ = fs::remove_dir_all(&output);
+                    let (riscv_proof, proof, cycles) = result?;
+                    Proved {
+                        public_values: riscv_proof.pv_stream.unwrap_or_default(),
+                        proof,
+                        cycles,
+                    }
+                    .into_response()
+                }
+            }
+        }};
+    }
+
+    match field {
+        "kb" => prove_with!(KoalaBearProverClient),
+        "bb" => prove_with!(BabyBearProverClient),
+        _ => Err(Error::msg(format!("unsupported field: {}", field))),
+    }
+}
+
+/// Creates an empty directory for the files a full proof writes out.
+fn scratch_dir() -> Result<PathBuf> {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "pico-server-{}-{}",
+        std::process::id(),
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
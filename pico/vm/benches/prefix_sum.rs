@@ -0,0 +1,37 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use p3_baby_bear::BabyBear;
+use p3_field::{FieldAlgebra, PrimeField32};
+use pico_vm::iter::pico_prefix_sum;
+use rand::{thread_rng, Rng};
+
+const NUM_ELEMENTS: usize = 10_000_000;
+
+fn scalar_prefix_sum(input: &[BabyBear]) -> Vec<BabyBear> {
+    input
+        .iter()
+        .scan(BabyBear::ZERO, |total, &x| {
+            *total += x;
+            Some(*total)
+        })
+        .collect()
+}
+
+fn bench_prefix_sum(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let input = (0..NUM_ELEMENTS)
+        .map(|_| BabyBear::from_canonical_u32(rng.gen_range(0..BabyBear::ORDER_U32)))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("prefix_sum_babybear_10m");
+    group.sample_size(10);
+    group.bench_function("scalar", |b| {
+        b.iter(|| scalar_prefix_sum(black_box(&input)))
+    });
+    group.bench_function("pico_prefix_sum", |b| {
+        b.iter(|| pico_prefix_sum(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_prefix_sum);
+criterion_main!(benches);
@@ -22,8 +22,8 @@ use pico_vm::{
         chiptype::{recursion_chiptype::RecursionChipType, riscv_chiptype::RiscvChipType},
         compiler::{
             onchain_circuit::{
-                gnark::builder::OnchainVerifierCircuit, stdin::OnchainStdin,
-                utils::build_gnark_config,
+                gnark::{builder::OnchainVerifierCircuit, inputs::GnarkInputs},
+                stdin::OnchainStdin,
             },
             recursion_circuit::{
                 compress::builder::CompressVerifierCircuit, embed::builder::EmbedVerifierCircuit,
@@ -50,7 +50,7 @@ use pico_vm::{
         RECURSION_NUM_PVS, RISCV_NUM_PVS,
     },
 };
-use std::{path::PathBuf, sync::Arc, time::Instant};
+use std::{path::Path, sync::Arc, time::Instant};
 use tracing::{debug, info};
 
 #[path = "common/parse_args.rs"]
@@ -211,7 +211,7 @@ macro_rules! run {
                 let convert_witness = ProvingWitness::setup_for_convert(
                     convert_stdin,
                     convert_machine.config(),
-                    recursion_opts,
+                    recursion_opts.clone(),
                 );
 
                 convert_machine.prove(&convert_witness)
@@ -465,7 +465,7 @@ macro_rules! run {
             let (constraints, witness) =
                 OnchainVerifierCircuit::<$embed_cc, $embed_sc>::build(&onchain_stdin);
 
-            build_gnark_config(constraints, witness, PathBuf::from("./"));
+            GnarkInputs::new(constraints, witness).save(Path::new("./")).unwrap();
             info!("Finished exporting gnark data");
 
             stats.embed = (embed_time, embed_proof_size);
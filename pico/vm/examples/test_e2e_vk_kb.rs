@@ -164,8 +164,11 @@ fn main() {
         &Some(recursion_shape_config),
     );
 
-    let convert_witness =
-        ProvingWitness::setup_for_convert(convert_stdin, convert_machine.config(), recursion_opts);
+    let convert_witness = ProvingWitness::setup_for_convert(
+        convert_stdin,
+        convert_machine.config(),
+        recursion_opts.clone(),
+    );
 
     // Generate the proof.
     info!("Generating CONVERT proof (at {:?})..", start.elapsed());
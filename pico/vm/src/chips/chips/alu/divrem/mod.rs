@@ -8,3 +8,40 @@ mod utils;
 /// A chip that implements addition for the opcodes DIV/REM.
 #[derive(Default)]
 pub struct DivRemChip<F>(PhantomData<F>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::riscv::syscalls::{
+            test_utils::{debug_program, syscall_instructions},
+            SyscallCode,
+        },
+    };
+    use alloc::sync::Arc;
+
+    #[test]
+    fn test_divrem_edge_cases_satisfy_constraints() {
+        // Signs, division by zero and the single overflowing signed division.
+        let cases = [
+            (7, 2),
+            (-7i32 as u32, 2),
+            (7, -2i32 as u32),
+            (-7i32 as u32, -2i32 as u32),
+            (i32::MIN as u32, -1i32 as u32),
+            (5, 0),
+            (0, 0),
+            (u32::MAX, 3),
+        ];
+        let mut instructions = vec![];
+        for (b, c) in cases {
+            instructions.push(Instruction::new(Opcode::ADD, 29, 0, b, false, true));
+            instructions.push(Instruction::new(Opcode::ADD, 30, 0, c, false, true));
+            for opcode in [Opcode::DIV, Opcode::DIVU, Opcode::REM, Opcode::REMU] {
+                instructions.push(Instruction::new(opcode, 31, 29, 30, false, false));
+            }
+        }
+        instructions.extend(syscall_instructions(SyscallCode::HALT, 0, 0));
+        debug_program(Arc::new(Program::new(instructions, 0, 0))).unwrap();
+    }
+}
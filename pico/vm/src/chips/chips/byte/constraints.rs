@@ -56,6 +56,9 @@ where
                 ByteOpcode::U16Range => {
                     builder.looked_byte(field_op, local.value_u16, CB::F::ZERO, CB::F::ZERO, mult)
                 }
+                ByteOpcode::AesSbox | ByteOpcode::Crc32 => {
+                    unreachable!("{opcode:?} is not in the byte table")
+                }
             }
         }
     }
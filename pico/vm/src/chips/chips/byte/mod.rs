@@ -10,5 +10,8 @@ pub mod utils;
 ///
 /// The chip contains a preprocessed table of all possible byte operations. Other chips can then
 /// use lookups into this table to compute their own operations.
+///
+/// The table has one row per 16-bit value, so besides the byte operations it doubles as the
+/// 16-bit range table: a `U16Range` lookup checks a whole u16 in one lookup.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ByteChip<F>(PhantomData<F>);
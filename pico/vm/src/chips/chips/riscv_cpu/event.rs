@@ -9,7 +9,7 @@ use crate::{
 ///
 /// This object encapsulates the information needed to prove a CPU operation. This includes its
 /// chunk, opcode, operands, and other relevant information.
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CpuEvent {
     /// The chunk number.
     pub chunk: u32,
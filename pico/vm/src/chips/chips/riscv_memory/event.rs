@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// This object encapsulates the information needed to prove a memory access operation. This
 /// includes the chunk, timestamp, and value of the memory address.
-#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MemoryRecord {
     /// The chunk number.
     pub chunk: u32,
@@ -38,7 +38,7 @@ pub enum MemoryAccessPosition {
 /// This object encapsulates the information needed to prove a memory read operation. This
 /// includes the value, chunk, timestamp, and previous chunk and timestamp.
 #[allow(clippy::manual_non_exhaustive)]
-#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MemoryReadRecord {
     /// The value.
     pub value: u32,
@@ -57,7 +57,7 @@ pub struct MemoryReadRecord {
 /// This object encapsulates the information needed to prove a memory write operation. This
 /// includes the value, chunk, timestamp, previous value, previous chunk, and previous timestamp.
 #[allow(clippy::manual_non_exhaustive)]
-#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MemoryWriteRecord {
     /// The value.
     pub value: u32,
@@ -77,7 +77,7 @@ pub struct MemoryWriteRecord {
 ///
 /// This enum represents the different types of memory records that can be stored in the memory
 /// event such as reads and writes.
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MemoryRecordEnum {
     /// Read.
     Read(MemoryReadRecord),
@@ -90,7 +90,7 @@ pub enum MemoryRecordEnum {
 /// This object encapsulates the information needed to prove a memory initialize or finalize
 /// operation. This includes the address, value, chunk, timestamp, and whether the memory is
 /// initialized or finalized.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MemoryInitializeFinalizeEvent {
     /// The address.
     pub addr: u32,
@@ -198,7 +198,7 @@ impl From<MemoryWriteRecord> for MemoryRecordEnum {
 }
 
 /// Memory Local Event
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MemoryLocalEvent {
     /// The address
     pub addr: u32,
@@ -0,0 +1,47 @@
+use crate::{
+    chips::chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+    emulator::riscv::syscalls::precompiles::aes::AES256_NUM_ROUNDS,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_AES256_ENCRYPT_BLOCK_COLS: usize = size_of::<Aes256EncryptBlockCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Aes256EncryptBlockCols<T> {
+    /// Inputs, copied over every row of an event.
+    pub chunk: T,
+    pub clk: T,
+    pub block_ptr: T,
+    pub schedule_ptr: T,
+
+    /// One-hot flag of the round computed in this row. The first flag marks the first row of an
+    /// event and the last flag marks the last row.
+    pub round: [T; AES256_NUM_ROUNDS],
+
+    /// The reads of the round key added to the plaintext, on the first row.
+    pub initial_key_access: [MemoryReadCols<T>; 4],
+    /// The reads of the round key of this round.
+    pub key_access: [MemoryReadCols<T>; 4],
+    /// The writes of the block, on the first row. The previous values are the plaintext.
+    pub block_access: [MemoryWriteCols<T>; 4],
+
+    /// The ciphertext, copied over every row of an event.
+    pub output: [T; 16],
+
+    /// The state at the start of the round.
+    pub state_in: [T; 16],
+    /// The S-box applied to `state_in`, and that value multiplied by `x`.
+    pub sbox: [T; 16],
+    pub sbox_xtime: [T; 16],
+    /// The partial sums of the five terms of each `MixColumns` output byte, and the output.
+    pub mix_partial: [[T; 3]; 16],
+    pub mix: [T; 16],
+    /// The state before `AddRoundKey`: `mix`, or the shifted S-box output on the last round.
+    pub round_out: [T; 16],
+    /// The state at the end of the round.
+    pub state_out: [T; 16],
+
+    pub is_real: T,
+}
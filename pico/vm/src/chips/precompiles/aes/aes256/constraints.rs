@@ -0,0 +1,190 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        precompiles::aes::aes256::{columns::Aes256EncryptBlockCols, Aes256EncryptBlockChip},
+    },
+    compiler::riscv::opcode::ByteOpcode,
+    emulator::riscv::syscalls::{
+        precompiles::aes::{AES256_NUM_ROUNDS, AES_SHIFT_ROWS},
+        SyscallCode,
+    },
+    machine::builder::{ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, RiscVMemoryBuilder},
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for Aes256EncryptBlockChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Aes256EncryptBlockCols<CB::Var> = (*local).borrow();
+        let next: &Aes256EncryptBlockCols<CB::Var> = (*next).borrow();
+
+        let xor = ByteOpcode::XOR.as_field::<CB::F>();
+        let aes_sbox = ByteOpcode::AesSbox.as_field::<CB::F>();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        let mut num_rounds = CB::Expr::ZERO;
+        for flag in local.round {
+            builder.assert_bool(flag);
+            num_rounds = num_rounds + flag.into();
+        }
+        builder.assert_eq(num_rounds, local.is_real);
+        let is_first = local.round[0];
+        let is_last = local.round[AES256_NUM_ROUNDS - 1];
+
+        // The table starts with a new event or with padding.
+        builder.when_first_row().assert_eq(is_first, local.is_real);
+
+        // Within an event, the next row computes the next round from the state left by this one.
+        let is_continuing: CB::Expr = local.is_real - is_last;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            for i in 0..AES256_NUM_ROUNDS - 1 {
+                builder.assert_eq(next.round[i + 1], local.round[i]);
+            }
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.block_ptr, local.block_ptr);
+            builder.assert_eq(next.schedule_ptr, local.schedule_ptr);
+            for i in 0..16 {
+                builder.assert_eq(next.output[i], local.output[i]);
+                builder.assert_eq(next.state_in[i], local.state_out[i]);
+            }
+        }
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(is_last)
+            .assert_eq(next.round[0], next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing.clone());
+
+        // Round `r`, counting from 1, reads the four words of the schedule at `16 r`.
+        let key_offset = local
+            .round
+            .iter()
+            .enumerate()
+            .map(|(i, &flag)| flag * CB::F::from_canonical_usize(16 * (i + 1)))
+            .sum::<CB::Expr>();
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.schedule_ptr,
+            &local.initial_key_access,
+            is_first,
+        );
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.schedule_ptr + key_offset,
+            &local.key_access,
+            local.is_real,
+        );
+
+        // Write the ciphertext over the plaintext on the first row.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.block_ptr,
+            &local.block_access,
+            is_first,
+        );
+        for i in 0..16 {
+            builder
+                .when(is_first)
+                .assert_eq(local.output[i], local.block_access[i / 4].value()[i % 4]);
+        }
+
+        // The first round starts from the plaintext plus the first round key.
+        for i in 0..16 {
+            builder.looking_byte(
+                xor,
+                local.state_in[i],
+                local.block_access[i / 4].prev_value()[i % 4],
+                local.initial_key_access[i / 4].value()[i % 4],
+                is_first,
+            );
+        }
+
+        // SubBytes.
+        for i in 0..16 {
+            builder.looking_byte_pair(
+                aes_sbox,
+                local.sbox[i],
+                local.sbox_xtime[i],
+                local.state_in[i],
+                CB::F::ZERO,
+                local.is_real,
+            );
+        }
+
+        // ShiftRows and MixColumns, as in AES-128.
+        let shifted = AES_SHIFT_ROWS.map(|j| local.sbox[j]);
+        let shifted_xtime = AES_SHIFT_ROWS.map(|j| local.sbox_xtime[j]);
+        for i in 0..16 {
+            let (c, r) = (i / 4, i % 4);
+            let at = |k: usize| 4 * c + (r + k) % 4;
+            let terms = [
+                shifted_xtime[at(0)],
+                shifted_xtime[at(1)],
+                shifted[at(1)],
+                shifted[at(2)],
+                shifted[at(3)],
+            ];
+
+            let partial = local.mix_partial[i];
+            builder.looking_byte(xor, partial[0], terms[0], terms[1], local.is_real);
+            builder.looking_byte(xor, partial[1], partial[0], terms[2], local.is_real);
+            builder.looking_byte(xor, partial[2], partial[1], terms[3], local.is_real);
+            builder.looking_byte(xor, local.mix[i], partial[2], terms[4], local.is_real);
+
+            builder
+                .when(is_continuing.clone())
+                .assert_eq(local.round_out[i], local.mix[i]);
+            builder
+                .when(is_last)
+                .assert_eq(local.round_out[i], shifted[i]);
+        }
+
+        // AddRoundKey.
+        for i in 0..16 {
+            builder.looking_byte(
+                xor,
+                local.state_out[i],
+                local.round_out[i],
+                local.key_access[i / 4].value()[i % 4],
+                local.is_real,
+            );
+        }
+
+        // The last round leaves the ciphertext.
+        for i in 0..16 {
+            builder
+                .when(is_last)
+                .assert_eq(local.state_out[i], local.output[i]);
+        }
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::AES256_ENCRYPT_BLOCK.syscall_id()),
+            local.block_ptr,
+            local.schedule_ptr,
+            is_first,
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// Proves `AES256_ENCRYPT_BLOCK` one round per row.
+///
+/// An event spans fourteen consecutive rows, with the same round function as
+/// [`Aes128EncryptBlockChip`](super::Aes128EncryptBlockChip). The round keys are not derived
+/// here: each row reads its round key from the schedule that `AES_KEY_SCHEDULE` wrote, so a key
+/// is only expanded once for any number of blocks.
+///
+/// The first row also reads the round key of the initial `AddRoundKey` and writes the ciphertext
+/// over the plaintext.
+#[derive(Default)]
+pub struct Aes256EncryptBlockChip<F> {
+    _phantom: PhantomData<F>,
+}
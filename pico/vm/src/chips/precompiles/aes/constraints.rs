@@ -0,0 +1,218 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        precompiles::aes::{columns::Aes128EncryptBlockCols, Aes128EncryptBlockChip},
+    },
+    compiler::riscv::opcode::ByteOpcode,
+    emulator::riscv::syscalls::{
+        precompiles::aes::{AES128_NUM_ROUNDS, AES_RCON, AES_SHIFT_ROWS},
+        SyscallCode,
+    },
+    machine::builder::{ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, RiscVMemoryBuilder},
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for Aes128EncryptBlockChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Aes128EncryptBlockCols<CB::Var> = (*local).borrow();
+        let next: &Aes128EncryptBlockCols<CB::Var> = (*next).borrow();
+
+        let xor = ByteOpcode::XOR.as_field::<CB::F>();
+        let aes_sbox = ByteOpcode::AesSbox.as_field::<CB::F>();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        let mut num_rounds = CB::Expr::ZERO;
+        for flag in local.round {
+            builder.assert_bool(flag);
+            num_rounds = num_rounds + flag.into();
+        }
+        builder.assert_eq(num_rounds, local.is_real);
+        let is_first = local.round[0];
+        let is_last = local.round[AES128_NUM_ROUNDS - 1];
+
+        // The table starts with a new event or with padding.
+        builder.when_first_row().assert_eq(is_first, local.is_real);
+
+        // Within an event, the next row computes the next round from the state and round key
+        // left by this one.
+        let is_continuing: CB::Expr = local.is_real - is_last;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            for i in 0..AES128_NUM_ROUNDS - 1 {
+                builder.assert_eq(next.round[i + 1], local.round[i]);
+            }
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.block_ptr, local.block_ptr);
+            builder.assert_eq(next.key_ptr, local.key_ptr);
+            for i in 0..16 {
+                builder.assert_eq(next.output[i], local.output[i]);
+                builder.assert_eq(next.prev_key[i], local.round_key[i]);
+                builder.assert_eq(next.state_in[i], local.state_out[i]);
+            }
+        }
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(is_last)
+            .assert_eq(next.round[0], next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing.clone());
+
+        // Read the key and write the ciphertext over the plaintext on the first row.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.key_ptr,
+            &local.key_access,
+            is_first,
+        );
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.block_ptr,
+            &local.block_access,
+            is_first,
+        );
+        for i in 0..16 {
+            let (word, byte) = (i / 4, i % 4);
+            let mut builder = builder.when(is_first);
+            builder.assert_eq(local.prev_key[i], local.key_access[word].value()[byte]);
+            builder.assert_eq(local.output[i], local.block_access[word].value()[byte]);
+        }
+
+        // The first round starts from the plaintext plus the cipher key.
+        for i in 0..16 {
+            builder.looking_byte(
+                xor,
+                local.state_in[i],
+                local.block_access[i / 4].prev_value()[i % 4],
+                local.prev_key[i],
+                is_first,
+            );
+        }
+
+        // Key schedule.
+        for i in 0..4 {
+            builder.looking_byte_pair(
+                aes_sbox,
+                local.key_sbox[i],
+                local.key_sbox_xtime[i],
+                local.prev_key[12 + (i + 1) % 4],
+                CB::F::ZERO,
+                local.is_real,
+            );
+        }
+        let rcon = local
+            .round
+            .iter()
+            .zip(AES_RCON)
+            .map(|(&flag, rcon)| flag * CB::F::from_canonical_u8(rcon))
+            .sum::<CB::Expr>();
+        builder.looking_byte(
+            xor,
+            local.key_sbox_rcon,
+            local.key_sbox[0],
+            rcon,
+            local.is_real,
+        );
+        for i in 0..16 {
+            let term = match i {
+                0 => local.key_sbox_rcon,
+                1..=3 => local.key_sbox[i],
+                _ => local.round_key[i - 4],
+            };
+            builder.looking_byte(
+                xor,
+                local.round_key[i],
+                local.prev_key[i],
+                term,
+                local.is_real,
+            );
+        }
+
+        // SubBytes.
+        for i in 0..16 {
+            builder.looking_byte_pair(
+                aes_sbox,
+                local.sbox[i],
+                local.sbox_xtime[i],
+                local.state_in[i],
+                CB::F::ZERO,
+                local.is_real,
+            );
+        }
+
+        // ShiftRows and MixColumns: output byte `r` of a column is
+        // `2 a_r + 3 a_{r+1} + a_{r+2} + a_{r+3}`, a sum of five terms since `3 a = 2 a + a`.
+        let shifted = AES_SHIFT_ROWS.map(|j| local.sbox[j]);
+        let shifted_xtime = AES_SHIFT_ROWS.map(|j| local.sbox_xtime[j]);
+        for i in 0..16 {
+            let (c, r) = (i / 4, i % 4);
+            let at = |k: usize| 4 * c + (r + k) % 4;
+            let terms = [
+                shifted_xtime[at(0)],
+                shifted_xtime[at(1)],
+                shifted[at(1)],
+                shifted[at(2)],
+                shifted[at(3)],
+            ];
+
+            let partial = local.mix_partial[i];
+            builder.looking_byte(xor, partial[0], terms[0], terms[1], local.is_real);
+            builder.looking_byte(xor, partial[1], partial[0], terms[2], local.is_real);
+            builder.looking_byte(xor, partial[2], partial[1], terms[3], local.is_real);
+            builder.looking_byte(xor, local.mix[i], partial[2], terms[4], local.is_real);
+
+            builder
+                .when(is_continuing.clone())
+                .assert_eq(local.round_out[i], local.mix[i]);
+            builder
+                .when(is_last)
+                .assert_eq(local.round_out[i], shifted[i]);
+        }
+
+        // AddRoundKey.
+        for i in 0..16 {
+            builder.looking_byte(
+                xor,
+                local.state_out[i],
+                local.round_out[i],
+                local.round_key[i],
+                local.is_real,
+            );
+        }
+
+        // The last round leaves the ciphertext.
+        for i in 0..16 {
+            builder
+                .when(is_last)
+                .assert_eq(local.state_out[i], local.output[i]);
+        }
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::AES128_ENCRYPT_BLOCK.syscall_id()),
+            local.block_ptr,
+            local.key_ptr,
+            is_first,
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    chips::chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+    emulator::riscv::syscalls::precompiles::aes::{
+        AES256_KEY_NUM_WORDS, AES256_SCHEDULE_NUM_WORDS,
+    },
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_AES_KEY_SCHEDULE_COLS: usize = size_of::<AesKeyScheduleCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct AesKeyScheduleCols<T> {
+    /// Inputs, copied over every row of an event.
+    pub chunk: T,
+    pub clk: T,
+    pub key_ptr: T,
+    pub schedule_ptr: T,
+
+    /// One-hot flag of the schedule word computed in this row. The first flag marks the first
+    /// row of an event and the last flag marks the last row.
+    pub index: [T; AES256_SCHEDULE_NUM_WORDS],
+
+    /// The read of a key word, on the first eight rows.
+    pub key_access: MemoryReadCols<T>,
+    /// The write of the schedule word.
+    pub schedule_access: MemoryWriteCols<T>,
+
+    /// The schedule words `w[i - 8..i]`, oldest first.
+    pub window: [[T; 4]; AES256_KEY_NUM_WORDS],
+    /// The bytes of `w[i - 1]` that go through the S-box, rotated on every eighth word.
+    pub sbox_in: [T; 4],
+    /// The S-box applied to `sbox_in`, and the same bytes multiplied by `x`, which the S-box
+    /// lookup returns alongside.
+    pub sbox: [T; 4],
+    pub sbox_xtime: [T; 4],
+    /// The first byte of `sbox` with the round constant added.
+    pub sbox_rcon: T,
+    /// The word added to `w[i - 8]`.
+    pub term: [T; 4],
+    /// The schedule word `w[i]`.
+    pub word: [T; 4],
+
+    pub is_real: T,
+}
@@ -0,0 +1,183 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        precompiles::aes::key_schedule::{columns::AesKeyScheduleCols, AesKeyScheduleChip},
+    },
+    compiler::riscv::opcode::ByteOpcode,
+    emulator::riscv::syscalls::{
+        precompiles::aes::{AES256_KEY_NUM_WORDS, AES256_SCHEDULE_NUM_WORDS, AES_RCON},
+        SyscallCode,
+    },
+    machine::builder::{ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, RiscVMemoryBuilder},
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for AesKeyScheduleChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &AesKeyScheduleCols<CB::Var> = (*local).borrow();
+        let next: &AesKeyScheduleCols<CB::Var> = (*next).borrow();
+
+        let xor = ByteOpcode::XOR.as_field::<CB::F>();
+        let aes_sbox = ByteOpcode::AesSbox.as_field::<CB::F>();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        let mut num_words = CB::Expr::ZERO;
+        for flag in local.index {
+            builder.assert_bool(flag);
+            num_words = num_words + flag.into();
+        }
+        builder.assert_eq(num_words, local.is_real);
+        let is_first = local.index[0];
+        let is_last = local.index[AES256_SCHEDULE_NUM_WORDS - 1];
+
+        // The kind of the word: a key word, a word whose term goes through the S-box with or
+        // without rotation and round constant, or a word whose term is `w[i - 1]` itself.
+        let sum_flags = |filter: &dyn Fn(usize) -> bool| {
+            (0..AES256_SCHEDULE_NUM_WORDS)
+                .filter(|&i| filter(i))
+                .map(|i| CB::Expr::from(local.index[i]))
+                .sum::<CB::Expr>()
+        };
+        let is_key = sum_flags(&|i| i < AES256_KEY_NUM_WORDS);
+        let is_rot = sum_flags(&|i| i >= AES256_KEY_NUM_WORDS && i % 8 == 0);
+        let is_sub = sum_flags(&|i| i >= AES256_KEY_NUM_WORDS && i % 8 == 4);
+        let is_sbox = is_rot.clone() + is_sub.clone();
+        let is_derived: CB::Expr = local.is_real - is_key.clone();
+        let is_plain = is_derived.clone() - is_sbox.clone();
+
+        // The table starts with a new event or with padding.
+        builder.when_first_row().assert_eq(is_first, local.is_real);
+
+        // Within an event, the next row computes the next word, with this word shifted into the
+        // window.
+        let is_continuing: CB::Expr = local.is_real - is_last;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            for i in 0..AES256_SCHEDULE_NUM_WORDS - 1 {
+                builder.assert_eq(next.index[i + 1], local.index[i]);
+            }
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.key_ptr, local.key_ptr);
+            builder.assert_eq(next.schedule_ptr, local.schedule_ptr);
+            for j in 0..4 {
+                for k in 0..AES256_KEY_NUM_WORDS - 1 {
+                    builder.assert_eq(next.window[k][j], local.window[k + 1][j]);
+                }
+                builder.assert_eq(next.window[AES256_KEY_NUM_WORDS - 1][j], local.word[j]);
+            }
+        }
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(is_last)
+            .assert_eq(next.index[0], next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing.clone());
+
+        // The byte offset of the word of this row.
+        let offset = local
+            .index
+            .iter()
+            .enumerate()
+            .map(|(i, &flag)| flag * CB::F::from_canonical_usize(4 * i))
+            .sum::<CB::Expr>();
+
+        // Read the key words, and write every word one cycle later.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.key_ptr + offset.clone(),
+            &local.key_access,
+            is_key.clone(),
+        );
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.schedule_ptr + offset,
+            &local.schedule_access,
+            local.is_real,
+        );
+        for j in 0..4 {
+            builder
+                .when(is_key.clone())
+                .assert_eq(local.word[j], local.key_access.value()[j]);
+            builder
+                .when(local.is_real)
+                .assert_eq(local.word[j], local.schedule_access.value()[j]);
+        }
+
+        // The term added to `w[i - 8]`.
+        let prev = local.window[AES256_KEY_NUM_WORDS - 1];
+        for j in 0..4 {
+            builder
+                .when(is_rot.clone())
+                .assert_eq(local.sbox_in[j], prev[(j + 1) % 4]);
+            builder
+                .when(is_sub.clone())
+                .assert_eq(local.sbox_in[j], prev[j]);
+            builder.looking_byte_pair(
+                aes_sbox,
+                local.sbox[j],
+                local.sbox_xtime[j],
+                local.sbox_in[j],
+                CB::F::ZERO,
+                is_sbox.clone(),
+            );
+        }
+        let rcon = (AES256_KEY_NUM_WORDS..AES256_SCHEDULE_NUM_WORDS)
+            .step_by(8)
+            .map(|i| local.index[i] * CB::F::from_canonical_u8(AES_RCON[i / 8 - 1]))
+            .sum::<CB::Expr>();
+        builder.looking_byte(xor, local.sbox_rcon, local.sbox[0], rcon, is_sbox.clone());
+        for j in 0..4 {
+            let sbox_term = if j == 0 {
+                local.sbox_rcon
+            } else {
+                local.sbox[j]
+            };
+            builder
+                .when(is_sbox.clone())
+                .assert_eq(local.term[j], sbox_term);
+            builder
+                .when(is_plain.clone())
+                .assert_eq(local.term[j], prev[j]);
+        }
+
+        // `w[i] = w[i - 8] + term`.
+        for j in 0..4 {
+            builder.looking_byte(
+                xor,
+                local.word[j],
+                local.window[0][j],
+                local.term[j],
+                is_derived.clone(),
+            );
+        }
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::AES_KEY_SCHEDULE.syscall_id()),
+            local.key_ptr,
+            local.schedule_ptr,
+            is_first,
+        );
+    }
+}
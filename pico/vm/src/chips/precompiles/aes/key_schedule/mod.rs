@@ -0,0 +1,16 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// Proves `AES_KEY_SCHEDULE` one schedule word per row.
+///
+/// An event spans sixty consecutive rows, one for each word `w[i]` of the AES-256 key schedule.
+/// The first eight rows read the words of the key. Every later row derives its word from
+/// `w[i - 8]` and `w[i - 1]`, which it finds in a window of the eight words written by the rows
+/// before. Every row writes its word to the schedule one cycle after the key is read.
+#[derive(Default)]
+pub struct AesKeyScheduleChip<F> {
+    _phantom: PhantomData<F>,
+}
@@ -0,0 +1,189 @@
+use crate::{
+    chips::{
+        chips::byte::event::{ByteLookupEvent, ByteRecordBehavior},
+        precompiles::aes::key_schedule::{
+            columns::{AesKeyScheduleCols, NUM_AES_KEY_SCHEDULE_COLS},
+            AesKeyScheduleChip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::riscv::{opcode::ByteOpcode, program::Program},
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{
+                aes::{xtime, AES256_KEY_NUM_WORDS, AES256_SCHEDULE_NUM_WORDS, AES_RCON, AES_SBOX},
+                AesKeyScheduleEvent, PrecompileEvent,
+            },
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for AesKeyScheduleChip<F> {
+    fn width(&self) -> usize {
+        NUM_AES_KEY_SCHEDULE_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for AesKeyScheduleChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "AesKeySchedule".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        for (_, event) in input
+            .get_precompile_events(SyscallCode::AES_KEY_SCHEDULE)
+            .iter()
+        {
+            let event = if let PrecompileEvent::AesKeySchedule(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut Vec::new());
+        }
+
+        let mut rows = rows.unwrap();
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(&mut rows, || [F::ZERO; NUM_AES_KEY_SCHEDULE_COLS], log_rows);
+
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect(),
+            NUM_AES_KEY_SCHEDULE_COLS,
+        )
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::AES_KEY_SCHEDULE)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::AesKeySchedule(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_rows(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record
+                .get_precompile_events(SyscallCode::AES_KEY_SCHEDULE)
+                .is_empty()
+        }
+    }
+}
+
+/// Looks up `b ^ c` in the byte table.
+fn xor(blu: &mut impl ByteRecordBehavior, b: u8, c: u8) -> u8 {
+    let a = b ^ c;
+    blu.add_byte_lookup_event(ByteLookupEvent::new(ByteOpcode::XOR, a.into(), 0, b, c));
+    a
+}
+
+impl<F: PrimeField32> AesKeyScheduleChip<F> {
+    fn event_to_rows(
+        &self,
+        event: &AesKeyScheduleEvent,
+        rows: &mut Option<Vec<[F; NUM_AES_KEY_SCHEDULE_COLS]>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        // The window of the first row is never used, so it starts out zero.
+        let mut window = [[0u8; 4]; AES256_KEY_NUM_WORDS];
+
+        for i in 0..AES256_SCHEDULE_NUM_WORDS {
+            let mut row = [F::ZERO; NUM_AES_KEY_SCHEDULE_COLS];
+            let cols: &mut AesKeyScheduleCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.key_ptr = F::from_canonical_u32(event.key_ptr);
+            cols.schedule_ptr = F::from_canonical_u32(event.schedule_ptr);
+            cols.index[i] = F::ONE;
+            cols.window = window.map(|word| word.map(F::from_canonical_u8));
+
+            let word = if i < AES256_KEY_NUM_WORDS {
+                cols.key_access.populate(event.key_read_records[i], blu);
+                event.key[i].to_le_bytes()
+            } else {
+                let prev = window[AES256_KEY_NUM_WORDS - 1];
+                let term = match i % 8 {
+                    0 | 4 => {
+                        let sbox_in: [u8; 4] = if i % 8 == 0 {
+                            core::array::from_fn(|j| prev[(j + 1) % 4])
+                        } else {
+                            prev
+                        };
+                        let sbox = sbox_in.map(|b| AES_SBOX[b as usize]);
+                        for (&b, &a) in sbox_in.iter().zip(&sbox) {
+                            blu.add_byte_lookup_event(ByteLookupEvent::new(
+                                ByteOpcode::AesSbox,
+                                a.into(),
+                                xtime(a),
+                                b,
+                                0,
+                            ));
+                        }
+                        let rcon = if i % 8 == 0 { AES_RCON[i / 8 - 1] } else { 0 };
+                        let sbox_rcon = xor(blu, sbox[0], rcon);
+
+                        cols.sbox_in = sbox_in.map(F::from_canonical_u8);
+                        cols.sbox = sbox.map(F::from_canonical_u8);
+                        cols.sbox_xtime = sbox.map(|a| F::from_canonical_u8(xtime(a)));
+                        cols.sbox_rcon = F::from_canonical_u8(sbox_rcon);
+                        [sbox_rcon, sbox[1], sbox[2], sbox[3]]
+                    }
+                    _ => prev,
+                };
+                cols.term = term.map(F::from_canonical_u8);
+                core::array::from_fn(|j| xor(blu, window[0][j], term[j]))
+            };
+            cols.schedule_access
+                .populate(event.schedule_write_records[i], blu);
+            cols.word = word.map(F::from_canonical_u8);
+
+            window.rotate_left(1);
+            window[AES256_KEY_NUM_WORDS - 1] = word;
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+
+        debug_assert_eq!(
+            event.schedule_write_records[AES256_SCHEDULE_NUM_WORDS - 1].value,
+            u32::from_le_bytes(window[AES256_KEY_NUM_WORDS - 1]),
+            "AES_KEY_SCHEDULE output mismatch"
+        );
+    }
+}
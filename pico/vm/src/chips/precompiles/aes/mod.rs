@@ -0,0 +1,53 @@
+use std::marker::PhantomData;
+
+pub mod aes256;
+mod columns;
+mod constraints;
+pub mod key_schedule;
+pub mod sbox;
+mod traces;
+
+/// Proves `AES128_ENCRYPT_BLOCK` one round per row.
+///
+/// An event spans ten consecutive rows. Each row derives its round key from the previous one and
+/// applies `SubBytes`, `ShiftRows`, `MixColumns` (skipped on the last round) and `AddRoundKey`
+/// to the state left by the row before. The S-box is a lookup into the table of
+/// [`AesSboxChip`](sbox::AesSboxChip), which also returns the S-box output multiplied by `x` so
+/// that `MixColumns` only needs XOR lookups. `ShiftRows` is free: it only changes which columns
+/// feed `MixColumns`.
+///
+/// The first row reads the key, writes the ciphertext over the plaintext and adds the cipher key
+/// to the plaintext before the first round.
+#[derive(Default)]
+pub struct Aes128EncryptBlockChip<F> {
+    _phantom: PhantomData<F>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{prove_program, syscall_program},
+        SyscallCode,
+    };
+
+    #[test]
+    fn test_aes_prove() {
+        const BLOCK_PTR: u32 = 0x2000;
+        const KEY_PTR: u32 = 0x3000;
+        const SCHEDULE_PTR: u32 = 0x4000;
+
+        let memory_image = (0..4)
+            .map(|i| (BLOCK_PTR + 4 * i, 0x3322_1100 + i * 0x4444_4444))
+            .chain((0..8).map(|i| (KEY_PTR + 4 * i, 0x0302_0100 + i * 0x0404_0404)))
+            .collect();
+        let program = syscall_program(
+            &[
+                (SyscallCode::AES128_ENCRYPT_BLOCK, BLOCK_PTR, KEY_PTR),
+                (SyscallCode::AES_KEY_SCHEDULE, KEY_PTR, SCHEDULE_PTR),
+                (SyscallCode::AES256_ENCRYPT_BLOCK, BLOCK_PTR, SCHEDULE_PTR),
+            ],
+            memory_image,
+        );
+        prove_program(program).unwrap();
+    }
+}
@@ -0,0 +1,32 @@
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_AES_SBOX_COLS: usize = size_of::<AesSboxCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct AesSboxCols<T> {
+    /// The input multiplied by `x^i` in GF(2^8), the first one being the input itself.
+    pub powers: [T; 8],
+    /// The most significant bit of each power but the last, reduced away by the next one.
+    pub powers_msb: [T; 7],
+
+    /// Whether the input is zero, which is its own inverse for the S-box.
+    pub is_zero: T,
+    /// The bits of the inverse of the input, least significant first.
+    pub inv_bits: [T; 8],
+    /// Each power masked by the bit of the inverse of the same index.
+    pub masked: [T; 8],
+    /// The running XOR of `masked`, the last one being the product of the input and its inverse.
+    pub product: [T; 7],
+
+    /// The running XOR of the inverse and its rotations left by one to four bits.
+    pub affine: [T; 4],
+    /// The S-box output, its most significant bit and its multiple by `x`.
+    pub sbox: T,
+    pub sbox_msb: T,
+    pub sbox_xtime: T,
+
+    /// The number of lookups of this row.
+    pub multiplicity: T,
+}
@@ -0,0 +1,112 @@
+use crate::{
+    chips::precompiles::aes::sbox::{columns::AesSboxCols, AesSboxChip},
+    compiler::riscv::opcode::ByteOpcode,
+    machine::builder::{ChipBuilder, ChipLookupBuilder},
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for AesSboxChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &AesSboxCols<CB::Var> = (*local).borrow();
+
+        let xor = ByteOpcode::XOR.as_field::<CB::F>();
+        let msb = ByteOpcode::MSB.as_field::<CB::F>();
+        let aes_sbox = ByteOpcode::AesSbox.as_field::<CB::F>();
+
+        // `a * x` is `a` shifted left with its top bit reduced by the AES polynomial.
+        let looking_xtime = |builder: &mut CB, a: CB::Var, a_msb: CB::Var, result: CB::Var| {
+            builder.looking_byte(msb, a_msb, a, CB::F::ZERO, CB::F::ONE);
+            builder.looking_byte(
+                xor,
+                result,
+                a * CB::F::TWO - a_msb * CB::F::from_canonical_u32(256),
+                a_msb * CB::F::from_canonical_u8(0x1b),
+                CB::F::ONE,
+            );
+        };
+
+        // The powers of the input, whose first lookup also checks that the input is a byte.
+        for i in 0..7 {
+            looking_xtime(
+                builder,
+                local.powers[i],
+                local.powers_msb[i],
+                local.powers[i + 1],
+            );
+        }
+
+        // The product of the input and its inverse is one, unless the input is zero and the
+        // inverse taken as zero.
+        let input = local.powers[0];
+        let inv = (0..8)
+            .map(|i| local.inv_bits[i] * CB::F::from_canonical_u32(1 << i))
+            .sum::<CB::Expr>();
+        builder.assert_bool(local.is_zero);
+        builder.when(local.is_zero).assert_zero(input);
+        builder.when(local.is_zero).assert_zero(inv.clone());
+        for i in 0..8 {
+            builder.assert_bool(local.inv_bits[i]);
+            builder.assert_eq(local.masked[i], local.inv_bits[i] * local.powers[i]);
+        }
+        builder.looking_byte(
+            xor,
+            local.product[0],
+            local.masked[0],
+            local.masked[1],
+            CB::F::ONE,
+        );
+        for i in 1..7 {
+            builder.looking_byte(
+                xor,
+                local.product[i],
+                local.product[i - 1],
+                local.masked[i + 1],
+                CB::F::ONE,
+            );
+        }
+        builder.assert_eq(local.product[6], CB::Expr::ONE - local.is_zero);
+
+        // The affine map of the inverse.
+        let rotate = |k: usize| {
+            (0..8)
+                .map(|i| local.inv_bits[i] * CB::F::from_canonical_u32(1 << ((i + k) % 8)))
+                .sum::<CB::Expr>()
+        };
+        builder.looking_byte(xor, local.affine[0], inv, rotate(1), CB::F::ONE);
+        for k in 1..4 {
+            builder.looking_byte(
+                xor,
+                local.affine[k],
+                local.affine[k - 1],
+                rotate(k + 1),
+                CB::F::ONE,
+            );
+        }
+        builder.looking_byte(
+            xor,
+            local.sbox,
+            local.affine[3],
+            CB::F::from_canonical_u8(0x63),
+            CB::F::ONE,
+        );
+        looking_xtime(builder, local.sbox, local.sbox_msb, local.sbox_xtime);
+
+        // Serve the S-box lookups of the AES chips.
+        builder.looked_byte_pair(
+            aes_sbox,
+            local.sbox,
+            local.sbox_xtime,
+            input,
+            CB::F::ZERO,
+            local.multiplicity,
+        );
+    }
+}
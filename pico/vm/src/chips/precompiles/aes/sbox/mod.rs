@@ -0,0 +1,26 @@
+use crate::emulator::riscv::syscalls::SyscallCode;
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// The syscalls whose chips look up the AES S-box.
+pub(crate) const AES_SBOX_SYSCALLS: [SyscallCode; 3] = [
+    SyscallCode::AES128_ENCRYPT_BLOCK,
+    SyscallCode::AES_KEY_SCHEDULE,
+    SyscallCode::AES256_ENCRYPT_BLOCK,
+];
+
+/// The AES S-box as a lookup table, one row per input byte.
+///
+/// The S-box is kept out of the byte table so that proofs which do not use AES keep their
+/// shapes. The table has no preprocessed trace either: each row proves its own entry from the
+/// definition of the S-box. The inverse of the input in GF(2^8) is given as bits and checked by
+/// multiplying it back to one, adding up the multiples of the input by powers of `x`. The S-box
+/// output is the affine map of the inverse, which is XOR of its rotations and `0x63`. All XORs
+/// and most significant bits are byte lookups.
+#[derive(Default)]
+pub struct AesSboxChip<F> {
+    _phantom: PhantomData<F>,
+}
@@ -0,0 +1,167 @@
+use crate::{
+    chips::{
+        chips::byte::event::{ByteLookupEvent, ByteRecordBehavior},
+        precompiles::aes::sbox::{
+            columns::{AesSboxCols, NUM_AES_SBOX_COLS},
+            AesSboxChip, AES_SBOX_SYSCALLS,
+        },
+    },
+    compiler::riscv::{opcode::ByteOpcode, program::Program},
+    emulator::riscv::{record::EmulationRecord, syscalls::precompiles::aes::xtime},
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use std::borrow::BorrowMut;
+
+/// The table has one row per input byte.
+pub const NUM_ROWS: usize = 1 << 8;
+
+impl<F: PrimeField32> BaseAir<F> for AesSboxChip<F> {
+    fn width(&self) -> usize {
+        NUM_AES_SBOX_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for AesSboxChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "AesSbox".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = vec![[F::ZERO; NUM_AES_SBOX_COLS]; NUM_ROWS];
+        for (b, row) in rows.iter_mut().enumerate() {
+            let cols: &mut AesSboxCols<F> = row.as_mut_slice().borrow_mut();
+            Self::populate(cols, b as u8, &mut Vec::new());
+        }
+        for (lookup, mult) in input.byte_lookups.iter() {
+            if lookup.opcode == ByteOpcode::AesSbox {
+                let cols: &mut AesSboxCols<F> = rows[lookup.b as usize].as_mut_slice().borrow_mut();
+                cols.multiplicity += F::from_canonical_usize(*mult);
+            }
+        }
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_AES_SBOX_COLS)
+    }
+
+    fn extra_record(&self, _: &Self::Record, output: &mut Self::Record) {
+        let mut blu = vec![];
+        let mut cols = AesSboxCols::<F>::default();
+        for b in 0..=u8::MAX {
+            Self::populate(&mut cols, b, &mut blu);
+        }
+        output.add_byte_lookup_events(blu);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            AES_SBOX_SYSCALLS
+                .iter()
+                .any(|&code| !record.get_precompile_events(code).is_empty())
+        }
+    }
+}
+
+/// Looks up `b ^ c` in the byte table.
+fn xor(blu: &mut impl ByteRecordBehavior, b: u8, c: u8) -> u8 {
+    let a = b ^ c;
+    blu.add_byte_lookup_event(ByteLookupEvent::new(ByteOpcode::XOR, a.into(), 0, b, c));
+    a
+}
+
+/// Looks up the most significant bit of `b` and `b * x` in the byte table.
+fn xtime_lookups(blu: &mut impl ByteRecordBehavior, b: u8) -> (u8, u8) {
+    let msb = b >> 7;
+    blu.add_byte_lookup_event(ByteLookupEvent::new(ByteOpcode::MSB, msb.into(), 0, b, 0));
+    let result = xor(blu, b << 1, msb * 0x1b);
+    debug_assert_eq!(result, xtime(b));
+    (msb, result)
+}
+
+/// The inverse of `b` in GF(2^8), taking zero to zero.
+fn gf_inv(b: u8) -> u8 {
+    // b^254 is the inverse of a non-zero b.
+    let mut result = 1u8;
+    for _ in 0..254 {
+        result = gf_mul(result, b);
+    }
+    result
+}
+
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+    product
+}
+
+impl<F: PrimeField32> AesSboxChip<F> {
+    fn populate(cols: &mut AesSboxCols<F>, b: u8, blu: &mut impl ByteRecordBehavior) {
+        let mut powers = [b; 8];
+        for i in 0..7 {
+            let (msb, next) = xtime_lookups(blu, powers[i]);
+            cols.powers_msb[i] = F::from_canonical_u8(msb);
+            powers[i + 1] = next;
+        }
+        cols.powers = powers.map(F::from_canonical_u8);
+
+        let inv = if b == 0 { 0 } else { gf_inv(b) };
+        cols.is_zero = F::from_bool(b == 0);
+        let masked: [u8; 8] = core::array::from_fn(|i| {
+            let bit = (inv >> i) & 1;
+            cols.inv_bits[i] = F::from_canonical_u8(bit);
+            bit * powers[i]
+        });
+        cols.masked = masked.map(F::from_canonical_u8);
+        let mut product = xor(blu, masked[0], masked[1]);
+        cols.product[0] = F::from_canonical_u8(product);
+        for i in 1..7 {
+            product = xor(blu, product, masked[i + 1]);
+            cols.product[i] = F::from_canonical_u8(product);
+        }
+
+        let mut affine = inv;
+        for k in 0..4 {
+            affine = xor(blu, affine, inv.rotate_left(k as u32 + 1));
+            cols.affine[k] = F::from_canonical_u8(affine);
+        }
+        let sbox = xor(blu, affine, 0x63);
+        let (sbox_msb, sbox_xtime) = xtime_lookups(blu, sbox);
+        cols.sbox = F::from_canonical_u8(sbox);
+        cols.sbox_msb = F::from_canonical_u8(sbox_msb);
+        cols.sbox_xtime = F::from_canonical_u8(sbox_xtime);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::emulator::riscv::syscalls::precompiles::aes::AES_SBOX;
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_aes_sbox_table() {
+        for b in 0..=u8::MAX {
+            let mut cols = AesSboxCols::<BabyBear>::default();
+            AesSboxChip::populate(&mut cols, b, &mut Vec::new());
+            assert_eq!(cols.sbox.as_canonical_u32(), AES_SBOX[b as usize] as u32);
+            assert_eq!(
+                cols.sbox_xtime.as_canonical_u32(),
+                xtime(AES_SBOX[b as usize]) as u32
+            );
+            assert_eq!(cols.product[6].as_canonical_u32(), (b != 0) as u32);
+        }
+    }
+}
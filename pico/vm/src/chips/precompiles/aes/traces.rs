@@ -0,0 +1,233 @@
+use crate::{
+    chips::{
+        chips::byte::event::{ByteLookupEvent, ByteRecordBehavior},
+        precompiles::aes::{
+            columns::{Aes128EncryptBlockCols, NUM_AES128_ENCRYPT_BLOCK_COLS},
+            Aes128EncryptBlockChip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::riscv::{opcode::ByteOpcode, program::Program},
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{
+                aes::{
+                    aes_words_to_bytes, xtime, AES128_NUM_ROUNDS, AES_RCON, AES_SBOX,
+                    AES_SHIFT_ROWS,
+                },
+                Aes128EncryptBlockEvent, PrecompileEvent,
+            },
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for Aes128EncryptBlockChip<F> {
+    fn width(&self) -> usize {
+        NUM_AES128_ENCRYPT_BLOCK_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for Aes128EncryptBlockChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Aes128EncryptBlock".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        for (_, event) in input
+            .get_precompile_events(SyscallCode::AES128_ENCRYPT_BLOCK)
+            .iter()
+        {
+            let event = if let PrecompileEvent::Aes128EncryptBlock(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut Vec::new());
+        }
+
+        let mut rows = rows.unwrap();
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(
+            &mut rows,
+            || [F::ZERO; NUM_AES128_ENCRYPT_BLOCK_COLS],
+            log_rows,
+        );
+
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect(),
+            NUM_AES128_ENCRYPT_BLOCK_COLS,
+        )
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::AES128_ENCRYPT_BLOCK)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::Aes128EncryptBlock(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_rows(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record
+                .get_precompile_events(SyscallCode::AES128_ENCRYPT_BLOCK)
+                .is_empty()
+        }
+    }
+}
+
+/// Looks up `b ^ c` in the byte table.
+fn xor(blu: &mut impl ByteRecordBehavior, b: u8, c: u8) -> u8 {
+    let a = b ^ c;
+    blu.add_byte_lookup_event(ByteLookupEvent::new(ByteOpcode::XOR, a.into(), 0, b, c));
+    a
+}
+
+/// Looks up the S-box output of `b` and its multiple by `x` in the S-box table.
+fn sbox(blu: &mut impl ByteRecordBehavior, b: u8) -> (u8, u8) {
+    let a = AES_SBOX[b as usize];
+    blu.add_byte_lookup_event(ByteLookupEvent::new(
+        ByteOpcode::AesSbox,
+        a.into(),
+        xtime(a),
+        b,
+        0,
+    ));
+    (a, xtime(a))
+}
+
+impl<F: PrimeField32> Aes128EncryptBlockChip<F> {
+    fn event_to_rows(
+        &self,
+        event: &Aes128EncryptBlockEvent,
+        rows: &mut Option<Vec<[F; NUM_AES128_ENCRYPT_BLOCK_COLS]>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        let input = aes_words_to_bytes(&event.input);
+        let output = aes_words_to_bytes(&event.output);
+        let mut prev_key = aes_words_to_bytes(&event.key);
+        let mut state: [u8; 16] = core::array::from_fn(|i| xor(blu, input[i], prev_key[i]));
+
+        for round in 0..AES128_NUM_ROUNDS {
+            let mut row = [F::ZERO; NUM_AES128_ENCRYPT_BLOCK_COLS];
+            let cols: &mut Aes128EncryptBlockCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+            cols.key_ptr = F::from_canonical_u32(event.key_ptr);
+            cols.round[round] = F::ONE;
+            cols.output = output.map(F::from_canonical_u8);
+
+            if round == 0 {
+                for i in 0..4 {
+                    cols.key_access[i].populate(event.key_read_records[i], blu);
+                    cols.block_access[i].populate(event.block_write_records[i], blu);
+                }
+            }
+
+            // Key schedule.
+            let mut round_key = [0u8; 16];
+            let mut key_sbox = [0u8; 4];
+            for i in 0..4 {
+                let (s, s_xtime) = sbox(blu, prev_key[12 + (i + 1) % 4]);
+                key_sbox[i] = s;
+                cols.key_sbox_xtime[i] = F::from_canonical_u8(s_xtime);
+            }
+            cols.key_sbox = key_sbox.map(F::from_canonical_u8);
+            let key_sbox_rcon = xor(blu, key_sbox[0], AES_RCON[round]);
+            cols.key_sbox_rcon = F::from_canonical_u8(key_sbox_rcon);
+            for i in 0..16 {
+                let term = match i {
+                    0 => key_sbox_rcon,
+                    1..=3 => key_sbox[i],
+                    _ => round_key[i - 4],
+                };
+                round_key[i] = xor(blu, prev_key[i], term);
+            }
+
+            // SubBytes.
+            let mut sbox_out = [0u8; 16];
+            let mut sbox_xtime = [0u8; 16];
+            for i in 0..16 {
+                (sbox_out[i], sbox_xtime[i]) = sbox(blu, state[i]);
+            }
+
+            // ShiftRows and MixColumns.
+            let shifted = AES_SHIFT_ROWS.map(|j| sbox_out[j]);
+            let shifted_xtime = AES_SHIFT_ROWS.map(|j| sbox_xtime[j]);
+            let mut mix = [0u8; 16];
+            for i in 0..16 {
+                let (c, r) = (i / 4, i % 4);
+                let at = |k: usize| 4 * c + (r + k) % 4;
+                let p0 = xor(blu, shifted_xtime[at(0)], shifted_xtime[at(1)]);
+                let p1 = xor(blu, p0, shifted[at(1)]);
+                let p2 = xor(blu, p1, shifted[at(2)]);
+                mix[i] = xor(blu, p2, shifted[at(3)]);
+                cols.mix_partial[i] = [p0, p1, p2].map(F::from_canonical_u8);
+            }
+            let round_out = if round == AES128_NUM_ROUNDS - 1 {
+                shifted
+            } else {
+                mix
+            };
+
+            // AddRoundKey.
+            let state_out: [u8; 16] =
+                core::array::from_fn(|i| xor(blu, round_out[i], round_key[i]));
+
+            cols.prev_key = prev_key.map(F::from_canonical_u8);
+            cols.round_key = round_key.map(F::from_canonical_u8);
+            cols.state_in = state.map(F::from_canonical_u8);
+            cols.sbox = sbox_out.map(F::from_canonical_u8);
+            cols.sbox_xtime = sbox_xtime.map(F::from_canonical_u8);
+            cols.mix = mix.map(F::from_canonical_u8);
+            cols.round_out = round_out.map(F::from_canonical_u8);
+            cols.state_out = state_out.map(F::from_canonical_u8);
+
+            prev_key = round_key;
+            state = state_out;
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+
+        debug_assert_eq!(state, output, "AES128_ENCRYPT_BLOCK output mismatch");
+    }
+}
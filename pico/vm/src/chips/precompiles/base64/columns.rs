@@ -0,0 +1,57 @@
+use crate::{
+    chips::chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+    compiler::word::Word,
+    emulator::riscv::syscalls::precompiles::base64::{BASE64_GROUPS_PER_ROW, BASE64_NUM_CLASSES},
+    primitives::consts::WORD_SIZE,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_BASE64_DECODE_COLS: usize = size_of::<Base64DecodeCols<u8>>();
+
+/// The number of output words a row writes, holding the 3 bytes of each of its groups.
+pub const BASE64_OUTPUT_WORDS_PER_ROW: usize = 3;
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Base64DecodeCols<T> {
+    pub chunk: T,
+    pub clk: T,
+    pub input_ptr: T,
+    pub output_ptr: T,
+
+    /// The input length in bytes, read from `input_ptr` on the first row.
+    pub len: Word<T>,
+    pub len_access: MemoryReadCols<T>,
+
+    /// The number of groups decoded by the previous rows of the event.
+    pub offset: T,
+
+    /// The input words of the groups of this row.
+    pub input_access: [MemoryReadCols<T>; BASE64_GROUPS_PER_ROW],
+    /// Whether each group is part of the input. Only the last row may have inactive groups, and
+    /// they come after the active ones.
+    pub is_active: [T; BASE64_GROUPS_PER_ROW],
+
+    /// One-hot class of each character, from `BASE64_UPPER` to `BASE64_PAD`.
+    pub class: [[[T; BASE64_NUM_CLASSES]; WORD_SIZE]; BASE64_GROUPS_PER_ROW],
+    /// Each character minus the smallest character of its class.
+    pub rel: [[T; WORD_SIZE]; BASE64_GROUPS_PER_ROW],
+
+    /// The high 2 and low 4 bits of the second sextet of each group.
+    pub s1_hi: [T; BASE64_GROUPS_PER_ROW],
+    pub s1_lo: [T; BASE64_GROUPS_PER_ROW],
+    /// The high 4 and low 2 bits of the third sextet of each group.
+    pub s2_hi: [T; BASE64_GROUPS_PER_ROW],
+    pub s2_lo: [T; BASE64_GROUPS_PER_ROW],
+
+    /// The output words of the groups of this row.
+    pub output_access: [MemoryWriteCols<T>; BASE64_OUTPUT_WORDS_PER_ROW],
+
+    /// The decoded length, written to `output_ptr` on the last row.
+    pub decoded_len_access: MemoryWriteCols<T>,
+
+    pub is_first: T,
+    pub is_last: T,
+    pub is_real: T,
+}
@@ -0,0 +1,290 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        precompiles::base64::{
+            columns::{Base64DecodeCols, BASE64_OUTPUT_WORDS_PER_ROW},
+            Base64DecodeChip,
+        },
+    },
+    emulator::riscv::syscalls::{
+        precompiles::base64::{
+            BASE64_CLASS_BASE, BASE64_CLASS_SPAN, BASE64_CLASS_START, BASE64_GROUPS_PER_ROW,
+            BASE64_PAD,
+        },
+        SyscallCode,
+    },
+    machine::builder::{
+        ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, ChipRangeBuilder, ChipWordBuilder,
+        RiscVMemoryBuilder,
+    },
+    primitives::consts::WORD_SIZE,
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for Base64DecodeChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Base64DecodeCols<CB::Var> = (*local).borrow();
+        let next: &Base64DecodeCols<CB::Var> = (*next).borrow();
+
+        self.eval_control_flow(builder, local, next);
+
+        self.eval_decode(builder, local);
+
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::BASE64_DECODE.syscall_id()),
+            local.input_ptr,
+            local.output_ptr,
+            local.is_first,
+        );
+    }
+}
+
+impl<F: PrimeField32> Base64DecodeChip<F> {
+    fn eval_control_flow<CB: ChipBuilder<F>>(
+        &self,
+        builder: &mut CB,
+        local: &Base64DecodeCols<CB::Var>,
+        next: &Base64DecodeCols<CB::Var>,
+    ) {
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // Rows of an event are contiguous and the table ends on the last row of an event. The row
+        // after a last row starts the next event, if there is one.
+        let in_event = local.is_real - local.is_last;
+        builder
+            .when_first_row()
+            .assert_eq(local.is_first, local.is_real);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_one(next.is_real);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_zero(next.is_first);
+        builder
+            .when_transition()
+            .when(local.is_last)
+            .assert_eq(next.is_first, next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+        builder
+            .when_last_row()
+            .assert_eq(local.is_real, local.is_last);
+
+        // The inputs stay the same for all rows of an event.
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(local.chunk, next.chunk);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(local.clk, next.clk);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(local.input_ptr, next.input_ptr);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(local.output_ptr, next.output_ptr);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_word_eq(local.len, next.len);
+
+        // The offset advances by a row of groups.
+        builder.when(local.is_first).assert_zero(local.offset);
+        builder.when_transition().when(in_event.clone()).assert_eq(
+            next.offset,
+            local.offset + CB::F::from_canonical_usize(BASE64_GROUPS_PER_ROW),
+        );
+
+        // The active groups are a prefix of the row, and only the last row may be partial.
+        for k in 0..BASE64_GROUPS_PER_ROW {
+            builder.assert_bool(local.is_active[k]);
+        }
+        builder
+            .when_not(local.is_real)
+            .assert_zero(local.is_active[0]);
+        for k in 1..BASE64_GROUPS_PER_ROW {
+            builder
+                .when(local.is_active[k])
+                .assert_one(local.is_active[k - 1]);
+        }
+        builder
+            .when(in_event)
+            .assert_one(local.is_active[BASE64_GROUPS_PER_ROW - 1]);
+
+        // The length is read on the first row, and is below 2^24 bytes.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.input_ptr,
+            &local.len_access,
+            local.is_first,
+        );
+        builder
+            .when(local.is_first)
+            .assert_word_eq(local.len, *local.len_access.value());
+        builder.when(local.is_first).assert_zero(local.len[3]);
+        builder.slice_range_check_u8(&local.len.0, local.is_first);
+
+        // The groups end with the input, which is therefore a multiple of 4 bytes long.
+        let num_groups = local.offset + self.num_active::<CB>(local);
+        builder.when(local.is_last).assert_eq(
+            local.len.reduce::<CB>(),
+            num_groups * CB::F::from_canonical_usize(WORD_SIZE),
+        );
+    }
+
+    /// Constrains the groups of a row to decode to the output words, and the decoded length on
+    /// the last row.
+    fn eval_decode<CB: ChipBuilder<F>>(&self, builder: &mut CB, local: &Base64DecodeCols<CB::Var>) {
+        let weighted = |flags: &[CB::Var], weight: &[u8]| {
+            flags
+                .iter()
+                .zip(weight)
+                .map(|(&flag, &weight)| flag * CB::F::from_canonical_u8(weight))
+                .sum::<CB::Expr>()
+        };
+        let sixteen = CB::F::from_canonical_u32(16);
+        let sixty_four = CB::F::from_canonical_u32(64);
+
+        let mut range_checks = Vec::new();
+        let mut bytes = Vec::new();
+        let mut num_pads = CB::Expr::ZERO;
+        for k in 0..BASE64_GROUPS_PER_ROW {
+            builder.eval_memory_access(
+                local.chunk,
+                local.clk,
+                local.input_ptr
+                    + CB::F::from_canonical_usize(WORD_SIZE)
+                    + (local.offset + CB::F::from_canonical_usize(k))
+                        * CB::F::from_canonical_usize(WORD_SIZE),
+                &local.input_access[k],
+                local.is_active[k],
+            );
+            let chars = local.input_access[k].value();
+
+            // Each character of an active group is in exactly one class, within the range of the
+            // class. Inactive groups are zero, so that they decode to zero bytes.
+            let mut sextets = Vec::with_capacity(WORD_SIZE);
+            for j in 0..WORD_SIZE {
+                let class = &local.class[k][j];
+                let rel = local.rel[k][j];
+                for &flag in class {
+                    builder.assert_bool(flag);
+                }
+                builder.assert_eq(
+                    class.iter().map(|&flag| flag.into()).sum::<CB::Expr>(),
+                    local.is_active[k],
+                );
+                builder.when_not(local.is_active[k]).assert_zero(chars[j]);
+                builder.assert_eq(rel, chars[j] - weighted(class, &BASE64_CLASS_START));
+                range_checks.push(rel.into());
+                range_checks.push(weighted(class, &BASE64_CLASS_SPAN) - rel);
+
+                sextets.push(rel + weighted(class, &BASE64_CLASS_BASE));
+            }
+
+            // Padding is only allowed in the last two characters of the last group, and the
+            // second to last character is only padding if the last one is.
+            let pad = |j: usize| local.class[k][j][BASE64_PAD];
+            let next_active = if k + 1 < BASE64_GROUPS_PER_ROW {
+                local.is_active[k + 1].into()
+            } else {
+                CB::Expr::ZERO
+            };
+            let is_last_group = local.is_last * (local.is_active[k] - next_active);
+            builder.assert_zero(pad(0));
+            builder.assert_zero(pad(1));
+            builder.when(pad(2)).assert_one(pad(3));
+            builder.when(pad(3)).assert_one(is_last_group);
+            num_pads = num_pads + pad(2) + pad(3);
+
+            // Split the middle sextets at the byte boundaries.
+            builder.assert_eq(
+                sextets[1].clone(),
+                local.s1_hi[k] * sixteen + local.s1_lo[k],
+            );
+            builder.assert_eq(
+                sextets[2].clone(),
+                local.s2_hi[k] * CB::F::from_canonical_u32(4) + local.s2_lo[k],
+            );
+            range_checks.push(local.s1_hi[k] * sixty_four);
+            range_checks.push(local.s1_lo[k] * sixteen);
+            range_checks.push(local.s2_hi[k] * sixteen);
+            range_checks.push(local.s2_lo[k] * sixty_four);
+
+            // The bits left over before the padding are zero.
+            builder.when(pad(2)).assert_zero(local.s1_lo[k]);
+            builder.when(pad(3)).assert_zero(local.s2_lo[k]);
+
+            bytes.push(sextets[0].clone() * CB::F::from_canonical_u32(4) + local.s1_hi[k]);
+            bytes.push(local.s1_lo[k] * sixteen + local.s2_hi[k]);
+            bytes.push(local.s2_lo[k] * sixty_four + sextets[3].clone());
+        }
+        builder.slice_range_check_u8(&range_checks, local.is_real);
+
+        // Write the decoded bytes of the row. A word is written if its first byte belongs to an
+        // active group, and the bytes of inactive groups are zero.
+        for w in 0..BASE64_OUTPUT_WORDS_PER_ROW {
+            let value = local.output_access[w].value();
+            for j in 0..WORD_SIZE {
+                builder.assert_eq(value[j], bytes[w * WORD_SIZE + j].clone());
+            }
+            builder.eval_memory_access(
+                local.chunk,
+                local.clk + CB::F::ONE,
+                local.output_ptr
+                    + CB::F::from_canonical_usize(WORD_SIZE)
+                    + local.offset * CB::F::from_canonical_u32(3)
+                    + CB::F::from_canonical_usize(w * WORD_SIZE),
+                &local.output_access[w],
+                local.is_active[w],
+            );
+        }
+
+        // The decoded length is 3 bytes per group, minus one per padding character.
+        let decoded_len = local.decoded_len_access.value();
+        let num_groups = local.offset + self.num_active::<CB>(local);
+        builder.when(local.is_last).assert_eq(
+            decoded_len.reduce::<CB>(),
+            num_groups * CB::F::from_canonical_u32(3) - num_pads,
+        );
+        builder.when(local.is_last).assert_zero(decoded_len[3]);
+        builder.slice_range_check_u8(&decoded_len.0, local.is_last);
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.output_ptr,
+            &local.decoded_len_access,
+            local.is_last,
+        );
+    }
+
+    fn num_active<CB: ChipBuilder<F>>(&self, local: &Base64DecodeCols<CB::Var>) -> CB::Expr {
+        local
+            .is_active
+            .iter()
+            .map(|&active| active.into())
+            .sum::<CB::Expr>()
+    }
+}
@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// Proves `BASE64_DECODE`, four groups of 4 characters per row.
+///
+/// An event takes up as many rows as it takes to cover its groups, and at least one. The first
+/// row reads the input length, every row reads its input words and writes the 3 output words they
+/// decode to, and the last row writes the decoded length. Each character is mapped to its 6-bit
+/// value by range checking it against the start of its one-hot class, and the sextets are split
+/// at the byte boundaries by range checked limbs.
+#[derive(Default)]
+pub struct Base64DecodeChip<F> {
+    _phantom: PhantomData<F>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{bytes_to_words, prove_program, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_base64_decode_prove() {
+        const INPUT_PTR: u32 = 0x2000;
+        const OUTPUT_PTR: u32 = 0x3000;
+
+        // Six groups, so that the second row is only half filled, ending in padding.
+        let text = b"UGljbyBkZWNvZGVzIGl0IQ==";
+        let mut memory_image = bytes_to_words(INPUT_PTR + 4, text).collect::<BTreeMap<_, _>>();
+        memory_image.insert(INPUT_PTR, text.len() as u32);
+        let program = syscall_program(
+            &[(SyscallCode::BASE64_DECODE, INPUT_PTR, OUTPUT_PTR)],
+            memory_image,
+        );
+        prove_program(program).unwrap();
+    }
+}
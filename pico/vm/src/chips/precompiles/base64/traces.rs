@@ -0,0 +1,186 @@
+use crate::{
+    chips::{
+        chips::byte::event::ByteRecordBehavior,
+        precompiles::base64::{
+            columns::{Base64DecodeCols, BASE64_OUTPUT_WORDS_PER_ROW, NUM_BASE64_DECODE_COLS},
+            Base64DecodeChip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::{riscv::program::Program, word::Word},
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{
+                base64::{
+                    base64_char_class, base64_sextet, BASE64_CLASS_SPAN, BASE64_CLASS_START,
+                    BASE64_GROUPS_PER_ROW,
+                },
+                Base64DecodeEvent, PrecompileEvent,
+            },
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for Base64DecodeChip<F> {
+    fn width(&self) -> usize {
+        NUM_BASE64_DECODE_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for Base64DecodeChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Base64Decode".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut wrapped_rows = Some(Vec::new());
+        for (_, event) in input.get_precompile_events(SyscallCode::BASE64_DECODE) {
+            let event = if let PrecompileEvent::Base64Decode(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut wrapped_rows, &mut Vec::new());
+        }
+        let mut rows = wrapped_rows.unwrap();
+
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(&mut rows, || [F::ZERO; NUM_BASE64_DECODE_COLS], log_rows);
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_BASE64_DECODE_COLS)
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::BASE64_DECODE)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::Base64Decode(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_rows(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record
+                .get_precompile_events(SyscallCode::BASE64_DECODE)
+                .is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> Base64DecodeChip<F> {
+    fn event_to_rows(
+        &self,
+        event: &Base64DecodeEvent,
+        rows: &mut Option<Vec<[F; NUM_BASE64_DECODE_COLS]>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        let num_groups = event.len as usize / 4;
+        let num_rows = std::cmp::max(num_groups.div_ceil(BASE64_GROUPS_PER_ROW), 1);
+
+        for row_idx in 0..num_rows {
+            let mut row = [F::ZERO; NUM_BASE64_DECODE_COLS];
+            let cols: &mut Base64DecodeCols<F> = row.as_mut_slice().borrow_mut();
+
+            let is_first = row_idx == 0;
+            let is_last = row_idx == num_rows - 1;
+            cols.is_real = F::ONE;
+            cols.is_first = F::from_bool(is_first);
+            cols.is_last = F::from_bool(is_last);
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.input_ptr = F::from_canonical_u32(event.input_ptr);
+            cols.output_ptr = F::from_canonical_u32(event.output_ptr);
+            cols.len = Word::from(event.len);
+
+            if is_first {
+                cols.len_access.populate(event.len_read_record, blu);
+                blu.add_u8_range_checks(event.len.to_le_bytes());
+            }
+
+            let offset = row_idx * BASE64_GROUPS_PER_ROW;
+            cols.offset = F::from_canonical_usize(offset);
+
+            let mut range_checks = Vec::new();
+            for k in 0..BASE64_GROUPS_PER_ROW {
+                let group_idx = offset + k;
+                let is_active = group_idx < num_groups;
+                cols.is_active[k] = F::from_bool(is_active);
+
+                let mut sextets = [0u8; 4];
+                if is_active {
+                    cols.input_access[k].populate(event.input_read_records[group_idx], blu);
+                    let chars = event.input[group_idx].to_le_bytes();
+                    for (j, &c) in chars.iter().enumerate() {
+                        let class = base64_char_class(c).unwrap();
+                        let rel = c - BASE64_CLASS_START[class];
+                        cols.class[k][j][class] = F::ONE;
+                        cols.rel[k][j] = F::from_canonical_u8(rel);
+                        range_checks.extend([rel, BASE64_CLASS_SPAN[class] - rel]);
+                        sextets[j] = base64_sextet(c, class);
+                    }
+                } else {
+                    range_checks.extend([0; 2 * 4]);
+                }
+
+                let (s1_hi, s1_lo) = (sextets[1] >> 4, sextets[1] & 0xf);
+                let (s2_hi, s2_lo) = (sextets[2] >> 2, sextets[2] & 0x3);
+                cols.s1_hi[k] = F::from_canonical_u8(s1_hi);
+                cols.s1_lo[k] = F::from_canonical_u8(s1_lo);
+                cols.s2_hi[k] = F::from_canonical_u8(s2_hi);
+                cols.s2_lo[k] = F::from_canonical_u8(s2_lo);
+                range_checks.extend([s1_hi * 64, s1_lo * 16, s2_hi * 16, s2_lo * 64]);
+            }
+            blu.add_u8_range_checks(range_checks);
+
+            for w in 0..BASE64_OUTPUT_WORDS_PER_ROW {
+                if offset + w < num_groups {
+                    let word_idx = row_idx * BASE64_OUTPUT_WORDS_PER_ROW + w;
+                    cols.output_access[w].populate(event.output_write_records[word_idx], blu);
+                }
+            }
+
+            if is_last {
+                cols.decoded_len_access
+                    .populate(event.decoded_len_write_record, blu);
+                blu.add_u8_range_checks(event.decoded_len.to_le_bytes());
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
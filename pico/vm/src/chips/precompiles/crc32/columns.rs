@@ -0,0 +1,54 @@
+use crate::{
+    chips::chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+    compiler::word::Word,
+    emulator::riscv::syscalls::precompiles::crc32::CRC32_NUM_ARGS,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_CRC32_COLS: usize = size_of::<Crc32Cols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Crc32Cols<T> {
+    /// Inputs, copied over every row of an event.
+    pub chunk: T,
+    pub clk: T,
+    pub data_ptr: T,
+    pub args_ptr: T,
+    pub len: T,
+    pub crc_ptr: T,
+    /// The checksum the event continues.
+    pub crc_in: Word<T>,
+
+    /// The index of the first byte of this row, increasing by 4 from zero.
+    pub offset: T,
+
+    /// Whether the current row is the first of an event and is real.
+    pub is_first: T,
+    /// Whether the current row is the last of an event and is real.
+    pub is_last: T,
+
+    /// The argument block `[len, crc_ptr]`, read on the first row.
+    pub args: [MemoryReadCols<T>; CRC32_NUM_ARGS],
+
+    /// The data word of this row.
+    pub data: MemoryReadCols<T>,
+    /// Whether each byte of `data` is part of the buffer, only the last row having unused bytes.
+    pub is_byte: [T; 4],
+
+    /// The CRC register before each byte of the row, and after the last one.
+    pub register: [Word<T>; 5],
+    /// The low byte of the register XORed with each data byte.
+    pub index: [T; 4],
+    /// The table entry of each `index`.
+    pub entry: [Word<T>; 4],
+    /// The low three bytes of each entry XORed with the register shifted right by a byte. The top
+    /// byte of the shifted register is zero, so the entry's top byte is kept as is.
+    pub shifted: [[T; 3]; 4],
+
+    /// The write of the checksum on the last row. The previous value is `crc_in`.
+    pub crc: MemoryWriteCols<T>,
+
+    pub is_real: T,
+}
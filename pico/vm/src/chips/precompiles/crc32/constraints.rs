@@ -0,0 +1,193 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        precompiles::crc32::{columns::Crc32Cols, Crc32Chip},
+    },
+    compiler::riscv::opcode::ByteOpcode,
+    emulator::riscv::syscalls::SyscallCode,
+    machine::builder::{ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, RiscVMemoryBuilder},
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for Crc32Chip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Crc32Cols<CB::Var> = (*local).borrow();
+        let next: &Crc32Cols<CB::Var> = (*next).borrow();
+
+        let xor = ByteOpcode::XOR.as_field::<CB::F>();
+        let crc32 = ByteOpcode::Crc32.as_field::<CB::F>();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // The bytes in use are a prefix of the word, and the first one is used on every real row.
+        builder.assert_eq(local.is_byte[0], local.is_real);
+        for k in 0..4 {
+            builder.assert_bool(local.is_byte[k]);
+            if k > 0 {
+                builder
+                    .when(local.is_byte[k])
+                    .assert_one(local.is_byte[k - 1]);
+            }
+        }
+
+        // The table starts with a new event or with padding.
+        builder
+            .when_first_row()
+            .assert_eq(local.is_first, local.is_real);
+
+        // Within an event, every byte of a row but the last is used, and the next row continues
+        // from the register left by this one with the same inputs.
+        let is_continuing: CB::Expr = local.is_real - local.is_last;
+        builder
+            .when(is_continuing.clone())
+            .assert_one(local.is_byte[3]);
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            builder.assert_one(next.is_real);
+            builder.assert_zero(next.is_first);
+            builder.assert_eq(next.offset, local.offset + CB::F::from_canonical_u32(4));
+            builder.assert_word_eq(next.register[0], local.register[4]);
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.data_ptr, local.data_ptr);
+            builder.assert_eq(next.args_ptr, local.args_ptr);
+            builder.assert_eq(next.len, local.len);
+            builder.assert_eq(next.crc_ptr, local.crc_ptr);
+            builder.assert_word_eq(next.crc_in, local.crc_in);
+        }
+
+        // The last row shifts in the bytes left.
+        builder.when(local.is_last).assert_eq(
+            local.is_byte.iter().map(|&x| x.into()).sum::<CB::Expr>(),
+            local.len - local.offset,
+        );
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(local.is_last)
+            .assert_eq(next.is_first, next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing);
+
+        // Read the argument block on the first row, and start from the complement of the
+        // checksum.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.args_ptr,
+            &local.args,
+            local.is_first,
+        );
+        {
+            let mut builder = builder.when(local.is_first);
+            builder.assert_eq(local.len, local.args[0].value().reduce::<CB>());
+            builder.assert_eq(local.crc_ptr, local.args[1].value().reduce::<CB>());
+            builder.assert_zero(local.offset);
+            for i in 0..4 {
+                builder.assert_eq(
+                    local.register[0][i],
+                    CB::Expr::from_canonical_u8(u8::MAX) - local.crc_in[i],
+                );
+            }
+        }
+
+        // Read the data word.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.data_ptr + local.offset,
+            &local.data,
+            local.is_real,
+        );
+
+        // Shift each byte in use into the register, and carry the register over the others.
+        let data = local.data.value();
+        for k in 0..4 {
+            let register = local.register[k];
+            let entry = local.entry[k];
+            let is_byte = local.is_byte[k];
+
+            builder.looking_byte(xor, local.index[k], register[0], data[k], is_byte);
+            builder.looking_byte_pair(
+                crc32,
+                entry[0],
+                entry[1],
+                local.index[k],
+                CB::F::ZERO,
+                is_byte,
+            );
+            builder.looking_byte_pair(
+                crc32,
+                entry[2],
+                entry[3],
+                local.index[k],
+                CB::F::ONE,
+                is_byte,
+            );
+            for j in 0..3 {
+                builder.looking_byte(xor, local.shifted[k][j], entry[j], register[j + 1], is_byte);
+            }
+
+            let updated = [
+                local.shifted[k][0],
+                local.shifted[k][1],
+                local.shifted[k][2],
+                entry[3],
+            ];
+            for j in 0..4 {
+                builder.assert_eq(
+                    local.register[k + 1][j],
+                    register[j] + is_byte * (updated[j] - register[j]),
+                );
+            }
+        }
+
+        // Write the complement of the final register on the last row.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.crc_ptr,
+            &local.crc,
+            local.is_last,
+        );
+        {
+            let mut builder = builder.when(local.is_last);
+            builder.assert_word_eq(*local.crc.prev_value(), local.crc_in);
+            for i in 0..4 {
+                builder.assert_eq(
+                    local.crc.value()[i],
+                    CB::Expr::from_canonical_u8(u8::MAX) - local.register[4][i],
+                );
+            }
+        }
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::CRC32.syscall_id()),
+            local.data_ptr,
+            local.args_ptr,
+            local.is_first,
+        );
+    }
+}
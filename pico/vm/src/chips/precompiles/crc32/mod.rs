@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+pub mod table;
+mod traces;
+
+/// Proves `CRC32` one data word per row.
+///
+/// An event over `len` bytes spans `len.div_ceil(4)` consecutive rows, the last one possibly
+/// using only some of the bytes of its word. Each byte is shifted into the CRC register with the
+/// table-driven update `register = table[register ^ byte] ^ (register >> 8)`: the table entry is
+/// looked up in the table of the
+/// `Crc32Table` chip two bytes at a time, and the XORs are byte lookups as well.
+#[derive(Default)]
+pub struct Crc32Chip<F> {
+    _phantom: PhantomData<F>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{bytes_to_words, prove_program, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_crc32_prove() {
+        const DATA_PTR: u32 = 0x2000;
+        const ARGS_PTR: u32 = 0x3000;
+        const CRC_PTR: u32 = 0x3100;
+
+        // The last word only holds one byte of data.
+        let data = b"123456789";
+        let mut memory_image = bytes_to_words(DATA_PTR, data).collect::<BTreeMap<_, _>>();
+        memory_image.insert(ARGS_PTR, data.len() as u32);
+        memory_image.insert(ARGS_PTR + 4, CRC_PTR);
+        let program = syscall_program(&[(SyscallCode::CRC32, DATA_PTR, ARGS_PTR)], memory_image);
+        prove_program(program).unwrap();
+    }
+}
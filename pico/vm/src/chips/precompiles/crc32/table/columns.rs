@@ -0,0 +1,16 @@
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_CRC32_TABLE_COLS: usize = size_of::<Crc32TableCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Crc32TableCols<T> {
+    /// The bits of the index, least significant first.
+    pub index_bits: [T; 8],
+    /// The bytes of the running XOR of the entries of the set bits after each bit but the first,
+    /// the last one being the entry of the index.
+    pub acc: [[T; 4]; 7],
+    /// The number of lookups of the low and the high half of the entry.
+    pub multiplicities: [T; 2],
+}
@@ -0,0 +1,62 @@
+use crate::{
+    chips::precompiles::crc32::table::{columns::Crc32TableCols, Crc32TableChip},
+    compiler::riscv::opcode::ByteOpcode,
+    emulator::riscv::syscalls::precompiles::crc32::CRC32_TABLE,
+    machine::builder::{ChipBuilder, ChipLookupBuilder},
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for Crc32TableChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Crc32TableCols<CB::Var> = (*local).borrow();
+
+        let xor = ByteOpcode::XOR.as_field::<CB::F>();
+        let crc32 = ByteOpcode::Crc32.as_field::<CB::F>();
+
+        // Byte `k` of the entry of bit `i` of the index if that bit is set, and zero otherwise.
+        let term = |i: usize, k: usize| {
+            let entry_byte = CRC32_TABLE[1 << i].to_le_bytes()[k];
+            local.index_bits[i] * CB::F::from_canonical_u8(entry_byte)
+        };
+
+        for i in 0..8 {
+            builder.assert_bool(local.index_bits[i]);
+        }
+        for k in 0..4 {
+            builder.looking_byte(xor, local.acc[0][k], term(0, k), term(1, k), CB::F::ONE);
+            for i in 1..7 {
+                builder.looking_byte(
+                    xor,
+                    local.acc[i][k],
+                    local.acc[i - 1][k],
+                    term(i + 1, k),
+                    CB::F::ONE,
+                );
+            }
+        }
+
+        // Serve both halves of the entry to the CRC32 chip.
+        let index = (0..8)
+            .map(|i| local.index_bits[i] * CB::F::from_canonical_u32(1 << i))
+            .sum::<CB::Expr>();
+        let entry = local.acc[6];
+        for c in 0..2 {
+            builder.looked_byte_pair(
+                crc32,
+                entry[2 * c],
+                entry[2 * c + 1],
+                index.clone(),
+                CB::F::from_canonical_usize(c),
+                local.multiplicities[c],
+            );
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// The CRC-32 table as a lookup table, one row per index byte.
+///
+/// The table is kept out of the byte table so that proofs which do not use `CRC32` keep their
+/// shapes. It has no preprocessed trace either: the table is linear over GF(2), so each row
+/// proves its entry as the XOR of the entries of the bits of its index, one byte lookup per bit
+/// and entry byte. A row serves the low and the high half of its entry with two multiplicities.
+#[derive(Default)]
+pub struct Crc32TableChip<F> {
+    _phantom: PhantomData<F>,
+}
@@ -0,0 +1,117 @@
+use crate::{
+    chips::{
+        chips::byte::event::{ByteLookupEvent, ByteRecordBehavior},
+        precompiles::crc32::table::{
+            columns::{Crc32TableCols, NUM_CRC32_TABLE_COLS},
+            Crc32TableChip,
+        },
+    },
+    compiler::riscv::{opcode::ByteOpcode, program::Program},
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{precompiles::crc32::CRC32_TABLE, SyscallCode},
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use std::borrow::BorrowMut;
+
+/// The table has one row per index byte.
+pub const NUM_ROWS: usize = 1 << 8;
+
+impl<F: PrimeField32> BaseAir<F> for Crc32TableChip<F> {
+    fn width(&self) -> usize {
+        NUM_CRC32_TABLE_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for Crc32TableChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Crc32Table".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = vec![[F::ZERO; NUM_CRC32_TABLE_COLS]; NUM_ROWS];
+        for (index, row) in rows.iter_mut().enumerate() {
+            let cols: &mut Crc32TableCols<F> = row.as_mut_slice().borrow_mut();
+            Self::populate(cols, index as u8, &mut Vec::new());
+        }
+        for (lookup, mult) in input.byte_lookups.iter() {
+            if lookup.opcode == ByteOpcode::Crc32 {
+                let cols: &mut Crc32TableCols<F> =
+                    rows[lookup.b as usize].as_mut_slice().borrow_mut();
+                cols.multiplicities[lookup.c as usize] += F::from_canonical_usize(*mult);
+            }
+        }
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_CRC32_TABLE_COLS)
+    }
+
+    fn extra_record(&self, _: &Self::Record, output: &mut Self::Record) {
+        let mut blu = vec![];
+        let mut cols = Crc32TableCols::<F>::default();
+        for index in 0..=u8::MAX {
+            Self::populate(&mut cols, index, &mut blu);
+        }
+        output.add_byte_lookup_events(blu);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record.get_precompile_events(SyscallCode::CRC32).is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> Crc32TableChip<F> {
+    fn populate(cols: &mut Crc32TableCols<F>, index: u8, blu: &mut impl ByteRecordBehavior) {
+        let terms: [[u8; 4]; 8] = core::array::from_fn(|i| {
+            let bit = (index >> i) & 1;
+            cols.index_bits[i] = F::from_canonical_u8(bit);
+            (bit as u32 * CRC32_TABLE[1 << i]).to_le_bytes()
+        });
+
+        let mut acc = terms[0];
+        for i in 0..7 {
+            for k in 0..4 {
+                let b = acc[k];
+                acc[k] ^= terms[i + 1][k];
+                blu.add_byte_lookup_event(ByteLookupEvent::new(
+                    ByteOpcode::XOR,
+                    acc[k].into(),
+                    0,
+                    b,
+                    terms[i + 1][k],
+                ));
+            }
+            cols.acc[i] = acc.map(F::from_canonical_u8);
+        }
+        debug_assert_eq!(u32::from_le_bytes(acc), CRC32_TABLE[index as usize]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_crc32_table() {
+        for index in 0..=u8::MAX {
+            let mut cols = Crc32TableCols::<BabyBear>::default();
+            let mut blu = Vec::new();
+            Crc32TableChip::populate(&mut cols, index, &mut blu);
+            let entry = cols.acc[6].map(|byte| byte.as_canonical_u32() as u8);
+            assert_eq!(u32::from_le_bytes(entry), CRC32_TABLE[index as usize]);
+            assert_eq!(blu.len(), 28);
+        }
+    }
+}
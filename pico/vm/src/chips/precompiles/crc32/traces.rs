@@ -0,0 +1,185 @@
+use crate::{
+    chips::{
+        chips::byte::event::{ByteLookupEvent, ByteRecordBehavior},
+        precompiles::crc32::{
+            columns::{Crc32Cols, NUM_CRC32_COLS},
+            Crc32Chip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::{
+        riscv::{opcode::ByteOpcode, program::Program},
+        word::Word,
+    },
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{
+                crc32::{crc32_step, CRC32_TABLE},
+                Crc32Event, PrecompileEvent,
+            },
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for Crc32Chip<F> {
+    fn width(&self) -> usize {
+        NUM_CRC32_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for Crc32Chip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Crc32".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        let mut new_byte_lookup_events = Vec::new();
+        for (_, event) in input.get_precompile_events(SyscallCode::CRC32).iter() {
+            let event = if let PrecompileEvent::Crc32(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut new_byte_lookup_events);
+        }
+
+        let mut rows = rows.unwrap();
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(&mut rows, || [F::ZERO; NUM_CRC32_COLS], log_rows);
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_CRC32_COLS)
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::CRC32)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::Crc32(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_rows(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record.get_precompile_events(SyscallCode::CRC32).is_empty()
+        }
+    }
+}
+
+/// Looks up `b ^ c` in the byte table.
+fn xor(blu: &mut impl ByteRecordBehavior, b: u8, c: u8) -> u8 {
+    let a = b ^ c;
+    blu.add_byte_lookup_event(ByteLookupEvent::new(ByteOpcode::XOR, a.into(), 0, b, c));
+    a
+}
+
+impl<F: PrimeField32> Crc32Chip<F> {
+    fn event_to_rows(
+        &self,
+        event: &Crc32Event,
+        rows: &mut Option<Vec<[F; NUM_CRC32_COLS]>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        let num_rows = event.data.len();
+        let mut register = !event.crc_in;
+
+        for (r, (word, record)) in event.data.iter().zip(&event.data_read_records).enumerate() {
+            let mut row = [F::ZERO; NUM_CRC32_COLS];
+            let cols: &mut Crc32Cols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.data_ptr = F::from_canonical_u32(event.data_ptr);
+            cols.args_ptr = F::from_canonical_u32(event.args_ptr);
+            cols.len = F::from_canonical_u32(event.len);
+            cols.crc_ptr = F::from_canonical_u32(event.crc_ptr);
+            cols.crc_in = Word::from(event.crc_in);
+
+            let offset = 4 * r as u32;
+            cols.offset = F::from_canonical_u32(offset);
+            cols.is_first = F::from_bool(r == 0);
+            cols.is_last = F::from_bool(r + 1 == num_rows);
+
+            if r == 0 {
+                for (args_cols, record) in cols.args.iter_mut().zip(&event.args_read_records) {
+                    args_cols.populate(*record, blu);
+                }
+            }
+
+            cols.data.populate(*record, blu);
+            let num_bytes = (event.len - offset).min(4) as usize;
+            for (k, &byte) in word.to_le_bytes()[..num_bytes].iter().enumerate() {
+                cols.is_byte[k] = F::ONE;
+                cols.register[k] = Word::from(register);
+
+                let bytes = register.to_le_bytes();
+                let index = xor(blu, bytes[0], byte);
+                let entry = CRC32_TABLE[index as usize];
+                let entry_bytes = entry.to_le_bytes();
+                for c in 0..2 {
+                    blu.add_byte_lookup_event(ByteLookupEvent::new(
+                        ByteOpcode::Crc32,
+                        entry_bytes[2 * c].into(),
+                        entry_bytes[2 * c + 1],
+                        index,
+                        c as u8,
+                    ));
+                }
+                for j in 0..3 {
+                    cols.shifted[k][j] =
+                        F::from_canonical_u8(xor(blu, entry_bytes[j], bytes[j + 1]));
+                }
+                cols.index[k] = F::from_canonical_u8(index);
+                cols.entry[k] = Word::from(entry);
+
+                register = crc32_step(register, byte);
+            }
+            // The register is carried over the bytes past the end of the data.
+            for k in num_bytes..5 {
+                cols.register[k] = Word::from(register);
+            }
+
+            if r + 1 == num_rows {
+                cols.crc.populate(event.crc_write_record, blu);
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+        gadgets::{is_zero::IsZeroGadget, xor::XorOperation},
+    },
+    emulator::riscv::syscalls::precompiles::ct_eq::CT_EQ_NUM_ARGS,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_CT_EQ_COLS: usize = size_of::<ConstantTimeEqCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ConstantTimeEqCols<T> {
+    /// Inputs, copied over every row of an event.
+    pub chunk: T,
+    pub clk: T,
+    pub a_ptr: T,
+    pub args_ptr: T,
+    pub b_ptr: T,
+    pub num_words: T,
+    pub result_ptr: T,
+
+    /// The index of the words compared in this row, counting down from `num_words - 1` to zero.
+    pub index: T,
+
+    /// Checks whether `index` is zero, i.e. whether this is the last word.
+    pub index_is_zero: IsZeroGadget<T>,
+
+    /// Whether the current row is the first of an event and is real.
+    pub is_first: T,
+    /// Whether the current row is the last of an event and is real.
+    pub is_last: T,
+
+    /// The argument block `[b_ptr, num_words, result_ptr]`, read on the first row.
+    pub args: [MemoryReadCols<T>; CT_EQ_NUM_ARGS],
+    /// The words compared in this row.
+    pub a: MemoryReadCols<T>,
+    pub b: MemoryReadCols<T>,
+
+    /// `a ^ b`.
+    pub xor: XorOperation<T>,
+
+    /// Sum of the XOR bytes before and after this row.
+    pub acc_in: T,
+    pub acc_out: T,
+
+    /// Checks whether the final accumulator is zero.
+    pub acc_is_zero: IsZeroGadget<T>,
+
+    /// The result, written on the last row.
+    pub result: MemoryWriteCols<T>,
+
+    pub is_real: T,
+}
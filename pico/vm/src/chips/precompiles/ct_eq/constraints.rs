@@ -0,0 +1,160 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        gadgets::{is_zero::IsZeroGadget, xor::XorOperation},
+        precompiles::ct_eq::{columns::ConstantTimeEqCols, ConstantTimeEqChip},
+    },
+    emulator::riscv::syscalls::SyscallCode,
+    machine::builder::{ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, RiscVMemoryBuilder},
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for ConstantTimeEqChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &ConstantTimeEqCols<CB::Var> = (*local).borrow();
+        let next: &ConstantTimeEqCols<CB::Var> = (*next).borrow();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.when(local.is_first).assert_one(local.is_real);
+        IsZeroGadget::<CB::F>::eval(
+            builder,
+            local.index.into(),
+            local.index_is_zero,
+            local.is_real.into(),
+        );
+        builder.assert_eq(local.is_last, local.is_real * local.index_is_zero.result);
+
+        // The table starts with a new event or with padding.
+        builder
+            .when_first_row()
+            .assert_eq(local.is_first, local.is_real);
+
+        // Within an event, the next row compares the next lower word and carries the accumulator
+        // and the inputs over.
+        let is_continuing: CB::Expr = local.is_real - local.is_last;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            builder.assert_one(next.is_real);
+            builder.assert_zero(next.is_first);
+            builder.assert_eq(next.index, local.index - CB::Expr::ONE);
+            builder.assert_eq(next.acc_in, local.acc_out);
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.a_ptr, local.a_ptr);
+            builder.assert_eq(next.args_ptr, local.args_ptr);
+            builder.assert_eq(next.b_ptr, local.b_ptr);
+            builder.assert_eq(next.num_words, local.num_words);
+            builder.assert_eq(next.result_ptr, local.result_ptr);
+        }
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(local.is_last)
+            .assert_eq(next.is_first, next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing);
+
+        // Read the argument block on the first row.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.args_ptr,
+            &local.args,
+            local.is_first,
+        );
+        {
+            let mut builder = builder.when(local.is_first);
+            builder.assert_eq(local.b_ptr, local.args[0].value().reduce::<CB>());
+            builder.assert_eq(local.num_words, local.args[1].value().reduce::<CB>());
+            builder.assert_eq(local.result_ptr, local.args[2].value().reduce::<CB>());
+            builder.assert_eq(local.index + CB::Expr::ONE, local.num_words);
+            builder.assert_zero(local.acc_in);
+        }
+
+        // Read the words of the current index.
+        let offset = local.index * CB::F::from_canonical_u32(4);
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.a_ptr + offset.clone(),
+            &local.a,
+            local.is_real,
+        );
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.b_ptr + offset,
+            &local.b,
+            local.is_real,
+        );
+
+        // Accumulate the bytes of `a ^ b`.
+        XorOperation::<CB::F>::eval(
+            builder,
+            *local.a.value(),
+            *local.b.value(),
+            local.xor,
+            local.is_real,
+        );
+        builder.when(local.is_real).assert_eq(
+            local.acc_out,
+            local.acc_in
+                + local
+                    .xor
+                    .value
+                    .0
+                    .iter()
+                    .map(|&x| x.into())
+                    .sum::<CB::Expr>(),
+        );
+
+        // Write whether the accumulator is zero on the last row.
+        IsZeroGadget::<CB::F>::eval(
+            builder,
+            local.acc_out.into(),
+            local.acc_is_zero,
+            local.is_last.into(),
+        );
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.result_ptr,
+            &local.result,
+            local.is_last,
+        );
+        {
+            let result = local.result.value();
+            let mut builder = builder.when(local.is_last);
+            builder.assert_eq(result[0], local.acc_is_zero.result);
+            builder.assert_zero(result[1]);
+            builder.assert_zero(result[2]);
+            builder.assert_zero(result[3]);
+        }
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::CT_EQ.syscall_id()),
+            local.a_ptr,
+            local.args_ptr,
+            local.is_first,
+        );
+    }
+}
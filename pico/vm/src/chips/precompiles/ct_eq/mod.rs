@@ -0,0 +1,60 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// Proves `CT_EQ` one word per row.
+///
+/// An event over `n` words spans `n` consecutive rows. Each row XORs a word of both buffers and
+/// adds the bytes of the XOR to an accumulator; the last row writes whether the accumulator is
+/// zero. Every word is read regardless of earlier differences.
+#[derive(Default)]
+pub struct ConstantTimeEqChip<F> {
+    _phantom: PhantomData<F>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{prove_program, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_ct_eq_prove() {
+        const A_PTR: u32 = 0x2000;
+        const B_PTR: u32 = 0x2100;
+        const ARGS_PTR: u32 = 0x3000;
+        const EQUAL_RESULT_PTR: u32 = 0x3100;
+        const UNEQUAL_ARGS_PTR: u32 = 0x3200;
+        const UNEQUAL_RESULT_PTR: u32 = 0x3300;
+
+        // `a` equals `b` on its first three words and differs on the fourth.
+        let mut memory_image = (0..4)
+            .flat_map(|i| {
+                [
+                    (A_PTR + 4 * i, 0xdead_0000 + i),
+                    (B_PTR + 4 * i, 0xdead_0000 + i + (i == 3) as u32),
+                ]
+            })
+            .collect::<BTreeMap<_, _>>();
+        memory_image.extend([
+            (ARGS_PTR, B_PTR),
+            (ARGS_PTR + 4, 3),
+            (ARGS_PTR + 8, EQUAL_RESULT_PTR),
+            (UNEQUAL_ARGS_PTR, B_PTR),
+            (UNEQUAL_ARGS_PTR + 4, 4),
+            (UNEQUAL_ARGS_PTR + 8, UNEQUAL_RESULT_PTR),
+        ]);
+        let program = syscall_program(
+            &[
+                (SyscallCode::CT_EQ, A_PTR, ARGS_PTR),
+                (SyscallCode::CT_EQ, A_PTR, UNEQUAL_ARGS_PTR),
+            ],
+            memory_image,
+        );
+        prove_program(program).unwrap();
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    chips::{
+        chips::byte::event::ByteRecordBehavior,
+        precompiles::ct_eq::{
+            columns::{ConstantTimeEqCols, NUM_CT_EQ_COLS},
+            ConstantTimeEqChip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::riscv::program::Program,
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{ConstantTimeEqEvent, PrecompileEvent},
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for ConstantTimeEqChip<F> {
+    fn width(&self) -> usize {
+        NUM_CT_EQ_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for ConstantTimeEqChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "ConstantTimeEq".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        let mut new_byte_lookup_events = Vec::new();
+        for (_, event) in input.get_precompile_events(SyscallCode::CT_EQ).iter() {
+            let event = if let PrecompileEvent::ConstantTimeEq(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut new_byte_lookup_events);
+        }
+
+        let mut rows = rows.unwrap();
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(&mut rows, || [F::ZERO; NUM_CT_EQ_COLS], log_rows);
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_CT_EQ_COLS)
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::CT_EQ)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::ConstantTimeEq(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_rows(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record.get_precompile_events(SyscallCode::CT_EQ).is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> ConstantTimeEqChip<F> {
+    fn event_to_rows(
+        &self,
+        event: &ConstantTimeEqEvent,
+        rows: &mut Option<Vec<[F; NUM_CT_EQ_COLS]>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        let mut acc = F::ZERO;
+
+        for (j, index) in (0..event.num_words as usize).rev().enumerate() {
+            let mut row = [F::ZERO; NUM_CT_EQ_COLS];
+            let cols: &mut ConstantTimeEqCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.a_ptr = F::from_canonical_u32(event.a_ptr);
+            cols.args_ptr = F::from_canonical_u32(event.args_ptr);
+            cols.b_ptr = F::from_canonical_u32(event.b_ptr);
+            cols.num_words = F::from_canonical_u32(event.num_words);
+            cols.result_ptr = F::from_canonical_u32(event.result_ptr);
+
+            cols.index = F::from_canonical_usize(index);
+            cols.index_is_zero.populate(index as u32);
+            cols.is_first = F::from_bool(j == 0);
+            cols.is_last = F::from_bool(index == 0);
+
+            if j == 0 {
+                for (args_cols, record) in cols.args.iter_mut().zip(&event.args_read_records) {
+                    args_cols.populate(*record, blu);
+                }
+            }
+
+            cols.a.populate(event.a_read_records[index], blu);
+            cols.b.populate(event.b_read_records[index], blu);
+            let xor = cols.xor.populate(blu, event.a[index], event.b[index]);
+
+            cols.acc_in = acc;
+            acc += xor
+                .to_le_bytes()
+                .into_iter()
+                .map(F::from_canonical_u8)
+                .sum::<F>();
+            cols.acc_out = acc;
+
+            if index == 0 {
+                cols.acc_is_zero.populate_from_field_element(acc);
+                cols.result.populate(event.result_write_record, blu);
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
@@ -20,7 +20,10 @@ use crate::{
         record::RecordBehavior,
         riscv::{
             record::EmulationRecord,
-            syscalls::{precompiles::PrecompileEvent, SyscallCode},
+            syscalls::{
+                precompiles::{FpEvent, PrecompileEvent},
+                SyscallCode,
+            },
         },
     },
     machine::{
@@ -87,7 +90,6 @@ where
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
     fn populate_field_ops(
         blu_events: &mut impl ByteRecordBehavior,
         cols: &mut FpOpCols<F, P>,
@@ -95,10 +97,98 @@ where
         q: BigUint,
         op: FieldOperation,
     ) {
-        let modulus_bytes = P::MODULUS;
-        let modulus = BigUint::from_bytes_le(modulus_bytes);
         cols.output
-            .populate_with_modulus(blu_events, &p, &q, &modulus, op);
+            .populate_with_modulus(blu_events, &p, &q, &P::modulus(), op);
+    }
+}
+
+/// The field specific parts of the `Fp` chip and syscall.
+///
+/// Everything else the chip needs, the limb and word counts and the modulus, already comes from
+/// [`FpOpField`], so this is implemented for every such field and adding a field only takes a new
+/// [`FieldType`] arm here.
+pub trait FieldChipTrace: FpOpField {
+    const CHIP_NAME: &'static str;
+
+    /// The syscall codes of [`FieldOperation::Add`], [`FieldOperation::Sub`] and
+    /// [`FieldOperation::Mul`]. The events of all three are recorded under the `Add` code.
+    const SYSCALL_CODES: [SyscallCode; 3];
+
+    fn wrap_event(event: FpEvent) -> PrecompileEvent;
+
+    fn unwrap_event(event: &PrecompileEvent) -> &FpEvent;
+
+    /// Fills a row of the chip from an event of this field.
+    fn populate_row<F: PrimeField32>(
+        event: &FpEvent,
+        cols: &mut FpOpCols<F, Self>,
+        blu_events: &mut impl ByteRecordBehavior,
+    ) {
+        let modulus = &Self::modulus();
+        let p = BigUint::from_bytes_le(&words_to_bytes_le_slice(&event.x)) % modulus;
+        let q = BigUint::from_bytes_le(&words_to_bytes_le_slice(&event.y)) % modulus;
+
+        cols.is_add = F::from_bool(event.op == FieldOperation::Add);
+        cols.is_sub = F::from_bool(event.op == FieldOperation::Sub);
+        cols.is_mul = F::from_bool(event.op == FieldOperation::Mul);
+        cols.is_real = F::ONE;
+        cols.chunk = F::from_canonical_u32(event.chunk);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+        cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+        FpOpChip::<F, Self>::populate_field_ops(blu_events, cols, p, q, event.op);
+
+        // Populate the memory access columns.
+        for i in 0..cols.y_access.len() {
+            cols.y_access[i].populate(event.y_memory_records[i], blu_events);
+        }
+        for i in 0..cols.x_access.len() {
+            cols.x_access[i].populate(event.x_memory_records[i], blu_events);
+        }
+    }
+}
+
+impl<P: FpOpField> FieldChipTrace for P {
+    const CHIP_NAME: &'static str = match P::FIELD_TYPE {
+        FieldType::Bn254 => "Bn254FpOp",
+        FieldType::Bls381 => "Bls381FpOp",
+        FieldType::Secp256k1 => "Secp256k1FpOp",
+    };
+
+    const SYSCALL_CODES: [SyscallCode; 3] = match P::FIELD_TYPE {
+        FieldType::Bn254 => [
+            SyscallCode::BN254_FP_ADD,
+            SyscallCode::BN254_FP_SUB,
+            SyscallCode::BN254_FP_MUL,
+        ],
+        FieldType::Bls381 => [
+            SyscallCode::BLS12381_FP_ADD,
+            SyscallCode::BLS12381_FP_SUB,
+            SyscallCode::BLS12381_FP_MUL,
+        ],
+        FieldType::Secp256k1 => [
+            SyscallCode::SECP256K1_FP_ADD,
+            SyscallCode::SECP256K1_FP_SUB,
+            SyscallCode::SECP256K1_FP_MUL,
+        ],
+    };
+
+    fn wrap_event(event: FpEvent) -> PrecompileEvent {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => PrecompileEvent::Bn254Fp(event),
+            FieldType::Bls381 => PrecompileEvent::Bls12381Fp(event),
+            FieldType::Secp256k1 => PrecompileEvent::Secp256k1Fp(event),
+        }
+    }
+
+    fn unwrap_event(event: &PrecompileEvent) -> &FpEvent {
+        match (P::FIELD_TYPE, event) {
+            (FieldType::Bn254, PrecompileEvent::Bn254Fp(event))
+            | (FieldType::Bls381, PrecompileEvent::Bls12381Fp(event))
+            | (FieldType::Secp256k1, PrecompileEvent::Secp256k1Fp(event)) => event,
+            _ => unreachable!(),
+        }
     }
 }
 
@@ -112,27 +202,13 @@ where
     type Program = Program;
 
     fn name(&self) -> String {
-        match P::FIELD_TYPE {
-            FieldType::Bn254 => "Bn254FpOp".to_string(),
-            FieldType::Bls381 => "Bls381FpOp".to_string(),
-            FieldType::Secp256k1 => "Secp256k1FpOp".to_string(),
-        }
+        P::CHIP_NAME.to_string()
     }
 
     fn generate_main(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
         // All the fp events for a given curve are coalesce to the curve's Add operation. Only retrieve
         // precompile events for that operation.
-        let events = match P::FIELD_TYPE {
-            FieldType::Bn254 => input
-                .get_precompile_events(SyscallCode::BN254_FP_ADD)
-                .iter(),
-            FieldType::Bls381 => input
-                .get_precompile_events(SyscallCode::BLS12381_FP_ADD)
-                .iter(),
-            FieldType::Secp256k1 => input
-                .get_precompile_events(SyscallCode::SECP256K1_FP_ADD)
-                .iter(),
-        };
+        let events = input.get_precompile_events(P::SYSCALL_CODES[0]);
 
         debug!(
             "record {} fp precompile events {:?}",
@@ -144,38 +220,9 @@ where
         let mut new_byte_lookup_events = Vec::new();
 
         for (_, event) in events {
-            let event = match (P::FIELD_TYPE, event) {
-                (FieldType::Bn254, PrecompileEvent::Bn254Fp(event)) => event,
-                (FieldType::Bls381, PrecompileEvent::Bls12381Fp(event)) => event,
-                (FieldType::Secp256k1, PrecompileEvent::Secp256k1Fp(event)) => event,
-                _ => unreachable!(),
-            };
-
             let mut row = vec![F::ZERO; num_fp_cols::<P>()];
             let cols: &mut FpOpCols<F, P> = row.as_mut_slice().borrow_mut();
-
-            let modulus = &BigUint::from_bytes_le(P::MODULUS);
-            let p = BigUint::from_bytes_le(&words_to_bytes_le_slice(&event.x)) % modulus;
-            let q = BigUint::from_bytes_le(&words_to_bytes_le_slice(&event.y)) % modulus;
-
-            cols.is_add = F::from_canonical_u8((event.op == FieldOperation::Add) as u8);
-            cols.is_sub = F::from_canonical_u8((event.op == FieldOperation::Sub) as u8);
-            cols.is_mul = F::from_canonical_u8((event.op == FieldOperation::Mul) as u8);
-            cols.is_real = F::ONE;
-            cols.chunk = F::from_canonical_u32(event.chunk);
-            cols.clk = F::from_canonical_u32(event.clk);
-            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
-            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
-
-            Self::populate_field_ops(&mut new_byte_lookup_events, cols, p, q, event.op);
-
-            // Populate the memory access columns.
-            for i in 0..cols.y_access.len() {
-                cols.y_access[i].populate(event.y_memory_records[i], &mut new_byte_lookup_events);
-            }
-            for i in 0..cols.x_access.len() {
-                cols.x_access[i].populate(event.x_memory_records[i], &mut new_byte_lookup_events);
-            }
+            P::populate_row(P::unwrap_event(event), cols, &mut new_byte_lookup_events);
             rows.push(row)
         }
 
@@ -236,17 +283,7 @@ where
         if let Some(shape) = input.shape.as_ref() {
             shape.included::<F, _>(self)
         } else {
-            match P::FIELD_TYPE {
-                FieldType::Bn254 => !input
-                    .get_precompile_events(SyscallCode::BN254_FP_ADD)
-                    .is_empty(),
-                FieldType::Bls381 => !input
-                    .get_precompile_events(SyscallCode::BLS12381_FP_ADD)
-                    .is_empty(),
-                FieldType::Secp256k1 => !input
-                    .get_precompile_events(SyscallCode::SECP256K1_FP_ADD)
-                    .is_empty(),
-            }
+            !input.get_precompile_events(P::SYSCALL_CODES[0]).is_empty()
         }
     }
 
@@ -329,23 +366,8 @@ where
         // Select the correct syscall id based on the operation flags.
         //
         // *Remark*: If support for division is added, we will need to add the division syscall id.
-        let (add_syscall_id, sub_syscall_id, mul_syscall_id) = match P::FIELD_TYPE {
-            FieldType::Bn254 => (
-                CB::F::from_canonical_u32(SyscallCode::BN254_FP_ADD.syscall_id()),
-                CB::F::from_canonical_u32(SyscallCode::BN254_FP_SUB.syscall_id()),
-                CB::F::from_canonical_u32(SyscallCode::BN254_FP_MUL.syscall_id()),
-            ),
-            FieldType::Bls381 => (
-                CB::F::from_canonical_u32(SyscallCode::BLS12381_FP_ADD.syscall_id()),
-                CB::F::from_canonical_u32(SyscallCode::BLS12381_FP_SUB.syscall_id()),
-                CB::F::from_canonical_u32(SyscallCode::BLS12381_FP_MUL.syscall_id()),
-            ),
-            FieldType::Secp256k1 => (
-                CB::F::from_canonical_u32(SyscallCode::SECP256K1_FP_ADD.syscall_id()),
-                CB::F::from_canonical_u32(SyscallCode::SECP256K1_FP_SUB.syscall_id()),
-                CB::F::from_canonical_u32(SyscallCode::SECP256K1_FP_MUL.syscall_id()),
-            ),
-        };
+        let [add_syscall_id, sub_syscall_id, mul_syscall_id] =
+            P::SYSCALL_CODES.map(|code| CB::F::from_canonical_u32(code.syscall_id()));
         let syscall_id_felt = local.is_add * add_syscall_id
             + local.is_sub * sub_syscall_id
             + local.is_mul * mul_syscall_id;
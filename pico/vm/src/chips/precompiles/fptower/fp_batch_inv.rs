@@ -0,0 +1,433 @@
+use super::{limbs_from_prev_access, words_to_bytes_le_slice};
+use crate::{
+    chips::{
+        chips::{
+            byte::event::ByteRecordBehavior,
+            riscv_memory::read_write::columns::{value_as_limbs, MemoryWriteCols},
+        },
+        gadgets::{
+            field::field_op::{FieldOpCols, FieldOperation},
+            is_zero::IsZeroGadget,
+            utils::{
+                field_params::{FieldType, FpOpField, NumLimbs, NumWords},
+                limbs::Limbs,
+                polynomial::Polynomial,
+            },
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::riscv::program::Program,
+    emulator::{
+        record::RecordBehavior,
+        riscv::{
+            record::EmulationRecord,
+            syscalls::{
+                precompiles::{FpBatchInvEvent, PrecompileEvent},
+                SyscallCode,
+            },
+        },
+    },
+    machine::{
+        builder::{ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, RiscVMemoryBuilder},
+        chip::ChipBehavior,
+    },
+};
+use core::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+    mem::size_of,
+};
+use hybrid_array::{typenum::Unsigned, Array};
+use itertools::Itertools;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{Field, FieldAlgebra, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use pico_derive::AlignedBorrow;
+use tracing::debug;
+
+pub const fn num_fp_batch_inv_cols<P>() -> usize
+where
+    P: FpOpField,
+{
+    size_of::<FpBatchInvCols<u8, P>>()
+}
+
+/// Proves the in place inversion of `len` consecutive field elements, one element per row.
+///
+/// An event spans `len` consecutive rows. Each row checks its inverse like [`FpInvChip`], i.e. as
+/// `result * a ≡ 1 (mod p)`, which costs a single multiplication. Montgomery's trick is only used
+/// by the emulator, since checking an inverse is already cheaper than computing it.
+///
+/// [`FpInvChip`]: super::fp_inv::FpInvChip
+#[derive(Default)]
+#[allow(clippy::type_complexity)]
+pub struct FpBatchInvChip<F, P> {
+    _marker: PhantomData<fn(F, P) -> (F, P)>,
+}
+
+/// A set of columns for the FpBatchInv operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FpBatchInvCols<F, P>
+where
+    P: FpOpField,
+{
+    /// Inputs, copied over every row of an event.
+    pub chunk: F,
+    pub clk: F,
+    pub ptr: F,
+    pub len: F,
+
+    /// The index of the element inverted in this row, counting down from `len - 1` to zero.
+    pub index: F,
+
+    /// Checks whether `index` is zero, i.e. whether this is the last element.
+    pub index_is_zero: IsZeroGadget<F>,
+
+    /// Whether the current row is the first of an event and is real.
+    pub is_first: F,
+    /// Whether the current row is the last of an event and is real.
+    pub is_last: F,
+
+    /// The element, replaced by its inverse.
+    pub access: Array<MemoryWriteCols<F>, P::WordsFieldElement>,
+    pub(crate) output: FieldOpCols<F, P>,
+
+    pub is_real: F,
+}
+
+impl<F, P> FpBatchInvChip<F, P>
+where
+    F: PrimeField32,
+    P: FpOpField,
+{
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F, P: FpOpField> FpBatchInvChip<F, P> {
+    fn syscall_code() -> SyscallCode {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => SyscallCode::BN254_FP_BATCH_INV,
+            FieldType::Bls381 => SyscallCode::BLS12381_FP_BATCH_INV,
+            FieldType::Secp256k1 => SyscallCode::SECP256K1_FP_BATCH_INV,
+        }
+    }
+}
+
+impl<F, P> ChipBehavior<F> for FpBatchInvChip<F, P>
+where
+    F: PrimeField32,
+    P: FpOpField,
+{
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254FpBatchInv".to_string(),
+            FieldType::Bls381 => "Bls381FpBatchInv".to_string(),
+            FieldType::Secp256k1 => "Secp256k1FpBatchInv".to_string(),
+        }
+    }
+
+    fn generate_main(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(Self::syscall_code());
+
+        debug!(
+            "record {} fp batch inv precompile events {:?}",
+            input.chunk_index(),
+            events.len()
+        );
+
+        let modulus = BigUint::from_bytes_le(P::MODULUS);
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in events.iter() {
+            let event = match (P::FIELD_TYPE, event) {
+                (FieldType::Bn254, PrecompileEvent::Bn254FpBatchInv(event)) => event,
+                (FieldType::Bls381, PrecompileEvent::Bls12381FpBatchInv(event)) => event,
+                (FieldType::Secp256k1, PrecompileEvent::Secp256k1FpBatchInv(event)) => event,
+                _ => unreachable!(),
+            };
+            self.event_to_rows(event, &modulus, &mut rows, &mut new_byte_lookup_events);
+        }
+
+        new_byte_lookup_events
+            .iter()
+            .for_each(|x| output.add_byte_lookup_event(*x));
+
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = vec![F::ZERO; num_fp_batch_inv_cols::<P>()];
+                let cols: &mut FpBatchInvCols<F, P> = row.as_mut_slice().borrow_mut();
+                let zero = BigUint::zero();
+                cols.output.populate_with_modulus(
+                    &mut vec![],
+                    &zero,
+                    &zero,
+                    &modulus,
+                    FieldOperation::Div,
+                );
+                row
+            },
+            log_rows,
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_fp_batch_inv_cols::<P>(),
+        )
+    }
+
+    fn extra_record(&self, input: &Self::Record, extra: &mut Self::Record) {
+        self.generate_main(input, extra);
+    }
+
+    fn is_active(&self, input: &Self::Record) -> bool {
+        if let Some(shape) = input.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !input.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, P> FpBatchInvChip<F, P>
+where
+    F: PrimeField32,
+    P: FpOpField,
+{
+    fn event_to_rows(
+        &self,
+        event: &FpBatchInvEvent,
+        modulus: &BigUint,
+        rows: &mut Vec<Vec<F>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        let num_words = <P as NumWords>::WordsFieldElement::USIZE;
+
+        for (j, index) in (0..event.len as usize).rev().enumerate() {
+            let mut row = vec![F::ZERO; num_fp_batch_inv_cols::<P>()];
+            let cols: &mut FpBatchInvCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.ptr = F::from_canonical_u32(event.ptr);
+            cols.len = F::from_canonical_u32(event.len);
+
+            cols.index = F::from_canonical_usize(index);
+            cols.index_is_zero.populate(index as u32);
+            cols.is_first = F::from_bool(j == 0);
+            cols.is_last = F::from_bool(index == 0);
+
+            let words = index * num_words..(index + 1) * num_words;
+            // The constraints take the element as read from memory, so it is not reduced here.
+            let a = BigUint::from_bytes_le(&words_to_bytes_le_slice(&event.a[words.clone()]));
+            cols.output.populate_with_modulus(
+                blu,
+                &BigUint::one(),
+                &a,
+                modulus,
+                FieldOperation::Div,
+            );
+
+            for (access, record) in cols.access.iter_mut().zip(&event.memory_records[words]) {
+                access.populate(*record, blu);
+            }
+
+            rows.push(row);
+        }
+    }
+}
+
+impl<F, P> BaseAir<F> for FpBatchInvChip<F, P>
+where
+    P: FpOpField,
+{
+    fn width(&self) -> usize {
+        num_fp_batch_inv_cols::<P>()
+    }
+}
+
+impl<F, P, CB> Air<CB> for FpBatchInvChip<F, P>
+where
+    F: Field,
+    CB: ChipBuilder<F>,
+    P: FpOpField,
+    Limbs<CB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &FpBatchInvCols<CB::Var, P> = (*local).borrow();
+        let next: &FpBatchInvCols<CB::Var, P> = (*next).borrow();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.when(local.is_first).assert_one(local.is_real);
+        IsZeroGadget::<CB::F>::eval(
+            builder,
+            local.index.into(),
+            local.index_is_zero,
+            local.is_real.into(),
+        );
+        builder.assert_eq(local.is_last, local.is_real * local.index_is_zero.result);
+
+        // The table starts with a new event or with padding.
+        builder
+            .when_first_row()
+            .assert_eq(local.is_first, local.is_real);
+
+        // Within an event, the next row inverts the next lower element and carries the inputs
+        // over.
+        let is_continuing: CB::Expr = local.is_real - local.is_last;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            builder.assert_one(next.is_real);
+            builder.assert_zero(next.is_first);
+            builder.assert_eq(next.index, local.index - CB::Expr::ONE);
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.ptr, local.ptr);
+            builder.assert_eq(next.len, local.len);
+        }
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(local.is_last)
+            .assert_eq(next.is_first, next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing);
+
+        builder
+            .when(local.is_first)
+            .assert_eq(local.index + CB::Expr::ONE, local.len);
+
+        // result * a ≡ is_real (mod p). On real rows this also forces a to be non-zero.
+        let one: Polynomial<CB::Expr> = Polynomial::from_coefficients(&[local.is_real.into()]);
+        let a: Limbs<CB::Var, <P as NumLimbs>::Limbs> = limbs_from_prev_access(&local.access);
+
+        let modulus_coeffs = P::MODULUS
+            .iter()
+            .map(|&limbs| CB::Expr::from_canonical_u8(limbs))
+            .collect_vec();
+        let p_modulus = Polynomial::from_coefficients(&modulus_coeffs);
+
+        local.output.eval_with_modulus(
+            builder,
+            &one,
+            &a,
+            &p_modulus,
+            FieldOperation::Div,
+            local.is_real,
+        );
+
+        builder
+            .when(local.is_real)
+            .inner
+            .assert_all_eq(local.output.result, value_as_limbs(&local.access));
+
+        // The element is read at clk and written at clk + 1, as for the in place Fp operations.
+        let element_size = 4 * <P as NumWords>::WordsFieldElement::USIZE;
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.ptr + local.index * CB::F::from_canonical_usize(element_size),
+            &local.access,
+            local.is_real,
+        );
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(Self::syscall_code().syscall_id()),
+            local.ptr,
+            local.len,
+            local.is_first,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chips::gadgets::{
+            curves::weierstrass::{bls381::Bls381BaseField, bn254::Bn254BaseField},
+            field::secp256k1::Secp256k1BaseField,
+            utils::field_params::{FieldParameters, NumWords},
+        },
+        emulator::riscv::syscalls::{
+            test_utils::{debug_program, prove_program, syscall_program},
+            SyscallCode,
+        },
+    };
+    use hybrid_array::typenum::Unsigned;
+    use num::BigUint;
+    use std::collections::BTreeMap;
+
+    /// Inverts a batch with a reduced and an unreduced element, which the chip must take as read
+    /// from memory.
+    fn debug_batch_inv<P: FieldParameters + NumWords>(code: SyscallCode) {
+        const PTR: u32 = 0x1000;
+        let num_words = <P::WordsFieldElement as Unsigned>::USIZE;
+        let inputs = [BigUint::from(2u32), P::modulus() + 5u32];
+
+        let mut memory_image = BTreeMap::new();
+        for (i, input) in inputs.iter().enumerate() {
+            for (j, word) in input.to_u32_digits().into_iter().enumerate() {
+                memory_image.insert(PTR + 4 * (i * num_words + j) as u32, word);
+            }
+        }
+        debug_program(syscall_program(
+            &[(code, PTR, inputs.len() as u32)],
+            memory_image,
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn test_fp_batch_inv_satisfies_constraints() {
+        debug_batch_inv::<Bn254BaseField>(SyscallCode::BN254_FP_BATCH_INV);
+        debug_batch_inv::<Bls381BaseField>(SyscallCode::BLS12381_FP_BATCH_INV);
+        debug_batch_inv::<Secp256k1BaseField>(SyscallCode::SECP256K1_FP_BATCH_INV);
+    }
+
+    #[test]
+    fn test_fp_batch_inv_prove() {
+        // Each field inverts the batch [5, 7] at its own address.
+        let fields = [
+            (SyscallCode::BN254_FP_BATCH_INV, 0x2000, 8),
+            (SyscallCode::BLS12381_FP_BATCH_INV, 0x3000, 12),
+            (SyscallCode::SECP256K1_FP_BATCH_INV, 0x4000, 8),
+        ];
+        let memory_image = fields
+            .iter()
+            .flat_map(|&(_, ptr, num_words)| [(ptr, 5), (ptr + 4 * num_words, 7)])
+            .collect();
+        let calls = fields.map(|(code, ptr, _)| (code, ptr, 2));
+        prove_program(syscall_program(&calls, memory_image)).unwrap();
+    }
+}
@@ -0,0 +1,339 @@
+use super::{limbs_from_prev_access, words_to_bytes_le_slice};
+use crate::{
+    chips::{
+        chips::{
+            byte::event::ByteRecordBehavior,
+            riscv_memory::read_write::columns::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
+        },
+        gadgets::{
+            field::field_op::{FieldOpCols, FieldOperation},
+            utils::{
+                field_params::{FieldType, FpOpField, NumLimbs},
+                limbs::Limbs,
+                polynomial::Polynomial,
+            },
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::riscv::program::Program,
+    emulator::{
+        record::RecordBehavior,
+        riscv::{
+            record::EmulationRecord,
+            syscalls::{precompiles::PrecompileEvent, SyscallCode},
+        },
+    },
+    machine::{
+        builder::{ChipBuilder, ChipLookupBuilder, RiscVMemoryBuilder},
+        chip::ChipBehavior,
+    },
+};
+use core::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+    mem::size_of,
+};
+use hybrid_array::Array;
+use itertools::Itertools;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, BaseAir};
+use p3_field::{Field, FieldAlgebra, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use pico_derive::AlignedBorrow;
+use tracing::debug;
+
+pub const fn num_fp_inv_cols<P>() -> usize
+where
+    P: FpOpField,
+{
+    size_of::<FpInvCols<u8, P>>()
+}
+
+/// Proves `result = a^{-1} mod p`, one inversion per row.
+///
+/// The inverse is checked as the division `is_real / a`, i.e. `result * a ≡ 1 (mod p)` on real
+/// rows, reusing the multiplication constraint of [`FieldOpCols`]. Since `0 * result ≡ 0` for any
+/// `result`, the same constraint rules out `a ≡ 0`. Padding rows check `0 / 0 = 0` instead.
+#[derive(Default)]
+#[allow(clippy::type_complexity)]
+pub struct FpInvChip<F, P> {
+    _marker: PhantomData<fn(F, P) -> (F, P)>,
+}
+
+/// A set of columns for the FpInv operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FpInvCols<F, P>
+where
+    P: FpOpField,
+{
+    pub is_real: F,
+    pub chunk: F,
+    pub clk: F,
+    pub a_ptr: F,
+    pub result_ptr: F,
+    pub a_access: Array<MemoryReadCols<F>, P::WordsFieldElement>,
+    pub result_access: Array<MemoryWriteCols<F>, P::WordsFieldElement>,
+    pub(crate) output: FieldOpCols<F, P>,
+}
+
+impl<F, P> FpInvChip<F, P>
+where
+    F: PrimeField32,
+    P: FpOpField,
+{
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F, P: FpOpField> FpInvChip<F, P> {
+    fn syscall_code() -> SyscallCode {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => SyscallCode::BN254_FP_INV,
+            FieldType::Bls381 => SyscallCode::BLS12381_FP_INV,
+            FieldType::Secp256k1 => SyscallCode::SECP256K1_FP_INV,
+        }
+    }
+}
+
+impl<F, P> ChipBehavior<F> for FpInvChip<F, P>
+where
+    F: PrimeField32,
+    P: FpOpField,
+{
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254FpInv".to_string(),
+            FieldType::Bls381 => "Bls381FpInv".to_string(),
+            FieldType::Secp256k1 => "Secp256k1FpInv".to_string(),
+        }
+    }
+
+    fn generate_main(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(Self::syscall_code());
+
+        debug!(
+            "record {} fp inv precompile events {:?}",
+            input.chunk_index(),
+            events.len()
+        );
+
+        let modulus = BigUint::from_bytes_le(P::MODULUS);
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in events.iter() {
+            let event = match (P::FIELD_TYPE, event) {
+                (FieldType::Bn254, PrecompileEvent::Bn254FpInv(event)) => event,
+                (FieldType::Bls381, PrecompileEvent::Bls12381FpInv(event)) => event,
+                (FieldType::Secp256k1, PrecompileEvent::Secp256k1FpInv(event)) => event,
+                _ => unreachable!(),
+            };
+
+            let mut row = vec![F::ZERO; num_fp_inv_cols::<P>()];
+            let cols: &mut FpInvCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            // The constraints take `a` as read from memory, so it is not reduced here.
+            let a = BigUint::from_bytes_le(&words_to_bytes_le_slice(&event.a));
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.a_ptr = F::from_canonical_u32(event.a_ptr);
+            cols.result_ptr = F::from_canonical_u32(event.result_ptr);
+
+            cols.output.populate_with_modulus(
+                &mut new_byte_lookup_events,
+                &BigUint::one(),
+                &a,
+                &modulus,
+                FieldOperation::Div,
+            );
+
+            // Populate the memory access columns.
+            for i in 0..cols.a_access.len() {
+                cols.a_access[i].populate(event.a_memory_records[i], &mut new_byte_lookup_events);
+            }
+            for i in 0..cols.result_access.len() {
+                cols.result_access[i]
+                    .populate(event.result_memory_records[i], &mut new_byte_lookup_events);
+            }
+            rows.push(row)
+        }
+
+        new_byte_lookup_events
+            .iter()
+            .for_each(|x| output.add_byte_lookup_event(*x));
+
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = vec![F::ZERO; num_fp_inv_cols::<P>()];
+                let cols: &mut FpInvCols<F, P> = row.as_mut_slice().borrow_mut();
+                let zero = BigUint::zero();
+                cols.output.populate_with_modulus(
+                    &mut vec![],
+                    &zero,
+                    &zero,
+                    &modulus,
+                    FieldOperation::Div,
+                );
+                row
+            },
+            log_rows,
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_fp_inv_cols::<P>(),
+        )
+    }
+
+    fn extra_record(&self, input: &Self::Record, extra: &mut Self::Record) {
+        self.generate_main(input, extra);
+    }
+
+    fn is_active(&self, input: &Self::Record) -> bool {
+        if let Some(shape) = input.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !input.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, P> BaseAir<F> for FpInvChip<F, P>
+where
+    P: FpOpField,
+{
+    fn width(&self) -> usize {
+        num_fp_inv_cols::<P>()
+    }
+}
+
+impl<F, P, CB> Air<CB> for FpInvChip<F, P>
+where
+    F: Field,
+    CB: ChipBuilder<F>,
+    P: FpOpField,
+    Limbs<CB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &FpInvCols<CB::Var, P> = (*local).borrow();
+
+        builder.assert_bool(local.is_real);
+
+        // The dividend is the constant 1 on real rows and 0 on padding rows.
+        let one: Polynomial<CB::Expr> = Polynomial::from_coefficients(&[local.is_real.into()]);
+        let a: Limbs<CB::Var, <P as NumLimbs>::Limbs> = limbs_from_prev_access(&local.a_access);
+
+        let modulus_coeffs = P::MODULUS
+            .iter()
+            .map(|&limbs| CB::Expr::from_canonical_u8(limbs))
+            .collect_vec();
+        let p_modulus = Polynomial::from_coefficients(&modulus_coeffs);
+
+        // result * a ≡ is_real (mod p). On real rows this also forces a to be non-zero.
+        local.output.eval_with_modulus(
+            builder,
+            &one,
+            &a,
+            &p_modulus,
+            FieldOperation::Div,
+            local.is_real,
+        );
+
+        builder
+            .when(local.is_real)
+            .inner
+            .assert_all_eq(local.output.result, value_as_limbs(&local.result_access));
+
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk.into(),
+            local.a_ptr,
+            &local.a_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk + CB::F::from_canonical_u32(1), /* We write the result at +1 since the
+                                                       * pointers could be the same. */
+            local.result_ptr,
+            &local.result_access,
+            local.is_real,
+        );
+
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(Self::syscall_code().syscall_id()),
+            local.a_ptr,
+            local.result_ptr,
+            local.is_real,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chips::gadgets::{
+            curves::weierstrass::{bls381::Bls381BaseField, bn254::Bn254BaseField},
+            field::secp256k1::Secp256k1BaseField,
+            utils::field_params::FieldParameters,
+        },
+        emulator::riscv::syscalls::{
+            test_utils::{debug_program, prove_program, syscall_program},
+            SyscallCode,
+        },
+    };
+
+    /// Inverts `p + 5` in place, which the chip must take as read from memory.
+    fn debug_unreduced_inv<P: FieldParameters>(code: SyscallCode) {
+        const PTR: u32 = 0x1000;
+        let input = P::modulus() + 5u32;
+
+        let memory_image = input
+            .to_u32_digits()
+            .into_iter()
+            .enumerate()
+            .map(|(i, word)| (PTR + 4 * i as u32, word))
+            .collect();
+        debug_program(syscall_program(&[(code, PTR, PTR)], memory_image)).unwrap();
+    }
+
+    #[test]
+    fn test_fp_inv_unreduced_satisfies_constraints() {
+        debug_unreduced_inv::<Bn254BaseField>(SyscallCode::BN254_FP_INV);
+        debug_unreduced_inv::<Bls381BaseField>(SyscallCode::BLS12381_FP_INV);
+        debug_unreduced_inv::<Secp256k1BaseField>(SyscallCode::SECP256K1_FP_INV);
+    }
+
+    #[test]
+    fn test_fp_inv_prove() {
+        // Each field inverts 5 in place at its own address.
+        let calls = [
+            (SyscallCode::BN254_FP_INV, 0x2000),
+            (SyscallCode::BLS12381_FP_INV, 0x3000),
+            (SyscallCode::SECP256K1_FP_INV, 0x4000),
+        ]
+        .map(|(code, ptr)| (code, ptr, ptr));
+        let memory_image = calls.iter().map(|&(_, ptr, _)| (ptr, 5)).collect();
+        prove_program(syscall_program(&calls, memory_image)).unwrap();
+    }
+}
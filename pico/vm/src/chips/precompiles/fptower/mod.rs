@@ -1,6 +1,8 @@
 pub mod fp;
 pub mod fp2_addsub;
 pub mod fp2_mul;
+pub mod fp_batch_inv;
+pub mod fp_inv;
 
 use crate::chips::{
     chips::riscv_memory::read_write::columns::MemoryCols, gadgets::utils::limbs::Limbs,
@@ -0,0 +1,46 @@
+use core::mem::size_of;
+
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryReadWriteCols},
+        gadgets::xor::XorOperation,
+    },
+    emulator::riscv::syscalls::precompiles::keccak256::{
+        absorb::RATE_NUM_WORDS, permute::STATE_NUM_WORDS,
+    },
+};
+use p3_keccak_air::KeccakCols;
+use pico_derive::AlignedBorrow;
+
+/// The column layout for keccak absorption, the layout of [`super::super::KeccakPermuteChip`]
+/// with the block and its XOR into the state.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub(crate) struct KeccakAbsorbCols<T> {
+    /// Keccak columns from p3_keccak_air. Note it is assumed in trace gen to be the first field.
+    pub keccak: KeccakCols<T>,
+
+    pub chunk: T,
+    pub clk: T,
+    pub state_addr: T,
+    pub block_addr: T,
+
+    /// Memory columns for the state.
+    pub state_mem: [MemoryReadWriteCols<T>; STATE_NUM_WORDS],
+
+    /// Memory columns for the block, read in the first cycle.
+    pub block_mem: [MemoryReadCols<T>; RATE_NUM_WORDS],
+
+    /// The rate of the state XORed with the block, in the first cycle.
+    pub absorbed: [XorOperation<T>; RATE_NUM_WORDS],
+
+    // If row is real and first or last cycle of 24-cycle
+    pub do_memory_check: T,
+
+    // If row is real and first cycle of 24-cycle
+    pub receive_ecall: T,
+
+    pub is_real: T,
+}
+
+pub const NUM_KECCAK_ABSORB_COLS: usize = size_of::<KeccakAbsorbCols<u8>>();
@@ -0,0 +1,156 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_keccak_air::{KeccakAir, NUM_KECCAK_COLS, NUM_ROUNDS, U64_LIMBS};
+use p3_matrix::Matrix;
+
+use super::{columns::KeccakAbsorbCols, KeccakAbsorbChip};
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        gadgets::xor::XorOperation,
+        precompiles::keccak256::{STATE_NUM_WORDS, STATE_SIZE},
+    },
+    emulator::riscv::syscalls::{precompiles::keccak256::absorb::RATE_NUM_WORDS, SyscallCode},
+    machine::builder::{
+        ChipBuilder, ChipLookupBuilder, ChipRangeBuilder, ChipWordBuilder, RiscVMemoryBuilder,
+        SubAirBuilder,
+    },
+};
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for KeccakAbsorbChip<F> {
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &KeccakAbsorbCols<CB::Var> = (*local).borrow();
+        let next: &KeccakAbsorbCols<CB::Var> = (*next).borrow();
+
+        let first_step = local.keccak.step_flags[0];
+        let final_step = local.keccak.step_flags[NUM_ROUNDS - 1];
+        let not_final_step = CB::Expr::ONE - final_step;
+
+        // Constrain memory in the first and last cycles.
+        builder.assert_eq(
+            (first_step + final_step) * local.is_real,
+            local.do_memory_check,
+        );
+
+        // Constrain the state memory as in `KeccakPermuteChip`.
+        for i in 0..STATE_NUM_WORDS as u32 {
+            builder
+                .when(local.keccak.step_flags[0] * local.is_real)
+                .assert_word_eq(
+                    *local.state_mem[i as usize].value(),
+                    *local.state_mem[i as usize].prev_value(),
+                );
+
+            builder.eval_memory_access(
+                local.chunk,
+                local.clk + final_step, // The clk increments by 1 after a final step
+                local.state_addr + CB::Expr::from_canonical_u32(i * 4),
+                &local.state_mem[i as usize],
+                local.do_memory_check,
+            );
+        }
+
+        // Receive the syscall in the first row of each 24-cycle
+        builder.assert_eq(local.receive_ecall, first_step * local.is_real);
+
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::KECCAK_ABSORB.syscall_id()),
+            local.state_addr,
+            local.block_addr,
+            local.receive_ecall,
+        );
+
+        // Read the block and XOR it into the rate of the state in the first cycle.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.block_addr,
+            &local.block_mem,
+            local.receive_ecall,
+        );
+        for i in 0..RATE_NUM_WORDS {
+            XorOperation::<CB::F>::eval(
+                builder,
+                *local.state_mem[i].value(),
+                *local.block_mem[i].value(),
+                local.absorbed[i],
+                local.receive_ecall,
+            );
+        }
+
+        // Constrain that the inputs stay the same throughout the 24 rows of each cycle
+        let mut transition_builder = builder.when_transition();
+        let mut transition_not_final_builder = transition_builder.when(not_final_step);
+        transition_not_final_builder.assert_eq(local.chunk, next.chunk);
+        transition_not_final_builder.assert_eq(local.clk, next.clk);
+        transition_not_final_builder.assert_eq(local.state_addr, next.state_addr);
+        transition_not_final_builder.assert_eq(local.block_addr, next.block_addr);
+        transition_not_final_builder.assert_eq(local.is_real, next.is_real);
+
+        // The last row must be nonreal because NUM_ROUNDS is not a power of 2. This constraint
+        // ensures that the table does not end abruptly.
+        builder.when_last_row().assert_zero(local.is_real);
+
+        // On a first step row, the permutation input is the absorbed rate followed by the
+        // capacity of the state. On a final step row, the output is written back to the state.
+        let expr_2_pow_8 = CB::Expr::from_canonical_u32(2u32.pow(8));
+        let limbs = |least_sig_word: [CB::Expr; 4], most_sig_word: [CB::Expr; 4]| {
+            let [b0, b1, b2, b3] = least_sig_word;
+            let [b4, b5, b6, b7] = most_sig_word;
+            [
+                b0 + b1 * expr_2_pow_8.clone(),
+                b2 + b3 * expr_2_pow_8.clone(),
+                b4 + b5 * expr_2_pow_8.clone(),
+                b6 + b7 * expr_2_pow_8.clone(),
+            ]
+        };
+        for i in 0..STATE_SIZE {
+            let y_idx = i / 5;
+            let x_idx = i % 5;
+
+            let word = |j: usize| -> [CB::Expr; 4] {
+                if j < RATE_NUM_WORDS {
+                    local.absorbed[j].value.0.map(Into::into)
+                } else {
+                    local.state_mem[j].value().0.map(Into::into)
+                }
+            };
+            let input_limbs = limbs(word(i * 2), word(i * 2 + 1));
+            let a_value_limbs = local.keccak.a[y_idx][x_idx];
+            for j in 0..U64_LIMBS {
+                builder
+                    .when(first_step * local.is_real)
+                    .assert_eq(input_limbs[j].clone(), a_value_limbs[j]);
+            }
+
+            let memory_limbs = limbs(
+                local.state_mem[i * 2].value().0.map(Into::into),
+                local.state_mem[i * 2 + 1].value().0.map(Into::into),
+            );
+            for j in 0..U64_LIMBS {
+                builder.when(final_step * local.is_real).assert_eq(
+                    memory_limbs[j].clone(),
+                    local.keccak.a_prime_prime_prime(y_idx, x_idx, j),
+                )
+            }
+        }
+
+        // Range check all the values in `state_mem` to be bytes. The block and the absorbed
+        // rate are bytes by the XOR lookups.
+        for i in 0..STATE_NUM_WORDS {
+            builder.slice_range_check_u8(&local.state_mem[i].value().0, local.do_memory_check);
+        }
+
+        let mut sub_builder =
+            SubAirBuilder::<CB, KeccakAir, CB::Var>::new(builder, 0..NUM_KECCAK_COLS);
+
+        // Eval the plonky3 keccak air
+        self.p3_keccak.eval(&mut sub_builder);
+    }
+}
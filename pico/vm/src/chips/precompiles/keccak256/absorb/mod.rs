@@ -0,0 +1,28 @@
+use std::marker::PhantomData;
+
+use p3_keccak_air::KeccakAir;
+
+mod columns;
+mod constraint;
+mod traces;
+
+/// Proves `KECCAK_ABSORB` in 24 rows per event, as [`super::KeccakPermuteChip`] does for
+/// `KECCAK_PERMUTE`.
+///
+/// On the first row, the state and the block are read, and the rate of the state is XORed with
+/// the block through byte lookups. The XORed lanes and the untouched capacity lanes are the input
+/// of the keccak-f permutation, whose output is written back to the state on the last row.
+#[derive(Debug)]
+pub struct KeccakAbsorbChip<F> {
+    p3_keccak: KeccakAir,
+    _marker: PhantomData<fn(F) -> F>,
+}
+
+impl<T: Default> Default for KeccakAbsorbChip<T> {
+    fn default() -> Self {
+        KeccakAbsorbChip {
+            p3_keccak: KeccakAir {},
+            _marker: PhantomData,
+        }
+    }
+}
@@ -2,8 +2,10 @@ use std::marker::PhantomData;
 
 use p3_keccak_air::KeccakAir;
 
+pub mod absorb;
 mod columns;
 mod constraint;
+pub mod squeeze;
 mod traces;
 
 pub(crate) const STATE_SIZE: usize = 25;
@@ -25,3 +27,32 @@ impl<T: Default> Default for KeccakPermuteChip<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::STATE_NUM_WORDS;
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{prove_program, syscall_program},
+        SyscallCode,
+    };
+
+    #[test]
+    fn test_keccak_absorb_squeeze_prove() {
+        const STATE_PTR: u32 = 0x2000;
+        const BLOCK_PTR: u32 = 0x3000;
+        const OUT_PTR: u32 = 0x4000;
+
+        let memory_image = (0..STATE_NUM_WORDS as u32)
+            .map(|i| (STATE_PTR + 4 * i, 0))
+            .chain((0..34).map(|i| (BLOCK_PTR + 4 * i, i * 0x0101_0101)))
+            .collect();
+        let program = syscall_program(
+            &[
+                (SyscallCode::KECCAK_ABSORB, STATE_PTR, BLOCK_PTR),
+                (SyscallCode::KECCAK_SQUEEZE, STATE_PTR, OUT_PTR),
+            ],
+            memory_image,
+        );
+        prove_program(program).unwrap();
+    }
+}
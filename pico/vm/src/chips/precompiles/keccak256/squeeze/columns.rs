@@ -0,0 +1,25 @@
+use crate::{
+    chips::chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+    emulator::riscv::syscalls::precompiles::keccak256::absorb::RATE_NUM_WORDS,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_KECCAK_SQUEEZE_COLS: usize = size_of::<KeccakSqueezeCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct KeccakSqueezeCols<T> {
+    pub chunk: T,
+    pub clk: T,
+    pub state_addr: T,
+    pub out_addr: T,
+
+    /// The rate of the state.
+    pub state_mem: [MemoryReadCols<T>; RATE_NUM_WORDS],
+
+    /// The output, a copy of the rate.
+    pub out_mem: [MemoryWriteCols<T>; RATE_NUM_WORDS],
+
+    pub is_real: T,
+}
@@ -0,0 +1,59 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        precompiles::keccak256::squeeze::{columns::KeccakSqueezeCols, KeccakSqueezeChip},
+    },
+    emulator::riscv::syscalls::SyscallCode,
+    machine::builder::{
+        ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, ChipWordBuilder, RiscVMemoryBuilder,
+    },
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for KeccakSqueezeChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &KeccakSqueezeCols<CB::Var> = (*local).borrow();
+
+        builder.assert_bool(local.is_real);
+
+        // Read the rate of the state, then write it to the output one cycle later so that the
+        // output may overlap the state.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.state_addr,
+            &local.state_mem,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.out_addr,
+            &local.out_mem,
+            local.is_real,
+        );
+
+        // The written words are the read ones, which are bytes already.
+        for (state, out) in local.state_mem.iter().zip(&local.out_mem) {
+            builder
+                .when(local.is_real)
+                .assert_word_eq(*out.value(), *state.value());
+        }
+
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::KECCAK_SQUEEZE.syscall_id()),
+            local.state_addr,
+            local.out_addr,
+            local.is_real,
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraint;
+mod traces;
+
+/// Proves `KECCAK_SQUEEZE` in one row per event.
+///
+/// The row reads the rate of the state and writes the same words to the output one cycle later.
+#[derive(Default)]
+pub struct KeccakSqueezeChip<F> {
+    _phantom: PhantomData<F>,
+}
@@ -0,0 +1,127 @@
+use crate::{
+    chips::{
+        chips::byte::event::ByteRecordBehavior,
+        precompiles::keccak256::squeeze::{
+            columns::{KeccakSqueezeCols, NUM_KECCAK_SQUEEZE_COLS},
+            KeccakSqueezeChip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::riscv::program::Program,
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{KeccakSqueezeEvent, PrecompileEvent},
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for KeccakSqueezeChip<F> {
+    fn width(&self) -> usize {
+        NUM_KECCAK_SQUEEZE_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for KeccakSqueezeChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "KeccakSqueeze".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .get_precompile_events(SyscallCode::KECCAK_SQUEEZE)
+            .iter()
+            .map(|(_, event)| {
+                let event = if let PrecompileEvent::KeccakSqueeze(event) = event {
+                    event
+                } else {
+                    unreachable!()
+                };
+                self.event_to_row(event, &mut new_byte_lookup_events)
+            })
+            .collect::<Vec<_>>();
+
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(&mut rows, || [F::ZERO; NUM_KECCAK_SQUEEZE_COLS], log_rows);
+
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect(),
+            NUM_KECCAK_SQUEEZE_COLS,
+        )
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::KECCAK_SQUEEZE)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::KeccakSqueeze(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_row(event, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record
+                .get_precompile_events(SyscallCode::KECCAK_SQUEEZE)
+                .is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> KeccakSqueezeChip<F> {
+    fn event_to_row(
+        &self,
+        event: &KeccakSqueezeEvent,
+        blu: &mut impl ByteRecordBehavior,
+    ) -> [F; NUM_KECCAK_SQUEEZE_COLS] {
+        let mut row = [F::ZERO; NUM_KECCAK_SQUEEZE_COLS];
+        let cols: &mut KeccakSqueezeCols<F> = row.as_mut_slice().borrow_mut();
+
+        cols.is_real = F::ONE;
+        cols.chunk = F::from_canonical_u32(event.chunk);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.state_addr = F::from_canonical_u32(event.state_addr);
+        cols.out_addr = F::from_canonical_u32(event.out_addr);
+
+        for (state_cols, record) in cols.state_mem.iter_mut().zip(&event.state_read_records) {
+            state_cols.populate(*record, blu);
+        }
+        for (out_cols, record) in cols.out_mem.iter_mut().zip(&event.out_write_records) {
+            out_cols.populate(*record, blu);
+        }
+
+        row
+    }
+}
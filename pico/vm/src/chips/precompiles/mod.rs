@@ -1,7 +1,15 @@
+pub mod aes;
+pub mod base64;
+pub mod crc32;
+pub mod ct_eq;
 pub mod edwards;
 pub mod fptower;
 pub mod keccak256;
+pub mod poly_eval;
 pub mod poseidon2;
+pub mod range_check;
+pub mod rlp;
 pub mod sha256;
+pub mod sort;
 pub mod uint256;
 pub mod weierstrass;
@@ -0,0 +1,56 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+        gadgets::{field_range_check::word_range::FieldWordRangeChecker, is_zero::IsZeroGadget},
+    },
+    emulator::riscv::syscalls::precompiles::poly_eval::POLY_EVAL_NUM_ARGS,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_POLY_EVAL_COLS: usize = size_of::<PolyEvalCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct PolyEvalCols<T> {
+    /// Inputs, copied over every row of an event.
+    pub chunk: T,
+    pub clk: T,
+    pub coeffs_ptr: T,
+    pub args_ptr: T,
+    pub degree: T,
+    pub point_ptr: T,
+    pub result_ptr: T,
+
+    /// The evaluation point as a field element.
+    pub x: T,
+
+    /// The index of the coefficient read in this row, counting down from `degree` to zero.
+    pub index: T,
+
+    /// Checks whether `index` is zero, i.e. whether this is the last Horner step.
+    pub index_is_zero: IsZeroGadget<T>,
+
+    /// Whether the current row is the first of an event and is real.
+    pub is_first: T,
+    /// Whether the current row is the last of an event and is real.
+    pub is_last: T,
+
+    /// The argument block `[degree, point_ptr, result_ptr]`, read on the first row.
+    pub args: [MemoryReadCols<T>; POLY_EVAL_NUM_ARGS],
+    /// The evaluation point, read on the first row.
+    pub point: MemoryReadCols<T>,
+    /// The coefficient consumed by this row.
+    pub coeff: MemoryReadCols<T>,
+    /// The result, written on the last row.
+    pub result: MemoryWriteCols<T>,
+    /// Checks that the result is a canonical field element, so that it is the only word reducing
+    /// to the accumulator.
+    pub result_range_checker: FieldWordRangeChecker<T>,
+
+    /// Horner accumulator before and after this row: `acc_out = acc_in * x + coeff`.
+    pub acc_in: T,
+    pub acc_out: T,
+
+    pub is_real: T,
+}
@@ -0,0 +1,145 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        gadgets::{field_range_check::word_range::FieldWordRangeChecker, is_zero::IsZeroGadget},
+        precompiles::poly_eval::{columns::PolyEvalCols, PolyEvalChip},
+    },
+    emulator::riscv::syscalls::SyscallCode,
+    machine::builder::{
+        ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, ChipRangeBuilder, RiscVMemoryBuilder,
+    },
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for PolyEvalChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &PolyEvalCols<CB::Var> = (*local).borrow();
+        let next: &PolyEvalCols<CB::Var> = (*next).borrow();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.when(local.is_first).assert_one(local.is_real);
+        IsZeroGadget::<CB::F>::eval(
+            builder,
+            local.index.into(),
+            local.index_is_zero,
+            local.is_real.into(),
+        );
+        builder.assert_eq(local.is_last, local.is_real * local.index_is_zero.result);
+
+        // The table starts with a new event or with padding.
+        builder
+            .when_first_row()
+            .assert_eq(local.is_first, local.is_real);
+
+        // Within an event, the next row continues with the next lower coefficient and carries the
+        // accumulator and the inputs over.
+        let is_continuing: CB::Expr = local.is_real - local.is_last;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            builder.assert_one(next.is_real);
+            builder.assert_zero(next.is_first);
+            builder.assert_eq(next.index, local.index - CB::Expr::ONE);
+            builder.assert_eq(next.acc_in, local.acc_out);
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.coeffs_ptr, local.coeffs_ptr);
+            builder.assert_eq(next.args_ptr, local.args_ptr);
+            builder.assert_eq(next.degree, local.degree);
+            builder.assert_eq(next.point_ptr, local.point_ptr);
+            builder.assert_eq(next.result_ptr, local.result_ptr);
+            builder.assert_eq(next.x, local.x);
+        }
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(local.is_last)
+            .assert_eq(next.is_first, next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing);
+
+        // Read the argument block and the point on the first row.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.args_ptr,
+            &local.args,
+            local.is_first,
+        );
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.point_ptr,
+            &local.point,
+            local.is_first,
+        );
+        {
+            let mut builder = builder.when(local.is_first);
+            builder.assert_eq(local.degree, local.args[0].value().reduce::<CB>());
+            builder.assert_eq(local.point_ptr, local.args[1].value().reduce::<CB>());
+            builder.assert_eq(local.result_ptr, local.args[2].value().reduce::<CB>());
+            builder.assert_eq(local.x, local.point.value().reduce::<CB>());
+            builder.assert_eq(local.index, local.degree);
+            builder.assert_zero(local.acc_in);
+        }
+
+        // Read the coefficient of the current index.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.coeffs_ptr + local.index * CB::F::from_canonical_u32(4),
+            &local.coeff,
+            local.is_real,
+        );
+
+        // One Horner step.
+        builder.when(local.is_real).assert_eq(
+            local.acc_out,
+            local.acc_in * local.x + local.coeff.value().reduce::<CB>(),
+        );
+
+        // Write the accumulator to the result on the last row.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.result_ptr,
+            &local.result,
+            local.is_last,
+        );
+        builder
+            .when(local.is_last)
+            .assert_eq(local.result.value().reduce::<CB>(), local.acc_out);
+        builder.slice_range_check_u8(&local.result.value().0, local.is_last);
+        FieldWordRangeChecker::<CB::F>::range_check(
+            builder,
+            *local.result.value(),
+            local.result_range_checker,
+            local.is_last.into(),
+        );
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::POLY_EVAL.syscall_id()),
+            local.coeffs_ptr,
+            local.args_ptr,
+            local.is_first,
+        );
+    }
+}
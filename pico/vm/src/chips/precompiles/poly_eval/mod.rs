@@ -0,0 +1,124 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// Proves `POLY_EVAL` one Horner step per row.
+///
+/// An event of degree `d` spans `d + 1` consecutive rows. Row `j` reads the coefficient of index
+/// `d - j` and computes `acc_out = acc_in * x + c`, with `acc_in` zero on the first row and equal
+/// to the previous `acc_out` otherwise. The last row writes `acc_out` to the result pointer.
+#[derive(Default)]
+pub struct PolyEvalChip<F> {
+    _phantom: PhantomData<F>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::riscv::program::Program,
+        configs::config::StarkGenericConfig,
+        emulator::{
+            opts::EmulatorOpts,
+            riscv::{
+                emulator::RiscvEmulator,
+                syscalls::{
+                    precompiles::PrecompileEvent,
+                    test_utils::{debug_program, prove_program, syscall_program},
+                    SyscallCode,
+                },
+            },
+        },
+        instances::{
+            chiptype::riscv_chiptype::RiscvChipType,
+            configs::riscv_bb_poseidon2::StarkConfig as RiscvBBSC, machine::riscv::RiscvMachine,
+        },
+        machine::{debug::IncrementalConstraintDebugger, machine::MachineBehavior},
+        primitives::consts::RISCV_NUM_PVS,
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+    use p3_field::PrimeField32;
+    use std::collections::BTreeMap;
+
+    const COEFFS_PTR: u32 = 0x2000;
+    const ARGS_PTR: u32 = 0x3000;
+    const POINT_PTR: u32 = 0x3100;
+    const RESULT_PTR: u32 = 0x3200;
+
+    /// A program evaluating `coeffs` at `point` with `POLY_EVAL`.
+    fn poly_eval_program(coeffs: &[u32], point: u32) -> Arc<Program> {
+        let mut memory_image = coeffs
+            .iter()
+            .enumerate()
+            .map(|(i, &coeff)| (COEFFS_PTR + 4 * i as u32, coeff))
+            .collect::<BTreeMap<_, _>>();
+        memory_image.insert(ARGS_PTR, coeffs.len() as u32 - 1);
+        memory_image.insert(ARGS_PTR + 4, POINT_PTR);
+        memory_image.insert(ARGS_PTR + 8, RESULT_PTR);
+        memory_image.insert(POINT_PTR, point);
+        syscall_program(
+            &[(SyscallCode::POLY_EVAL, COEFFS_PTR, ARGS_PTR)],
+            memory_image,
+        )
+    }
+
+    #[test]
+    fn test_poly_eval_constraints() {
+        // Coefficients and point above the modulus are taken as their reductions.
+        let p = BabyBear::ORDER_U32;
+        let program = poly_eval_program(&[3, p + 2, 0, u32::MAX], p + 5);
+        debug_program(program).unwrap();
+    }
+
+    #[test]
+    fn test_poly_eval_prove() {
+        prove_program(poly_eval_program(&[1, 2, 3, 4, 5], 7)).unwrap();
+    }
+
+    #[test]
+    fn test_poly_eval_rejects_unreduced_result() {
+        // 3 + 2 * 5 = 13, which the chip must not accept as 13 + p.
+        let program = poly_eval_program(&[3, 2], 5);
+        let machine =
+            RiscvMachine::new(RiscvBBSC::new(), RiscvChipType::chips(true), RISCV_NUM_PVS);
+        let (pk, _) = machine.setup_keys(&program);
+        let chips = machine.chips();
+
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::default());
+        let mut records = emulator.run(None).unwrap();
+        let record = records
+            .iter_mut()
+            .find(|record| {
+                record
+                    .precompile_events
+                    .get_events(SyscallCode::POLY_EVAL)
+                    .is_some_and(|events| !events.is_empty())
+            })
+            .unwrap();
+        let mut events = record
+            .precompile_events
+            .get_events(SyscallCode::POLY_EVAL)
+            .unwrap()
+            .clone();
+        let PrecompileEvent::PolyEval(event) = &mut events[0].1 else {
+            unreachable!()
+        };
+        assert_eq!(event.result, 13);
+        event.result += BabyBear::ORDER_U32;
+        event.result_write_record.value = event.result;
+        record
+            .precompile_events
+            .insert(SyscallCode::POLY_EVAL, events);
+        RiscvMachine::complement_record_static(chips.clone(), record);
+
+        let mut debugger = IncrementalConstraintDebugger::new(
+            &pk,
+            &mut machine.config().challenger(),
+            machine.base_machine().has_global(),
+        );
+        debugger.debug_incremental(&chips, std::slice::from_ref(record));
+        assert_eq!(debugger.first_failure(), Some(("PolyEval", 1)));
+    }
+}
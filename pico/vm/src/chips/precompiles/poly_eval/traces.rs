@@ -0,0 +1,150 @@
+use crate::{
+    chips::{
+        chips::byte::event::ByteRecordBehavior,
+        precompiles::poly_eval::{
+            columns::{PolyEvalCols, NUM_POLY_EVAL_COLS},
+            PolyEvalChip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::riscv::program::Program,
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{PolyEvalEvent, PrecompileEvent},
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for PolyEvalChip<F> {
+    fn width(&self) -> usize {
+        NUM_POLY_EVAL_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for PolyEvalChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "PolyEval".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        let mut new_byte_lookup_events = Vec::new();
+        for (_, event) in input.get_precompile_events(SyscallCode::POLY_EVAL).iter() {
+            let event = if let PrecompileEvent::PolyEval(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut new_byte_lookup_events);
+        }
+
+        let mut rows = rows.unwrap();
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(&mut rows, || [F::ZERO; NUM_POLY_EVAL_COLS], log_rows);
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_POLY_EVAL_COLS)
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::POLY_EVAL)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::PolyEval(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_rows(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record
+                .get_precompile_events(SyscallCode::POLY_EVAL)
+                .is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> PolyEvalChip<F> {
+    fn event_to_rows(
+        &self,
+        event: &PolyEvalEvent,
+        rows: &mut Option<Vec<[F; NUM_POLY_EVAL_COLS]>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        let x = F::from_wrapped_u32(event.point);
+        let mut acc = F::ZERO;
+
+        for (j, index) in (0..=event.degree as usize).rev().enumerate() {
+            let mut row = [F::ZERO; NUM_POLY_EVAL_COLS];
+            let cols: &mut PolyEvalCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.coeffs_ptr = F::from_canonical_u32(event.coeffs_ptr);
+            cols.args_ptr = F::from_canonical_u32(event.args_ptr);
+            cols.degree = F::from_canonical_u32(event.degree);
+            cols.point_ptr = F::from_canonical_u32(event.point_ptr);
+            cols.result_ptr = F::from_canonical_u32(event.result_ptr);
+            cols.x = x;
+
+            cols.index = F::from_canonical_usize(index);
+            cols.index_is_zero.populate(index as u32);
+            cols.is_first = F::from_bool(j == 0);
+            cols.is_last = F::from_bool(index == 0);
+
+            if j == 0 {
+                for (args_cols, record) in cols.args.iter_mut().zip(&event.args_read_records) {
+                    args_cols.populate(*record, blu);
+                }
+                cols.point.populate(event.point_read_record, blu);
+            }
+
+            cols.coeff.populate(event.coeffs_read_records[index], blu);
+            cols.acc_in = acc;
+            acc = acc * x + F::from_wrapped_u32(event.coeffs[index]);
+            cols.acc_out = acc;
+
+            if index == 0 {
+                cols.result.populate(event.result_write_record, blu);
+                cols.result_range_checker.populate(event.result);
+                blu.add_u8_range_checks(event.result.to_le_bytes());
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
@@ -11,20 +11,20 @@ use crate::{
     primitives::consts::PERMUTATION_WIDTH,
 };
 
-pub const fn num_poseidon2_cols<Config: Poseidon2Config>() -> usize {
-    size_of::<Poseidon2Cols<u8, Config>>()
+pub const fn num_poseidon2_cols<Config: Poseidon2Config, const OUTPUT_WIDTH: usize>() -> usize {
+    size_of::<Poseidon2Cols<u8, Config, OUTPUT_WIDTH>>()
 }
 
 #[derive(AlignedBorrow)]
 #[repr(C)]
-pub struct Poseidon2Cols<T, Config: Poseidon2Config> {
+pub struct Poseidon2Cols<T, Config: Poseidon2Config, const OUTPUT_WIDTH: usize> {
     pub chunk: T,
     pub clk: T,
     pub input_memory_ptr: T,
     pub input_memory: [MemoryReadCols<T>; PERMUTATION_WIDTH],
 
     pub output_memory_ptr: T,
-    pub output_memory: [MemoryWriteCols<T>; PERMUTATION_WIDTH],
+    pub output_memory: [MemoryWriteCols<T>; OUTPUT_WIDTH],
 
     // TODO: is it safe to remove state_linear_layer cols?
     pub value_cols: Poseidon2ValueCols<T, Config>,
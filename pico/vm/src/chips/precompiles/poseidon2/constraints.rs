@@ -7,7 +7,6 @@ use crate::{
         gadgets::poseidon2::constraints::eval_poseidon2,
     },
     configs::config::Poseidon2Config,
-    emulator::riscv::syscalls::SyscallCode,
     machine::builder::{ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, RiscVMemoryBuilder},
     primitives::consts::PERMUTATION_WIDTH,
 };
@@ -21,14 +20,15 @@ impl<
         LinearLayers: GenericPoseidon2LinearLayers<CB::Expr, PERMUTATION_WIDTH>,
         Config: Poseidon2Config,
         CB: ChipBuilder<F>,
-    > Air<CB> for Poseidon2PermuteChip<F, LinearLayers, Config>
+        const OUTPUT_WIDTH: usize,
+    > Air<CB> for Poseidon2PermuteChip<F, LinearLayers, Config, OUTPUT_WIDTH>
 where
     CB::Var: Sized,
 {
     fn eval(&self, builder: &mut CB) {
         let main = builder.main();
         let local = main.row_slice(0);
-        let local: &Poseidon2Cols<CB::Var, Config> = (*local).borrow();
+        let local: &Poseidon2Cols<CB::Var, Config, OUTPUT_WIDTH> = (*local).borrow();
 
         // Load from memory to the state
         for (i, word) in local.input_memory.iter().enumerate() {
@@ -41,9 +41,13 @@ where
             &self.constants,
         );
 
-        // Assert that the permuted state is being written to input_memory.
+        // Assert that the first OUTPUT_WIDTH elements of the permuted state are written to
+        // output_memory.
         builder.when(local.value_cols.is_real).assert_all_eq(
-            state.into_iter().collect::<Vec<CB::Expr>>(),
+            state
+                .into_iter()
+                .take(OUTPUT_WIDTH)
+                .collect::<Vec<CB::Expr>>(),
             local
                 .output_memory
                 .into_iter()
@@ -69,11 +73,9 @@ where
             local.value_cols.is_real,
         );
 
-        let syscall_code = SyscallCode::POSEIDON2_PERMUTE;
-
         builder.looked_syscall(
             local.clk,
-            CB::F::from_canonical_u32(syscall_code.syscall_id()),
+            CB::F::from_canonical_u32(Self::SYSCALL_CODE.syscall_id()),
             local.input_memory_ptr,
             local.output_memory_ptr,
             local.value_cols.is_real,
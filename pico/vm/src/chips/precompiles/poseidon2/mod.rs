@@ -3,8 +3,11 @@ mod constraints;
 mod traces;
 
 use crate::{
-    chips::gadgets::poseidon2::constants::RoundConstants, configs::config::Poseidon2Config,
+    chips::gadgets::poseidon2::constants::RoundConstants,
+    configs::config::Poseidon2Config,
+    emulator::riscv::syscalls::SyscallCode,
     machine::field::FieldSpecificPoseidon2Config,
+    primitives::consts::{DIGEST_SIZE, PERMUTATION_WIDTH},
 };
 use core::marker::PhantomData;
 use p3_field::Field;
@@ -15,15 +18,41 @@ pub type FieldSpecificPrecompilePoseidon2Chip<F> = Poseidon2PermuteChip<
     <F as FieldSpecificPoseidon2Config>::Poseidon2Config,
 >;
 
+pub type FieldSpecificPrecompilePoseidon2CompressChip<F> = Poseidon2PermuteChip<
+    F,
+    <F as FieldSpecificPoseidon2Config>::LinearLayers,
+    <F as FieldSpecificPoseidon2Config>::Poseidon2Config,
+    DIGEST_SIZE,
+>;
+
+/// Proves Poseidon2 permutations of 16 elements read from memory, writing the first
+/// `OUTPUT_WIDTH` elements of the result back: the whole state for `POSEIDON2_PERMUTE`, or the
+/// Merkle parent of two digests for `POSEIDON2_COMPRESS`.
 #[allow(clippy::type_complexity)]
 #[derive(Debug)]
-pub struct Poseidon2PermuteChip<F, LinearLayers, Config: Poseidon2Config> {
+pub struct Poseidon2PermuteChip<
+    F,
+    LinearLayers,
+    Config: Poseidon2Config,
+    const OUTPUT_WIDTH: usize = PERMUTATION_WIDTH,
+> {
     pub(crate) constants: RoundConstants<F, Config>,
     pub _phantom: PhantomData<fn(LinearLayers) -> LinearLayers>,
 }
 
-impl<F: Field, LinearLayers, Config: Poseidon2Config> Default
-    for Poseidon2PermuteChip<F, LinearLayers, Config>
+impl<F, LinearLayers, Config: Poseidon2Config, const OUTPUT_WIDTH: usize>
+    Poseidon2PermuteChip<F, LinearLayers, Config, OUTPUT_WIDTH>
+{
+    /// The syscall whose events the chip proves.
+    pub(crate) const SYSCALL_CODE: SyscallCode = match OUTPUT_WIDTH {
+        PERMUTATION_WIDTH => SyscallCode::POSEIDON2_PERMUTE,
+        DIGEST_SIZE => SyscallCode::POSEIDON2_COMPRESS,
+        _ => panic!("a Poseidon2 precompile writes either the whole state or one digest"),
+    };
+}
+
+impl<F: Field, LinearLayers, Config: Poseidon2Config, const OUTPUT_WIDTH: usize> Default
+    for Poseidon2PermuteChip<F, LinearLayers, Config, OUTPUT_WIDTH>
 {
     fn default() -> Self {
         let constants = RoundConstants::default();
@@ -33,3 +62,26 @@ impl<F: Field, LinearLayers, Config: Poseidon2Config> Default
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{prove_program, syscall_program},
+        SyscallCode,
+    };
+
+    #[test]
+    fn test_poseidon2_compress_prove() {
+        const INPUT_PTR: u32 = 0x2000;
+        const OUTPUT_PTR: u32 = 0x3000;
+
+        let memory_image = (0..16)
+            .map(|i| (INPUT_PTR + 4 * i, i * 1_000_003))
+            .collect();
+        let program = syscall_program(
+            &[(SyscallCode::POSEIDON2_COMPRESS, INPUT_PTR, OUTPUT_PTR)],
+            memory_image,
+        );
+        prove_program(program).unwrap();
+    }
+}
@@ -29,11 +29,11 @@ use p3_poseidon2::GenericPoseidon2LinearLayers;
 use std::borrow::BorrowMut;
 use tracing::debug;
 
-impl<F: Field, LinearLayers, Config: Poseidon2Config> BaseAir<F>
-    for Poseidon2PermuteChip<F, LinearLayers, Config>
+impl<F: Field, LinearLayers, Config: Poseidon2Config, const OUTPUT_WIDTH: usize> BaseAir<F>
+    for Poseidon2PermuteChip<F, LinearLayers, Config, OUTPUT_WIDTH>
 {
     fn width(&self) -> usize {
-        num_poseidon2_cols::<Config>()
+        num_poseidon2_cols::<Config, OUTPUT_WIDTH>()
     }
 }
 
@@ -41,28 +41,21 @@ impl<
         F: PrimeField32,
         LinearLayers: GenericPoseidon2LinearLayers<F, PERMUTATION_WIDTH>,
         Config: Poseidon2Config,
-    > ChipBehavior<F> for Poseidon2PermuteChip<F, LinearLayers, Config>
+        const OUTPUT_WIDTH: usize,
+    > ChipBehavior<F> for Poseidon2PermuteChip<F, LinearLayers, Config, OUTPUT_WIDTH>
 {
     type Record = EmulationRecord;
     type Program = Program;
 
     fn name(&self) -> String {
-        "Poseidon2Permute".to_string()
+        match Self::SYSCALL_CODE {
+            SyscallCode::POSEIDON2_COMPRESS => "Poseidon2Compress".to_string(),
+            _ => "Poseidon2Permute".to_string(),
+        }
     }
 
     fn generate_main(&self, input: &Self::Record, _output: &mut Self::Record) -> RowMajorMatrix<F> {
-        let syscall_code = SyscallCode::POSEIDON2_PERMUTE;
-        let events: Vec<_> = input
-            .get_precompile_events(syscall_code)
-            .iter()
-            .filter_map(|(_, event)| {
-                if let PrecompileEvent::Poseidon2Permute(event) = event {
-                    Some(event)
-                } else {
-                    unreachable!()
-                }
-            })
-            .collect();
+        let events = self.events(input);
 
         debug!(
             "record {} poseidon2 precompile events {:?}",
@@ -88,7 +81,8 @@ impl<
         let dummy_row = {
             let mut dummy = vec![F::ZERO; self.width()];
 
-            let dummy_cols: &mut Poseidon2Cols<F, Config> = dummy.as_mut_slice().borrow_mut();
+            let dummy_cols: &mut Poseidon2Cols<F, Config, OUTPUT_WIDTH> =
+                dummy.as_mut_slice().borrow_mut();
 
             let dummy_perm = &mut dummy_cols.value_cols;
 
@@ -114,10 +108,7 @@ impl<
         if let Some(shape) = record.shape.as_ref() {
             shape.included::<F, _>(self)
         } else {
-            let syscalls = [SyscallCode::POSEIDON2_PERMUTE];
-            syscalls
-                .iter()
-                .any(|&syscall| !record.get_precompile_events(syscall).is_empty())
+            !record.get_precompile_events(Self::SYSCALL_CODE).is_empty()
         }
     }
 
@@ -129,18 +120,7 @@ impl<
     }
 
     fn extra_record(&self, input: &Self::Record, extra: &mut Self::Record) {
-        let syscall_code = SyscallCode::POSEIDON2_PERMUTE;
-        let events: Vec<_> = input
-            .get_precompile_events(syscall_code)
-            .iter()
-            .filter_map(|(_, event)| {
-                if let PrecompileEvent::Poseidon2Permute(event) = event {
-                    Some(event)
-                } else {
-                    unreachable!()
-                }
-            })
-            .collect();
+        let events = self.events(input);
 
         let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
         let blu_batches = events
@@ -165,8 +145,21 @@ impl<
         F: PrimeField32,
         LinearLayers: GenericPoseidon2LinearLayers<F, PERMUTATION_WIDTH>,
         Config: Poseidon2Config,
-    > Poseidon2PermuteChip<F, LinearLayers, Config>
+        const OUTPUT_WIDTH: usize,
+    > Poseidon2PermuteChip<F, LinearLayers, Config, OUTPUT_WIDTH>
 {
+    fn events<'a>(&self, input: &'a EmulationRecord) -> Vec<&'a Poseidon2PermuteEvent> {
+        input
+            .get_precompile_events(Self::SYSCALL_CODE)
+            .iter()
+            .map(|(_, event)| match event {
+                PrecompileEvent::Poseidon2Permute(event)
+                | PrecompileEvent::Poseidon2Compress(event) => event,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
     fn event_to_row(
         &self,
         event: &Poseidon2PermuteEvent,
@@ -174,8 +167,8 @@ impl<
         blu: &mut impl ByteRecordBehavior,
     ) {
         // use Vec because num_cols is too large
-        let mut row = vec![F::ZERO; num_poseidon2_cols::<Config>()];
-        let cols: &mut Poseidon2Cols<F, Config> = row.as_mut_slice().borrow_mut();
+        let mut row = vec![F::ZERO; num_poseidon2_cols::<Config, OUTPUT_WIDTH>()];
+        let cols: &mut Poseidon2Cols<F, Config, OUTPUT_WIDTH> = row.as_mut_slice().borrow_mut();
 
         // cols.value_cols.is_real is populated in the following populate_perm
         cols.chunk = F::from_canonical_u32(event.chunk);
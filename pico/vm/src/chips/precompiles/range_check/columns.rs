@@ -0,0 +1,47 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryReadCols,
+        precompiles::range_check::RANGE_CHECK_BATCH_VALUES_PER_ROW,
+    },
+    emulator::riscv::syscalls::precompiles::range_check::{
+        RANGE_CHECK_BATCH_MAX_BITS, RANGE_CHECK_BATCH_NUM_ARGS,
+    },
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_RANGE_CHECK_BATCH_COLS: usize = size_of::<RangeCheckBatchCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct RangeCheckBatchCols<T> {
+    /// Inputs, copied over every row of an event.
+    pub chunk: T,
+    pub clk: T,
+    pub values_ptr: T,
+    pub args_ptr: T,
+    pub n: T,
+    pub bits: T,
+
+    /// `bits_flags[k]` is set if and only if `bits == k`.
+    pub bits_flags: [T; RANGE_CHECK_BATCH_MAX_BITS + 1],
+
+    /// The index of the first value of this row, increasing by 16 from zero.
+    pub offset: T,
+
+    /// Whether the current row is the first of an event and is real.
+    pub is_first: T,
+    /// Whether the current row is the last of an event and is real.
+    pub is_last: T,
+
+    /// The argument block `[n, bits]`, read on the first row.
+    pub args: [MemoryReadCols<T>; RANGE_CHECK_BATCH_NUM_ARGS],
+
+    /// The values checked in this row.
+    pub values: [MemoryReadCols<T>; RANGE_CHECK_BATCH_VALUES_PER_ROW],
+    /// Whether each slot of `values` holds a value of the event, only the last row having unused
+    /// slots.
+    pub is_value: [T; RANGE_CHECK_BATCH_VALUES_PER_ROW],
+
+    pub is_real: T,
+}
@@ -0,0 +1,160 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        precompiles::range_check::{
+            columns::RangeCheckBatchCols, max_byte, RangeCheckBatchChip,
+            RANGE_CHECK_BATCH_VALUES_PER_ROW,
+        },
+    },
+    emulator::riscv::syscalls::SyscallCode,
+    machine::builder::{
+        ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, ChipRangeBuilder, RiscVMemoryBuilder,
+    },
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for RangeCheckBatchChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &RangeCheckBatchCols<CB::Var> = (*local).borrow();
+        let next: &RangeCheckBatchCols<CB::Var> = (*next).borrow();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // The slots in use are a prefix of the row, and the first one is used on every real row.
+        builder.assert_eq(local.is_value[0], local.is_real);
+        for j in 0..RANGE_CHECK_BATCH_VALUES_PER_ROW {
+            builder.assert_bool(local.is_value[j]);
+            if j > 0 {
+                builder
+                    .when(local.is_value[j])
+                    .assert_one(local.is_value[j - 1]);
+            }
+        }
+
+        // `bits_flags` is the one-hot encoding of `bits` on real rows.
+        let mut num_flags = CB::Expr::ZERO;
+        let mut flagged_bits = CB::Expr::ZERO;
+        for (k, &flag) in local.bits_flags.iter().enumerate() {
+            builder.assert_bool(flag);
+            num_flags = num_flags + flag;
+            flagged_bits = flagged_bits + flag * CB::F::from_canonical_usize(k);
+        }
+        builder.assert_eq(num_flags, local.is_real);
+        builder
+            .when(local.is_real)
+            .assert_eq(flagged_bits, local.bits);
+
+        // The table starts with a new event or with padding.
+        builder
+            .when_first_row()
+            .assert_eq(local.is_first, local.is_real);
+
+        // Within an event, every slot of a row but the last is used, and the next row checks the
+        // next sixteen values with the same inputs.
+        let is_continuing: CB::Expr = local.is_real - local.is_last;
+        builder
+            .when(is_continuing.clone())
+            .assert_one(local.is_value[RANGE_CHECK_BATCH_VALUES_PER_ROW - 1]);
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            builder.assert_one(next.is_real);
+            builder.assert_zero(next.is_first);
+            builder.assert_eq(
+                next.offset,
+                local.offset + CB::F::from_canonical_usize(RANGE_CHECK_BATCH_VALUES_PER_ROW),
+            );
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.values_ptr, local.values_ptr);
+            builder.assert_eq(next.args_ptr, local.args_ptr);
+            builder.assert_eq(next.n, local.n);
+            builder.assert_eq(next.bits, local.bits);
+        }
+
+        // The last row checks the values left.
+        builder.when(local.is_last).assert_eq(
+            local.is_value.iter().map(|&x| x.into()).sum::<CB::Expr>(),
+            local.n - local.offset,
+        );
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(local.is_last)
+            .assert_eq(next.is_first, next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing);
+
+        // Read the argument block on the first row.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            local.args_ptr,
+            &local.args,
+            local.is_first,
+        );
+        {
+            let mut builder = builder.when(local.is_first);
+            builder.assert_eq(local.n, local.args[0].value().reduce::<CB>());
+            builder.assert_eq(local.bits, local.args[1].value().reduce::<CB>());
+            builder.assert_zero(local.offset);
+        }
+
+        // The largest value of each byte allowed by `bits`.
+        let max_bytes: [CB::Expr; 4] = core::array::from_fn(|i| {
+            local
+                .bits_flags
+                .iter()
+                .enumerate()
+                .map(|(k, &flag)| flag * CB::F::from_canonical_u8(max_byte(k, i)))
+                .sum()
+        });
+
+        for (j, (value, &is_value)) in local.values.iter().zip(&local.is_value).enumerate() {
+            builder.eval_memory_access(
+                local.chunk,
+                local.clk,
+                local.values_ptr
+                    + (local.offset + CB::F::from_canonical_usize(j))
+                        * CB::F::from_canonical_u32(4),
+                value,
+                is_value,
+            );
+
+            // A value fits in `bits` bits if and only if each of its bytes is at most the
+            // largest one allowed, that is if both the byte and its distance to it are bytes.
+            let bytes = value.value().0;
+            builder.slice_range_check_u8(&bytes, is_value);
+            let headroom: [CB::Expr; 4] = core::array::from_fn(|i| max_bytes[i].clone() - bytes[i]);
+            builder.slice_range_check_u8(&headroom, is_value);
+        }
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::RANGE_CHECK_BATCH.syscall_id()),
+            local.values_ptr,
+            local.args_ptr,
+            local.is_first,
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// The number of values checked in one row of [`RangeCheckBatchChip`].
+pub const RANGE_CHECK_BATCH_VALUES_PER_ROW: usize = 16;
+
+/// Proves `RANGE_CHECK_BATCH` sixteen values per row.
+///
+/// An event over `n` values spans `n.div_ceil(16)` consecutive rows, the last one possibly
+/// partially filled. Every byte of a value is looked up in the byte table together with its
+/// distance to the largest byte allowed at its position by `bits`, so that both lookups only
+/// succeed if the value fits.
+#[derive(Default)]
+pub struct RangeCheckBatchChip<F> {
+    _phantom: PhantomData<F>,
+}
+
+/// The largest value of the `i`-th little endian byte of a value of `bits` bits.
+pub(crate) fn max_byte(bits: usize, i: usize) -> u8 {
+    ((1u32 << bits.saturating_sub(8 * i).min(8)) - 1) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{prove_program, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_range_check_batch_prove() {
+        const VALUES_PTR: u32 = 0x2000;
+        const ARGS_PTR: u32 = 0x3000;
+
+        // 20 values of up to 13 bits fill one row and part of the next.
+        let mut memory_image = (0..20)
+            .map(|i| (VALUES_PTR + 4 * i, (i * 409) % (1 << 13)))
+            .collect::<BTreeMap<_, _>>();
+        memory_image.insert(VALUES_PTR + 4 * 19, (1 << 13) - 1);
+        memory_image.extend([(ARGS_PTR, 20), (ARGS_PTR + 4, 13)]);
+        let program = syscall_program(
+            &[(SyscallCode::RANGE_CHECK_BATCH, VALUES_PTR, ARGS_PTR)],
+            memory_image,
+        );
+        prove_program(program).unwrap();
+    }
+}
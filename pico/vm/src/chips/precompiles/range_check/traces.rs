@@ -0,0 +1,168 @@
+use crate::{
+    chips::{
+        chips::byte::event::ByteRecordBehavior,
+        precompiles::range_check::{
+            columns::{RangeCheckBatchCols, NUM_RANGE_CHECK_BATCH_COLS},
+            max_byte, RangeCheckBatchChip, RANGE_CHECK_BATCH_VALUES_PER_ROW,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::riscv::program::Program,
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{PrecompileEvent, RangeCheckBatchEvent},
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for RangeCheckBatchChip<F> {
+    fn width(&self) -> usize {
+        NUM_RANGE_CHECK_BATCH_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for RangeCheckBatchChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "RangeCheckBatch".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        let mut new_byte_lookup_events = Vec::new();
+        for (_, event) in input
+            .get_precompile_events(SyscallCode::RANGE_CHECK_BATCH)
+            .iter()
+        {
+            let event = if let PrecompileEvent::RangeCheckBatch(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut new_byte_lookup_events);
+        }
+
+        let mut rows = rows.unwrap();
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(
+            &mut rows,
+            || [F::ZERO; NUM_RANGE_CHECK_BATCH_COLS],
+            log_rows,
+        );
+
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect(),
+            NUM_RANGE_CHECK_BATCH_COLS,
+        )
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::RANGE_CHECK_BATCH)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::RangeCheckBatch(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_rows(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record
+                .get_precompile_events(SyscallCode::RANGE_CHECK_BATCH)
+                .is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> RangeCheckBatchChip<F> {
+    fn event_to_rows(
+        &self,
+        event: &RangeCheckBatchEvent,
+        rows: &mut Option<Vec<[F; NUM_RANGE_CHECK_BATCH_COLS]>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        let bits = event.bits as usize;
+        let max_bytes: [u8; 4] = core::array::from_fn(|i| max_byte(bits, i));
+        let num_rows = event
+            .values
+            .len()
+            .div_ceil(RANGE_CHECK_BATCH_VALUES_PER_ROW);
+
+        for (r, (values, records)) in event
+            .values
+            .chunks(RANGE_CHECK_BATCH_VALUES_PER_ROW)
+            .zip(
+                event
+                    .values_read_records
+                    .chunks(RANGE_CHECK_BATCH_VALUES_PER_ROW),
+            )
+            .enumerate()
+        {
+            let mut row = [F::ZERO; NUM_RANGE_CHECK_BATCH_COLS];
+            let cols: &mut RangeCheckBatchCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.values_ptr = F::from_canonical_u32(event.values_ptr);
+            cols.args_ptr = F::from_canonical_u32(event.args_ptr);
+            cols.n = F::from_canonical_u32(event.n);
+            cols.bits = F::from_canonical_u32(event.bits);
+            cols.bits_flags[bits] = F::ONE;
+
+            cols.offset = F::from_canonical_usize(r * RANGE_CHECK_BATCH_VALUES_PER_ROW);
+            cols.is_first = F::from_bool(r == 0);
+            cols.is_last = F::from_bool(r + 1 == num_rows);
+
+            if r == 0 {
+                for (args_cols, record) in cols.args.iter_mut().zip(&event.args_read_records) {
+                    args_cols.populate(*record, blu);
+                }
+            }
+
+            for (j, (value, record)) in values.iter().zip(records).enumerate() {
+                cols.is_value[j] = F::ONE;
+                cols.values[j].populate(*record, blu);
+
+                let bytes = value.to_le_bytes();
+                blu.add_u8_range_checks(bytes);
+                blu.add_u8_range_checks(max_bytes.iter().zip(bytes).map(|(max, byte)| max - byte));
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
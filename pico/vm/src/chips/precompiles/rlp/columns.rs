@@ -0,0 +1,40 @@
+use crate::{
+    chips::chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+    compiler::word::Word,
+    emulator::riscv::syscalls::precompiles::rlp::{RLP_MAX_LEN_OF_LEN, RLP_NUM_KINDS},
+    primitives::consts::WORD_SIZE,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_RLP_DECODE_ITEM_COLS: usize = size_of::<RlpDecodeItemCols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct RlpDecodeItemCols<T> {
+    pub chunk: T,
+    pub clk: T,
+    /// The bytes of the input pointer, which need not be word aligned.
+    pub input_ptr: Word<T>,
+    pub output_ptr: T,
+
+    /// The low byte of the input pointer divided by 4, rounded down.
+    pub input_ptr_low_div4: T,
+    /// One-hot position of the prefix byte in the first word.
+    pub offset: [T; WORD_SIZE],
+
+    /// The two words starting at the word that holds the prefix byte.
+    pub words: [MemoryReadCols<T>; 2],
+
+    /// One-hot kind of the header, from `RLP_SINGLE_BYTE` to `RLP_LONG_LIST`.
+    pub kind: [T; RLP_NUM_KINDS],
+    /// The prefix byte minus the smallest prefix of its kind.
+    pub prefix_rel: T,
+    /// One-hot number of length bytes minus one, for long strings and lists.
+    pub len_of_len: [T; RLP_MAX_LEN_OF_LEN],
+
+    /// The output `[header_len, payload_len, is_list]`.
+    pub output: [MemoryWriteCols<T>; 3],
+
+    pub is_real: T,
+}
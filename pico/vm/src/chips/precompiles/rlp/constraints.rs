@@ -0,0 +1,156 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        precompiles::rlp::{columns::RlpDecodeItemCols, RlpDecodeItemChip},
+    },
+    emulator::riscv::syscalls::{
+        precompiles::rlp::{
+            RLP_HEADER_MAX_LEN, RLP_KIND_PREFIX_SPAN, RLP_KIND_PREFIX_START, RLP_LONG_LIST,
+            RLP_LONG_STRING, RLP_MAX_LEN_OF_LEN, RLP_SHORT_LIST, RLP_SHORT_STRING, RLP_SINGLE_BYTE,
+        },
+        SyscallCode,
+    },
+    machine::builder::{
+        ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, ChipRangeBuilder, RiscVMemoryBuilder,
+    },
+    primitives::consts::WORD_SIZE,
+};
+use core::{array, borrow::Borrow};
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for RlpDecodeItemChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &RlpDecodeItemCols<CB::Var> = (*local).borrow();
+
+        let sum = |flags: &[CB::Var]| flags.iter().map(|&flag| flag.into()).sum::<CB::Expr>();
+        let weighted = |flags: &[CB::Var], weight: &dyn Fn(usize) -> u32| {
+            flags
+                .iter()
+                .enumerate()
+                .map(|(i, &flag)| flag * CB::F::from_canonical_u32(weight(i)))
+                .sum::<CB::Expr>()
+        };
+
+        // One-hot flags.
+        builder.assert_bool(local.is_real);
+        for &flag in local
+            .offset
+            .iter()
+            .chain(&local.kind)
+            .chain(&local.len_of_len)
+        {
+            builder.assert_bool(flag);
+        }
+        builder.assert_eq(sum(&local.offset), local.is_real);
+        builder.assert_eq(sum(&local.kind), local.is_real);
+        let is_long: CB::Expr = local.kind[RLP_LONG_STRING] + local.kind[RLP_LONG_LIST];
+        builder.assert_eq(sum(&local.len_of_len), is_long.clone());
+
+        // The low two bits of the input pointer give the offset of the prefix byte, and the rest
+        // give the aligned pointer of the first word.
+        let offset = weighted(&local.offset, &|i| i as u32);
+        builder.when(local.is_real).assert_eq(
+            local.input_ptr[0],
+            local.input_ptr_low_div4 * CB::F::from_canonical_u32(4) + offset.clone(),
+        );
+        let aligned_ptr = local.input_ptr.reduce::<CB>() - offset;
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk,
+            aligned_ptr,
+            &local.words,
+            local.is_real,
+        );
+
+        // The header bytes, starting at the prefix.
+        let bytes = local
+            .words
+            .iter()
+            .flat_map(|word| word.value().0)
+            .collect::<Vec<_>>();
+        let header: [CB::Expr; RLP_HEADER_MAX_LEN] =
+            array::from_fn(|k| (0..WORD_SIZE).map(|i| local.offset[i] * bytes[i + k]).sum());
+
+        // The prefix is within the range of its kind.
+        let rel = local.prefix_rel;
+        builder.assert_eq(
+            rel,
+            header[0].clone() - weighted(&local.kind, &|i| RLP_KIND_PREFIX_START[i].into()),
+        );
+        let span = weighted(&local.kind, &|i| RLP_KIND_PREFIX_SPAN[i].into());
+        builder.slice_range_check_u8(
+            &[local.input_ptr_low_div4.into(), rel.into(), span - rel],
+            local.is_real,
+        );
+        builder.slice_range_check_u8(&local.input_ptr.0, local.is_real);
+
+        // For long strings and lists, the prefix gives the number of length bytes.
+        builder
+            .when(is_long)
+            .assert_eq(rel, weighted(&local.len_of_len, &|i| i as u32));
+
+        // The header length.
+        let is_short: CB::Expr = local.kind[RLP_SHORT_STRING] + local.kind[RLP_SHORT_LIST];
+        let header_len = local.output[0].value();
+        builder.assert_eq(
+            header_len[0],
+            is_short.clone() + weighted(&local.len_of_len, &|i| i as u32 + 2),
+        );
+
+        // The payload length, whose little-endian bytes are the big-endian length bytes in
+        // reverse for long strings and lists.
+        let payload_len = local.output[1].value();
+        builder.assert_eq(
+            payload_len[0],
+            local.kind[RLP_SINGLE_BYTE]
+                + is_short * rel
+                + (0..RLP_MAX_LEN_OF_LEN)
+                    .map(|i| local.len_of_len[i] * header[i + 1].clone())
+                    .sum::<CB::Expr>(),
+        );
+        for j in 1..WORD_SIZE {
+            builder.assert_eq(
+                payload_len[j],
+                (j..RLP_MAX_LEN_OF_LEN)
+                    .map(|i| local.len_of_len[i] * header[i + 1 - j].clone())
+                    .sum::<CB::Expr>(),
+            );
+        }
+
+        // Whether the item is a list.
+        let is_list = local.output[2].value();
+        builder.assert_eq(
+            is_list[0],
+            local.kind[RLP_SHORT_LIST] + local.kind[RLP_LONG_LIST],
+        );
+
+        for output in [header_len, is_list] {
+            for j in 1..WORD_SIZE {
+                builder.assert_zero(output[j]);
+            }
+        }
+
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.output_ptr,
+            &local.output,
+            local.is_real,
+        );
+
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(SyscallCode::RLP_DECODE_ITEM.syscall_id()),
+            local.input_ptr.reduce::<CB>(),
+            local.output_ptr,
+            local.is_real,
+        );
+    }
+}
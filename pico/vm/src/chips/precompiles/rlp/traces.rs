@@ -0,0 +1,153 @@
+use crate::{
+    chips::{
+        chips::byte::event::ByteRecordBehavior,
+        precompiles::rlp::{
+            columns::{RlpDecodeItemCols, NUM_RLP_DECODE_ITEM_COLS},
+            RlpDecodeItemChip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::{riscv::program::Program, word::Word},
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{
+                rlp::{
+                    rlp_decode_header, RLP_HEADER_MAX_LEN, RLP_KIND_PREFIX_SPAN,
+                    RLP_KIND_PREFIX_START, RLP_LONG_LIST, RLP_LONG_STRING,
+                },
+                PrecompileEvent, RlpDecodeItemEvent,
+            },
+            SyscallCode,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for RlpDecodeItemChip<F> {
+    fn width(&self) -> usize {
+        NUM_RLP_DECODE_ITEM_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for RlpDecodeItemChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "RlpDecodeItem".to_string()
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+        for (_, event) in input.get_precompile_events(SyscallCode::RLP_DECODE_ITEM) {
+            let event = if let PrecompileEvent::RlpDecodeItem(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            rows.push(self.event_to_row(event, &mut new_byte_lookup_events));
+        }
+
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(&mut rows, || [F::ZERO; NUM_RLP_DECODE_ITEM_COLS], log_rows);
+
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect(),
+            NUM_RLP_DECODE_ITEM_COLS,
+        )
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(SyscallCode::RLP_DECODE_ITEM)
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::RlpDecodeItem(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_row(event, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record
+                .get_precompile_events(SyscallCode::RLP_DECODE_ITEM)
+                .is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> RlpDecodeItemChip<F> {
+    fn event_to_row(
+        &self,
+        event: &RlpDecodeItemEvent,
+        blu: &mut impl ByteRecordBehavior,
+    ) -> [F; NUM_RLP_DECODE_ITEM_COLS] {
+        let mut row = [F::ZERO; NUM_RLP_DECODE_ITEM_COLS];
+        let cols: &mut RlpDecodeItemCols<F> = row.as_mut_slice().borrow_mut();
+
+        cols.is_real = F::ONE;
+        cols.chunk = F::from_canonical_u32(event.chunk);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.input_ptr = Word::from(event.input_ptr);
+        cols.output_ptr = F::from_canonical_u32(event.output_ptr);
+
+        let ptr_low = event.input_ptr.to_le_bytes()[0];
+        let offset = (ptr_low % 4) as usize;
+        cols.input_ptr_low_div4 = F::from_canonical_u8(ptr_low / 4);
+        cols.offset[offset] = F::ONE;
+
+        for (word_cols, record) in cols.words.iter_mut().zip(event.word_read_records) {
+            word_cols.populate(record, blu);
+        }
+
+        let bytes = event
+            .words
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        let header = rlp_decode_header(&bytes[offset..offset + RLP_HEADER_MAX_LEN])
+            .expect("the syscall only records decodable headers");
+        cols.kind[header.kind] = F::ONE;
+        let rel = bytes[offset] - RLP_KIND_PREFIX_START[header.kind];
+        cols.prefix_rel = F::from_canonical_u8(rel);
+        if header.kind == RLP_LONG_STRING || header.kind == RLP_LONG_LIST {
+            cols.len_of_len[rel as usize] = F::ONE;
+        }
+
+        blu.add_u8_range_checks([ptr_low / 4, rel, RLP_KIND_PREFIX_SPAN[header.kind] - rel]);
+        blu.add_u8_range_checks(event.input_ptr.to_le_bytes());
+
+        for (output_cols, record) in cols.output.iter_mut().zip(event.output_write_records) {
+            output_cols.populate(record, blu);
+        }
+
+        row
+    }
+}
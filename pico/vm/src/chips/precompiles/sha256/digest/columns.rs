@@ -0,0 +1,168 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::{MemoryReadCols, MemoryWriteCols},
+        gadgets::{
+            add::AddGadget, add4::Add4Operation, add5::Add5Operation, and::AndOperation,
+            fixed_rotate_right::FixedRotateRightOperation,
+            fixed_shift_right::FixedShiftRightOperation, not::NotOperation, xor::XorOperation,
+        },
+    },
+    compiler::word::Word,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_SHA256_DIGEST_COLS: usize = size_of::<Sha256DigestCols<u8>>();
+pub const NUM_MULTI_HASH_COLS: usize = size_of::<MultiHashCols<u8>>();
+
+/// A set of columns needed to compute one round of the SHA-256 digest of a padded message.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Sha256DigestCols<T> {
+    /// Inputs.
+    pub chunk: T,
+    pub clk: T,
+    pub input_ptr: T,
+    pub output_ptr: T,
+
+    /// Which of the 64 rounds of the current block this row computes.
+    pub round: [T; 64],
+    pub is_first_block: T,
+    pub is_last_block: T,
+
+    /// The message length in bytes, read from `input_ptr` on the first row.
+    pub len: Word<T>,
+    pub len_access: MemoryReadCols<T>,
+
+    /// The byte offset into the message of the word scheduled in this row, during the first 16
+    /// rounds of a block.
+    pub offset: T,
+    /// The memory read of the message word at `offset`.
+    pub message_access: MemoryReadCols<T>,
+
+    /// The scheduled word is four message bytes.
+    pub is_message: T,
+    /// The scheduled word holds the end of the message and the `0x80` padding byte.
+    pub is_boundary: T,
+    /// One-hot number of message bytes in the boundary word.
+    pub boundary_len: [T; 4],
+    /// The scheduled word is zero padding.
+    pub is_zero_pad: T,
+    /// The scheduled word is the high or low half of the message bit length.
+    pub is_len_hi: T,
+    pub is_len_lo: T,
+    /// Whether the boundary word has been scheduled by this row.
+    pub message_ended: T,
+
+    /// The previous 16 words of the message schedule, `w[i-16..i]`.
+    pub w_window: [Word<T>; 16],
+    /// The scheduled word `w[i]`.
+    pub w: Word<T>,
+
+    pub is_extend: T,
+    pub w_i_minus_15_rr_7: FixedRotateRightOperation<T>,
+    pub w_i_minus_15_rr_18: FixedRotateRightOperation<T>,
+    pub w_i_minus_15_rs_3: FixedShiftRightOperation<T>,
+    pub sigma0_intermediate: XorOperation<T>,
+    /// `s0 := (w[i-15] rightrotate 7) xor (w[i-15] rightrotate 18) xor (w[i-15] rightshift 3)`.
+    pub sigma0: XorOperation<T>,
+    pub w_i_minus_2_rr_17: FixedRotateRightOperation<T>,
+    pub w_i_minus_2_rr_19: FixedRotateRightOperation<T>,
+    pub w_i_minus_2_rs_10: FixedShiftRightOperation<T>,
+    pub sigma1_intermediate: XorOperation<T>,
+    /// `s1 := (w[i-2] rightrotate 17) xor (w[i-2] rightrotate 19) xor (w[i-2] rightshift 10)`.
+    pub sigma1: XorOperation<T>,
+    /// `w[i] := w[i-16] + s0 + w[i-7] + s1`.
+    pub extended: Add4Operation<T>,
+
+    /// The hash state at the start of the current block.
+    pub h_in: [Word<T>; 8],
+
+    pub a: Word<T>,
+    pub b: Word<T>,
+    pub c: Word<T>,
+    pub d: Word<T>,
+    pub e: Word<T>,
+    pub f: Word<T>,
+    pub g: Word<T>,
+    pub h: Word<T>,
+
+    /// Current value of K[i].
+    pub k: Word<T>,
+
+    pub e_rr_6: FixedRotateRightOperation<T>,
+    pub e_rr_11: FixedRotateRightOperation<T>,
+    pub e_rr_25: FixedRotateRightOperation<T>,
+    pub s1_intermediate: XorOperation<T>,
+    /// `S1 := (e rightrotate 6) xor (e rightrotate 11) xor (e rightrotate 25)`.
+    pub s1: XorOperation<T>,
+
+    pub e_and_f: AndOperation<T>,
+    pub e_not: NotOperation<T>,
+    pub e_not_and_g: AndOperation<T>,
+    /// `ch := (e and f) xor ((not e) and g)`.
+    pub ch: XorOperation<T>,
+
+    /// `temp1 := h + S1 + ch + k[i] + w[i]`.
+    pub temp1: Add5Operation<T>,
+
+    pub a_rr_2: FixedRotateRightOperation<T>,
+    pub a_rr_13: FixedRotateRightOperation<T>,
+    pub a_rr_22: FixedRotateRightOperation<T>,
+    pub s0_intermediate: XorOperation<T>,
+    /// `S0 := (a rightrotate 2) xor (a rightrotate 13) xor (a rightrotate 22)`.
+    pub s0: XorOperation<T>,
+
+    pub a_and_b: AndOperation<T>,
+    pub a_and_c: AndOperation<T>,
+    pub b_and_c: AndOperation<T>,
+    pub maj_intermediate: XorOperation<T>,
+    /// `maj := (a and b) xor (a and c) xor (b and c)`.
+    pub maj: XorOperation<T>,
+
+    /// `temp2 := S0 + maj`.
+    pub temp2: AddGadget<T>,
+
+    /// The next value of `e` is `d + temp1`.
+    pub d_add_temp1: AddGadget<T>,
+    /// The next value of `a` is `temp1 + temp2`.
+    pub temp1_add_temp2: AddGadget<T>,
+
+    /// On the last round of a block, the hash state after the block.
+    pub h_out: [AddGadget<T>; 8],
+
+    /// The digest writes on the last round of the last block.
+    pub output_access: [MemoryWriteCols<T>; 8],
+
+    /// The last round of a block.
+    pub is_block_end: T,
+    /// The last round of the last block.
+    pub is_output: T,
+    /// The first round of the first block.
+    pub start: T,
+
+    pub is_real: T,
+}
+
+/// The columns of a `MULTI_HASH` round: the round of one of the digests of the batch, together
+/// with the position of that digest in the batch.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MultiHashCols<T> {
+    pub digest: Sha256DigestCols<T>,
+
+    /// This digest is the first or the last of its batch.
+    pub is_batch_first: T,
+    pub is_batch_last: T,
+    /// The first round of the first digest of a batch.
+    pub batch_start: T,
+    /// The pointer to the descriptor block, the address of the input and output pointers of this
+    /// digest in it, and the number of digests left in the batch.
+    pub descriptor_ptr: T,
+    pub descriptor_addr: T,
+    pub batch_remaining: T,
+    /// The read of the number of digests on the batch start, and the reads of the pointers of
+    /// each digest on its start.
+    pub num_access: MemoryReadCols<T>,
+    pub descriptor_access: [MemoryReadCols<T>; 2],
+}
@@ -0,0 +1,743 @@
+use core::borrow::Borrow;
+
+use super::{
+    columns::{MultiHashCols, Sha256DigestCols},
+    Sha256DigestChip,
+};
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        gadgets::{
+            add::AddGadget, add4::Add4Operation, add5::Add5Operation, and::AndOperation,
+            fixed_rotate_right::FixedRotateRightOperation,
+            fixed_shift_right::FixedShiftRightOperation, not::NotOperation, xor::XorOperation,
+        },
+        precompiles::sha256::compress::SHA_COMPRESS_K,
+    },
+    compiler::word::Word,
+    emulator::riscv::syscalls::precompiles::sha256::{
+        digest::SHA256_IV, multi_hash::MULTI_HASH_SHA256,
+    },
+    machine::builder::{
+        ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, ChipRangeBuilder, ChipWordBuilder,
+        RiscVMemoryBuilder,
+    },
+};
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for Sha256DigestChip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let syscall_id = CB::F::from_canonical_u32(self.syscall_code().syscall_id());
+
+        if self.batched {
+            let local: &MultiHashCols<CB::Var> = (*local).borrow();
+            let next: &MultiHashCols<CB::Var> = (*next).borrow();
+
+            self.eval_digest(builder, &local.digest, &next.digest);
+
+            self.eval_batch(builder, local, next);
+
+            builder.looked_syscall(
+                local.digest.clk,
+                syscall_id,
+                CB::F::from_canonical_u32(MULTI_HASH_SHA256),
+                local.descriptor_ptr,
+                local.batch_start,
+            );
+        } else {
+            let local: &Sha256DigestCols<CB::Var> = (*local).borrow();
+            let next: &Sha256DigestCols<CB::Var> = (*next).borrow();
+
+            self.eval_digest(builder, local, next);
+
+            builder.looked_syscall(
+                local.clk,
+                syscall_id,
+                local.input_ptr,
+                local.output_ptr,
+                local.start,
+            );
+        }
+    }
+}
+
+impl<F: PrimeField32> Sha256DigestChip<F> {
+    fn eval_digest<CB: ChipBuilder<F>>(
+        &self,
+        builder: &mut CB,
+        local: &Sha256DigestCols<CB::Var>,
+        next: &Sha256DigestCols<CB::Var>,
+    ) {
+        self.eval_control_flow_flags(builder, local, next);
+
+        self.eval_message_schedule(builder, local, next);
+
+        self.eval_compression_ops(builder, local, next);
+
+        self.eval_finalize_ops(builder, local, next);
+    }
+
+    fn eval_control_flow_flags<CB: ChipBuilder<F>>(
+        &self,
+        builder: &mut CB,
+        local: &Sha256DigestCols<CB::Var>,
+        next: &Sha256DigestCols<CB::Var>,
+    ) {
+        // Exactly one round flag is set, and the rounds cycle through the 64 rounds of a block.
+        let mut round_sum = CB::Expr::ZERO;
+        for i in 0..64 {
+            builder.assert_bool(local.round[i]);
+            round_sum += local.round[i].into();
+        }
+        builder.assert_one(round_sum);
+        builder.when_first_row().assert_one(local.round[0]);
+        for i in 0..64 {
+            builder
+                .when_transition()
+                .when(local.round[i])
+                .assert_one(next.round[(i + 1) % 64]);
+        }
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first_block);
+        builder.assert_bool(local.is_last_block);
+
+        builder.assert_eq(local.is_block_end, local.round[63] * local.is_real);
+        builder.assert_eq(local.is_output, local.is_block_end * local.is_last_block);
+        builder.assert_eq(
+            local.start,
+            local.is_real * local.round[0] * local.is_first_block,
+        );
+
+        // The block flags are fixed within a block. The block after a last block is the first
+        // block of the next event.
+        builder.when_first_row().assert_one(local.is_first_block);
+        builder
+            .when_transition()
+            .when_not(local.round[63])
+            .assert_eq(local.is_first_block, next.is_first_block);
+        builder
+            .when_transition()
+            .when_not(local.round[63])
+            .assert_eq(local.is_last_block, next.is_last_block);
+        builder
+            .when_transition()
+            .when(local.round[63])
+            .assert_eq(local.is_last_block, next.is_first_block);
+
+        // Rows of an event are contiguous and the table ends on the last row of an event.
+        let in_event = local.is_real - local.is_output;
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_one(next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+        builder
+            .when_last_row()
+            .assert_eq(local.is_real, local.is_output);
+
+        // The inputs stay the same for all rows of an event.
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(local.chunk, next.chunk);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(local.clk, next.clk);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(local.input_ptr, next.input_ptr);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(local.output_ptr, next.output_ptr);
+        builder
+            .when_transition()
+            .when(in_event)
+            .assert_word_eq(local.len, next.len);
+
+        // The length is read on the first row, and is below 2^24 bytes.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.input_ptr,
+            &local.len_access,
+            local.start,
+        );
+        builder
+            .when(local.start)
+            .assert_word_eq(local.len, *local.len_access.value());
+        builder.when(local.start).assert_zero(local.len[3]);
+        builder.slice_range_check_u8(&local.len.0, local.start);
+    }
+
+    /// Chains the digests of a `MULTI_HASH` batch and takes their pointers from the descriptor
+    /// block.
+    fn eval_batch<CB: ChipBuilder<F>>(
+        &self,
+        builder: &mut CB,
+        local: &MultiHashCols<CB::Var>,
+        next: &MultiHashCols<CB::Var>,
+    ) {
+        builder.assert_bool(local.is_batch_first);
+        builder.assert_bool(local.is_batch_last);
+        builder.assert_eq(local.batch_start, local.digest.start * local.is_batch_first);
+
+        let in_event = local.digest.is_real - local.digest.is_output;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(in_event);
+            builder.assert_eq(next.is_batch_first, local.is_batch_first);
+            builder.assert_eq(next.is_batch_last, local.is_batch_last);
+            builder.assert_eq(next.descriptor_ptr, local.descriptor_ptr);
+            builder.assert_eq(next.descriptor_addr, local.descriptor_addr);
+            builder.assert_eq(next.batch_remaining, local.batch_remaining);
+        }
+
+        // The batch starts by reading the number of digests, followed by their pointers.
+        builder.eval_memory_access(
+            local.digest.chunk,
+            local.digest.clk,
+            local.descriptor_ptr,
+            &local.num_access,
+            local.batch_start,
+        );
+        {
+            let mut builder = builder.when(local.batch_start);
+            builder.assert_eq(
+                local.batch_remaining,
+                local.num_access.value().reduce::<CB>(),
+            );
+            builder.assert_eq(
+                local.descriptor_addr,
+                local.descriptor_ptr + CB::F::from_canonical_u32(4),
+            );
+        }
+        builder.eval_memory_access_slice(
+            local.digest.chunk,
+            local.digest.clk,
+            local.descriptor_addr,
+            &local.descriptor_access,
+            local.digest.start,
+        );
+        {
+            let mut builder = builder.when(local.digest.start);
+            builder.assert_eq(
+                local.digest.input_ptr,
+                local.descriptor_access[0].value().reduce::<CB>(),
+            );
+            builder.assert_eq(
+                local.digest.output_ptr,
+                local.descriptor_access[1].value().reduce::<CB>(),
+            );
+        }
+        builder
+            .when(local.is_batch_last)
+            .assert_one(local.batch_remaining);
+
+        // A digest that is not the last of its batch is followed by the next one, and every digest
+        // that does not start a batch follows such a digest.
+        let continues = local.digest.is_output - local.digest.is_output * local.is_batch_last;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(continues.clone());
+            builder.assert_one(next.digest.is_real);
+            builder.assert_zero(next.is_batch_first);
+            builder.assert_eq(next.digest.chunk, local.digest.chunk);
+            builder.assert_eq(next.digest.clk, local.digest.clk);
+            builder.assert_eq(next.descriptor_ptr, local.descriptor_ptr);
+            builder.assert_eq(
+                next.descriptor_addr,
+                local.descriptor_addr + CB::F::from_canonical_u32(8),
+            );
+            builder.assert_eq(next.batch_remaining, local.batch_remaining - CB::F::ONE);
+        }
+        builder
+            .when_transition()
+            .assert_eq(next.digest.start - next.batch_start, continues.clone());
+        builder
+            .when_first_row()
+            .assert_eq(local.digest.start, local.batch_start);
+        builder.when_last_row().assert_zero(continues);
+    }
+
+    /// Constrains the scheduled word of each round: the padded message in the first 16 rounds of
+    /// a block and the extended schedule in the rest.
+    fn eval_message_schedule<CB: ChipBuilder<F>>(
+        &self,
+        builder: &mut CB,
+        local: &Sha256DigestCols<CB::Var>,
+        next: &Sha256DigestCols<CB::Var>,
+    ) {
+        let in_event = local.is_real - local.is_output;
+        let is_input_round = local.round[..16]
+            .iter()
+            .fold(CB::Expr::ZERO, |acc, &round| acc + round);
+        let is_extend_round = local.round[16..]
+            .iter()
+            .fold(CB::Expr::ZERO, |acc, &round| acc + round);
+        let len = local.len.reduce::<CB>();
+
+        builder.assert_bool(local.is_message);
+        builder.assert_bool(local.is_boundary);
+        builder.assert_bool(local.is_zero_pad);
+        builder.assert_bool(local.is_len_hi);
+        builder.assert_bool(local.is_len_lo);
+        builder.assert_bool(local.message_ended);
+        let mut boundary_len_sum = CB::Expr::ZERO;
+        for i in 0..4 {
+            builder.assert_bool(local.boundary_len[i]);
+            boundary_len_sum += local.boundary_len[i].into();
+        }
+        builder.assert_eq(boundary_len_sum, local.is_boundary);
+
+        // Each input round schedules exactly one kind of word, and the length occupies the last
+        // two words of the last block.
+        builder.assert_eq(
+            local.is_message
+                + local.is_boundary
+                + local.is_zero_pad
+                + local.is_len_hi
+                + local.is_len_lo,
+            is_input_round.clone() * local.is_real,
+        );
+        builder.assert_eq(
+            local.is_len_hi,
+            local.round[14] * local.is_last_block * local.is_real,
+        );
+        builder.assert_eq(
+            local.is_len_lo,
+            local.round[15] * local.is_last_block * local.is_real,
+        );
+        builder.assert_eq(local.is_extend, is_extend_round * local.is_real);
+
+        // The message is followed by exactly one boundary word, then by padding.
+        builder
+            .when(local.start)
+            .assert_eq(local.message_ended, local.is_boundary);
+        builder
+            .when_transition()
+            .when(in_event.clone())
+            .assert_eq(next.message_ended, local.message_ended + next.is_boundary);
+        builder
+            .when(local.is_message)
+            .assert_zero(local.message_ended);
+        builder
+            .when(local.is_zero_pad + local.is_len_hi + local.is_len_lo)
+            .assert_one(local.message_ended);
+
+        // Only the last block may hold the boundary word before its length words, so that no
+        // block of zero padding is skipped or added.
+        builder.assert_zero(
+            local.round[13] * (local.is_real - local.is_last_block) * local.message_ended,
+        );
+
+        // The offset advances by a word in every input round.
+        builder.when(local.start).assert_zero(local.offset);
+        builder.when_transition().when(in_event.clone()).assert_eq(
+            next.offset,
+            local.offset + is_input_round * CB::F::from_canonical_u32(4),
+        );
+
+        // The boundary word is the one where the message ends.
+        let mut boundary_bytes = CB::Expr::ZERO;
+        for i in 1..4 {
+            boundary_bytes += local.boundary_len[i] * CB::F::from_canonical_usize(i);
+        }
+        builder
+            .when(local.is_boundary)
+            .assert_eq(len.clone(), local.offset + boundary_bytes);
+
+        // Read the message words that hold at least one message byte.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk,
+            local.input_ptr + CB::F::from_canonical_u32(4) + local.offset,
+            &local.message_access,
+            local.is_message + local.is_boundary - local.boundary_len[0],
+        );
+
+        // SHA-256 words are big-endian, so the first byte in memory is the most significant.
+        let message = local.message_access.value();
+        for i in 0..4 {
+            builder
+                .when(local.is_message)
+                .assert_eq(local.w[3 - i], message[i]);
+
+            let mut is_message_byte = CB::Expr::ZERO;
+            for j in (i + 1)..4 {
+                is_message_byte += local.boundary_len[j].into();
+            }
+            builder.when(local.is_boundary).assert_eq(
+                local.w[3 - i],
+                is_message_byte * message[i]
+                    + local.boundary_len[i] * CB::F::from_canonical_u32(0x80),
+            );
+        }
+        builder
+            .when(local.is_zero_pad + local.is_len_hi)
+            .assert_word_zero(local.w);
+
+        // The low length word is the bit length, which stays below 2^27 since the length is below
+        // 2^24.
+        builder
+            .when(local.is_len_lo)
+            .assert_eq(local.w.reduce::<CB>(), len * CB::F::from_canonical_u32(8));
+        builder.slice_range_check_u8(
+            &[local.w[3] * CB::F::from_canonical_u32(32)],
+            local.is_len_lo,
+        );
+
+        // Shift the schedule window within a block.
+        let in_block = local.is_real - local.is_block_end;
+        for i in 0..15 {
+            builder
+                .when_transition()
+                .when(in_block.clone())
+                .assert_word_eq(next.w_window[i], local.w_window[i + 1]);
+        }
+        builder
+            .when_transition()
+            .when(in_block)
+            .assert_word_eq(next.w_window[15], local.w);
+
+        // s0 := (w[i-15] rightrotate 7) xor (w[i-15] rightrotate 18) xor (w[i-15] rightshift 3).
+        let w_i_minus_15 = local.w_window[1];
+        FixedRotateRightOperation::<CB::F>::eval(
+            builder,
+            w_i_minus_15,
+            7,
+            local.w_i_minus_15_rr_7,
+            local.is_extend,
+        );
+        FixedRotateRightOperation::<CB::F>::eval(
+            builder,
+            w_i_minus_15,
+            18,
+            local.w_i_minus_15_rr_18,
+            local.is_extend,
+        );
+        FixedShiftRightOperation::<CB::F>::eval(
+            builder,
+            w_i_minus_15,
+            3,
+            local.w_i_minus_15_rs_3,
+            local.is_extend,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.w_i_minus_15_rr_7.value,
+            local.w_i_minus_15_rr_18.value,
+            local.sigma0_intermediate,
+            local.is_extend,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.sigma0_intermediate.value,
+            local.w_i_minus_15_rs_3.value,
+            local.sigma0,
+            local.is_extend,
+        );
+
+        // s1 := (w[i-2] rightrotate 17) xor (w[i-2] rightrotate 19) xor (w[i-2] rightshift 10).
+        let w_i_minus_2 = local.w_window[14];
+        FixedRotateRightOperation::<CB::F>::eval(
+            builder,
+            w_i_minus_2,
+            17,
+            local.w_i_minus_2_rr_17,
+            local.is_extend,
+        );
+        FixedRotateRightOperation::<CB::F>::eval(
+            builder,
+            w_i_minus_2,
+            19,
+            local.w_i_minus_2_rr_19,
+            local.is_extend,
+        );
+        FixedShiftRightOperation::<CB::F>::eval(
+            builder,
+            w_i_minus_2,
+            10,
+            local.w_i_minus_2_rs_10,
+            local.is_extend,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.w_i_minus_2_rr_17.value,
+            local.w_i_minus_2_rr_19.value,
+            local.sigma1_intermediate,
+            local.is_extend,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.sigma1_intermediate.value,
+            local.w_i_minus_2_rs_10.value,
+            local.sigma1,
+            local.is_extend,
+        );
+
+        // w[i] := w[i-16] + s0 + w[i-7] + s1.
+        Add4Operation::<CB::F>::eval(
+            builder,
+            local.w_window[0],
+            local.sigma0.value,
+            local.w_window[9],
+            local.sigma1.value,
+            local.is_extend,
+            local.extended,
+        );
+        builder
+            .when(local.is_extend)
+            .assert_word_eq(local.w, local.extended.value);
+    }
+
+    fn eval_compression_ops<CB: ChipBuilder<F>>(
+        &self,
+        builder: &mut CB,
+        local: &Sha256DigestCols<CB::Var>,
+        next: &Sha256DigestCols<CB::Var>,
+    ) {
+        // Constrain k column which loops over 64 constant values.
+        for i in 0..64 {
+            builder
+                .when(local.round[i] * local.is_real)
+                .assert_all_eq(local.k, Word::<CB::F>::from(SHA_COMPRESS_K[i]));
+        }
+
+        // Each block starts from the hash state of the previous block.
+        let vars = [
+            local.a, local.b, local.c, local.d, local.e, local.f, local.g, local.h,
+        ];
+        for (var, h_in) in vars.iter().zip(local.h_in.iter()) {
+            builder
+                .when(local.round[0] * local.is_real)
+                .assert_word_eq(*var, *h_in);
+        }
+
+        // S1 := (e rightrotate 6) xor (e rightrotate 11) xor (e rightrotate 25).
+        FixedRotateRightOperation::<CB::F>::eval(builder, local.e, 6, local.e_rr_6, local.is_real);
+        FixedRotateRightOperation::<CB::F>::eval(
+            builder,
+            local.e,
+            11,
+            local.e_rr_11,
+            local.is_real,
+        );
+        FixedRotateRightOperation::<CB::F>::eval(
+            builder,
+            local.e,
+            25,
+            local.e_rr_25,
+            local.is_real,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.e_rr_6.value,
+            local.e_rr_11.value,
+            local.s1_intermediate,
+            local.is_real,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.s1_intermediate.value,
+            local.e_rr_25.value,
+            local.s1,
+            local.is_real,
+        );
+
+        // ch := (e and f) xor ((not e) and g).
+        AndOperation::<CB::F>::eval(builder, local.e, local.f, local.e_and_f, local.is_real);
+        NotOperation::<CB::F>::eval(builder, local.e, local.e_not, local.is_real);
+        AndOperation::<CB::F>::eval(
+            builder,
+            local.e_not.value,
+            local.g,
+            local.e_not_and_g,
+            local.is_real,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.e_and_f.value,
+            local.e_not_and_g.value,
+            local.ch,
+            local.is_real,
+        );
+
+        // temp1 := h + S1 + ch + k[i] + w[i].
+        Add5Operation::<CB::F>::eval(
+            builder,
+            &[local.h, local.s1.value, local.ch.value, local.k, local.w],
+            local.is_real,
+            local.temp1,
+        );
+
+        // S0 := (a rightrotate 2) xor (a rightrotate 13) xor (a rightrotate 22).
+        FixedRotateRightOperation::<CB::F>::eval(builder, local.a, 2, local.a_rr_2, local.is_real);
+        FixedRotateRightOperation::<CB::F>::eval(
+            builder,
+            local.a,
+            13,
+            local.a_rr_13,
+            local.is_real,
+        );
+        FixedRotateRightOperation::<CB::F>::eval(
+            builder,
+            local.a,
+            22,
+            local.a_rr_22,
+            local.is_real,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.a_rr_2.value,
+            local.a_rr_13.value,
+            local.s0_intermediate,
+            local.is_real,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.s0_intermediate.value,
+            local.a_rr_22.value,
+            local.s0,
+            local.is_real,
+        );
+
+        // maj := (a and b) xor (a and c) xor (b and c).
+        AndOperation::<CB::F>::eval(builder, local.a, local.b, local.a_and_b, local.is_real);
+        AndOperation::<CB::F>::eval(builder, local.a, local.c, local.a_and_c, local.is_real);
+        AndOperation::<CB::F>::eval(builder, local.b, local.c, local.b_and_c, local.is_real);
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.a_and_b.value,
+            local.a_and_c.value,
+            local.maj_intermediate,
+            local.is_real,
+        );
+        XorOperation::<CB::F>::eval(
+            builder,
+            local.maj_intermediate.value,
+            local.b_and_c.value,
+            local.maj,
+            local.is_real,
+        );
+
+        // temp2 := S0 + maj.
+        AddGadget::<CB::F>::eval(
+            builder,
+            local.s0.value,
+            local.maj.value,
+            local.temp2,
+            local.is_real.into(),
+        );
+        AddGadget::<CB::F>::eval(
+            builder,
+            local.d,
+            local.temp1.value,
+            local.d_add_temp1,
+            local.is_real.into(),
+        );
+        AddGadget::<CB::F>::eval(
+            builder,
+            local.temp1.value,
+            local.temp2.value,
+            local.temp1_add_temp2,
+            local.is_real.into(),
+        );
+
+        // Move to the next round within a block.
+        let in_block = local.is_real - local.is_block_end;
+        let next_vars = [
+            (next.a, local.temp1_add_temp2.value),
+            (next.b, local.a),
+            (next.c, local.b),
+            (next.d, local.c),
+            (next.e, local.d_add_temp1.value),
+            (next.f, local.e),
+            (next.g, local.f),
+            (next.h, local.g),
+        ];
+        for (next_var, value) in next_vars {
+            builder
+                .when_transition()
+                .when(in_block.clone())
+                .assert_word_eq(next_var, value);
+        }
+    }
+
+    fn eval_finalize_ops<CB: ChipBuilder<F>>(
+        &self,
+        builder: &mut CB,
+        local: &Sha256DigestCols<CB::Var>,
+        next: &Sha256DigestCols<CB::Var>,
+    ) {
+        // The first block starts from the initial hash value, later blocks from the state after
+        // the previous block.
+        for (h_in, iv) in local.h_in.iter().zip(SHA256_IV) {
+            builder
+                .when(local.start)
+                .assert_all_eq(*h_in, Word::<CB::F>::from(iv));
+        }
+        let in_block = local.is_real - local.is_block_end;
+        for i in 0..8 {
+            builder
+                .when_transition()
+                .when(in_block.clone())
+                .assert_word_eq(next.h_in[i], local.h_in[i]);
+            builder
+                .when_transition()
+                .when(local.is_block_end - local.is_output)
+                .assert_word_eq(next.h_in[i], local.h_out[i].value);
+        }
+
+        // h_out := h_in + (a, b, ..., h) after the last round.
+        let block_result = [
+            local.temp1_add_temp2.value,
+            local.a,
+            local.b,
+            local.c,
+            local.d_add_temp1.value,
+            local.e,
+            local.f,
+            local.g,
+        ];
+        for i in 0..8 {
+            AddGadget::<CB::F>::eval(
+                builder,
+                local.h_in[i],
+                block_result[i],
+                local.h_out[i],
+                local.is_block_end.into(),
+            );
+        }
+
+        // Write the digest with its bytes in big-endian order.
+        builder.eval_memory_access_slice(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.output_ptr,
+            &local.output_access,
+            local.is_output,
+        );
+        for i in 0..8 {
+            let value = local.output_access[i].value();
+            for j in 0..4 {
+                builder
+                    .when(local.is_output)
+                    .assert_eq(value[j], local.h_out[i].value[3 - j]);
+            }
+        }
+    }
+}
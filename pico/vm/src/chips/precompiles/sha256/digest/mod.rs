@@ -0,0 +1,93 @@
+use crate::emulator::riscv::syscalls::SyscallCode;
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod trace;
+
+/// Implements the one-shot SHA-256 digest of a length-prefixed buffer. The inputs to the syscall
+/// are a pointer to the length word, which is followed by the message, and a pointer to the 8
+/// word digest.
+///
+/// In the AIR, each 64-byte block of the padded message takes up 64 rows, one per round. The
+/// first 16 rows of a block read the message word (or build the padding word) scheduled for their
+/// round, the remaining 48 rows extend the schedule from the previous 16 words carried along in
+/// the row. The last row of a block adds the block result into the hash state, and the last row of
+/// the last block writes the digest to memory.
+///
+/// The same AIR also proves `MULTI_HASH` calls, see [`Sha256DigestChip::multi_hash`].
+#[derive(Default)]
+pub struct Sha256DigestChip<F> {
+    batched: bool,
+    _marker: PhantomData<F>,
+}
+
+impl<F> Sha256DigestChip<F> {
+    /// The chip for `MULTI_HASH` batches. The digests of a batch take up contiguous rows, each
+    /// laid out like a single `SHA256` call followed by the batch columns, and the batch is looked
+    /// up as one syscall on its first row. The inputs and outputs of each digest are read from the
+    /// descriptor block instead of the syscall arguments, and all digests share the clock of the
+    /// call.
+    pub const fn multi_hash() -> Self {
+        Self {
+            batched: true,
+            _marker: PhantomData,
+        }
+    }
+
+    const fn syscall_code(&self) -> SyscallCode {
+        if self.batched {
+            SyscallCode::MULTI_HASH
+        } else {
+            SyscallCode::SHA256
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        precompiles::sha256::multi_hash::MULTI_HASH_SHA256,
+        test_utils::{bytes_to_words, prove_program, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+
+    const INPUT_PTR: u32 = 0x2000;
+    const OUTPUT_PTR: u32 = 0x3000;
+    const DESCRIPTOR_PTR: u32 = 0x4000;
+
+    #[test]
+    fn test_sha256_digest_prove() {
+        // 56 bytes, whose padding spills over into a second block.
+        let message = [b'x'; 56];
+        let mut memory_image = bytes_to_words(INPUT_PTR + 4, &message).collect::<BTreeMap<_, _>>();
+        memory_image.insert(INPUT_PTR, message.len() as u32);
+        let program = syscall_program(
+            &[(SyscallCode::SHA256, INPUT_PTR, OUTPUT_PTR)],
+            memory_image,
+        );
+        prove_program(program).unwrap();
+    }
+
+    #[test]
+    fn test_multi_hash_prove() {
+        let messages: [&[u8]; 2] = [b"abc", &[b'y'; 70]];
+        let mut memory_image = BTreeMap::from([(DESCRIPTOR_PTR, messages.len() as u32)]);
+        for (i, message) in messages.iter().enumerate() {
+            let input_ptr = INPUT_PTR + 0x100 * i as u32;
+            memory_image.extend(bytes_to_words(input_ptr + 4, message));
+            memory_image.insert(input_ptr, message.len() as u32);
+            memory_image.insert(DESCRIPTOR_PTR + 4 + 8 * i as u32, input_ptr);
+            memory_image.insert(
+                DESCRIPTOR_PTR + 8 + 8 * i as u32,
+                OUTPUT_PTR + 32 * i as u32,
+            );
+        }
+        let program = syscall_program(
+            &[(SyscallCode::MULTI_HASH, MULTI_HASH_SHA256, DESCRIPTOR_PTR)],
+            memory_image,
+        );
+        prove_program(program).unwrap();
+    }
+}
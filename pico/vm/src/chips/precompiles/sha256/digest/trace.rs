@@ -0,0 +1,353 @@
+use std::borrow::{Borrow, BorrowMut};
+
+use super::{
+    columns::{MultiHashCols, Sha256DigestCols, NUM_MULTI_HASH_COLS, NUM_SHA256_DIGEST_COLS},
+    Sha256DigestChip,
+};
+use crate::{
+    chips::{
+        chips::byte::event::ByteRecordBehavior, precompiles::sha256::compress::SHA_COMPRESS_K,
+        utils::pad_rows_fixed,
+    },
+    compiler::{riscv::program::Program, word::Word},
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::precompiles::{
+            sha256::digest::{sha256_padded_words, SHA256_IV},
+            MultiHashEvent, PrecompileEvent, Sha256DigestEvent,
+        },
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+
+impl<F: PrimeField32> BaseAir<F> for Sha256DigestChip<F> {
+    fn width(&self) -> usize {
+        if self.batched {
+            NUM_MULTI_HASH_COLS
+        } else {
+            NUM_SHA256_DIGEST_COLS
+        }
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for Sha256DigestChip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        if self.batched {
+            "MultiHash".to_string()
+        } else {
+            "Sha256Digest".to_string()
+        }
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(self.syscall_code());
+        let log_rows = input.shape_chip_size(&self.name());
+        if self.batched {
+            let mut rows = Some(Vec::new());
+            for (_, event) in events {
+                let PrecompileEvent::MultiHash(event) = event else {
+                    unreachable!()
+                };
+                self.batch_to_rows(event, &mut rows, &mut Vec::new());
+            }
+            Self::padded_trace(rows.unwrap(), log_rows, |row: &mut MultiHashCols<F>| {
+                &mut row.digest
+            })
+        } else {
+            let mut rows = Some(Vec::new());
+            for (_, event) in events {
+                let PrecompileEvent::Sha256Digest(event) = event else {
+                    unreachable!()
+                };
+                self.event_to_rows(event, &mut rows, &mut Vec::new());
+            }
+            Self::padded_trace(rows.unwrap(), log_rows, |row: &mut Sha256DigestCols<F>| row)
+        }
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = input.get_precompile_events(self.syscall_code());
+
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|(_, event)| match event {
+                    PrecompileEvent::Sha256Digest(event) => {
+                        self.event_to_rows(event, &mut None, &mut blu);
+                    }
+                    PrecompileEvent::MultiHash(event) => {
+                        self.batch_to_rows(event, &mut None, &mut blu);
+                    }
+                    _ => unreachable!(),
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record.get_precompile_events(self.syscall_code()).is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> Sha256DigestChip<F> {
+    /// Pads `rows` to the shape height and flattens them into a trace. Padding rows keep cycling
+    /// through the rounds of single-block dummy events.
+    fn padded_trace<const N: usize, C>(
+        mut rows: Vec<[F; N]>,
+        log_rows: Option<usize>,
+        digest_cols: impl Fn(&mut C) -> &mut Sha256DigestCols<F>,
+    ) -> RowMajorMatrix<F>
+    where
+        [F]: BorrowMut<C>,
+    {
+        let num_real_rows = rows.len();
+        pad_rows_fixed(&mut rows, || [F::ZERO; N], log_rows);
+
+        for (i, row) in rows[num_real_rows..].iter_mut().enumerate() {
+            let cols = digest_cols(row.as_mut_slice().borrow_mut());
+            cols.round[i % 64] = F::ONE;
+            cols.is_first_block = F::ONE;
+            cols.is_last_block = F::ONE;
+        }
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), N)
+    }
+
+    fn batch_to_rows(
+        &self,
+        event: &MultiHashEvent,
+        rows: &mut Option<Vec<[F; NUM_MULTI_HASH_COLS]>>,
+        brb: &mut impl ByteRecordBehavior,
+    ) {
+        let num_digests = event.digests.len();
+        for (i, digest) in event.digests.iter().enumerate() {
+            let mut digest_rows = rows.as_ref().map(|_| Vec::new());
+            self.event_to_rows(digest, &mut digest_rows, brb);
+
+            let mut batch_cols = MultiHashCols::<F>::default();
+            if i == 0 {
+                batch_cols.num_access.populate(event.num_read_record, brb);
+            }
+            for (access, record) in batch_cols
+                .descriptor_access
+                .iter_mut()
+                .zip(event.descriptor_read_records[i])
+            {
+                access.populate(record, brb);
+            }
+
+            let Some(digest_rows) = digest_rows else {
+                continue;
+            };
+            for (j, digest_row) in digest_rows.iter().enumerate() {
+                let mut row = [F::ZERO; NUM_MULTI_HASH_COLS];
+                let cols: &mut MultiHashCols<F> = row.as_mut_slice().borrow_mut();
+                cols.digest = *Borrow::<Sha256DigestCols<F>>::borrow(digest_row.as_slice());
+                cols.is_batch_first = F::from_bool(i == 0);
+                cols.is_batch_last = F::from_bool(i == num_digests - 1);
+                cols.descriptor_ptr = F::from_canonical_u32(event.descriptor_ptr);
+                cols.descriptor_addr =
+                    F::from_canonical_u32(event.descriptor_ptr + 4 + 8 * i as u32);
+                cols.batch_remaining = F::from_canonical_usize(num_digests - i);
+                if j == 0 {
+                    cols.batch_start = F::from_bool(i == 0);
+                    cols.num_access = batch_cols.num_access;
+                    cols.descriptor_access = batch_cols.descriptor_access;
+                }
+                rows.as_mut().unwrap().push(row);
+            }
+        }
+    }
+
+    fn event_to_rows(
+        &self,
+        event: &Sha256DigestEvent,
+        rows: &mut Option<Vec<[F; NUM_SHA256_DIGEST_COLS]>>,
+        brb: &mut impl ByteRecordBehavior,
+    ) {
+        let len = event.len as usize;
+        // The index of the word holding the `0x80` padding byte.
+        let boundary_idx = len / 4;
+        let padded = sha256_padded_words(event.len, &event.message);
+        let num_blocks = padded.len() / 16;
+
+        brb.add_u8_range_checks(event.len.to_le_bytes());
+
+        let mut h_in = SHA256_IV;
+        for (block_idx, block) in padded.chunks_exact(16).enumerate() {
+            let is_first_block = block_idx == 0;
+            let is_last_block = block_idx == num_blocks - 1;
+
+            let mut w = [0u32; 64];
+            w[..16].copy_from_slice(block);
+
+            let mut state = h_in;
+            for i in 0..64 {
+                let mut row = [F::ZERO; NUM_SHA256_DIGEST_COLS];
+                let cols: &mut Sha256DigestCols<F> = row.as_mut_slice().borrow_mut();
+
+                cols.chunk = F::from_canonical_u32(event.chunk);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.input_ptr = F::from_canonical_u32(event.input_ptr);
+                cols.output_ptr = F::from_canonical_u32(event.output_ptr);
+                cols.len = Word::from(event.len);
+
+                cols.round[i] = F::ONE;
+                cols.is_first_block = F::from_bool(is_first_block);
+                cols.is_last_block = F::from_bool(is_last_block);
+
+                let word_idx = 16 * block_idx + i.min(15);
+                cols.offset = F::from_canonical_usize(64 * block_idx + 4 * i.min(16));
+                cols.message_ended = F::from_bool(word_idx >= boundary_idx);
+
+                if is_first_block && i == 0 {
+                    cols.start = F::ONE;
+                    cols.len_access.populate(event.len_read_record, brb);
+                }
+
+                for j in 0..16 {
+                    if i + j >= 16 {
+                        cols.w_window[j] = Word::from(w[i + j - 16]);
+                    }
+                }
+
+                if i < 16 {
+                    if is_last_block && i == 14 {
+                        cols.is_len_hi = F::ONE;
+                    } else if is_last_block && i == 15 {
+                        cols.is_len_lo = F::ONE;
+                        brb.add_u8_range_check(((w[i] >> 24) * 32) as u8, 0);
+                    } else if word_idx < boundary_idx {
+                        cols.is_message = F::ONE;
+                    } else if word_idx == boundary_idx {
+                        cols.is_boundary = F::ONE;
+                        cols.boundary_len[len % 4] = F::ONE;
+                    } else {
+                        cols.is_zero_pad = F::ONE;
+                    }
+
+                    if word_idx < event.message_read_records.len() {
+                        cols.message_access
+                            .populate(event.message_read_records[word_idx], brb);
+                    }
+                } else {
+                    cols.is_extend = F::ONE;
+
+                    let w_i_minus_15 = w[i - 15];
+                    let w_i_minus_15_rr_7 = cols.w_i_minus_15_rr_7.populate(brb, w_i_minus_15, 7);
+                    let w_i_minus_15_rr_18 =
+                        cols.w_i_minus_15_rr_18.populate(brb, w_i_minus_15, 18);
+                    let w_i_minus_15_rs_3 = cols.w_i_minus_15_rs_3.populate(brb, w_i_minus_15, 3);
+                    let sigma0_intermediate = cols.sigma0_intermediate.populate(
+                        brb,
+                        w_i_minus_15_rr_7,
+                        w_i_minus_15_rr_18,
+                    );
+                    let sigma0 = cols
+                        .sigma0
+                        .populate(brb, sigma0_intermediate, w_i_minus_15_rs_3);
+
+                    let w_i_minus_2 = w[i - 2];
+                    let w_i_minus_2_rr_17 = cols.w_i_minus_2_rr_17.populate(brb, w_i_minus_2, 17);
+                    let w_i_minus_2_rr_19 = cols.w_i_minus_2_rr_19.populate(brb, w_i_minus_2, 19);
+                    let w_i_minus_2_rs_10 = cols.w_i_minus_2_rs_10.populate(brb, w_i_minus_2, 10);
+                    let sigma1_intermediate = cols.sigma1_intermediate.populate(
+                        brb,
+                        w_i_minus_2_rr_17,
+                        w_i_minus_2_rr_19,
+                    );
+                    let sigma1 = cols
+                        .sigma1
+                        .populate(brb, sigma1_intermediate, w_i_minus_2_rs_10);
+
+                    w[i] = cols
+                        .extended
+                        .populate(brb, w[i - 16], sigma0, w[i - 7], sigma1);
+                }
+                cols.w = Word::from(w[i]);
+
+                cols.h_in = h_in.map(Word::from);
+                cols.k = Word::from(SHA_COMPRESS_K[i]);
+
+                let [a, b, c, d, e, f, g, h] = state;
+                cols.a = Word::from(a);
+                cols.b = Word::from(b);
+                cols.c = Word::from(c);
+                cols.d = Word::from(d);
+                cols.e = Word::from(e);
+                cols.f = Word::from(f);
+                cols.g = Word::from(g);
+                cols.h = Word::from(h);
+
+                let e_rr_6 = cols.e_rr_6.populate(brb, e, 6);
+                let e_rr_11 = cols.e_rr_11.populate(brb, e, 11);
+                let e_rr_25 = cols.e_rr_25.populate(brb, e, 25);
+                let s1_intermediate = cols.s1_intermediate.populate(brb, e_rr_6, e_rr_11);
+                let s1 = cols.s1.populate(brb, s1_intermediate, e_rr_25);
+
+                let e_and_f = cols.e_and_f.populate(brb, e, f);
+                let e_not = cols.e_not.populate(brb, e);
+                let e_not_and_g = cols.e_not_and_g.populate(brb, e_not, g);
+                let ch = cols.ch.populate(brb, e_and_f, e_not_and_g);
+
+                let temp1 = cols.temp1.populate(brb, h, s1, ch, w[i], SHA_COMPRESS_K[i]);
+
+                let a_rr_2 = cols.a_rr_2.populate(brb, a, 2);
+                let a_rr_13 = cols.a_rr_13.populate(brb, a, 13);
+                let a_rr_22 = cols.a_rr_22.populate(brb, a, 22);
+                let s0_intermediate = cols.s0_intermediate.populate(brb, a_rr_2, a_rr_13);
+                let s0 = cols.s0.populate(brb, s0_intermediate, a_rr_22);
+
+                let a_and_b = cols.a_and_b.populate(brb, a, b);
+                let a_and_c = cols.a_and_c.populate(brb, a, c);
+                let b_and_c = cols.b_and_c.populate(brb, b, c);
+                let maj_intermediate = cols.maj_intermediate.populate(brb, a_and_b, a_and_c);
+                let maj = cols.maj.populate(brb, maj_intermediate, b_and_c);
+
+                let temp2 = cols.temp2.populate(brb, s0, maj);
+
+                let d_add_temp1 = cols.d_add_temp1.populate(brb, d, temp1);
+                let temp1_add_temp2 = cols.temp1_add_temp2.populate(brb, temp1, temp2);
+
+                state = [temp1_add_temp2, a, b, c, d_add_temp1, e, f, g];
+
+                if i == 63 {
+                    cols.is_block_end = F::ONE;
+                    for j in 0..8 {
+                        h_in[j] = cols.h_out[j].populate(brb, h_in[j], state[j]);
+                    }
+
+                    if is_last_block {
+                        cols.is_output = F::ONE;
+                        for j in 0..8 {
+                            cols.output_access[j].populate(event.output_write_records[j], brb);
+                        }
+                    }
+                }
+
+                cols.is_real = F::ONE;
+
+                if rows.as_ref().is_some() {
+                    rows.as_mut().unwrap().push(row);
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod compress;
+pub mod digest;
 pub mod extend;
@@ -0,0 +1,48 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryWriteCols, gadgets::is_zero::IsZeroGadget,
+    },
+    compiler::word::Word,
+};
+use pico_derive::AlignedBorrow;
+use std::mem::size_of;
+
+pub const NUM_SORT_U32_COLS: usize = size_of::<SortU32Cols<u8>>();
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SortU32Cols<T> {
+    /// Inputs, copied over every row of an event.
+    pub chunk: T,
+    pub clk: T,
+    pub ptr: T,
+    pub len: T,
+
+    /// The index of the word written in this row, counting down from `len - 1` to zero.
+    pub index: T,
+
+    /// Checks whether `index` is zero, i.e. whether this is the last word.
+    pub index_is_zero: IsZeroGadget<T>,
+
+    /// Whether the current row is the first of an event and is real.
+    pub is_first: T,
+    /// Whether the current row is the last of an event and is real.
+    pub is_last: T,
+
+    /// The write of the word at `index`. The previous value is the input word and the new value
+    /// is the sorted word.
+    pub access: MemoryWriteCols<T>,
+
+    /// The sorted word at `index + 1`, written by the previous row. On the first row of a
+    /// `FIELD_SORT` event this is the field order, which the largest element must be below.
+    pub above: Word<T>,
+
+    /// One-hot flag of the most significant byte in which the sorted word is smaller than
+    /// `above`. All zero if the two words are equal.
+    pub lt_byte_flags: [T; 4],
+    /// The bytes of the sorted word and of `above` at the flagged position.
+    pub comparison_byte: T,
+    pub above_comparison_byte: T,
+
+    pub is_real: T,
+}
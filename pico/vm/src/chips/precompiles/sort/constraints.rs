@@ -0,0 +1,161 @@
+use crate::{
+    chips::{
+        chips::riscv_memory::read_write::columns::MemoryCols,
+        gadgets::is_zero::IsZeroGadget,
+        precompiles::sort::{columns::SortU32Cols, SortU32Chip},
+    },
+    compiler::{riscv::opcode::ByteOpcode, word::Word},
+    machine::{
+        builder::{
+            ChipBaseBuilder, ChipBuilder, ChipLookupBuilder, ChipWordBuilder, RiscVMemoryBuilder,
+        },
+        lookup::{LookupScope, LookupType, SymbolicLookup},
+    },
+};
+use core::borrow::Borrow;
+use p3_air::{Air, AirBuilder};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::Matrix;
+
+impl<F: PrimeField32, CB: ChipBuilder<F>> Air<CB> for SortU32Chip<F>
+where
+    CB::Var: Sized,
+{
+    fn eval(&self, builder: &mut CB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &SortU32Cols<CB::Var> = (*local).borrow();
+        let next: &SortU32Cols<CB::Var> = (*next).borrow();
+
+        // Control flags.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.when(local.is_first).assert_one(local.is_real);
+        IsZeroGadget::<CB::F>::eval(
+            builder,
+            local.index.into(),
+            local.index_is_zero,
+            local.is_real.into(),
+        );
+        builder.assert_eq(local.is_last, local.is_real * local.index_is_zero.result);
+
+        // The table starts with a new event or with padding.
+        builder
+            .when_first_row()
+            .assert_eq(local.is_first, local.is_real);
+
+        // Within an event, the next row writes the next lower word and compares it against the
+        // word written here.
+        let is_continuing: CB::Expr = local.is_real - local.is_last;
+        {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(is_continuing.clone());
+            builder.assert_one(next.is_real);
+            builder.assert_zero(next.is_first);
+            builder.assert_eq(next.index, local.index - CB::Expr::ONE);
+            builder.assert_word_eq(next.above, *local.access.value());
+            builder.assert_eq(next.chunk, local.chunk);
+            builder.assert_eq(next.clk, local.clk);
+            builder.assert_eq(next.ptr, local.ptr);
+            builder.assert_eq(next.len, local.len);
+        }
+
+        // After the last row of an event, either a new event starts or padding begins.
+        builder
+            .when_transition()
+            .when(local.is_last)
+            .assert_eq(next.is_first, next.is_real);
+        builder
+            .when_transition()
+            .when_not(local.is_real)
+            .assert_zero(next.is_real);
+
+        // Every event must be complete by the end of the table.
+        builder.when_last_row().assert_zero(is_continuing);
+
+        builder
+            .when(local.is_first)
+            .assert_eq(local.index + CB::Expr::ONE, local.len);
+
+        // Write the sorted word in place.
+        builder.eval_memory_access(
+            local.chunk,
+            local.clk + CB::F::ONE,
+            local.ptr + local.index * CB::F::from_canonical_u32(4),
+            &local.access,
+            local.is_real,
+        );
+
+        // Check that the sorted word is at most `above`: the bytes agree down to the flagged one,
+        // where the sorted word is strictly smaller. Without a flag the words are equal.
+        //
+        // For `FIELD_SORT`, the first row compares the largest element against the field order,
+        // and it must be strictly smaller.
+        let is_comparing: CB::Expr = if self.bounded {
+            builder
+                .when(local.is_first)
+                .assert_word_eq(local.above, Word::<CB::F>::from(CB::F::ORDER_U32));
+            local.is_real.into()
+        } else {
+            local.is_real - local.is_first
+        };
+        let value = local.access.value();
+        let mut is_lt = CB::Expr::ZERO;
+        let mut comparison_byte = CB::Expr::ZERO;
+        let mut above_comparison_byte = CB::Expr::ZERO;
+        for i in (0..4).rev() {
+            let flag = local.lt_byte_flags[i];
+            builder.assert_bool(flag);
+            is_lt = is_lt + flag.into();
+            comparison_byte = comparison_byte + value[i] * flag;
+            above_comparison_byte = above_comparison_byte + local.above[i] * flag;
+
+            builder
+                .when(is_comparing.clone())
+                .when_not(is_lt.clone())
+                .assert_eq(value[i], local.above[i]);
+        }
+        builder.assert_bool(is_lt.clone());
+        builder.when_not(is_comparing).assert_zero(is_lt.clone());
+        if self.bounded {
+            builder.when(local.is_first).assert_one(is_lt.clone());
+        }
+        builder.assert_eq(local.comparison_byte, comparison_byte);
+        builder.assert_eq(local.above_comparison_byte, above_comparison_byte);
+        builder.looking_byte(
+            ByteOpcode::LTU.as_field::<CB::F>(),
+            CB::F::ONE,
+            local.comparison_byte,
+            local.above_comparison_byte,
+            is_lt,
+        );
+
+        // The sorted words are a permutation of the input words: every row contributes its input
+        // word and takes back its sorted word, keyed by the clock of the event.
+        let mut input: Vec<CB::Expr> = vec![local.clk.into()];
+        input.extend(local.access.prev_value().0.map(Into::into));
+        builder.looking(SymbolicLookup::new(
+            input,
+            local.is_real.into(),
+            LookupType::Permutation,
+            LookupScope::Regional,
+        ));
+        let mut output: Vec<CB::Expr> = vec![local.clk.into()];
+        output.extend(local.access.value().0.map(Into::into));
+        builder.looked(SymbolicLookup::new(
+            output,
+            local.is_real.into(),
+            LookupType::Permutation,
+            LookupScope::Regional,
+        ));
+
+        // Receive the syscall on the first row of an event.
+        builder.looked_syscall(
+            local.clk,
+            CB::F::from_canonical_u32(self.syscall_code().syscall_id()),
+            local.ptr,
+            local.len,
+            local.is_first,
+        );
+    }
+}
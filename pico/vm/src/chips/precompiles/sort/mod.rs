@@ -0,0 +1,77 @@
+use crate::emulator::riscv::syscalls::SyscallCode;
+use std::marker::PhantomData;
+
+mod columns;
+mod constraints;
+mod traces;
+
+/// Proves `SORT_U32` one array word per row.
+///
+/// An event over `n` words spans `n` consecutive rows, walking the array from the last index down
+/// to zero. Each row writes one sorted word in place and checks that it is at most the word
+/// written by the row before. A permutation argument between the previous and new values of the
+/// writes then shows that the output is a reordering of the input, so the chip does not need to
+/// follow the sorting network the emulator uses.
+///
+/// The same AIR also proves `FIELD_SORT` calls, see [`SortU32Chip::field_sort`].
+#[derive(Default)]
+pub struct SortU32Chip<F> {
+    bounded: bool,
+    _phantom: PhantomData<F>,
+}
+
+impl<F> SortU32Chip<F> {
+    /// The chip for `FIELD_SORT`. The first row of an event also compares the largest element
+    /// against the field order, so every element of both the output and, through the
+    /// permutation, the input is canonical.
+    pub const fn field_sort() -> Self {
+        Self {
+            bounded: true,
+            _phantom: PhantomData,
+        }
+    }
+
+    const fn syscall_code(&self) -> SyscallCode {
+        if self.bounded {
+            SyscallCode::FIELD_SORT
+        } else {
+            SyscallCode::SORT_U32
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{prove_program, syscall_program},
+        SyscallCode,
+    };
+    use p3_baby_bear::BabyBear;
+    use p3_field::PrimeField32;
+
+    const PTR: u32 = 0x2000;
+
+    fn prove_sort(code: SyscallCode, values: &[u32]) {
+        let memory_image = values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| (PTR + 4 * i as u32, value))
+            .collect();
+        let program = syscall_program(&[(code, PTR, values.len() as u32)], memory_image);
+        prove_program(program).unwrap();
+    }
+
+    #[test]
+    fn test_sort_u32_prove() {
+        prove_sort(SyscallCode::SORT_U32, &[9, u32::MAX, 0, 9, 0x8000_0000, 1]);
+    }
+
+    #[test]
+    fn test_field_sort_prove() {
+        // Includes a duplicate and the largest canonical element.
+        prove_sort(
+            SyscallCode::FIELD_SORT,
+            &[7, BabyBear::ORDER_U32 - 1, 0, 7, 123_456],
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    chips::{
+        chips::byte::event::{ByteLookupEvent, ByteRecordBehavior},
+        precompiles::sort::{
+            columns::{SortU32Cols, NUM_SORT_U32_COLS},
+            SortU32Chip,
+        },
+        utils::pad_rows_fixed,
+    },
+    compiler::{
+        riscv::{opcode::ByteOpcode, program::Program},
+        word::Word,
+    },
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::precompiles::{PrecompileEvent, SortU32Event},
+    },
+    machine::chip::ChipBehavior,
+};
+use p3_air::BaseAir;
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use std::borrow::BorrowMut;
+
+impl<F: PrimeField32> BaseAir<F> for SortU32Chip<F> {
+    fn width(&self) -> usize {
+        NUM_SORT_U32_COLS
+    }
+}
+
+impl<F: PrimeField32> ChipBehavior<F> for SortU32Chip<F> {
+    type Record = EmulationRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        if self.bounded {
+            "FieldSort".to_string()
+        } else {
+            "SortU32".to_string()
+        }
+    }
+
+    fn generate_main(&self, input: &EmulationRecord, _: &mut EmulationRecord) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        let mut new_byte_lookup_events = Vec::new();
+        for (_, event) in input.get_precompile_events(self.syscall_code()).iter() {
+            let event = if let PrecompileEvent::SortU32(event) | PrecompileEvent::FieldSort(event) =
+                event
+            {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut new_byte_lookup_events);
+        }
+
+        let mut rows = rows.unwrap();
+        let log_rows = input.shape_chip_size(&self.name());
+        pad_rows_fixed(&mut rows, || [F::ZERO; NUM_SORT_U32_COLS], log_rows);
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_SORT_U32_COLS)
+    }
+
+    fn extra_record(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events: Vec<_> = input
+            .get_precompile_events(self.syscall_code())
+            .iter()
+            .filter_map(|(_, event)| {
+                if let PrecompileEvent::SortU32(event) | PrecompileEvent::FieldSort(event) = event {
+                    Some(event)
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut blu = vec![];
+                events.iter().for_each(|event| {
+                    self.event_to_rows(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect();
+
+        output.add_byte_lookup_events(blu_batches);
+    }
+
+    fn is_active(&self, record: &Self::Record) -> bool {
+        if let Some(shape) = record.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !record.get_precompile_events(self.syscall_code()).is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> SortU32Chip<F> {
+    fn event_to_rows(
+        &self,
+        event: &SortU32Event,
+        rows: &mut Option<Vec<[F; NUM_SORT_U32_COLS]>>,
+        blu: &mut impl ByteRecordBehavior,
+    ) {
+        let len = event.output.len();
+
+        for (j, index) in (0..len).rev().enumerate() {
+            let mut row = [F::ZERO; NUM_SORT_U32_COLS];
+            let cols: &mut SortU32Cols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::ONE;
+            cols.chunk = F::from_canonical_u32(event.chunk);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.ptr = F::from_canonical_u32(event.ptr);
+            cols.len = F::from_canonical_usize(len);
+
+            cols.index = F::from_canonical_usize(index);
+            cols.index_is_zero.populate(index as u32);
+            cols.is_first = F::from_bool(j == 0);
+            cols.is_last = F::from_bool(index == 0);
+
+            cols.access.populate(event.write_records[index], blu);
+
+            // The first row of a `FIELD_SORT` event compares against the field order.
+            let above = if j > 0 {
+                Some(event.output[index + 1])
+            } else {
+                self.bounded.then_some(F::ORDER_U32)
+            };
+            if let Some(above) = above {
+                let value = event.output[index].to_le_bytes();
+                cols.above = Word::from(above);
+                let above = above.to_le_bytes();
+
+                // Flag the most significant byte that differs, if any.
+                if let Some(i) = (0..4).rev().find(|&i| value[i] != above[i]) {
+                    assert!(value[i] < above[i], "{} output is not sorted", self.name());
+                    cols.lt_byte_flags[i] = F::ONE;
+                    cols.comparison_byte = F::from_canonical_u8(value[i]);
+                    cols.above_comparison_byte = F::from_canonical_u8(above[i]);
+                    blu.add_byte_lookup_event(ByteLookupEvent {
+                        opcode: ByteOpcode::LTU,
+                        a1: 1,
+                        a2: 0,
+                        b: value[i],
+                        c: above[i],
+                    });
+                }
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
@@ -7,7 +7,6 @@ use crate::{
     emulator::recursion::public_values::{ChallengerPublicValues, RecursionPublicValues},
     primitives::consts::DIGEST_SIZE,
 };
-use itertools::Itertools;
 use p3_bn254_fr::Bn254Fr;
 use p3_field::{FieldAlgebra, PrimeField32};
 use std::mem::MaybeUninit;
@@ -27,14 +26,16 @@ pub fn embed_public_values_digest<SC: StarkGenericConfig>(
     config.hash_slice(&input)
 }
 
-pub fn assert_embed_public_values_valid<SC: StarkGenericConfig>(
+/// Check that the digest in the embed public values is the hash of the values it covers.
+pub fn check_embed_public_values<SC: StarkGenericConfig>(
     config: &SC,
     public_values: &RecursionPublicValues<Val<SC>>,
-) {
+) -> anyhow::Result<()> {
     let expected_digest = embed_public_values_digest(config, public_values);
-    for (value, expected) in public_values.digest.iter().copied().zip_eq(expected_digest) {
-        assert_eq!(value, expected);
+    if public_values.digest.iter().copied().ne(expected_digest) {
+        anyhow::bail!("embed public values digest mismatch");
     }
+    Ok(())
 }
 
 #[allow(dead_code)]
@@ -76,6 +77,11 @@ pub fn field_bytes_to_bn254<F: PrimeField32>(bytes: &[F; 32]) -> Bn254Fr {
     result
 }
 
+/// Pack a digest of BabyBear or KoalaBear elements into one Bn254Fr variable, in radix `2^31`.
+///
+/// The packing is constrained in the outer circuit: `CircuitFelt2Var` reduces each felt to its
+/// canonical value with range-checked limbs, so every limb is below `2^31` and the packing is
+/// injective.
 #[allow(dead_code)]
 pub fn felts_to_bn254_var<FC: FieldGenericConfig>(
     builder: &mut Builder<FC>,
@@ -94,6 +100,8 @@ pub fn felts_to_bn254_var<FC: FieldGenericConfig>(
     result
 }
 
+/// Pack 32 byte-valued felts into one Bn254Fr variable, dropping the top 3 bits of the first
+/// byte. Each byte is bit-decomposed in the circuit before it is added in.
 #[allow(dead_code)]
 pub fn felt_bytes_to_bn254_var<FC: FieldGenericConfig>(
     builder: &mut Builder<FC>,
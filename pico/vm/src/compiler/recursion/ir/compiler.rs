@@ -67,7 +67,9 @@ where
 
         // Replace the mults using the address count data gathered in this previous.
         // Exhaustive match for refactoring purposes.
-        let total_memory = self.addr_to_mult.len() + self.consts.len();
+        // Addresses are allocated upwards from zero, so the next free address bounds every
+        // address of the program.
+        let total_memory = self.next_addr.as_canonical_u64() as usize;
         let mut backfill = |(mult, addr): (&mut F, &Address<F>)| {
             *mult = self.addr_to_mult.remove(addr.as_usize()).unwrap()
         };
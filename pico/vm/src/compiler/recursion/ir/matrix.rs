@@ -0,0 +1,127 @@
+use super::{Builder, Felt, MemIndex, MemVariable, Ptr, Usize, Variable};
+use crate::configs::config::FieldGenericConfig;
+
+/// A `ROWS x COLS` matrix of felts.
+///
+/// In memory the matrix takes `ROWS * COLS` consecutive slots in row-major order, so an array of
+/// matrices can be indexed like any other array of variables.
+#[derive(Debug, Clone)]
+pub struct MatrixVariable<FC: FieldGenericConfig, const ROWS: usize, const COLS: usize> {
+    pub rows: [[Felt<FC::F>; COLS]; ROWS],
+}
+
+impl<FC: FieldGenericConfig, const ROWS: usize, const COLS: usize> MatrixVariable<FC, ROWS, COLS> {
+    /// Gets the entry at `row` and `col`.
+    pub fn get(&self, row: usize, col: usize) -> Felt<FC::F> {
+        self.rows[row][col]
+    }
+
+    /// Iterates over the entries in row-major order.
+    pub fn entries(&self) -> impl Iterator<Item = &Felt<FC::F>> {
+        self.rows.iter().flatten()
+    }
+}
+
+impl<FC: FieldGenericConfig> Builder<FC> {
+    /// Loads the `index`-th matrix of the array of matrices at `ptr`.
+    pub fn matrix_load<const ROWS: usize, const COLS: usize>(
+        &mut self,
+        ptr: Ptr<FC::N>,
+        index: impl Into<Usize<FC::N>>,
+    ) -> MatrixVariable<FC, ROWS, COLS> {
+        let index = MemIndex {
+            index: index.into(),
+            offset: 0,
+            size: <MatrixVariable<FC, ROWS, COLS> as MemVariable<FC>>::size_of(),
+        };
+        let mat: MatrixVariable<FC, ROWS, COLS> = self.uninit();
+        self.load(mat.clone(), ptr, index);
+        mat
+    }
+
+    /// Stores `mat` as the `index`-th matrix of the array of matrices at `ptr`.
+    pub fn matrix_store<const ROWS: usize, const COLS: usize>(
+        &mut self,
+        ptr: Ptr<FC::N>,
+        index: impl Into<Usize<FC::N>>,
+        mat: &MatrixVariable<FC, ROWS, COLS>,
+    ) {
+        let index = MemIndex {
+            index: index.into(),
+            offset: 0,
+            size: <MatrixVariable<FC, ROWS, COLS> as MemVariable<FC>>::size_of(),
+        };
+        self.store(ptr, index, mat.clone());
+    }
+}
+
+impl<FC: FieldGenericConfig, const ROWS: usize, const COLS: usize> Variable<FC>
+    for MatrixVariable<FC, ROWS, COLS>
+{
+    type Expression = Self;
+
+    fn uninit(builder: &mut Builder<FC>) -> Self {
+        Self {
+            rows: core::array::from_fn(|_| core::array::from_fn(|_| builder.uninit())),
+        }
+    }
+
+    fn assign(&self, src: Self::Expression, builder: &mut Builder<FC>) {
+        for (dst, src) in self.entries().zip(src.entries()) {
+            dst.assign((*src).into(), builder);
+        }
+    }
+
+    fn assert_eq(
+        lhs: impl Into<Self::Expression>,
+        rhs: impl Into<Self::Expression>,
+        builder: &mut Builder<FC>,
+    ) {
+        let lhs = lhs.into();
+        let rhs = rhs.into();
+        for (l, r) in lhs.entries().zip(rhs.entries()) {
+            <Felt<FC::F> as Variable<FC>>::assert_eq(*l, *r, builder);
+        }
+    }
+
+    fn assert_ne(
+        lhs: impl Into<Self::Expression>,
+        rhs: impl Into<Self::Expression>,
+        builder: &mut Builder<FC>,
+    ) {
+        let lhs = lhs.into();
+        let rhs = rhs.into();
+        for (l, r) in lhs.entries().zip(rhs.entries()) {
+            <Felt<FC::F> as Variable<FC>>::assert_ne(*l, *r, builder);
+        }
+    }
+}
+
+impl<FC: FieldGenericConfig, const ROWS: usize, const COLS: usize> MemVariable<FC>
+    for MatrixVariable<FC, ROWS, COLS>
+{
+    fn size_of() -> usize {
+        ROWS * COLS
+    }
+
+    fn load(&self, ptr: Ptr<FC::N>, index: MemIndex<FC::N>, builder: &mut Builder<FC>) {
+        let mut index = index;
+        for entry in self.entries() {
+            entry.load(ptr, index, builder);
+            index.offset += 1;
+        }
+    }
+
+    fn store(
+        &self,
+        ptr: Ptr<<FC as FieldGenericConfig>::N>,
+        index: MemIndex<FC::N>,
+        builder: &mut Builder<FC>,
+    ) {
+        let mut index = index;
+        for entry in self.entries() {
+            entry.store(ptr, index, builder);
+            index.offset += 1;
+        }
+    }
+}
@@ -5,6 +5,7 @@ mod builder;
 mod collections;
 pub mod compiler;
 mod instructions;
+mod matrix;
 mod ptr;
 mod symbolic;
 mod types;
@@ -16,6 +17,7 @@ pub use block::*;
 pub use builder::*;
 pub use collections::*;
 pub use instructions::*;
+pub use matrix::*;
 pub use ptr::*;
 pub use symbolic::*;
 pub use types::*;
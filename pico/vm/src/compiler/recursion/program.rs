@@ -1,17 +1,32 @@
-use super::instruction::Instruction;
+use super::{
+    instruction::Instruction,
+    types::{Address, MemAccessKind},
+};
 use crate::{
-    compiler::program::ProgramBehavior,
+    compiler::program::ProgramBehavior, emulator::recursion::public_values::RecursionPublicValues,
     instances::compiler::shapes::recursion_shape::RecursionPadShape, machine::septic::SepticDigest,
 };
 use backtrace::Backtrace;
+use core::borrow::Borrow;
 use hashbrown::HashMap;
-use p3_field::Field;
+use p3_field::{Field, PrimeField64};
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
+#[derive(Debug, Clone, Default)]
+pub struct ProgramStats {
+    pub total_instructions: usize,
+    pub by_type: HashMap<&'static str, usize>,
+    /// The highest address written by any instruction.
+    pub max_memory_address: usize,
+    pub total_memory: usize,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RecursionProgram<F> {
     pub instructions: Vec<Instruction<F>>,
+    /// One past the highest memory address of the program, which the runtime enforces. Zero if
+    /// unknown, in which case the memory is unbounded.
     pub total_memory: usize,
     #[serde(skip)]
     pub traces: Vec<Option<Backtrace>>,
@@ -55,22 +70,7 @@ impl<F: Field> RecursionProgram<F> {
     pub fn stats(&self) -> HashMap<String, usize> {
         let mut stats = HashMap::new();
         for instr in &self.instructions {
-            let key = match instr {
-                Instruction::BaseAlu(_) => "BaseAlu",
-                Instruction::ExtAlu(_) => "ExtAlu",
-                Instruction::Mem(_) => "Mem",
-                Instruction::Poseidon2(_) => "Poseidon2",
-                Instruction::Select(_) => "Select",
-                Instruction::ExpReverseBitsLen(_) => "ExpReverseBitsLen",
-                Instruction::BatchFRI(_) => "BatchFRI",
-                Instruction::HintBits(_) => "HintBits",
-                Instruction::Print(_) => "Print",
-                Instruction::HintExt2Felts(_) => "HintExt2Felts",
-                Instruction::CommitPublicValues(_) => "CommitPublicValues",
-                Instruction::HintAddCurve(_) => "HintAddCurve",
-                Instruction::Hint(_) => "Hint",
-            };
-            *stats.entry(key.to_string()).or_insert(0) += 1;
+            *stats.entry(instr_name(instr).to_string()).or_insert(0) += 1;
         }
         stats
     }
@@ -84,3 +84,305 @@ impl<F: Field> RecursionProgram<F> {
         }
     }
 }
+
+impl<F: PrimeField64> RecursionProgram<F> {
+    /// Instruction counts by type together with the memory footprint of the program.
+    pub fn statistics(&self) -> ProgramStats {
+        let mut by_type = HashMap::new();
+        let mut max_memory_address = 0;
+        for instr in &self.instructions {
+            *by_type.entry(instr_name(instr)).or_insert(0) += 1;
+            for (addr, _) in instr_outputs(instr) {
+                max_memory_address = max_memory_address.max(addr);
+            }
+        }
+        ProgramStats {
+            total_instructions: self.instructions.len(),
+            by_type,
+            max_memory_address,
+            total_memory: self.total_memory,
+        }
+    }
+
+    /// Indices of instructions that cannot affect the proof.
+    ///
+    /// Recursion programs have no control flow, so every instruction is reached from pc = 0 in
+    /// order. Reachability therefore comes down to whether anything consumes the instruction's
+    /// results: an instruction is dead when it writes memory and every written value has
+    /// multiplicity zero. Instructions without outputs, such as public value commits and prints,
+    /// are always kept.
+    pub fn dead_code_analysis(&self) -> Vec<usize> {
+        self.instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, instr)| {
+                let outputs = instr_outputs(instr);
+                !outputs.is_empty() && outputs.iter().all(|(_, mult)| mult.is_zero())
+            })
+            .map(|(pc, _)| pc)
+            .collect()
+    }
+
+    /// Concatenates two programs into one that runs `a` and then `b`.
+    ///
+    /// Compiled programs allocate their memory from address zero, so the addresses of `b` are
+    /// moved up by `a.total_memory` and the two programs do not share any memory. Constants are
+    /// initialized by memory writes at the top of each program and move along with the rest of
+    /// `b`, so a constant used by both programs is stored twice. The merged program has no shape,
+    /// as the chip heights of either one no longer apply. At most one of the programs should
+    /// commit public values.
+    pub fn merge(a: RecursionProgram<F>, b: RecursionProgram<F>) -> RecursionProgram<F> {
+        let offset = F::from_canonical_usize(a.total_memory);
+        let num_a = a.instructions.len();
+        let num_b = b.instructions.len();
+
+        let mut instructions = a.instructions;
+        instructions.extend(b.instructions.into_iter().map(|mut instr| {
+            shift_addrs(&mut instr, offset);
+            instr
+        }));
+
+        // Traces are only recorded in debug builds, keep them aligned with the instructions.
+        let traces = if a.traces.is_empty() && b.traces.is_empty() {
+            vec![]
+        } else {
+            let mut traces = a.traces;
+            traces.resize_with(num_a, || None);
+            traces.extend(b.traces);
+            traces.resize_with(num_a + num_b, || None);
+            traces
+        };
+
+        RecursionProgram {
+            instructions,
+            total_memory: a.total_memory + b.total_memory,
+            traces,
+            shape: None,
+        }
+    }
+}
+
+/// Moves every address read or written by an instruction up by `offset`.
+fn shift_addrs<F: Field>(instr: &mut Instruction<F>, offset: F) {
+    let shift = |addr: &mut Address<F>| addr.0 += offset;
+    let shift_mults = |addrs_mults: &mut [(Address<F>, F)]| {
+        addrs_mults.iter_mut().for_each(|(addr, _)| shift(addr))
+    };
+    match instr {
+        Instruction::BaseAlu(instr) => {
+            [
+                &mut instr.addrs.out,
+                &mut instr.addrs.in1,
+                &mut instr.addrs.in2,
+            ]
+            .into_iter()
+            .for_each(shift);
+        }
+        Instruction::ExtAlu(instr) => {
+            [
+                &mut instr.addrs.out,
+                &mut instr.addrs.in1,
+                &mut instr.addrs.in2,
+            ]
+            .into_iter()
+            .for_each(shift);
+        }
+        Instruction::Mem(instr) => shift(&mut instr.addrs.inner),
+        Instruction::Poseidon2(instr) => {
+            instr.addrs.input.iter_mut().for_each(shift);
+            instr.addrs.output.iter_mut().for_each(shift);
+        }
+        Instruction::Select(instr) => {
+            let addrs = &mut instr.addrs;
+            [
+                &mut addrs.bit,
+                &mut addrs.out1,
+                &mut addrs.out2,
+                &mut addrs.in1,
+                &mut addrs.in2,
+            ]
+            .into_iter()
+            .for_each(shift);
+        }
+        Instruction::ExpReverseBitsLen(instr) => {
+            shift(&mut instr.addrs.base);
+            instr.addrs.exp.iter_mut().for_each(shift);
+            shift(&mut instr.addrs.result);
+        }
+        Instruction::HintBits(instr) => {
+            shift_mults(&mut instr.output_addrs_mults);
+            shift(&mut instr.input_addr);
+        }
+        Instruction::BatchFRI(instr) => {
+            instr.base_vec_addrs.p_at_x.iter_mut().for_each(shift);
+            shift(&mut instr.ext_single_addrs.acc);
+            instr.ext_vec_addrs.p_at_z.iter_mut().for_each(shift);
+            instr.ext_vec_addrs.alpha_pow.iter_mut().for_each(shift);
+        }
+        Instruction::HintAddCurve(instr) => {
+            shift_mults(&mut instr.output_x_addrs_mults);
+            shift_mults(&mut instr.output_y_addrs_mults);
+            instr.input1_x_addrs.iter_mut().for_each(shift);
+            instr.input1_y_addrs.iter_mut().for_each(shift);
+            instr.input2_x_addrs.iter_mut().for_each(shift);
+            instr.input2_y_addrs.iter_mut().for_each(shift);
+        }
+        Instruction::Print(instr) => shift(&mut instr.addr),
+        Instruction::HintExt2Felts(instr) => {
+            shift_mults(&mut instr.output_addrs_mults);
+            shift(&mut instr.input_addr);
+        }
+        Instruction::CommitPublicValues(instr) => {
+            let mut addrs = instr.pv_addrs.as_array();
+            addrs.iter_mut().for_each(shift);
+            let pv_addrs: &RecursionPublicValues<Address<F>> = addrs.as_slice().borrow();
+            instr.pv_addrs = *pv_addrs;
+        }
+        Instruction::Hint(instr) => shift_mults(&mut instr.output_addrs_mults),
+    }
+}
+
+fn instr_name<F>(instr: &Instruction<F>) -> &'static str {
+    match instr {
+        Instruction::BaseAlu(_) => "BaseAlu",
+        Instruction::ExtAlu(_) => "ExtAlu",
+        Instruction::Mem(_) => "Mem",
+        Instruction::Poseidon2(_) => "Poseidon2",
+        Instruction::Select(_) => "Select",
+        Instruction::ExpReverseBitsLen(_) => "ExpReverseBitsLen",
+        Instruction::BatchFRI(_) => "BatchFRI",
+        Instruction::HintBits(_) => "HintBits",
+        Instruction::Print(_) => "Print",
+        Instruction::HintExt2Felts(_) => "HintExt2Felts",
+        Instruction::CommitPublicValues(_) => "CommitPublicValues",
+        Instruction::HintAddCurve(_) => "HintAddCurve",
+        Instruction::Hint(_) => "Hint",
+    }
+}
+
+/// The addresses written by an instruction, each with the number of times it is read.
+fn instr_outputs<F: PrimeField64>(instr: &Instruction<F>) -> Vec<(usize, F)> {
+    match instr {
+        Instruction::BaseAlu(instr) => vec![(instr.addrs.out.as_usize(), instr.mult)],
+        Instruction::ExtAlu(instr) => vec![(instr.addrs.out.as_usize(), instr.mult)],
+        Instruction::Mem(instr) => match instr.kind {
+            MemAccessKind::Write => vec![(instr.addrs.inner.as_usize(), instr.mult)],
+            MemAccessKind::Read => vec![],
+        },
+        Instruction::Poseidon2(instr) => instr
+            .addrs
+            .output
+            .iter()
+            .zip(instr.mults)
+            .map(|(addr, mult)| (addr.as_usize(), mult))
+            .collect(),
+        Instruction::Select(instr) => vec![
+            (instr.addrs.out1.as_usize(), instr.mult1),
+            (instr.addrs.out2.as_usize(), instr.mult2),
+        ],
+        Instruction::ExpReverseBitsLen(instr) => {
+            vec![(instr.addrs.result.as_usize(), instr.mult)]
+        }
+        Instruction::BatchFRI(instr) => {
+            vec![(instr.ext_single_addrs.acc.as_usize(), instr.acc_mult)]
+        }
+        Instruction::HintBits(instr) => instr
+            .output_addrs_mults
+            .iter()
+            .map(|(addr, mult)| (addr.as_usize(), *mult))
+            .collect(),
+        Instruction::HintExt2Felts(instr) => instr
+            .output_addrs_mults
+            .iter()
+            .map(|(addr, mult)| (addr.as_usize(), *mult))
+            .collect(),
+        Instruction::Hint(instr) => instr
+            .output_addrs_mults
+            .iter()
+            .map(|(addr, mult)| (addr.as_usize(), *mult))
+            .collect(),
+        Instruction::HintAddCurve(instr) => instr
+            .output_x_addrs_mults
+            .iter()
+            .chain(&instr.output_y_addrs_mults)
+            .map(|(addr, mult)| (addr.as_usize(), *mult))
+            .collect(),
+        Instruction::Print(_) | Instruction::CommitPublicValues(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        compiler::recursion::instruction::{base_alu, mem},
+        emulator::recursion::emulator::BaseAluOpcode,
+    };
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_merge_moves_addresses_of_second_program() {
+        // Each program writes a constant to 0 and adds it to itself into 1.
+        let program = || RecursionProgram::<BabyBear> {
+            instructions: vec![
+                mem(MemAccessKind::Write, 2, 0, 5),
+                base_alu(BaseAluOpcode::AddF, 1, 1, 0, 0),
+            ],
+            total_memory: 2,
+            ..Default::default()
+        };
+
+        let merged = RecursionProgram::merge(program(), program());
+        assert_eq!(merged.instructions.len(), 4);
+        assert_eq!(merged.total_memory, 4);
+        let addrs = merged
+            .instructions
+            .iter()
+            .map(|instr| match instr {
+                Instruction::Mem(instr) => vec![instr.addrs.inner.as_usize()],
+                Instruction::BaseAlu(instr) => [instr.addrs.out, instr.addrs.in1, instr.addrs.in2]
+                    .map(|addr| addr.as_usize())
+                    .to_vec(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(addrs, vec![vec![0], vec![1, 0, 0], vec![2], vec![3, 2, 2]]);
+    }
+
+    /// Writes 5 to 0, then 0 + 0 to 1 and 1 * 1 to 2, reads 1 back and writes 7 to 3. Nothing
+    /// reads 2 or 3.
+    fn program_with_dead_writes() -> RecursionProgram<BabyBear> {
+        RecursionProgram {
+            instructions: vec![
+                mem(MemAccessKind::Write, 2, 0, 5),
+                base_alu(BaseAluOpcode::AddF, 3, 1, 0, 0),
+                base_alu(BaseAluOpcode::MulF, 0, 2, 1, 1),
+                mem(MemAccessKind::Read, 1, 1, 10),
+                mem(MemAccessKind::Write, 0, 3, 7),
+            ],
+            total_memory: 4,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_statistics() {
+        let stats = program_with_dead_writes().statistics();
+        assert_eq!(stats.total_instructions, 5);
+        assert_eq!(
+            stats.by_type,
+            HashMap::from_iter([("Mem", 3), ("BaseAlu", 2)])
+        );
+        assert_eq!(stats.max_memory_address, 3);
+        assert_eq!(stats.total_memory, 4);
+    }
+
+    #[test]
+    fn test_dead_code_analysis() {
+        // The read at 3 writes nothing, so it is never dead.
+        assert_eq!(program_with_dead_writes().dead_code_analysis(), vec![2, 4]);
+        assert!(RecursionProgram::<BabyBear>::default()
+            .dead_code_analysis()
+            .is_empty());
+    }
+}
@@ -17,6 +17,48 @@ impl Compilable {
             Compilable::RISCV(elf) => elf.compile(),
         }
     }
+
+    fn disassemble(&self) -> Vec<DisassemblyLine> {
+        match self {
+            Compilable::RISCV(elf) => elf.disassemble(),
+        }
+    }
+
+    fn symbols(&self) -> &[Symbol] {
+        match self {
+            Compilable::RISCV(elf) => &elf.symbols,
+        }
+    }
+}
+
+/// One instruction of a disassembly listing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisassemblyLine {
+    /// The address of the instruction.
+    pub addr: u32,
+    /// The encoded instruction.
+    pub hex: u32,
+    /// The instruction mnemonic, e.g. `addi`, or `unknown` if the word does not decode.
+    pub mnemonic: String,
+    /// The comma-separated operands, e.g. `x17, x12, 123`.
+    pub operands: String,
+}
+
+/// A named address from the symbol table of the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub name: String,
+    /// The address of the symbol.
+    pub addr: u32,
+    /// The number of bytes the symbol covers, or zero if unknown, e.g. for linker symbols.
+    pub size: u32,
+}
+
+impl Symbol {
+    /// Whether `addr` lies within the symbol.
+    pub fn contains(&self, addr: u32) -> bool {
+        addr >= self.addr && addr - self.addr < self.size
+    }
 }
 
 pub struct Compiler {
@@ -48,4 +90,48 @@ impl Compiler {
         debug!("Compiling {} source...", self.name());
         self.source.compile()
     }
+
+    /// Disassemble the executable instructions of the source, in address order.
+    pub fn disassemble(&self) -> Vec<DisassemblyLine> {
+        self.source.disassemble()
+    }
+
+    /// The named symbols of the source, in address order. Stripped sources have none.
+    pub fn symbols(&self) -> &[Symbol] {
+        self.source.symbols()
+    }
+
+    /// The symbol that covers `addr`, if any.
+    pub fn symbol_at(&self, addr: u32) -> Option<&Symbol> {
+        let symbols = self.symbols();
+        // Symbols may nest, so check the ones starting at or below `addr` from the closest down.
+        let end = symbols.partition_point(|symbol| symbol.addr <= addr);
+        symbols[..end]
+            .iter()
+            .rev()
+            .find(|symbol| symbol.contains(addr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disassemble() {
+        let elf = include_bytes!("../test_elf/riscv32im-pico-fibonacci-elf");
+        let compiler = Compiler::new(SourceType::RISCV, elf);
+        let program = compiler.compile();
+        let lines = compiler.disassemble();
+
+        assert_eq!(lines.len(), program.instructions.len());
+        for (i, line) in lines.iter().enumerate() {
+            assert_eq!(line.addr, program.pc_base + 4 * i as u32);
+        }
+        let entry = lines
+            .iter()
+            .find(|line| line.addr == program.pc_start)
+            .unwrap();
+        assert_ne!(entry.mnemonic, "unknown");
+    }
 }
@@ -1,12 +1,17 @@
-use crate::compiler::riscv::{disassembler::transpile, program::Program};
+use crate::compiler::riscv::{
+    compiler::{DisassemblyLine, Symbol},
+    disassembler::transpile,
+    program::Program,
+};
 use alloc::sync::Arc;
 use elf::{
-    abi::{EM_RISCV, ET_EXEC, PF_X, PT_LOAD},
+    abi::{EM_RISCV, ET_EXEC, PF_W, PF_X, PT_LOAD, STT_FUNC, STT_NOTYPE, STT_OBJECT},
     endian::LittleEndian,
     file::Class,
     ElfBytes,
 };
-use std::{cmp::min, collections::BTreeMap};
+use rrs_lib::{instruction_string_outputter::InstructionStringOutputter, process_instruction};
+use std::{cmp::min, collections::BTreeMap, ops::Range};
 
 use crate::primitives::consts::WORD_SIZE;
 
@@ -32,6 +37,10 @@ pub struct Elf {
     pub(crate) pc_base: u32,
     /// The initial memory image, useful for global constants.
     pub(crate) memory_image: Arc<BTreeMap<u32, u32>>,
+    /// The address ranges of the segments without write permission, such as code and rodata.
+    pub(crate) read_only_ranges: Arc<[Range<u32>]>,
+    /// The named functions, objects and linker symbols, sorted by address.
+    pub(crate) symbols: Vec<Symbol>,
 }
 
 impl Elf {
@@ -79,6 +88,7 @@ impl Elf {
 
         let mut instructions: Vec<u32> = Vec::new();
         let mut base_address = u32::MAX;
+        let mut read_only_ranges = Vec::new();
 
         // Only read segments that are executable instructions that are also PT_LOAD.
         for segment in segments.iter().filter(|x| x.p_type == PT_LOAD) {
@@ -106,6 +116,10 @@ impl Elf {
                 base_address = vaddr;
             }
 
+            if (segment.p_flags & PF_W) == 0 {
+                read_only_ranges.push(vaddr..vaddr.saturating_add(mem_size));
+            }
+
             // Get the offset to the segment.
             let offset: u32 = segment.p_offset.try_into()?;
 
@@ -143,11 +157,33 @@ impl Elf {
             }
         }
 
+        // Keep the symbols for resolving addresses in tools. Stripped ELFs have no symbol table.
+        let mut symbols = Vec::new();
+        if let Some((symtab, strtab)) = elf.symbol_table()? {
+            for symbol in symtab.iter() {
+                let symtype = symbol.st_symtype();
+                if symbol.st_name == 0
+                    || !matches!(symtype, STT_FUNC | STT_OBJECT | STT_NOTYPE)
+                    || symbol.is_undefined()
+                {
+                    continue;
+                }
+                symbols.push(Symbol {
+                    name: strtab.get(symbol.st_name as usize)?.to_string(),
+                    addr: symbol.st_value.try_into()?,
+                    size: symbol.st_size.try_into()?,
+                });
+            }
+        }
+        symbols.sort_by_key(|symbol| symbol.addr);
+
         Ok(Self {
             instructions,
             pc_start: entry,
             pc_base: base_address,
             memory_image: image.into(),
+            read_only_ranges: read_only_ranges.into(),
+            symbols,
         })
     }
 
@@ -162,8 +198,30 @@ impl Elf {
             pc_start: self.pc_start,
             pc_base: self.pc_base,
             memory_image: self.memory_image.clone(),
+            read_only_ranges: self.read_only_ranges.clone(),
             preprocessed_shape: None,
         }
         .into()
     }
+
+    /// Disassemble the instructions, which are laid out from `pc_base` on.
+    pub fn disassemble(&self) -> Vec<DisassemblyLine> {
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(i, &hex)| {
+                let addr = self.pc_base + (i * WORD_SIZE) as u32;
+                let mut outputter = InstructionStringOutputter { insn_pc: addr };
+                let text = process_instruction(&mut outputter, hex)
+                    .unwrap_or_else(|| "unknown".to_string());
+                let (mnemonic, operands) = text.split_once(' ').unwrap_or((&text, ""));
+                DisassemblyLine {
+                    addr,
+                    hex,
+                    mnemonic: mnemonic.to_string(),
+                    operands: operands.trim().to_string(),
+                }
+            })
+            .collect()
+    }
 }
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 /// The structure of the instruction differs from the RISC-V ISA. We do not encode the instructions
 /// as 32-bit words, but instead use a custom encoding that is more friendly to decode in the
 /// Pico zkVM.
-#[derive(Clone, Copy, Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Instruction {
     /// The operation to emulate.
     pub opcode: Opcode,
@@ -104,6 +104,9 @@ pub enum Opcode {
 /// This represents a basic operation that can be performed on a byte. Usually, these operations
 /// are performed via lookup tables on that iterate over the domain of two 8-bit values. The
 /// operations include both bitwise operations (AND, OR, XOR) as well as basic arithmetic.
+///
+/// The opcodes from [`NUM_BYTE_OPS`] on are not in the byte table: they are looked up in the
+/// tables of the precompiles that use them.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum ByteOpcode {
@@ -125,6 +128,11 @@ pub enum ByteOpcode {
     U8Range = 7,
     /// Unsigned 16-bit Range Check.
     U16Range = 8,
+    /// Two bytes of a CRC-32 table entry. Looked up in the table of the `Crc32Table` chip.
+    Crc32 = 9,
+    /// AES S-box, together with the S-box output multiplied by `x` in GF(2^8). Looked up in the
+    /// table of the `AesSbox` chip.
+    AesSbox = 10,
 }
 
 /// Range Check Opcode.
@@ -217,7 +225,7 @@ impl From<Opcode> for ByteOpcode {
 }
 
 impl ByteOpcode {
-    /// Get all the byte opcodes.
+    /// Get all the byte opcodes of the byte table.
     #[must_use]
     pub fn all() -> Vec<Self> {
         let opcodes = vec![
@@ -1,7 +1,11 @@
 //! Programs that can be emulated by the Pico.
 
 use crate::{
-    compiler::{program::ProgramBehavior, riscv::instruction::Instruction},
+    compiler::{
+        program::ProgramBehavior,
+        riscv::{instruction::Instruction, opcode::Opcode, register::Register},
+    },
+    emulator::riscv::syscalls::SyscallCode,
     instances::compiler::shapes::riscv_shape::RiscvPadShape,
     iter::{IntoPicoIterator, PicoBridge, PicoIterator},
     machine::{
@@ -12,7 +16,10 @@ use crate::{
 use alloc::sync::Arc;
 use p3_field::{FieldExtensionAlgebra, PrimeField32};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, HashSet},
+    ops::Range,
+};
 
 /// A program that can be emulated by the Pico.
 ///
@@ -30,6 +37,9 @@ pub struct Program {
     pub pc_base: u32,
     /// The initial memory image, useful for global constants.
     pub memory_image: Arc<BTreeMap<u32, u32>>,
+    /// The parts of `memory_image` that are loaded from read-only segments, such as code and
+    /// rodata. The rest of it, such as `.data` and `.bss`, is writable.
+    pub read_only_ranges: Arc<[Range<u32>]>,
     /// The shape for the preprocessed tables.
     pub preprocessed_shape: Option<RiscvPadShape>,
 }
@@ -43,6 +53,7 @@ impl Program {
             pc_start,
             pc_base,
             memory_image: BTreeMap::new().into(),
+            read_only_ranges: Vec::new().into(),
             preprocessed_shape: None,
         }
     }
@@ -64,6 +75,99 @@ impl Program {
         let idx = (pc - self.pc_base) as usize / 4;
         self.instructions[idx]
     }
+
+    /// Returns the syscalls that the program's `ecall` sites can invoke.
+    ///
+    /// This is a static scan: it follows the constants loaded into `t0` along straight-line code
+    /// before each `ecall`, forgetting them at jumps and at the targets of direct branches and
+    /// jumps. It sees every `ecall` in the program, including unreachable ones, but cannot tell
+    /// the code of an `ecall` whose `t0` is computed, loaded from memory or set across a jump.
+    /// Those sites are listed by [`Program::unresolved_ecalls`], and a whitelist check should
+    /// reject a program that has any. A dry emulation, [`RiscvEmulator::invoked_syscalls`], instead
+    /// only sees the syscalls reached with the given input.
+    ///
+    /// [`RiscvEmulator::invoked_syscalls`]: crate::emulator::riscv::emulator::RiscvEmulator::invoked_syscalls
+    pub fn referenced_syscalls(&self) -> HashSet<SyscallCode> {
+        self.scan_ecalls().0
+    }
+
+    /// Returns the pcs of the `ecall`s whose syscall code [`Program::referenced_syscalls`] cannot
+    /// determine.
+    pub fn unresolved_ecalls(&self) -> Vec<u32> {
+        self.scan_ecalls().1
+    }
+
+    fn scan_ecalls(&self) -> (HashSet<SyscallCode>, Vec<u32>) {
+        let pc_of = |i: usize| self.pc_base + 4 * i as u32;
+
+        // Code reached from a direct branch or jump may arrive with any register values.
+        let targets = self
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, instruction)| match instruction.opcode {
+                Opcode::JAL => Some(pc_of(i).wrapping_add(instruction.op_b)),
+                _ if instruction.is_branch_instruction() => {
+                    Some(pc_of(i).wrapping_add(instruction.op_c))
+                }
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mut syscalls = HashSet::new();
+        let mut unresolved = Vec::new();
+        let mut registers = [None; 32];
+        registers[0] = Some(0u32);
+        let forget = |registers: &mut [Option<u32>; 32]| registers[1..].fill(None);
+
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            let pc = pc_of(i);
+            if targets.contains(&pc) {
+                forget(&mut registers);
+            }
+
+            let b = if instruction.imm_b {
+                Some(instruction.op_b)
+            } else {
+                registers[instruction.op_b as usize]
+            };
+            let c = if instruction.imm_c {
+                Some(instruction.op_c)
+            } else {
+                registers[instruction.op_c as usize]
+            };
+            let value = match instruction.opcode {
+                Opcode::ECALL => {
+                    match registers[Register::X5 as usize].and_then(SyscallCode::try_from_u32) {
+                        Some(code) => {
+                            syscalls.insert(code);
+                        }
+                        None => unresolved.push(pc),
+                    }
+                    // The syscall returns its result in `t0`.
+                    None
+                }
+                Opcode::ADD => b.zip(c).map(|(b, c)| b.wrapping_add(c)),
+                _ => None,
+            };
+
+            let writes_op_a = !instruction.is_branch_instruction()
+                && !matches!(
+                    instruction.opcode,
+                    Opcode::SB | Opcode::SH | Opcode::SW | Opcode::EBREAK | Opcode::UNIMP
+                );
+            if writes_op_a && instruction.op_a != 0 {
+                registers[instruction.op_a as usize] = value;
+            }
+
+            // The next instruction may be a return address or reached through a computed jump.
+            if instruction.is_jump_instruction() {
+                forget(&mut registers);
+            }
+        }
+
+        (syscalls, unresolved)
+    }
 }
 
 impl<F: PrimeField32> ProgramBehavior<F> for Program {
@@ -77,6 +181,7 @@ impl<F: PrimeField32> ProgramBehavior<F> for Program {
             pc_start: self.pc_start,
             pc_base: self.pc_base,
             memory_image: self.memory_image.clone(),
+            read_only_ranges: self.read_only_ranges.clone(),
             preprocessed_shape: self.preprocessed_shape.clone(),
         }
     }
@@ -111,3 +216,30 @@ impl<F: PrimeField32> ProgramBehavior<F> for Program {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_referenced_syscalls() {
+        let instructions = vec![
+            // lui t0, 0x10; addi t0, t0, 0x134
+            Instruction::new(Opcode::ADD, 5, 0, 0x1_0000, true, true),
+            Instruction::new(Opcode::ADD, 5, 5, 0x134, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            // t0 loaded from memory.
+            Instruction::new(Opcode::LW, 5, 2, 0, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HALT as u32, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0x1000, 0x1000);
+
+        assert_eq!(
+            program.referenced_syscalls(),
+            HashSet::from([SyscallCode::SHA256, SyscallCode::HALT])
+        );
+        assert_eq!(program.unresolved_ecalls(), vec![0x1010]);
+    }
+}
@@ -67,6 +67,11 @@ pub trait StarkGenericConfig: Clone + Serialize + Sync {
     fn name(&self) -> String;
 
     fn hash_slice(&self, input: &[Val<Self>]) -> [Val<Self>; DIGEST_SIZE];
+
+    /// Whether proofs under this config are the embed proofs wrapped for on-chain verification.
+    fn is_embed() -> bool {
+        false
+    }
 }
 
 pub trait FieldGenericConfig: Clone + Default {
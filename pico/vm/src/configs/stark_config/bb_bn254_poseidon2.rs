@@ -137,6 +137,10 @@ impl StarkGenericConfig for BabyBearBn254Poseidon2 {
     fn hash_slice(&self, _input: &[Val<Self>]) -> [Val<Self>; DIGEST_SIZE] {
         todo!()
     }
+
+    fn is_embed() -> bool {
+        true
+    }
 }
 
 impl ZeroCommitment<BabyBearBn254Poseidon2> for SC_Pcs {
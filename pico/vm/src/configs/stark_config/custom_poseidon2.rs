@@ -0,0 +1,341 @@
+//! A [`StarkGenericConfig`] over a user supplied 31-bit field.
+//!
+//! The shipped configs each fix one field. [`CustomPoseidon2`] takes the field as a type parameter
+//! instead, and only needs the field's Poseidon2 permutation through [`Poseidon2Init`]. The
+//! permutation can also be built from custom round constants with
+//! [`CustomPoseidon2::with_round_constants`]. This is enough to prove and verify plain AIRs over
+//! the field. Proving the RISC-V and recursion machines
+//! additionally needs the septic curve of the global lookups over the field, see
+//! `machine::septic::fields` for what that takes.
+
+use crate::{
+    configs::config::{Com, SimpleFriConfig, StarkGenericConfig, Val, ZeroCommitment},
+    primitives::{
+        consts::{DIGEST_SIZE, PERMUTATION_WIDTH},
+        pico_poseidon2_with_constants, Poseidon2Constants, Poseidon2Init,
+    },
+};
+use anyhow::Result;
+use p3_challenger::DuplexChallenger;
+use p3_commit::{ExtensionMmcs, Pcs};
+use p3_dft::Radix2DitParallel;
+use p3_field::{
+    extension::{BinomialExtensionField, BinomiallyExtendable, HasTwoAdicBinomialExtension},
+    Field, FieldAlgebra, PrimeField32, TwoAdicField,
+};
+use p3_fri::{FriConfig, TwoAdicFriPcs};
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_poseidon2::ExternalLayerConstants;
+use p3_symmetric::{
+    CryptographicHasher, CryptographicPermutation, PaddingFreeSponge, TruncatedPermutation,
+};
+use serde::Serialize;
+use std::{any::type_name, marker::PhantomData};
+
+/// A 31-bit two-adic prime field with a width 16 Poseidon2 permutation and a degree 4 binomial
+/// extension, as the shipped BabyBear and KoalaBear configs use.
+///
+/// Implemented for every field that provides these, so a new field only needs the `p3` field
+/// traits and a [`Poseidon2Init`] impl returning its permutation with its own round constants.
+pub trait CustomField:
+    PrimeField32
+    + TwoAdicField
+    + BinomiallyExtendable<4>
+    + HasTwoAdicBinomialExtension<4>
+    + Poseidon2Init<
+        Poseidon2: CryptographicPermutation<[Self; 16]>
+                       + CryptographicPermutation<[<Self as Field>::Packing; 16]>
+                       + Clone
+                       + Send
+                       + Sync,
+    >
+{
+}
+
+impl<F> CustomField for F where
+    F: PrimeField32
+        + TwoAdicField
+        + BinomiallyExtendable<4>
+        + HasTwoAdicBinomialExtension<4>
+        + Poseidon2Init<
+            Poseidon2: CryptographicPermutation<[F; 16]>
+                           + CryptographicPermutation<[<F as Field>::Packing; 16]>
+                           + Clone
+                           + Send
+                           + Sync,
+        >
+{
+}
+
+pub type CustomPerm<F> = <F as Poseidon2Init>::Poseidon2;
+pub type CustomHash<F> = PaddingFreeSponge<CustomPerm<F>, 16, 8, 8>;
+pub type CustomCompress<F> = TruncatedPermutation<CustomPerm<F>, 2, 8, 16>;
+pub type CustomValMmcs<F> = MerkleTreeMmcs<
+    <F as Field>::Packing,
+    <F as Field>::Packing,
+    CustomHash<F>,
+    CustomCompress<F>,
+    8,
+>;
+pub type CustomChallenge<F> = BinomialExtensionField<F, 4>;
+pub type CustomChallengeMmcs<F> = ExtensionMmcs<F, CustomChallenge<F>, CustomValMmcs<F>>;
+
+pub type CustomChallenger<F> = DuplexChallenger<F, CustomPerm<F>, 16, 8>;
+pub type CustomDft<F> = Radix2DitParallel<F>;
+pub type CustomPcs<F> = TwoAdicFriPcs<F, CustomDft<F>, CustomValMmcs<F>, CustomChallengeMmcs<F>>;
+pub type CustomDigestHash<F> = p3_symmetric::Hash<F, F, DIGEST_SIZE>;
+
+/// The Poseidon2 config over the field `F`, with the FRI parameters of the shipped BabyBear and
+/// KoalaBear configs.
+pub struct CustomPoseidon2<F: CustomField> {
+    pub perm: CustomPerm<F>,
+    simple_fri_config: SimpleFriConfig,
+    log_blowup: usize,
+    num_queries: usize,
+    _marker: PhantomData<fn(F) -> F>,
+}
+
+impl<F: CustomField> Clone for CustomPoseidon2<F> {
+    fn clone(&self) -> Self {
+        Self {
+            perm: self.perm.clone(),
+            simple_fri_config: self.simple_fri_config,
+            log_blowup: self.log_blowup,
+            num_queries: self.num_queries,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: CustomField> Serialize for CustomPoseidon2<F> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        PhantomData::<CustomPoseidon2<F>>.serialize(serializer)
+    }
+}
+
+impl<F: CustomField> Default for CustomPoseidon2<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: CustomField> Poseidon2Init for CustomPoseidon2<F> {
+    type Poseidon2 = CustomPerm<F>;
+    fn init() -> Self::Poseidon2 {
+        F::init()
+    }
+}
+
+impl<F: CustomField> StarkGenericConfig for CustomPoseidon2<F> {
+    type Val = F;
+    type Domain = <CustomPcs<F> as Pcs<CustomChallenge<F>, CustomChallenger<F>>>::Domain;
+    type Challenge = CustomChallenge<F>;
+    type Challenger = CustomChallenger<F>;
+    type Pcs = CustomPcs<F>;
+
+    /// Targeting 100 bits of security.
+    fn new() -> Self {
+        Self::with_fri_params(F::init(), 1, 84)
+    }
+
+    fn pcs(&self) -> Self::Pcs {
+        let hash = CustomHash::<F>::new(self.perm.clone());
+        let compress = CustomCompress::<F>::new(self.perm.clone());
+        let val_mmcs = CustomValMmcs::<F>::new(hash, compress);
+        let fri_config = FriConfig {
+            log_blowup: self.log_blowup,
+            num_queries: self.num_queries,
+            proof_of_work_bits: 16,
+            mmcs: CustomChallengeMmcs::<F>::new(val_mmcs.clone()),
+        };
+        CustomPcs::<F>::new(CustomDft::<F>::default(), val_mmcs, fri_config)
+    }
+
+    fn challenger(&self) -> Self::Challenger {
+        CustomChallenger::<F>::new(self.perm.clone())
+    }
+
+    fn name(&self) -> String {
+        format!("CustomPoseidon2<{}>", type_name::<F>())
+    }
+
+    fn hash_slice(&self, input: &[Val<Self>]) -> [Val<Self>; DIGEST_SIZE] {
+        let hash = CustomHash::<F>::new(self.perm.clone());
+        hash.hash_slice(input)
+    }
+}
+
+impl<F: CustomField> CustomPoseidon2<F> {
+    /// Targeting 100 bits of security.
+    pub fn compress() -> Self {
+        Self::with_fri_params(F::init(), 3, 28)
+    }
+
+    /// The config of [`StarkGenericConfig::new`] with a permutation built from custom round
+    /// constants instead of the defaults of `F`, e.g. to match the Poseidon2 instance of another
+    /// system. The number of constants must match the round numbers of `F`.
+    ///
+    /// The Poseidon2 chips and the recursion circuits are built for the default constants, so this
+    /// only applies to plain AIRs.
+    pub fn with_round_constants(
+        external_round_constants: ExternalLayerConstants<F, PERMUTATION_WIDTH>,
+        internal_round_constants: Vec<F>,
+    ) -> Result<Self>
+    where
+        F: Poseidon2Constants,
+    {
+        let perm =
+            pico_poseidon2_with_constants::<F>(external_round_constants, internal_round_constants)?;
+        Ok(Self::with_fri_params(perm, 1, 84))
+    }
+
+    /// The number of queries can be overridden with the `FRI_QUERIES` environment variable, as
+    /// for the shipped configs.
+    fn with_fri_params(perm: CustomPerm<F>, log_blowup: usize, default_num_queries: usize) -> Self {
+        let num_queries = match std::env::var("FRI_QUERIES") {
+            Ok(num_queries) => num_queries.parse().unwrap(),
+            Err(_) => default_num_queries,
+        };
+        let simple_fri_config = SimpleFriConfig {
+            log_blowup,
+            num_queries,
+            proof_of_work_bits: 16,
+        };
+
+        Self {
+            perm,
+            simple_fri_config,
+            log_blowup,
+            num_queries,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn fri_config(&self) -> &SimpleFriConfig {
+        &self.simple_fri_config
+    }
+}
+
+impl<F: CustomField> ZeroCommitment<CustomPoseidon2<F>> for CustomPcs<F> {
+    fn zero_commitment(&self) -> Com<CustomPoseidon2<F>> {
+        CustomDigestHash::<F>::from([F::ZERO; DIGEST_SIZE])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        configs::stark_config::KoalaBearPoseidon2,
+        primitives::{consts::KOALABEAR_NUM_INTERNAL_ROUNDS, RC_16_30_KoalaBear},
+    };
+    use p3_challenger::{CanObserve, FieldChallenger};
+    use p3_koala_bear::KoalaBear;
+    use p3_matrix::dense::RowMajorMatrix;
+
+    type F = KoalaBear;
+    type SC = CustomPoseidon2<F>;
+
+    #[test]
+    fn test_custom_poseidon2_matches_shipped_config() {
+        let input = (0..20).map(F::from_canonical_u32).collect::<Vec<_>>();
+        let custom = SC::new();
+        let shipped = KoalaBearPoseidon2::new();
+        assert_eq!(custom.hash_slice(&input), shipped.hash_slice(&input));
+
+        let mut custom_challenger = custom.challenger();
+        let mut shipped_challenger = shipped.challenger();
+        custom_challenger.observe_slice(&input);
+        shipped_challenger.observe_slice(&input);
+        assert_eq!(
+            custom_challenger.sample_ext_element::<CustomChallenge<F>>(),
+            shipped_challenger.sample_ext_element::<CustomChallenge<F>>()
+        );
+    }
+
+    #[test]
+    fn test_custom_poseidon2_round_constants() {
+        let input = (0..20).map(F::from_canonical_u32).collect::<Vec<_>>();
+        let constants = |offset: u32| {
+            let round_constants = RC_16_30_KoalaBear
+                .iter()
+                .map(|row| row.map(|c| c + F::from_canonical_u32(offset)))
+                .collect::<Vec<_>>();
+            let rounds_f = F::NUM_EXTERNAL_ROUNDS;
+            let internal = round_constants[rounds_f / 2..rounds_f / 2 + F::NUM_INTERNAL_ROUNDS]
+                .iter()
+                .map(|row| row[0])
+                .collect::<Vec<_>>();
+            let external = ExternalLayerConstants::new(
+                round_constants[..rounds_f / 2].to_vec(),
+                round_constants[rounds_f / 2 + F::NUM_INTERNAL_ROUNDS..][..rounds_f / 2].to_vec(),
+            );
+            (external, internal)
+        };
+
+        // The default constants give back the default config.
+        let (external, internal) = constants(0);
+        let config = SC::with_round_constants(external, internal).unwrap();
+        assert_eq!(config.hash_slice(&input), SC::new().hash_slice(&input));
+
+        let (external, internal) = constants(1);
+        let config = SC::with_round_constants(external, internal).unwrap();
+        assert_ne!(config.hash_slice(&input), SC::new().hash_slice(&input));
+
+        let (external, mut internal) = constants(0);
+        internal.truncate(KOALABEAR_NUM_INTERNAL_ROUNDS - 1);
+        assert!(SC::with_round_constants(external, internal).is_err());
+    }
+
+    #[test]
+    fn test_custom_poseidon2_pcs_open_and_verify() {
+        let config = SC::compress();
+        let pcs = config.pcs();
+
+        let log_height = 6;
+        let width = 4;
+        let values = (0..width << log_height)
+            .map(F::from_canonical_usize)
+            .collect::<Vec<_>>();
+        let trace = RowMajorMatrix::new(values, width);
+        let domain = <CustomPcs<F> as Pcs<CustomChallenge<F>, CustomChallenger<F>>>::natural_domain_for_degree(
+            &pcs,
+            1 << log_height,
+        );
+        let (commit, data) = <CustomPcs<F> as Pcs<CustomChallenge<F>, CustomChallenger<F>>>::commit(
+            &pcs,
+            vec![(domain, trace)],
+        );
+
+        let mut challenger = config.challenger();
+        challenger.observe(commit);
+        let zeta = challenger.sample_ext_element::<CustomChallenge<F>>();
+        let (opened_values, opening_proof) =
+            <CustomPcs<F> as Pcs<CustomChallenge<F>, CustomChallenger<F>>>::open(
+                &pcs,
+                vec![(&data, vec![vec![zeta]])],
+                &mut challenger,
+            );
+
+        let verify = |values: Vec<CustomChallenge<F>>| {
+            let mut challenger = config.challenger();
+            challenger.observe(commit);
+            let zeta = challenger.sample_ext_element::<CustomChallenge<F>>();
+            <CustomPcs<F> as Pcs<CustomChallenge<F>, CustomChallenger<F>>>::verify(
+                &pcs,
+                vec![(commit, vec![(domain, vec![(zeta, values)])])],
+                &opening_proof,
+                &mut challenger,
+            )
+        };
+        let values = opened_values[0][0][0].clone();
+        assert!(verify(values.clone()).is_ok());
+
+        let mut tampered = values;
+        tampered[0] += CustomChallenge::<F>::ONE;
+        assert!(verify(tampered).is_err());
+    }
+}
@@ -2,12 +2,14 @@
 
 pub mod bb_bn254_poseidon2;
 pub mod bb_poseidon2;
+pub mod custom_poseidon2;
 pub mod kb_bn254_poseidon2;
 pub mod kb_poseidon2;
 pub mod m31_poseidon2;
 
 pub use bb_bn254_poseidon2::BabyBearBn254Poseidon2;
 pub use bb_poseidon2::BabyBearPoseidon2;
+pub use custom_poseidon2::{CustomField, CustomPoseidon2};
 pub use kb_bn254_poseidon2::KoalaBearBn254Poseidon2;
 pub use kb_poseidon2::KoalaBearPoseidon2;
 pub use m31_poseidon2::M31Poseidon2;
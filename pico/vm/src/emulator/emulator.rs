@@ -59,15 +59,18 @@ where
 {
     pub fn setup_riscv(proving_witness: &ProvingWitness<SC, C, Vec<u8>>) -> Self {
         // create a new emulator based on the emulator type
-        let opts = proving_witness.opts.unwrap();
+        let opts = proving_witness.opts.clone().unwrap();
+        let batch_size = opts.chunk_batch_size;
         let mut emulator =
             RiscvEmulator::new::<SC::Val>(proving_witness.program.clone().unwrap(), opts);
         emulator.write_stdin(proving_witness.stdin.as_ref().unwrap());
+        emulator.vk_digest = proving_witness.vk_digest;
+        emulator.host_call_handlers = proving_witness.host_call_handlers.clone();
 
         Self {
             stdin: proving_witness.stdin.clone().unwrap(),
             emulator: Some(emulator),
-            batch_size: opts.chunk_batch_size,
+            batch_size,
             _sc_and_chip: PhantomData,
         }
     }
@@ -158,7 +161,7 @@ macro_rules! impl_emulator {
                 >,
                 machine: &'a BaseMachine<$recur_sc, C>,
             ) -> Self {
-                let batch_size = match proving_witness.opts {
+                let batch_size = match &proving_witness.opts {
                     Some(opts) => opts.chunk_batch_size,
                     None => 0,
                 };
@@ -249,7 +252,7 @@ macro_rules! impl_emulator {
                 >,
                 machine: &'a BaseMachine<$recur_sc, C>,
             ) -> Self {
-                let batch_size = match proving_witness.opts {
+                let batch_size = match &proving_witness.opts {
                     Some(opts) => opts.chunk_batch_size,
                     None => 0,
                 };
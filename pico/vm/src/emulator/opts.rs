@@ -1,24 +1,46 @@
 use crate::primitives::consts::{
     BENCH_MAX_CHUNK_BATCH_SIZE, BENCH_MAX_CHUNK_SIZE, BENCH_MAX_DEFERRED_SPLIT_THRESHOLD,
-    BENCH_RECURSION_MAX_CHUNK_SIZE, MAX_LOG_NUMBER_OF_CHUNKS, TEST_CHUNK_BATCH_SIZE,
+    BENCH_RECURSION_MAX_CHUNK_SIZE, COMBINE_SIZE, MAX_LOG_NUMBER_OF_CHUNKS, TEST_CHUNK_BATCH_SIZE,
     TEST_CHUNK_SIZE, TEST_DEFERRED_SPLIT_THRESHOLD,
 };
 use serde::{Deserialize, Serialize};
-use std::env;
+use std::{env, ops::Range};
 use sysinfo::System;
 use tracing::debug;
 
 /// Options for the core prover.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EmulatorOpts {
     /// The size of a chunk in terms of cycles.
     pub chunk_size: u32,
+    /// Where chunks end besides the cycle limit.
+    pub chunk_boundary: ChunkBoundary,
     /// The size of a batch of chunks in terms of cycles.
     pub chunk_batch_size: u32,
     /// Options for splitting deferred events.
     pub split_opts: SplitOpts,
     /// The maximum number of cpu cycles to use for emulation.
     pub max_cycles: Option<u64>,
+    /// The values of the registers before the first cycle, instead of zero. `x0` must stay zero.
+    #[serde(default)]
+    pub initial_registers: Option<[u32; 32]>,
+    /// The fuel budget the guest reads with `pico_sdk::io::fuel_remaining`, in cycles. Unlike
+    /// `max_cycles`, running out of fuel does not stop the emulation.
+    #[serde(default)]
+    pub fuel: Option<u64>,
+    /// The number of proofs the combine prover folds into one per recursion layer. A higher arity
+    /// takes fewer layers of larger combine programs. With vk verification it must be the
+    /// `combine_arity` of the recursion shape config, which the vk map is built for.
+    #[serde(default = "default_combine_arity")]
+    pub combine_arity: usize,
+    /// Whether stores into the read-only segments of the program, such as its code, fail the
+    /// emulation with `EmulationError::WriteToReadOnly`. Writes by precompiles are not checked.
+    #[serde(default)]
+    pub read_only_program_memory: bool,
+}
+
+fn default_combine_arity() -> usize {
+    COMBINE_SIZE
 }
 
 impl Default for EmulatorOpts {
@@ -45,9 +67,14 @@ impl Default for EmulatorOpts {
 
         Self {
             chunk_size: default_chunk_size,
+            chunk_boundary: ChunkBoundary::default(),
             chunk_batch_size: default_chunk_batch_size,
             split_opts: SplitOpts::new(split_threshold),
             max_cycles: default_max_cycles.into(),
+            initial_registers: None,
+            fuel: None,
+            combine_arity: COMBINE_SIZE,
+            read_only_program_memory: false,
         }
     }
 }
@@ -114,6 +141,35 @@ impl EmulatorOpts {
     }
 }
 
+/// Where the emulator ends one chunk and starts the next.
+///
+/// A chunk always ends before its clock passes `chunk_size * 4`, so that it fits the trace; the
+/// boundary only adds split points before that.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChunkBoundary {
+    /// Split on the cycle limit alone.
+    #[default]
+    Cycles,
+    /// Also split right before the instruction at any of `split_pcs` runs, so that the new chunk
+    /// starts at that address. Placing these at the head of a hot loop keeps whole iterations,
+    /// and the precompile calls in them, inside one chunk.
+    ///
+    /// The list is scanned on every cycle, so it is meant for a handful of addresses, e.g. ones
+    /// picked from the cycle tracker output.
+    AtProgramCounter { split_pcs: Vec<u32> },
+}
+
+impl ChunkBoundary {
+    /// Whether a new chunk starts at `pc`.
+    #[inline]
+    pub fn splits_at(&self, pc: u32) -> bool {
+        match self {
+            Self::Cycles => false,
+            Self::AtProgramCounter { split_pcs } => split_pcs.contains(&pc),
+        }
+    }
+}
+
 /// Options for splitting deferred events.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SplitOpts {
@@ -127,6 +183,8 @@ pub struct SplitOpts {
     pub sha_compress: usize,
     /// The threshold for memory events.
     pub memory: usize,
+    /// How memory initialize and finalize events are split into chunks.
+    pub memory_strategy: SplitStrategy,
 }
 
 impl SplitOpts {
@@ -139,8 +197,57 @@ impl SplitOpts {
             sha_extend: deferred_shift_threshold / 48,
             sha_compress: deferred_shift_threshold / 80,
             memory: deferred_shift_threshold * 4,
+            memory_strategy: SplitStrategy::default(),
         }
     }
+
+    /// Returns these options with the given strategy for splitting memory events.
+    #[must_use]
+    pub fn with_memory_strategy(mut self, memory_strategy: SplitStrategy) -> Self {
+        self.memory_strategy = memory_strategy;
+        self
+    }
+}
+
+/// How the sorted memory initialize and finalize events are split into chunks of at most
+/// [`SplitOpts::memory`] events of each kind.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SplitStrategy {
+    /// Fill each chunk with as many events as the threshold allows, so the last chunk holds the
+    /// remainder.
+    #[default]
+    ByCount,
+    /// Use as few chunks as the total number of initialize and finalize events allows, and
+    /// spread both kinds evenly over them, so each chunk holds about the threshold in total.
+    BySize,
+    /// Use as many chunks as [`SplitStrategy::ByCount`], but spread the events evenly over them.
+    Balanced,
+}
+
+impl SplitStrategy {
+    /// Returns the ranges of initialize and finalize events of each chunk. The ranges are
+    /// contiguous and cover both event lists in order.
+    pub fn memory_chunks(
+        self,
+        num_init: usize,
+        num_finalize: usize,
+        threshold: usize,
+    ) -> Vec<(Range<usize>, Range<usize>)> {
+        let num_chunks = match self {
+            Self::ByCount | Self::Balanced => num_init.max(num_finalize).div_ceil(threshold),
+            Self::BySize => (num_init + num_finalize)
+                .div_ceil(threshold)
+                .max(num_init.max(num_finalize).div_ceil(threshold)),
+        };
+
+        let ranges = |len: usize, i: usize| match self {
+            Self::ByCount => (i * threshold).min(len)..((i + 1) * threshold).min(len),
+            Self::BySize | Self::Balanced => i * len / num_chunks..(i + 1) * len / num_chunks,
+        };
+        (0..num_chunks)
+            .map(|i| (ranges(num_init, i), ranges(num_finalize, i)))
+            .collect()
+    }
 }
 
 #[allow(clippy::cast_precision_loss)]
@@ -161,3 +268,34 @@ fn chunk_batch_size(total_available_mem: u64) -> u32 {
         _ => BENCH_MAX_CHUNK_BATCH_SIZE,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balanced_split_reduces_max_chunk() {
+        let max_chunk = |strategy: SplitStrategy, num_init, num_finalize| {
+            let chunks = strategy.memory_chunks(num_init, num_finalize, 100);
+            // The chunks cover both lists in order.
+            let (mut init_end, mut finalize_end) = (0, 0);
+            for (init, finalize) in &chunks {
+                assert_eq!(init.start, init_end);
+                assert_eq!(finalize.start, finalize_end);
+                (init_end, finalize_end) = (init.end, finalize.end);
+            }
+            assert_eq!((init_end, finalize_end), (num_init, num_finalize));
+
+            chunks
+                .iter()
+                .map(|(init, finalize)| init.len().max(finalize.len()))
+                .max()
+                .unwrap_or(0)
+        };
+
+        assert_eq!(max_chunk(SplitStrategy::ByCount, 201, 20), 100);
+        assert_eq!(max_chunk(SplitStrategy::Balanced, 201, 20), 67);
+        assert!(max_chunk(SplitStrategy::BySize, 201, 20) <= 100);
+        assert_eq!(max_chunk(SplitStrategy::Balanced, 0, 0), 0);
+    }
+}
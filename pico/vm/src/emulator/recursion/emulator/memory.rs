@@ -2,6 +2,7 @@ use std::iter::repeat;
 
 use crate::compiler::recursion::{ir::Block, types::Address};
 use p3_field::PrimeField64;
+use thiserror::Error;
 use vec_map::{Entry, VecMap};
 
 #[derive(Debug, Clone, Default)]
@@ -10,6 +11,14 @@ pub struct MemoryEntry<F> {
     pub mult: F,
 }
 
+/// A write to an address at or above the memory limit of the runtime.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("attempted to write to address {addr}, beyond the memory limit of {limit} entries")]
+pub struct MemoryOverflow {
+    pub addr: usize,
+    pub limit: usize,
+}
+
 pub trait Memory<F> {
     /// Allocates memory with at least the given capacity.
     fn with_capacity(capacity: usize) -> Self;
@@ -28,17 +37,31 @@ pub trait Memory<F> {
 
     /// Write to a memory address, setting the given value and mult.
     ///
+    /// Returns an error if the address is beyond the memory limit, if there is one.
+    ///
     /// # Panics
     /// Panics if the address is already assigned.
-    fn mw(&mut self, addr: Address<F>, val: Block<F>, mult: F) -> &mut MemoryEntry<F>;
+    fn mw(
+        &mut self,
+        addr: Address<F>,
+        val: Block<F>,
+        mult: F,
+    ) -> Result<&mut MemoryEntry<F>, MemoryOverflow>;
 }
 
 #[derive(Clone, Debug, Default)]
-pub struct MemVecMap<F>(pub VecMap<MemoryEntry<F>>);
+pub struct MemVecMap<F> {
+    pub entries: VecMap<MemoryEntry<F>>,
+    /// Writes to addresses at or above the limit fail instead of growing the map.
+    pub limit: Option<usize>,
+}
 
 impl<F: PrimeField64> Memory<F> for MemVecMap<F> {
     fn with_capacity(capacity: usize) -> Self {
-        Self(VecMap::with_capacity(capacity))
+        Self {
+            entries: VecMap::with_capacity(capacity),
+            limit: None,
+        }
     }
 
     fn mr(&mut self, addr: Address<F>) -> &mut MemoryEntry<F> {
@@ -46,7 +69,7 @@ impl<F: PrimeField64> Memory<F> for MemVecMap<F> {
     }
 
     fn mr_mult(&mut self, addr: Address<F>, mult: F) -> &mut MemoryEntry<F> {
-        match self.0.entry(addr.as_usize()) {
+        match self.entries.entry(addr.as_usize()) {
             Entry::Occupied(mut entry) => {
                 let entry_mult = &mut entry.get_mut().mult;
                 *entry_mult -= mult;
@@ -56,9 +79,17 @@ impl<F: PrimeField64> Memory<F> for MemVecMap<F> {
         }
     }
 
-    fn mw(&mut self, addr: Address<F>, val: Block<F>, mult: F) -> &mut MemoryEntry<F> {
+    fn mw(
+        &mut self,
+        addr: Address<F>,
+        val: Block<F>,
+        mult: F,
+    ) -> Result<&mut MemoryEntry<F>, MemoryOverflow> {
         let index = addr.as_usize();
-        match self.0.entry(index) {
+        if let Some(limit) = self.limit.filter(|&limit| index >= limit) {
+            return Err(MemoryOverflow { addr: index, limit });
+        }
+        match self.entries.entry(index) {
             Entry::Occupied(entry) => {
                 panic!(
                     "tried to write to assigned address {}: {:?}",
@@ -66,7 +97,7 @@ impl<F: PrimeField64> Memory<F> for MemVecMap<F> {
                     entry.get()
                 )
             }
-            Entry::Vacant(entry) => entry.insert(MemoryEntry { val, mult }),
+            Entry::Vacant(entry) => Ok(entry.insert(MemoryEntry { val, mult })),
         }
     }
 }
@@ -96,7 +127,12 @@ impl<F: PrimeField64> Memory<F> for MemVec<F> {
         }
     }
 
-    fn mw(&mut self, addr: Address<F>, val: Block<F>, mult: F) -> &mut MemoryEntry<F> {
+    fn mw(
+        &mut self,
+        addr: Address<F>,
+        val: Block<F>,
+        mult: F,
+    ) -> Result<&mut MemoryEntry<F>, MemoryOverflow> {
         let addr_usize = addr.as_usize();
         self.0
             .extend(repeat(None).take((addr_usize + 1).saturating_sub(self.0.len())));
@@ -105,7 +141,30 @@ impl<F: PrimeField64> Memory<F> for MemVec<F> {
                 "tried to write to assigned address: {entry:?}\nbacktrace: {:?}",
                 backtrace::Backtrace::new()
             ),
-            entry @ None => entry.insert(MemoryEntry { val, mult }),
+            entry @ None => Ok(entry.insert(MemoryEntry { val, mult })),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p3_baby_bear::BabyBear;
+    use p3_field::FieldAlgebra;
+
+    #[test]
+    fn test_mem_vec_map_limit() {
+        let mut memory = MemVecMap::<BabyBear>::with_capacity(4);
+        memory.limit = Some(4);
+        let addr = |i| Address(BabyBear::from_canonical_usize(i));
+
+        assert!(memory.mw(addr(3), Block::default(), BabyBear::ONE).is_ok());
+        assert_eq!(
+            memory
+                .mw(addr(4), Block::default(), BabyBear::ONE)
+                .unwrap_err(),
+            MemoryOverflow { addr: 4, limit: 4 }
+        );
+        assert!(!memory.entries.contains_key(4));
+    }
+}
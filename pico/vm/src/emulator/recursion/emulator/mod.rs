@@ -42,9 +42,21 @@ use crate::{
     },
     primitives::consts::{PERMUTATION_WIDTH, RECURSION_NUM_PVS},
 };
+pub use memory::MemoryOverflow;
 use memory::*;
 pub use opcode::*;
 
+/// Memory usage of a recursion program run, to help size `RecursionProgram::total_memory`.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryProfile {
+    /// The largest number of assigned memory entries at any point of the run.
+    pub peak_entries: usize,
+    /// An estimate of the bytes held by the memory map at its peak.
+    pub peak_bytes_estimate: usize,
+    /// The number of executed operations of each kind.
+    pub ops_by_type: HashMap<&'static str, usize>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct CycleTrackerEntry {
     pub span_entered: bool,
@@ -142,6 +154,8 @@ pub enum RuntimeError<F: Debug, EF: Debug> {
     DebugPrint(#[from] std::io::Error),
     #[error("attempted to read from empty witness stream")]
     EmptyWitnessStream,
+    #[error(transparent)]
+    MemoryOverflow(#[from] MemoryOverflow),
 }
 
 impl<F, EF, ExternalPerm, InternalPerm, const D: u64>
@@ -160,7 +174,8 @@ where
             program: program.clone(),
             ..Default::default()
         };
-        let memory = Memory::with_capacity(program.total_memory);
+        let mut memory = MemVecMap::with_capacity(program.total_memory);
+        memory.limit = (program.total_memory > 0).then_some(program.total_memory);
         Self {
             timestamp: 0,
             nb_poseidons: 0,
@@ -188,6 +203,14 @@ where
         }
     }
 
+    /// Limit the memory of the run to addresses below `limit`, in place of the
+    /// `total_memory` of the program. A write to any other address makes [`Runtime::run`] fail
+    /// with [`RuntimeError::MemoryOverflow`] rather than grow the memory map, which guards
+    /// against malformed programs.
+    pub fn set_memory_limit(&mut self, limit: usize) {
+        self.memory.limit = Some(limit);
+    }
+
     pub fn print_stats(&self) {
         // print all stats
         tracing::info!("   |- {:<26}: {}", "Total Cycles:", self.timestamp);
@@ -211,11 +234,66 @@ where
         tracing::info!("   |- {:<26}: {}", "Memory Operations:", self.nb_memory_ops);
         tracing::info!("   |- {:<26}: {}", "Branch Operations:", self.nb_branch_ops);
 
+        let program_stats = self.program.statistics();
+        tracing::info!(
+            "   |- {:<26}: {}",
+            "Program Instructions:",
+            program_stats.total_instructions
+        );
+        for (name, count) in program_stats
+            .by_type
+            .iter()
+            .sorted_by_key(|(name, _)| **name)
+        {
+            tracing::info!("   |  |- {:<23}: {}", name, count);
+        }
+        tracing::info!(
+            "   |- {:<26}: {}",
+            "Max Memory Address:",
+            program_stats.max_memory_address
+        );
+        tracing::info!(
+            "   |- {:<26}: {}",
+            "Dead Instructions:",
+            self.program.dead_code_analysis().len()
+        );
+
         for (name, entry) in self.cycle_tracker.iter().sorted_by_key(|(name, _)| *name) {
             tracing::info!("> {}: {}", name, entry.cumulative_cycles);
         }
     }
 
+    /// The memory usage of the run so far, usually called after [`Runtime::run`].
+    ///
+    /// Memory entries are written once and never freed, so the peak number of entries is the
+    /// current one. The byte estimate covers the backing vector of the memory map, which grows
+    /// with the highest written address rather than with the number of entries.
+    pub fn profile_memory_usage(&self) -> MemoryProfile {
+        let ops_by_type = [
+            ("Poseidon2", self.nb_poseidons),
+            ("Wide Poseidon2", self.nb_wide_poseidons),
+            ("Bit Decomposition", self.nb_bit_decompositions),
+            ("Extension ALU", self.nb_ext_ops),
+            ("Base ALU", self.nb_base_ops),
+            ("Memory", self.nb_memory_ops),
+            ("Branch", self.nb_branch_ops),
+            ("Select", self.nb_select),
+            ("Exp Reverse Bits", self.nb_exp_reverse_bits),
+            ("BatchFRI", self.nb_batch_fri),
+            ("Print Felt", self.nb_print_f),
+            ("Print Ext", self.nb_print_e),
+        ]
+        .into_iter()
+        .collect();
+
+        MemoryProfile {
+            peak_entries: self.memory.entries.len(),
+            peak_bytes_estimate: self.memory.entries.capacity()
+                * std::mem::size_of::<Option<MemoryEntry<F>>>(),
+            ops_by_type,
+        }
+    }
+
     fn nearest_pc_backtrace(&mut self) -> Option<(usize, Trace)> {
         let trap_pc = self.pc.as_canonical_u32() as usize;
         let trace = self.program.traces.get(trap_pc).cloned()?;
@@ -278,7 +356,7 @@ where
                             }
                         },
                     };
-                    self.memory.mw(addrs.out, Block::from(out), mult);
+                    self.memory.mw(addrs.out, Block::from(out), mult)?;
                     self.record
                         .base_alu_events
                         .push(BaseAluEvent { out, in1, in2 });
@@ -320,7 +398,7 @@ where
                         },
                     };
                     let out = Block::from(out_ef.as_base_slice());
-                    self.memory.mw(addrs.out, out, mult);
+                    self.memory.mw(addrs.out, out, mult)?;
                     self.record
                         .ext_alu_events
                         .push(ExtAluEvent { out, in1, in2 });
@@ -340,7 +418,7 @@ where
                                 "stored memory value should be the specified value"
                             );
                         }
-                        MemAccessKind::Write => drop(self.memory.mw(addr, val, mult)),
+                        MemAccessKind::Write => drop(self.memory.mw(addr, val, mult)?),
                     }
                     self.record.mem_const_count += 1;
                 }
@@ -353,13 +431,9 @@ where
                     let in_vals = std::array::from_fn(|i| self.memory.mr(input[i]).val[0]);
                     let perm_output = self.perm.as_ref().unwrap().permute(in_vals);
 
-                    perm_output
-                        .iter()
-                        .zip(output)
-                        .zip(mults)
-                        .for_each(|((&val, addr), mult)| {
-                            self.memory.mw(addr, Block::from(val), mult);
-                        });
+                    for ((&val, addr), mult) in perm_output.iter().zip(output).zip(mults) {
+                        self.memory.mw(addr, Block::from(val), mult)?;
+                    }
                     self.record.poseidon2_events.push(Poseidon2Event {
                         input: in_vals,
                         output: perm_output,
@@ -383,8 +457,8 @@ where
                     let in2 = self.memory.mr(in2).val[0];
                     let out1_val = bit * in2 + (F::ONE - bit) * in1;
                     let out2_val = bit * in1 + (F::ONE - bit) * in2;
-                    self.memory.mw(out1, Block::from(out1_val), mult1);
-                    self.memory.mw(out2, Block::from(out2_val), mult2);
+                    self.memory.mw(out1, Block::from(out1_val), mult1)?;
+                    self.memory.mw(out2, Block::from(out2_val), mult2)?;
                     self.record.select_events.push(SelectEvent {
                         bit,
                         out1: out1_val,
@@ -407,7 +481,7 @@ where
                         .fold(0, |acc, (i, &val)| acc + val.as_canonical_u32() * (1 << i));
                     let out =
                         base_val.exp_u64(reverse_bits_len(exp_val as usize, exp_bits.len()) as u64);
-                    self.memory.mw(result, Block::from(out), mult);
+                    self.memory.mw(result, Block::from(out), mult)?;
                     self.record
                         .exp_reverse_bits_len_events
                         .push(ExpReverseBitsEvent {
@@ -428,7 +502,7 @@ where
                         .collect::<Vec<_>>();
                     // Write the bits to the array at dst.
                     for (bit, (addr, mult)) in bits.into_iter().zip(output_addrs_mults) {
-                        self.memory.mw(addr, bit, mult);
+                        self.memory.mw(addr, bit, mult)?;
                         self.record.mem_var_events.push(MemEvent { inner: bit });
                     }
                 }
@@ -473,11 +547,11 @@ where
                         });
                     }
 
-                    let _ = self.memory.mw(
+                    self.memory.mw(
                         ext_single_addrs.acc,
                         Block::from(acc.as_base_slice()),
                         acc_mult,
-                    );
+                    )?;
                 }
 
                 Instruction::CommitPublicValues(instr) => {
@@ -526,7 +600,7 @@ where
                     for (val, (addr, mult)) in
                         output.x.0.into_iter().zip(output_x_addrs_mults.into_iter())
                     {
-                        self.memory.mw(addr, Block::from(val), mult);
+                        self.memory.mw(addr, Block::from(val), mult)?;
                         self.record.mem_var_events.push(MemEvent {
                             inner: Block::from(val),
                         });
@@ -534,7 +608,7 @@ where
                     for (val, (addr, mult)) in
                         output.y.0.into_iter().zip(output_y_addrs_mults.into_iter())
                     {
-                        self.memory.mw(addr, Block::from(val), mult);
+                        self.memory.mw(addr, Block::from(val), mult)?;
                         self.record.mem_var_events.push(MemEvent {
                             inner: Block::from(val),
                         });
@@ -566,7 +640,7 @@ where
                     // Write the bits to the array at dst.
                     for (f, (addr, mult)) in fs.into_iter().zip(output_addrs_mults) {
                         let felt = Block::from(f);
-                        self.memory.mw(addr, felt, mult);
+                        self.memory.mw(addr, felt, mult)?;
                         self.record.mem_var_events.push(MemEvent { inner: felt });
                     }
                 }
@@ -578,7 +652,7 @@ where
                     let witness = self.witness_stream.drain(0..output_addrs_mults.len());
                     for ((addr, mult), val) in zip(output_addrs_mults, witness) {
                         // Inline [`Self::mw`] to mutably borrow multiple fields of `self`.
-                        self.memory.mw(addr, val, mult);
+                        self.memory.mw(addr, val, mult)?;
                         self.record.mem_var_events.push(MemEvent { inner: val });
                     }
                 }
@@ -595,3 +669,39 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        compiler::recursion::instruction::mem,
+        primitives::{consts::BABYBEAR_S_BOX_DEGREE, Poseidon2Init},
+    };
+    use p3_baby_bear::BabyBear;
+    use p3_field::extension::BinomialExtensionField;
+
+    #[test]
+    fn test_memory_limit_from_program() {
+        // The second write is past the memory of the program.
+        let program = RecursionProgram::<BabyBear> {
+            instructions: vec![
+                mem(MemAccessKind::Write, 1, 0, 5),
+                mem(MemAccessKind::Write, 1, 2, 5),
+            ],
+            total_memory: 2,
+            ..Default::default()
+        };
+        let mut runtime =
+            Runtime::<_, BinomialExtensionField<BabyBear, 4>, _, _, BABYBEAR_S_BOX_DEGREE>::new(
+                Arc::new(program),
+                BabyBear::init(),
+            );
+
+        match runtime.run() {
+            Err(RuntimeError::MemoryOverflow(overflow)) => {
+                assert_eq!(overflow, MemoryOverflow { addr: 2, limit: 2 })
+            }
+            result => panic!("expected a memory overflow, got {result:?}"),
+        }
+    }
+}
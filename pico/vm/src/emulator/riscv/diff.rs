@@ -0,0 +1,266 @@
+//! Structured comparison of two emulation records, for chasing down nondeterminism.
+
+use super::{public_values::PublicValues, record::EmulationRecord};
+use crate::chips::chips::{
+    riscv_cpu::event::CpuEvent,
+    riscv_memory::event::{MemoryInitializeFinalizeEvent, MemoryLocalEvent},
+};
+use std::{collections::BTreeMap, fmt};
+
+/// The first cpu event at which two records differ.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuDivergence {
+    /// The index of the event in `cpu_events`, i.e. the cycle within the chunk.
+    pub index: usize,
+    /// The pc of the divergent event, taken from the left record if it has one.
+    pub pc: u32,
+    /// The clk of the divergent event, taken from the left record if it has one.
+    pub clk: u32,
+    /// The event of the left record, `None` if its trace is shorter.
+    pub left: Option<CpuEvent>,
+    /// The event of the right record, `None` if its trace is shorter.
+    pub right: Option<CpuEvent>,
+}
+
+/// A memory event that differs between two records, or is only in one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemoryEventDiff {
+    Initialize {
+        addr: u32,
+        left: Option<MemoryInitializeFinalizeEvent>,
+        right: Option<MemoryInitializeFinalizeEvent>,
+    },
+    Finalize {
+        addr: u32,
+        left: Option<MemoryInitializeFinalizeEvent>,
+        right: Option<MemoryInitializeFinalizeEvent>,
+    },
+    Local {
+        addr: u32,
+        left: Option<MemoryLocalEvent>,
+        right: Option<MemoryLocalEvent>,
+    },
+}
+
+/// A public value that differs between two records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicValuesMismatch {
+    /// The name of the field in [`PublicValues`].
+    pub field: &'static str,
+    pub left: Vec<u32>,
+    pub right: Vec<u32>,
+}
+
+/// The differences between two emulation records, see [`EmulationRecord::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordDiff {
+    pub cpu: Option<CpuDivergence>,
+    /// Ordered by kind, then by address.
+    pub memory: Vec<MemoryEventDiff>,
+    pub public_values: Vec<PublicValuesMismatch>,
+}
+
+impl RecordDiff {
+    pub fn is_empty(&self) -> bool {
+        self.cpu.is_none() && self.memory.is_empty() && self.public_values.is_empty()
+    }
+}
+
+impl EmulationRecord {
+    /// Compares the cpu events, the memory initialize, finalize and cpu local events, and the
+    /// public values of two records.
+    ///
+    /// Only the first divergent cpu event is reported, since every later one usually differs as
+    /// well. Memory events are matched by address.
+    pub fn diff(&self, other: &EmulationRecord) -> RecordDiff {
+        let num_cpu_events = self.cpu_events.len().max(other.cpu_events.len());
+        let cpu = (0..num_cpu_events).find_map(|index| {
+            let left = self.cpu_events.get(index).copied();
+            let right = other.cpu_events.get(index).copied();
+            if left == right {
+                return None;
+            }
+            let event = left.or(right).unwrap();
+            Some(CpuDivergence {
+                index,
+                pc: event.pc,
+                clk: event.clk,
+                left,
+                right,
+            })
+        });
+
+        let mut memory = Vec::new();
+        memory.extend(
+            diff_by_addr(
+                &self.memory_initialize_events,
+                &other.memory_initialize_events,
+                |event| event.addr,
+            )
+            .map(|(addr, left, right)| MemoryEventDiff::Initialize {
+                addr,
+                left,
+                right,
+            }),
+        );
+        memory.extend(
+            diff_by_addr(
+                &self.memory_finalize_events,
+                &other.memory_finalize_events,
+                |event| event.addr,
+            )
+            .map(|(addr, left, right)| MemoryEventDiff::Finalize { addr, left, right }),
+        );
+        memory.extend(
+            diff_by_addr(
+                &self.cpu_local_memory_access,
+                &other.cpu_local_memory_access,
+                |event| event.addr,
+            )
+            .map(|(addr, left, right)| MemoryEventDiff::Local { addr, left, right }),
+        );
+
+        RecordDiff {
+            cpu,
+            memory,
+            public_values: diff_public_values(&self.public_values, &other.public_values),
+        }
+    }
+}
+
+/// The events of either side that have no equal event at the same address on the other side.
+fn diff_by_addr<E: Clone + PartialEq>(
+    left: &[E],
+    right: &[E],
+    addr: impl Fn(&E) -> u32,
+) -> impl Iterator<Item = (u32, Option<E>, Option<E>)> {
+    let mut events: BTreeMap<u32, (Option<E>, Option<E>)> = BTreeMap::new();
+    for event in left {
+        events.entry(addr(event)).or_default().0 = Some(event.clone());
+    }
+    for event in right {
+        events.entry(addr(event)).or_default().1 = Some(event.clone());
+    }
+    events
+        .into_iter()
+        .filter(|(_, (left, right))| left != right)
+        .map(|(addr, (left, right))| (addr, left, right))
+}
+
+fn diff_public_values(
+    left: &PublicValues<u32, u32>,
+    right: &PublicValues<u32, u32>,
+) -> Vec<PublicValuesMismatch> {
+    let mut mismatches = Vec::new();
+    let mut compare = |field: &'static str, left: &[u32], right: &[u32]| {
+        if left != right {
+            mismatches.push(PublicValuesMismatch {
+                field,
+                left: left.to_vec(),
+                right: right.to_vec(),
+            });
+        }
+    };
+    macro_rules! compare_fields {
+        ($($field:ident),*) => {
+            $(compare(
+                stringify!($field),
+                core::slice::from_ref(&left.$field),
+                core::slice::from_ref(&right.$field),
+            );)*
+        };
+        ($($field:ident),* ;array) => {
+            $(compare(stringify!($field), &left.$field, &right.$field);)*
+        };
+    }
+    compare_fields!(committed_value_digest; array);
+    compare_fields!(start_pc, next_pc, exit_code, chunk, execution_chunk);
+    compare_fields!(
+        previous_initialize_addr_bits,
+        last_initialize_addr_bits,
+        previous_finalize_addr_bits,
+        last_finalize_addr_bits;
+        array
+    );
+    mismatches
+}
+
+impl fmt::Display for RecordDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "records are identical");
+        }
+        if let Some(cpu) = &self.cpu {
+            writeln!(
+                f,
+                "first divergent cpu event #{} at pc {:#010x}, clk {}:",
+                cpu.index, cpu.pc, cpu.clk
+            )?;
+            writeln!(f, "  left:  {:?}", cpu.left)?;
+            writeln!(f, "  right: {:?}", cpu.right)?;
+        }
+        for diff in &self.memory {
+            match diff {
+                MemoryEventDiff::Initialize { addr, left, right } => writeln!(
+                    f,
+                    "memory initialize at {:#010x}: {:?} != {:?}",
+                    addr, left, right
+                )?,
+                MemoryEventDiff::Finalize { addr, left, right } => writeln!(
+                    f,
+                    "memory finalize at {:#010x}: {:?} != {:?}",
+                    addr, left, right
+                )?,
+                MemoryEventDiff::Local { addr, left, right } => writeln!(
+                    f,
+                    "local memory at {:#010x}: {:?} != {:?}",
+                    addr, left, right
+                )?,
+            }
+        }
+        for mismatch in &self.public_values {
+            writeln!(
+                f,
+                "public value {}: {:?} != {:?}",
+                mismatch.field, mismatch.left, mismatch.right
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::{
+            opts::EmulatorOpts,
+            riscv::{emulator::RiscvEmulator, record::EmulationRecord},
+        },
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+
+    fn run(imm: u32) -> EmulationRecord {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, 7, false, true),
+            Instruction::new(Opcode::ADD, 6, 0, imm, false, true),
+            Instruction::new(Opcode::SW, 6, 0, 0x1000, false, true),
+        ];
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::test_opts());
+        emulator.run(None).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_record_diff() {
+        let (left, right) = (run(1), run(2));
+        assert!(left.diff(&run(1)).is_empty());
+
+        let diff = left.diff(&right);
+        let cpu = diff.cpu.unwrap();
+        assert_eq!((cpu.index, cpu.pc), (1, 4));
+        assert_eq!(cpu.left.unwrap().a, 1);
+        assert_eq!(cpu.right.unwrap().a, 2);
+    }
+}
@@ -36,4 +36,16 @@ pub enum EmulationError {
     /// The emulation ended in unconstrained mode
     #[error("ended in unconstrained mode")]
     UnconstrainedEnd,
+
+    /// The emulation failed with a store into read-only program memory.
+    #[error("write to read-only program memory at address {addr:#010x} from pc {pc:#010x}")]
+    WriteToReadOnly { addr: u32, pc: u32 },
+
+    /// The emulation failed with a syscall rejecting its arguments.
+    #[error("syscall {0:#x} failed: {1}")]
+    SyscallFailed(u32, String),
+
+    /// The program has already finished, so there is nothing left to step through.
+    #[error("program already finished")]
+    ProgramFinished,
 }
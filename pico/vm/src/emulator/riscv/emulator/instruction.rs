@@ -213,6 +213,7 @@ impl RiscvEmulator {
             // Store instructions.
             Opcode::SB => {
                 (a, b, c, addr, memory_read_value) = self.store_rr(instruction);
+                self.check_writable(addr)?;
                 let value = match addr % 4 {
                     0 => (a & 0x0000_00FF) + (memory_read_value & 0xFFFF_FF00),
                     1 => ((a & 0x0000_00FF) << 8) + (memory_read_value & 0xFFFF_00FF),
@@ -225,6 +226,7 @@ impl RiscvEmulator {
             }
             Opcode::SH => {
                 (a, b, c, addr, memory_read_value) = self.store_rr(instruction);
+                self.check_writable(addr)?;
                 if addr % 2 != 0 {
                     return Err(EmulationError::InvalidMemoryAccess(Opcode::SH, addr));
                 }
@@ -238,6 +240,7 @@ impl RiscvEmulator {
             }
             Opcode::SW => {
                 (a, b, c, addr, _) = self.store_rr(instruction);
+                self.check_writable(addr)?;
                 if addr % 4 != 0 {
                     return Err(EmulationError::InvalidMemoryAccess(Opcode::SW, addr));
                 }
@@ -319,6 +322,7 @@ impl RiscvEmulator {
                 let syscall = SyscallCode::from_u32(syscall_id);
 
                 self.mode.check_unconstrained_syscall(syscall)?;
+                self.state.invoked_syscalls.insert(syscall);
 
                 // Update the syscall counts.
                 let syscall_for_count = syscall.count_map();
@@ -343,6 +347,9 @@ impl RiscvEmulator {
                         // register. If it returns None, we just keep the
                         // syscall_id in t0.
                         let res = syscall_impl.emulate(&mut precompile_rt, syscall, b, c);
+                        if let Some(reason) = precompile_rt.error.take() {
+                            return Err(EmulationError::SyscallFailed(syscall_id, reason));
+                        }
                         if let Some(val) = res {
                             a = val;
                         } else {
@@ -0,0 +1,88 @@
+use super::RiscvEmulator;
+use crate::compiler::riscv::register::Register;
+use serde::{Deserialize, Serialize};
+
+/// The number of reads and writes of a memory word.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryAccessCount {
+    pub reads: u64,
+    pub writes: u64,
+}
+
+impl MemoryAccessCount {
+    pub fn total(&self) -> u64 {
+        self.reads + self.writes
+    }
+}
+
+impl RiscvEmulator {
+    /// Starts counting the reads and writes of each memory word, for finding hot spots.
+    ///
+    /// All accesses made through the emulator are counted, those of registers and precompiles
+    /// included. Counting works in every mode, so it can be combined with the simple mode to
+    /// avoid the cost of tracing.
+    pub fn count_memory_accesses(&mut self) {
+        self.memory_access_counts
+            .get_or_insert_with(Default::default);
+    }
+
+    /// The `n` most accessed memory words, excluding registers, most accessed first. Ties are
+    /// broken by address.
+    pub fn hottest_memory_words(&self, n: usize) -> Vec<(u32, MemoryAccessCount)> {
+        let Some(counts) = self.memory_access_counts.as_ref() else {
+            return Vec::new();
+        };
+        let num_registers = Register::X31 as u32 + 1;
+        let mut hottest = counts
+            .iter()
+            .filter(|(&addr, _)| addr >= num_registers)
+            .map(|(&addr, &count)| (addr, count))
+            .collect::<Vec<_>>();
+        hottest.sort_unstable_by_key(|&(addr, count)| (std::cmp::Reverse(count.total()), addr));
+        hottest.truncate(n);
+        hottest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryAccessCount;
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::{opts::EmulatorOpts, riscv::emulator::RiscvEmulator},
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_hottest_memory_words() {
+        let mut instructions = vec![Instruction::new(Opcode::LW, 5, 0, 0x1000, false, true); 3];
+        instructions.push(Instruction::new(Opcode::SW, 5, 0, 0x2000, false, true));
+        let program = Arc::new(Program::new(instructions, 0, 0));
+
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::test_opts());
+        emulator.count_memory_accesses();
+        emulator.run(None).unwrap();
+
+        assert_eq!(
+            emulator.hottest_memory_words(50),
+            vec![
+                (
+                    0x1000,
+                    MemoryAccessCount {
+                        reads: 3,
+                        writes: 0
+                    }
+                ),
+                (
+                    0x2000,
+                    MemoryAccessCount {
+                        reads: 0,
+                        writes: 1
+                    }
+                ),
+            ]
+        );
+        assert_eq!(emulator.hottest_memory_words(1).len(), 1);
+    }
+}
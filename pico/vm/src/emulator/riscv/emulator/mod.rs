@@ -1,6 +1,8 @@
 pub mod error;
 pub mod instruction;
+pub mod memtrace;
 pub mod mode;
+pub mod step;
 pub mod unconstrained;
 pub mod util;
 
@@ -18,20 +20,25 @@ use crate::{
             public_values::PublicValues,
             record::{EmulationRecord, MemoryAccessRecord},
             state::RiscvEmulationState,
-            syscalls::{default_syscall_map, Syscall, SyscallCode},
+            syscalls::{
+                default_syscall_map, HostCallHandler, HostCallHandlers, Syscall, SyscallCode,
+            },
         },
     },
-    primitives::Poseidon2Init,
+    primitives::{consts::DIGEST_SIZE, Poseidon2Init},
 };
 use alloc::sync::Arc;
 use hashbrown::{hash_map::Entry, HashMap};
 use p3_field::PrimeField32;
 use p3_symmetric::Permutation;
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
 use tracing::{debug, error, instrument};
 
 pub use error::EmulationError;
+pub use memtrace::MemoryAccessCount;
 pub use mode::RiscvEmulatorMode;
+pub use step::StepInfo;
 pub use unconstrained::UnconstrainedState;
 pub use util::align;
 
@@ -162,6 +169,16 @@ pub struct RiscvEmulator {
     /// Local memory access events.
     pub local_memory_access: HashMap<u32, MemoryLocalEvent>,
 
+    /// The vk digest of the program, returned to the guest by `SELF_VK_DIGEST`.
+    pub vk_digest: Option<[u32; DIGEST_SIZE]>,
+
+    /// The handlers of the requests the guest makes with `HOST_CALL`, by tag.
+    pub host_call_handlers: HostCallHandlers,
+
+    /// The number of accesses of each memory word, if counting is enabled with
+    /// [`RiscvEmulator::count_memory_accesses`].
+    pub memory_access_counts: Option<HashMap<u32, MemoryAccessCount>>,
+
     /// The state for saving the deferred information
     deferred_state: Option<EmulationDeferredState>,
 
@@ -200,21 +217,64 @@ impl RiscvEmulator {
         let hook_map = default_hook_map();
 
         let log_syscalls = std::env::var_os("LOG_SYSCALLS").is_some();
+        let initial_registers = opts.initial_registers;
 
-        Self {
+        let mut state = RiscvEmulationState::new(program.pc_start);
+        state.fuel = opts.fuel;
+
+        let mut emulator = Self {
             syscall_map,
             hook_map,
             memory_accesses: Default::default(),
             record,
-            state: RiscvEmulationState::new(program.pc_start),
+            state,
             program,
             opts,
             max_syscall_cycles,
             local_memory_access: Default::default(),
+            vk_digest: None,
+            host_call_handlers: Default::default(),
+            memory_access_counts: None,
             mode: RiscvEmulatorMode::Trace,
             deferred_state,
             log_syscalls,
+        };
+        if let Some(registers) = initial_registers {
+            emulator.set_registers(registers);
         }
+        emulator
+    }
+
+    /// Sets the values of the registers before the first cycle, instead of zero.
+    ///
+    /// The values are the initial values of the register memory, like those of `HINT_READ`, so
+    /// they end up in the memory initialize events of the proof. `x0` must be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the emulation has already started or if `registers[0]` is not zero.
+    pub fn set_registers(&mut self, registers: [u32; 32]) {
+        assert_eq!(
+            self.state.global_clk, 0,
+            "registers can only be set before the first cycle"
+        );
+        assert_eq!(registers[0], 0, "register x0 must be zero");
+        for (i, value) in registers.into_iter().enumerate().skip(1) {
+            let addr = Register::from_u32(i as u32) as u32;
+            if value == 0 {
+                self.state.uninitialized_memory.remove(&addr);
+            } else {
+                self.state.uninitialized_memory.insert(addr, value);
+            }
+        }
+    }
+
+    /// Answers the `HOST_CALL` requests of the guest with tag `tag` with `handler`, replacing
+    /// any handler registered for it before.
+    ///
+    /// The responses are not constrained by the proof, see `pico_sdk::io::host_call`.
+    pub fn register_host_call(&mut self, tag: u32, handler: impl HostCallHandler + 'static) {
+        self.host_call_handlers.insert(tag, Arc::new(handler));
     }
 
     /// If it's the first cycle, initialize the program.
@@ -250,6 +310,9 @@ impl RiscvEmulator {
 
         // Increment the clock.
         self.state.global_clk += 1;
+        if let Some(fuel) = self.state.fuel.as_mut() {
+            *fuel = fuel.saturating_sub(1);
+        }
 
         if let Some(max_cycles) = self.opts.max_cycles {
             if self.state.global_clk >= max_cycles {
@@ -257,9 +320,7 @@ impl RiscvEmulator {
             }
         }
 
-        let done = self.state.pc == 0
-            || self.state.pc.wrapping_sub(self.program.pc_base)
-                >= (self.program.instructions.len() * 4) as u32;
+        let done = self.is_done();
         if done && self.is_unconstrained() {
             error!(
                 "program ended in unconstrained mode at clk {}",
@@ -269,8 +330,11 @@ impl RiscvEmulator {
         }
 
         if !self.is_unconstrained() {
-            // Check if there's enough cycles or move to the next chunk.
-            if self.state.clk + self.max_syscall_cycles >= self.opts.chunk_size * 4 {
+            // Check if there's enough cycles or move to the next chunk. The next instruction may
+            // also be a configured split point.
+            if self.state.clk + self.max_syscall_cycles >= self.opts.chunk_size * 4
+                || self.opts.chunk_boundary.splits_at(self.state.pc)
+            {
                 self.state.current_chunk += 1;
                 self.state.clk = 0;
 
@@ -281,11 +345,32 @@ impl RiscvEmulator {
         Ok(done)
     }
 
+    /// Whether the program counter has left the program, i.e. the program has finished.
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.state.pc == 0
+            || self.state.pc.wrapping_sub(self.program.pc_base)
+                >= (self.program.instructions.len() * 4) as u32
+    }
+
     /// Emulate chunk_batch_size cycles and bump to self.batch_records.
     /// `record_callback` is used to return the EmulationRecord in function or closure.
     /// Return the emulation complete flag if success.
-    #[instrument(name = "emulate_batch_records", level = "debug", skip_all)]
     pub fn emulate_batch<F>(&mut self, record_callback: &mut F) -> Result<bool, EmulationError>
+    where
+        F: FnMut(EmulationRecord),
+    {
+        self.emulate_batch_until(record_callback, None)
+    }
+
+    /// Like [`Self::emulate_batch`], but also ends the batch at the first chunk boundary after
+    /// `deadline`, if there is one.
+    #[instrument(name = "emulate_batch_records", level = "debug", skip_all)]
+    pub(crate) fn emulate_batch_until<F>(
+        &mut self,
+        record_callback: &mut F,
+        deadline: Option<Instant>,
+    ) -> Result<bool, EmulationError>
     where
         F: FnMut(EmulationRecord),
     {
@@ -313,12 +398,16 @@ impl RiscvEmulator {
                 break;
             }
 
-            if self.opts.chunk_batch_size > 0 && current_chunk != self.state.current_chunk {
-                num_chunks_emulated += 1;
+            if current_chunk != self.state.current_chunk {
                 current_chunk = self.state.current_chunk;
+                num_chunks_emulated += 1;
                 if num_chunks_emulated == self.opts.chunk_batch_size {
                     break;
                 }
+                // The clock is only read between chunks to keep it off the per-cycle path.
+                if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                    break;
+                }
             }
         }
         debug!("emulate - global clk {}", self.state.global_clk);
@@ -361,6 +450,10 @@ impl RiscvEmulator {
         timestamp: u32,
         local_memory_access: Option<&mut HashMap<u32, MemoryLocalEvent>>,
     ) -> MemoryReadRecord {
+        if let Some(counts) = self.memory_access_counts.as_mut() {
+            counts.entry(addr).or_default().reads += 1;
+        }
+
         // Get the memory record entry.
         let entry = self.state.memory.entry(addr);
 
@@ -407,6 +500,10 @@ impl RiscvEmulator {
         timestamp: u32,
         local_memory_access: Option<&mut HashMap<u32, MemoryLocalEvent>>,
     ) -> MemoryWriteRecord {
+        if let Some(counts) = self.memory_access_counts.as_mut() {
+            counts.entry(addr).or_default().writes += 1;
+        }
+
         // Get the memory record entry.
         let entry = self.state.memory.entry(addr);
 
@@ -545,6 +642,24 @@ impl RiscvEmulator {
         (a, b, c, addr, memory_value)
     }
 
+    /// Fail a store to `addr` if it falls into the read-only program memory and
+    /// `read_only_program_memory` is set.
+    fn check_writable(&self, addr: u32) -> Result<(), EmulationError> {
+        if self.opts.read_only_program_memory
+            && self
+                .program
+                .read_only_ranges
+                .iter()
+                .any(|range| range.contains(&addr))
+        {
+            return Err(EmulationError::WriteToReadOnly {
+                addr,
+                pc: self.state.pc,
+            });
+        }
+        Ok(())
+    }
+
     /// Fetch the input operand values for a branch instruction.
     fn branch_rr(&mut self, instruction: &Instruction) -> (u32, u32, u32) {
         let (rs1, rs2, imm) = instruction.b_type();
@@ -577,7 +692,7 @@ impl RiscvEmulator {
 
             registers[i] = match record {
                 Some(record) => record.value,
-                None => 0,
+                None => *self.state.uninitialized_memory.get(&addr).unwrap_or(&0),
             };
         }
         registers
@@ -591,7 +706,7 @@ impl RiscvEmulator {
 
         match record {
             Some(record) => record.value,
-            None => 0,
+            None => *self.state.uninitialized_memory.get(&addr).unwrap_or(&0),
         }
     }
 
@@ -690,13 +805,26 @@ impl RiscvEmulator {
 
 #[cfg(test)]
 mod tests {
-    use super::{Program, RiscvEmulator};
+    use super::{EmulationError, EmulationRecord, Program, RiscvEmulator};
     use crate::{
-        compiler::riscv::compiler::{Compiler, SourceType},
-        emulator::{opts::EmulatorOpts, stdin::EmulatorStdin},
+        compiler::riscv::{
+            compiler::{Compiler, SourceType},
+            instruction::Instruction,
+            opcode::Opcode,
+            register::Register,
+        },
+        emulator::{
+            opts::{ChunkBoundary, EmulatorOpts},
+            riscv::syscalls::{test_utils::syscall_program, SyscallCode},
+            stdin::EmulatorStdin,
+        },
     };
     use alloc::sync::Arc;
     use p3_baby_bear::BabyBear;
+    use std::{
+        collections::{BTreeMap, HashSet},
+        time::Duration,
+    };
 
     #[allow(dead_code)]
     const FIBONACCI_ELF: &[u8] =
@@ -731,6 +859,239 @@ mod tests {
         // println!("{:x?}", emulator.state.public_values_stream)
     }
 
+    #[test]
+    fn test_step_matches_run() {
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        stdin.write(&10u32);
+        let stdin = stdin.finalize();
+
+        let mut expected =
+            RiscvEmulator::new::<BabyBear>(simple_fibo_program(), EmulatorOpts::default());
+        expected.run(Some(stdin.clone())).unwrap();
+
+        let mut emulator =
+            RiscvEmulator::new::<BabyBear>(simple_fibo_program(), EmulatorOpts::default());
+        emulator.write_stdin(&stdin);
+        loop {
+            let pc = emulator.state.pc;
+            let info = emulator.step().unwrap();
+            assert_eq!(info.pc, pc);
+            for (register, value) in &info.registers {
+                assert_eq!(emulator.register(*register), *value);
+            }
+            if info.done {
+                break;
+            }
+        }
+
+        assert_eq!(emulator.state.global_clk, expected.state.global_clk);
+        assert_eq!(emulator.registers(), expected.registers());
+        assert!(emulator.step().is_err());
+    }
+
+    #[test]
+    fn test_split_at_program_counter() {
+        // Five increments at pc 0, 4, .., 16, with new chunks starting at 8 and 16.
+        let instructions = (0..5)
+            .map(|_| Instruction::new(Opcode::ADD, 5, 5, 1, false, true))
+            .collect();
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let opts = EmulatorOpts {
+            chunk_boundary: ChunkBoundary::AtProgramCounter {
+                split_pcs: vec![8, 16],
+            },
+            ..EmulatorOpts::test_opts()
+        };
+
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, opts);
+        let mut records = Vec::new();
+        while !emulator
+            .emulate_batch(&mut |record| records.push(record))
+            .unwrap()
+        {}
+
+        let chunk_pcs = records
+            .iter()
+            .filter(|record| !record.cpu_events.is_empty())
+            .map(|record| {
+                record
+                    .cpu_events
+                    .iter()
+                    .map(|event| event.pc)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(chunk_pcs, vec![vec![0, 4], vec![8, 12], vec![16]]);
+        assert_eq!(emulator.register(Register::X5), 5);
+    }
+
+    #[test]
+    fn test_initial_registers() {
+        // x5 := x5 + x6, then x7 := x7 - x5.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 5, 6, false, false),
+            Instruction::new(Opcode::SUB, 7, 7, 5, false, false),
+        ];
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let mut registers = [0; 32];
+        registers[5] = 2;
+        registers[6] = 3;
+        registers[7] = 10;
+        let opts = EmulatorOpts {
+            initial_registers: Some(registers),
+            ..EmulatorOpts::test_opts()
+        };
+
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, opts);
+        assert_eq!(emulator.registers(), registers);
+        emulator.run(None).unwrap();
+        assert_eq!(emulator.register(Register::X5), 5);
+        assert_eq!(emulator.register(Register::X6), 3);
+        assert_eq!(emulator.register(Register::X7), 5);
+    }
+
+    #[test]
+    fn test_divrem_edge_cases() {
+        // The results the M extension defines for division by zero and signed overflow, next to
+        // ordinary signed cases: (dividend, divisor, div, divu, rem, remu).
+        let min = i32::MIN as u32;
+        let neg = |x: i32| x as u32;
+        let cases = [
+            (7, 0, u32::MAX, u32::MAX, 7, 7),
+            (neg(-7), 0, u32::MAX, u32::MAX, neg(-7), neg(-7)),
+            (0, 0, u32::MAX, u32::MAX, 0, 0),
+            (min, 0, u32::MAX, u32::MAX, min, min),
+            (min, neg(-1), min, 0, 0, min),
+            (neg(-7), 2, neg(-3), 0x7fff_fffc, neg(-1), 1),
+            (7, neg(-2), neg(-3), 0, 1, 7),
+        ];
+
+        let instructions = vec![
+            Instruction::new(Opcode::DIV, 10, 5, 6, false, false),
+            Instruction::new(Opcode::DIVU, 11, 5, 6, false, false),
+            Instruction::new(Opcode::REM, 12, 5, 6, false, false),
+            Instruction::new(Opcode::REMU, 13, 5, 6, false, false),
+        ];
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        for (b, c, div, divu, rem, remu) in cases {
+            let mut registers = [0; 32];
+            registers[5] = b;
+            registers[6] = c;
+            let opts = EmulatorOpts {
+                initial_registers: Some(registers),
+                ..EmulatorOpts::test_opts()
+            };
+
+            let mut emulator = RiscvEmulator::new::<BabyBear>(program.clone(), opts);
+            emulator.run(None).unwrap();
+            assert_eq!(
+                [
+                    emulator.register(Register::X10),
+                    emulator.register(Register::X11),
+                    emulator.register(Register::X12),
+                    emulator.register(Register::X13),
+                ],
+                [div, divu, rem, remu],
+                "{:#x} / {:#x}",
+                b,
+                c
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_with_timeout_resumes() {
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        stdin.write(&1000u32);
+        let stdin = stdin.finalize();
+        let opts = EmulatorOpts {
+            chunk_size: 256,
+            chunk_batch_size: 4,
+            ..EmulatorOpts::test_opts()
+        };
+
+        let mut expected = RiscvEmulator::new::<BabyBear>(simple_fibo_program(), opts.clone());
+        let expected_records = expected.run(Some(stdin.clone())).unwrap();
+
+        // A zero timeout interrupts the run after every chunk.
+        let mut emulator = RiscvEmulator::new::<BabyBear>(simple_fibo_program(), opts);
+        emulator.write_stdin(&stdin);
+        let mut records = Vec::new();
+        let mut num_calls = 0;
+        while !emulator
+            .run_with_timeout(Duration::ZERO, &mut |record| records.push(record))
+            .unwrap()
+        {
+            num_calls += 1;
+        }
+
+        assert!(num_calls > 1);
+        assert_eq!(emulator.state.global_clk, expected.state.global_clk);
+        assert_eq!(emulator.state.pc, expected.state.pc);
+        assert_eq!(emulator.registers(), expected.registers());
+        assert_eq!(
+            emulator.state.public_values_stream,
+            expected.state.public_values_stream
+        );
+        let num_cpu_events =
+            |records: &[EmulationRecord]| records.iter().map(|r| r.cpu_events.len()).sum::<usize>();
+        assert_eq!(num_cpu_events(&records), num_cpu_events(&expected_records));
+    }
+
+    #[test]
+    fn test_read_only_program_memory() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, 42, false, true),
+            Instruction::new(Opcode::SW, 5, 0, 0x1004, false, true),
+        ];
+        let mut program = Program::new(instructions, 0, 0);
+        program.memory_image = Arc::new(BTreeMap::from([(0x1000, 1), (0x1004, 2), (0x2000, 3)]));
+        program.read_only_ranges = vec![0x1000..0x1008].into();
+        let program = Arc::new(program);
+
+        let opts = EmulatorOpts {
+            read_only_program_memory: true,
+            ..EmulatorOpts::default()
+        };
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program.clone(), opts.clone());
+        assert!(matches!(
+            emulator.run(None),
+            Err(EmulationError::WriteToReadOnly {
+                addr: 0x1004,
+                pc: 4
+            })
+        ));
+
+        // The check is off by default.
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::default());
+        emulator.run(None).unwrap();
+        assert_eq!(emulator.word(0x1004), 42);
+
+        // Guests only write to their writable segments.
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        stdin.write(&10u32);
+        let mut emulator = RiscvEmulator::new::<BabyBear>(simple_fibo_program(), opts);
+        emulator.run(Some(stdin.finalize())).unwrap();
+    }
+
+    #[test]
+    fn test_invoked_syscalls() {
+        let program = syscall_program(
+            &[(SyscallCode::SHA256, 0x2000, 0x3000)],
+            BTreeMap::from([(0x2000, 0)]),
+        );
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program.clone(), EmulatorOpts::default());
+        let invoked = emulator
+            .invoked_syscalls(EmulatorStdin::<Program, Vec<u8>>::new_builder().finalize())
+            .unwrap();
+
+        assert_eq!(
+            invoked,
+            HashSet::from([SyscallCode::SHA256, SyscallCode::HALT])
+        );
+        assert_eq!(invoked, program.referenced_syscalls());
+    }
+
     #[test]
     fn test_simple_keccak() {
         let program = simple_keccak_program();
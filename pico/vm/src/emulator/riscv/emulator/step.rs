@@ -0,0 +1,75 @@
+use super::{EmulationError, RiscvEmulator};
+use crate::{
+    compiler::riscv::{instruction::Instruction, register::Register},
+    emulator::riscv::record::MemoryAccessRecord,
+};
+
+/// What a single call to [`RiscvEmulator::step`] did.
+#[derive(Debug, Clone)]
+pub struct StepInfo {
+    /// The program counter of the executed instruction.
+    pub pc: u32,
+    /// The program counter after the instruction.
+    pub next_pc: u32,
+    /// The executed instruction.
+    pub instruction: Instruction,
+    /// The registers whose value changed, with their new values.
+    pub registers: Vec<(Register, u32)>,
+    /// The memory accesses of the instruction. Only populated in trace mode.
+    pub memory_accesses: MemoryAccessRecord,
+    /// Whether the program has finished.
+    pub done: bool,
+}
+
+impl RiscvEmulator {
+    /// Emulates exactly one instruction, for single-step debugging.
+    ///
+    /// Unlike [`RiscvEmulator::emulate_batch`], this never moves to the next chunk or hands out
+    /// records: all events stay in `self.record`. Registers and memory can be inspected between
+    /// steps with [`RiscvEmulator::registers`] and [`RiscvEmulator::word`].
+    pub fn step(&mut self) -> Result<StepInfo, EmulationError> {
+        self.initialize_if_needed();
+
+        if self.is_done() {
+            return Err(EmulationError::ProgramFinished);
+        }
+        if let Some(max_cycles) = self.opts.max_cycles {
+            if self.state.global_clk >= max_cycles {
+                return Err(EmulationError::ExceededCycleLimit(max_cycles));
+            }
+        }
+
+        let pc = self.state.pc;
+        let instruction = self.program.fetch(pc);
+        let registers_before = self.registers();
+
+        self.emulate_instruction(&instruction)?;
+        self.state.global_clk += 1;
+        if let Some(fuel) = self.state.fuel.as_mut() {
+            *fuel = fuel.saturating_sub(1);
+        }
+
+        let registers = self
+            .registers()
+            .into_iter()
+            .zip(registers_before)
+            .enumerate()
+            .filter(|(_, (after, before))| after != before)
+            .map(|(i, (after, _))| (Register::from_u32(i as u32), after))
+            .collect();
+
+        let done = self.is_done();
+        if done && self.is_unconstrained() {
+            return Err(EmulationError::UnconstrainedEnd);
+        }
+
+        Ok(StepInfo {
+            pc,
+            next_pc: self.state.pc,
+            instruction,
+            registers,
+            memory_accesses: self.memory_accesses,
+            done,
+        })
+    }
+}
@@ -11,6 +11,10 @@ use crate::{
     },
 };
 use alloc::sync::Arc;
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
 
 type Stdin = EmulatorStdin<Program, Vec<u8>>;
 
@@ -19,6 +23,7 @@ impl RiscvEmulator {
         for input in &*stdin.inputs {
             self.state.input_stream.push(input.clone());
         }
+        self.state.args = stdin.args.to_vec();
     }
 
     /// Run without tracing
@@ -39,6 +44,31 @@ impl RiscvEmulator {
         }
     }
 
+    /// Emulates until the program finishes or `timeout` has passed, handing each completed record
+    /// to `record_callback`, and returns whether the program finished.
+    ///
+    /// The deadline is only checked between chunks, so a call always makes progress and may
+    /// overrun `timeout` by up to one chunk. On `Ok(false)`, call again to resume where the run
+    /// stopped.
+    pub fn run_with_timeout<F>(
+        &mut self,
+        timeout: Duration,
+        record_callback: &mut F,
+    ) -> Result<bool, EmulationError>
+    where
+        F: FnMut(EmulationRecord),
+    {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.emulate_batch_until(record_callback, Some(deadline))? {
+                return Ok(true);
+            }
+            if Instant::now() >= deadline {
+                return Ok(false);
+            }
+        }
+    }
+
     /// Emulates the program and prints the emulation report.
     ///
     /// # Errors
@@ -57,6 +87,21 @@ impl RiscvEmulator {
         }
     }
 
+    /// Runs the program on `stdin` without tracing and returns the syscalls it invoked.
+    ///
+    /// This is the dynamic counterpart of [`Program::referenced_syscalls`]: every code it returns
+    /// was actually reached, but a code the program only invokes on other inputs is missed. The
+    /// codes are exact, unlike the keys of the merged syscall counts.
+    pub fn invoked_syscalls(
+        &mut self,
+        stdin: Stdin,
+    ) -> Result<HashSet<SyscallCode>, EmulationError> {
+        self.write_stdin(&stdin);
+        self.mode = RiscvEmulatorMode::Simple;
+        while !self.emulate_batch(&mut |_| {})? {}
+        Ok(self.state.invoked_syscalls.iter().copied().collect())
+    }
+
     pub fn is_unconstrained(&self) -> bool {
         self.mode.is_unconstrained()
     }
@@ -1,3 +1,4 @@
+pub mod diff;
 pub mod emulator;
 pub mod hook;
 pub mod public_values;
@@ -20,7 +20,7 @@ use crate::{
     instances::compiler::shapes::riscv_shape::RiscvPadShape,
 };
 use hashbrown::HashMap;
-use itertools::{EitherOrBoth, Itertools};
+use itertools::Itertools;
 use p3_field::FieldAlgebra;
 use serde::{Deserialize, Serialize};
 use std::{mem::take, sync::Arc};
@@ -198,11 +198,15 @@ impl EmulationRecord {
         for (syscall_code, events) in precompile_events.into_iter() {
             let threshold = match syscall_code {
                 // TODO: refactor to remove magic number
-                SyscallCode::KECCAK_PERMUTE => (THRESHOLD_2POW20 / 26).min(opts.keccak),
+                SyscallCode::KECCAK_PERMUTE | SyscallCode::KECCAK_ABSORB => {
+                    (THRESHOLD_2POW20 / 26).min(opts.keccak)
+                }
                 SyscallCode::SHA_EXTEND => (THRESHOLD_2POW20 / 48).min(opts.sha_extend),
                 SyscallCode::SHA_COMPRESS => (THRESHOLD_2POW20 / 80).min(opts.sha_compress),
                 SyscallCode::BLS12381_FP_ADD => THRESHOLD_2POW16.min(opts.deferred),
-                SyscallCode::POSEIDON2_PERMUTE => THRESHOLD_2POW15.min(opts.deferred),
+                SyscallCode::POSEIDON2_PERMUTE | SyscallCode::POSEIDON2_COMPRESS => {
+                    THRESHOLD_2POW15.min(opts.deferred)
+                }
                 SyscallCode::BLS12381_ADD => THRESHOLD_2POW15.min(opts.deferred),
                 SyscallCode::BLS12381_FP2_MUL => THRESHOLD_2POW15.min(opts.deferred),
                 SyscallCode::BN254_FP2_MUL => THRESHOLD_2POW15.min(opts.deferred),
@@ -256,18 +260,13 @@ impl EmulationRecord {
 
             let mut init_addr_bits = [0; 32];
             let mut finalize_addr_bits = [0; 32];
-            for mem_chunks in self
-                .memory_initialize_events
-                .chunks(opts.memory)
-                .zip_longest(self.memory_finalize_events.chunks(opts.memory))
-            {
-                let (mem_init_chunk, mem_finalize_chunk) = match mem_chunks {
-                    EitherOrBoth::Both(mem_init_chunk, mem_finalize_chunk) => {
-                        (mem_init_chunk, mem_finalize_chunk)
-                    }
-                    EitherOrBoth::Left(mem_init_chunk) => (mem_init_chunk, [].as_slice()),
-                    EitherOrBoth::Right(mem_finalize_chunk) => ([].as_slice(), mem_finalize_chunk),
-                };
+            for (init_range, finalize_range) in opts.memory_strategy.memory_chunks(
+                self.memory_initialize_events.len(),
+                self.memory_finalize_events.len(),
+                opts.memory,
+            ) {
+                let mem_init_chunk = &self.memory_initialize_events[init_range];
+                let mem_finalize_chunk = &self.memory_finalize_events[finalize_range];
                 let mut memory_chunk = EmulationRecord::new(self.program.clone());
                 memory_chunk
                     .memory_initialize_events
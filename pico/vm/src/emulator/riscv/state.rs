@@ -1,4 +1,4 @@
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use nohash_hasher::BuildNoHashHasher;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -44,6 +44,16 @@ pub struct RiscvEmulationState {
     /// A ptr to the current position in the input stream incremented by HINT_READ opcode.
     pub input_stream_ptr: usize,
 
+    /// The command line style arguments of the program, read by the guest with `ARGS_READ`.
+    pub args: Vec<String>,
+
+    /// The fuel left, decremented every cycle down to zero and read by the guest with
+    /// `FUEL_REMAINING`. `None` if no budget was set.
+    pub fuel: Option<u64>,
+
+    /// The response of the last `HOST_CALL`, until the guest reads it with `HOST_CALL_READ`.
+    pub host_call_response: Option<Vec<u8>>,
+
     /// A stream of public values from the program (global to entire program).
     pub public_values_stream: Vec<u8>,
 
@@ -55,6 +65,9 @@ pub struct RiscvEmulationState {
 
     /// Keeps track of how many times a certain syscall has been called.
     pub syscall_counts: HashMap<SyscallCode, u64>,
+
+    /// The syscalls the program has invoked, by their exact code.
+    pub invoked_syscalls: HashSet<SyscallCode>,
 }
 
 impl RiscvEmulationState {
@@ -72,4 +85,15 @@ impl RiscvEmulationState {
             ..Default::default()
         }
     }
+
+    /// The arguments as `ARGS_READ` delivers them: for each argument its length as a little
+    /// endian u32, followed by its bytes.
+    pub fn encoded_args(&self) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        for arg in &self.args {
+            encoded.extend_from_slice(&(arg.len() as u32).to_le_bytes());
+            encoded.extend_from_slice(arg.as_bytes());
+        }
+        encoded
+    }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
 /// System Calls.
@@ -68,6 +69,12 @@ pub enum SyscallCode {
     /// Executes the `COMMIT` precompile.
     COMMIT = 0x00_00_00_10,
 
+    /// Appends a length-prefixed UTF-8 string to the public values stream.
+    STRING_COMMIT = 0x00_00_00_11,
+
+    /// Appends an array of words to the public values stream.
+    COMMIT_ARRAY = 0x00_00_00_12,
+
     /// Executes the `VERIFY_PICO_PROOF` precompile.
     VERIFY_PICO_PROOF = 0x00_00_00_1B,
 
@@ -80,6 +87,39 @@ pub enum SyscallCode {
     /// Executes the `HINT_READ` precompile.
     HINT_READ = 0x00_00_00_F1,
 
+    /// Executes the `SELF_VK_DIGEST` precompile.
+    SELF_VK_DIGEST = 0x00_00_00_F2,
+
+    /// Executes the `MEMORY_PREFETCH` hint.
+    MEMORY_PREFETCH = 0x00_00_00_F4,
+
+    /// Executes the `ARGS_LEN` precompile.
+    ARGS_LEN = 0x00_00_00_F6,
+
+    /// Executes the `ARGS_READ` precompile.
+    ARGS_READ = 0x00_00_00_F7,
+
+    /// Executes the `FUEL_REMAINING` precompile.
+    FUEL_REMAINING = 0x00_00_00_F8,
+
+    /// Executes the `BIGINT_SQRT` hint.
+    BIGINT_SQRT = 0x00_00_00_F9,
+
+    /// Executes the `UINT256_DIV` hint.
+    UINT256_DIV = 0x00_00_00_FA,
+
+    /// Executes the `UINT256_REM` hint.
+    UINT256_REM = 0x00_00_00_FB,
+
+    /// Executes the `HOST_CALL` hint.
+    HOST_CALL = 0x00_00_00_FC,
+
+    /// Executes the `HOST_CALL_READ` hint.
+    HOST_CALL_READ = 0x00_00_00_FD,
+
+    /// Executes the `CYCLE_COUNT` hint.
+    CYCLE_COUNT = 0x00_00_00_FE,
+
     /// Executes the `UINT256_MUL` precompile.
     UINT256_MUL = 0x00_01_01_1D,
 
@@ -136,6 +176,74 @@ pub enum SyscallCode {
 
     /// Executes the `POSEIDON2_PERMUTE` precompile.
     POSEIDON2_PERMUTE = 0x00_01_01_2F,
+
+    /// Executes the `POLY_EVAL` precompile.
+    POLY_EVAL = 0x00_01_01_30,
+
+    /// Executes the `CT_EQ` precompile.
+    CT_EQ = 0x00_01_01_31,
+
+    /// Executes the `BN254_FP_INV` precompile.
+    BN254_FP_INV = 0x00_01_01_32,
+
+    /// Executes the `BLS12381_FP_INV` precompile.
+    BLS12381_FP_INV = 0x00_01_01_33,
+
+    /// Executes the `SHA256` precompile.
+    SHA256 = 0x00_01_01_34,
+
+    /// Executes the `SORT_U32` precompile.
+    SORT_U32 = 0x00_01_01_35,
+
+    /// Executes the `AES128_ENCRYPT_BLOCK` precompile.
+    AES128_ENCRYPT_BLOCK = 0x00_01_01_36,
+
+    /// Executes the `RLP_DECODE_ITEM` precompile.
+    RLP_DECODE_ITEM = 0x00_01_01_37,
+
+    /// Executes the `BASE64_DECODE` precompile.
+    BASE64_DECODE = 0x00_01_01_38,
+
+    // 0x00_01_01_39 is reserved for a BN254 Poseidon2 permutation, which needs a chip that
+    // constrains BN254 Fr arithmetic over the proving field first.
+    /// Executes the `BN254_FP_BATCH_INV` precompile.
+    BN254_FP_BATCH_INV = 0x00_01_01_3A,
+
+    /// Executes the `BLS12381_FP_BATCH_INV` precompile.
+    BLS12381_FP_BATCH_INV = 0x00_01_01_3B,
+
+    /// Executes the `KECCAK_ABSORB` precompile.
+    KECCAK_ABSORB = 0x00_01_01_3C,
+
+    /// Executes the `KECCAK_SQUEEZE` precompile.
+    KECCAK_SQUEEZE = 0x00_01_01_3D,
+
+    /// Executes the `AES_KEY_SCHEDULE` precompile.
+    AES_KEY_SCHEDULE = 0x00_01_01_3E,
+
+    /// Executes the `AES256_ENCRYPT_BLOCK` precompile.
+    AES256_ENCRYPT_BLOCK = 0x00_01_01_3F,
+
+    /// Executes the `POSEIDON2_COMPRESS` precompile.
+    POSEIDON2_COMPRESS = 0x00_01_01_40,
+
+    /// Executes the `RANGE_CHECK_BATCH` precompile.
+    RANGE_CHECK_BATCH = 0x00_01_01_41,
+
+    /// Executes the `FIELD_SORT` precompile.
+    FIELD_SORT = 0x00_01_01_42,
+
+    /// Executes the `CRC32` precompile.
+    CRC32 = 0x00_01_01_43,
+
+    /// Executes the `MULTI_HASH` precompile.
+    MULTI_HASH = 0x00_01_01_44,
+
+    /// Executes the `SECP256K1_FP_INV` precompile.
+    SECP256K1_FP_INV = 0x00_01_01_45,
+
+    /// Executes the `SECP256K1_FP_BATCH_INV` precompile.
+    SECP256K1_FP_BATCH_INV = 0x00_01_01_46,
 }
 
 impl SyscallCode {
@@ -160,9 +268,22 @@ impl SyscallCode {
             0x00_01_01_1E => SyscallCode::BLS12381_ADD,
             0x00_00_01_1F => SyscallCode::BLS12381_DOUBLE,
             0x00_00_00_10 => SyscallCode::COMMIT,
+            0x00_00_00_11 => SyscallCode::STRING_COMMIT,
+            0x00_00_00_12 => SyscallCode::COMMIT_ARRAY,
             0x00_00_00_1B => SyscallCode::VERIFY_PICO_PROOF,
             0x00_00_00_F0 => SyscallCode::HINT_LEN,
             0x00_00_00_F1 => SyscallCode::HINT_READ,
+            0x00_00_00_F2 => SyscallCode::SELF_VK_DIGEST,
+            0x00_00_00_F4 => SyscallCode::MEMORY_PREFETCH,
+            0x00_00_00_F6 => SyscallCode::ARGS_LEN,
+            0x00_00_00_F7 => SyscallCode::ARGS_READ,
+            0x00_00_00_F8 => SyscallCode::FUEL_REMAINING,
+            0x00_00_00_F9 => SyscallCode::BIGINT_SQRT,
+            0x00_00_00_FA => SyscallCode::UINT256_DIV,
+            0x00_00_00_FB => SyscallCode::UINT256_REM,
+            0x00_00_00_FC => SyscallCode::HOST_CALL,
+            0x00_00_00_FD => SyscallCode::HOST_CALL_READ,
+            0x00_00_00_FE => SyscallCode::CYCLE_COUNT,
             0x00_01_01_1D => SyscallCode::UINT256_MUL,
             0x00_01_01_20 => SyscallCode::BLS12381_FP_ADD,
             0x00_01_01_21 => SyscallCode::BLS12381_FP_SUB,
@@ -181,10 +302,38 @@ impl SyscallCode {
             0x00_01_01_2E => SyscallCode::SECP256K1_FP_MUL,
             0x00_00_01_1C => SyscallCode::BLS12381_DECOMPRESS,
             0x00_01_01_2F => SyscallCode::POSEIDON2_PERMUTE,
+            0x00_01_01_30 => SyscallCode::POLY_EVAL,
+            0x00_01_01_31 => SyscallCode::CT_EQ,
+            0x00_01_01_32 => SyscallCode::BN254_FP_INV,
+            0x00_01_01_33 => SyscallCode::BLS12381_FP_INV,
+            0x00_01_01_34 => SyscallCode::SHA256,
+            0x00_01_01_35 => SyscallCode::SORT_U32,
+            0x00_01_01_36 => SyscallCode::AES128_ENCRYPT_BLOCK,
+            0x00_01_01_37 => SyscallCode::RLP_DECODE_ITEM,
+            0x00_01_01_38 => SyscallCode::BASE64_DECODE,
+            0x00_01_01_3A => SyscallCode::BN254_FP_BATCH_INV,
+            0x00_01_01_3B => SyscallCode::BLS12381_FP_BATCH_INV,
+            0x00_01_01_3C => SyscallCode::KECCAK_ABSORB,
+            0x00_01_01_3D => SyscallCode::KECCAK_SQUEEZE,
+            0x00_01_01_3E => SyscallCode::AES_KEY_SCHEDULE,
+            0x00_01_01_3F => SyscallCode::AES256_ENCRYPT_BLOCK,
+            0x00_01_01_40 => SyscallCode::POSEIDON2_COMPRESS,
+            0x00_01_01_41 => SyscallCode::RANGE_CHECK_BATCH,
+            0x00_01_01_42 => SyscallCode::FIELD_SORT,
+            0x00_01_01_43 => SyscallCode::CRC32,
+            0x00_01_01_44 => SyscallCode::MULTI_HASH,
+            0x00_01_01_45 => SyscallCode::SECP256K1_FP_INV,
+            0x00_01_01_46 => SyscallCode::SECP256K1_FP_BATCH_INV,
             _ => panic!("invalid syscall number: {}", value),
         }
     }
 
+    /// Create a [`SyscallCode`] from a u32, or `None` if it is not a syscall number.
+    #[must_use]
+    pub fn try_from_u32(value: u32) -> Option<Self> {
+        Self::iter().find(|code| *code as u32 == value)
+    }
+
     /// Get the system call identifier.
     #[must_use]
     pub fn syscall_id(self) -> u32 {
@@ -197,6 +346,37 @@ impl SyscallCode {
         (self as u32).to_le_bytes()[1].into()
     }
 
+    /// Whether the syscall is a precompile whose chip is only registered with the
+    /// `unreleased-precompiles` feature, since the recursion vk maps have no shapes for it yet.
+    #[must_use]
+    pub fn is_unreleased_precompile(self) -> bool {
+        matches!(
+            self,
+            SyscallCode::POLY_EVAL
+                | SyscallCode::CT_EQ
+                | SyscallCode::BN254_FP_INV
+                | SyscallCode::BLS12381_FP_INV
+                | SyscallCode::SECP256K1_FP_INV
+                | SyscallCode::BN254_FP_BATCH_INV
+                | SyscallCode::BLS12381_FP_BATCH_INV
+                | SyscallCode::SECP256K1_FP_BATCH_INV
+                | SyscallCode::SHA256
+                | SyscallCode::MULTI_HASH
+                | SyscallCode::SORT_U32
+                | SyscallCode::FIELD_SORT
+                | SyscallCode::AES128_ENCRYPT_BLOCK
+                | SyscallCode::AES_KEY_SCHEDULE
+                | SyscallCode::AES256_ENCRYPT_BLOCK
+                | SyscallCode::RLP_DECODE_ITEM
+                | SyscallCode::BASE64_DECODE
+                | SyscallCode::KECCAK_ABSORB
+                | SyscallCode::KECCAK_SQUEEZE
+                | SyscallCode::POSEIDON2_COMPRESS
+                | SyscallCode::RANGE_CHECK_BATCH
+                | SyscallCode::CRC32
+        )
+    }
+
     /// Get the number of additional cycles the syscall uses.
     #[must_use]
     pub fn num_cycles(self) -> u32 {
@@ -19,3 +19,84 @@ impl Syscall for CommitSyscall {
         None
     }
 }
+
+/// Appends a string to the public values stream, prefixed with its length in bytes as a
+/// little-endian `u32`.
+///
+/// `arg1` points to the UTF-8 bytes of the string and `arg2` is their number. Like writes to the
+/// public values fd, the guest folds the same bytes into its public values digest.
+pub(crate) struct StringCommitSyscall;
+
+impl Syscall for StringCommitSyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, ptr: u32, len: u32) -> Option<u32> {
+        let rt = &mut ctx.rt;
+        let bytes = (0..len).map(|i| rt.byte(ptr + i)).collect::<Vec<u8>>();
+
+        let stream = &mut rt.state.public_values_stream;
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&bytes);
+
+        None
+    }
+}
+
+/// Appends an array of words to the public values stream, each as 4 little-endian bytes.
+///
+/// `arg1` points to the words and `arg2` is their number. It commits the same bytes as a write of
+/// the array to the public values fd, in one syscall regardless of the length.
+pub(crate) struct CommitArraySyscall;
+
+impl Syscall for CommitArraySyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, ptr: u32, len: u32) -> Option<u32> {
+        assert_eq!(ptr % 4, 0, "commit array address not aligned to 4 bytes");
+        let rt = &mut ctx.rt;
+        let words = (0..len).map(|i| rt.word(ptr + 4 * i)).collect::<Vec<u32>>();
+
+        rt.state
+            .public_values_stream
+            .extend(words.iter().flat_map(|word| word.to_le_bytes()));
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{bytes_to_words, emulate, syscall_program},
+        SyscallCode,
+    };
+
+    const STR_PTR: u32 = 0x1000;
+
+    #[test]
+    fn test_string_commit() {
+        let s = "pico \u{2713}";
+        let emulator = emulate(syscall_program(
+            &[(SyscallCode::STRING_COMMIT, STR_PTR, s.len() as u32)],
+            bytes_to_words(STR_PTR, s.as_bytes()).collect(),
+        ));
+
+        let mut expected = (s.len() as u32).to_le_bytes().to_vec();
+        expected.extend_from_slice(s.as_bytes());
+        assert_eq!(emulator.state.public_values_stream, expected);
+    }
+
+    #[test]
+    fn test_commit_array() {
+        const ARRAY_PTR: u32 = 0x2000;
+        let words = [0x0403_0201, 0xdead_beef, 7];
+        let memory_image = words
+            .iter()
+            .enumerate()
+            .map(|(i, &word)| (ARRAY_PTR + 4 * i as u32, word))
+            .collect();
+        let emulator = emulate(syscall_program(
+            &[(SyscallCode::COMMIT_ARRAY, ARRAY_PTR, words.len() as u32)],
+            memory_image,
+        ));
+
+        let expected: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        assert_eq!(emulator.state.public_values_stream, expected);
+    }
+}
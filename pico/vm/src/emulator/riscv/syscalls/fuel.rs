@@ -0,0 +1,102 @@
+use super::{Syscall, SyscallCode, SyscallContext};
+
+/// Returns a word of the fuel left, the low word if `arg1` is 0 and the high word if it is 1.
+///
+/// Without a budget the fuel is unlimited, reported as `u64::MAX`. Like the hints, the value is
+/// not constrained by the proof, so a guest may use it to decide how much work to do but should
+/// not commit to it.
+pub(crate) struct FuelRemainingSyscall;
+
+impl Syscall for FuelRemainingSyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, word: u32, _: u32) -> Option<u32> {
+        let fuel = ctx.rt.state.fuel.unwrap_or(u64::MAX);
+        match word {
+            0 => Some(fuel as u32),
+            1 => Some((fuel >> 32) as u32),
+            _ => ctx.fail(format!("FUEL_REMAINING expects word 0 or 1, got {word}")),
+        }
+    }
+}
+
+/// Returns a word of the number of cycles run so far, the low word if `arg1` is 0 and the high
+/// word if it is 1. Like `FUEL_REMAINING`, the value is not constrained by the proof.
+pub(crate) struct CycleCountSyscall;
+
+impl Syscall for CycleCountSyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, word: u32, _: u32) -> Option<u32> {
+        let cycles = ctx.rt.state.global_clk;
+        match word {
+            0 => Some(cycles as u32),
+            1 => Some((cycles >> 32) as u32),
+            _ => ctx.fail(format!("CYCLE_COUNT expects word 0 or 1, got {word}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::{
+            opts::EmulatorOpts,
+            riscv::{
+                emulator::{EmulationError, RiscvEmulator},
+                syscalls::{test_utils::syscall_instructions, SyscallCode},
+            },
+        },
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+
+    fn fuel_remaining(fuel: Option<u64>, word: u32) -> u32 {
+        let instructions = syscall_instructions(SyscallCode::FUEL_REMAINING, word, 0);
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let opts = EmulatorOpts {
+            fuel,
+            ..EmulatorOpts::test_opts()
+        };
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, opts);
+        emulator.run(None).unwrap();
+        emulator.registers()[5]
+    }
+
+    #[test]
+    fn test_fuel_remaining() {
+        // Three cycles are spent before the ecall.
+        assert_eq!(fuel_remaining(Some(100), 0), 97);
+        assert_eq!(fuel_remaining(Some(100), 1), 0);
+        assert_eq!(fuel_remaining(Some(1), 0), 0);
+        assert_eq!(fuel_remaining(Some((5 << 32) | 7), 1), 5);
+        assert_eq!(fuel_remaining(None, 0), u32::MAX);
+        assert_eq!(fuel_remaining(None, 1), u32::MAX);
+    }
+
+    #[test]
+    fn test_cycle_count() {
+        let mut instructions = syscall_instructions(SyscallCode::CYCLE_COUNT, 0, 0);
+        instructions.push(Instruction::new(Opcode::ADD, 12, 5, 0, false, true));
+        instructions.extend(syscall_instructions(SyscallCode::CYCLE_COUNT, 0, 0));
+        instructions.push(Instruction::new(Opcode::ADD, 13, 5, 0, false, true));
+        instructions.extend(syscall_instructions(SyscallCode::CYCLE_COUNT, 1, 0));
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::test_opts());
+        emulator.run(None).unwrap();
+
+        // Three cycles are spent before the first ecall, and five between both.
+        let registers = emulator.registers();
+        assert_eq!(registers[12], 3);
+        assert_eq!(registers[13], 8);
+        assert_eq!(registers[5], 0);
+    }
+
+    #[test]
+    fn test_cycle_count_bad_word() {
+        let instructions = syscall_instructions(SyscallCode::CYCLE_COUNT, 2, 0);
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::test_opts());
+        assert!(matches!(
+            emulator.run(None),
+            Err(EmulationError::SyscallFailed(id, _)) if id == SyscallCode::CYCLE_COUNT as u32
+        ));
+    }
+}
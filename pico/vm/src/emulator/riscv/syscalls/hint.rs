@@ -32,34 +32,173 @@ impl Syscall for HintReadSyscall {
                 ctx.rt.state.input_stream.len()
             );
         }
-        let vec = &ctx.rt.state.input_stream[ctx.rt.state.input_stream_ptr];
+        let input_ptr = ctx.rt.state.input_stream_ptr;
+        let vec = &ctx.rt.state.input_stream[input_ptr];
+        let input_len = vec.len();
+        if input_len as u32 != len {
+            return ctx.fail(format!(
+                "hint input stream read length mismatch: input has {input_len} bytes, read {len}"
+            ));
+        }
+        // Pad the last word with 0s. This is fine because we are assuming the word is
+        // uninitialized, so filling it with 0s makes sense.
+        let words = vec
+            .chunks(4)
+            .map(|chunk| {
+                let mut bytes = [0u8; 4];
+                bytes[..chunk.len()].copy_from_slice(chunk);
+                u32::from_le_bytes(bytes)
+            })
+            .collect::<Vec<_>>();
+
+        // Save the data into emulator state so the emulator will use the desired data instead of
+        // 0 when first reading/writing from this address.
+        if let Err(reason) = ctx.init_words(ptr, words) {
+            return ctx.fail(format!("hint read: {reason}"));
+        }
         ctx.rt.state.input_stream_ptr += 1;
-        assert_eq!(
-            vec.len() as u32,
-            len,
-            "hint input stream read length mismatch"
-        );
-        assert_eq!(ptr % 4, 0, "hint read address not aligned to 4 bytes");
-        // Iterate through the vec in 4-byte chunks
-        for i in (0..len).step_by(4) {
-            // Get each byte in the chunk
-            let b1 = vec[i as usize];
-            // In case the vec is not a multiple of 4, right-pad with 0s. This is fine because we
-            // are assuming the word is uninitialized, so filling it with 0s makes sense.
-            let b2 = vec.get(i as usize + 1).copied().unwrap_or(0);
-            let b3 = vec.get(i as usize + 2).copied().unwrap_or(0);
-            let b4 = vec.get(i as usize + 3).copied().unwrap_or(0);
-            let word = u32::from_le_bytes([b1, b2, b3, b4]);
-
-            // Save the data into emulator state so the emulator will use the desired data instead of
-            // 0 when first reading/writing from this address.
-            ctx.rt
-                .state
-                .uninitialized_memory
-                .entry(ptr + i)
-                .and_modify(|_| panic!("hint read address is initialized already"))
-                .or_insert(word);
+        None
+    }
+}
+
+pub(crate) struct SelfVkDigestSyscall;
+
+impl Syscall for SelfVkDigestSyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, ptr: u32, _: u32) -> Option<u32> {
+        let Some(vk_digest) = ctx.rt.vk_digest else {
+            return ctx
+                .fail("self vk digest is only available when emulating with a verifying key");
+        };
+        // Same as HINT_READ, the digest is injected as the initial value of fresh memory.
+        if let Err(reason) = ctx.init_words(ptr, vk_digest) {
+            return ctx.fail(format!("self vk digest: {reason}"));
+        }
+        None
+    }
+}
+
+pub(crate) struct ArgsLenSyscall;
+
+impl Syscall for ArgsLenSyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, _: u32, _: u32) -> Option<u32> {
+        Some(ctx.rt.state.encoded_args().len() as u32)
+    }
+}
+
+pub(crate) struct ArgsReadSyscall;
+
+impl Syscall for ArgsReadSyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, ptr: u32, len: u32) -> Option<u32> {
+        let encoded = ctx.rt.state.encoded_args();
+        if encoded.len() as u32 != len {
+            return ctx.fail(format!(
+                "args read length mismatch: args have {} bytes, read {len}",
+                encoded.len()
+            ));
+        }
+        // Same as HINT_READ, the last word is padded with zeros.
+        let words = encoded
+            .chunks(4)
+            .map(|word| {
+                let mut bytes = [0u8; 4];
+                bytes[..word.len()].copy_from_slice(word);
+                u32::from_le_bytes(bytes)
+            })
+            .collect::<Vec<_>>();
+        if let Err(reason) = ctx.init_words(ptr, words) {
+            return ctx.fail(format!("args read: {reason}"));
         }
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::{
+            opts::EmulatorOpts,
+            riscv::{
+                emulator::{EmulationError, RiscvEmulator},
+                syscalls::{test_utils::syscall_instructions, SyscallCode},
+            },
+            stdin::EmulatorStdin,
+        },
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+
+    fn hint_reads(ptrs: &[u32], touch: Option<u32>) -> Result<RiscvEmulator, EmulationError> {
+        let mut instructions = Vec::new();
+        if let Some(addr) = touch {
+            instructions.push(Instruction::new(Opcode::LW, 12, 0, addr, false, true));
+        }
+        for &ptr in ptrs {
+            instructions.extend(syscall_instructions(SyscallCode::HINT_READ, ptr, 4));
+        }
+        instructions.push(Instruction::new(Opcode::LW, 13, 0, 0x1000, false, true));
+        let program = Arc::new(Program::new(instructions, 0, 0));
+
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        for i in 0..ptrs.len() as u32 {
+            stdin.write_slice(&(i + 1).to_le_bytes());
+        }
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::default());
+        emulator.run(Some(stdin.finalize()))?;
+        Ok(emulator)
+    }
+
+    #[test]
+    fn test_hint_read_reinit() {
+        // A hint that was never read is replaced by the next one to the same address.
+        let emulator = hint_reads(&[0x1000, 0x1000], None).unwrap();
+        assert_eq!(emulator.registers()[13], 2);
+
+        // Once the program has accessed the address, its initial value is fixed.
+        let result = hint_reads(&[0x1000], Some(0x1000));
+        assert!(matches!(
+            result,
+            Err(EmulationError::SyscallFailed(_, reason)) if reason.contains("initialized already")
+        ));
+        let result = hint_reads(&[0x1002], None);
+        assert!(matches!(
+            result,
+            Err(EmulationError::SyscallFailed(_, reason)) if reason.contains("not aligned")
+        ));
+    }
+
+    #[test]
+    fn test_args() {
+        let args = ["prog", "--flag", ""];
+        let mut expected = Vec::new();
+        for arg in args {
+            expected.extend_from_slice(&(arg.len() as u32).to_le_bytes());
+            expected.extend_from_slice(arg.as_bytes());
+        }
+
+        let mut instructions = syscall_instructions(SyscallCode::ARGS_LEN, 0, 0);
+        instructions.push(Instruction::new(Opcode::ADD, 12, 5, 0, false, true));
+        instructions.extend(syscall_instructions(
+            SyscallCode::ARGS_READ,
+            0x1000,
+            expected.len() as u32,
+        ));
+        let program = Arc::new(Program::new(instructions, 0, 0));
+
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        stdin.set_args(&args);
+
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::default());
+        emulator.run(Some(stdin.finalize())).unwrap();
+
+        assert_eq!(emulator.registers()[12], expected.len() as u32);
+        for (i, word) in expected.chunks(4).enumerate() {
+            let mut bytes = [0u8; 4];
+            bytes[..word.len()].copy_from_slice(word);
+            assert_eq!(
+                emulator.state.uninitialized_memory[&(0x1000 + 4 * i as u32)],
+                u32::from_le_bytes(bytes)
+            );
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::{Syscall, SyscallCode, SyscallContext};
+use alloc::sync::Arc;
+use hashbrown::HashMap;
+
+/// Answers the requests a guest makes with `HOST_CALL`.
+///
+/// Emulation can run more than once for a single proof, for example to count the chunks before
+/// proving them, so a handler must return the same bytes for the same arguments every time.
+pub trait HostCallHandler: Send + Sync {
+    /// The response to a call with the argument bytes `args`.
+    fn call(&self, args: &[u8]) -> Vec<u8>;
+}
+
+impl<F> HostCallHandler for F
+where
+    F: Fn(&[u8]) -> Vec<u8> + Send + Sync,
+{
+    fn call(&self, args: &[u8]) -> Vec<u8> {
+        self(args)
+    }
+}
+
+/// The handlers of `HOST_CALL` by tag.
+pub type HostCallHandlers = HashMap<u32, Arc<dyn HostCallHandler>>;
+
+/// Runs the handler registered for the tag `arg1` on the argument bytes described by the two
+/// words `[args_ptr, args_len]` at `arg2`, and returns the length of the response. The response
+/// waits in the emulation state until the guest reads it with `HOST_CALL_READ`.
+///
+/// Neither the arguments nor the response are constrained by the proof.
+pub(crate) struct HostCallSyscall;
+
+impl Syscall for HostCallSyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, tag: u32, ptr: u32) -> Option<u32> {
+        if ptr % 4 != 0 {
+            return ctx.fail("host call arguments not aligned to 4 bytes");
+        }
+        let args_ptr = ctx.rt.word(ptr);
+        let args_len = ctx.rt.word(ptr + 4);
+        let args = (0..args_len)
+            .map(|i| ctx.rt.byte(args_ptr + i))
+            .collect::<Vec<_>>();
+
+        let Some(handler) = ctx.rt.host_call_handlers.get(&tag) else {
+            return ctx.fail(format!("no host call handler is registered for tag {tag}"));
+        };
+        let response = handler.call(&args);
+        let len = response.len() as u32;
+        ctx.rt.state.host_call_response = Some(response);
+        Some(len)
+    }
+}
+
+/// Writes the pending response of `HOST_CALL` to `len` bytes of fresh memory at `ptr`, as
+/// `HINT_READ` does for inputs.
+pub(crate) struct HostCallReadSyscall;
+
+impl Syscall for HostCallReadSyscall {
+    fn emulate(&self, ctx: &mut SyscallContext, _: SyscallCode, ptr: u32, len: u32) -> Option<u32> {
+        let Some(response) = ctx.rt.state.host_call_response.take() else {
+            return ctx.fail("host call read without a pending host call");
+        };
+        if response.len() as u32 != len {
+            return ctx.fail(format!(
+                "host call read length mismatch: response has {} bytes, read {len}",
+                response.len()
+            ));
+        }
+        // Same as HINT_READ, the last word is padded with zeros.
+        let words = response.chunks(4).map(|word| {
+            let mut bytes = [0u8; 4];
+            bytes[..word.len()].copy_from_slice(word);
+            u32::from_le_bytes(bytes)
+        });
+        if let Err(reason) = ctx.init_words(ptr, words) {
+            return ctx.fail(format!("host call read: {reason}"));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::{
+            opts::EmulatorOpts,
+            riscv::{
+                emulator::RiscvEmulator,
+                syscalls::{test_utils::syscall_instructions, SyscallCode},
+            },
+        },
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_host_call() {
+        let mut instructions = syscall_instructions(SyscallCode::HOST_CALL, 7, 0x1000);
+        // Keep the response length returned in t0.
+        instructions.push(Instruction::new(Opcode::ADD, 12, 5, 0, false, true));
+        instructions.extend(syscall_instructions(SyscallCode::HOST_CALL_READ, 0x2000, 5));
+        instructions.push(Instruction::new(Opcode::LW, 13, 0, 0x2000, false, true));
+        instructions.push(Instruction::new(Opcode::LW, 14, 0, 0x2004, false, true));
+
+        // The arguments are the 3 bytes at 0x1102, which need not be aligned.
+        let mut program = Program::new(instructions, 0, 0);
+        program.memory_image = Arc::new(BTreeMap::from([
+            (0x1000, 0x1102),
+            (0x1004, 3),
+            (0x1100, 0x0201_0000),
+            (0x1104, 0x03),
+        ]));
+
+        let mut emulator =
+            RiscvEmulator::new::<BabyBear>(Arc::new(program), EmulatorOpts::default());
+        emulator.register_host_call(7, |args: &[u8]| {
+            let mut response = args.iter().rev().copied().collect::<Vec<_>>();
+            response.extend([0xff, 0xee]);
+            response
+        });
+        emulator.run(None).unwrap();
+
+        let registers = emulator.registers();
+        assert_eq!(registers[12], 5);
+        assert_eq!(registers[13], u32::from_le_bytes([3, 2, 1, 0xff]));
+        assert_eq!(registers[14], 0xee);
+    }
+}
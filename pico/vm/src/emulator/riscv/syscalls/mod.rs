@@ -2,10 +2,16 @@
 
 pub mod code;
 mod commit;
+mod fuel;
 mod halt;
 mod hint;
+mod host_call;
 pub mod precompiles;
+mod prefetch;
+mod sqrt;
 pub mod syscall_context;
+#[cfg(test)]
+pub(crate) mod test_utils;
 mod unconstrained;
 mod write;
 
@@ -18,31 +24,57 @@ use crate::{
         field::field_op::FieldOperation,
     },
     emulator::riscv::syscalls::{
-        commit::CommitSyscall, halt::HaltSyscall, syscall_context::SyscallContext,
+        commit::{CommitArraySyscall, CommitSyscall, StringCommitSyscall},
+        fuel::{CycleCountSyscall, FuelRemainingSyscall},
+        halt::HaltSyscall,
+        syscall_context::SyscallContext,
     },
     primitives::Poseidon2Init,
 };
 pub use code::*;
 use hashbrown::HashMap;
-use hint::{HintLenSyscall, HintReadSyscall};
+use hint::{ArgsLenSyscall, ArgsReadSyscall, HintLenSyscall, HintReadSyscall, SelfVkDigestSyscall};
+pub use host_call::{HostCallHandler, HostCallHandlers};
+use host_call::{HostCallReadSyscall, HostCallSyscall};
 use p3_field::PrimeField32;
 use p3_symmetric::Permutation;
 use precompiles::{
+    aes::syscall::{Aes128EncryptBlockSyscall, Aes256EncryptBlockSyscall, AesKeyScheduleSyscall},
+    base64::syscall::Base64DecodeSyscall,
+    crc32::syscall::Crc32Syscall,
+    ct_eq::syscall::ConstantTimeEqSyscall,
     edwards::{add::EdwardsAddAssignSyscall, decompress::EdwardsDecompressSyscall},
-    fptower::{fp::FpSyscall, fp2_addsub::Fp2AddSubSyscall, fp2_mul::Fp2MulSyscall},
-    keccak256::permute::Keccak256PermuteSyscall,
+    field_sort::syscall::FieldSortSyscall,
+    fptower::{
+        fp::FpSyscall, fp2_addsub::Fp2AddSubSyscall, fp2_mul::Fp2MulSyscall,
+        fp_batch_inv::FpBatchInvSyscall, fp_inv::FpInvSyscall,
+    },
+    keccak256::{
+        absorb::KeccakAbsorbSyscall, permute::Keccak256PermuteSyscall,
+        squeeze::KeccakSqueezeSyscall,
+    },
+    poly_eval::syscall::PolyEvalSyscall,
     poseidon2::permute::Poseidon2PermuteSyscall,
-    sha256::{compress::Sha256CompressSyscall, extend::Sha256ExtendSyscall},
-    uint256::syscall::Uint256MulSyscall,
+    range_check::syscall::RangeCheckBatchSyscall,
+    rlp::syscall::RlpDecodeItemSyscall,
+    sha256::{
+        compress::Sha256CompressSyscall, digest::Sha256DigestSyscall, extend::Sha256ExtendSyscall,
+        multi_hash::MultiHashSyscall,
+    },
+    sort::syscall::SortU32Syscall,
+    uint256::{div::Uint256DivSyscall, syscall::Uint256MulSyscall},
     weierstrass::{
         add::WeierstrassAddAssignSyscall, decompress::WeierstrassDecompressSyscall,
         double::WeierstrassDoubleAssignSyscall,
     },
 };
+use prefetch::MemoryPrefetchSyscall;
 use serde::{Deserialize, Serialize};
+use sqrt::BigintSqrtSyscall;
 use std::{marker::PhantomData, sync::Arc};
 use unconstrained::{EnterUnconstrainedSyscall, ExitUnconstrainedSyscall};
 use write::WriteSyscall;
+pub use write::{FD_LOG, GUEST_LOG_TARGET};
 
 /// A system call in the Pico RISC-V zkVM.
 ///
@@ -98,12 +130,37 @@ where
 
     syscall_map.insert(SyscallCode::HINT_READ, Arc::new(HintReadSyscall));
 
+    syscall_map.insert(SyscallCode::SELF_VK_DIGEST, Arc::new(SelfVkDigestSyscall));
+
+    syscall_map.insert(SyscallCode::ARGS_LEN, Arc::new(ArgsLenSyscall));
+    syscall_map.insert(SyscallCode::ARGS_READ, Arc::new(ArgsReadSyscall));
+    syscall_map.insert(SyscallCode::FUEL_REMAINING, Arc::new(FuelRemainingSyscall));
+    syscall_map.insert(SyscallCode::CYCLE_COUNT, Arc::new(CycleCountSyscall));
+    syscall_map.insert(SyscallCode::BIGINT_SQRT, Arc::new(BigintSqrtSyscall));
+    syscall_map.insert(SyscallCode::UINT256_DIV, Arc::new(Uint256DivSyscall));
+    syscall_map.insert(SyscallCode::UINT256_REM, Arc::new(Uint256DivSyscall));
+    syscall_map.insert(SyscallCode::HOST_CALL, Arc::new(HostCallSyscall));
+    syscall_map.insert(SyscallCode::HOST_CALL_READ, Arc::new(HostCallReadSyscall));
+
+    syscall_map.insert(
+        SyscallCode::MEMORY_PREFETCH,
+        Arc::new(MemoryPrefetchSyscall),
+    );
+
     syscall_map.insert(SyscallCode::COMMIT, Arc::new(CommitSyscall));
 
+    syscall_map.insert(SyscallCode::STRING_COMMIT, Arc::new(StringCommitSyscall));
+
+    syscall_map.insert(SyscallCode::COMMIT_ARRAY, Arc::new(CommitArraySyscall));
+
     syscall_map.insert(SyscallCode::SHA_EXTEND, Arc::new(Sha256ExtendSyscall));
 
     syscall_map.insert(SyscallCode::SHA_COMPRESS, Arc::new(Sha256CompressSyscall));
 
+    syscall_map.insert(SyscallCode::SHA256, Arc::new(Sha256DigestSyscall));
+
+    syscall_map.insert(SyscallCode::MULTI_HASH, Arc::new(MultiHashSyscall));
+
     syscall_map.insert(SyscallCode::HALT, Arc::new(HaltSyscall));
 
     syscall_map.insert(
@@ -111,6 +168,10 @@ where
         Arc::new(Keccak256PermuteSyscall),
     );
 
+    syscall_map.insert(SyscallCode::KECCAK_ABSORB, Arc::new(KeccakAbsorbSyscall));
+
+    syscall_map.insert(SyscallCode::KECCAK_SQUEEZE, Arc::new(KeccakSqueezeSyscall));
+
     // bls12-381 fp operations
     syscall_map.insert(
         SyscallCode::BLS12381_FP_ADD,
@@ -124,6 +185,14 @@ where
         SyscallCode::BLS12381_FP_MUL,
         Arc::new(FpSyscall::<Bls381BaseField>::new(FieldOperation::Mul)),
     );
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP_INV,
+        Arc::new(FpInvSyscall::<Bls381BaseField>::new()),
+    );
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP_BATCH_INV,
+        Arc::new(FpBatchInvSyscall::<Bls381BaseField>::new()),
+    );
     syscall_map.insert(
         SyscallCode::BLS12381_FP2_ADD,
         Arc::new(Fp2AddSubSyscall::<Bls381BaseField>::new(
@@ -154,6 +223,14 @@ where
         SyscallCode::BN254_FP_MUL,
         Arc::new(FpSyscall::<Bn254BaseField>::new(FieldOperation::Mul)),
     );
+    syscall_map.insert(
+        SyscallCode::BN254_FP_INV,
+        Arc::new(FpInvSyscall::<Bn254BaseField>::new()),
+    );
+    syscall_map.insert(
+        SyscallCode::BN254_FP_BATCH_INV,
+        Arc::new(FpBatchInvSyscall::<Bn254BaseField>::new()),
+    );
     syscall_map.insert(
         SyscallCode::BN254_FP2_ADD,
         Arc::new(Fp2AddSubSyscall::<Bn254BaseField>::new(FieldOperation::Add)),
@@ -180,6 +257,14 @@ where
         SyscallCode::SECP256K1_FP_MUL,
         Arc::new(FpSyscall::<Secp256k1BaseField>::new(FieldOperation::Mul)),
     );
+    syscall_map.insert(
+        SyscallCode::SECP256K1_FP_INV,
+        Arc::new(FpInvSyscall::<Secp256k1BaseField>::new()),
+    );
+    syscall_map.insert(
+        SyscallCode::SECP256K1_FP_BATCH_INV,
+        Arc::new(FpBatchInvSyscall::<Secp256k1BaseField>::new()),
+    );
 
     // edwards
     syscall_map.insert(
@@ -233,6 +318,51 @@ where
         Arc::new(Poseidon2PermuteSyscall::<F>(PhantomData)),
     );
 
+    syscall_map.insert(
+        SyscallCode::POSEIDON2_COMPRESS,
+        Arc::new(Poseidon2PermuteSyscall::<F>(PhantomData)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::POLY_EVAL,
+        Arc::new(PolyEvalSyscall::<F>(PhantomData)),
+    );
+
+    syscall_map.insert(SyscallCode::CT_EQ, Arc::new(ConstantTimeEqSyscall));
+
+    syscall_map.insert(SyscallCode::SORT_U32, Arc::new(SortU32Syscall));
+
+    syscall_map.insert(
+        SyscallCode::FIELD_SORT,
+        Arc::new(FieldSortSyscall::<F>(PhantomData)),
+    );
+
+    syscall_map.insert(SyscallCode::CRC32, Arc::new(Crc32Syscall));
+
+    syscall_map.insert(
+        SyscallCode::RANGE_CHECK_BATCH,
+        Arc::new(RangeCheckBatchSyscall),
+    );
+
+    syscall_map.insert(
+        SyscallCode::AES128_ENCRYPT_BLOCK,
+        Arc::new(Aes128EncryptBlockSyscall),
+    );
+
+    syscall_map.insert(
+        SyscallCode::AES_KEY_SCHEDULE,
+        Arc::new(AesKeyScheduleSyscall),
+    );
+
+    syscall_map.insert(
+        SyscallCode::AES256_ENCRYPT_BLOCK,
+        Arc::new(Aes256EncryptBlockSyscall),
+    );
+
+    syscall_map.insert(SyscallCode::RLP_DECODE_ITEM, Arc::new(RlpDecodeItemSyscall));
+
+    syscall_map.insert(SyscallCode::BASE64_DECODE, Arc::new(Base64DecodeSyscall));
+
     syscall_map
 }
 
@@ -0,0 +1,84 @@
+use crate::chips::chips::riscv_memory::event::{
+    MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord,
+};
+use serde::{Deserialize, Serialize};
+
+/// AES-128 Encrypt Block Event.
+///
+/// This event is emitted when a 16-byte block is encrypted in place with AES-128.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Aes128EncryptBlockEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the block.
+    pub block_ptr: u32,
+    /// The pointer to the key.
+    pub key_ptr: u32,
+    /// The plaintext block.
+    pub input: [u32; 4],
+    /// The cipher key.
+    pub key: [u32; 4],
+    /// The ciphertext block.
+    pub output: [u32; 4],
+    /// The memory records for the key.
+    pub key_read_records: [MemoryReadRecord; 4],
+    /// The memory records for the block. The previous values of the writes are the plaintext.
+    pub block_write_records: [MemoryWriteRecord; 4],
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// AES Key Schedule Event.
+///
+/// This event is emitted when an AES-256 key is expanded into its round keys.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct AesKeyScheduleEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the key.
+    pub key_ptr: u32,
+    /// The pointer to the key schedule.
+    pub schedule_ptr: u32,
+    /// The cipher key.
+    pub key: [u32; 8],
+    /// The key schedule, starting with the cipher key.
+    pub schedule: Vec<u32>,
+    /// The memory records for the key.
+    pub key_read_records: [MemoryReadRecord; 8],
+    /// The memory records for the key schedule.
+    pub schedule_write_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// AES-256 Encrypt Block Event.
+///
+/// This event is emitted when a 16-byte block is encrypted in place with AES-256 under an
+/// expanded key.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Aes256EncryptBlockEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the block.
+    pub block_ptr: u32,
+    /// The pointer to the key schedule.
+    pub schedule_ptr: u32,
+    /// The plaintext block.
+    pub input: [u32; 4],
+    /// The key schedule.
+    pub schedule: Vec<u32>,
+    /// The ciphertext block.
+    pub output: [u32; 4],
+    /// The memory records for the key schedule.
+    pub schedule_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the block. The previous values of the writes are the plaintext.
+    pub block_write_records: [MemoryWriteRecord; 4],
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,154 @@
+pub mod event;
+pub mod syscall;
+
+/// The AES S-box.
+pub const AES_SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// The round constants of the key schedule, one per round key of AES-128 and one per eight words
+/// of the AES-256 schedule.
+pub const AES_RCON: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// The number of rounds of AES-128.
+pub const AES128_NUM_ROUNDS: usize = 10;
+
+/// The number of rounds of AES-256.
+pub const AES256_NUM_ROUNDS: usize = 14;
+
+/// The number of words of an AES-256 key.
+pub const AES256_KEY_NUM_WORDS: usize = 8;
+
+/// The number of words of an expanded AES-256 key: a round key of four words for the initial
+/// `AddRoundKey` and for each round.
+pub const AES256_SCHEDULE_NUM_WORDS: usize = 4 * (AES256_NUM_ROUNDS + 1);
+
+/// `AES_SHIFT_ROWS[i]` is the index of the state byte that `ShiftRows` moves to index `i`.
+///
+/// The state is stored column by column, so byte `i` is in row `i % 4` and column `i / 4`.
+pub const AES_SHIFT_ROWS: [usize; 16] = [0, 5, 10, 15, 4, 9, 14, 3, 8, 13, 2, 7, 12, 1, 6, 11];
+
+/// Multiplies a byte by `x` in GF(2^8).
+pub const fn xtime(b: u8) -> u8 {
+    (b << 1) ^ if b & 0x80 != 0 { 0x1b } else { 0 }
+}
+
+/// Derives the next AES-128 round key from the previous one.
+///
+/// `round` counts from 1, so the first call turns the cipher key into the key of round 1.
+pub fn aes128_next_round_key(prev: &[u8; 16], round: usize) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    for i in 0..4 {
+        // `SubWord(RotWord(w))` of the last word of the previous key.
+        key[i] = prev[i] ^ AES_SBOX[prev[12 + (i + 1) % 4] as usize];
+    }
+    key[0] ^= AES_RCON[round - 1];
+    for i in 4..16 {
+        key[i] = prev[i] ^ key[i - 4];
+    }
+    key
+}
+
+/// The word that `w[i - 8]` is added to in the AES-256 key schedule, given `prev = w[i - 1]`.
+///
+/// This is `SubWord(RotWord(prev))` plus the round constant for every eighth word, `SubWord(prev)`
+/// four words later, and `prev` otherwise.
+pub fn aes256_schedule_term(prev: &[u8; 4], i: usize) -> [u8; 4] {
+    match i % 8 {
+        0 => {
+            let mut term: [u8; 4] = core::array::from_fn(|j| AES_SBOX[prev[(j + 1) % 4] as usize]);
+            term[0] ^= AES_RCON[i / 8 - 1];
+            term
+        }
+        4 => prev.map(|b| AES_SBOX[b as usize]),
+        _ => *prev,
+    }
+}
+
+/// Expands an AES-256 key into the words `w[0..60]` of its key schedule.
+pub fn aes256_key_schedule(key: &[u8; 32]) -> [[u8; 4]; AES256_SCHEDULE_NUM_WORDS] {
+    let mut w = [[0u8; 4]; AES256_SCHEDULE_NUM_WORDS];
+    for i in 0..AES256_SCHEDULE_NUM_WORDS {
+        w[i] = if i < AES256_KEY_NUM_WORDS {
+            key[4 * i..4 * i + 4].try_into().unwrap()
+        } else {
+            let term = aes256_schedule_term(&w[i - 1], i);
+            core::array::from_fn(|j| w[i - 8][j] ^ term[j])
+        };
+    }
+    w
+}
+
+/// Applies `MixColumns` to one column of the state.
+pub fn aes_mix_column(a: [u8; 4]) -> [u8; 4] {
+    core::array::from_fn(|r| {
+        xtime(a[r]) ^ xtime(a[(r + 1) % 4]) ^ a[(r + 1) % 4] ^ a[(r + 2) % 4] ^ a[(r + 3) % 4]
+    })
+}
+
+/// Applies one AES round to `state`: `SubBytes`, `ShiftRows`, `MixColumns` unless this is the
+/// last round, and `AddRoundKey`.
+pub fn aes_round(state: &[u8; 16], round_key: &[u8; 16], is_last: bool) -> [u8; 16] {
+    let shifted: [u8; 16] = core::array::from_fn(|i| AES_SBOX[state[AES_SHIFT_ROWS[i]] as usize]);
+    let mut out = shifted;
+    if !is_last {
+        for c in 0..4 {
+            let column = aes_mix_column(shifted[4 * c..4 * c + 4].try_into().unwrap());
+            out[4 * c..4 * c + 4].copy_from_slice(&column);
+        }
+    }
+    for (out, key) in out.iter_mut().zip(round_key) {
+        *out ^= key;
+    }
+    out
+}
+
+/// Encrypts one block with AES-128.
+pub fn aes128_encrypt_block(block: &[u8; 16], key: &[u8; 16]) -> [u8; 16] {
+    let mut round_key = *key;
+    let mut state: [u8; 16] = core::array::from_fn(|i| block[i] ^ key[i]);
+    for round in 1..=AES128_NUM_ROUNDS {
+        round_key = aes128_next_round_key(&round_key, round);
+        state = aes_round(&state, &round_key, round == AES128_NUM_ROUNDS);
+    }
+    state
+}
+
+/// Encrypts one block with AES-256, given the expanded key.
+pub fn aes256_encrypt_block(
+    block: &[u8; 16],
+    schedule: &[[u8; 4]; AES256_SCHEDULE_NUM_WORDS],
+) -> [u8; 16] {
+    let round_key =
+        |round: usize| -> [u8; 16] { core::array::from_fn(|i| schedule[4 * round + i / 4][i % 4]) };
+    let mut state: [u8; 16] = core::array::from_fn(|i| block[i] ^ round_key(0)[i]);
+    for round in 1..=AES256_NUM_ROUNDS {
+        state = aes_round(&state, &round_key(round), round == AES256_NUM_ROUNDS);
+    }
+    state
+}
+
+/// Reinterprets four little-endian words as the 16 bytes they hold in memory.
+pub fn aes_words_to_bytes(words: &[u32]) -> [u8; 16] {
+    core::array::from_fn(|i| words[i / 4].to_le_bytes()[i % 4])
+}
+
+/// Packs 16 bytes into the four little-endian words that hold them in memory.
+pub fn aes_bytes_to_words(bytes: &[u8; 16]) -> [u32; 4] {
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
@@ -0,0 +1,286 @@
+use super::{
+    aes128_encrypt_block, aes256_encrypt_block, aes256_key_schedule, aes_bytes_to_words,
+    aes_words_to_bytes,
+    event::{Aes128EncryptBlockEvent, Aes256EncryptBlockEvent, AesKeyScheduleEvent},
+    AES256_KEY_NUM_WORDS, AES256_SCHEDULE_NUM_WORDS,
+};
+use crate::emulator::riscv::syscalls::{
+    precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
+};
+
+/// Encrypts a 16-byte block in place with AES-128.
+///
+/// `arg1` points to the block and `arg2` points to the 16-byte key. Both are word aligned.
+pub(crate) struct Aes128EncryptBlockSyscall;
+
+impl Syscall for Aes128EncryptBlockSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let block_ptr = arg1;
+        if block_ptr % 4 != 0 {
+            panic!("block_ptr must be word aligned");
+        }
+        let key_ptr = arg2;
+        if key_ptr % 4 != 0 {
+            panic!("key_ptr must be word aligned");
+        }
+
+        let (key_read_records, key) = ctx.mr_slice(key_ptr, 4);
+        // The previous values of the writes are the plaintext, so the block does not need a
+        // separate read.
+        let input = ctx.slice_unsafe(block_ptr, 4);
+
+        let ciphertext =
+            aes128_encrypt_block(&aes_words_to_bytes(&input), &aes_words_to_bytes(&key));
+        let output = aes_bytes_to_words(&ciphertext);
+
+        // The key may overlap the block, so write one cycle after the reads.
+        ctx.clk += 1;
+        let block_write_records = ctx.mw_slice(block_ptr, &output);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::Aes128EncryptBlock(Aes128EncryptBlockEvent {
+            chunk,
+            clk,
+            block_ptr,
+            key_ptr,
+            input: input.try_into().unwrap(),
+            key: key.try_into().unwrap(),
+            output,
+            key_read_records: key_read_records.try_into().unwrap(),
+            block_write_records: block_write_records.try_into().unwrap(),
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+/// Expands a 32-byte AES-256 key into its 240-byte key schedule.
+///
+/// `arg1` points to the key and `arg2` points to the schedule. Both are word aligned. The
+/// schedule starts with the key, so it may be expanded in place.
+pub(crate) struct AesKeyScheduleSyscall;
+
+impl Syscall for AesKeyScheduleSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let key_ptr = arg1;
+        if key_ptr % 4 != 0 {
+            panic!("key_ptr must be word aligned");
+        }
+        let schedule_ptr = arg2;
+        if schedule_ptr % 4 != 0 {
+            panic!("schedule_ptr must be word aligned");
+        }
+
+        let (key_read_records, key) = ctx.mr_slice(key_ptr, AES256_KEY_NUM_WORDS);
+        let key_bytes: Vec<u8> = key.iter().flat_map(|word| word.to_le_bytes()).collect();
+        let schedule = aes256_key_schedule(&key_bytes.try_into().unwrap())
+            .map(u32::from_le_bytes)
+            .to_vec();
+
+        // The schedule may overlap the key, so write one cycle after the reads.
+        ctx.clk += 1;
+        let schedule_write_records = ctx.mw_slice(schedule_ptr, &schedule);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::AesKeySchedule(AesKeyScheduleEvent {
+            chunk,
+            clk,
+            key_ptr,
+            schedule_ptr,
+            key: key.try_into().unwrap(),
+            schedule,
+            key_read_records: key_read_records.try_into().unwrap(),
+            schedule_write_records,
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+/// Encrypts a 16-byte block in place with AES-256, under a key schedule expanded by
+/// `AES_KEY_SCHEDULE`.
+///
+/// `arg1` points to the block and `arg2` points to the 240-byte schedule. Both are word aligned.
+pub(crate) struct Aes256EncryptBlockSyscall;
+
+impl Syscall for Aes256EncryptBlockSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let block_ptr = arg1;
+        if block_ptr % 4 != 0 {
+            panic!("block_ptr must be word aligned");
+        }
+        let schedule_ptr = arg2;
+        if schedule_ptr % 4 != 0 {
+            panic!("schedule_ptr must be word aligned");
+        }
+
+        let (schedule_read_records, schedule) =
+            ctx.mr_slice(schedule_ptr, AES256_SCHEDULE_NUM_WORDS);
+        let input = ctx.slice_unsafe(block_ptr, 4);
+
+        let schedule_bytes: [[u8; 4]; AES256_SCHEDULE_NUM_WORDS] =
+            core::array::from_fn(|i| schedule[i].to_le_bytes());
+        let ciphertext = aes256_encrypt_block(&aes_words_to_bytes(&input), &schedule_bytes);
+        let output = aes_bytes_to_words(&ciphertext);
+
+        ctx.clk += 1;
+        let block_write_records = ctx.mw_slice(block_ptr, &output);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::Aes256EncryptBlock(Aes256EncryptBlockEvent {
+            chunk,
+            clk,
+            block_ptr,
+            schedule_ptr,
+            input: input.try_into().unwrap(),
+            schedule,
+            output,
+            schedule_read_records,
+            block_write_records: block_write_records.try_into().unwrap(),
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::{
+        emulator::RiscvEmulator,
+        syscalls::{
+            test_utils::{bytes_to_words, emulate, syscall_program},
+            SyscallCode,
+        },
+    };
+    use std::collections::BTreeMap;
+
+    const BLOCK_PTR: u32 = 0x1000;
+    const KEY_PTR: u32 = 0x2000;
+    const SCHEDULE_PTR: u32 = 0x3000;
+
+    fn run(calls: &[(SyscallCode, u32, u32)], block: &str, key: &str) -> RiscvEmulator {
+        let mut memory_image = BTreeMap::new();
+        for (ptr, bytes) in [(BLOCK_PTR, block), (KEY_PTR, key)] {
+            memory_image.extend(bytes_to_words(ptr, &hex::decode(bytes).unwrap()));
+        }
+        emulate(syscall_program(calls, memory_image))
+    }
+
+    fn read_hex(emulator: &mut RiscvEmulator, ptr: u32, num_words: u32) -> String {
+        let bytes = (0..num_words)
+            .flat_map(|i| emulator.word(ptr + 4 * i).to_le_bytes())
+            .collect::<Vec<_>>();
+        hex::encode(bytes)
+    }
+
+    fn encrypt(block: &str, key: &str) -> String {
+        let calls = [(SyscallCode::AES128_ENCRYPT_BLOCK, BLOCK_PTR, KEY_PTR)];
+        let mut emulator = run(&calls, block, key);
+        read_hex(&mut emulator, BLOCK_PTR, 4)
+    }
+
+    #[test]
+    fn test_aes128_encrypt_block() {
+        // FIPS-197, appendix C.1.
+        assert_eq!(
+            encrypt(
+                "00112233445566778899aabbccddeeff",
+                "000102030405060708090a0b0c0d0e0f"
+            ),
+            "69c4e0d86a7b0430d8cdb78070b4c55a"
+        );
+        // FIPS-197, appendix B.
+        assert_eq!(
+            encrypt(
+                "3243f6a8885a308d313198a2e0370734",
+                "2b7e151628aed2a6abf7158809cf4f3c"
+            ),
+            "3925841d02dc09fbdc118597196a0b32"
+        );
+    }
+
+    #[test]
+    fn test_aes256_encrypt_block() {
+        let calls = [
+            (SyscallCode::AES_KEY_SCHEDULE, KEY_PTR, SCHEDULE_PTR),
+            (SyscallCode::AES256_ENCRYPT_BLOCK, BLOCK_PTR, SCHEDULE_PTR),
+        ];
+
+        // FIPS-197, appendix C.3.
+        let mut emulator = run(
+            &calls,
+            "00112233445566778899aabbccddeeff",
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        );
+        assert_eq!(
+            read_hex(&mut emulator, BLOCK_PTR, 4),
+            "8ea2b7ca516745bfeafc49904b496089"
+        );
+
+        // FIPS-197, appendix A.3: the schedule starts with the key and ends with `w[56..60]`.
+        let key = "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4";
+        let mut emulator = run(&calls, "00112233445566778899aabbccddeeff", key);
+        assert_eq!(read_hex(&mut emulator, SCHEDULE_PTR, 8), key);
+        assert_eq!(
+            read_hex(&mut emulator, SCHEDULE_PTR + 4 * 56, 4),
+            "fe4890d1e6188d0b046df344706c631e"
+        );
+    }
+}
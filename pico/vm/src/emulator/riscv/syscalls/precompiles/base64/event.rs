@@ -0,0 +1,37 @@
+use crate::chips::chips::riscv_memory::event::{
+    MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord,
+};
+use serde::{Deserialize, Serialize};
+
+/// Base64 Decode Event.
+///
+/// This event is emitted when a length-prefixed base64 text is decoded.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Base64DecodeEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the length-prefixed input.
+    pub input_ptr: u32,
+    /// The pointer to the length-prefixed output.
+    pub output_ptr: u32,
+    /// The input length in bytes.
+    pub len: u32,
+    /// The memory words holding the input.
+    pub input: Vec<u32>,
+    /// The number of decoded bytes.
+    pub decoded_len: u32,
+    /// The memory words holding the decoded bytes, zero-padded.
+    pub output: Vec<u32>,
+    /// The memory read of the input length.
+    pub len_read_record: MemoryReadRecord,
+    /// The memory reads of the input words.
+    pub input_read_records: Vec<MemoryReadRecord>,
+    /// The memory write of the decoded length.
+    pub decoded_len_write_record: MemoryWriteRecord,
+    /// The memory writes of the output words.
+    pub output_write_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,89 @@
+pub mod event;
+pub mod syscall;
+
+/// The input length limit of the `BASE64_DECODE` precompile, in bytes.
+pub const BASE64_MAX_LEN: u32 = 1 << 24;
+
+/// The number of 4-character groups decoded in one row of the chip.
+pub const BASE64_GROUPS_PER_ROW: usize = 4;
+
+/// The number of character classes.
+pub const BASE64_NUM_CLASSES: usize = 8;
+
+/// The index of each character class in [`BASE64_CLASS_START`], [`BASE64_CLASS_SPAN`] and
+/// [`BASE64_CLASS_BASE`]. `+` and `/` are from the standard alphabet and `-` and `_` from the
+/// URL-safe one; both are accepted.
+pub const BASE64_UPPER: usize = 0;
+pub const BASE64_LOWER: usize = 1;
+pub const BASE64_DIGIT: usize = 2;
+pub const BASE64_PLUS: usize = 3;
+pub const BASE64_MINUS: usize = 4;
+pub const BASE64_SLASH: usize = 5;
+pub const BASE64_UNDERSCORE: usize = 6;
+pub const BASE64_PAD: usize = 7;
+
+/// The smallest character of each class.
+pub const BASE64_CLASS_START: [u8; BASE64_NUM_CLASSES] =
+    [b'A', b'a', b'0', b'+', b'-', b'/', b'_', b'='];
+
+/// The number of characters of each class after the smallest one.
+pub const BASE64_CLASS_SPAN: [u8; BASE64_NUM_CLASSES] = [25, 25, 9, 0, 0, 0, 0, 0];
+
+/// The 6-bit value of the smallest character of each class. Padding decodes to zero.
+pub const BASE64_CLASS_BASE: [u8; BASE64_NUM_CLASSES] = [0, 26, 52, 62, 62, 63, 63, 0];
+
+/// The class of a base64 character, or `None` if it is not one.
+pub fn base64_char_class(c: u8) -> Option<usize> {
+    (0..BASE64_NUM_CLASSES).find(|&class| {
+        c >= BASE64_CLASS_START[class] && c - BASE64_CLASS_START[class] <= BASE64_CLASS_SPAN[class]
+    })
+}
+
+/// The 6-bit value of a base64 character of the given class.
+pub fn base64_sextet(c: u8, class: usize) -> u8 {
+    c - BASE64_CLASS_START[class] + BASE64_CLASS_BASE[class]
+}
+
+/// Decodes padded base64 text in either alphabet.
+///
+/// Fails if the length is not a multiple of 4, on characters outside both alphabets, on padding
+/// anywhere but the last one or two characters, and on non-zero bits left over before the padding.
+pub fn base64_decode(text: &[u8]) -> Result<Vec<u8>, String> {
+    if text.len() % 4 != 0 {
+        return Err(format!(
+            "base64 input of {} bytes is not padded to a multiple of 4",
+            text.len()
+        ));
+    }
+
+    let num_groups = text.len() / 4;
+    let mut decoded = Vec::with_capacity(3 * num_groups);
+    for (i, group) in text.chunks_exact(4).enumerate() {
+        let mut sextets = [0u8; 4];
+        let mut pads = 0;
+        for (j, &c) in group.iter().enumerate() {
+            let class =
+                base64_char_class(c).ok_or_else(|| format!("invalid base64 character {c:#04x}"))?;
+            if class == BASE64_PAD {
+                if i != num_groups - 1 || j < 2 {
+                    return Err("base64 padding is only allowed at the end".to_string());
+                }
+                pads += 1;
+            } else if pads != 0 {
+                return Err("base64 padding is only allowed at the end".to_string());
+            }
+            sextets[j] = base64_sextet(c, class);
+        }
+
+        let bytes = [
+            (sextets[0] << 2) | (sextets[1] >> 4),
+            (sextets[1] << 4) | (sextets[2] >> 2),
+            (sextets[2] << 6) | sextets[3],
+        ];
+        if bytes[3 - pads..].iter().any(|&byte| byte != 0) {
+            return Err("base64 input has non-zero bits before the padding".to_string());
+        }
+        decoded.extend_from_slice(&bytes[..3 - pads]);
+    }
+    Ok(decoded)
+}
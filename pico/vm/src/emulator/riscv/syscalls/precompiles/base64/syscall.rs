@@ -0,0 +1,168 @@
+use super::{base64_decode, event::Base64DecodeEvent, BASE64_MAX_LEN};
+use crate::emulator::riscv::syscalls::{
+    precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
+};
+
+/// The number of words `BASE64_DECODE` writes after the decoded length for a `len`-byte input.
+/// Each group of 4 characters takes up 3 bytes, so the last word may hold zero bytes past the
+/// decoded ones.
+pub fn base64_num_output_words(len: u32) -> usize {
+    (3 * len as usize / 4).div_ceil(4)
+}
+
+/// Decodes a length-prefixed base64 text in one call.
+///
+/// `arg1` points to a word holding the text length in bytes, which must be a multiple of 4,
+/// followed by the text itself. `arg2` points to a word that receives the decoded length,
+/// followed by room for [`base64_num_output_words`] words of decoded bytes.
+pub(crate) struct Base64DecodeSyscall;
+
+impl Syscall for Base64DecodeSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let input_ptr = arg1;
+        let output_ptr = arg2;
+        let start_clk = ctx.clk;
+        if output_ptr % 4 != 0 {
+            return ctx.fail("output_ptr must be word aligned");
+        }
+
+        let (len_read_record, len) = ctx.mr(input_ptr);
+        if len >= BASE64_MAX_LEN {
+            return ctx.fail(format!(
+                "base64 input of {len} bytes exceeds the limit of {BASE64_MAX_LEN}"
+            ));
+        }
+        let (input_read_records, input) = ctx.mr_slice(input_ptr + 4, len.div_ceil(4) as usize);
+
+        let text = input
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        let mut decoded = match base64_decode(&text[..len as usize]) {
+            Ok(decoded) => decoded,
+            Err(reason) => return ctx.fail(reason),
+        };
+        let decoded_len = decoded.len() as u32;
+        let num_output_words = base64_num_output_words(len);
+        decoded.resize(4 * num_output_words, 0);
+        let output = decoded
+            .chunks_exact(4)
+            .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        // The input may overlap the output, so write one cycle after the reads.
+        ctx.clk += 1;
+        let decoded_len_write_record = ctx.mw(output_ptr, decoded_len);
+        let output_write_records = ctx.mw_slice(output_ptr + 4, &output);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::Base64Decode(Base64DecodeEvent {
+            chunk,
+            clk: start_clk,
+            input_ptr,
+            output_ptr,
+            len,
+            input,
+            decoded_len,
+            output,
+            len_read_record,
+            input_read_records,
+            decoded_len_write_record,
+            output_write_records,
+            local_mem_access: ctx.postprocess(),
+        });
+        let syscall_event = ctx
+            .rt
+            .syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::base64_num_output_words;
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{bytes_to_words, emulate, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+
+    const INPUT_PTR: u32 = 0x1000;
+    const OUTPUT_PTR: u32 = 0x8000;
+
+    fn base64_decode(text: &[u8]) -> Vec<u8> {
+        let mut memory_image = bytes_to_words(INPUT_PTR + 4, text).collect::<BTreeMap<_, _>>();
+        memory_image.insert(INPUT_PTR, text.len() as u32);
+        let mut emulator = emulate(syscall_program(
+            &[(SyscallCode::BASE64_DECODE, INPUT_PTR, OUTPUT_PTR)],
+            memory_image,
+        ));
+
+        let decoded_len = emulator.word(OUTPUT_PTR) as usize;
+        let num_words = base64_num_output_words(text.len() as u32) as u32;
+        let output = (0..num_words)
+            .flat_map(|i| emulator.word(OUTPUT_PTR + 4 * (i + 1)).to_le_bytes())
+            .collect::<Vec<_>>();
+        // The bytes past the decoded ones are cleared.
+        assert!(output[decoded_len..].iter().all(|&byte| byte == 0));
+        output[..decoded_len].to_vec()
+    }
+
+    #[test]
+    fn test_base64_decode() {
+        // The test vectors of RFC 4648.
+        assert_eq!(base64_decode(b""), b"");
+        assert_eq!(base64_decode(b"Zg=="), b"f");
+        assert_eq!(base64_decode(b"Zm8="), b"fo");
+        assert_eq!(base64_decode(b"Zm9v"), b"foo");
+        assert_eq!(base64_decode(b"Zm9vYg=="), b"foob");
+        assert_eq!(base64_decode(b"Zm9vYmE="), b"fooba");
+        assert_eq!(base64_decode(b"Zm9vYmFy"), b"foobar");
+
+        // Longer than one row of 4 groups.
+        assert_eq!(
+            base64_decode(b"VGhlIHF1aWNrIGJyb3duIGZveCBqdW1wcyBvdmVyIHRoZSBsYXp5IGRvZw=="),
+            b"The quick brown fox jumps over the lazy dog"
+        );
+    }
+
+    #[test]
+    fn test_base64_decode_alphabets() {
+        assert_eq!(base64_decode(b"+/+/"), [0xfb, 0xff, 0xbf]);
+        assert_eq!(base64_decode(b"-_-__g=="), [0xfb, 0xff, 0xbf, 0xfe]);
+        assert_eq!(base64_decode(b"AAAA"), [0, 0, 0]);
+        assert_eq!(base64_decode(b"////"), [0xff; 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero bits before the padding")]
+    fn test_base64_decode_non_canonical() {
+        // `h` leaves a non-zero low nibble in the second sextet.
+        base64_decode(b"Zh==");
+    }
+
+    #[test]
+    #[should_panic(expected = "padding is only allowed at the end")]
+    fn test_base64_decode_inner_padding() {
+        base64_decode(b"Zg==Zg==");
+    }
+
+    #[test]
+    #[should_panic(expected = "not padded to a multiple of 4")]
+    fn test_base64_decode_unpadded() {
+        base64_decode(b"Zg");
+    }
+}
@@ -0,0 +1,37 @@
+use crate::chips::chips::riscv_memory::event::{
+    MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord,
+};
+use serde::{Deserialize, Serialize};
+
+/// CRC-32 Event.
+///
+/// This event is emitted when the CRC-32 checksum of a buffer is computed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Crc32Event {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the data.
+    pub data_ptr: u32,
+    /// The pointer to the argument block `[len, crc_ptr]`.
+    pub args_ptr: u32,
+    /// The length of the data in bytes.
+    pub len: u32,
+    /// The pointer to the checksum.
+    pub crc_ptr: u32,
+    /// The words holding the data, the last one possibly only partially.
+    pub data: Vec<u32>,
+    /// The checksum before the data.
+    pub crc_in: u32,
+    /// The checksum after the data.
+    pub crc_out: u32,
+    /// The memory records for the argument block.
+    pub args_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the data.
+    pub data_read_records: Vec<MemoryReadRecord>,
+    /// The memory record for the checksum. The previous value is `crc_in`.
+    pub crc_write_record: MemoryWriteRecord,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,47 @@
+pub mod event;
+pub mod syscall;
+
+/// The number of words in the argument block of `CRC32`: `[len, crc_ptr]`.
+pub const CRC32_NUM_ARGS: usize = 2;
+
+/// The maximum number of bytes checksummed by a single `CRC32` call.
+pub const CRC32_MAX_LEN: u32 = 4096;
+
+/// The reflected CRC-32 (IEEE 802.3) polynomial.
+const CRC32_POLY: u32 = 0xedb8_8320;
+
+/// The CRC-32 table: entry `b` is the register after shifting the byte `b` into a zero register.
+pub const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut b = 0;
+    while b < 256 {
+        let mut entry = b as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            entry = if entry & 1 == 1 {
+                (entry >> 1) ^ CRC32_POLY
+            } else {
+                entry >> 1
+            };
+            bit += 1;
+        }
+        table[b] = entry;
+        b += 1;
+    }
+    table
+}
+
+/// Shifts one byte into the CRC-32 register.
+pub fn crc32_step(register: u32, byte: u8) -> u32 {
+    CRC32_TABLE[((register ^ byte as u32) & 0xff) as usize] ^ (register >> 8)
+}
+
+/// Continues the CRC-32 checksum `crc` over `bytes`. Starting from zero gives the CRC-32 of
+/// `bytes`, and a buffer checksummed in pieces gives the same result as in one go.
+pub fn crc32_update(crc: u32, bytes: &[u8]) -> u32 {
+    !bytes
+        .iter()
+        .fold(!crc, |register, &byte| crc32_step(register, byte))
+}
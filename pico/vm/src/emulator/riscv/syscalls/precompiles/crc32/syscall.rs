@@ -0,0 +1,153 @@
+use super::{crc32_update, event::Crc32Event, CRC32_MAX_LEN, CRC32_NUM_ARGS};
+use crate::emulator::riscv::syscalls::{
+    precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
+};
+
+/// Continues a CRC-32 checksum over a buffer.
+///
+/// `arg1` points to the data and `arg2` points to the argument block `[len, crc_ptr]`, where `len`
+/// is in bytes. The checksum at `crc_ptr` is replaced by its continuation over the data.
+pub(crate) struct Crc32Syscall;
+
+impl Syscall for Crc32Syscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let data_ptr = arg1;
+        if data_ptr % 4 != 0 {
+            return ctx.fail("data_ptr must be word aligned");
+        }
+        let args_ptr = arg2;
+        if args_ptr % 4 != 0 {
+            return ctx.fail("args_ptr must be word aligned");
+        }
+
+        // Read the argument block.
+        let (args_read_records, args) = ctx.mr_slice(args_ptr, CRC32_NUM_ARGS);
+        let (len, crc_ptr) = (args[0], args[1]);
+        if crc_ptr % 4 != 0 {
+            return ctx.fail("crc_ptr must be word aligned");
+        }
+        if len == 0 || len > CRC32_MAX_LEN {
+            return ctx.fail(format!(
+                "CRC32 expects between 1 and {CRC32_MAX_LEN} bytes, got {len}"
+            ));
+        }
+
+        let (data_read_records, data) = ctx.mr_slice(data_ptr, len.div_ceil(4) as usize);
+        let bytes = data
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .take(len as usize)
+            .collect::<Vec<_>>();
+        let crc_in = ctx.word_unsafe(crc_ptr);
+        let crc_out = crc32_update(crc_in, &bytes);
+
+        // Increment clk so that the write is not at the same cycle as the reads.
+        ctx.clk += 1;
+        let crc_write_record = ctx.mw(crc_ptr, crc_out);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::Crc32(Crc32Event {
+            chunk,
+            clk,
+            data_ptr,
+            args_ptr,
+            len,
+            crc_ptr,
+            data,
+            crc_in,
+            crc_out,
+            args_read_records,
+            data_read_records,
+            crc_write_record,
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::{
+        emulator::EmulationError,
+        syscalls::{
+            test_utils::{bytes_to_words, emulate, syscall_program, try_emulate},
+            SyscallCode,
+        },
+    };
+    use std::collections::BTreeMap;
+
+    const DATA_PTR: u32 = 0x1000;
+    const ARGS_PTR: u32 = 0x2000;
+    const CRC_PTR: u32 = 0x3000;
+
+    /// Continues `crc` over `data` with the `CRC32` syscall.
+    fn crc32(crc: u32, data: &[u8]) -> u32 {
+        let mut memory_image = bytes_to_words(DATA_PTR, data).collect::<BTreeMap<_, _>>();
+        memory_image.insert(ARGS_PTR, data.len() as u32);
+        memory_image.insert(ARGS_PTR + 4, CRC_PTR);
+        memory_image.insert(CRC_PTR, crc);
+        let mut emulator = emulate(syscall_program(
+            &[(SyscallCode::CRC32, DATA_PTR, ARGS_PTR)],
+            memory_image,
+        ));
+        emulator.word(CRC_PTR)
+    }
+
+    #[test]
+    fn test_crc32() {
+        let inputs: [&[u8]; 5] = [
+            b"a",
+            b"123456789",
+            b"The quick brown fox jumps over the lazy dog",
+            &[0u8; 64],
+            &(0..1000u32).map(|i| (i * 31 + 7) as u8).collect::<Vec<_>>(),
+        ];
+
+        for input in inputs {
+            assert_eq!(crc32(0, input), crc32fast::hash(input));
+        }
+    }
+
+    #[test]
+    fn test_crc32_continued() {
+        let data = b"checksummed in two pieces";
+        let (head, tail) = data.split_at(10);
+        assert_eq!(crc32(crc32(0, head), tail), crc32fast::hash(data));
+    }
+
+    #[test]
+    fn test_crc32_rejects_bad_arguments() {
+        let run = |data_ptr: u32, len: u32, crc_ptr: u32| {
+            let memory_image = BTreeMap::from([(ARGS_PTR, len), (ARGS_PTR + 4, crc_ptr)]);
+            let program =
+                syscall_program(&[(SyscallCode::CRC32, data_ptr, ARGS_PTR)], memory_image);
+            match try_emulate(program) {
+                Err(EmulationError::SyscallFailed(_, reason)) => reason,
+                result => panic!("expected the syscall to fail, got {:?}", result.map(|_| ())),
+            }
+        };
+
+        assert!(run(DATA_PTR + 1, 4, CRC_PTR).contains("data_ptr must be word aligned"));
+        assert!(run(DATA_PTR, 4, CRC_PTR + 2).contains("crc_ptr must be word aligned"));
+        assert!(run(DATA_PTR, 0, CRC_PTR).contains("got 0"));
+    }
+}
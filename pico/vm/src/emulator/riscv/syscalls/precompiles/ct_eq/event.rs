@@ -0,0 +1,41 @@
+use crate::chips::chips::riscv_memory::event::{
+    MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord,
+};
+use serde::{Deserialize, Serialize};
+
+/// Constant-Time Equality Event.
+///
+/// This event is emitted when two word buffers are compared for equality.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ConstantTimeEqEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the first buffer.
+    pub a_ptr: u32,
+    /// The pointer to the argument block.
+    pub args_ptr: u32,
+    /// The pointer to the second buffer.
+    pub b_ptr: u32,
+    /// The number of words in each buffer.
+    pub num_words: u32,
+    /// The pointer to the result.
+    pub result_ptr: u32,
+    /// The first buffer as a list of words.
+    pub a: Vec<u32>,
+    /// The second buffer as a list of words.
+    pub b: Vec<u32>,
+    /// The comparison result, 1 if the buffers are equal and 0 otherwise.
+    pub result: u32,
+    /// The memory records for the argument block.
+    pub args_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the first buffer.
+    pub a_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the second buffer.
+    pub b_read_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result.
+    pub result_write_record: MemoryWriteRecord,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,10 @@
+pub mod event;
+pub mod syscall;
+
+/// The number of words in the argument block of `CT_EQ`: `[b_ptr, num_words, result_ptr]`.
+pub const CT_EQ_NUM_ARGS: usize = 3;
+
+/// The maximum number of words compared by a single `CT_EQ` call.
+///
+/// This keeps the accumulated byte differences below the field modulus.
+pub const CT_EQ_MAX_WORDS: u32 = 1 << 20;
@@ -0,0 +1,158 @@
+use super::{event::ConstantTimeEqEvent, CT_EQ_MAX_WORDS, CT_EQ_NUM_ARGS};
+use crate::emulator::riscv::syscalls::{
+    precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
+};
+
+/// Compares two word buffers without early exit.
+///
+/// `arg1` points to the first buffer and `arg2` points to the argument block
+/// `[b_ptr, num_words, result_ptr]`. Writes 1 to the result if the buffers are equal, 0 otherwise.
+pub(crate) struct ConstantTimeEqSyscall;
+
+impl Syscall for ConstantTimeEqSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let a_ptr = arg1;
+        if a_ptr % 4 != 0 {
+            return ctx.fail(format!("{syscall_code}: a_ptr is unaligned"));
+        }
+        let args_ptr = arg2;
+        if args_ptr % 4 != 0 {
+            return ctx.fail(format!("{syscall_code}: args_ptr is unaligned"));
+        }
+
+        // Read the argument block.
+        let (args_read_records, args) = ctx.mr_slice(args_ptr, CT_EQ_NUM_ARGS);
+        let (b_ptr, num_words, result_ptr) = (args[0], args[1], args[2]);
+        if b_ptr % 4 != 0 || result_ptr % 4 != 0 {
+            return ctx.fail(format!("{syscall_code}: b_ptr or result is unaligned"));
+        }
+        if num_words == 0 || num_words > CT_EQ_MAX_WORDS {
+            return ctx.fail(format!(
+                "{syscall_code}: expects between 1 and {CT_EQ_MAX_WORDS} words, got {num_words}"
+            ));
+        }
+
+        // Read both buffers.
+        let (a_read_records, a) = ctx.mr_slice(a_ptr, num_words as usize);
+        let (b_read_records, b) = ctx.mr_slice(b_ptr, num_words as usize);
+
+        // XOR-accumulate over all the words, without short-circuiting.
+        let diff = a
+            .iter()
+            .zip(b.iter())
+            .fold(0u32, |acc, (x, y)| acc | (x ^ y));
+        let result = (diff == 0) as u32;
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        ctx.clk += 1;
+        let result_write_record = ctx.mw(result_ptr, result);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::ConstantTimeEq(ConstantTimeEqEvent {
+            chunk,
+            clk,
+            a_ptr,
+            args_ptr,
+            b_ptr,
+            num_words,
+            result_ptr,
+            a,
+            b,
+            result,
+            args_read_records,
+            a_read_records,
+            b_read_records,
+            result_write_record,
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::{
+        emulator::EmulationError,
+        syscalls::{
+            precompiles::ct_eq::CT_EQ_MAX_WORDS,
+            test_utils::{emulate, syscall_program, try_emulate},
+            SyscallCode,
+        },
+    };
+    use std::collections::BTreeMap;
+
+    const A_PTR: u32 = 0x1000;
+    const B_PTR: u32 = 0x2000;
+    const ARGS_PTR: u32 = 0x3000;
+    const RESULT_PTR: u32 = 0x4000;
+
+    fn ct_eq(a: &[u32], b: &[u32]) -> u32 {
+        let mut memory_image = BTreeMap::new();
+        for (i, (x, y)) in a.iter().zip(b.iter()).enumerate() {
+            memory_image.insert(A_PTR + 4 * i as u32, *x);
+            memory_image.insert(B_PTR + 4 * i as u32, *y);
+        }
+        memory_image.insert(ARGS_PTR, B_PTR);
+        memory_image.insert(ARGS_PTR + 4, a.len() as u32);
+        memory_image.insert(ARGS_PTR + 8, RESULT_PTR);
+        let emulator = emulate(syscall_program(
+            &[(SyscallCode::CT_EQ, A_PTR, ARGS_PTR)],
+            memory_image,
+        ));
+
+        emulator.state.memory.get(&RESULT_PTR).unwrap().value
+    }
+
+    #[test]
+    fn test_ct_eq_equal() {
+        let a = [0xdead_beef, 0, 0x0102_0304];
+        assert_eq!(ct_eq(&a, &a), 1);
+    }
+
+    #[test]
+    fn test_ct_eq_differing() {
+        let a = [0xdead_beef, 0, 0x0102_0304];
+        let b = [0xdead_beef, 0, 0x0102_0305];
+        assert_eq!(ct_eq(&a, &b), 0);
+        assert_eq!(ct_eq(&b, &a), 0);
+        assert_eq!(ct_eq(&a[..1], &b[..1]), 1);
+    }
+
+    #[test]
+    fn test_ct_eq_bad_length() {
+        for num_words in [0, CT_EQ_MAX_WORDS + 1] {
+            let memory_image = BTreeMap::from([
+                (ARGS_PTR, B_PTR),
+                (ARGS_PTR + 4, num_words),
+                (ARGS_PTR + 8, RESULT_PTR),
+            ]);
+            let result = try_emulate(syscall_program(
+                &[(SyscallCode::CT_EQ, A_PTR, ARGS_PTR)],
+                memory_image,
+            ));
+            assert!(matches!(
+                result,
+                Err(EmulationError::SyscallFailed(_, reason)) if reason.contains("expects between")
+            ));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod syscall;
+
+/// The maximum number of elements sorted by a single `FIELD_SORT` call.
+pub const FIELD_SORT_MAX_LEN: u32 = 256;
@@ -0,0 +1,121 @@
+use super::FIELD_SORT_MAX_LEN;
+use crate::emulator::riscv::syscalls::{
+    precompiles::{sort::sort_with_network, PrecompileEvent, SortU32Event},
+    syscall_context::SyscallContext,
+    Syscall, SyscallCode,
+};
+use p3_field::PrimeField32;
+use std::marker::PhantomData;
+
+/// Sorts an array of field elements in place, in ascending order of their canonical values.
+///
+/// `arg1` points to the array and `arg2` is its length in elements. Every element must be
+/// canonical, i.e. smaller than the order of `F`.
+pub(crate) struct FieldSortSyscall<F>(pub(crate) PhantomData<fn(F) -> F>);
+
+impl<F: PrimeField32> Syscall for FieldSortSyscall<F> {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let ptr = arg1;
+        if ptr % 4 != 0 {
+            return ctx.fail("ptr must be word aligned");
+        }
+        let len = arg2;
+        if len == 0 || len > FIELD_SORT_MAX_LEN {
+            return ctx.fail(format!(
+                "FIELD_SORT expects between 1 and {FIELD_SORT_MAX_LEN} elements, got {len}"
+            ));
+        }
+
+        let input = ctx.slice_unsafe(ptr, len as usize);
+        if let Some(value) = input.iter().find(|&&value| value >= F::ORDER_U32) {
+            return ctx.fail(format!(
+                "FIELD_SORT expects canonical field elements, got {value} >= {}",
+                F::ORDER_U32
+            ));
+        }
+        let mut output = input.clone();
+        sort_with_network(&mut output);
+
+        ctx.clk += 1;
+        let write_records = ctx.mw_slice(ptr, &output);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::FieldSort(SortU32Event {
+            chunk,
+            clk,
+            ptr,
+            input,
+            output,
+            write_records,
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::{
+        emulator::EmulationError,
+        syscalls::{
+            test_utils::{syscall_program, try_emulate},
+            SyscallCode,
+        },
+    };
+    use p3_baby_bear::BabyBear;
+    use p3_field::PrimeField32;
+
+    const PTR: u32 = 0x1000;
+
+    fn field_sort(values: &[u32]) -> Result<Vec<u32>, EmulationError> {
+        let memory_image = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (PTR + 4 * i as u32, *value))
+            .collect();
+        let mut emulator = try_emulate(syscall_program(
+            &[(SyscallCode::FIELD_SORT, PTR, values.len() as u32)],
+            memory_image,
+        ))?;
+
+        Ok((0..values.len() as u32)
+            .map(|i| emulator.word(PTR + 4 * i))
+            .collect())
+    }
+
+    #[test]
+    fn test_field_sort() {
+        let max = BabyBear::ORDER_U32 - 1;
+        let input = [5, max, 0, 1 << 30, 5, max, 1];
+        assert_eq!(field_sort(&input).unwrap(), [0, 1, 5, 5, 1 << 30, max, max]);
+    }
+
+    #[test]
+    fn test_field_sort_non_canonical() {
+        match field_sort(&[1, BabyBear::ORDER_U32]) {
+            Err(EmulationError::SyscallFailed(_, reason)) => {
+                assert!(reason.contains("canonical field elements"))
+            }
+            result => panic!("expected the syscall to fail, got {result:?}"),
+        }
+    }
+}
@@ -76,3 +76,43 @@ pub struct Fp2MulEvent {
     /// The local memory access records.
     pub local_mem_access: Vec<MemoryLocalEvent>,
 }
+
+/// Base field batch inversion events
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct FpBatchInvEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the elements, which are inverted in place.
+    pub ptr: u32,
+    /// The number of elements.
+    pub len: u32,
+    /// The elements before inversion.
+    pub a: Box<[u32]>,
+    /// The memory records for the elements.
+    pub memory_records: Box<[MemoryWriteRecord]>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// Base field inversion events
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct FpInvEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the input.
+    pub a_ptr: u32,
+    /// The input.
+    pub a: Box<[u32]>,
+    /// The pointer to the result.
+    pub result_ptr: u32,
+    /// The memory records for the input.
+    pub a_memory_records: Box<[MemoryReadRecord]>,
+    /// The memory records for the result.
+    pub result_memory_records: Box<[MemoryWriteRecord]>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
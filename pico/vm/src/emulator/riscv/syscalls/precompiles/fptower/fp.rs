@@ -1,14 +1,14 @@
-use crate::chips::gadgets::utils::field_params::{FieldType, FpOpField, NumWords};
+use crate::chips::{
+    gadgets::utils::field_params::{FpOpField, NumWords},
+    precompiles::fptower::fp::FieldChipTrace,
+};
 use hybrid_array::typenum::Unsigned;
 use num::BigUint;
 use std::marker::PhantomData;
 
 use crate::{
     chips::gadgets::field::field_op::FieldOperation,
-    emulator::riscv::syscalls::{
-        precompiles::{FpEvent, PrecompileEvent},
-        Syscall, SyscallCode, SyscallContext,
-    },
+    emulator::riscv::syscalls::{precompiles::FpEvent, Syscall, SyscallCode, SyscallContext},
 };
 
 pub struct FpSyscall<P> {
@@ -78,61 +78,15 @@ impl<P: FpOpField> Syscall for FpSyscall<P> {
         };
 
         // Group all of the events for a specific curve into the same syscall code key.
-        match P::FIELD_TYPE {
-            FieldType::Bn254 => {
-                let syscall_code_key = match syscall_code {
-                    SyscallCode::BN254_FP_ADD
-                    | SyscallCode::BN254_FP_SUB
-                    | SyscallCode::BN254_FP_MUL => SyscallCode::BN254_FP_ADD,
-                    _ => unreachable!(),
-                };
-
-                let syscall_event =
-                    rt.rt
-                        .syscall_event(clk, syscall_code.syscall_id(), x_ptr, y_ptr);
-                rt.record_mut().add_precompile_event(
-                    syscall_code_key,
-                    syscall_event,
-                    PrecompileEvent::Bn254Fp(event),
-                );
-            }
-
-            FieldType::Bls381 => {
-                let syscall_code_key = match syscall_code {
-                    SyscallCode::BLS12381_FP_ADD
-                    | SyscallCode::BLS12381_FP_SUB
-                    | SyscallCode::BLS12381_FP_MUL => SyscallCode::BLS12381_FP_ADD,
-                    _ => unreachable!(),
-                };
-
-                let syscall_event =
-                    rt.rt
-                        .syscall_event(clk, syscall_code.syscall_id(), x_ptr, y_ptr);
-                rt.record_mut().add_precompile_event(
-                    syscall_code_key,
-                    syscall_event,
-                    PrecompileEvent::Bls12381Fp(event),
-                );
-            }
-
-            FieldType::Secp256k1 => {
-                let syscall_code_key = match syscall_code {
-                    SyscallCode::SECP256K1_FP_ADD
-                    | SyscallCode::SECP256K1_FP_SUB
-                    | SyscallCode::SECP256K1_FP_MUL => SyscallCode::SECP256K1_FP_ADD,
-                    _ => unreachable!(),
-                };
-
-                let syscall_event =
-                    rt.rt
-                        .syscall_event(clk, syscall_code.syscall_id(), x_ptr, y_ptr);
-                rt.record_mut().add_precompile_event(
-                    syscall_code_key,
-                    syscall_event,
-                    PrecompileEvent::Secp256k1Fp(event),
-                );
-            }
-        }
+        assert!(P::SYSCALL_CODES.contains(&syscall_code));
+        let syscall_event = rt
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), x_ptr, y_ptr);
+        rt.record_mut().add_precompile_event(
+            P::SYSCALL_CODES[0],
+            syscall_event,
+            P::wrap_event(event),
+        );
 
         None
     }
@@ -0,0 +1,160 @@
+use crate::chips::gadgets::utils::field_params::{FieldType, FpOpField, NumWords};
+use hybrid_array::typenum::Unsigned;
+use num::{BigUint, One, Zero};
+use std::marker::PhantomData;
+
+use crate::emulator::riscv::syscalls::{
+    precompiles::{FpBatchInvEvent, PrecompileEvent},
+    Syscall, SyscallCode, SyscallContext,
+};
+
+/// Replaces each of the `len` consecutive elements of the base field `P` at `ptr` with its
+/// inverse.
+///
+/// The inverses are computed with Montgomery's trick, i.e. a single inversion of the product of
+/// all elements and three multiplications per element. Every element must be non-zero.
+pub struct FpBatchInvSyscall<P> {
+    _marker: PhantomData<fn(P) -> P>,
+}
+
+impl<P> FpBatchInvSyscall<P> {
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P: FpOpField> Syscall for FpBatchInvSyscall<P> {
+    fn emulate(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        ptr: u32,
+        len: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+        if ptr % 4 != 0 {
+            return rt.fail(format!("{syscall_code}: ptr is unaligned"));
+        }
+        if len == 0 {
+            return rt.fail(format!("{syscall_code}: nothing to invert"));
+        }
+
+        let num_words = <P as NumWords>::WordsFieldElement::USIZE;
+
+        let a = rt.slice_unsafe(ptr, len as usize * num_words);
+
+        let modulus = &BigUint::from_bytes_le(P::MODULUS);
+        let elements = a
+            .chunks_exact(num_words)
+            .map(|words| BigUint::from_slice(words) % modulus)
+            .collect::<Vec<_>>();
+        if elements.iter().any(|element| element.is_zero()) {
+            return rt.fail(format!("{syscall_code}: cannot invert zero"));
+        }
+
+        // prefix[i] is the product of the first i elements.
+        let mut prefix = Vec::with_capacity(elements.len() + 1);
+        prefix.push(BigUint::one());
+        for element in &elements {
+            prefix.push(prefix.last().unwrap() * element % modulus);
+        }
+        // The modulus is prime, so the inverse is a^(p - 2) by Fermat's little theorem.
+        let mut inv = prefix
+            .last()
+            .unwrap()
+            .modpow(&(modulus.clone() - 2u32), modulus);
+
+        let mut result = vec![0; a.len()];
+        for (i, element) in elements.iter().enumerate().rev() {
+            // inv is the inverse of the product of the first i + 1 elements.
+            let mut words = (&inv * &prefix[i] % modulus).to_u32_digits();
+            words.resize(num_words, 0);
+            result[i * num_words..(i + 1) * num_words].copy_from_slice(&words);
+            inv = inv * element % modulus;
+        }
+
+        rt.clk += 1;
+        let memory_records = rt.mw_slice(ptr, &result);
+
+        let event = FpBatchInvEvent {
+            chunk: rt.current_chunk(),
+            clk,
+            ptr,
+            len,
+            a: a.into_boxed_slice(),
+            memory_records: memory_records.into_boxed_slice(),
+            local_mem_access: rt.postprocess(),
+        };
+
+        let precompile_event = match P::FIELD_TYPE {
+            FieldType::Bn254 => PrecompileEvent::Bn254FpBatchInv(event),
+            FieldType::Bls381 => PrecompileEvent::Bls12381FpBatchInv(event),
+            FieldType::Secp256k1 => PrecompileEvent::Secp256k1FpBatchInv(event),
+        };
+
+        let syscall_event = rt
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), ptr, len);
+        rt.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, precompile_event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chips::gadgets::{
+            field::bn254::Bn254BaseField,
+            utils::field_params::{FieldParameters, NumWords},
+        },
+        emulator::riscv::syscalls::{
+            test_utils::{emulate, syscall_program},
+            SyscallCode,
+        },
+    };
+    use hybrid_array::typenum::Unsigned;
+    use num::{BigUint, One};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_bn254_fp_batch_inv() {
+        const PTR: u32 = 0x2000;
+        let num_words = <Bn254BaseField as NumWords>::WordsFieldElement::USIZE;
+        let modulus = Bn254BaseField::modulus();
+        // The last element is not reduced.
+        let inputs = [
+            BigUint::from(2u32),
+            BigUint::from(0xdead_beef_u32) << 200,
+            &modulus - 1u32,
+            &modulus + 5u32,
+        ];
+
+        let mut memory_image = BTreeMap::new();
+        for (i, input) in inputs.iter().enumerate() {
+            for (j, word) in input.to_u32_digits().into_iter().enumerate() {
+                memory_image.insert(PTR + 4 * (i * num_words + j) as u32, word);
+            }
+        }
+        let mut emulator = emulate(syscall_program(
+            &[(SyscallCode::BN254_FP_BATCH_INV, PTR, inputs.len() as u32)],
+            memory_image,
+        ));
+
+        for (i, input) in inputs.iter().enumerate() {
+            let words = (0..num_words)
+                .map(|j| emulator.word(PTR + 4 * (i * num_words + j) as u32))
+                .collect::<Vec<_>>();
+            let output = BigUint::from_slice(&words);
+            assert!(output < modulus);
+            assert_eq!(output * input % &modulus, BigUint::one());
+        }
+    }
+}
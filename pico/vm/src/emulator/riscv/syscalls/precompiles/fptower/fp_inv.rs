@@ -0,0 +1,88 @@
+use crate::chips::gadgets::utils::field_params::{FieldType, FpOpField, NumWords};
+use hybrid_array::typenum::Unsigned;
+use num::{BigUint, Zero};
+use std::marker::PhantomData;
+
+use crate::emulator::riscv::syscalls::{
+    precompiles::{FpInvEvent, PrecompileEvent},
+    Syscall, SyscallCode, SyscallContext,
+};
+
+/// Computes `result = a^{-1} mod p` for the base field `P`.
+///
+/// `a` is read from `a_ptr` and left untouched; the inverse is written to `result_ptr`. The two
+/// pointers may be equal to invert in place.
+pub struct FpInvSyscall<P> {
+    _marker: PhantomData<fn(P) -> P>,
+}
+
+impl<P> FpInvSyscall<P> {
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P: FpOpField> Syscall for FpInvSyscall<P> {
+    fn emulate(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        a_ptr: u32,
+        result_ptr: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+        if a_ptr % 4 != 0 || result_ptr % 4 != 0 {
+            return rt.fail(format!("{syscall_code}: pointers are unaligned"));
+        }
+
+        let num_words = <P as NumWords>::WordsFieldElement::USIZE;
+
+        let (a_memory_records, a) = rt.mr_slice(a_ptr, num_words);
+
+        let modulus = &BigUint::from_bytes_le(P::MODULUS);
+        let a_reduced = BigUint::from_slice(&a) % modulus;
+        if a_reduced.is_zero() {
+            return rt.fail(format!("{syscall_code}: cannot invert zero"));
+        }
+
+        // The modulus is prime, so the inverse is a^(p - 2) by Fermat's little theorem.
+        let result = a_reduced.modpow(&(modulus.clone() - 2u32), modulus);
+        let mut result = result.to_u32_digits();
+        result.resize(num_words, 0);
+
+        // The result is written one cycle after the read so that in-place inversion is sound.
+        rt.clk += 1;
+        let result_memory_records = rt.mw_slice(result_ptr, &result);
+
+        let event = FpInvEvent {
+            chunk: rt.current_chunk(),
+            clk,
+            a_ptr,
+            a: a.into_boxed_slice(),
+            result_ptr,
+            a_memory_records: a_memory_records.into_boxed_slice(),
+            result_memory_records: result_memory_records.into_boxed_slice(),
+            local_mem_access: rt.postprocess(),
+        };
+
+        let precompile_event = match P::FIELD_TYPE {
+            FieldType::Bn254 => PrecompileEvent::Bn254FpInv(event),
+            FieldType::Bls381 => PrecompileEvent::Bls12381FpInv(event),
+            FieldType::Secp256k1 => PrecompileEvent::Secp256k1FpInv(event),
+        };
+
+        let syscall_event = rt
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), a_ptr, result_ptr);
+        rt.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, precompile_event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
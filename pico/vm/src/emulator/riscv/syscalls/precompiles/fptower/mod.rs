@@ -2,3 +2,5 @@ pub mod event;
 pub mod fp;
 pub mod fp2_addsub;
 pub mod fp2_mul;
+pub mod fp_batch_inv;
+pub mod fp_inv;
@@ -0,0 +1,125 @@
+use super::permute::{STATE_NUM_WORDS, STATE_SIZE};
+use crate::emulator::riscv::syscalls::{
+    precompiles::{KeccakAbsorbEvent, PrecompileEvent},
+    syscall_context::SyscallContext,
+    Syscall, SyscallCode,
+};
+use tiny_keccak::keccakf;
+
+/// The rate of keccak-256 is 136 bytes, the first 17 lanes of the state, so 34 words.
+pub const RATE_NUM_WORDS: usize = 34;
+
+/// XORs a block of `RATE_NUM_WORDS` words into the state and permutes it.
+///
+/// The state and the block must not overlap.
+pub(crate) struct KeccakAbsorbSyscall;
+
+impl Syscall for KeccakAbsorbSyscall {
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = ctx.clk;
+        let state_ptr = arg1;
+        let block_ptr = arg2;
+
+        let (state_read_records, mut state_values) = ctx.mr_slice(state_ptr, STATE_NUM_WORDS);
+        let (block_read_records, block_values) = ctx.mr_slice(block_ptr, RATE_NUM_WORDS);
+
+        for (word, block_word) in state_values.iter_mut().zip(block_values) {
+            *word ^= block_word;
+        }
+        let pre_state: [u64; STATE_SIZE] = core::array::from_fn(|i| {
+            state_values[2 * i] as u64 + ((state_values[2 * i + 1] as u64) << 32)
+        });
+
+        let mut post_state = pre_state;
+        keccakf(&mut post_state);
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        ctx.clk += 1;
+        let values_to_write = post_state
+            .iter()
+            .flat_map(|lane| [*lane as u32, (lane >> 32) as u32])
+            .collect::<Vec<_>>();
+        let state_write_records = ctx.mw_slice(state_ptr, &values_to_write);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::KeccakAbsorb(KeccakAbsorbEvent {
+            chunk,
+            clk: start_clk,
+            pre_state,
+            post_state,
+            state_read_records,
+            block_read_records,
+            state_write_records,
+            state_addr: state_ptr,
+            block_addr: block_ptr,
+            local_mem_access: ctx.postprocess(),
+        });
+        let syscall_event = ctx
+            .rt
+            .syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RATE_NUM_WORDS;
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{emulate, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+    use tiny_keccak::keccakf;
+
+    #[test]
+    fn test_keccak_absorb_squeeze() {
+        const STATE_PTR: u32 = 0x1000;
+        const BLOCK_PTR: u32 = 0x2000;
+        const OUT_PTR: u32 = 0x3000;
+
+        let state: [u64; 25] = core::array::from_fn(|i| 0x0101_0101_0101_0101 * i as u64);
+        let block: [u32; RATE_NUM_WORDS] = core::array::from_fn(|i| 0xdead_beef ^ i as u32);
+        let mut memory_image = BTreeMap::new();
+        for (i, lane) in state.iter().enumerate() {
+            memory_image.insert(STATE_PTR + 8 * i as u32, *lane as u32);
+            memory_image.insert(STATE_PTR + 8 * i as u32 + 4, (lane >> 32) as u32);
+        }
+        for (i, word) in block.iter().enumerate() {
+            memory_image.insert(BLOCK_PTR + 4 * i as u32, *word);
+        }
+        let emulator = emulate(syscall_program(
+            &[
+                (SyscallCode::KECCAK_ABSORB, STATE_PTR, BLOCK_PTR),
+                (SyscallCode::KECCAK_SQUEEZE, STATE_PTR, OUT_PTR),
+            ],
+            memory_image,
+        ));
+
+        let mut expected = state;
+        for (i, lane) in expected.iter_mut().take(RATE_NUM_WORDS / 2).enumerate() {
+            *lane ^= block[2 * i] as u64 + ((block[2 * i + 1] as u64) << 32);
+        }
+        keccakf(&mut expected);
+
+        let word = |addr: u32| emulator.state.memory.get(&addr).unwrap().value;
+        for (i, lane) in expected.iter().enumerate() {
+            let addr = STATE_PTR + 8 * i as u32;
+            assert_eq!(word(addr) as u64 + ((word(addr + 4) as u64) << 32), *lane);
+        }
+        for i in 0..RATE_NUM_WORDS as u32 {
+            assert_eq!(word(OUT_PTR + 4 * i), word(STATE_PTR + 4 * i));
+        }
+    }
+}
@@ -27,3 +27,52 @@ pub struct KeccakPermuteEvent {
     /// The local memory access records.
     pub local_mem_access: Vec<MemoryLocalEvent>,
 }
+
+/// Keccak-256 Absorb Event.
+///
+/// This event is emitted when a block is absorbed into a keccak-256 sponge state, i.e. XORed into
+/// the rate of the state before the state is permuted.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct KeccakAbsorbEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The state after absorbing the block and before the permutation, as a list of u64 words.
+    pub pre_state: [u64; STATE_SIZE],
+    /// The post-state as a list of u64 words.
+    pub post_state: [u64; STATE_SIZE],
+    /// The memory records for the state before absorbing the block.
+    pub state_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the block.
+    pub block_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the post-state.
+    pub state_write_records: Vec<MemoryWriteRecord>,
+    /// The address of the state.
+    pub state_addr: u32,
+    /// The address of the block.
+    pub block_addr: u32,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// Keccak-256 Squeeze Event.
+///
+/// This event is emitted when the rate of a keccak-256 sponge state is copied out.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct KeccakSqueezeEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The memory records for the rate of the state.
+    pub state_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the output.
+    pub out_write_records: Vec<MemoryWriteRecord>,
+    /// The address of the state.
+    pub state_addr: u32,
+    /// The address of the output.
+    pub out_addr: u32,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -1,2 +1,4 @@
+pub mod absorb;
 pub mod event;
 pub mod permute;
+pub mod squeeze;
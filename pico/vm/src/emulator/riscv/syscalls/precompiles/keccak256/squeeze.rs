@@ -0,0 +1,52 @@
+use super::absorb::RATE_NUM_WORDS;
+use crate::emulator::riscv::syscalls::{
+    precompiles::{KeccakSqueezeEvent, PrecompileEvent},
+    syscall_context::SyscallContext,
+    Syscall, SyscallCode,
+};
+
+/// Copies the `RATE_NUM_WORDS` words of the rate of the state to the output.
+///
+/// The state is not permuted, squeezing more than one block takes a `KECCAK_PERMUTE` in between.
+pub(crate) struct KeccakSqueezeSyscall;
+
+impl Syscall for KeccakSqueezeSyscall {
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = ctx.clk;
+        let state_ptr = arg1;
+        let out_ptr = arg2;
+
+        let (state_read_records, rate) = ctx.mr_slice(state_ptr, RATE_NUM_WORDS);
+
+        // Increment the clk by 1 before writing, so that the output may overlap the state.
+        ctx.clk += 1;
+        let out_write_records = ctx.mw_slice(out_ptr, &rate);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::KeccakSqueeze(KeccakSqueezeEvent {
+            chunk,
+            clk: start_clk,
+            state_read_records,
+            out_write_records,
+            state_addr: state_ptr,
+            out_addr: out_ptr,
+            local_mem_access: ctx.postprocess(),
+        });
+        let syscall_event = ctx
+            .rt
+            .syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -1,9 +1,18 @@
+pub mod aes;
+pub mod base64;
+pub mod crc32;
+pub mod ct_eq;
 pub mod ec;
 pub mod edwards;
+pub mod field_sort;
 pub mod fptower;
 pub mod keccak256;
+pub mod poly_eval;
 pub mod poseidon2;
+pub mod range_check;
+pub mod rlp;
 pub mod sha256;
+pub mod sort;
 pub mod uint256;
 pub mod weierstrass;
 
@@ -15,12 +24,20 @@ use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
 use strum::{EnumIter, IntoEnumIterator};
 
+pub use aes::event::{Aes128EncryptBlockEvent, Aes256EncryptBlockEvent, AesKeyScheduleEvent};
+pub use base64::event::Base64DecodeEvent;
+pub use crc32::event::Crc32Event;
+pub use ct_eq::event::ConstantTimeEqEvent;
 pub use ec::event::{EllipticCurveDecompressEvent, EllipticCurveDoubleEvent};
 pub use edwards::event::{EdDecompressEvent, EllipticCurveAddEvent};
-pub use fptower::event::{Fp2AddSubEvent, Fp2MulEvent, FpEvent};
-pub use keccak256::event::KeccakPermuteEvent;
+pub use fptower::event::{Fp2AddSubEvent, Fp2MulEvent, FpBatchInvEvent, FpEvent, FpInvEvent};
+pub use keccak256::event::{KeccakAbsorbEvent, KeccakPermuteEvent, KeccakSqueezeEvent};
+pub use poly_eval::event::PolyEvalEvent;
 pub use poseidon2::event::Poseidon2PermuteEvent;
-pub use sha256::event::{ShaCompressEvent, ShaExtendEvent};
+pub use range_check::event::RangeCheckBatchEvent;
+pub use rlp::event::RlpDecodeItemEvent;
+pub use sha256::event::{MultiHashEvent, Sha256DigestEvent, ShaCompressEvent, ShaExtendEvent};
+pub use sort::event::SortU32Event;
 pub use uint256::event::Uint256MulEvent;
 
 #[derive(Clone, Debug, Serialize, Deserialize, EnumIter)]
@@ -30,8 +47,16 @@ pub enum PrecompileEvent {
     ShaExtend(ShaExtendEvent),
     /// Sha256 compress precompile event.
     ShaCompress(ShaCompressEvent),
+    /// Sha256 one-shot digest precompile event.
+    Sha256Digest(Sha256DigestEvent),
+    /// Batched Sha256 digest precompile event.
+    MultiHash(MultiHashEvent),
     /// Keccak256 permute precompile event.
     KeccakPermute(KeccakPermuteEvent),
+    /// Keccak256 absorb precompile event.
+    KeccakAbsorb(KeccakAbsorbEvent),
+    /// Keccak256 squeeze precompile event.
+    KeccakSqueeze(KeccakSqueezeEvent),
     /// Edwards curve add precompile event.
     EdAdd(EllipticCurveAddEvent),
     /// Edwards curve decompress precompile event.
@@ -50,6 +75,10 @@ pub enum PrecompileEvent {
     Bn254Double(EllipticCurveDoubleEvent),
     /// Bn254 base field operation precompile event.
     Bn254Fp(FpEvent),
+    /// Bn254 base field inversion precompile event.
+    Bn254FpInv(FpInvEvent),
+    /// Bn254 base field batch inversion precompile event.
+    Bn254FpBatchInv(FpBatchInvEvent),
     /// Bn254 quadratic field add/sub precompile event.
     Bn254Fp2AddSub(Fp2AddSubEvent),
     /// Bn254 quadratic field mul precompile event.
@@ -62,16 +91,48 @@ pub enum PrecompileEvent {
     Bls12381Decompress(EllipticCurveDecompressEvent),
     /// Bls12-381 base field operation precompile event.
     Bls12381Fp(FpEvent),
+    /// Bls12-381 base field inversion precompile event.
+    Bls12381FpInv(FpInvEvent),
+    /// Bls12-381 base field batch inversion precompile event.
+    Bls12381FpBatchInv(FpBatchInvEvent),
     /// Bls12-381 quadratic field add/sub precompile event.
     Bls12381Fp2AddSub(Fp2AddSubEvent),
     /// Bls12-381 quadratic field mul precompile event.
     Bls12381Fp2Mul(Fp2MulEvent),
     /// Secp256k1 base field operation precompile event.
     Secp256k1Fp(FpEvent),
+    /// Secp256k1 base field inversion precompile event.
+    Secp256k1FpInv(FpInvEvent),
+    /// Secp256k1 base field batch inversion precompile event.
+    Secp256k1FpBatchInv(FpBatchInvEvent),
     /// Uint256 mul precompile event.
     Uint256Mul(Uint256MulEvent),
     /// Poseidon2 Permute precompile event
     Poseidon2Permute(Poseidon2PermuteEvent),
+    /// Poseidon2 two-to-one compression precompile event.
+    Poseidon2Compress(Poseidon2PermuteEvent),
+    /// Polynomial evaluation precompile event.
+    PolyEval(PolyEvalEvent),
+    /// Constant-time equality precompile event.
+    ConstantTimeEq(ConstantTimeEqEvent),
+    /// Sort precompile event.
+    SortU32(SortU32Event),
+    /// AES-128 block encryption precompile event.
+    Aes128EncryptBlock(Aes128EncryptBlockEvent),
+    /// AES-256 key schedule precompile event.
+    AesKeySchedule(AesKeyScheduleEvent),
+    /// AES-256 block encryption precompile event.
+    Aes256EncryptBlock(Aes256EncryptBlockEvent),
+    /// RLP item header decoding precompile event.
+    RlpDecodeItem(RlpDecodeItemEvent),
+    /// Base64 decoding precompile event.
+    Base64Decode(Base64DecodeEvent),
+    /// Batch range check precompile event.
+    RangeCheckBatch(RangeCheckBatchEvent),
+    /// Field element sort precompile event.
+    FieldSort(SortU32Event),
+    /// CRC-32 precompile event.
+    Crc32(Crc32Event),
 }
 
 /// Trait to retrieve all the local memory events from a vec of precompile events.
@@ -92,9 +153,21 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::ShaCompress(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::Sha256Digest(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::MultiHash(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
                 PrecompileEvent::KeccakPermute(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::KeccakAbsorb(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::KeccakSqueeze(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
                 PrecompileEvent::EdDecompress(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
@@ -125,13 +198,53 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 | PrecompileEvent::Secp256k1Fp(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::Bls12381FpInv(e)
+                | PrecompileEvent::Bn254FpInv(e)
+                | PrecompileEvent::Secp256k1FpInv(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Bls12381FpBatchInv(e)
+                | PrecompileEvent::Bn254FpBatchInv(e)
+                | PrecompileEvent::Secp256k1FpBatchInv(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
                 PrecompileEvent::Bls12381Fp2AddSub(e) | PrecompileEvent::Bn254Fp2AddSub(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
                 PrecompileEvent::Bls12381Fp2Mul(e) | PrecompileEvent::Bn254Fp2Mul(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
-                PrecompileEvent::Poseidon2Permute(e) => {
+                PrecompileEvent::Poseidon2Permute(e) | PrecompileEvent::Poseidon2Compress(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::PolyEval(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::ConstantTimeEq(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::SortU32(e) | PrecompileEvent::FieldSort(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Aes128EncryptBlock(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::AesKeySchedule(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Aes256EncryptBlock(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::RlpDecodeItem(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Base64Decode(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::RangeCheckBatch(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Crc32(e) => {
                     iterators.push(e.local_mem_access.iter());
                 } // _ => { unreachable!()}
             }
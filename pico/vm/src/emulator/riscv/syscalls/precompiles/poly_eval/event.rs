@@ -0,0 +1,41 @@
+use crate::chips::chips::riscv_memory::event::{
+    MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord,
+};
+use serde::{Deserialize, Serialize};
+
+/// Polynomial Evaluation Event.
+///
+/// This event is emitted when a polynomial is evaluated at a point with Horner's method.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct PolyEvalEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the coefficients, lowest degree first.
+    pub coeffs_ptr: u32,
+    /// The pointer to the argument block.
+    pub args_ptr: u32,
+    /// The degree of the polynomial.
+    pub degree: u32,
+    /// The pointer to the evaluation point.
+    pub point_ptr: u32,
+    /// The pointer to the result.
+    pub result_ptr: u32,
+    /// The coefficients as a list of words.
+    pub coeffs: Vec<u32>,
+    /// The evaluation point.
+    pub point: u32,
+    /// The evaluation result.
+    pub result: u32,
+    /// The memory records for the argument block.
+    pub args_read_records: Vec<MemoryReadRecord>,
+    /// The memory record for the evaluation point.
+    pub point_read_record: MemoryReadRecord,
+    /// The memory records for the coefficients.
+    pub coeffs_read_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result.
+    pub result_write_record: MemoryWriteRecord,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
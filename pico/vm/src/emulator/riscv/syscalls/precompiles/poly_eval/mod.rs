@@ -0,0 +1,5 @@
+pub mod event;
+pub mod syscall;
+
+/// The number of words in the argument block of `POLY_EVAL`: `[degree, point_ptr, result_ptr]`.
+pub const POLY_EVAL_NUM_ARGS: usize = 3;
@@ -0,0 +1,89 @@
+use super::{event::PolyEvalEvent, POLY_EVAL_NUM_ARGS};
+use crate::emulator::riscv::syscalls::{
+    precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
+};
+use p3_field::{FieldAlgebra, PrimeField32};
+use std::marker::PhantomData;
+
+/// Evaluates a polynomial over the current prime field with Horner's method.
+///
+/// `arg1` points to the `degree + 1` coefficients, lowest degree first, and `arg2` points to the
+/// argument block `[degree, point_ptr, result_ptr]`. The coefficients, the argument block, the
+/// point and the result must not overlap.
+#[allow(clippy::type_complexity)]
+pub(crate) struct PolyEvalSyscall<F>(pub(crate) PhantomData<fn(F) -> F>);
+
+impl<F: PrimeField32> Syscall for PolyEvalSyscall<F> {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let coeffs_ptr = arg1;
+        if coeffs_ptr % 4 != 0 {
+            return ctx.fail(format!("{syscall_code}: coeffs_ptr is unaligned"));
+        }
+        let args_ptr = arg2;
+        if args_ptr % 4 != 0 {
+            return ctx.fail(format!("{syscall_code}: args_ptr is unaligned"));
+        }
+
+        // Read the argument block.
+        let (args_read_records, args) = ctx.mr_slice(args_ptr, POLY_EVAL_NUM_ARGS);
+        let (degree, point_ptr, result_ptr) = (args[0], args[1], args[2]);
+        if point_ptr % 4 != 0 || result_ptr % 4 != 0 {
+            return ctx.fail(format!("{syscall_code}: point or result is unaligned"));
+        }
+
+        // Read the point and the coefficients.
+        let (point_read_record, point) = ctx.mr(point_ptr);
+        let (coeffs_read_records, coeffs) = ctx.mr_slice(coeffs_ptr, degree as usize + 1);
+
+        // Horner's method, starting from the leading coefficient.
+        let x = F::from_wrapped_u32(point);
+        let result = coeffs
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, &c| acc * x + F::from_wrapped_u32(c))
+            .as_canonical_u32();
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        ctx.clk += 1;
+        let result_write_record = ctx.mw(result_ptr, result);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::PolyEval(PolyEvalEvent {
+            chunk,
+            clk,
+            coeffs_ptr,
+            args_ptr,
+            degree,
+            point_ptr,
+            result_ptr,
+            coeffs,
+            point,
+            result,
+            args_read_records,
+            point_read_record,
+            coeffs_read_records,
+            result_write_record,
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
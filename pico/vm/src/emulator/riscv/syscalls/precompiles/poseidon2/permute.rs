@@ -3,12 +3,18 @@ use crate::{
     emulator::riscv::syscalls::{
         precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
     },
-    primitives::{consts::PERMUTATION_WIDTH, Poseidon2Init},
+    primitives::{
+        consts::{DIGEST_SIZE, PERMUTATION_WIDTH},
+        Poseidon2Init,
+    },
 };
 use p3_field::PrimeField32;
 use p3_symmetric::Permutation;
 use std::marker::PhantomData;
 
+/// Permutes the 16 elements at `arg1` and writes them to `arg2` for `POSEIDON2_PERMUTE`. For
+/// `POSEIDON2_COMPRESS` the input is two digests `[left, right]`, and only the first 8 elements of
+/// the permutation, their Merkle parent, are written.
 #[allow(clippy::type_complexity)]
 pub(crate) struct Poseidon2PermuteSyscall<F>(pub(crate) PhantomData<fn(F) -> F>);
 
@@ -51,7 +57,15 @@ where
         // Increment the clk by 1 before writing because we read from memory at start_clk.
         ctx.clk += 1;
 
-        let write_records = ctx.mw_slice(output_memory_ptr, &state.map(|f| f.as_canonical_u32()));
+        let output_width = match syscall_code {
+            SyscallCode::POSEIDON2_COMPRESS => DIGEST_SIZE,
+            _ => PERMUTATION_WIDTH,
+        };
+        let output = state[..output_width]
+            .iter()
+            .map(|f| f.as_canonical_u32())
+            .collect::<Vec<_>>();
+        let write_records = ctx.mw_slice(output_memory_ptr, &output);
         state_write_records.extend_from_slice(&write_records);
 
         let chunk = ctx.current_chunk();
@@ -69,12 +83,53 @@ where
         let syscall_event = ctx
             .rt
             .syscall_event(clk_init, syscall_code.syscall_id(), arg1, arg2);
-        ctx.record_mut().add_precompile_event(
-            syscall_code,
-            syscall_event,
-            PrecompileEvent::Poseidon2Permute(event),
-        );
+        let event = match syscall_code {
+            SyscallCode::POSEIDON2_COMPRESS => PrecompileEvent::Poseidon2Compress(event),
+            _ => PrecompileEvent::Poseidon2Permute(event),
+        };
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
 
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::recursion::circuit::hash::FieldHasher,
+        configs::stark_config::BabyBearPoseidon2,
+        emulator::riscv::syscalls::{
+            test_utils::{emulate, syscall_program},
+            SyscallCode,
+        },
+    };
+    use p3_baby_bear::BabyBear;
+    use p3_field::{FieldAlgebra, PrimeField32};
+
+    #[test]
+    fn test_poseidon2_compress() {
+        let left: [BabyBear; 8] = core::array::from_fn(BabyBear::from_canonical_usize);
+        let right: [BabyBear; 8] = core::array::from_fn(|i| BabyBear::from_canonical_usize(i * 7));
+
+        let memory_image = left
+            .iter()
+            .chain(&right)
+            .enumerate()
+            .map(|(i, f)| (0x2000 + 4 * i as u32, f.as_canonical_u32()))
+            .collect();
+        let emulator = emulate(syscall_program(
+            &[(SyscallCode::POSEIDON2_COMPRESS, 0x2000, 0x3000)],
+            memory_image,
+        ));
+
+        let parent = (0..8)
+            .map(|i| emulator.state.memory.get(&(0x3000 + 4 * i)).unwrap().value)
+            .collect::<Vec<_>>();
+        let expected =
+            <BabyBearPoseidon2 as FieldHasher<BabyBear>>::constant_compress([left, right]);
+        assert_eq!(parent, expected.map(|f| f.as_canonical_u32()).to_vec());
+        // Only the parent is written.
+        assert!(emulator.state.memory.get(&(0x3000 + 4 * 8)).is_none());
+    }
+}
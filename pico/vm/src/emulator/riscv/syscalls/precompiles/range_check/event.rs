@@ -0,0 +1,29 @@
+use crate::chips::chips::riscv_memory::event::{MemoryLocalEvent, MemoryReadRecord};
+use serde::{Deserialize, Serialize};
+
+/// Range Check Batch Event.
+///
+/// This event is emitted when a buffer of words is checked to fit in a number of bits.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct RangeCheckBatchEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the values.
+    pub values_ptr: u32,
+    /// The pointer to the argument block.
+    pub args_ptr: u32,
+    /// The number of values.
+    pub n: u32,
+    /// The number of bits every value must fit in.
+    pub bits: u32,
+    /// The values checked.
+    pub values: Vec<u32>,
+    /// The memory records for the argument block.
+    pub args_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the values.
+    pub values_read_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
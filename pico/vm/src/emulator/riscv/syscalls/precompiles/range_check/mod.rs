@@ -0,0 +1,8 @@
+pub mod event;
+pub mod syscall;
+
+/// The number of words in the argument block of `RANGE_CHECK_BATCH`: `[n, bits]`.
+pub const RANGE_CHECK_BATCH_NUM_ARGS: usize = 2;
+
+/// The largest bit width `RANGE_CHECK_BATCH` checks values against.
+pub const RANGE_CHECK_BATCH_MAX_BITS: usize = 32;
@@ -0,0 +1,121 @@
+use super::{event::RangeCheckBatchEvent, RANGE_CHECK_BATCH_MAX_BITS, RANGE_CHECK_BATCH_NUM_ARGS};
+use crate::emulator::riscv::syscalls::{
+    precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
+};
+
+/// Checks that every word of a buffer fits in a number of bits.
+///
+/// `arg1` points to the values and `arg2` points to the argument block `[n, bits]`. Nothing is
+/// written: a value of `bits` bits or more fails the syscall, and so the emulation and the proof.
+pub(crate) struct RangeCheckBatchSyscall;
+
+impl Syscall for RangeCheckBatchSyscall {
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let values_ptr = arg1;
+        if values_ptr % 4 != 0 {
+            return ctx.fail("values_ptr must be word aligned");
+        }
+        let args_ptr = arg2;
+        if args_ptr % 4 != 0 {
+            return ctx.fail("args_ptr must be word aligned");
+        }
+
+        // Read the argument block.
+        let (args_read_records, args) = ctx.mr_slice(args_ptr, RANGE_CHECK_BATCH_NUM_ARGS);
+        let (n, bits) = (args[0], args[1]);
+        if n == 0 {
+            return ctx.fail("RANGE_CHECK_BATCH expects at least one value");
+        }
+        if bits as usize > RANGE_CHECK_BATCH_MAX_BITS {
+            return ctx.fail(format!(
+                "RANGE_CHECK_BATCH expects at most {RANGE_CHECK_BATCH_MAX_BITS} bits, got {bits}"
+            ));
+        }
+
+        let (values_read_records, values) = ctx.mr_slice(values_ptr, n as usize);
+        for (i, value) in values.iter().enumerate() {
+            if bits < 32 && *value >> bits != 0 {
+                return ctx.fail(format!(
+                    "RANGE_CHECK_BATCH value {i} is {value}, which does not fit in {bits} bits"
+                ));
+            }
+        }
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::RangeCheckBatch(RangeCheckBatchEvent {
+            chunk,
+            clk,
+            values_ptr,
+            args_ptr,
+            n,
+            bits,
+            values,
+            args_read_records,
+            values_read_records,
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::{
+        emulator::EmulationError,
+        syscalls::{
+            test_utils::{syscall_program, try_emulate},
+            SyscallCode,
+        },
+    };
+    use std::collections::BTreeMap;
+
+    const VALUES_PTR: u32 = 0x1000;
+    const ARGS_PTR: u32 = 0x2000;
+
+    fn range_check_batch(values: &[u32], bits: u32) -> Result<(), EmulationError> {
+        let mut memory_image = BTreeMap::new();
+        for (i, value) in values.iter().enumerate() {
+            memory_image.insert(VALUES_PTR + 4 * i as u32, *value);
+        }
+        memory_image.insert(ARGS_PTR, values.len() as u32);
+        memory_image.insert(ARGS_PTR + 4, bits);
+        try_emulate(syscall_program(
+            &[(SyscallCode::RANGE_CHECK_BATCH, VALUES_PTR, ARGS_PTR)],
+            memory_image,
+        ))
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_range_check_batch() {
+        // More values than fit in a row of the chip.
+        let values = (0..20).map(|i| i * 0x0c0c).collect::<Vec<_>>();
+        range_check_batch(&values, 16).unwrap();
+        range_check_batch(&[0, 0, 0], 0).unwrap();
+        range_check_batch(&[u32::MAX, 1], 32).unwrap();
+    }
+
+    #[test]
+    fn test_range_check_batch_out_of_range() {
+        let result = range_check_batch(&[0xfff, 0x1000], 12);
+        assert!(matches!(
+            result,
+            Err(EmulationError::SyscallFailed(_, reason)) if reason.contains("does not fit in 12 bits")
+        ));
+    }
+}
@@ -0,0 +1,29 @@
+use crate::chips::chips::riscv_memory::event::{
+    MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord,
+};
+use serde::{Deserialize, Serialize};
+
+/// RLP Decode Item Event.
+///
+/// This event is emitted when the header of an RLP item is decoded.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct RlpDecodeItemEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the encoded item, which need not be word aligned.
+    pub input_ptr: u32,
+    /// The pointer to the output.
+    pub output_ptr: u32,
+    /// The two words starting at the word that holds the prefix byte.
+    pub words: [u32; 2],
+    /// The output `[header_len, payload_len, is_list]`.
+    pub output: [u32; 3],
+    /// The memory records for the input words.
+    pub word_read_records: [MemoryReadRecord; 2],
+    /// The memory records for the output.
+    pub output_write_records: [MemoryWriteRecord; 3],
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,81 @@
+pub mod event;
+pub mod syscall;
+
+/// The number of kinds of RLP item headers.
+pub const RLP_NUM_KINDS: usize = 5;
+
+/// The index of each header kind in [`RLP_KIND_PREFIX_START`] and [`RLP_KIND_PREFIX_SPAN`].
+pub const RLP_SINGLE_BYTE: usize = 0;
+pub const RLP_SHORT_STRING: usize = 1;
+pub const RLP_LONG_STRING: usize = 2;
+pub const RLP_SHORT_LIST: usize = 3;
+pub const RLP_LONG_LIST: usize = 4;
+
+/// The maximum number of big-endian length bytes after a long string or list prefix. Longer
+/// payloads cannot fit in the 32-bit address space.
+pub const RLP_MAX_LEN_OF_LEN: usize = 4;
+
+/// The smallest prefix byte of each header kind.
+pub const RLP_KIND_PREFIX_START: [u8; RLP_NUM_KINDS] = [0x00, 0x80, 0xb8, 0xc0, 0xf8];
+
+/// The number of prefix bytes of each header kind after the smallest one.
+pub const RLP_KIND_PREFIX_SPAN: [u8; RLP_NUM_KINDS] = [0x7f, 0x37, 0x03, 0x37, 0x03];
+
+/// The number of bytes `RLP_DECODE_ITEM` reads from the input: the prefix and the longest
+/// length that follows it.
+pub const RLP_HEADER_MAX_LEN: usize = 1 + RLP_MAX_LEN_OF_LEN;
+
+/// A decoded RLP item header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RlpHeader {
+    /// The header kind, one of `RLP_SINGLE_BYTE` to `RLP_LONG_LIST`.
+    pub kind: usize,
+    /// The number of bytes before the payload.
+    pub header_len: u32,
+    /// The number of bytes of the payload.
+    pub payload_len: u32,
+}
+
+impl RlpHeader {
+    /// Whether the item is a list.
+    pub fn is_list(&self) -> bool {
+        self.kind == RLP_SHORT_LIST || self.kind == RLP_LONG_LIST
+    }
+}
+
+/// Decodes the header of the RLP item starting at `bytes[0]`. `bytes` must hold at least
+/// [`RLP_HEADER_MAX_LEN`] bytes; the ones past the header are ignored.
+///
+/// Only the header is decoded, so non-canonical encodings, such as lengths with leading zero
+/// bytes, are accepted. Fails on a long prefix with more than [`RLP_MAX_LEN_OF_LEN`] length bytes.
+pub fn rlp_decode_header(bytes: &[u8]) -> Result<RlpHeader, String> {
+    let prefix = bytes[0];
+    let kind = (0..RLP_NUM_KINDS)
+        .rev()
+        .find(|&kind| prefix >= RLP_KIND_PREFIX_START[kind])
+        .unwrap();
+    let rel = prefix - RLP_KIND_PREFIX_START[kind];
+    if rel > RLP_KIND_PREFIX_SPAN[kind] {
+        return Err(format!(
+            "RLP prefix {prefix:#04x} has more than {RLP_MAX_LEN_OF_LEN} length bytes"
+        ));
+    }
+
+    let (header_len, payload_len) = match kind {
+        RLP_SINGLE_BYTE => (0, 1),
+        RLP_SHORT_STRING | RLP_SHORT_LIST => (1, rel as u32),
+        _ => {
+            let len_of_len = rel as usize + 1;
+            let payload_len = bytes[1..=len_of_len]
+                .iter()
+                .fold(0, |acc, &byte| (acc << 8) | byte as u32);
+            (1 + len_of_len as u32, payload_len)
+        }
+    };
+
+    Ok(RlpHeader {
+        kind,
+        header_len,
+        payload_len,
+    })
+}
@@ -0,0 +1,127 @@
+use super::{event::RlpDecodeItemEvent, rlp_decode_header, RLP_HEADER_MAX_LEN};
+use crate::emulator::riscv::syscalls::{
+    precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
+};
+
+/// Decodes the header of one RLP item.
+///
+/// `arg1` points to the encoded item at any alignment, and `arg2` points to 3 words that receive
+/// `[header_len, payload_len, is_list]`. The payload starts `header_len` bytes after `arg1`.
+pub(crate) struct RlpDecodeItemSyscall;
+
+impl Syscall for RlpDecodeItemSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let input_ptr = arg1;
+        let output_ptr = arg2;
+        if output_ptr % 4 != 0 {
+            return ctx.fail("output_ptr must be word aligned");
+        }
+
+        // The prefix and up to four length bytes always lie within two words.
+        let offset = (input_ptr % 4) as usize;
+        let (word_read_records, words) = ctx.mr_slice(input_ptr - offset as u32, 2);
+        let bytes = words
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        let header = match rlp_decode_header(&bytes[offset..offset + RLP_HEADER_MAX_LEN]) {
+            Ok(header) => header,
+            Err(reason) => return ctx.fail(reason),
+        };
+        let output = [
+            header.header_len,
+            header.payload_len,
+            header.is_list() as u32,
+        ];
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        ctx.clk += 1;
+        let output_write_records = ctx.mw_slice(output_ptr, &output);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::RlpDecodeItem(RlpDecodeItemEvent {
+            chunk,
+            clk,
+            input_ptr,
+            output_ptr,
+            words: words.try_into().unwrap(),
+            output,
+            word_read_records: word_read_records.try_into().unwrap(),
+            output_write_records: output_write_records.try_into().unwrap(),
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{bytes_to_words, emulate, syscall_program},
+        SyscallCode,
+    };
+
+    const INPUT_PTR: u32 = 0x1000;
+    const OUTPUT_PTR: u32 = 0x4000;
+
+    /// Decodes the item at byte `offset` of `encoded`.
+    fn rlp_decode_item(encoded: &[u8], offset: u32) -> [u32; 3] {
+        let mut emulator = emulate(syscall_program(
+            &[(SyscallCode::RLP_DECODE_ITEM, INPUT_PTR + offset, OUTPUT_PTR)],
+            bytes_to_words(INPUT_PTR, encoded).collect(),
+        ));
+
+        [0, 1, 2].map(|i| emulator.word(OUTPUT_PTR + 4 * i))
+    }
+
+    #[test]
+    fn test_rlp_decode_item() {
+        // The examples of the RLP specification in the Ethereum yellow paper.
+        assert_eq!(rlp_decode_item(&[0x00], 0), [0, 1, 0]);
+        assert_eq!(rlp_decode_item(&[0x7f], 0), [0, 1, 0]);
+        assert_eq!(rlp_decode_item(&[0x80], 0), [1, 0, 0]);
+        assert_eq!(rlp_decode_item(b"\x83dog", 0), [1, 3, 0]);
+        assert_eq!(rlp_decode_item(b"\xc8\x83cat\x83dog", 0), [1, 8, 1]);
+        assert_eq!(rlp_decode_item(&[0xc0], 0), [1, 0, 1]);
+
+        // "Lorem ipsum dolor sit amet, consectetur adipisicing elit" is 56 bytes long.
+        let mut lorem = vec![0xb8, 0x38];
+        lorem.extend_from_slice(b"Lorem ipsum dolor sit amet, consectetur adipisicing elit");
+        assert_eq!(rlp_decode_item(&lorem, 0), [2, 56, 0]);
+
+        // A list with a 1024-byte payload, and a string with a 3-byte length.
+        assert_eq!(rlp_decode_item(&[0xf9, 0x04, 0x00], 0), [3, 1024, 1]);
+        assert_eq!(rlp_decode_item(&[0xba, 0x01, 0x00, 0x00], 0), [4, 65536, 0]);
+    }
+
+    #[test]
+    fn test_rlp_decode_item_unaligned() {
+        // The second item of the list ["cat", "dog"] starts at byte 5.
+        let encoded = b"\xc8\x83cat\x83dog";
+        assert_eq!(rlp_decode_item(encoded, 1), [1, 3, 0]);
+        assert_eq!(rlp_decode_item(encoded, 5), [1, 3, 0]);
+        assert_eq!(
+            rlp_decode_item(&[0, 0, 0, 0xbb, 1, 2, 3, 4], 3),
+            [5, 0x0102_0304, 0]
+        );
+    }
+}
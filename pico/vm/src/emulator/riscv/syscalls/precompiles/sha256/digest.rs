@@ -0,0 +1,215 @@
+use super::compress::SHA_COMPRESS_K;
+use crate::emulator::riscv::syscalls::{
+    precompiles::{PrecompileEvent, Sha256DigestEvent},
+    syscall_context::SyscallContext,
+    Syscall, SyscallCode,
+};
+
+/// The initial SHA-256 hash value.
+pub const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The message length limit of the `SHA256` precompile, in bytes. The chip relies on the bit
+/// length of the message fitting in 27 bits.
+pub const SHA256_MAX_LEN: u32 = 1 << 24;
+
+/// The number of 64-byte blocks of a `len`-byte message after padding.
+pub fn sha256_num_blocks(len: u32) -> usize {
+    (len as usize + 9).div_ceil(64)
+}
+
+/// Pads a `len`-byte message and returns it as big-endian SHA-256 words. `words` holds the
+/// message as it is laid out in memory; any bytes past `len` are ignored.
+pub fn sha256_padded_words(len: u32, words: &[u32]) -> Vec<u32> {
+    let mut bytes = words
+        .iter()
+        .flat_map(|word| word.to_le_bytes())
+        .take(len as usize)
+        .collect::<Vec<_>>();
+    bytes.push(0x80);
+    bytes.resize(sha256_num_blocks(len) * 64 - 8, 0);
+    bytes.extend_from_slice(&(u64::from(len) * 8).to_be_bytes());
+
+    bytes
+        .chunks_exact(4)
+        .map(|word| u32::from_be_bytes(word.try_into().unwrap()))
+        .collect()
+}
+
+/// Applies the SHA-256 compression function to `state` with one 16-word block.
+#[allow(clippy::many_single_char_names)]
+pub fn sha256_compress_block(state: &mut [u32; 8], block: &[u32]) {
+    let mut w = [0u32; 64];
+    w[..16].copy_from_slice(block);
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(SHA_COMPRESS_K[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    for (state, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *state = state.wrapping_add(value);
+    }
+}
+
+/// Hashes the length-prefixed buffer at `input_ptr` and writes the digest to `output_ptr`, reading
+/// at the current clock and writing one cycle later. The local memory accesses of the returned
+/// event are left to the caller. Fails on unaligned pointers or a message over the length limit.
+pub(crate) fn sha256_digest(
+    ctx: &mut SyscallContext,
+    input_ptr: u32,
+    output_ptr: u32,
+) -> Result<Sha256DigestEvent, String> {
+    if input_ptr % 4 != 0 || output_ptr % 4 != 0 {
+        return Err("sha256 input and output pointers must be word aligned".to_string());
+    }
+    let start_clk = ctx.clk;
+
+    let (len_read_record, len) = ctx.mr(input_ptr);
+    if len >= SHA256_MAX_LEN {
+        return Err(format!(
+            "sha256 input of {len} bytes exceeds the limit of {SHA256_MAX_LEN}"
+        ));
+    }
+    let (message_read_records, message) = ctx.mr_slice(input_ptr + 4, len.div_ceil(4) as usize);
+
+    let mut state = SHA256_IV;
+    for block in sha256_padded_words(len, &message).chunks_exact(16) {
+        sha256_compress_block(&mut state, block);
+    }
+
+    // The input may overlap the output, so write one cycle after the reads.
+    ctx.clk += 1;
+    let digest = state.map(u32::swap_bytes);
+    let output_write_records = ctx.mw_slice(output_ptr, &digest);
+
+    Ok(Sha256DigestEvent {
+        chunk: ctx.current_chunk(),
+        clk: start_clk,
+        input_ptr,
+        output_ptr,
+        len,
+        message,
+        len_read_record,
+        message_read_records,
+        output_write_records: output_write_records.try_into().unwrap(),
+        local_mem_access: Vec::new(),
+    })
+}
+
+/// Hashes a length-prefixed buffer in one call.
+///
+/// `arg1` points to a word holding the message length in bytes, followed by the message itself.
+/// `arg2` points to 8 words that receive the digest, laid out so that their bytes are the
+/// standard 32-byte SHA-256 output.
+pub(crate) struct Sha256DigestSyscall;
+
+impl Syscall for Sha256DigestSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = ctx.clk;
+        let mut event = match sha256_digest(ctx, arg1, arg2) {
+            Ok(event) => event,
+            Err(reason) => return ctx.fail(reason),
+        };
+        event.local_mem_access = ctx.postprocess();
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut().add_precompile_event(
+            syscall_code,
+            syscall_event,
+            PrecompileEvent::Sha256Digest(event),
+        );
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{bytes_to_words, emulate, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+
+    const INPUT_PTR: u32 = 0x1000;
+    const OUTPUT_PTR: u32 = 0x8000;
+
+    fn sha256(message: &[u8]) -> String {
+        let mut memory_image = bytes_to_words(INPUT_PTR + 4, message).collect::<BTreeMap<_, _>>();
+        memory_image.insert(INPUT_PTR, message.len() as u32);
+        let mut emulator = emulate(syscall_program(
+            &[(SyscallCode::SHA256, INPUT_PTR, OUTPUT_PTR)],
+            memory_image,
+        ));
+
+        let digest = (0..8)
+            .flat_map(|i| emulator.word(OUTPUT_PTR + 4 * i).to_le_bytes())
+            .collect::<Vec<_>>();
+        hex::encode(digest)
+    }
+
+    #[test]
+    fn test_sha256_digest() {
+        assert_eq!(
+            sha256(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        // 55 bytes still leave room for the length in the first block, 56 bytes do not.
+        assert_eq!(
+            sha256(&[b'a'; 55]),
+            "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"
+        );
+        assert_eq!(
+            sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        assert_eq!(
+            sha256(&[b'a'; 1000]),
+            "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+        );
+    }
+}
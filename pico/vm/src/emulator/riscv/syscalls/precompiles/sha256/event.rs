@@ -54,3 +54,55 @@ pub struct ShaCompressEvent {
     /// The local memory accesses.
     pub local_mem_access: Vec<MemoryLocalEvent>,
 }
+
+/// SHA-256 Digest Event.
+///
+/// This event is emitted when a length-prefixed buffer is hashed with the one-shot SHA-256
+/// precompile.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Sha256DigestEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the length-prefixed input.
+    pub input_ptr: u32,
+    /// The pointer to the digest.
+    pub output_ptr: u32,
+    /// The message length in bytes.
+    pub len: u32,
+    /// The memory words holding the message.
+    pub message: Vec<u32>,
+    /// The memory read of the message length.
+    pub len_read_record: MemoryReadRecord,
+    /// The memory reads of the message words.
+    pub message_read_records: Vec<MemoryReadRecord>,
+    /// The memory writes of the digest.
+    pub output_write_records: [MemoryWriteRecord; 8],
+    /// The local memory accesses.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// Multi-Hash Event.
+///
+/// This event is emitted when several independent buffers are hashed with one `MULTI_HASH`
+/// call. All digests are clocked at the cycle of the call.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct MultiHashEvent {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The hash function, currently always `MULTI_HASH_SHA256`.
+    pub hash_type: u32,
+    /// The pointer to the descriptor block.
+    pub descriptor_ptr: u32,
+    /// The memory read of the number of digests.
+    pub num_read_record: MemoryReadRecord,
+    /// The memory reads of the input and output pointer of each digest.
+    pub descriptor_read_records: Vec<[MemoryReadRecord; 2]>,
+    /// The individual digests, without local memory accesses of their own.
+    pub digests: Vec<Sha256DigestEvent>,
+    /// The local memory accesses.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
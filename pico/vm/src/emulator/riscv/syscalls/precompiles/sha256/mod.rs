@@ -1,3 +1,5 @@
 pub mod compress;
+pub mod digest;
 pub mod event;
 pub mod extend;
+pub mod multi_hash;
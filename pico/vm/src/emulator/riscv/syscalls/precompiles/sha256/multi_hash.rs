@@ -0,0 +1,211 @@
+use super::digest::sha256_digest;
+use crate::emulator::riscv::syscalls::{
+    precompiles::{MultiHashEvent, PrecompileEvent},
+    syscall_context::SyscallContext,
+    Syscall, SyscallCode,
+};
+use hashbrown::HashSet;
+
+/// The `MULTI_HASH` hash type of SHA-256 digests.
+pub const MULTI_HASH_SHA256: u32 = 0;
+
+/// The maximum number of digests in one `MULTI_HASH` call.
+pub const MULTI_HASH_MAX_DIGESTS: u32 = 32;
+
+/// Computes several independent digests in one call.
+///
+/// `arg1` is the hash type, which must be `MULTI_HASH_SHA256`. `arg2` points to a descriptor
+/// block holding the number of digests `n`, followed by `n` pairs of input and output pointers.
+/// Each pair is handled like the arguments of a `SHA256` call.
+///
+/// All digests read at the cycle of the call and write one cycle later, so the digests must not
+/// share memory with each other or with the descriptor block.
+pub(crate) struct MultiHashSyscall;
+
+impl Syscall for MultiHashSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let hash_type = arg1;
+        let descriptor_ptr = arg2;
+        if hash_type != MULTI_HASH_SHA256 {
+            return ctx.fail(format!("multi_hash does not support hash type {hash_type}"));
+        }
+        if descriptor_ptr % 4 != 0 {
+            return ctx.fail("multi_hash descriptor pointer must be word aligned".to_string());
+        }
+        let start_clk = ctx.clk;
+
+        let (num_read_record, num_digests) = ctx.mr(descriptor_ptr);
+        if !(1..=MULTI_HASH_MAX_DIGESTS).contains(&num_digests) {
+            return ctx.fail(format!(
+                "multi_hash takes between 1 and {MULTI_HASH_MAX_DIGESTS} digests, got {num_digests}"
+            ));
+        }
+
+        let mut touched = (0..=2 * num_digests)
+            .map(|i| descriptor_ptr + 4 * i)
+            .collect::<HashSet<_>>();
+        let mut descriptor_read_records = Vec::with_capacity(num_digests as usize);
+        let mut digests = Vec::with_capacity(num_digests as usize);
+        for i in 0..num_digests {
+            ctx.clk = start_clk;
+            let (records, pointers) = ctx.mr_slice(descriptor_ptr + 4 + 8 * i, 2);
+            descriptor_read_records.push(records.try_into().unwrap());
+            let digest = match sha256_digest(ctx, pointers[0], pointers[1]) {
+                Ok(digest) => digest,
+                Err(reason) => return ctx.fail(reason),
+            };
+
+            let words = (0..=digest.len.div_ceil(4))
+                .map(|j| digest.input_ptr + 4 * j)
+                .chain((0..8).map(|j| digest.output_ptr + 4 * j))
+                .collect::<HashSet<_>>();
+            if !touched.is_disjoint(&words) {
+                return ctx.fail(format!(
+                    "multi_hash digest {i} shares memory with an earlier digest or the descriptors"
+                ));
+            }
+            touched.extend(words);
+            digests.push(digest);
+        }
+
+        let event = PrecompileEvent::MultiHash(MultiHashEvent {
+            chunk: ctx.current_chunk(),
+            clk: start_clk,
+            hash_type,
+            descriptor_ptr,
+            num_read_record,
+            descriptor_read_records,
+            digests,
+            local_mem_access: ctx.postprocess(),
+        });
+        let syscall_event = ctx
+            .rt
+            .syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MULTI_HASH_SHA256;
+    use crate::emulator::{
+        opts::EmulatorOpts,
+        riscv::{
+            emulator::{EmulationError, RiscvEmulator},
+            syscalls::{
+                precompiles::PrecompileEvent,
+                test_utils::{bytes_to_words, syscall_program},
+                SyscallCode,
+            },
+        },
+    };
+    use p3_baby_bear::BabyBear;
+    use std::collections::BTreeMap;
+
+    const DESCRIPTOR_PTR: u32 = 0x4000;
+    const INPUT_PTR: u32 = 0x2000;
+    const OUTPUT_PTR: u32 = 0x8000;
+
+    /// The memory image of a batch hashing `messages`, the `i`-th one read from `input_ptrs[i]`.
+    fn batch_memory(messages: &[&[u8]], input_ptrs: &[u32]) -> BTreeMap<u32, u32> {
+        let mut memory_image = BTreeMap::from([(DESCRIPTOR_PTR, messages.len() as u32)]);
+        for (i, (message, &input_ptr)) in messages.iter().zip(input_ptrs).enumerate() {
+            memory_image.extend(bytes_to_words(input_ptr + 4, message));
+            memory_image.insert(input_ptr, message.len() as u32);
+            memory_image.insert(DESCRIPTOR_PTR + 4 + 8 * i as u32, input_ptr);
+            memory_image.insert(
+                DESCRIPTOR_PTR + 8 + 8 * i as u32,
+                OUTPUT_PTR + 32 * i as u32,
+            );
+        }
+        memory_image
+    }
+
+    /// The reason the batch fails with.
+    fn failure(hash_type: u32, memory_image: BTreeMap<u32, u32>) -> String {
+        let program = syscall_program(
+            &[(SyscallCode::MULTI_HASH, hash_type, DESCRIPTOR_PTR)],
+            memory_image,
+        );
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::default());
+        match emulator.run(None) {
+            Err(EmulationError::SyscallFailed(_, reason)) => reason,
+            _ => panic!("multi_hash did not fail"),
+        }
+    }
+
+    #[test]
+    fn test_multi_hash() {
+        // The last message repeats the first one from its own buffer.
+        let messages: [&[u8]; 4] = [b"abc", b"", &[b'a'; 1000], b"abc"];
+        let input_ptrs = [
+            INPUT_PTR,
+            INPUT_PTR + 0x100,
+            INPUT_PTR + 0x200,
+            INPUT_PTR + 0x600,
+        ];
+        let program = syscall_program(
+            &[(SyscallCode::MULTI_HASH, MULTI_HASH_SHA256, DESCRIPTOR_PTR)],
+            batch_memory(&messages, &input_ptrs),
+        );
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::default());
+        let records = emulator.run(None).unwrap();
+
+        let digests = (0..4)
+            .map(|i| {
+                let digest = (0..8)
+                    .flat_map(|j| emulator.word(OUTPUT_PTR + 32 * i + 4 * j).to_le_bytes())
+                    .collect::<Vec<_>>();
+                hex::encode(digest)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            digests,
+            [
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+                "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3",
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ]
+        );
+
+        let events = records
+            .iter()
+            .filter_map(|record| record.precompile_events.get_events(SyscallCode::MULTI_HASH))
+            .flatten()
+            .collect::<Vec<_>>();
+        assert_eq!(events.len(), 1);
+        let PrecompileEvent::MultiHash(event) = &events[0].1 else {
+            unreachable!()
+        };
+        assert!(event.digests.iter().all(|digest| digest.clk == event.clk));
+    }
+
+    #[test]
+    fn test_multi_hash_rejects_bad_batches() {
+        let messages: [&[u8]; 2] = [b"abc", b"abc"];
+        let memory_image = batch_memory(&messages, &[INPUT_PTR, INPUT_PTR + 0x100]);
+
+        assert!(failure(1, memory_image.clone()).contains("hash type 1"));
+
+        let mut empty = memory_image.clone();
+        empty.insert(DESCRIPTOR_PTR, 0);
+        assert!(failure(MULTI_HASH_SHA256, empty).contains("got 0"));
+
+        let shared = batch_memory(&messages, &[INPUT_PTR, INPUT_PTR]);
+        assert!(failure(MULTI_HASH_SHA256, shared).contains("digest 1 shares memory"));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::chips::chips::riscv_memory::event::{MemoryLocalEvent, MemoryWriteRecord};
+use serde::{Deserialize, Serialize};
+
+/// Sort Event.
+///
+/// This event is emitted when an array of words is sorted in place.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct SortU32Event {
+    /// The chunk number.
+    pub chunk: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the array.
+    pub ptr: u32,
+    /// The array before sorting.
+    pub input: Vec<u32>,
+    /// The array after sorting.
+    pub output: Vec<u32>,
+    /// The memory records for the array. The previous values of the writes are the input.
+    pub write_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
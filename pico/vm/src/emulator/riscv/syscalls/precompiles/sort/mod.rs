@@ -0,0 +1,64 @@
+pub mod event;
+pub mod syscall;
+
+/// The maximum number of words sorted by a single `SORT_U32` call.
+pub const SORT_U32_MAX_LEN: u32 = 256;
+
+/// The compare-exchange pairs of Batcher's odd-even merge sort over `n` elements.
+///
+/// The network is built for the next power of two and every pair touching an index `>= n` is
+/// dropped, which is the same as padding the input with `u32::MAX`. Each pair `(i, j)` has
+/// `i < j` and moves the smaller value to `i`.
+pub fn sorting_network(n: usize) -> Vec<(usize, usize)> {
+    let size = n.next_power_of_two();
+    let mut pairs = Vec::new();
+
+    let mut p = 1;
+    while p < size {
+        let mut k = p;
+        while k >= 1 {
+            for j in (k % p..size - k).step_by(2 * k) {
+                for i in 0..k.min(size - j - k) {
+                    let (a, b) = (i + j, i + j + k);
+                    if a / (2 * p) == b / (2 * p) && b < n {
+                        pairs.push((a, b));
+                    }
+                }
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+
+    pairs
+}
+
+/// Sorts `values` in place by running them through [`sorting_network`].
+pub fn sort_with_network(values: &mut [u32]) {
+    for (i, j) in sorting_network(values.len()) {
+        if values[i] > values[j] {
+            values.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorting_network_zero_one() {
+        // By the 0-1 principle, a comparator network sorts every input iff it sorts every
+        // sequence of zeros and ones.
+        for n in 1..=12 {
+            for bits in 0u32..(1 << n) {
+                let mut values = (0..n).map(|i| (bits >> i) & 1).collect::<Vec<_>>();
+                sort_with_network(&mut values);
+                assert!(
+                    values.windows(2).all(|w| w[0] <= w[1]),
+                    "n = {n}, bits = {bits:b}"
+                );
+            }
+        }
+    }
+}
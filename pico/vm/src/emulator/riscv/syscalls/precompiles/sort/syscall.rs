@@ -0,0 +1,118 @@
+use super::{event::SortU32Event, sort_with_network, SORT_U32_MAX_LEN};
+use crate::emulator::riscv::syscalls::{
+    precompiles::PrecompileEvent, syscall_context::SyscallContext, Syscall, SyscallCode,
+};
+
+/// Sorts an array of words in place, in ascending order.
+///
+/// `arg1` points to the array and `arg2` is its length in words.
+pub(crate) struct SortU32Syscall;
+
+impl Syscall for SortU32Syscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = ctx.clk;
+
+        let ptr = arg1;
+        if ptr % 4 != 0 {
+            return ctx.fail("ptr must be word aligned");
+        }
+        let len = arg2;
+        if len == 0 || len > SORT_U32_MAX_LEN {
+            return ctx.fail(format!(
+                "SORT_U32 expects between 1 and {SORT_U32_MAX_LEN} words, got {len}"
+            ));
+        }
+
+        // The previous values of the writes are the input, so the array does not need a separate
+        // read.
+        let input = ctx.slice_unsafe(ptr, len as usize);
+        let mut output = input.clone();
+        sort_with_network(&mut output);
+
+        // Write one cycle after the syscall, like the other precompiles that write to memory.
+        ctx.clk += 1;
+        let write_records = ctx.mw_slice(ptr, &output);
+
+        let chunk = ctx.current_chunk();
+        let event = PrecompileEvent::SortU32(SortU32Event {
+            chunk,
+            clk,
+            ptr,
+            input,
+            output,
+            write_records,
+            local_mem_access: ctx.postprocess(),
+        });
+
+        let syscall_event = ctx
+            .rt
+            .syscall_event(clk, syscall_code.syscall_id(), arg1, arg2);
+        ctx.record_mut()
+            .add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{emulate, syscall_program},
+        SyscallCode,
+    };
+    use hashbrown::HashMap;
+
+    const PTR: u32 = 0x1000;
+
+    fn sort_u32(values: &[u32]) -> Vec<u32> {
+        let memory_image = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (PTR + 4 * i as u32, *value))
+            .collect();
+        let mut emulator = emulate(syscall_program(
+            &[(SyscallCode::SORT_U32, PTR, values.len() as u32)],
+            memory_image,
+        ));
+
+        (0..values.len() as u32)
+            .map(|i| emulator.word(PTR + 4 * i))
+            .collect()
+    }
+
+    fn counts(values: &[u32]) -> HashMap<u32, usize> {
+        let mut counts = HashMap::new();
+        for value in values {
+            *counts.entry(*value).or_default() += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_sort_u32() {
+        let inputs: [&[u32]; 4] = [
+            &[7],
+            &[3, 1, 2],
+            &[u32::MAX, 0, 0x8000_0000, 5, 5, 0x7fff_ffff, 1, u32::MAX, 0],
+            &(0..100u32)
+                .map(|i| i.wrapping_mul(0x9e37_79b9) >> (i % 7))
+                .collect::<Vec<_>>(),
+        ];
+
+        for input in inputs {
+            let output = sort_u32(input);
+            assert!(output.windows(2).all(|w| w[0] <= w[1]));
+            assert_eq!(counts(&output), counts(input));
+        }
+    }
+}
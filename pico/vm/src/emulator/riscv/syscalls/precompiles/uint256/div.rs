@@ -0,0 +1,103 @@
+use num::{BigUint, Integer, Zero};
+
+use crate::{
+    chips::precompiles::uint256::UINT256_NUM_WORDS,
+    emulator::riscv::syscalls::{syscall_context::SyscallContext, Syscall, SyscallCode},
+};
+
+/// Divides the dividend by the divisor, taking `[dividend, divisor]` as 16 little endian words at
+/// `arg1` and writing 8 words at `arg2`: the quotient for `UINT256_DIV` and the remainder for
+/// `UINT256_REM`. As in RISC-V, dividing by zero gives a quotient of `2^256 - 1` and leaves the
+/// dividend as the remainder.
+///
+/// Neither result is constrained by the proof. It is placed as the initial value of fresh memory,
+/// as in `HINT_READ`, and the guest is expected to check it on `UINT256_MUL`: the remainder as
+/// `dividend * 1 mod divisor`, and the quotient as `quotient * divisor + remainder = dividend`
+/// modulo `2^256`, which only has one solution below `2^256 >> divisor.trailing_zeros()`.
+pub(crate) struct Uint256DivSyscall;
+
+impl Syscall for Uint256DivSyscall {
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        input_ptr: u32,
+        out_ptr: u32,
+    ) -> Option<u32> {
+        assert_eq!(input_ptr % 4, 0, "uint256 div input not aligned to 4 bytes");
+        assert_eq!(out_ptr % 4, 0, "uint256 div output not aligned to 4 bytes");
+
+        let input = ctx.slice_unsafe(input_ptr, 2 * UINT256_NUM_WORDS);
+        let (dividend, divisor) = input.split_at(UINT256_NUM_WORDS);
+        let dividend = BigUint::from_slice(dividend);
+        let divisor = BigUint::from_slice(divisor);
+
+        let (quotient, remainder) = if divisor.is_zero() {
+            ((BigUint::from(1u32) << 256) - 1u32, dividend)
+        } else {
+            dividend.div_rem(&divisor)
+        };
+        let result = match syscall_code {
+            SyscallCode::UINT256_DIV => quotient,
+            SyscallCode::UINT256_REM => remainder,
+            _ => unreachable!(),
+        };
+
+        let mut words = result.to_u32_digits();
+        words.resize(UINT256_NUM_WORDS, 0);
+        for (i, word) in words.into_iter().enumerate() {
+            ctx.rt
+                .state
+                .uninitialized_memory
+                .entry(out_ptr + 4 * i as u32)
+                .and_modify(|_| panic!("uint256 div output address is initialized already"))
+                .or_insert(word);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emulator::riscv::syscalls::{
+        test_utils::{emulate, syscall_program},
+        SyscallCode,
+    };
+    use std::collections::BTreeMap;
+
+    /// Runs `code` on `[dividend, divisor]` and returns the two lowest words of the result.
+    fn run(code: SyscallCode, dividend: [u32; 2], divisor: [u32; 2]) -> [u32; 2] {
+        let memory_image = BTreeMap::from([
+            (0x1000, dividend[0]),
+            (0x1004, dividend[1]),
+            (0x1020, divisor[0]),
+            (0x1024, divisor[1]),
+        ]);
+        let mut emulator = emulate(syscall_program(&[(code, 0x1000, 0x2000)], memory_image));
+        [emulator.word(0x2000), emulator.word(0x2004)]
+    }
+
+    #[test]
+    fn test_uint256_div_rem_syscalls() {
+        // The quotient of a two word dividend by 3 spans both words.
+        let dividend = [5, 7];
+        let divisor = [3, 0];
+        let n = (7u64 << 32) + 5;
+        let q = n / 3;
+        assert_eq!(
+            run(SyscallCode::UINT256_DIV, dividend, divisor),
+            [q as u32, (q >> 32) as u32]
+        );
+        assert_eq!(
+            run(SyscallCode::UINT256_REM, dividend, divisor),
+            [(n % 3) as u32, 0]
+        );
+
+        // Dividing by zero follows RISC-V.
+        assert_eq!(
+            run(SyscallCode::UINT256_DIV, dividend, [0, 0]),
+            [u32::MAX, u32::MAX]
+        );
+        assert_eq!(run(SyscallCode::UINT256_REM, dividend, [0, 0]), dividend);
+    }
+}
@@ -1,2 +1,3 @@
+pub mod div;
 pub mod event;
 pub mod syscall;
@@ -0,0 +1,71 @@
+use super::{Syscall, SyscallCode, SyscallContext};
+
+/// The largest number of words a single `MEMORY_PREFETCH` reserves room for.
+pub const MEMORY_PREFETCH_MAX_WORDS: usize = 1 << 24;
+
+/// Reserves room in the emulator memory for the words in `[arg1, arg2)`.
+///
+/// This is only a hint to the emulator, so that the first touch of a large input does not rehash
+/// the memory table over and over. No memory records are created: a record for a word the program
+/// never touches would still produce memory initialize and finalize events, and the initial value
+/// of a word is only known once it is accessed, as it may come from a later `HINT_READ`.
+pub(crate) struct MemoryPrefetchSyscall;
+
+impl Syscall for MemoryPrefetchSyscall {
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        start: u32,
+        end: u32,
+    ) -> Option<u32> {
+        if start > end {
+            return ctx.fail(format!(
+                "memory prefetch range is reversed: {start:#x}..{end:#x}"
+            ));
+        }
+        let num_words = (end - start).div_ceil(4) as usize;
+        ctx.rt
+            .state
+            .memory
+            .reserve(num_words.min(MEMORY_PREFETCH_MAX_WORDS));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::{
+            opts::EmulatorOpts,
+            riscv::{
+                emulator::RiscvEmulator,
+                syscalls::{test_utils::syscall_instructions, SyscallCode},
+            },
+        },
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_memory_prefetch() {
+        const START: u32 = 0x10_0000;
+        const END: u32 = 0x20_0000;
+
+        let mut instructions = syscall_instructions(SyscallCode::MEMORY_PREFETCH, START, END);
+        instructions.push(Instruction::new(Opcode::LW, 12, 0, START + 4, false, true));
+        let mut program = Program::new(instructions, 0, 0);
+        program.memory_image = Arc::new(BTreeMap::from([(START + 4, 0xdead_beef)]));
+
+        let mut emulator =
+            RiscvEmulator::new::<BabyBear>(Arc::new(program), EmulatorOpts::default());
+        emulator.run(None).unwrap();
+
+        // The reservation does not create records or change what the program reads.
+        assert!(emulator.state.memory.capacity() >= ((END - START) / 4) as usize);
+        assert!(!emulator.state.memory.contains_key(&(START + 8)));
+        assert_eq!(emulator.registers()[12], 0xdead_beef);
+    }
+}
@@ -0,0 +1,195 @@
+use super::{Syscall, SyscallCode, SyscallContext};
+use num::{BigUint, One, Zero};
+
+/// The number of words of the operands of `BIGINT_SQRT`.
+pub const BIGINT_SQRT_NUM_WORDS: usize = 8;
+
+/// Computes a square root of `a` modulo the prime `p`, taking `[a, p]` as 16 little endian words
+/// at `arg1` and writing the root to the 8 words at `arg2`. Returns 1 if `a` is a square modulo
+/// `p` and 0 otherwise, in which case zeros are written.
+///
+/// Like the hints, neither the root nor the returned flag is constrained by the proof. The root
+/// is placed as the initial value of fresh memory, as in `HINT_READ`, and the guest is expected
+/// to check `out < p` and `out^2 = a (mod p)` on `UINT256_MUL`, or Euler's criterion when no
+/// root is reported.
+pub(crate) struct BigintSqrtSyscall;
+
+impl Syscall for BigintSqrtSyscall {
+    fn emulate(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        input_ptr: u32,
+        out_ptr: u32,
+    ) -> Option<u32> {
+        if input_ptr % 4 != 0 || out_ptr % 4 != 0 {
+            return ctx.fail("bigint sqrt operands not aligned to 4 bytes");
+        }
+
+        let input = ctx.slice_unsafe(input_ptr, 2 * BIGINT_SQRT_NUM_WORDS);
+        let (a, p) = input.split_at(BIGINT_SQRT_NUM_WORDS);
+        let a = BigUint::from_slice(a);
+        let p = BigUint::from_slice(p);
+        if p.is_zero() {
+            return ctx.fail("bigint sqrt modulus is zero");
+        }
+
+        let root = match sqrt_mod(&a, &p) {
+            Ok(root) => root,
+            Err(reason) => return ctx.fail(reason),
+        };
+        let mut words = root
+            .as_ref()
+            .map(BigUint::to_u32_digits)
+            .unwrap_or_default();
+        words.resize(BIGINT_SQRT_NUM_WORDS, 0);
+        if let Err(reason) = ctx.init_words(out_ptr, words) {
+            return ctx.fail(reason);
+        }
+        Some(root.is_some() as u32)
+    }
+}
+
+/// The number of candidates tried for a quadratic non-residue. Under the generalized Riemann
+/// hypothesis, the least non-residue of a prime `p` is below `2 ln(p)^2`, which is less than this
+/// for the 256-bit operands of `BIGINT_SQRT`.
+const MAX_NON_RESIDUE_CANDIDATES: u32 = 1 << 16;
+
+/// The smaller square root of `a` modulo the prime `p`, or `None` if `a` is not a square.
+///
+/// Primes `p = 3 (mod 4)` take the root as `a^((p + 1) / 4)`, the others go through
+/// Tonelli-Shanks. Primality is not checked. An even `p` other than 2 is an error, and so is a
+/// `p` without a non-residue among the first candidates, so that a composite `p` cannot stall the
+/// search. Otherwise the result for a composite `p` is not meaningful.
+pub fn sqrt_mod(a: &BigUint, p: &BigUint) -> Result<Option<BigUint>, String> {
+    let one = BigUint::one();
+    let a = a % p;
+    if a.is_zero() || p == &BigUint::from(2u32) {
+        return Ok(Some(a));
+    }
+    if !p.bit(0) {
+        return Err(format!("bigint sqrt modulus {p} is even"));
+    }
+
+    let p_minus_one = p - &one;
+    let euler_exp = &p_minus_one >> 1;
+    if a.modpow(&euler_exp, p) != one {
+        return Ok(None);
+    }
+
+    let root = if (p % 4u32) == BigUint::from(3u32) {
+        a.modpow(&((p + &one) >> 2), p)
+    } else {
+        // Write p - 1 = q * 2^s with q odd.
+        let s = p_minus_one.trailing_zeros().unwrap();
+        let q = &p_minus_one >> s;
+
+        // Any quadratic non-residue generates the 2-Sylow subgroup as z^q.
+        let z = (2..MAX_NON_RESIDUE_CANDIDATES + 2)
+            .map(BigUint::from)
+            .take_while(|z| z < p)
+            .find(|z| z.modpow(&euler_exp, p) == p_minus_one)
+            .ok_or_else(|| {
+                format!("no quadratic non-residue found modulo {p}, which is not prime")
+            })?;
+
+        let mut m = s;
+        let mut c = z.modpow(&q, p);
+        let mut t = a.modpow(&q, p);
+        let mut r = a.modpow(&((&q + &one) >> 1), p);
+        while t != one {
+            // The least i with t^(2^i) = 1.
+            let mut i = 0;
+            let mut t_pow = t.clone();
+            while t_pow != one {
+                t_pow = (&t_pow * &t_pow) % p;
+                i += 1;
+                if i >= m {
+                    return Ok(None);
+                }
+            }
+            let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+            m = i;
+            c = (&b * &b) % p;
+            t = (t * &c) % p;
+            r = (r * b) % p;
+        }
+        r
+    };
+
+    if (&root * &root) % p != a {
+        return Ok(None);
+    }
+    let other = p - &root;
+    Ok(Some(root.min(other)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sqrt_mod;
+    use crate::{
+        compiler::riscv::program::Program,
+        emulator::{
+            opts::EmulatorOpts,
+            riscv::{
+                emulator::RiscvEmulator,
+                syscalls::{test_utils::syscall_instructions, SyscallCode},
+            },
+        },
+    };
+    use alloc::sync::Arc;
+    use num::BigUint;
+    use p3_baby_bear::BabyBear;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_sqrt_mod() {
+        // 7 and 11 take the fast path, 13 and 17 go through Tonelli-Shanks.
+        for p in [2u32, 7, 11, 13, 17, 97] {
+            let squares = (0..p).map(|x| x * x % p).collect::<Vec<_>>();
+            for a in 0..p {
+                let root = sqrt_mod(&BigUint::from(a), &BigUint::from(p)).unwrap();
+                match root {
+                    Some(root) => {
+                        let root: u32 = root.try_into().unwrap();
+                        assert_eq!(root * root % p, a);
+                        assert!(root <= p - root);
+                    }
+                    None => assert!(!squares.contains(&a)),
+                }
+            }
+        }
+
+        // Moduli that are not prime end the search with an error. 9 has no non-residue.
+        assert!(sqrt_mod(&BigUint::from(2u32), &BigUint::from(8u32)).is_err());
+        assert!(sqrt_mod(&BigUint::from(1u32), &BigUint::from(9u32)).is_err());
+    }
+
+    #[test]
+    fn test_bigint_sqrt_syscall() {
+        let run = |a: u32| {
+            // The flag is returned in t0, which a halting ecall would overwrite.
+            let instructions = syscall_instructions(SyscallCode::BIGINT_SQRT, 0x1000, 0x2000);
+            let mut program = Program::new(instructions, 0, 0);
+            // a, then the secp256k1 base field prime, which is 3 (mod 4).
+            let mut memory_image = BTreeMap::from([(0x1000, a)]);
+            let p = [
+                0xfffffc2f, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+                0xffffffff,
+            ];
+            for (i, word) in p.into_iter().enumerate() {
+                memory_image.insert(0x1020 + 4 * i as u32, word);
+            }
+            program.memory_image = Arc::new(memory_image);
+
+            let mut emulator =
+                RiscvEmulator::new::<BabyBear>(Arc::new(program), EmulatorOpts::default());
+            emulator.run(None).unwrap();
+            (emulator.registers()[5], emulator.word(0x2000))
+        };
+
+        assert_eq!(run(9), (1, 3));
+        // 3 is not a square modulo the secp256k1 prime.
+        assert_eq!(run(3), (0, 0));
+    }
+}
@@ -23,6 +23,8 @@ pub struct SyscallContext<'a: 'a> {
     pub syscall_lookup_id: u128,
     /// The local memory access events for the syscall.
     pub local_memory_access: HashMap<u32, MemoryLocalEvent>,
+    /// Why the syscall failed, if it did. See [`Self::fail`].
+    pub error: Option<String>,
 }
 
 impl<'a> SyscallContext<'a> {
@@ -38,6 +40,7 @@ impl<'a> SyscallContext<'a> {
             rt: runtime,
             syscall_lookup_id: 0,
             local_memory_access: HashMap::new(),
+            error: None,
         }
     }
 
@@ -150,6 +153,29 @@ impl<'a> SyscallContext<'a> {
         values
     }
 
+    /// Set `words` as the initial values of the memory at `addr`, for syscalls that hand
+    /// unconstrained data to the guest like `HINT_READ`. The words end up in the memory
+    /// initialize events, so they must not have been accessed yet: a word that was only given an
+    /// initial value by an earlier hint is overwritten, but one the program has read or written
+    /// is rejected.
+    pub fn init_words(
+        &mut self,
+        addr: u32,
+        words: impl IntoIterator<Item = u32>,
+    ) -> Result<(), String> {
+        if addr % 4 != 0 {
+            return Err(format!("address {addr:#x} is not aligned to 4 bytes"));
+        }
+        for (i, word) in words.into_iter().enumerate() {
+            let word_addr = addr + 4 * i as u32;
+            if self.rt.state.memory.contains_key(&word_addr) {
+                return Err(format!("address {word_addr:#x} is initialized already"));
+            }
+            self.rt.state.uninitialized_memory.insert(word_addr, word);
+        }
+        Ok(())
+    }
+
     /// Set the next program counter.
     pub fn set_next_pc(&mut self, next_pc: u32) {
         self.next_pc = next_pc;
@@ -159,4 +185,14 @@ impl<'a> SyscallContext<'a> {
     pub fn set_exit_code(&mut self, exit_code: u32) {
         self.exit_code = exit_code;
     }
+
+    /// Fail the syscall, for arguments the guest got wrong. The emulation stops with
+    /// [`EmulationError::SyscallFailed`] instead of panicking the host. The syscall should return
+    /// the result right away, without recording an event.
+    ///
+    /// [`EmulationError::SyscallFailed`]: crate::emulator::riscv::emulator::EmulationError::SyscallFailed
+    pub fn fail(&mut self, reason: impl Into<String>) -> Option<u32> {
+        self.error = Some(reason.into());
+        None
+    }
 }
@@ -0,0 +1,101 @@
+//! Fixtures shared by the syscall and precompile chip tests.
+
+use super::SyscallCode;
+use crate::{
+    compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+    emulator::{
+        opts::EmulatorOpts,
+        riscv::emulator::{EmulationError, RiscvEmulator},
+        stdin::EmulatorStdin,
+    },
+    instances::{
+        chiptype::riscv_chiptype::RiscvChipType,
+        configs::riscv_bb_poseidon2::StarkConfig as RiscvBBSC, machine::riscv::RiscvMachine,
+    },
+    machine::{machine::MachineBehavior, witness::ProvingWitness},
+    primitives::consts::RISCV_NUM_PVS,
+};
+use alloc::sync::Arc;
+use anyhow::Result;
+use p3_baby_bear::BabyBear;
+use std::collections::BTreeMap;
+
+/// The instructions that call `code` with `arg1` in `x10` and `arg2` in `x11`.
+pub(crate) fn syscall_instructions(code: SyscallCode, arg1: u32, arg2: u32) -> Vec<Instruction> {
+    vec![
+        Instruction::new(Opcode::ADD, 5, 0, code as u32, false, true),
+        Instruction::new(Opcode::ADD, 10, 0, arg1, false, true),
+        Instruction::new(Opcode::ADD, 11, 0, arg2, false, true),
+        Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+    ]
+}
+
+/// A program that runs the syscalls `calls` one after the other on `memory_image`, then halts
+/// with exit code 0. It starts at a non-zero pc, which the riscv verifier requires.
+pub(crate) fn syscall_program(
+    calls: &[(SyscallCode, u32, u32)],
+    memory_image: BTreeMap<u32, u32>,
+) -> Arc<Program> {
+    let mut instructions = calls
+        .iter()
+        .flat_map(|&(code, arg1, arg2)| syscall_instructions(code, arg1, arg2))
+        .collect::<Vec<_>>();
+    instructions.extend(syscall_instructions(SyscallCode::HALT, 0, 0));
+
+    let mut program = Program::new(instructions, 0x1000, 0x1000);
+    program.memory_image = Arc::new(memory_image);
+    Arc::new(program)
+}
+
+/// The little-endian words of `bytes`, zero padded, placed from `addr` on.
+pub(crate) fn bytes_to_words(addr: u32, bytes: &[u8]) -> impl Iterator<Item = (u32, u32)> + '_ {
+    bytes.chunks(4).enumerate().map(move |(i, chunk)| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        (addr + 4 * i as u32, u32::from_le_bytes(word))
+    })
+}
+
+/// Emulates `program`, returning the emulator to read the memory it left.
+pub(crate) fn emulate(program: Arc<Program>) -> RiscvEmulator {
+    try_emulate(program).unwrap()
+}
+
+/// Emulates `program`, returning the error a failing syscall stopped it with.
+pub(crate) fn try_emulate(program: Arc<Program>) -> Result<RiscvEmulator, EmulationError> {
+    let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::default());
+    emulator.run(None)?;
+    Ok(emulator)
+}
+
+fn machine_and_witness(
+    program: Arc<Program>,
+) -> (
+    RiscvMachine<RiscvBBSC, RiscvChipType<BabyBear>>,
+    ProvingWitness<RiscvBBSC, RiscvChipType<BabyBear>, Vec<u8>>,
+) {
+    // Register the unreleased precompiles too, which are proven here without recursion.
+    let machine = RiscvMachine::new(RiscvBBSC::new(), RiscvChipType::chips(true), RISCV_NUM_PVS);
+    let (pk, vk) = machine.setup_keys(&program);
+    let witness = ProvingWitness::setup_for_riscv(
+        program,
+        EmulatorStdin::<Program, Vec<u8>>::new_builder().finalize(),
+        EmulatorOpts::default(),
+        pk,
+        vk,
+    );
+    (machine, witness)
+}
+
+/// Checks the constraints and lookups of every chip on the execution of `program`.
+pub(crate) fn debug_program(program: Arc<Program>) -> Result<()> {
+    let (machine, witness) = machine_and_witness(program);
+    machine.debug_with_shape(&witness, None)
+}
+
+/// Proves the execution of `program` and verifies the proof.
+pub(crate) fn prove_program(program: Arc<Program>) -> Result<()> {
+    let (machine, witness) = machine_and_witness(program);
+    let (proof, _) = machine.prove_with_shape_cycles(&witness, None);
+    machine.verify(&proof, witness.vk())
+}
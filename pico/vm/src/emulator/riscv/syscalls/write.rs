@@ -2,6 +2,13 @@ use crate::compiler::riscv::register::Register;
 
 use super::{Syscall, SyscallCode, SyscallContext};
 
+/// The file descriptor for guest log lines. Each write is one line, prefixed with a byte holding
+/// its level, from 1 for error to 5 for trace.
+pub const FD_LOG: u32 = 10;
+
+/// The tracing target of guest log lines, for filtering them apart from host logs.
+pub const GUEST_LOG_TARGET: &str = "pico_guest";
+
 pub(crate) struct WriteSyscall;
 
 impl Syscall for WriteSyscall {
@@ -21,6 +28,9 @@ impl Syscall for WriteSyscall {
     /// If fd = 4:
     /// - Update the input stream.
     ///
+    /// If fd = 10:
+    /// - Emit the guest log line through `tracing` at its level.
+    ///
     /// If the fd matches a hook in the hook registry, invoke the hook.
     ///
     /// Else, log a warning.
@@ -49,6 +59,8 @@ impl Syscall for WriteSyscall {
             rt.state.public_values_stream.extend_from_slice(slice);
         } else if fd == 4 {
             rt.state.input_stream.push(slice.to_vec());
+        } else if fd == FD_LOG {
+            log_guest_line(slice);
         } else if let Some(hook) = rt.hook_map.get(&fd) {
             let result = hook(rt, slice);
             let ptr = rt.state.input_stream_ptr;
@@ -59,3 +71,17 @@ impl Syscall for WriteSyscall {
         None
     }
 }
+
+fn log_guest_line(bytes: &[u8]) {
+    let Some((&level, msg)) = bytes.split_first() else {
+        return;
+    };
+    let msg = String::from_utf8_lossy(msg);
+    match level {
+        1 => tracing::error!(target: GUEST_LOG_TARGET, "{}", msg),
+        2 => tracing::warn!(target: GUEST_LOG_TARGET, "{}", msg),
+        3 => tracing::info!(target: GUEST_LOG_TARGET, "{}", msg),
+        4 => tracing::debug!(target: GUEST_LOG_TARGET, "{}", msg),
+        _ => tracing::trace!(target: GUEST_LOG_TARGET, "{}", msg),
+    }
+}
@@ -39,23 +39,28 @@ use crate::{
     primitives::consts::{DIGEST_SIZE, EXTENSION_DEGREE},
 };
 use alloc::sync::Arc;
+use anyhow::{anyhow, bail, Context, Result};
 use p3_air::Air;
 use p3_commit::TwoAdicMultiplicativeCoset;
 use p3_field::{extension::BinomiallyExtendable, PrimeField32, TwoAdicField};
 use p3_maybe_rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::{array, fmt::Debug};
+use sha2::{Digest, Sha256};
+use std::{array, fmt::Debug, fs, path::Path};
 use tracing::instrument;
 
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct EmulatorStdinBuilder<I> {
     pub buffer: Vec<I>,
+    pub args: Vec<String>,
 }
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct EmulatorStdin<P, I> {
     pub programs: Arc<[P]>,
     pub inputs: Arc<[I]>,
+    /// The arguments returned by `pico_sdk::io::args`, only used by the riscv emulator.
+    pub args: Arc<[String]>,
     pub flag_empty: bool,
     pub pointer: usize,
 }
@@ -69,6 +74,7 @@ where
         Self {
             programs: self.programs.clone(),
             inputs: self.inputs.clone(),
+            args: self.args.clone(),
             flag_empty: self.flag_empty,
             pointer: self.pointer,
         }
@@ -112,6 +118,7 @@ impl<P, I> EmulatorStdin<P, I> {
         Self {
             programs: Arc::new([]),
             inputs: Arc::from(buf),
+            args: Arc::new([]),
             flag_empty: false,
             pointer: 0,
         }
@@ -126,21 +133,144 @@ impl EmulatorStdinBuilder<Vec<u8>> {
         self.buffer.push(tmp);
     }
 
+    /// Write an optional value as a single input, to be read in the guest with
+    /// `pico_sdk::io::read_option`. The input is a discriminant byte, 0 for `None` and 1 for
+    /// `Some`, followed by the serialized value if there is one.
+    pub fn write_option<T: Serialize>(&mut self, data: &Option<T>) {
+        let mut tmp = vec![data.is_some() as u8];
+        if let Some(data) = data {
+            bincode::serialize_into(&mut tmp, data).expect("serialization failed");
+        }
+        self.buffer.push(tmp);
+    }
+
     /// Write a slice of bytes to the buffer.
     pub fn write_slice(&mut self, slice: &[u8]) {
         self.buffer.push(slice.to_vec());
     }
 
+    /// Write a slice of plain-old-data values, to be read in the guest with
+    /// `pico_sdk::io::read_slice`.
+    pub fn write_pod_slice<T: bytemuck::Pod>(&mut self, slice: &[T]) {
+        self.write_slice(bytemuck::cast_slice(slice));
+    }
+
+    /// Selects which function of a guest built with `entrypoint!(dispatch: ...)` to run. The
+    /// selector is the first input the guest reads, so it must be written before anything else.
+    pub fn select_function(&mut self, fn_name: &str) {
+        assert!(
+            self.buffer.is_empty(),
+            "the function selector must be the first input"
+        );
+        self.write_slice(fn_name.as_bytes());
+    }
+
+    /// Sets the arguments the guest reads with `pico_sdk::io::args`, in order. They are kept apart
+    /// from the inputs, so they can be set at any point.
+    pub fn set_args(&mut self, args: &[&str]) {
+        self.args = args.iter().map(|arg| arg.to_string()).collect();
+    }
+
+    /// The digest `pico_sdk::io::input_digest` returns once the guest has read every input
+    /// written so far, in order: the SHA-256 of the inputs, each prefixed with its length as a
+    /// little-endian `u32`.
+    ///
+    /// A program that commits the digest with `pico_sdk::io::commit_input_digest` is bound to
+    /// these inputs: the committed bytes are the digest itself.
+    pub fn input_digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        for input in &self.buffer {
+            hasher.update((input.len() as u32).to_le_bytes());
+            hasher.update(input);
+        }
+        hasher.finalize().into()
+    }
+
     pub fn finalize<P>(self) -> EmulatorStdin<P, Vec<u8>> {
         EmulatorStdin {
             programs: Arc::new([]),
             inputs: self.buffer.into(),
+            args: self.args.into(),
             flag_empty: false,
             pointer: 0,
         }
     }
 }
 
+/// Magic bytes at the start of a serialized riscv stdin.
+const STDIN_MAGIC: [u8; 4] = *b"PSTD";
+
+/// Version of the riscv stdin serialization, bumped whenever [`StdinV1`] changes.
+const STDIN_SERIALIZATION_VERSION: u32 = 1;
+
+/// The serialized form of a riscv stdin, version 1.
+#[derive(Serialize, Deserialize)]
+struct StdinV1 {
+    inputs: Vec<Vec<u8>>,
+    args: Vec<String>,
+}
+
+// for saving and replaying riscv machine stdin
+impl<P> EmulatorStdin<P, Vec<u8>> {
+    /// Serializes the inputs and arguments: the magic `PSTD`, the format version as a
+    /// little-endian `u32`, then the bincode encoding of the inputs and arguments. Programs and
+    /// the read position are not part of it.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let stdin = StdinV1 {
+            inputs: self.inputs.to_vec(),
+            args: self.args.to_vec(),
+        };
+
+        let mut bytes = STDIN_MAGIC.to_vec();
+        bytes.extend_from_slice(&STDIN_SERIALIZATION_VERSION.to_le_bytes());
+        bincode::serialize_into(&mut bytes, &stdin).expect("stdin serialization failed");
+        bytes
+    }
+
+    /// Deserializes a stdin serialized with [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (magic, rest) = bytes
+            .split_at_checked(STDIN_MAGIC.len())
+            .ok_or_else(|| anyhow!("stdin bytes too short"))?;
+        if magic != STDIN_MAGIC {
+            bail!("not a serialized stdin");
+        }
+        let (version, payload) = rest
+            .split_at_checked(4)
+            .ok_or_else(|| anyhow!("stdin bytes too short"))?;
+        let version = u32::from_le_bytes(version.try_into().unwrap());
+        if version != STDIN_SERIALIZATION_VERSION {
+            bail!(
+                "unsupported stdin version {}, expected {}",
+                version,
+                STDIN_SERIALIZATION_VERSION
+            );
+        }
+
+        let stdin: StdinV1 = bincode::deserialize(payload)?;
+        Ok(Self {
+            programs: Arc::new([]),
+            inputs: stdin.inputs.into(),
+            args: stdin.args.into(),
+            flag_empty: false,
+            pointer: 0,
+        })
+    }
+
+    /// Saves the stdin to `path`, so a run can be reproduced with [`Self::replay_from_file`].
+    pub fn save_to_file(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_bytes())
+            .with_context(|| format!("failed to write stdin to {}", path.display()))
+    }
+
+    /// Loads a stdin saved with [`Self::save_to_file`].
+    pub fn replay_from_file(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path)
+            .with_context(|| format!("failed to read stdin from {}", path.display()))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
 // for convert stdin, converting riscv proofs to recursion proofs
 impl<SC> EmulatorStdin<RecursionProgram<Val<SC>>, ConvertStdin<SC, RiscvChipType<Val<SC>>>>
 where
@@ -229,6 +359,7 @@ where
         Self {
             programs: programs.into(),
             inputs: inputs.into(),
+            args: Arc::new([]),
             flag_empty,
             pointer: 0,
         }
@@ -337,6 +468,7 @@ where
             Self {
                 programs: programs.into(),
                 inputs: inputs.into(),
+                args: Arc::new([]),
                 flag_empty,
                 pointer: 0,
             },
@@ -345,3 +477,62 @@ where
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EmulatorStdin;
+    use crate::compiler::riscv::program::Program;
+
+    #[test]
+    fn test_stdin_bytes_roundtrip() {
+        let mut builder = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        builder.write(&42u32);
+        builder.write_slice(b"pico");
+        builder.set_args(&["--n", "10"]);
+        let stdin = builder.finalize::<Program>();
+
+        let bytes = stdin.to_bytes();
+        let restored = EmulatorStdin::<Program, Vec<u8>>::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.inputs, stdin.inputs);
+        assert_eq!(restored.args, stdin.args);
+        assert_eq!(restored.to_bytes(), bytes);
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] += 1;
+        assert!(EmulatorStdin::<Program, Vec<u8>>::from_bytes(&wrong_version).is_err());
+        assert!(EmulatorStdin::<Program, Vec<u8>>::from_bytes(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_select_function() {
+        let mut builder = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        builder.select_function("prove_add");
+        builder.write(&7u32);
+        let stdin = builder.finalize::<Program>();
+        assert_eq!(stdin.inputs[0], b"prove_add");
+        assert_eq!(stdin.inputs.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the function selector must be the first input")]
+    fn test_select_function_after_input() {
+        let mut builder = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        builder.write(&7u32);
+        builder.select_function("prove_add");
+    }
+
+    #[test]
+    fn test_input_digest_separates_inputs() {
+        let digest = |inputs: &[&[u8]]| {
+            let mut builder = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+            for input in inputs {
+                builder.write_slice(input);
+            }
+            builder.input_digest()
+        };
+
+        assert_ne!(digest(&[b"ab", b"c"]), digest(&[b"a", b"bc"]));
+        assert_ne!(digest(&[b"abc"]), digest(&[b"abc", b""]));
+        assert_eq!(digest(&[b"ab", b"c"]), digest(&[b"ab", b"c"]));
+    }
+}
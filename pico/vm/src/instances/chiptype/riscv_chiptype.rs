@@ -1,4 +1,6 @@
-use crate::chips::precompiles::poseidon2::FieldSpecificPrecompilePoseidon2Chip;
+use crate::chips::precompiles::poseidon2::{
+    FieldSpecificPrecompilePoseidon2Chip, FieldSpecificPrecompilePoseidon2CompressChip,
+};
 use hashbrown::HashSet;
 use p3_air::{Air, BaseAir};
 use p3_field::PrimeField32;
@@ -38,10 +40,24 @@ use crate::{
             field::secp256k1::Secp256k1BaseField,
         },
         precompiles::{
+            aes::{
+                aes256::Aes256EncryptBlockChip, key_schedule::AesKeyScheduleChip,
+                sbox::AesSboxChip, Aes128EncryptBlockChip,
+            },
+            base64::Base64DecodeChip,
+            crc32::{table::Crc32TableChip, Crc32Chip},
+            ct_eq::ConstantTimeEqChip,
             edwards::{EdAddAssignChip, EdDecompressChip},
-            fptower::{fp::FpOpChip, fp2_addsub::Fp2AddSubChip, fp2_mul::Fp2MulChip},
-            keccak256::KeccakPermuteChip,
-            sha256::{compress::ShaCompressChip, extend::ShaExtendChip},
+            fptower::{
+                fp::FpOpChip, fp2_addsub::Fp2AddSubChip, fp2_mul::Fp2MulChip,
+                fp_batch_inv::FpBatchInvChip, fp_inv::FpInvChip,
+            },
+            keccak256::{absorb::KeccakAbsorbChip, squeeze::KeccakSqueezeChip, KeccakPermuteChip},
+            poly_eval::PolyEvalChip,
+            range_check::{RangeCheckBatchChip, RANGE_CHECK_BATCH_VALUES_PER_ROW},
+            rlp::RlpDecodeItemChip,
+            sha256::{compress::ShaCompressChip, digest::Sha256DigestChip, extend::ShaExtendChip},
+            sort::SortU32Chip,
             uint256::Uint256MulChip,
             weierstrass::{
                 weierstrass_add::WeierstrassAddAssignChip,
@@ -52,7 +68,15 @@ use crate::{
     },
     compiler::riscv::program::Program,
     define_chip_type,
-    emulator::riscv::{record::EmulationRecord, syscalls::precompiles::PrecompileLocalMemory},
+    emulator::riscv::{
+        record::EmulationRecord,
+        syscalls::{
+            precompiles::{
+                sha256::digest::sha256_num_blocks, PrecompileEvent, PrecompileLocalMemory,
+            },
+            SyscallCode,
+        },
+    },
     instances::compiler::shapes::riscv_shape::{
         precompile_rows_per_event, precompile_syscall_code,
     },
@@ -69,12 +93,18 @@ use crate::{
 };
 
 type FpOpBn254<F> = FpOpChip<F, Bn254BaseField>;
+type FpInvBn254<F> = FpInvChip<F, Bn254BaseField>;
+type FpBatchInvBn254<F> = FpBatchInvChip<F, Bn254BaseField>;
 type Fp2AddSubBn254<F> = Fp2AddSubChip<F, Bn254BaseField>;
 type Fp2MulBn254<F> = Fp2MulChip<F, Bn254BaseField>;
 type FpOpBls381<F> = FpOpChip<F, Bls381BaseField>;
+type FpInvBls381<F> = FpInvChip<F, Bls381BaseField>;
+type FpBatchInvBls381<F> = FpBatchInvChip<F, Bls381BaseField>;
 type Fp2AddSubBls381<F> = Fp2AddSubChip<F, Bls381BaseField>;
 type Fp2MulBls381<F> = Fp2MulChip<F, Bls381BaseField>;
 type FpOpSecp256k1<F> = FpOpChip<F, Secp256k1BaseField>;
+type FpInvSecp256k1<F> = FpInvChip<F, Secp256k1BaseField>;
+type FpBatchInvSecp256k1<F> = FpBatchInvChip<F, Secp256k1BaseField>;
 
 type WsBn254Add<F> = WeierstrassAddAssignChip<F, Bn254>;
 type WsBls381Add<F> = WeierstrassAddAssignChip<F, Bls12381>;
@@ -114,15 +144,39 @@ define_chip_type!(
         (AddSub, AddSubChip),
         (Bitwise, BitwiseChip),
         (KeecakP, KeccakPermuteChip),
+        (KeccakAbsorb, KeccakAbsorbChip),
+        (KeccakSqueeze, KeccakSqueezeChip),
         (FpBn254, FpOpBn254),
+        (FpInvBn254, FpInvBn254),
+        (FpBatchInvBn254, FpBatchInvBn254),
         (Fp2AddSubBn254, Fp2AddSubBn254),
         (Fp2MulBn254, Fp2MulBn254),
         (FpBls381, FpOpBls381),
+        (FpInvBls381, FpInvBls381),
+        (FpBatchInvBls381, FpBatchInvBls381),
         (Fp2AddSubBls381, Fp2AddSubBls381),
         (Fp2MulBls381, Fp2MulBls381),
         (FpSecp256k1, FpOpSecp256k1),
+        (FpInvSecp256k1, FpInvSecp256k1),
+        (FpBatchInvSecp256k1, FpBatchInvSecp256k1),
         (U256Mul, Uint256MulChip),
         (Poseidon2P, FieldSpecificPrecompilePoseidon2Chip),
+        (Poseidon2C, FieldSpecificPrecompilePoseidon2CompressChip),
+        (PolyEval, PolyEvalChip),
+        (ConstantTimeEq, ConstantTimeEqChip),
+        (Sha256Digest, Sha256DigestChip),
+        (MultiHash, Sha256DigestChip),
+        (SortU32, SortU32Chip),
+        (Aes128EncryptBlock, Aes128EncryptBlockChip),
+        (AesKeySchedule, AesKeyScheduleChip),
+        (Aes256EncryptBlock, Aes256EncryptBlockChip),
+        (AesSbox, AesSboxChip),
+        (RlpDecodeItem, RlpDecodeItemChip),
+        (Base64Decode, Base64DecodeChip),
+        (RangeCheckBatch, RangeCheckBatchChip),
+        (FieldSort, SortU32Chip),
+        (Crc32, Crc32Chip),
+        (Crc32Table, Crc32TableChip),
         (SyscallRiscv, SyscallChip),
         (SyscallPrecompile, SyscallChip),
         (Global, GlobalChip),
@@ -132,8 +186,19 @@ define_chip_type!(
 );
 
 impl<F: PrimeField32 + FieldSpecificPoseidon2Config> RiscvChipType<F> {
+    /// The chips of the RISC-V machine.
+    ///
+    /// The precompiles added since the recursion vk maps were last built have no shapes in them,
+    /// so vk-verified recursion would reject any program using them. Their chips are only
+    /// registered with the `unreleased-precompiles` feature until the maps are regenerated;
+    /// without it, shape padding fails on their records with `PrecompileNotIncluded`.
     pub fn all_chips() -> Vec<MetaChip<F, Self>> {
-        [
+        Self::chips(cfg!(feature = "unreleased-precompiles"))
+    }
+
+    /// The chips of the RISC-V machine, with the unreleased precompiles or without.
+    pub(crate) fn chips(unreleased_precompiles: bool) -> Vec<MetaChip<F, Self>> {
+        let mut chips = vec![
             Self::Program(Default::default()),
             Self::Cpu(Default::default()),
             Self::ShaCompress(Default::default()),
@@ -171,14 +236,43 @@ impl<F: PrimeField32 + FieldSpecificPoseidon2Config> RiscvChipType<F> {
             Self::FpSecp256k1(Default::default()),
             Self::U256Mul(Default::default()),
             Self::Poseidon2P(Default::default()),
+        ];
+        if unreleased_precompiles {
+            chips.extend([
+                Self::KeccakAbsorb(Default::default()),
+                Self::KeccakSqueeze(Default::default()),
+                Self::FpInvBn254(Default::default()),
+                Self::FpBatchInvBn254(Default::default()),
+                Self::FpInvBls381(Default::default()),
+                Self::FpBatchInvBls381(Default::default()),
+                Self::FpInvSecp256k1(Default::default()),
+                Self::FpBatchInvSecp256k1(Default::default()),
+                Self::Poseidon2C(Default::default()),
+                Self::PolyEval(Default::default()),
+                Self::ConstantTimeEq(Default::default()),
+                Self::Sha256Digest(Default::default()),
+                Self::MultiHash(Sha256DigestChip::multi_hash()),
+                Self::SortU32(Default::default()),
+                Self::Aes128EncryptBlock(Default::default()),
+                Self::AesKeySchedule(Default::default()),
+                Self::Aes256EncryptBlock(Default::default()),
+                Self::AesSbox(Default::default()),
+                Self::RlpDecodeItem(Default::default()),
+                Self::Base64Decode(Default::default()),
+                Self::RangeCheckBatch(Default::default()),
+                Self::FieldSort(SortU32Chip::field_sort()),
+                Self::Crc32(Default::default()),
+                Self::Crc32Table(Default::default()),
+            ]);
+        }
+        chips.extend([
             Self::SyscallRiscv(SyscallChip::riscv()),
             Self::SyscallPrecompile(SyscallChip::precompile()),
             Self::Global(Default::default()),
             Self::Byte(Default::default()),
             Self::Poseidon2(Default::default()),
-        ]
-        .map(MetaChip::new)
-        .into()
+        ]);
+        chips.into_iter().map(MetaChip::new).collect()
     }
 
     /// Get the heights of the preprocessed chips for a given program.
@@ -295,8 +389,71 @@ impl<F: PrimeField32 + FieldSpecificPoseidon2Config> RiscvChipType<F> {
             .get_events(precompile_syscall_code(chip_name))
             .filter(|events| !events.is_empty())
             .map(|events| {
+                // Variable-length precompiles take one row per word or element of input.
+                let num_rows = match precompile_syscall_code(chip_name) {
+                    SyscallCode::POLY_EVAL
+                    | SyscallCode::CT_EQ
+                    | SyscallCode::SORT_U32
+                    | SyscallCode::FIELD_SORT
+                    | SyscallCode::BN254_FP_BATCH_INV
+                    | SyscallCode::BLS12381_FP_BATCH_INV
+                    | SyscallCode::SECP256K1_FP_BATCH_INV => events
+                        .iter()
+                        .map(|(_, event)| match event {
+                            PrecompileEvent::PolyEval(event) => event.coeffs.len(),
+                            PrecompileEvent::ConstantTimeEq(event) => event.a.len(),
+                            PrecompileEvent::SortU32(event) | PrecompileEvent::FieldSort(event) => {
+                                event.output.len()
+                            }
+                            PrecompileEvent::Bn254FpBatchInv(event)
+                            | PrecompileEvent::Bls12381FpBatchInv(event)
+                            | PrecompileEvent::Secp256k1FpBatchInv(event) => event.len as usize,
+                            _ => unreachable!(),
+                        })
+                        .sum(),
+                    // Sixteen values per row.
+                    SyscallCode::RANGE_CHECK_BATCH => events
+                        .iter()
+                        .map(|(_, event)| match event {
+                            PrecompileEvent::RangeCheckBatch(event) => event
+                                .values
+                                .len()
+                                .div_ceil(RANGE_CHECK_BATCH_VALUES_PER_ROW),
+                            _ => unreachable!(),
+                        })
+                        .sum(),
+                    // One data word per row.
+                    SyscallCode::CRC32 => events
+                        .iter()
+                        .map(|(_, event)| match event {
+                            PrecompileEvent::Crc32(event) => event.data.len(),
+                            _ => unreachable!(),
+                        })
+                        .sum(),
+                    // One 64-row block per padded message block.
+                    SyscallCode::SHA256 => events
+                        .iter()
+                        .map(|(_, event)| match event {
+                            PrecompileEvent::Sha256Digest(event) => {
+                                precompile_rows_per_event(chip_name) * sha256_num_blocks(event.len)
+                            }
+                            _ => unreachable!(),
+                        })
+                        .sum(),
+                    SyscallCode::MULTI_HASH => events
+                        .iter()
+                        .flat_map(|(_, event)| match event {
+                            PrecompileEvent::MultiHash(event) => event.digests.iter(),
+                            _ => unreachable!(),
+                        })
+                        .map(|digest| {
+                            precompile_rows_per_event(chip_name) * sha256_num_blocks(digest.len)
+                        })
+                        .sum(),
+                    _ => events.len() * precompile_rows_per_event(chip_name),
+                };
                 (
-                    events.len() * precompile_rows_per_event(chip_name),
+                    num_rows,
                     events.get_local_mem_events().into_iter().count(),
                     record.global_lookup_events.len(),
                 )
@@ -348,6 +505,9 @@ impl<F: PrimeField32 + FieldSpecificPoseidon2Config> RiscvChipType<F> {
         }
 
         excluded_chip_names.insert(Self::SyscallPrecompile(SyscallChip::precompile()).name());
+        // Remove the lookup tables of precompiles, which come with the precompiles using them.
+        excluded_chip_names.insert(Self::AesSbox(AesSboxChip::default()).name());
+        excluded_chip_names.insert(Self::Crc32Table(Crc32TableChip::default()).name());
         // Remove the preprocessed chips.
         excluded_chip_names.insert(Self::Program(ProgramChip::default()).name());
         excluded_chip_names.insert(Self::Byte(ByteChip::default()).name());
@@ -0,0 +1,118 @@
+use super::witness::GnarkWitness;
+use crate::{
+    compiler::recursion::{constraints::Constraint, ir::Witness},
+    configs::config::FieldGenericConfig,
+    instances::compiler::onchain_circuit::utils::build_gnark_config,
+};
+use anyhow::Result;
+use num_bigint::BigUint;
+use num_traits::Num;
+use std::path::Path;
+
+/// An embed proof lowered to the inputs of the gnark Groth16 prover. The Groth16 proof itself
+/// is produced by the gnark prover from these inputs.
+///
+/// `constraints` is the verifier circuit in the json layout read by the gnark backend. In
+/// `witness`, every `vars`, `felts` and `exts` entry is the canonical value of the field
+/// element as an unsigned decimal string; an ext is its base coefficients from lowest degree
+/// up. The public inputs of the gnark circuit are `vkey_hash` followed by
+/// `committed_values_digest`, both Bn254Fr elements in the same decimal form.
+#[derive(Debug, Clone)]
+pub struct GnarkInputs<EmbedFC> {
+    pub constraints: Vec<Constraint>,
+    pub witness: GnarkWitness<EmbedFC>,
+}
+
+impl<EmbedFC: FieldGenericConfig> GnarkInputs<EmbedFC> {
+    pub fn new(constraints: Vec<Constraint>, witness: Witness<EmbedFC>) -> Self {
+        Self {
+            constraints,
+            witness: GnarkWitness::new(witness),
+        }
+    }
+
+    /// The public inputs `[vkey_hash, committed_values_digest]`, each encoded as a 32-byte
+    /// big-endian integer. This is the `uint256` layout expected by gnark's Solidity verifiers.
+    pub fn public_inputs(&self) -> [[u8; 32]; 2] {
+        [
+            decimal_to_be_bytes(&self.witness.vkey_hash),
+            decimal_to_be_bytes(&self.witness.committed_values_digest),
+        ]
+    }
+
+    /// Write `constraints.json` and `groth16_witness.json` into `build_dir`.
+    pub fn save(&self, build_dir: &Path) -> Result<()> {
+        build_gnark_config(&self.constraints, &self.witness, build_dir)
+    }
+}
+
+fn decimal_to_be_bytes(value: &str) -> [u8; 32] {
+    let bytes = BigUint::from_str_radix(value, 10)
+        .expect("witness value is not a decimal integer")
+        .to_bytes_be();
+    assert!(bytes.len() <= 32, "witness value exceeds 32 bytes");
+
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(&bytes);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        compiler::recursion::constraints::opcodes::ConstraintOpcode,
+        configs::field_config::BabyBearBn254,
+    };
+    use p3_baby_bear::BabyBear;
+    use p3_bn254_fr::Bn254Fr;
+    use p3_field::{extension::BinomialExtensionField, FieldAlgebra, FieldExtensionAlgebra};
+    use std::fs::{self, File};
+
+    #[test]
+    fn test_save_round_trip() {
+        let witness = Witness::<BabyBearBn254> {
+            vars: vec![Bn254Fr::from_canonical_u32(7)],
+            felts: vec![BabyBear::from_canonical_u32(11)],
+            exts: vec![BinomialExtensionField::from_base_slice(
+                &[1, 2, 3, 4].map(BabyBear::from_canonical_u32),
+            )],
+            vkey_hash: Bn254Fr::from_canonical_u32(0x1234),
+            committed_values_digest: Bn254Fr::from_canonical_u32(5),
+        };
+        let constraint = Constraint {
+            opcode: ConstraintOpcode::ImmV,
+            args: vec![vec!["backend0".to_string()], vec!["7".to_string()]],
+        };
+        let inputs = GnarkInputs::new(vec![constraint], witness);
+        assert_eq!(inputs.witness.vars, ["7", "999"]);
+        assert_eq!(inputs.witness.exts[0], ["1", "2", "3", "4"]);
+
+        let dir = std::env::temp_dir().join("pico-test-gnark-inputs");
+        fs::create_dir_all(&dir).unwrap();
+        inputs.save(&dir).unwrap();
+        let constraints: Vec<Constraint> =
+            serde_json::from_reader(File::open(dir.join("constraints.json")).unwrap()).unwrap();
+        let witness: GnarkWitness<BabyBearBn254> =
+            serde_json::from_reader(File::open(dir.join("groth16_witness.json")).unwrap()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&constraints).unwrap(),
+            serde_json::to_value(&inputs.constraints).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(&witness).unwrap(),
+            serde_json::to_value(&inputs.witness).unwrap()
+        );
+
+        let loaded = GnarkInputs {
+            constraints,
+            witness,
+        };
+        let [vkey_hash, committed_values_digest] = loaded.public_inputs();
+        assert_eq!(vkey_hash[..30], [0; 30]);
+        assert_eq!(vkey_hash[30..], [0x12, 0x34]);
+        assert_eq!(committed_values_digest[31], 5);
+    }
+}
@@ -1,2 +1,3 @@
 pub mod builder;
+pub mod inputs;
 pub mod witness;
@@ -1,5 +1,5 @@
 use crate::{
-    compiler::recursion::{constraints::Constraint, ir::Witness},
+    compiler::recursion::constraints::Constraint,
     configs::config::{FieldGenericConfig, StarkGenericConfig},
     machine::proof::MetaProof,
 };
@@ -11,7 +11,7 @@ use serde_json::json;
 use std::{
     fs::{self, File},
     io::{BufReader, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use super::gnark::witness::GnarkWitness;
@@ -53,45 +53,27 @@ pub fn save_embed_proof_data<SC: StarkGenericConfig, Bn254SC: StarkGenericConfig
     Ok(())
 }
 
-#[allow(unused)]
+/// Write the gnark verifier circuit and its witness into `build_dir` as `constraints.json` and
+/// `groth16_witness.json`, the files read by the gnark prover.
 pub fn build_gnark_config<EmbedFC: FieldGenericConfig>(
-    constraints: Vec<Constraint>,
-    witness: Witness<EmbedFC>,
-    build_dir: PathBuf,
-) {
-    let serialized = serde_json::to_string(&constraints).unwrap();
-
-    // Write constraints.
+    constraints: &[Constraint],
+    witness: &GnarkWitness<EmbedFC>,
+    build_dir: &Path,
+) -> Result<()> {
     let constraints_path = build_dir.join(CONSTRAINTS_JSON_FILE);
-    let mut file = File::create(constraints_path).unwrap();
-    file.write_all(serialized.as_bytes()).unwrap();
+    let mut file = File::create(&constraints_path)
+        .with_context(|| format!("Failed to create file: {:?}", constraints_path))?;
+    serde_json::to_writer(&mut file, constraints)
+        .context("Failed to serialize constraints to JSON")?;
+    file.flush()?;
 
-    // Write witness.
     let witness_path = build_dir.join(GROTH16_JSON_FILE);
-    let gnark_witness = GnarkWitness::new(witness);
-    let mut file = File::create(witness_path).unwrap();
-    let serialized = serde_json::to_string(&gnark_witness).unwrap();
-    file.write_all(serialized.as_bytes()).unwrap();
-}
-
-#[allow(unused)]
-pub fn build_gnark_config_with_str<EmbedFC: FieldGenericConfig>(
-    constraints: Vec<Constraint>,
-    witness: Witness<EmbedFC>,
-    build_dir: PathBuf,
-) -> String {
-    let serialized = serde_json::to_string(&constraints).unwrap();
+    let mut file = File::create(&witness_path)
+        .with_context(|| format!("Failed to create file: {:?}", witness_path))?;
+    serde_json::to_writer(&mut file, witness).context("Failed to serialize witness to JSON")?;
+    file.flush()?;
 
-    // Write constraints.
-    let constraints_path = build_dir.join(CONSTRAINTS_JSON_FILE);
-    let mut file = File::create(constraints_path).unwrap();
-    file.write_all(serialized.as_bytes()).unwrap();
-
-    // Write witness.
-    let witness_path = build_dir.join(GROTH16_JSON_FILE);
-    let gnark_witness = GnarkWitness::new(witness);
-    let mut file = File::create(witness_path).unwrap();
-    serde_json::to_string(&gnark_witness).unwrap()
+    Ok(())
 }
 
 pub fn generate_contract_inputs<EmbedFC: FieldGenericConfig>(
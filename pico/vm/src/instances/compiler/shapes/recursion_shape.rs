@@ -12,7 +12,7 @@ use crate::{
     compiler::recursion::program::RecursionProgram,
     instances::{chiptype::recursion_chiptype::RecursionChipType, compiler::shapes::ProofShape},
     machine::{chip::ChipBehavior, field::FieldSpecificPoseidon2Config},
-    primitives::consts::EXTENSION_DEGREE,
+    primitives::consts::{COMBINE_SIZE, EXTENSION_DEGREE},
 };
 use hashbrown::HashMap;
 use itertools::Itertools;
@@ -66,9 +66,48 @@ impl From<Vec<ProofShape>> for RecursionShape {
 
 pub struct RecursionShapeConfig<F, A> {
     allowed_shapes: Vec<HashMap<String, usize>>,
+    combine_arity: usize,
     _marker: PhantomData<(F, A)>,
 }
 
+impl<F, A> RecursionShapeConfig<F, A> {
+    /// A config padding recursion programs to `allowed_shapes`, whose combine shapes fit the
+    /// combine programs of `combine_arity` proofs.
+    pub fn new(allowed_shapes: Vec<HashMap<String, usize>>, combine_arity: usize) -> Self {
+        Self {
+            allowed_shapes,
+            combine_arity,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The number of proofs a combine program padded to these shapes verifies. The vk map holds
+    /// the combine programs built from `get_all_shape_combinations(self.combine_arity())`.
+    pub fn combine_arity(&self) -> usize {
+        self.combine_arity
+    }
+}
+
+/// Check that combine programs of `arity` proofs can be proven: any arity from 2 works without vk
+/// verification, while with it the programs must pad to the combine shapes of `shape_config`.
+pub(crate) fn check_combine_arity<F, A>(
+    arity: usize,
+    vk_verification: bool,
+    shape_config: Option<&RecursionShapeConfig<F, A>>,
+) {
+    assert!(arity >= 2, "combine arity must be at least 2, got {arity}");
+    if vk_verification {
+        let supported = shape_config
+            .expect("shape_config in combine should not be None when VK_VERIFICATION enabled")
+            .combine_arity();
+        assert_eq!(
+            arity, supported,
+            "combine arity {arity} is not supported by the recursion shapes, which combine \
+             {supported} proofs"
+        );
+    }
+}
+
 impl<F: PrimeField32 + BinomiallyExtendable<EXTENSION_DEGREE> + FieldSpecificPoseidon2Config>
     Default for RecursionShapeConfig<F, RecursionChipType<F>>
 where
@@ -197,10 +236,8 @@ where
         ]
         .map(HashMap::from)
         .to_vec();
-        Self {
-            allowed_shapes,
-            _marker: PhantomData,
-        }
+        // The combine shapes above fit the combine programs of two proofs.
+        Self::new(allowed_shapes, COMBINE_SIZE)
     }
 }
 
@@ -289,3 +326,40 @@ impl<F: PrimeField32 + BinomiallyExtendable<EXTENSION_DEGREE> + FieldSpecificPos
 pub struct RecursionPadShape {
     pub(crate) inner: HashMap<String, usize>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{check_combine_arity, RecursionShapeConfig};
+    use crate::{
+        instances::chiptype::recursion_chiptype::RecursionChipType,
+        primitives::consts::COMBINE_SIZE,
+    };
+    use p3_baby_bear::BabyBear;
+
+    type ShapeConfig = RecursionShapeConfig<BabyBear, RecursionChipType<BabyBear>>;
+
+    #[test]
+    fn test_combine_arity() {
+        let default = ShapeConfig::default();
+        assert_eq!(default.combine_arity(), COMBINE_SIZE);
+        let custom = ShapeConfig::new(default.allowed_shapes.clone(), 4);
+        assert_eq!(custom.combine_arity(), 4);
+
+        check_combine_arity(COMBINE_SIZE, true, Some(&default));
+        check_combine_arity(4, true, Some(&custom));
+        // Without vk verification the programs need not pad to the shapes.
+        check_combine_arity::<BabyBear, RecursionChipType<BabyBear>>(5, false, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not supported by the recursion shapes")]
+    fn test_combine_arity_unsupported() {
+        check_combine_arity(COMBINE_SIZE + 1, true, Some(&ShapeConfig::default()));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2")]
+    fn test_combine_arity_too_small() {
+        check_combine_arity::<BabyBear, RecursionChipType<BabyBear>>(1, false, None);
+    }
+}
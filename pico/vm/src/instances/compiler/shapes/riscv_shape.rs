@@ -197,6 +197,12 @@ pub(crate) fn precompile_rows_per_event(chip_name: &str) -> usize {
         "ShaCompress" => 80,
         "ShaExtend" => 48,
         "KeccakPermute" => 24,
+        "KeccakAbsorb" => 24,
+        "Sha256Digest" => 64,
+        "MultiHash" => 64,
+        "Aes128EncryptBlock" => 10,
+        "AesKeySchedule" => 60,
+        "Aes256EncryptBlock" => 14,
         _ => 1,
     }
 }
@@ -208,11 +214,15 @@ pub(crate) fn precompile_syscall_code(chip_name: &str) -> SyscallCode {
         "Bn254AddAssign" => SyscallCode::BN254_ADD,
         "Bn254DoubleAssign" => SyscallCode::BN254_DOUBLE,
         "Bn254FpOp" => SyscallCode::BN254_FP_ADD,
+        "Bn254FpInv" => SyscallCode::BN254_FP_INV,
+        "Bn254FpBatchInv" => SyscallCode::BN254_FP_BATCH_INV,
         "Bn254Fp2AddSub" => SyscallCode::BN254_FP2_ADD,
         "Bn254Fp2Mul" => SyscallCode::BN254_FP2_MUL,
         "EdAddAssign" => SyscallCode::ED_ADD,
         "EdDecompress" => SyscallCode::ED_DECOMPRESS,
         "KeccakPermute" => SyscallCode::KECCAK_PERMUTE,
+        "KeccakAbsorb" => SyscallCode::KECCAK_ABSORB,
+        "KeccakSqueeze" => SyscallCode::KECCAK_SQUEEZE,
         "Secp256k1AddAssign" => SyscallCode::SECP256K1_ADD,
         "Secp256k1DoubleAssign" => SyscallCode::SECP256K1_DOUBLE,
         "ShaCompress" => SyscallCode::SHA_COMPRESS,
@@ -222,16 +232,47 @@ pub(crate) fn precompile_syscall_code(chip_name: &str) -> SyscallCode {
         "Secp256k1Decompress" => SyscallCode::SECP256K1_DECOMPRESS,
         "Bls12381DoubleAssign" => SyscallCode::BLS12381_DOUBLE,
         "Bls381FpOp" => SyscallCode::BLS12381_FP_ADD,
+        "Bls381FpInv" => SyscallCode::BLS12381_FP_INV,
+        "Bls381FpBatchInv" => SyscallCode::BLS12381_FP_BATCH_INV,
         "Bls381Fp2Mul" => SyscallCode::BLS12381_FP2_MUL,
         "Bls381Fp2AddSub" => SyscallCode::BLS12381_FP2_ADD,
         "Secp256k1FpOp" => SyscallCode::SECP256K1_FP_ADD,
+        "Secp256k1FpInv" => SyscallCode::SECP256K1_FP_INV,
+        "Secp256k1FpBatchInv" => SyscallCode::SECP256K1_FP_BATCH_INV,
         "Poseidon2Permute" => SyscallCode::POSEIDON2_PERMUTE,
+        "Poseidon2Compress" => SyscallCode::POSEIDON2_COMPRESS,
+        "PolyEval" => SyscallCode::POLY_EVAL,
+        "ConstantTimeEq" => SyscallCode::CT_EQ,
+        "Sha256Digest" => SyscallCode::SHA256,
+        "MultiHash" => SyscallCode::MULTI_HASH,
+        "SortU32" => SyscallCode::SORT_U32,
+        "Aes128EncryptBlock" => SyscallCode::AES128_ENCRYPT_BLOCK,
+        "AesKeySchedule" => SyscallCode::AES_KEY_SCHEDULE,
+        "Aes256EncryptBlock" => SyscallCode::AES256_ENCRYPT_BLOCK,
+        "RlpDecodeItem" => SyscallCode::RLP_DECODE_ITEM,
+        "Base64Decode" => SyscallCode::BASE64_DECODE,
+        "RangeCheckBatch" => SyscallCode::RANGE_CHECK_BATCH,
+        "FieldSort" => SyscallCode::FIELD_SORT,
+        "Crc32" => SyscallCode::CRC32,
         _ => {
             unreachable!("precompile {} not supported yet", chip_name);
         }
     }
 }
 
+// TODO: remove hardcode chip_names here
+/// The lookup tables a precompile uses besides the byte table, with their log heights. They are
+/// only part of the shapes of that precompile.
+pub(crate) fn precompile_table_chips(chip_name: &str) -> Vec<(String, usize)> {
+    match chip_name {
+        "Aes128EncryptBlock" | "AesKeySchedule" | "Aes256EncryptBlock" => {
+            vec![("AesSbox".to_string(), 8)]
+        }
+        "Crc32" => vec![("Crc32Table".to_string(), 8)],
+        _ => vec![],
+    }
+}
+
 fn filter_shapes<F: FieldSpecificPoseidon2Config>(
     shapes: impl Iterator<Item = ProofShape>,
 ) -> impl Iterator<Item = ProofShape> {
@@ -567,7 +608,7 @@ impl<F: PrimeField32 + FieldSpecificPoseidon2Config> RiscvShapeConfig<F> {
         chip_name: String,
         mem_events_per_row: usize,
         allowed_log_height: usize,
-    ) -> Vec<[(String, usize); 5]> {
+    ) -> Vec<Vec<(String, usize)>> {
         // TODO: this is a temporary workaround to the precompile chunk shape
         // vec![1, precompile_rows_per_event(&chip_name)]
         // .into_iter()
@@ -581,7 +622,7 @@ impl<F: PrimeField32 + FieldSpecificPoseidon2Config> RiscvShapeConfig<F> {
                     + ((1 << allowed_log_height) as usize)
                         .div_ceil(precompile_rows_per_event(&chip_name));
 
-                [
+                let mut shape = vec![
                     (chip_name.clone(), allowed_log_height),
                     (
                         SyscallChip::<F>::precompile().name(),
@@ -611,7 +652,9 @@ impl<F: PrimeField32 + FieldSpecificPoseidon2Config> RiscvShapeConfig<F> {
                             .ilog2() as usize)
                             .clamp(4, 21),
                     ),
-                ]
+                ];
+                shape.extend(precompile_table_chips(&chip_name));
+                shape
             })
             .collect()
     }
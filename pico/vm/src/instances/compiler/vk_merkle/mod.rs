@@ -2,7 +2,10 @@ pub mod builder;
 pub mod stdin;
 
 use crate::{
-    compiler::recursion::circuit::{hash::FieldHasher, merkle_tree::MerkleTree},
+    compiler::recursion::circuit::{
+        hash::FieldHasher,
+        merkle_tree::{MerkleProof, MerkleTree},
+    },
     configs::{
         config::{StarkGenericConfig, Val},
         stark_config::{BabyBearPoseidon2, KoalaBearPoseidon2},
@@ -13,12 +16,16 @@ use crate::{
     },
     machine::{
         chip::ChipBehavior,
-        keys::{BaseVerifyingKey, HashableKey},
+        keys::{vk_digest_fingerprint, BaseVerifyingKey, HashableKey},
     },
     primitives::consts::DIGEST_SIZE,
 };
 use once_cell::sync::Lazy;
-use std::{collections::BTreeMap, env};
+use p3_field::PrimeField32;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+};
 use tracing::{debug, info};
 
 pub fn vk_verification_enabled() -> bool {
@@ -41,7 +48,10 @@ where
     Val<SC>: Ord,
 {
     /// Initialize the VkMerkleManager
-    pub fn new_from_bytes(file_content: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn new_from_bytes(file_content: &[u8]) -> Result<Self, Box<dyn std::error::Error>>
+    where
+        Val<SC>: PrimeField32,
+    {
         // Deserialize the vk_map from the byte slice
         let allowed_vk_map: BTreeMap<[Val<SC>; DIGEST_SIZE], usize> =
             bincode::deserialize(file_content)?;
@@ -52,6 +62,7 @@ where
 
         let vk_verification = vk_verification_enabled();
         info!("VK_VERIFICATION: {}", vk_verification);
+        log_allowed_vks::<SC>(&allowed_vk_map);
 
         Ok(Self {
             allowed_vk_map,
@@ -66,7 +77,10 @@ where
     }
 
     /// Initialize the VkMerkleManager from a file
-    pub fn new_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn new_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>>
+    where
+        Val<SC>: PrimeField32,
+    {
         // Deserialize the vk_map from the file
         let allowed_vk_map: BTreeMap<[Val<SC>; DIGEST_SIZE], usize> =
             bincode::deserialize(std::fs::read(file_path)?.as_slice())?;
@@ -76,6 +90,7 @@ where
             MerkleTree::commit(allowed_vk_map.keys().copied().collect());
 
         let vk_verification = vk_verification_enabled();
+        log_allowed_vks::<SC>(&allowed_vk_map);
 
         Ok(Self {
             allowed_vk_map,
@@ -85,6 +100,41 @@ where
         })
     }
 
+    /// Initialize the VkMerkleManager from the vk digests of a set of allowed programs
+    ///
+    /// The Merkle tree needs at least two leaves, so the set must hold two distinct digests.
+    pub fn new_from_vk_digests(vk_digests: impl IntoIterator<Item = [Val<SC>; DIGEST_SIZE]>) -> Self
+    where
+        Val<SC>: PrimeField32,
+    {
+        // Index the digests in sorted order, which is the order of the Merkle leaves
+        let allowed_vk_map: BTreeMap<[Val<SC>; DIGEST_SIZE], usize> = vk_digests
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .enumerate()
+            .map(|(index, vk_digest)| (vk_digest, index))
+            .collect();
+        assert!(
+            allowed_vk_map.len() >= 2,
+            "at least two distinct vk digests are required, got {}",
+            allowed_vk_map.len()
+        );
+
+        let (merkle_root, merkle_tree) =
+            MerkleTree::commit(allowed_vk_map.keys().copied().collect());
+
+        let vk_verification = vk_verification_enabled();
+        log_allowed_vks::<SC>(&allowed_vk_map);
+
+        Self {
+            allowed_vk_map,
+            merkle_root,
+            merkle_tree,
+            vk_verification,
+        }
+    }
+
     /// Generate a RecursionVkStdin from a given RecursionStdin input
     pub fn add_vk_merkle_proof<'a, C>(
         &self,
@@ -92,6 +142,7 @@ where
     ) -> RecursionVkStdin<'a, SC, C>
     where
         BaseVerifyingKey<SC>: HashableKey<Val<SC>>,
+        Val<SC>: PrimeField32,
         C: ChipBehavior<Val<SC>>,
     {
         // Map over vks_and_proofs to extract vk digests and their indices
@@ -103,7 +154,8 @@ where
                 let index = self
                     .allowed_vk_map
                     .get(&vk_digest)
-                    .unwrap_or_else(|| panic!("vk not allowed: {:?}", vk_digest));
+                    .unwrap_or_else(|| panic!("vk not allowed: {} (digest {:?})", vk, vk_digest));
+                debug!("vk {} (digest {:?}) at index {}", vk, vk_digest, index);
                 (*index, vk_digest)
             })
             .unzip();
@@ -130,6 +182,32 @@ where
     pub fn is_vk_allowed(&self, vk_digest: [Val<SC>; DIGEST_SIZE]) -> bool {
         self.allowed_vk_map.contains_key(&vk_digest)
     }
+
+    /// Open the Merkle path of an allowed vk digest, to be checked against `merkle_root` by
+    /// `MachineBehavior::verify_with_allowlist`. Returns `None` if the digest is not allowed.
+    pub fn open_vk(&self, vk_digest: &[Val<SC>; DIGEST_SIZE]) -> Option<MerkleProof<Val<SC>, SC>> {
+        let index = self.allowed_vk_map.get(vk_digest)?;
+        let (_, proof) = MerkleTree::open(&self.merkle_tree, *index);
+        Some(proof)
+    }
+}
+
+/// Logs the fingerprint of each allowed vk next to its full digest.
+fn log_allowed_vks<SC>(allowed_vk_map: &BTreeMap<[Val<SC>; DIGEST_SIZE], usize>)
+where
+    SC: StarkGenericConfig,
+    Val<SC>: PrimeField32,
+{
+    debug!("{} allowed vks", allowed_vk_map.len());
+    for (digest, index) in allowed_vk_map {
+        let fingerprint = vk_digest_fingerprint(digest);
+        debug!(
+            "allowed vk {}: pico-vk:{} (digest {:?})",
+            index,
+            hex::encode_upper(fingerprint),
+            digest
+        );
+    }
 }
 
 pub static VK_MANAGER_BB: Lazy<VkMerkleManager<BabyBearPoseidon2>> = Lazy::new(|| {
@@ -163,3 +241,71 @@ impl HasStaticVkManager for KoalaBearPoseidon2 {
         &VK_MANAGER_KB
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::VkMerkleManager;
+    use crate::{
+        chips::chips::toys::toy::ToyChip,
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        configs::config::StarkGenericConfig,
+        emulator::{opts::EmulatorOpts, riscv::emulator::RiscvEmulator},
+        instances::{
+            configs::riscv_bb_poseidon2::StarkConfig as RiscvBBSC, machine::simple::SimpleMachine,
+        },
+        machine::{
+            chip::MetaChip, keys::HashableKey, machine::MachineBehavior, proof::MetaProof,
+            witness::ProvingWitness,
+        },
+        primitives::consts::RISCV_NUM_PVS,
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+    use p3_field::FieldAlgebra;
+
+    #[test]
+    fn test_verify_with_allowlist() {
+        // The toy chip has a preprocessed trace of 2048 rows, which the add events must fill.
+        let instructions = vec![Instruction::new(Opcode::ADD, 5, 5, 1, false, true); 1500];
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program.clone(), EmulatorOpts::default());
+        let mut records = emulator.run(None).unwrap();
+
+        let machine = SimpleMachine::new(
+            RiscvBBSC::new(),
+            vec![MetaChip::new(ToyChip::default())],
+            RISCV_NUM_PVS,
+        );
+        let (pk, vk) = machine.setup_keys(&program);
+        machine.complement_record(&mut records);
+        let witness = ProvingWitness::setup_with_keys_and_records(pk, vk.clone(), records);
+        let proof = machine.prove(&witness);
+
+        let vk_digest = vk.hash_field();
+        let others = (1..5u32).map(|i| [BabyBear::from_canonical_u32(i); 8]);
+
+        // Accepted when the vk is in the committed set.
+        let allowed =
+            VkMerkleManager::<RiscvBBSC>::new_from_vk_digests(others.clone().chain([vk_digest]));
+        let merkle_proof = allowed.open_vk(&vk_digest).unwrap();
+        machine
+            .verify_with_allowlist(&proof, &allowed.merkle_root, &merkle_proof)
+            .unwrap();
+
+        // An allowed program still goes through the machine verifier.
+        let truncated = MetaProof::new(Arc::new([]), proof.vks.clone(), None);
+        assert!(machine
+            .verify_with_allowlist(&truncated, &allowed.merkle_root, &merkle_proof)
+            .is_err());
+
+        // Rejected against a set without the vk, whichever path is presented.
+        let denied = VkMerkleManager::<RiscvBBSC>::new_from_vk_digests(others);
+        assert!(denied.open_vk(&vk_digest).is_none());
+        let other_proof = denied.open_vk(&[BabyBear::ONE; 8]).unwrap();
+        for merkle_proof in [&merkle_proof, &other_proof] {
+            assert!(machine
+                .verify_with_allowlist(&proof, &denied.merkle_root, merkle_proof)
+                .is_err());
+        }
+    }
+}
@@ -10,13 +10,13 @@ use crate::{
     emulator::{
         emulator::{BabyBearMetaEmulator, KoalaBearMetaEmulator},
         record::RecordBehavior,
-        recursion::{emulator::RecursionRecord, public_values::RecursionPublicValues},
+        recursion::emulator::RecursionRecord,
         stdin::EmulatorStdin,
     },
     instances::{
         chiptype::recursion_chiptype::RecursionChipType,
         compiler::{
-            shapes::recursion_shape::RecursionShapeConfig,
+            shapes::recursion_shape::{check_combine_arity, RecursionShapeConfig},
             vk_merkle::{stdin::RecursionStdinVariant, HasStaticVkManager},
         },
     },
@@ -26,16 +26,19 @@ use crate::{
         keys::HashableKey,
         machine::{BaseMachine, MachineBehavior},
         proof::MetaProof,
-        utils::{assert_recursion_public_values_valid, assert_riscv_vk_digest},
+        utils::{
+            check_recursion_public_values, check_riscv_vk_digest, recursion_riscv_vk_digest,
+            single_recursion_public_values,
+        },
         witness::ProvingWitness,
     },
-    primitives::consts::COMBINE_SIZE,
+    primitives::consts::DIGEST_SIZE,
 };
-use anyhow::Result;
+use anyhow::{bail, Result};
 use p3_air::Air;
 use p3_field::FieldAlgebra;
 use p3_maybe_rayon::prelude::*;
-use std::{any::type_name, borrow::Borrow, time::Instant};
+use std::{any::type_name, time::Instant};
 use tracing::{debug, debug_span, instrument};
 
 pub struct CombineMachine<SC, C>
@@ -74,8 +77,8 @@ macro_rules! impl_combine_machine {
                 &self.base_machine
             }
 
-            /// Get the prover of the machine.
-            #[instrument(name = "COMBINE MACHINE PROVE", level = "debug", skip_all)]
+            /// Get the prover of the machine. The combine programs are padded to the default
+            /// recursion shapes, see [`Self::prove_with_shape`] for other shapes.
             fn prove(
                 &self,
                 proving_witness: &ProvingWitness<$recur_sc, C, RecursionStdinVariant<$recur_sc, C>>,
@@ -89,6 +92,87 @@ macro_rules! impl_combine_machine {
                     >,
                 >,
             {
+                let recursion_shape_config = RecursionShapeConfig::default();
+                self.prove_with_shape(proving_witness, Some(&recursion_shape_config))
+            }
+
+            /// Verify the proof.
+            fn verify(&self, proof: &MetaProof<$recur_sc>, riscv_vk: &dyn HashableKey<Val<$recur_sc>>) -> Result<()> {
+                // assert completion
+                let public_values = single_recursion_public_values(proof)?;
+                if public_values.flag_complete != <Val<$recur_sc>>::ONE {
+                    bail!("flag_complete is not 1");
+                }
+
+                check_recursion_public_values(self.config().as_ref(), public_values)?;
+                check_riscv_vk_digest(proof, riscv_vk)?;
+
+                // Vk Verification
+                let [combine_vk] = proof.vks() else {
+                    bail!("expected a single vk, got {}", proof.vks().len());
+                };
+                let vk_manager = <$recur_sc as HasStaticVkManager>::static_vk_manager();
+                if vk_manager.vk_verification_enabled()
+                    && !vk_manager.is_vk_allowed(combine_vk.hash_field())
+                {
+                    bail!("Recursion Vk Verification failed");
+                }
+
+
+                // verify
+                self.base_machine
+                    .verify_ensemble(combine_vk, &proof.proofs())?;
+
+                Ok(())
+            }
+
+            fn riscv_vk_digest(
+                &self,
+                proof: &MetaProof<$recur_sc>,
+            ) -> Result<[Val<$recur_sc>; DIGEST_SIZE]> {
+                recursion_riscv_vk_digest(proof)
+            }
+        }
+
+        impl<C> CombineMachine<$recur_sc, C>
+        where
+            C: Send + Sync
+                + ChipBehavior<
+                    Val<$recur_sc>,
+                    Program = RecursionProgram<Val<$recur_sc>>,
+                    Record = RecursionRecord<Val<$recur_sc>>,
+                > + Air<ProverConstraintFolder<$recur_sc>>
+                + for<'b> Air<VerifierConstraintFolder<'b, $recur_sc>>
+                + for<'b> Air<RecursiveVerifierConstraintFolder<'b, $recur_cc>>,
+        {
+            /// Prove like [`MachineBehavior::prove`], padding the combine programs to
+            /// `recursion_shape_config`, which must be given when vk verification is enabled.
+            /// The combine arity of the options must match the arity of the shapes.
+            #[instrument(name = "COMBINE MACHINE PROVE", level = "debug", skip_all)]
+            pub fn prove_with_shape(
+                &self,
+                proving_witness: &ProvingWitness<$recur_sc, C, RecursionStdinVariant<$recur_sc, C>>,
+                recursion_shape_config: Option<
+                    &RecursionShapeConfig<Val<$recur_sc>, RecursionChipType<Val<$recur_sc>>>,
+                >,
+            ) -> MetaProof<$recur_sc>
+            where
+                C: for<'c> Air<
+                    DebugConstraintFolder<
+                        'c,
+                        <$recur_sc as StarkGenericConfig>::Val,
+                        <$recur_sc as StarkGenericConfig>::Challenge,
+                    >,
+                >,
+            {
+                let combine_arity = proving_witness.opts.as_ref().unwrap().combine_arity;
+                let vk_manager = <$recur_sc as HasStaticVkManager>::static_vk_manager();
+                check_combine_arity(
+                    combine_arity,
+                    vk_manager.vk_verification_enabled(),
+                    recursion_shape_config,
+                );
+
                 let mut recursion_emulator = $emul_name::<
                     _,
                     _,
@@ -107,12 +191,6 @@ macro_rules! impl_combine_machine {
 
                 let mut chunk_index = 1;
                 let mut layer_index = 1;
-                // TODO: move recursion_shape_config creation in higher level functions
-                let vk_manager = <$recur_sc as HasStaticVkManager>::static_vk_manager();
-                let recursion_shape_config = RecursionShapeConfig::<
-                    Val<$recur_sc>,
-                    RecursionChipType<Val<$recur_sc>>,
-                >::default();
 
                 loop {
                     let mut batch_num = 1;
@@ -214,10 +292,10 @@ macro_rules! impl_combine_machine {
                         &all_vks,
                         &all_proofs,
                         self.base_machine(),
-                        COMBINE_SIZE,
-                        all_proofs.len() <= COMBINE_SIZE,
+                        combine_arity,
+                        all_proofs.len() <= combine_arity,
                         &vk_manager,
-                        Some(&recursion_shape_config),
+                        recursion_shape_config,
                     );
 
                     recursion_witness = ProvingWitness::setup_for_combine(
@@ -226,7 +304,7 @@ macro_rules! impl_combine_machine {
                         last_vk,
                         last_proof,
                         self.config(),
-                        proving_witness.opts.unwrap(),
+                        proving_witness.opts.clone().unwrap(),
                     );
 
                     recursion_emulator =
@@ -259,38 +337,6 @@ macro_rules! impl_combine_machine {
                 // construct meta proof
                 MetaProof::new(all_proofs.into(), all_vks.into(), None)
             }
-
-            /// Verify the proof.
-            fn verify(&self, proof: &MetaProof<$recur_sc>, riscv_vk: &dyn HashableKey<Val<$recur_sc>>) -> Result<()> {
-                assert_eq!(proof.proofs().len(), 1);
-
-                // assert completion
-
-                let public_values: &RecursionPublicValues<_> =
-                    proof.proofs[0].public_values.as_ref().borrow();
-
-                if public_values.flag_complete != <Val<$recur_sc>>::ONE {
-                    panic!("flag_complete is not 1");
-                }
-
-                assert_recursion_public_values_valid(self.config().as_ref(), public_values);
-                assert_riscv_vk_digest(proof, riscv_vk);
-
-                // Vk Verification
-                assert_eq!(proof.vks().len(), 1);
-                let vk_manager = <$recur_sc as HasStaticVkManager>::static_vk_manager();
-                let combine_vk = proof.vks().first().unwrap();
-                if vk_manager.vk_verification_enabled(){
-                    assert!(vk_manager.is_vk_allowed(combine_vk.hash_field()), "Recursion Vk Verification failed");
-                }
-
-
-                // verify
-                self.base_machine
-                    .verify_ensemble(combine_vk, &proof.proofs())?;
-
-                Ok(())
-            }
         }
     };
 }
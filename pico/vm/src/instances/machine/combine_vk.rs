@@ -10,7 +10,7 @@ use crate::{
     emulator::{
         emulator::{BabyBearMetaEmulator, KoalaBearMetaEmulator},
         record::RecordBehavior,
-        recursion::{emulator::RecursionRecord, public_values::RecursionPublicValues},
+        recursion::emulator::RecursionRecord,
         stdin::EmulatorStdin,
     },
     instances::{
@@ -26,16 +26,19 @@ use crate::{
         keys::HashableKey,
         machine::{BaseMachine, MachineBehavior},
         proof::MetaProof,
-        utils::{assert_recursion_public_values_valid, assert_riscv_vk_digest},
+        utils::{
+            check_recursion_public_values, check_riscv_vk_digest, recursion_riscv_vk_digest,
+            single_recursion_public_values,
+        },
         witness::ProvingWitness,
     },
-    primitives::consts::COMBINE_SIZE,
+    primitives::consts::{COMBINE_SIZE, DIGEST_SIZE},
 };
-use anyhow::Result;
+use anyhow::{bail, Result};
 use p3_air::Air;
 use p3_field::FieldAlgebra;
 use p3_maybe_rayon::prelude::*;
-use std::{any::type_name, borrow::Borrow, time::Instant};
+use std::{any::type_name, time::Instant};
 use tracing::{debug, instrument};
 
 pub struct CombineVkMachine<SC, C>
@@ -222,7 +225,7 @@ macro_rules! impl_combine_vk_machine {
                         last_vk,
                         last_proof,
                         self.config(),
-                        proving_witness.opts.unwrap(),
+                        proving_witness.opts.clone().unwrap(),
                     );
 
                     recursion_emulator =
@@ -257,25 +260,23 @@ macro_rules! impl_combine_vk_machine {
 
             /// Verify the proof.
             fn verify(&self, proof: &MetaProof<$recur_sc>, riscv_vk: &dyn HashableKey<Val<$recur_sc>>) -> Result<()> {
-                assert_eq!(proof.proofs().len(), 1);
-
                 // assert completion
-
-                let public_values: &RecursionPublicValues<_> =
-                    proof.proofs[0].public_values.as_ref().borrow();
-
+                let public_values = single_recursion_public_values(proof)?;
                 if public_values.flag_complete != <Val<$recur_sc>>::ONE {
-                    panic!("flag_complete is not 1");
+                    bail!("flag_complete is not 1");
                 }
 
-                assert_recursion_public_values_valid(self.config().as_ref(), public_values);
-                assert_riscv_vk_digest(proof, riscv_vk);
+                check_recursion_public_values(self.config().as_ref(), public_values)?;
+                check_riscv_vk_digest(proof, riscv_vk)?;
 
                 // Vk Verification
-                assert_eq!(proof.vks().len(), 1);
+                let [combine_vk] = proof.vks() else {
+                    bail!("expected a single vk, got {}", proof.vks().len());
+                };
                 let vk_manager = <$recur_sc as HasStaticVkManager>::static_vk_manager();
-                let combine_vk = proof.vks().first().unwrap();
-                assert!(vk_manager.is_vk_allowed(combine_vk.hash_field()), "Recursion Vk Verification failed");
+                if !vk_manager.is_vk_allowed(combine_vk.hash_field()) {
+                    bail!("Recursion Vk Verification failed");
+                }
 
 
                 // verify
@@ -284,6 +285,13 @@ macro_rules! impl_combine_vk_machine {
 
                 Ok(())
             }
+
+            fn riscv_vk_digest(
+                &self,
+                proof: &MetaProof<$recur_sc>,
+            ) -> Result<[Val<$recur_sc>; DIGEST_SIZE]> {
+                recursion_riscv_vk_digest(proof)
+            }
         }
     };
 }
@@ -1,10 +1,7 @@
 use crate::{
     compiler::recursion::{circuit::hash::FieldHasher, program::RecursionProgram},
     configs::config::{Com, PcsProof, PcsProverData, StarkGenericConfig, Val},
-    emulator::{
-        record::RecordBehavior,
-        recursion::{emulator::RecursionRecord, public_values::RecursionPublicValues},
-    },
+    emulator::{record::RecordBehavior, recursion::emulator::RecursionRecord},
     instances::compiler::vk_merkle::{stdin::RecursionStdinVariant, HasStaticVkManager},
     machine::{
         chip::{ChipBehavior, MetaChip},
@@ -12,15 +9,19 @@ use crate::{
         keys::{BaseVerifyingKey, HashableKey},
         machine::{BaseMachine, MachineBehavior},
         proof::{BaseProof, MetaProof},
-        utils::{assert_recursion_public_values_valid, assert_riscv_vk_digest},
+        utils::{
+            check_recursion_public_values, check_riscv_vk_digest, recursion_riscv_vk_digest,
+            single_recursion_public_values,
+        },
         witness::ProvingWitness,
     },
-    primitives::consts::EXTENSION_DEGREE,
+    primitives::consts::{DIGEST_SIZE, EXTENSION_DEGREE},
 };
+use anyhow::bail;
 use p3_air::Air;
 use p3_commit::TwoAdicMultiplicativeCoset;
 use p3_field::{extension::BinomiallyExtendable, PrimeField32, TwoAdicField};
-use std::{any::type_name, borrow::Borrow};
+use std::any::type_name;
 use tracing::{debug, debug_span, instrument};
 
 pub struct CompressMachine<SC, C>
@@ -119,35 +120,35 @@ where
         proof: &MetaProof<SC>,
         riscv_vk: &dyn HashableKey<SC::Val>,
     ) -> anyhow::Result<()> {
-        let vk = proof.vks().first().unwrap();
+        let [vk] = proof.vks() else {
+            bail!("expected a single vk, got {}", proof.vks().len());
+        };
 
         let vk_manager = <SC as HasStaticVkManager>::static_vk_manager();
 
-        if vk_manager.vk_verification_enabled() {
-            assert!(
-                vk_manager.is_vk_allowed(vk.hash_field()),
-                "Recursion Vk Verification failed"
-            );
+        if vk_manager.vk_verification_enabled() && !vk_manager.is_vk_allowed(vk.hash_field()) {
+            bail!("Recursion Vk Verification failed");
         }
 
-        assert_eq!(proof.num_proofs(), 1);
-
-        let public_values: &RecursionPublicValues<_> =
-            proof.proofs[0].public_values.as_ref().borrow();
+        let public_values = single_recursion_public_values(proof)?;
 
         // assert completion
         if public_values.flag_complete != <Val<SC>>::ONE {
-            panic!("flag_complete is not 1");
+            bail!("flag_complete is not 1");
         }
 
-        assert_recursion_public_values_valid(self.config().as_ref(), public_values);
-        assert_riscv_vk_digest(proof, riscv_vk);
+        check_recursion_public_values(self.config().as_ref(), public_values)?;
+        check_riscv_vk_digest(proof, riscv_vk)?;
 
         // verify
         self.base_machine.verify_ensemble(vk, &proof.proofs())?;
 
         Ok(())
     }
+
+    fn riscv_vk_digest(&self, proof: &MetaProof<SC>) -> anyhow::Result<[Val<SC>; DIGEST_SIZE]> {
+        recursion_riscv_vk_digest(proof)
+    }
 }
 
 impl<SC, C> CompressMachine<SC, C>
@@ -7,7 +7,7 @@ use crate::{
     emulator::{
         emulator::{BabyBearMetaEmulator, KoalaBearMetaEmulator},
         record::RecordBehavior,
-        recursion::{emulator::RecursionRecord, public_values::RecursionPublicValues},
+        recursion::emulator::RecursionRecord,
     },
     instances::{
         chiptype::riscv_chiptype::RiscvChipType, compiler::riscv_circuit::stdin::ConvertStdin,
@@ -18,14 +18,18 @@ use crate::{
         keys::HashableKey,
         machine::{BaseMachine, MachineBehavior},
         proof::MetaProof,
-        utils::{assert_recursion_public_values_valid, assert_riscv_vk_digest},
+        utils::{
+            check_recursion_public_values, check_riscv_vk_digest, recursion_public_values,
+            recursion_riscv_vk_digest,
+        },
         witness::ProvingWitness,
     },
+    primitives::consts::DIGEST_SIZE,
 };
-use anyhow::Result;
+use anyhow::{bail, Result};
 use p3_air::Air;
 use p3_maybe_rayon::prelude::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
-use std::{any::type_name, borrow::Borrow, time::Instant};
+use std::{any::type_name, time::Instant};
 use tracing::{debug, debug_span, instrument};
 
 pub struct ConvertMachine<SC, C>
@@ -191,16 +195,22 @@ macro_rules! impl_convert_machine {
             where
                 C: for<'a> Air<VerifierConstraintFolder<'a, $recur_sc>>,
             {
-                assert_riscv_vk_digest(proof, riscv_vk);
+                check_riscv_vk_digest(proof, riscv_vk)?;
+                if proof.num_proofs() != proof.vks().len() {
+                    bail!(
+                        "expected a vk for each of the {} proofs, got {}",
+                        proof.num_proofs(),
+                        proof.vks().len()
+                    );
+                }
 
                 proof
                     .proofs()
                     .par_iter()
                     .zip(proof.vks().par_iter())
                     .try_for_each(|(p, vk)| {
-                        let public_values: &RecursionPublicValues<_> =
-                            p.public_values.as_ref().borrow();
-                        assert_recursion_public_values_valid(self.config().as_ref(), public_values);
+                        let public_values = recursion_public_values(p)?;
+                        check_recursion_public_values(self.config().as_ref(), public_values)?;
 
                         self.base_machine
                             .verify_ensemble(vk, std::slice::from_ref(p))
@@ -208,6 +218,13 @@ macro_rules! impl_convert_machine {
 
                 Ok(())
             }
+
+            fn riscv_vk_digest(
+                &self,
+                proof: &MetaProof<$recur_sc>,
+            ) -> Result<[Val<$recur_sc>; DIGEST_SIZE]> {
+                recursion_riscv_vk_digest(proof)
+            }
         }
     };
 }
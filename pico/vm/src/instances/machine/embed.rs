@@ -1,25 +1,22 @@
 use crate::{
-    compiler::recursion::{
-        circuit::utils::assert_embed_public_values_valid, program::RecursionProgram,
-    },
+    compiler::recursion::{circuit::utils::check_embed_public_values, program::RecursionProgram},
     configs::config::{Challenge, Com, PcsProverData, StarkGenericConfig, Val},
-    emulator::{
-        record::RecordBehavior,
-        recursion::{emulator::RecursionRecord, public_values::RecursionPublicValues},
-    },
+    emulator::{record::RecordBehavior, recursion::emulator::RecursionRecord},
     machine::{
         chip::{ChipBehavior, MetaChip},
         folder::{DebugConstraintFolder, ProverConstraintFolder, VerifierConstraintFolder},
         keys::HashableKey,
         machine::{BaseMachine, MachineBehavior},
         proof::MetaProof,
-        utils::assert_riscv_vk_digest,
+        utils::{check_riscv_vk_digest, recursion_riscv_vk_digest, single_recursion_public_values},
         witness::ProvingWitness,
     },
+    primitives::consts::DIGEST_SIZE,
 };
+use anyhow::bail;
 use p3_air::Air;
 use p3_field::{FieldAlgebra, PrimeField32};
-use std::{any::type_name, borrow::Borrow, marker::PhantomData};
+use std::{any::type_name, marker::PhantomData};
 use tracing::{debug, debug_span, instrument};
 
 pub struct EmbedMachine<PrevSC, SC, C, I>
@@ -102,27 +99,33 @@ where
     where
         C: for<'a> Air<VerifierConstraintFolder<'a, EmbedSC>>,
     {
-        let vk = proof.vks().first().unwrap();
+        let [vk] = proof.vks() else {
+            bail!("expected a single vk, got {}", proof.vks().len());
+        };
 
-        assert_eq!(proof.num_proofs(), 1);
-
-        let public_values: &RecursionPublicValues<_> =
-            proof.proofs[0].public_values.as_ref().borrow();
+        let public_values = single_recursion_public_values(proof)?;
 
         // assert completion
         if public_values.flag_complete != <Val<EmbedSC>>::ONE {
-            panic!("flag_complete is not 1");
+            bail!("flag_complete is not 1");
         }
 
         // assert public value digest
-        assert_embed_public_values_valid(&PrevSC::new(), public_values);
+        check_embed_public_values(&PrevSC::new(), public_values)?;
 
-        assert_riscv_vk_digest(proof, riscv_vk);
+        check_riscv_vk_digest(proof, riscv_vk)?;
 
         // verify
         self.base_machine.verify_ensemble(vk, &proof.proofs())?;
         Ok(())
     }
+
+    fn riscv_vk_digest(
+        &self,
+        proof: &MetaProof<EmbedSC>,
+    ) -> anyhow::Result<[Val<EmbedSC>; DIGEST_SIZE]> {
+        recursion_riscv_vk_digest(proof)
+    }
 }
 
 impl<PrevSC, SC, C, I> EmbedMachine<PrevSC, SC, C, I>
@@ -8,25 +8,28 @@ use crate::{
     instances::compiler::{
         shapes::riscv_shape::RiscvShapeConfig, vk_merkle::vk_verification_enabled,
     },
-    iter::{IntoPicoIterator, PicoIterator},
+    iter::{IntoPicoIterator, IntoPicoRefIterator, IntoPicoRefMutIterator, PicoIterator},
     machine::{
         chip::{ChipBehavior, MetaChip},
+        debug::{IncrementalConstraintDebugger, IncrementalLookupDebugger},
         field::FieldSpecificPoseidon2Config,
         folder::{DebugConstraintFolder, ProverConstraintFolder, VerifierConstraintFolder},
-        keys::{BaseProvingKey, HashableKey},
+        keys::{BaseProvingKey, BaseVerifyingKey, HashableKey},
+        lookup::LookupScope,
         machine::{BaseMachine, MachineBehavior},
+        progress::ProveProgress,
         proof::{BaseProof, MetaProof},
         witness::ProvingWitness,
     },
     primitives::{consts::MAX_LOG_CHUNK_SIZE, Poseidon2Init},
 };
-use anyhow::Result;
+use anyhow::{bail, Result};
 use crossbeam::channel::{bounded, Receiver, Sender};
 use p3_air::Air;
 use p3_field::{FieldAlgebra, PrimeField32};
 use p3_maybe_rayon::prelude::IndexedParallelIterator;
 use p3_symmetric::Permutation;
-use std::{any::type_name, borrow::Borrow, cmp::min, mem, thread, time::Instant};
+use std::{any::type_name, borrow::Borrow, cmp::min, mem, slice, thread, time::Instant};
 use tracing::{debug, debug_span, info, instrument};
 
 /// Maximum number of pending emulation record for proving
@@ -50,12 +53,56 @@ where
     BaseProof<SC>: Send + Sync,
 {
     /// Prove with shape config
-    #[instrument(name = "RISCV MACHINE PROVE", level = "debug", skip_all)]
     pub fn prove_with_shape_cycles(
         &self,
         witness: &ProvingWitness<SC, C, Vec<u8>>,
         shape_config: Option<&RiscvShapeConfig<SC::Val>>,
     ) -> (MetaProof<SC>, u64)
+    where
+        C: for<'a> Air<
+                DebugConstraintFolder<
+                    'a,
+                    <SC as StarkGenericConfig>::Val,
+                    <SC as StarkGenericConfig>::Challenge,
+                >,
+            > + Air<ProverConstraintFolder<SC>>,
+    {
+        self.prove_with_progress(witness, shape_config, &mut |_| {})
+    }
+
+    /// Prove with shape config, reporting each emulated and proven chunk to `progress`.
+    pub fn prove_with_progress(
+        &self,
+        witness: &ProvingWitness<SC, C, Vec<u8>>,
+        shape_config: Option<&RiscvShapeConfig<SC::Val>>,
+        progress: &mut dyn FnMut(ProveProgress),
+    ) -> (MetaProof<SC>, u64)
+    where
+        C: for<'a> Air<
+                DebugConstraintFolder<
+                    'a,
+                    <SC as StarkGenericConfig>::Val,
+                    <SC as StarkGenericConfig>::Challenge,
+                >,
+            > + Air<ProverConstraintFolder<SC>>,
+    {
+        self.prove_retaining_records(witness, shape_config, progress, None)
+    }
+
+    /// Prove like [`Self::prove_with_progress`], appending the record of every chunk to
+    /// `retained_records` once it is proven, in chunk order.
+    ///
+    /// The records are the completed ones the traces were generated from. Normally each record is
+    /// dropped as soon as its chunk is proven, so retaining them keeps the whole execution in
+    /// memory at once.
+    #[instrument(name = "RISCV MACHINE PROVE", level = "debug", skip_all)]
+    pub fn prove_retaining_records(
+        &self,
+        witness: &ProvingWitness<SC, C, Vec<u8>>,
+        shape_config: Option<&RiscvShapeConfig<SC::Val>>,
+        progress: &mut dyn FnMut(ProveProgress),
+        mut retained_records: Option<&mut Vec<EmulationRecord>>,
+    ) -> (MetaProof<SC>, u64)
     where
         C: for<'a> Air<
                 DebugConstraintFolder<
@@ -118,6 +165,7 @@ where
 
         // Generate the proofs.
         let mut current_chunk = 0;
+        let mut num_emulated = 0;
         let all_proofs = {
             #[cfg(feature = "debug")]
             let mut constraint_debugger = crate::machine::debug::IncrementalConstraintDebugger::new(
@@ -125,6 +173,11 @@ where
                 &mut self.config().challenger(),
                 self.base_machine.has_global(),
             );
+            #[cfg(feature = "debug")]
+            let mut memory_argument_debugger =
+                crate::machine::debug::IncrementalMemoryArgumentDebugger::new(
+                    witness.program.as_ref().unwrap(),
+                );
             #[cfg(feature = "debug-lookups")]
             let mut global_lookup_debugger = crate::machine::debug::IncrementalLookupDebugger::new(
                 pk,
@@ -138,6 +191,10 @@ where
 
             while let Ok(record) = record_receiver.recv() {
                 pending_records.push(record);
+                progress(ProveProgress::Emulating {
+                    chunk_index: num_emulated,
+                });
+                num_emulated += 1;
 
                 debug!(
                     "Current riscv records queue size: {}",
@@ -154,9 +211,14 @@ where
                     );
 
                     let records = mem::take(&mut pending_records);
+                    (current_chunk..current_chunk + records.len())
+                        .for_each(|chunk_index| progress(ProveProgress::TraceGen { chunk_index }));
 
                     #[cfg(feature = "debug")]
-                    constraint_debugger.debug_incremental(&self.chips(), &records);
+                    {
+                        constraint_debugger.debug_incremental(&self.chips(), &records);
+                        memory_argument_debugger.debug_incremental(&records);
+                    }
                     #[cfg(feature = "debug-lookups")]
                     {
                         crate::machine::debug::debug_regional_lookups(
@@ -168,8 +230,14 @@ where
                         global_lookup_debugger.debug_incremental(&self.chips(), &records);
                     }
 
-                    let proofs =
-                        self.prove_records(current_chunk, pk, &challenger, shape_config, records);
+                    let proofs = self.prove_records(
+                        current_chunk,
+                        pk,
+                        &challenger,
+                        shape_config,
+                        records,
+                        retained_records.as_deref_mut(),
+                    );
                     all_proofs.extend(proofs);
 
                     debug!(
@@ -194,7 +262,10 @@ where
                 );
 
                 #[cfg(feature = "debug")]
-                constraint_debugger.debug_incremental(&self.chips(), &pending_records);
+                {
+                    constraint_debugger.debug_incremental(&self.chips(), &pending_records);
+                    memory_argument_debugger.debug_incremental(&pending_records);
+                }
                 #[cfg(feature = "debug-lookups")]
                 {
                     crate::machine::debug::debug_regional_lookups(
@@ -206,12 +277,15 @@ where
                     global_lookup_debugger.debug_incremental(&self.chips(), &pending_records);
                 }
 
+                (current_chunk..current_chunk + pending_len)
+                    .for_each(|chunk_index| progress(ProveProgress::TraceGen { chunk_index }));
                 let proofs = self.prove_records(
                     current_chunk,
                     pk,
                     &challenger,
                     shape_config,
                     pending_records,
+                    retained_records.as_deref_mut(),
                 );
                 all_proofs.extend(proofs);
 
@@ -224,7 +298,10 @@ where
             }
 
             #[cfg(feature = "debug")]
-            constraint_debugger.print_results();
+            {
+                constraint_debugger.print_results();
+                memory_argument_debugger.print_results();
+            }
             #[cfg(feature = "debug-lookups")]
             global_lookup_debugger.print_results();
 
@@ -302,14 +379,88 @@ where
         self.prove_with_shape_cycles(witness, None)
     }
 
+    /// Emulate the program and check the constraints and the lookups of every chunk instead of
+    /// proving it.
+    ///
+    /// All records are kept in memory, so this is meant for small inputs during development. The
+    /// debuggers log the details of every failure; the error names the chip and row of the first
+    /// failing constraint.
+    pub fn debug_with_shape(
+        &self,
+        witness: &ProvingWitness<SC, C, Vec<u8>>,
+        shape_config: Option<&RiscvShapeConfig<SC::Val>>,
+    ) -> Result<()>
+    where
+        C: for<'a> Air<
+            DebugConstraintFolder<
+                'a,
+                <SC as StarkGenericConfig>::Val,
+                <SC as StarkGenericConfig>::Challenge,
+            >,
+        >,
+    {
+        let pk = witness.pk();
+        let mut emulator = MetaEmulator::setup_riscv(witness);
+        let mut records = Vec::new();
+        loop {
+            let done = emulator.next_record_batch(&mut |record| records.push(record));
+            if done {
+                break;
+            }
+        }
+
+        let chips = self.chips();
+        records.pico_iter_mut().for_each(|record| {
+            RiscvMachine::complement_record_static(chips.clone(), record);
+            if vk_verification_enabled() {
+                if let Some(shape_config) = shape_config {
+                    shape_config.padding_shape(record).unwrap();
+                }
+            }
+        });
+
+        let mut constraint_debugger = IncrementalConstraintDebugger::new(
+            pk,
+            &mut self.config().challenger(),
+            self.base_machine.has_global(),
+        );
+        constraint_debugger.debug_incremental(&chips, &records);
+        let first_failure = constraint_debugger
+            .first_failure()
+            .map(|(chip, row)| (chip.to_string(), row));
+        let constraints_ok = constraint_debugger.print_results();
+
+        let mut lookups_ok = true;
+        for record in &records {
+            let mut debugger = IncrementalLookupDebugger::new(pk, LookupScope::Regional, None);
+            debugger.debug_incremental(&chips, slice::from_ref(record));
+            lookups_ok &= debugger.print_results();
+        }
+        let mut debugger = IncrementalLookupDebugger::new(pk, LookupScope::Global, None);
+        debugger.debug_incremental(&chips, &records);
+        lookups_ok &= debugger.print_results();
+
+        if let Some((chip, row)) = first_failure {
+            bail!("constraint failed in chip {} at row {}", chip, row);
+        }
+        if !constraints_ok {
+            bail!("global cumulative sum is not zero");
+        }
+        if !lookups_ok {
+            bail!("lookups are not balanced");
+        }
+        Ok(())
+    }
+
     /// Generate the RiscV proofs for the emulation records.
-    fn prove_records(
+    pub(crate) fn prove_records(
         &self,
         base_chunk: usize,
         pk: &BaseProvingKey<SC>,
         challenger: &SC::Challenger,
         shape_config: Option<&RiscvShapeConfig<SC::Val>>,
         records: Vec<EmulationRecord>,
+        retained_records: Option<&mut Vec<EmulationRecord>>,
     ) -> Vec<BaseProof<SC>>
     where
         C: Air<ProverConstraintFolder<SC>>,
@@ -320,7 +471,8 @@ where
                     .entered();
 
         let chips = self.chips();
-        let proofs = records
+        let retain = retained_records.is_some();
+        let (proofs, records): (Vec<_>, Vec<_>) = records
             .into_pico_iter()
             .enumerate()
             .map(|(i, mut record)| {
@@ -344,21 +496,52 @@ where
                         .in_scope(|| self.base_machine.commit(&record).unwrap());
 
                 // Generate the proof.
-                debug_span!(parent: &local_span, "prove_plain", chunk_index).in_scope(|| {
-                    self.base_machine.prove_plain(
-                        pk,
-                        &mut challenger.clone(),
-                        base_chunk + i,
-                        main_commitment,
-                    )
-                })
+                let proof =
+                    debug_span!(parent: &local_span, "prove_plain", chunk_index).in_scope(|| {
+                        self.base_machine.prove_plain(
+                            pk,
+                            &mut challenger.clone(),
+                            base_chunk + i,
+                            main_commitment,
+                        )
+                    });
+                (proof, retain.then_some(record))
             })
-            .collect();
+            .unzip();
 
         local_span.exit();
 
+        if let Some(retained_records) = retained_records {
+            retained_records.extend(records.into_iter().flatten());
+        }
+
         proofs
     }
+
+    /// Verify a batch of independent proofs of the program with verifying key `vk`. The vk is
+    /// observed once and the resulting challenger is shared by all the proofs.
+    pub fn verify_batch(&self, proofs: &[MetaProof<SC>], vk: &BaseVerifyingKey<SC>) -> Vec<bool>
+    where
+        C: for<'a> Air<VerifierConstraintFolder<'a, SC>> + Sync,
+        SC::Challenger: Sync,
+        MetaProof<SC>: Sync,
+    {
+        let challenger = self.base_machine.vk_challenger(vk);
+        proofs
+            .pico_iter()
+            .enumerate()
+            .map(|(i, proof)| {
+                let proofs = proof.proofs();
+                check_chunk_public_values(vk, &proofs)
+                    .and_then(|_| {
+                        self.base_machine
+                            .verify_riscv_with_challenger(vk, &challenger, &proofs)
+                    })
+                    .inspect_err(|e| debug!("verify_batch: proof {} failed: {}", i, e))
+                    .is_ok()
+            })
+            .collect()
+    }
 }
 
 impl<SC, C> MachineBehavior<SC, C, Vec<u8>> for RiscvMachine<SC, C>
@@ -399,120 +582,127 @@ where
     where
         C: for<'a> Air<VerifierConstraintFolder<'a, SC>>,
     {
-        // Assert single vk
-        assert_eq!(proof.vks().len(), 1);
-
-        // Get vk from proof
-        let vk = proof.vks().first().unwrap();
-
-        // initialize bookkeeping
-        let mut proof_count = <Val<SC>>::ZERO;
-        let mut execution_proof_count = <Val<SC>>::ZERO;
-        let mut prev_next_pc = vk.pc_start;
-        let mut prev_last_initialize_addr_bits = [<Val<SC>>::ZERO; 32];
-        let mut prev_last_finalize_addr_bits = [<Val<SC>>::ZERO; 32];
-
-        // let mut flag_extra = true;
-        let mut committed_value_digest_prev = Default::default();
-        let zero_cvd = Default::default();
-
-        for (i, each_proof) in proof.proofs().iter().enumerate() {
-            let public_values: &PublicValues<Word<_>, _> =
-                each_proof.public_values.as_ref().borrow();
-
-            debug!(
-                "chunk: {}, execution chunk: {}",
-                public_values.chunk, public_values.execution_chunk
-            );
+        let [vk] = proof.vks() else {
+            bail!("expected a single vk, got {}", proof.vks().len());
+        };
+        let proofs = proof.proofs();
+        check_chunk_public_values(vk, &proofs)?;
 
-            // beginning constraints
-            if i == 0 && !each_proof.includes_chip("Cpu") {
-                panic!("First proof does not include Cpu chip");
-            }
+        // Verify the proofs.
+        self.base_machine.verify_riscv(vk, &proofs)
+    }
+}
 
-            // conditional constraints
-            proof_count += <Val<SC>>::ONE;
-            // hack to make execution chunk consistent
+/// Check the public values that chain the chunks of a riscv proof under `vk` together.
+fn check_chunk_public_values<SC: StarkGenericConfig>(
+    vk: &BaseVerifyingKey<SC>,
+    proofs: &[BaseProof<SC>],
+) -> Result<()>
+where
+    Val<SC>: PrimeField32,
+{
+    // initialize bookkeeping
+    let mut proof_count = <Val<SC>>::ZERO;
+    let mut execution_proof_count = <Val<SC>>::ZERO;
+    let mut prev_next_pc = vk.pc_start;
+    let mut prev_last_initialize_addr_bits = [<Val<SC>>::ZERO; 32];
+    let mut prev_last_finalize_addr_bits = [<Val<SC>>::ZERO; 32];
+
+    // let mut flag_extra = true;
+    let mut committed_value_digest_prev = Default::default();
+    let zero_cvd = Default::default();
+
+    for (i, each_proof) in proofs.iter().enumerate() {
+        let public_values: &PublicValues<Word<_>, _> = each_proof.public_values.as_ref().borrow();
+
+        debug!(
+            "chunk: {}, execution chunk: {}",
+            public_values.chunk, public_values.execution_chunk
+        );
 
-            if each_proof.includes_chip("Cpu") {
-                execution_proof_count += <Val<SC>>::ONE;
+        // beginning constraints
+        if i == 0 && !each_proof.includes_chip("Cpu") {
+            bail!("First proof does not include Cpu chip");
+        }
 
-                if public_values.execution_chunk != execution_proof_count {
-                    println!(
-                        "execution chunk: {}, execution_proof_count: {}",
-                        public_values.execution_chunk, execution_proof_count
-                    );
-                    panic!("Execution chunk number mismatch");
-                }
+        // conditional constraints
+        proof_count += <Val<SC>>::ONE;
+        // hack to make execution chunk consistent
 
-                if each_proof.log_main_degree() > MAX_LOG_CHUNK_SIZE {
-                    panic!("Cpu log degree too large");
-                }
+        if each_proof.includes_chip("Cpu") {
+            execution_proof_count += <Val<SC>>::ONE;
 
-                if public_values.start_pc == <Val<SC>>::ZERO {
-                    panic!("First proof start_pc is zero");
-                }
-            } else if public_values.start_pc != public_values.next_pc {
-                panic!("Non-Cpu proof start_pc is not equal to next_pc");
-            }
-            if !each_proof.includes_chip("MemoryInitialize")
-                && public_values.previous_initialize_addr_bits
-                    != public_values.last_initialize_addr_bits
-            {
-                panic!("Previous initialize addr bits mismatch");
+            if public_values.execution_chunk != execution_proof_count {
+                bail!(
+                    "Execution chunk number mismatch: {} != {}",
+                    public_values.execution_chunk,
+                    execution_proof_count
+                );
             }
 
-            if !each_proof.includes_chip("MemoryFinalize")
-                && public_values.previous_finalize_addr_bits
-                    != public_values.last_finalize_addr_bits
-            {
-                panic!("Previous finalize addr bits mismatch");
+            if each_proof.log_main_degree() > MAX_LOG_CHUNK_SIZE {
+                bail!("Cpu log degree too large");
             }
 
-            // ending constraints
-            if i == proof.proofs().len() - 1 && public_values.next_pc != <Val<SC>>::ZERO {
-                panic!("Last proof next_pc is not zero");
+            if public_values.start_pc == <Val<SC>>::ZERO {
+                bail!("First proof start_pc is zero");
             }
+        } else if public_values.start_pc != public_values.next_pc {
+            bail!("Non-Cpu proof start_pc is not equal to next_pc");
+        }
+        if !each_proof.includes_chip("MemoryInitialize")
+            && public_values.previous_initialize_addr_bits
+                != public_values.last_initialize_addr_bits
+        {
+            bail!("Previous initialize addr bits mismatch");
+        }
 
-            // global constraints
-            if public_values.start_pc != prev_next_pc {
-                panic!("PC mismatch");
-            }
-            if public_values.chunk != proof_count {
-                panic!("Chunk number mismatch");
-            }
+        if !each_proof.includes_chip("MemoryFinalize")
+            && public_values.previous_finalize_addr_bits != public_values.last_finalize_addr_bits
+        {
+            bail!("Previous finalize addr bits mismatch");
+        }
 
-            if public_values.exit_code != <Val<SC>>::ZERO {
-                panic!("Exit code is not zero");
-            }
-            if public_values.previous_initialize_addr_bits != prev_last_initialize_addr_bits {
-                panic!("Previous init addr bits mismatch");
-            }
-            if public_values.previous_finalize_addr_bits != prev_last_finalize_addr_bits {
-                panic!("Previous finalize addr bits mismatch");
-            }
+        // ending constraints
+        if i == proofs.len() - 1 && public_values.next_pc != <Val<SC>>::ZERO {
+            bail!("Last proof next_pc is not zero");
+        }
 
-            // update bookkeeping
-            prev_next_pc = public_values.next_pc;
-            prev_last_initialize_addr_bits = public_values.last_initialize_addr_bits;
-            prev_last_finalize_addr_bits = public_values.last_finalize_addr_bits;
-
-            // committed_value_digest checks
-            transition_with_condition(
-                &mut committed_value_digest_prev,
-                &public_values.committed_value_digest,
-                &zero_cvd,
-                each_proof.includes_chip("Cpu"),
-                "committed_value_digest",
-                i,
-            );
+        // global constraints
+        if public_values.start_pc != prev_next_pc {
+            bail!("PC mismatch");
+        }
+        if public_values.chunk != proof_count {
+            bail!("Chunk number mismatch");
         }
 
-        // Verify the proofs.
-        self.base_machine.verify_riscv(vk, &proof.proofs())?;
+        if public_values.exit_code != <Val<SC>>::ZERO {
+            bail!("Exit code is not zero");
+        }
+        if public_values.previous_initialize_addr_bits != prev_last_initialize_addr_bits {
+            bail!("Previous init addr bits mismatch");
+        }
+        if public_values.previous_finalize_addr_bits != prev_last_finalize_addr_bits {
+            bail!("Previous finalize addr bits mismatch");
+        }
 
-        Ok(())
+        // update bookkeeping
+        prev_next_pc = public_values.next_pc;
+        prev_last_initialize_addr_bits = public_values.last_initialize_addr_bits;
+        prev_last_finalize_addr_bits = public_values.last_finalize_addr_bits;
+
+        // committed_value_digest checks
+        transition_with_condition(
+            &mut committed_value_digest_prev,
+            &public_values.committed_value_digest,
+            &zero_cvd,
+            each_proof.includes_chip("Cpu"),
+            "committed_value_digest",
+            i,
+        )?;
     }
+
+    Ok(())
 }
 
 // Digest constraints.
@@ -539,17 +729,28 @@ fn transition_with_condition<'a, T: Copy + core::fmt::Debug + Eq>(
     cond: bool,
     desc: &str,
     pos: usize,
-) {
+) -> Result<()> {
     if prev != default {
-        assert_eq!(
-            prev, cur,
-            "discrepancy between {} at position {}",
-            desc, pos
-        );
+        if prev != cur {
+            bail!("discrepancy between {} at position {}", desc, pos);
+        }
     } else if cond {
         *prev = *cur;
-    } else {
-        assert_eq!(cur, default, "{} not zeroed on failed condition", desc);
+    } else if cur != default {
+        bail!("{} not zeroed on failed condition", desc);
+    }
+    Ok(())
+}
+
+impl<SC, C> Clone for RiscvMachine<SC, C>
+where
+    SC: StarkGenericConfig,
+    C: ChipBehavior<SC::Val>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            base_machine: self.base_machine.clone(),
+        }
     }
 }
 
@@ -564,3 +765,139 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RiscvMachine;
+    use crate::{
+        compiler::{
+            riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+            word::Word,
+        },
+        emulator::{
+            opts::EmulatorOpts,
+            riscv::{
+                public_values::PublicValues,
+                syscalls::{test_utils::syscall_instructions, SyscallCode},
+            },
+            stdin::EmulatorStdin,
+        },
+        instances::{
+            chiptype::riscv_chiptype::RiscvChipType,
+            configs::riscv_bb_poseidon2::StarkConfig as RiscvBBSC,
+        },
+        machine::{
+            chip::{ChipBehavior, MetaChip},
+            machine::MachineBehavior,
+            proof::{BaseProof, MetaProof},
+            witness::ProvingWitness,
+        },
+        primitives::consts::RISCV_NUM_PVS,
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+    use p3_field::FieldAlgebra;
+    use std::borrow::BorrowMut;
+
+    type TestMachine = RiscvMachine<RiscvBBSC, RiscvChipType<BabyBear>>;
+
+    /// A machine and a witness for a program that takes two chunks and halts with exit code 0.
+    fn two_chunk_witness() -> (
+        TestMachine,
+        ProvingWitness<RiscvBBSC, RiscvChipType<BabyBear>, Vec<u8>>,
+    ) {
+        let mut instructions = vec![Instruction::new(Opcode::ADD, 29, 29, 1, false, true); 300];
+        instructions.extend(syscall_instructions(SyscallCode::HALT, 0, 0));
+        // The verifier rejects a start pc of zero.
+        let program = Arc::new(Program::new(instructions, 0x1000, 0x1000));
+
+        let machine =
+            RiscvMachine::new(RiscvBBSC::new(), RiscvChipType::all_chips(), RISCV_NUM_PVS);
+        let (pk, vk) = machine.setup_keys(&program);
+        let witness = ProvingWitness::setup_for_riscv(
+            program,
+            EmulatorStdin::<Program, Vec<u8>>::new_builder().finalize(),
+            EmulatorOpts {
+                chunk_size: 256,
+                ..Default::default()
+            },
+            pk,
+            vk,
+        );
+        (machine, witness)
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let (machine, witness) = two_chunk_witness();
+        let vk = witness.vk();
+        let (proof, _) = machine.prove_with_shape_cycles(&witness, None);
+        assert!(proof.proofs().len() > 1);
+
+        // A chunk claiming a non-zero exit code.
+        let mut chunks = proof.proofs().to_vec();
+        let mut public_values = chunks[0].public_values.to_vec();
+        let pv: &mut PublicValues<Word<BabyBear>, BabyBear> =
+            public_values.as_mut_slice().borrow_mut();
+        pv.exit_code = BabyBear::ONE;
+        chunks[0] = BaseProof {
+            public_values: public_values.into(),
+            ..chunks[0].clone()
+        };
+        let tampered = MetaProof::new(chunks.into(), proof.vks.clone(), None);
+
+        // A proof missing its last chunk.
+        let chunks = proof.proofs();
+        let truncated = MetaProof::new(
+            chunks[..chunks.len() - 1].to_vec().into(),
+            proof.vks.clone(),
+            None,
+        );
+
+        let results = machine.verify_batch(&[proof.clone(), tampered, truncated, proof], vk);
+        assert_eq!(results, [true, false, false, true]);
+    }
+
+    #[test]
+    fn test_check_all_chips() {
+        let machine =
+            RiscvMachine::new(RiscvBBSC::new(), RiscvChipType::all_chips(), RISCV_NUM_PVS);
+        machine.base_machine().check_all_chips().unwrap();
+
+        // Drop the keccak permutation chip and register the byte chip twice.
+        let mut chips = RiscvChipType::<BabyBear>::all_chips()
+            .into_iter()
+            .filter(|chip| chip.name() != "KeccakPermute")
+            .collect::<Vec<_>>();
+        chips.push(MetaChip::new(RiscvChipType::Byte(Default::default())));
+        let machine = RiscvMachine::new(RiscvBBSC::new(), chips, RISCV_NUM_PVS);
+        let err = machine
+            .base_machine()
+            .check_all_chips()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("chip Byte is registered more than once"));
+        assert!(err.contains("syscall KECCAK_PERMUTE is received by no chip"));
+    }
+
+    #[test]
+    fn test_prove_retaining_records() {
+        let (machine, witness) = two_chunk_witness();
+        let mut records = Vec::new();
+        let (proof, cycles) =
+            machine.prove_retaining_records(&witness, None, &mut |_| {}, Some(&mut records));
+        assert!(machine.verify(&proof, witness.vk()).is_ok());
+
+        // One record per chunk proof, in chunk order, covering every cycle.
+        assert_eq!(records.len(), proof.proofs().len());
+        assert!(records
+            .iter()
+            .enumerate()
+            .all(|(i, record)| record.public_values.chunk == i as u32 + 1));
+        let num_cpu_events = records
+            .iter()
+            .map(|record| record.cpu_events.len() as u64)
+            .sum::<u64>();
+        assert_eq!(num_cpu_events, cycles);
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
         witness::ProvingWitness,
     },
 };
-use anyhow::Result;
+use anyhow::{bail, Result};
 use p3_air::Air;
 use p3_field::PrimeField32;
 use std::any::type_name;
@@ -61,15 +61,10 @@ where
     where
         C: for<'a> Air<VerifierConstraintFolder<'a, SC>>,
     {
-        // panic if proofs is empty
-        if proof.proofs().is_empty() {
-            panic!("proofs is empty");
-        }
-
-        assert_eq!(proof.vks().len(), 1);
-
-        self.base_machine
-            .verify_ensemble(&(proof.vks()[0]), &proof.proofs())?;
+        let [vk] = proof.vks() else {
+            bail!("expected a single vk, got {}", proof.vks().len());
+        };
+        self.base_machine.verify_ensemble(vk, &proof.proofs())?;
 
         Ok(())
     }
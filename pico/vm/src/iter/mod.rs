@@ -10,3 +10,6 @@ pub use rayon::*;
 mod single;
 #[cfg(not(feature = "rayon"))]
 pub use single::*;
+
+mod prefix_sum;
+pub use prefix_sum::{pico_prefix_sum, PrefixSumField};
@@ -0,0 +1,190 @@
+//! Prefix sums over 31-bit prime fields.
+//!
+//! The input is split into one chunk per thread. Each chunk is scanned on its own, the chunk
+//! totals are scanned sequentially, and each chunk is then shifted by the total of the chunks
+//! before it. With the `avx2` feature both per-chunk passes process 8 elements at a time.
+
+use super::{current_num_threads, IndexedPicoIterator, PicoIterator, PicoSliceMut};
+use p3_baby_bear::BabyBear;
+use p3_field::{Field, FieldAlgebra, PrimeField32};
+use p3_koala_bear::KoalaBear;
+
+/// Chunks shorter than this are not worth handing to another thread.
+const MIN_CHUNK_LEN: usize = 1 << 14;
+
+/// A 31-bit prime field whose elements are a single `u32` below `ORDER_U32`, on which field
+/// addition is integer addition modulo `ORDER_U32`.
+///
+/// # Safety
+///
+/// The type must be `repr(transparent)` over `u32` and keep its value reduced, as the
+/// Montgomery fields do. The vectorized prefix sum reads and writes the raw values directly.
+pub unsafe trait PrefixSumField: PrimeField32 {}
+
+unsafe impl PrefixSumField for BabyBear {}
+unsafe impl PrefixSumField for KoalaBear {}
+
+/// Returns the inclusive prefix sums of `input`, i.e. `output[i] = input[0] + ... + input[i]`.
+pub fn pico_prefix_sum<F: PrefixSumField>(input: &[F]) -> Vec<F> {
+    let mut output = input.to_vec();
+    if output.is_empty() {
+        return output;
+    }
+
+    let chunk_size = output
+        .len()
+        .div_ceil(current_num_threads())
+        .max(MIN_CHUNK_LEN);
+
+    output
+        .pico_chunks_mut(chunk_size)
+        .for_each(prefix_sum_in_place);
+
+    let offsets = output
+        .chunks(chunk_size)
+        .scan(F::ZERO, |total, chunk| {
+            let offset = *total;
+            *total += *chunk.last().unwrap();
+            Some(offset)
+        })
+        .collect::<Vec<_>>();
+
+    output
+        .pico_chunks_mut(chunk_size)
+        .zip(offsets)
+        .for_each(|(chunk, offset)| {
+            if !offset.is_zero() {
+                add_in_place(chunk, offset)
+            }
+        });
+
+    output
+}
+
+fn prefix_sum_in_place<F: PrefixSumField>(values: &mut [F]) {
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: `PrefixSumField` guarantees the layout and the reduced values.
+        unsafe {
+            let raw =
+                core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u32, values.len());
+            return avx2::prefix_sum_in_place(raw, F::ORDER_U32);
+        }
+    }
+
+    let mut total = F::ZERO;
+    for value in values {
+        total += *value;
+        *value = total;
+    }
+}
+
+fn add_in_place<F: PrefixSumField>(values: &mut [F], offset: F) {
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: `PrefixSumField` guarantees the layout and the reduced values.
+        unsafe {
+            let raw =
+                core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u32, values.len());
+            let offset = *(&offset as *const F as *const u32);
+            return avx2::add_in_place(raw, offset, F::ORDER_U32);
+        }
+    }
+
+    values.iter_mut().for_each(|value| *value += offset);
+}
+
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+mod avx2 {
+    use core::arch::x86_64::*;
+
+    const WIDTH: usize = 8;
+
+    /// Lane-wise `(a + b) mod p` for `a, b < p < 2^31`. If the sum wraps past `p`, subtracting
+    /// `p` gives the smaller unsigned value, otherwise the subtraction underflows.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn add_mod(a: __m256i, b: __m256i, p: __m256i) -> __m256i {
+        let sum = _mm256_add_epi32(a, b);
+        _mm256_min_epu32(sum, _mm256_sub_epi32(sum, p))
+    }
+
+    /// Inclusive prefix sum of the 8 lanes of `x`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn scan_lanes(x: __m256i, p: __m256i) -> __m256i {
+        // Scan each 128-bit half: the byte shifts do not cross halves.
+        let x = add_mod(x, _mm256_slli_si256::<4>(x), p);
+        let x = add_mod(x, _mm256_slli_si256::<8>(x), p);
+        // Carry the total of the low half (lane 3) into the high half.
+        let low_total = _mm256_permutevar8x32_epi32(x, _mm256_set1_epi32(3));
+        let carry = _mm256_blend_epi32::<0b1111_0000>(_mm256_setzero_si256(), low_total);
+        add_mod(x, carry, p)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn prefix_sum_in_place(values: &mut [u32], order: u32) {
+        let p = _mm256_set1_epi32(order as i32);
+        let last_lane = _mm256_set1_epi32(WIDTH as i32 - 1);
+
+        let mut total = _mm256_setzero_si256();
+        let mut blocks = values.chunks_exact_mut(WIDTH);
+        for block in &mut blocks {
+            let ptr = block.as_mut_ptr() as *mut __m256i;
+            let x = add_mod(scan_lanes(_mm256_loadu_si256(ptr), p), total, p);
+            _mm256_storeu_si256(ptr, x);
+            total = _mm256_permutevar8x32_epi32(x, last_lane);
+        }
+
+        let mut total = _mm256_cvtsi256_si32(total) as u32;
+        for value in blocks.into_remainder() {
+            total = add_scalar(total, *value, order);
+            *value = total;
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn add_in_place(values: &mut [u32], offset: u32, order: u32) {
+        let p = _mm256_set1_epi32(order as i32);
+        let offset_lanes = _mm256_set1_epi32(offset as i32);
+
+        let mut blocks = values.chunks_exact_mut(WIDTH);
+        for block in &mut blocks {
+            let ptr = block.as_mut_ptr() as *mut __m256i;
+            _mm256_storeu_si256(ptr, add_mod(_mm256_loadu_si256(ptr), offset_lanes, p));
+        }
+        for value in blocks.into_remainder() {
+            *value = add_scalar(*value, offset, order);
+        }
+    }
+
+    #[inline]
+    fn add_scalar(a: u32, b: u32, order: u32) -> u32 {
+        let sum = a + b;
+        sum.min(sum.wrapping_sub(order))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_prefix_sum_matches_scalar() {
+        let mut rng = thread_rng();
+        for len in [0, 1, 7, 8, 9, 63, 1000, 3 * MIN_CHUNK_LEN + 5] {
+            let input = (0..len)
+                .map(|_| BabyBear::from_canonical_u32(rng.gen_range(0..BabyBear::ORDER_U32)))
+                .collect::<Vec<_>>();
+            let expected = input
+                .iter()
+                .scan(BabyBear::ZERO, |total, &x| {
+                    *total += x;
+                    Some(*total)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(pico_prefix_sum(&input), expected, "length {len}");
+        }
+    }
+}
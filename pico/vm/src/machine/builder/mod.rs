@@ -1,7 +1,7 @@
 //! This module includes the functions of the main chip builder we used in the VM.
 
-use crate::machine::lookup::SymbolicLookup;
-use p3_air::{AirBuilder, FilteredAirBuilder};
+use crate::machine::{chip::ChipBehavior, lookup::SymbolicLookup};
+use p3_air::{Air, AirBuilder, FilteredAirBuilder};
 use p3_field::Field;
 
 mod base;
@@ -37,6 +37,18 @@ pub trait ChipBuilder<F: Field>:
     /// get preprocessed trace
     /// Originally from PaiBuilder in p3
     fn preprocessed(&self) -> Self::M;
+
+    /// Evaluates the constraints of `chip` on its columns of this builder's traces. The chip's
+    /// main and preprocessed columns start at `main_col_start` and `preprocessed_col_start`.
+    fn sub_air<C>(&mut self, chip: &C, main_col_start: usize, preprocessed_col_start: usize)
+    where
+        Self: Sized,
+        C: ChipBehavior<F> + for<'a> Air<SubAirBuilder<'a, Self, C, F>>,
+    {
+        let mut builder = SubAirBuilder::<Self, C, F>::for_chip(self, chip)
+            .offset(main_col_start, preprocessed_col_start);
+        chip.eval(&mut builder);
+    }
 }
 
 // aggregation of chip-related builders
@@ -3,7 +3,10 @@ use std::{
     ops::{Deref, Range},
 };
 
+use super::{ChipBuilder, LookupBuilder, PublicValuesBuilder};
+use crate::machine::{chip::ChipBehavior, lookup::SymbolicLookup};
 use p3_air::{AirBuilder, BaseAir};
+use p3_field::Field;
 use p3_matrix::Matrix;
 
 /// A submatrix of a matrix.  The matrix will contain a subset of the columns of `self.inner`.
@@ -59,22 +62,54 @@ impl<M: Matrix<T>, T: Send + Sync> Matrix<T> for SubMatrixRowSlices<M, T> {
 /// A builder used to eval a sub-air.  This will handle enforcing constraints for a subset of a
 /// trace matrix.  E.g. if a particular air needs to be enforced for a subset of the columns of
 /// the trace, then the [`SubAirBuilder`] can be used.
+///
+/// When the outer builder is a [`ChipBuilder`], so is the [`SubAirBuilder`]: lookups and public
+/// values go to the outer builder, so a chip can be evaluated as part of a larger chip.
 pub struct SubAirBuilder<'a, AB: AirBuilder, SubAir: BaseAir<T>, T> {
     inner: &'a mut AB,
     column_range: Range<usize>,
+    preprocessed_range: Range<usize>,
     _phantom: std::marker::PhantomData<(SubAir, T)>,
 }
 
 impl<'a, AB: AirBuilder, SubAir: BaseAir<T>, T> SubAirBuilder<'a, AB, SubAir, T> {
-    /// Creates a new [`SubAirBuilder`].
+    /// Creates a new [`SubAirBuilder`] over the main columns in `column_range`, without
+    /// preprocessed columns.
     #[must_use]
     pub fn new(inner: &'a mut AB, column_range: Range<usize>) -> Self {
         Self {
             inner,
             column_range,
+            preprocessed_range: 0..0,
             _phantom: std::marker::PhantomData,
         }
     }
+
+    /// Creates a new [`SubAirBuilder`] for `chip`, whose main and preprocessed columns are the
+    /// first columns of the outer traces.
+    #[must_use]
+    pub fn for_chip(inner: &'a mut AB, chip: &SubAir) -> Self
+    where
+        SubAir: ChipBehavior<T>,
+        T: Field,
+    {
+        Self {
+            inner,
+            column_range: 0..chip.width(),
+            preprocessed_range: 0..chip.preprocessed_width(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Moves the sub-air's main and preprocessed columns to start at the given columns of the
+    /// outer traces. Their widths are unchanged.
+    #[must_use]
+    pub fn offset(mut self, main_col_start: usize, preprocessed_col_start: usize) -> Self {
+        self.column_range = main_col_start..main_col_start + self.column_range.len();
+        self.preprocessed_range =
+            preprocessed_col_start..preprocessed_col_start + self.preprocessed_range.len();
+        self
+    }
 }
 
 /// Implement `AirBuilder` for `SubAirBuilder`.
@@ -106,3 +141,37 @@ impl<AB: AirBuilder, SubAir: BaseAir<F>, F> AirBuilder for SubAirBuilder<'_, AB,
         self.inner.assert_zero(x.into());
     }
 }
+
+impl<AB, SubAir: BaseAir<T>, T> LookupBuilder<SymbolicLookup<AB::Expr>>
+    for SubAirBuilder<'_, AB, SubAir, T>
+where
+    AB: AirBuilder + LookupBuilder<SymbolicLookup<AB::Expr>>,
+{
+    fn looking(&mut self, message: SymbolicLookup<AB::Expr>) {
+        self.inner.looking(message);
+    }
+
+    fn looked(&mut self, message: SymbolicLookup<AB::Expr>) {
+        self.inner.looked(message);
+    }
+}
+
+impl<AB: PublicValuesBuilder, SubAir: BaseAir<T>, T> PublicValuesBuilder
+    for SubAirBuilder<'_, AB, SubAir, T>
+{
+    type PublicVar = AB::PublicVar;
+
+    fn public_values(&self) -> &[Self::PublicVar] {
+        self.inner.public_values()
+    }
+}
+
+impl<F: Field, AB: ChipBuilder<F>, SubAir: BaseAir<T>, T> ChipBuilder<F>
+    for SubAirBuilder<'_, AB, SubAir, T>
+{
+    fn preprocessed(&self) -> Self::M {
+        let matrix = self.inner.preprocessed();
+
+        SubMatrixRowSlices::new(matrix, self.preprocessed_range.clone())
+    }
+}
@@ -40,6 +40,7 @@ pub struct IncrementalConstraintDebugger<'a, SC: StarkGenericConfig> {
     challenges: [SC::Challenge; 2],
     messages: Vec<(DebuggerMessageLevel, String)>,
     failures: HashMap<String, usize>,
+    first_failure: Option<(String, usize)>,
 }
 
 impl<'a, SC: StarkGenericConfig> IncrementalConstraintDebugger<'a, SC> {
@@ -64,9 +65,17 @@ impl<'a, SC: StarkGenericConfig> IncrementalConstraintDebugger<'a, SC> {
             challenges,
             messages,
             failures,
+            first_failure: None,
         }
     }
 
+    /// The name of the chip and the row of the first constraint failure found so far.
+    pub fn first_failure(&self) -> Option<(&str, usize)> {
+        self.first_failure
+            .as_ref()
+            .map(|(chip, row)| (chip.as_str(), *row))
+    }
+
     pub fn print_results(self) -> bool {
         let mut success = true;
 
@@ -307,6 +316,7 @@ impl<'a, SC: StarkGenericConfig> IncrementalConstraintDebugger<'a, SC> {
             // update the number of failures remaining
             if !builder.failures.is_empty() {
                 *max_failures -= 1;
+                self.first_failure.get_or_insert_with(|| (chip.name(), i));
                 self.messages.push((
                     DebuggerMessageLevel::Error,
                     format!(
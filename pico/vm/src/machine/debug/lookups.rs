@@ -144,14 +144,71 @@ where
     }
 }
 
-#[allow(clippy::type_complexity)]
+/// A row that took part in a lookup. `record` counts the records in the order they are debugged.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct InteractionSite {
+    chip_name: String,
+    record: usize,
+    row: usize,
+}
+
+/// The running balance of one lookup key.
+#[derive(Clone, Debug)]
+struct KeyBalance<F> {
+    total: F,
+    per_chip: BTreeMap<String, F>,
+    first_looking: Option<InteractionSite>,
+    first_looked: Option<InteractionSite>,
+}
+
+impl<F: Field> Default for KeyBalance<F> {
+    fn default() -> Self {
+        Self {
+            total: F::ZERO,
+            per_chip: BTreeMap::new(),
+            first_looking: None,
+            first_looked: None,
+        }
+    }
+}
+
+/// A lookup key left with a non-zero balance, together with the first interaction on the side
+/// that outweighs the other.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImbalancedInteraction {
+    pub chip_name: String,
+    /// The index of the record among all the records given to the debugger.
+    pub record: usize,
+    pub row: usize,
+    pub kind: LookupType,
+    pub is_looking: bool,
+    pub key: Vec<String>,
+    /// Positive when there are more lookings than lookeds.
+    pub net_multiplicity: i64,
+}
+
+/// The outcome of [`IncrementalLookupDebugger::report`].
+#[derive(Clone, Debug, Default)]
+pub struct LookupDebugReport {
+    pub imbalanced_interactions: Vec<ImbalancedInteraction>,
+    /// The sum of the balances of all keys.
+    pub total_imbalance: i64,
+}
+
+impl LookupDebugReport {
+    pub fn is_balanced(&self) -> bool {
+        self.imbalanced_interactions.is_empty()
+    }
+}
+
 pub struct IncrementalLookupDebugger<'a, SC: StarkGenericConfig> {
     pk: &'a BaseProvingKey<SC>,
     scope: LookupScope,
     types: Option<&'a [LookupType]>,
-    lookups: BTreeMap<DebugLookupKey<SC::Val>, (SC::Val, BTreeMap<String, SC::Val>)>,
+    lookups: BTreeMap<DebugLookupKey<SC::Val>, KeyBalance<SC::Val>>,
     messages: Vec<(DebuggerMessageLevel, String)>,
     total: SC::Val,
+    num_records: usize,
 }
 
 impl<'a, SC: StarkGenericConfig> IncrementalLookupDebugger<'a, SC> {
@@ -171,6 +228,46 @@ impl<'a, SC: StarkGenericConfig> IncrementalLookupDebugger<'a, SC> {
             lookups,
             messages,
             total,
+            num_records: 0,
+        }
+    }
+
+    /// Lists the lookup keys that are not balanced so far.
+    pub fn report(&self) -> LookupDebugReport
+    where
+        SC::Val: PrimeField64,
+    {
+        let imbalanced_interactions = self
+            .lookups
+            .iter()
+            .filter(|(_, balance)| !balance.total.is_zero())
+            .map(|(key, balance)| {
+                let net_multiplicity = field_to_int(balance.total) as i64;
+                // Blame the heavier side, unless it has no interactions at all, which happens
+                // when a multiplicity is negative.
+                let looking = balance.first_looking.as_ref().map(|site| (site, true));
+                let looked = balance.first_looked.as_ref().map(|site| (site, false));
+                let (site, is_looking) = if net_multiplicity > 0 {
+                    looking.or(looked)
+                } else {
+                    looked.or(looking)
+                }
+                .unwrap();
+                ImbalancedInteraction {
+                    chip_name: site.chip_name.clone(),
+                    record: site.record,
+                    row: site.row,
+                    kind: key.kind,
+                    is_looking,
+                    key: key.values.iter().map(ToString::to_string).collect(),
+                    net_multiplicity,
+                }
+            })
+            .collect();
+
+        LookupDebugReport {
+            imbalanced_interactions,
+            total_imbalance: field_to_int(self.total) as i64,
         }
     }
 
@@ -179,6 +276,7 @@ impl<'a, SC: StarkGenericConfig> IncrementalLookupDebugger<'a, SC> {
         SC::Val: PrimeField64,
     {
         let mut success = true;
+        let report = self.report();
 
         info!("\n******** {} Lookups Debugging START ********", self.scope);
 
@@ -195,15 +293,33 @@ impl<'a, SC: StarkGenericConfig> IncrementalLookupDebugger<'a, SC> {
 
         info!("Checking for imbalance");
         // checks the imbalance per lookup key
-        for (k, (v, cv)) in self.lookups {
-            if !v.is_zero() {
-                info!("lookup imbalance of {} for {}", field_to_int(v), k);
+        let mut imbalanced = report.imbalanced_interactions.iter();
+        for (k, balance) in self.lookups {
+            if !balance.total.is_zero() {
+                info!(
+                    "lookup imbalance of {} for {}",
+                    field_to_int(balance.total),
+                    k
+                );
                 success = false;
 
                 // print the detailed per-chip balancing data
-                for (c, cv) in cv {
+                for (c, cv) in balance.per_chip {
                     info!("  {} balance: {}", c, field_to_int(cv));
                 }
+
+                let interaction = imbalanced.next().unwrap();
+                info!(
+                    "  first {} at {} row {} of record {}",
+                    if interaction.is_looking {
+                        "looking"
+                    } else {
+                        "looked"
+                    },
+                    interaction.chip_name,
+                    interaction.row,
+                    interaction.record,
+                );
             }
         }
 
@@ -236,25 +352,39 @@ impl<'a, SC: StarkGenericConfig> IncrementalLookupDebugger<'a, SC> {
             );
         }
 
-        // this stores (total balance, chip => local balance) per lookup key
+        // this stores the total balance, the balance per chip and the first interaction on each
+        // side per lookup key
         for chip in chips {
             let mut chip_events = 0;
-            for chunk in chunks {
+            for (i, chunk) in chunks.iter().enumerate() {
                 let data = DebugLookup::debug_lookups(self.pk, chip, chunk, self.scope, self.types)
                     .lookup_data;
                 chip_events += data.len();
 
                 // this loop consumes counts and thus the lookup key which allows us to use Box
                 // rather than Rc
-                for (k, (_, v)) in data {
+                for (k, (interactions, v)) in data {
                     self.total += v;
 
                     let entry = self.lookups.entry(k).or_default();
 
                     // total balance
-                    entry.0 += v;
+                    entry.total += v;
                     // keyed balance
-                    *entry.1.entry(chip.name()).or_default() += v;
+                    *entry.per_chip.entry(chip.name()).or_default() += v;
+
+                    for interaction in interactions.iter().filter(|d| !d.mult.is_zero()) {
+                        let first = if interaction.is_looking {
+                            &mut entry.first_looking
+                        } else {
+                            &mut entry.first_looked
+                        };
+                        first.get_or_insert_with(|| InteractionSite {
+                            chip_name: interaction.chip_name.clone(),
+                            record: self.num_records + i,
+                            row: interaction.row,
+                        });
+                    }
                 }
             }
 
@@ -263,6 +393,7 @@ impl<'a, SC: StarkGenericConfig> IncrementalLookupDebugger<'a, SC> {
                 format!("chip {} experienced {} events", chip.name(), chip_events),
             ));
         }
+        self.num_records += chunks.len();
     }
 }
 
@@ -279,3 +410,66 @@ fn field_to_int<F: PrimeField64>(x: F) -> i32 {
         val as i32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::IncrementalLookupDebugger;
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::{opts::EmulatorOpts, riscv::emulator::RiscvEmulator},
+        instances::{
+            chiptype::riscv_chiptype::RiscvChipType,
+            configs::riscv_bb_poseidon2::StarkConfig as RiscvBBSC, machine::riscv::RiscvMachine,
+        },
+        machine::{
+            lookup::{LookupScope, LookupType},
+            machine::MachineBehavior,
+        },
+        primitives::consts::RISCV_NUM_PVS,
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_report_imbalanced_interactions() {
+        let instructions = vec![Instruction::new(Opcode::ADD, 5, 0, 7, false, true)];
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let machine =
+            RiscvMachine::new(RiscvBBSC::new(), RiscvChipType::all_chips(), RISCV_NUM_PVS);
+        let (pk, _) = machine.setup_keys(&program);
+        let chips = machine.chips();
+
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::test_opts());
+        let mut records = emulator.run(None).unwrap();
+        let record = &mut records[0];
+        RiscvMachine::complement_record_static(chips.clone(), record);
+
+        let types = [LookupType::Alu];
+        let mut debugger = IncrementalLookupDebugger::new(&pk, LookupScope::Regional, Some(&types));
+        debugger.debug_incremental(&chips, std::slice::from_ref(record));
+        assert!(debugger.report().is_balanced());
+
+        // The AddSub chip recomputes the sum, so it now answers a lookup of 8 = 0 + 8 that the CPU
+        // never made, while the CPU lookup of 7 = 0 + 7 goes unanswered.
+        record.add_events[0].c += 1;
+        let mut debugger = IncrementalLookupDebugger::new(&pk, LookupScope::Regional, Some(&types));
+        debugger.debug_incremental(&chips, std::slice::from_ref(record));
+        let report = debugger.report();
+        let mut interactions = report
+            .imbalanced_interactions
+            .iter()
+            .map(|interaction| {
+                assert_eq!(interaction.kind, LookupType::Alu);
+                assert_eq!((interaction.record, interaction.row), (0, 0));
+                (
+                    interaction.chip_name.as_str(),
+                    interaction.is_looking,
+                    interaction.net_multiplicity,
+                )
+            })
+            .collect::<Vec<_>>();
+        interactions.sort();
+        assert_eq!(interactions, [("AddSub", false, -1), ("Cpu", true, 1)]);
+        assert_eq!(report.total_imbalance, 0);
+    }
+}
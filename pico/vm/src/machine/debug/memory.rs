@@ -0,0 +1,157 @@
+use crate::{compiler::riscv::program::Program, emulator::riscv::record::EmulationRecord};
+use log::{error, info};
+use std::collections::BTreeMap;
+
+/// A message of the global memory argument, ordered by address first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MemoryMessage {
+    pub addr: u32,
+    pub chunk: u32,
+    pub timestamp: u32,
+    pub value: u32,
+}
+
+/// Checks the global memory argument of the riscv machine on the emulation records, before any
+/// trace is generated.
+///
+/// The argument is a multiset equality across all chunks: the program memory image and the memory
+/// initialize events send `(addr, 0, 0, value)`, each chunk receives the first access of an
+/// address in the chunk and sends back its last one, and the memory finalize events receive the
+/// final values. The proofs only check that the septic curve sums of these messages cancel out,
+/// which fails with a single nonzero sum. This debugger keeps the multiplicity of every message,
+/// so a failure names the unmatched accesses.
+pub struct IncrementalMemoryArgumentDebugger {
+    balances: BTreeMap<MemoryMessage, i64>,
+}
+
+impl IncrementalMemoryArgumentDebugger {
+    pub fn new(program: &Program) -> Self {
+        let mut debugger = Self {
+            balances: BTreeMap::new(),
+        };
+        for (&addr, &value) in program.memory_image.iter() {
+            debugger.send(addr, 0, 0, value);
+        }
+        debugger
+    }
+
+    pub fn debug_incremental(&mut self, records: &[EmulationRecord]) {
+        for record in records {
+            for event in &record.memory_initialize_events {
+                self.send(event.addr, 0, 0, event.value);
+            }
+            for event in &record.memory_finalize_events {
+                self.receive(event.addr, event.chunk, event.timestamp, event.value);
+            }
+            for event in record.get_local_mem_events() {
+                let initial = event.initial_mem_access;
+                let last = event.final_mem_access;
+                self.receive(event.addr, initial.chunk, initial.timestamp, initial.value);
+                self.send(event.addr, last.chunk, last.timestamp, last.value);
+            }
+        }
+    }
+
+    /// The messages sent more often than received, with a positive balance, or received more
+    /// often than sent, with a negative one.
+    pub fn unbalanced(&self) -> impl Iterator<Item = (&MemoryMessage, i64)> {
+        self.balances
+            .iter()
+            .filter(|(_, &balance)| balance != 0)
+            .map(|(message, &balance)| (message, balance))
+    }
+
+    pub fn print_results(self) -> bool {
+        info!("\n******** MemoryArgument Debugging START ********");
+
+        let mut success = true;
+        for (message, balance) in self.unbalanced() {
+            success = false;
+            error!(
+                "MemoryArgument: {} of {:#010x} = {:#010x} at chunk {}, timestamp {} is {}",
+                balance.abs(),
+                message.addr,
+                message.value,
+                message.chunk,
+                message.timestamp,
+                if balance > 0 {
+                    "sent but never received"
+                } else {
+                    "received but never sent"
+                },
+            );
+        }
+
+        if success {
+            info!("MemoryArgument is balanced");
+        } else {
+            error!("MemoryArgument failed!");
+        }
+
+        info!("\n******** MemoryArgument Debugging END ********");
+
+        success
+    }
+
+    fn send(&mut self, addr: u32, chunk: u32, timestamp: u32, value: u32) {
+        self.update(addr, chunk, timestamp, value, 1);
+    }
+
+    fn receive(&mut self, addr: u32, chunk: u32, timestamp: u32, value: u32) {
+        self.update(addr, chunk, timestamp, value, -1);
+    }
+
+    fn update(&mut self, addr: u32, chunk: u32, timestamp: u32, value: u32, delta: i64) {
+        let message = MemoryMessage {
+            addr,
+            chunk,
+            timestamp,
+            value,
+        };
+        *self.balances.entry(message).or_default() += delta;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IncrementalMemoryArgumentDebugger;
+    use crate::{
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        emulator::{opts::EmulatorOpts, riscv::emulator::RiscvEmulator},
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_memory_argument_debugger() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, 7, false, true),
+            Instruction::new(Opcode::SW, 5, 0, 0x1000, false, true),
+            Instruction::new(Opcode::LW, 6, 0, 0x1000, false, true),
+        ];
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let mut emulator =
+            RiscvEmulator::new::<BabyBear>(program.clone(), EmulatorOpts::test_opts());
+        let mut records = emulator.run(None).unwrap();
+
+        let mut debugger = IncrementalMemoryArgumentDebugger::new(&program);
+        debugger.debug_incremental(&records);
+        assert_eq!(debugger.unbalanced().count(), 0);
+
+        // Tamper with the value a chunk reports as the last one written to 0x1000.
+        let event = records
+            .iter_mut()
+            .flat_map(|record| record.cpu_local_memory_access.iter_mut())
+            .find(|event| event.addr == 0x1000)
+            .unwrap();
+        event.final_mem_access.value += 1;
+
+        let mut debugger = IncrementalMemoryArgumentDebugger::new(&program);
+        debugger.debug_incremental(&records);
+        let unbalanced = debugger
+            .unbalanced()
+            .map(|(message, balance)| (message.addr, message.value, balance))
+            .collect::<Vec<_>>();
+        assert_eq!(unbalanced, vec![(0x1000, 7, -1), (0x1000, 8, 1)]);
+    }
+}
@@ -1,8 +1,10 @@
 pub mod constraints;
 pub mod lookups;
+pub mod memory;
 
 pub use constraints::IncrementalConstraintDebugger;
-pub use lookups::IncrementalLookupDebugger;
+pub use lookups::{ImbalancedInteraction, IncrementalLookupDebugger, LookupDebugReport};
+pub use memory::IncrementalMemoryArgumentDebugger;
 
 use super::{
     chip::{ChipBehavior, MetaChip},
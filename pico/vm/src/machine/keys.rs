@@ -6,17 +6,20 @@ use crate::{
     },
 };
 use alloc::sync::Arc;
+use anyhow::{anyhow, bail, Result};
+use core::fmt;
 use hashbrown::HashMap;
 use p3_baby_bear::BabyBear;
 use p3_challenger::CanObserve;
 use p3_circle::CircleDomain;
 use p3_commit::{Pcs, PolynomialSpace, TwoAdicMultiplicativeCoset};
-use p3_field::{FieldAlgebra, TwoAdicField};
+use p3_field::{FieldAlgebra, PrimeField32, TwoAdicField};
 use p3_koala_bear::KoalaBear;
 use p3_matrix::{dense::RowMajorMatrix, Dimensions};
 use p3_mersenne_31::Mersenne31;
 use p3_symmetric::CryptographicHasher;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 pub struct BaseProvingKey<SC: StarkGenericConfig> {
     /// The commitment to the named traces.
@@ -94,6 +97,124 @@ impl<SC: StarkGenericConfig> BaseVerifyingKey<SC> {
     }
 }
 
+/// The first bytes of a serialized [`BaseVerifyingKey`].
+const VK_MAGIC: [u8; 4] = *b"PVK\0";
+
+/// The version of the [`BaseVerifyingKey`] serialization, bumped on any change of its layout.
+pub const VK_SERIALIZATION_VERSION: u32 = 1;
+
+/// The layout of version 1 of the [`BaseVerifyingKey`] serialization.
+#[derive(Serialize, Deserialize)]
+struct VerifyingKeyV1<C, V, D> {
+    commit: C,
+    pc_start: V,
+    preprocessed_info: Vec<(String, D, Dimensions)>,
+    /// Sorted by index, so that the encoding does not depend on the hash map iteration order.
+    preprocessed_chip_ordering: Vec<(String, usize)>,
+    initial_global_cumulative_sum: SepticDigest<V>,
+}
+
+impl<SC: StarkGenericConfig> BaseVerifyingKey<SC>
+where
+    Dom<SC>: Serialize + DeserializeOwned,
+{
+    /// Serializes the key in a stable format, for distributing it to verifiers that do not build
+    /// the program.
+    ///
+    /// The format is the 4 bytes `PVK\0`, the [`VK_SERIALIZATION_VERSION`] as a little endian
+    /// `u32`, then the bincode encoding of the commitment, the start pc, the preprocessed chip
+    /// information, the chip ordering sorted by index and the initial global cumulative sum.
+    /// Equal keys always serialize to equal bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut preprocessed_chip_ordering = self
+            .preprocessed_chip_ordering
+            .iter()
+            .map(|(name, &index)| (name.clone(), index))
+            .collect::<Vec<_>>();
+        preprocessed_chip_ordering.sort_by_key(|&(_, index)| index);
+        let vk = VerifyingKeyV1 {
+            commit: self.commit.clone(),
+            pc_start: self.pc_start,
+            preprocessed_info: self.preprocessed_info.to_vec(),
+            preprocessed_chip_ordering,
+            initial_global_cumulative_sum: self.initial_global_cumulative_sum,
+        };
+
+        let mut bytes = VK_MAGIC.to_vec();
+        bytes.extend_from_slice(&VK_SERIALIZATION_VERSION.to_le_bytes());
+        bincode::serialize_into(&mut bytes, &vk).expect("verifying key serialization failed");
+        bytes
+    }
+
+    /// Deserializes a key serialized with [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (magic, rest) = bytes
+            .split_at_checked(VK_MAGIC.len())
+            .ok_or_else(|| anyhow!("verifying key bytes too short"))?;
+        if magic != VK_MAGIC {
+            bail!("not a serialized verifying key");
+        }
+        let (version, payload) = rest
+            .split_at_checked(4)
+            .ok_or_else(|| anyhow!("verifying key bytes too short"))?;
+        let version = u32::from_le_bytes(version.try_into().unwrap());
+        if version != VK_SERIALIZATION_VERSION {
+            bail!(
+                "unsupported verifying key version {}, expected {}",
+                version,
+                VK_SERIALIZATION_VERSION
+            );
+        }
+
+        let vk: VerifyingKeyV1<Com<SC>, SC::Val, Dom<SC>> = bincode::deserialize(payload)?;
+        Ok(Self {
+            commit: vk.commit,
+            pc_start: vk.pc_start,
+            preprocessed_info: vk.preprocessed_info.into(),
+            preprocessed_chip_ordering: Arc::new(
+                vk.preprocessed_chip_ordering.into_iter().collect(),
+            ),
+            initial_global_cumulative_sum: vk.initial_global_cumulative_sum,
+        })
+    }
+}
+
+impl<SC: StarkGenericConfig> BaseVerifyingKey<SC>
+where
+    Self: HashableKey<Val<SC>>,
+    Val<SC>: PrimeField32,
+{
+    /// A short identifier of the key, for logs and CLI output. See [`vk_digest_fingerprint`].
+    pub fn fingerprint(&self) -> [u8; 8] {
+        vk_digest_fingerprint(&self.hash_field())
+    }
+}
+
+impl<SC: StarkGenericConfig> fmt::Display for BaseVerifyingKey<SC>
+where
+    Self: HashableKey<Val<SC>>,
+    Val<SC>: PrimeField32,
+{
+    /// Prints the fingerprint as `pico-vk:` followed by 16 uppercase hex digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pico-vk:")?;
+        for byte in self.fingerprint() {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// The first 8 bytes of the SHA-256 of a vk digest, with each element serialized as a
+/// little-endian `u32`.
+pub fn vk_digest_fingerprint<F: PrimeField32>(digest: &[F; DIGEST_SIZE]) -> [u8; 8] {
+    let mut hasher = Sha256::new();
+    for value in digest {
+        hasher.update(value.as_canonical_u32().to_le_bytes());
+    }
+    hasher.finalize()[..8].try_into().unwrap()
+}
+
 /// A trait for keys that can be hashed into a digest.
 pub trait HashableKey<F> {
     /// Hash the key into a digest of BabyBear elements.
@@ -168,3 +289,59 @@ where
         POSEIDON2_M31_HASHER.hash_iter(inputs)
     }
 }
+
+/// A digest hashes to itself, so a digest can stand in for the key it was computed from.
+impl<F: Copy> HashableKey<F> for [F; DIGEST_SIZE] {
+    fn hash_field(&self) -> [F; DIGEST_SIZE] {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BaseVerifyingKey;
+    use crate::{
+        chips::chips::toys::toy::ToyChip,
+        compiler::riscv::{instruction::Instruction, opcode::Opcode, program::Program},
+        configs::config::StarkGenericConfig,
+        emulator::{opts::EmulatorOpts, riscv::emulator::RiscvEmulator},
+        instances::{
+            configs::riscv_bb_poseidon2::StarkConfig as RiscvBBSC, machine::simple::SimpleMachine,
+        },
+        machine::{chip::MetaChip, machine::MachineBehavior, witness::ProvingWitness},
+        primitives::consts::RISCV_NUM_PVS,
+    };
+    use alloc::sync::Arc;
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_vk_bytes_verify_proof() {
+        // The toy chip has a preprocessed trace of 2048 rows, which the add events must fill.
+        let instructions = vec![Instruction::new(Opcode::ADD, 5, 5, 1, false, true); 1500];
+        let program = Arc::new(Program::new(instructions, 0, 0));
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program.clone(), EmulatorOpts::default());
+        let mut records = emulator.run(None).unwrap();
+
+        let machine = SimpleMachine::new(
+            RiscvBBSC::new(),
+            vec![MetaChip::new(ToyChip::default())],
+            RISCV_NUM_PVS,
+        );
+        let (pk, vk) = machine.setup_keys(&program);
+        machine.complement_record(&mut records);
+        let witness = ProvingWitness::setup_with_keys_and_records(pk, vk.clone(), records);
+        let proof = machine.prove(&witness);
+
+        let bytes = vk.to_bytes();
+        let restored = BaseVerifyingKey::<RiscvBBSC>::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.to_bytes(), bytes);
+        machine
+            .base_machine()
+            .verify_ensemble(&restored, &proof.proofs())
+            .unwrap();
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] += 1;
+        assert!(BaseVerifyingKey::<RiscvBBSC>::from_bytes(&wrong_version).is_err());
+    }
+}
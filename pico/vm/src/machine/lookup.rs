@@ -65,6 +65,9 @@ pub enum LookupType {
 
     /// Interaction with the global table.
     Global = 10,
+
+    /// Multiset equality between the input and output of a permutation, within a chip.
+    Permutation = 11,
 }
 
 #[derive(
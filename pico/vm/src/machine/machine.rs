@@ -1,7 +1,15 @@
-use super::{folder::DebugConstraintFolder, keys::HashableKey, lookup::LookupScope};
+use super::{
+    folder::DebugConstraintFolder,
+    keys::HashableKey,
+    lookup::{LookupScope, LookupType},
+};
 use crate::{
+    compiler::recursion::circuit::{
+        hash::FieldHasher,
+        merkle_tree::{MerkleProof, MerkleTree},
+    },
     configs::config::{StarkGenericConfig, Val},
-    emulator::record::RecordBehavior,
+    emulator::{record::RecordBehavior, riscv::syscalls::SyscallCode},
     machine::{
         chip::{ChipBehavior, MetaChip},
         folder::{ProverConstraintFolder, VerifierConstraintFolder},
@@ -12,15 +20,17 @@ use crate::{
         verifier::BaseVerifier,
         witness::ProvingWitness,
     },
+    primitives::consts::DIGEST_SIZE,
 };
 use alloc::sync::Arc;
-use anyhow::Result;
-use hashbrown::HashMap;
+use anyhow::{bail, Result};
+use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
-use p3_air::Air;
-use p3_field::{Field, PrimeField64};
+use p3_air::{Air, BaseAir};
+use p3_field::{Field, FieldAlgebra, PrimeField64};
 use p3_maybe_rayon::prelude::*;
 use std::time::Instant;
+use strum::IntoEnumIterator;
 use tracing::{debug, instrument};
 
 /// Functions that each machine instance should implement.
@@ -96,6 +106,44 @@ where
     fn verify(&self, proof: &MetaProof<SC>, riscv_vk: &dyn HashableKey<SC::Val>) -> Result<()>
     where
         C: for<'a> Air<VerifierConstraintFolder<'a, SC>>;
+
+    /// The digest of the riscv vk of the program that `proof` attests to. This is the digest of
+    /// the vk of the proof itself, unless the machine proves recursion programs.
+    fn riscv_vk_digest(&self, proof: &MetaProof<SC>) -> Result<[Val<SC>; DIGEST_SIZE]>
+    where
+        BaseVerifyingKey<SC>: HashableKey<Val<SC>>,
+    {
+        let [vk] = proof.vks() else {
+            bail!("expected a single vk, got {}", proof.vks().len());
+        };
+        Ok(vk.hash_field())
+    }
+
+    /// Verify a proof of a program from a committed set of allowed programs.
+    ///
+    /// `merkle_proof` opens the riscv vk digest of the proof against `merkle_root`, as returned by
+    /// `VkMerkleManager::open_vk`. Membership is checked first, so a proof of a program outside
+    /// the set is rejected without verifying it. The proof is then checked by `verify` against
+    /// the allowed digest.
+    fn verify_with_allowlist(
+        &self,
+        proof: &MetaProof<SC>,
+        merkle_root: &[Val<SC>; DIGEST_SIZE],
+        merkle_proof: &MerkleProof<Val<SC>, SC>,
+    ) -> Result<()>
+    where
+        C: for<'a> Air<VerifierConstraintFolder<'a, SC>>,
+        SC: FieldHasher<Val<SC>, Digest = [Val<SC>; DIGEST_SIZE]>,
+        BaseVerifyingKey<SC>: HashableKey<Val<SC>>,
+    {
+        let riscv_vk = self.riscv_vk_digest(proof)?;
+        if MerkleTree::<Val<SC>, SC>::verify(merkle_proof.clone(), riscv_vk, *merkle_root).is_err()
+        {
+            bail!("riscv vk {:?} is not in the allowlist", riscv_vk);
+        }
+
+        self.verify(proof, &riscv_vk)
+    }
 }
 
 /// A basic machine that includes elemental proving gadgets.
@@ -196,14 +244,140 @@ where
             .iter()
             .any(|chip| chip.lookup_scope() == LookupScope::Global);
 
-        Self {
+        let machine = Self {
             config: config.into(),
             chips,
             prover: BaseProver::new(),
             verifier: BaseVerifier::new(),
             num_public_values,
             has_global,
+        };
+
+        #[cfg(debug_assertions)]
+        if let Err(err) = machine.check_all_chips() {
+            tracing::warn!("{}", err);
+        }
+
+        machine
+    }
+
+    /// Checks that the chips of the machine are registered consistently:
+    /// - no two chips share a name, since chips are keyed by name in shapes and chip orderings;
+    /// - every lookup type and scope that some chip sends is received by some chip, and vice
+    ///   versa;
+    /// - if the machine handles syscalls, every syscall with its own table is received by a chip,
+    ///   except the unreleased precompiles without the `unreleased-precompiles` feature.
+    ///
+    /// The syscall ids received by a chip are read off its lookups by evaluating the id at rows
+    /// with at most one column set, which covers constant ids and ids selected by flag columns.
+    pub fn check_all_chips(&self) -> Result<()> {
+        let mut errors = Vec::new();
+
+        let mut names = HashSet::new();
+        for chip in self.chips.iter() {
+            if !names.insert(chip.name()) {
+                errors.push(format!("chip {} is registered more than once", chip.name()));
+            }
+        }
+
+        let looking = self
+            .chips
+            .iter()
+            .flat_map(|chip| {
+                chip.get_looking()
+                    .iter()
+                    .map(move |lookup| ((lookup.kind, lookup.scope), chip.name()))
+            })
+            .into_group_map();
+        let looked = self
+            .chips
+            .iter()
+            .flat_map(|chip| {
+                chip.get_looked()
+                    .iter()
+                    .map(move |lookup| ((lookup.kind, lookup.scope), chip.name()))
+            })
+            .into_group_map();
+        for (channel, chips) in looking.iter().sorted_by_key(|(channel, _)| **channel) {
+            if !looked.contains_key(channel) {
+                errors.push(format!(
+                    "{:?} lookups sent by {} are received by no chip",
+                    channel,
+                    chips.iter().unique().join(", ")
+                ));
+            }
+        }
+        for (channel, chips) in looked.iter().sorted_by_key(|(channel, _)| **channel) {
+            if !looking.contains_key(channel) {
+                errors.push(format!(
+                    "{:?} lookups received by {} are sent by no chip",
+                    channel,
+                    chips.iter().unique().join(", ")
+                ));
+            }
+        }
+
+        let syscall_chips = self.syscall_ids_by_chip();
+        if !syscall_chips.is_empty() {
+            // The chips of unreleased precompiles are only registered with their feature.
+            let unreleased = cfg!(feature = "unreleased-precompiles");
+            for code in SyscallCode::iter().filter(|code| {
+                code.should_send() == 1 && (unreleased || !code.is_unreleased_precompile())
+            }) {
+                let id = Val::<SC>::from_canonical_u32(code.syscall_id());
+                let receivers = syscall_chips
+                    .iter()
+                    .filter(|(_, ids)| ids.contains(&id))
+                    .map(|(name, _)| name)
+                    .collect_vec();
+                match receivers.len() {
+                    0 => errors.push(format!("syscall {:?} is received by no chip", code)),
+                    1 => {}
+                    _ => errors.push(format!(
+                        "syscall {:?} is received by several chips: {}",
+                        code,
+                        receivers.iter().join(", ")
+                    )),
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            bail!(
+                "{} has inconsistent chip registrations:\n  {}",
+                self.name(),
+                errors.join("\n  ")
+            );
         }
+        Ok(())
+    }
+
+    /// The syscall ids that each chip receives, for chips that receive syscalls with constant or
+    /// flag-selected ids.
+    fn syscall_ids_by_chip(&self) -> Vec<(String, HashSet<Val<SC>>)> {
+        self.chips
+            .iter()
+            .filter_map(|chip| {
+                let preprocessed_row = vec![Val::<SC>::ZERO; chip.preprocessed_width()];
+                let mut main_row = vec![Val::<SC>::ZERO; chip.width()];
+                let mut ids = HashSet::new();
+                for lookup in chip
+                    .get_looked()
+                    .iter()
+                    .filter(|lookup| lookup.kind == LookupType::Syscall)
+                {
+                    // The id is the second value, after the clock.
+                    let id = &lookup.values[1];
+                    ids.insert(id.apply::<Val<SC>, Val<SC>>(&preprocessed_row, &main_row));
+                    for i in 0..main_row.len() {
+                        main_row[i] = Val::<SC>::ONE;
+                        ids.insert(id.apply::<Val<SC>, Val<SC>>(&preprocessed_row, &main_row));
+                        main_row[i] = Val::<SC>::ZERO;
+                    }
+                }
+                (!ids.is_empty()).then(|| (chip.name(), ids))
+            })
+            .collect()
     }
 
     pub fn preprocessed_chip_ids(&self) -> Vec<usize> {
@@ -298,22 +472,42 @@ where
         )
     }
 
+    /// The challenger every proof under `vk` starts from, after observing the vk.
+    pub fn vk_challenger(&self, vk: &BaseVerifyingKey<SC>) -> SC::Challenger {
+        let mut challenger = self.config().challenger();
+        vk.observed_by(&mut challenger);
+        challenger
+    }
+
     pub fn verify_riscv(&self, vk: &BaseVerifyingKey<SC>, proofs: &[BaseProof<SC>]) -> Result<()>
     where
         C: for<'a> Air<VerifierConstraintFolder<'a, SC>>,
     {
-        assert!(!proofs.is_empty());
-
-        let mut challenger = self.config().challenger();
+        self.verify_riscv_with_challenger(vk, &self.vk_challenger(vk), proofs)
+    }
 
-        // observe all preprocessed and main commits and pv's
-        vk.observed_by(&mut challenger);
+    /// Verify like [`Self::verify_riscv`], starting from `challenger` as returned by
+    /// [`Self::vk_challenger`] for `vk`, so that proofs under the same vk can share it.
+    pub fn verify_riscv_with_challenger(
+        &self,
+        vk: &BaseVerifyingKey<SC>,
+        challenger: &SC::Challenger,
+        proofs: &[BaseProof<SC>],
+    ) -> Result<()>
+    where
+        C: for<'a> Air<VerifierConstraintFolder<'a, SC>>,
+    {
+        if proofs.is_empty() {
+            bail!("verify_riscv: no proofs");
+        }
 
         // verify all proofs
+        let config = self.config();
+        let chips = self.chips();
         for proof in proofs {
             self.verifier.verify(
-                &self.config(),
-                &self.chips(),
+                &config,
+                &chips,
                 vk,
                 &mut challenger.clone(),
                 proof,
@@ -321,7 +515,7 @@ where
             )?;
 
             if !proof.regional_cumulative_sum().is_zero() {
-                panic!("verify_riscv: local lookup cumulative sum is not zero");
+                bail!("verify_riscv: local lookup cumulative sum is not zero");
             }
         }
 
@@ -335,7 +529,7 @@ where
                 .sum::<SepticDigest<SC::Val>>();
         };
         if !sum.is_zero() {
-            panic!("verify_riscv: global lookup cumulative sum is not zero");
+            bail!("verify_riscv: global lookup cumulative sum is not zero");
         }
 
         Ok(())
@@ -346,12 +540,11 @@ where
     where
         C: for<'a> Air<VerifierConstraintFolder<'a, SC>>,
     {
-        assert!(!proofs.is_empty());
-
-        let mut challenger = self.config().challenger();
+        if proofs.is_empty() {
+            bail!("verify_ensemble: no proofs");
+        }
 
-        // observe all preprocessed and main commits and pv's
-        vk.observed_by(&mut challenger);
+        let challenger = self.vk_challenger(vk);
 
         // verify all proofs
         for proof in proofs {
@@ -365,7 +558,7 @@ where
             )?;
 
             if !proof.regional_cumulative_sum().is_zero() {
-                panic!("verify_ensemble: local lookup cumulative sum is not zero");
+                bail!("verify_ensemble: local lookup cumulative sum is not zero");
             }
         }
 
@@ -379,7 +572,7 @@ where
                 .sum::<SepticDigest<SC::Val>>();
         };
         if !sum.is_zero() {
-            panic!("verify_riscv: global lookup cumulative sum is not zero");
+            bail!("verify_ensemble: global lookup cumulative sum is not zero");
         }
 
         Ok(())
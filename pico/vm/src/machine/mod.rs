@@ -9,6 +9,7 @@ pub mod logger;
 pub mod lookup;
 pub mod machine;
 pub mod permutation;
+pub mod progress;
 pub mod proof;
 pub mod prover;
 pub mod septic;
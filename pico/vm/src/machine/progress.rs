@@ -0,0 +1,15 @@
+//! Progress reporting for long-running proofs
+
+/// A step of the proving pipeline, reported to the callback passed to the `*_with_progress`
+/// prove methods.
+///
+/// Callbacks run on the proving thread between chunks, so they should return quickly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProveProgress {
+    /// The emulator has produced the record of a chunk.
+    Emulating { chunk_index: usize },
+    /// The traces of a chunk are being generated, committed and proven.
+    TraceGen { chunk_index: usize },
+    /// A recursion stage has started folding the proofs of the previous stage.
+    Folding { stage: &'static str },
+}
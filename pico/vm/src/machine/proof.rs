@@ -1,18 +1,39 @@
 use crate::{
-    configs::config::{Com, PcsProof, PcsProverData, StarkGenericConfig},
+    compiler::riscv::{
+        compiler::{Compiler, SourceType},
+        program::Program,
+    },
+    configs::config::{Com, Dom, PcsProof, PcsProverData, StarkGenericConfig, Val},
+    emulator::{opts::EmulatorOpts, riscv::riscv_emulator::RiscvEmulator, stdin::EmulatorStdin},
     instances::compiler::shapes::ProofShape,
     machine::{keys::BaseVerifyingKey, septic::SepticDigest},
+    primitives::{
+        consts::{PV_DIGEST_NUM_WORDS, WORD_SIZE},
+        Poseidon2Init,
+    },
 };
 use alloc::{sync::Arc, vec::Vec};
+use anyhow::{anyhow, Context, Result};
 use hashbrown::HashMap;
 use itertools::Itertools;
+use p3_field::PrimeField32;
 use p3_matrix::dense::RowMajorMatrix;
-use serde::{Deserialize, Serialize};
+use p3_symmetric::Permutation;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 /// Wrapper for all proof types
 /// The top layer of abstraction (the most abstract layer)
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "Dom<SC>: Serialize"))]
+#[serde(bound(deserialize = "Dom<SC>: DeserializeOwned"))]
 pub struct MetaProof<SC>
 where
     SC: StarkGenericConfig,
@@ -61,6 +82,344 @@ where
     pub fn num_proofs(&self) -> usize {
         self.proofs.len()
     }
+
+    /// Get the stage of the proving pipeline that produced this proof.
+    ///
+    /// The mode is read off the proof itself rather than stored alongside it: embed proofs have
+    /// their own config type, and recursion proofs are told apart from riscv proofs by their
+    /// chips, which the verifier checks against the machine.
+    pub fn proving_mode(&self) -> ProvingMode {
+        if SC::is_embed() {
+            ProvingMode::Embedded
+        } else if self
+            .proofs
+            .iter()
+            .any(|proof| proof.includes_chip("MemoryVar"))
+        {
+            ProvingMode::Recursive
+        } else {
+            ProvingMode::Fast
+        }
+    }
+
+    /// Get the named output segments committed with `pico_sdk::io::commit_segment`.
+    ///
+    /// Returns `None` if there is no public values stream, or if it holds anything other than a
+    /// sequence of segments with distinct names.
+    pub fn outputs(&self) -> Option<BTreeMap<String, OutputSegment>> {
+        OutputSegment::decode_all(self.pv_stream.as_ref()?)
+    }
+
+    /// Get the cycle counts committed with `pico_sdk::io::span_commit`, by span name.
+    ///
+    /// Returns `None` under the same conditions as [`Self::outputs`], or if a span segment does
+    /// not hold a single `uint64`.
+    pub fn spans(&self) -> Option<BTreeMap<String, u64>> {
+        self.outputs()?
+            .into_iter()
+            .filter_map(|(name, segment)| {
+                let name = name.strip_prefix(OutputSegment::SPAN_PREFIX)?.to_string();
+                Some(segment.decode_uint64().map(|cycles| (name, cycles)))
+            })
+            .collect()
+    }
+
+    /// Read a string committed with `pico_sdk::io::commit_str`, starting at byte `offset` of the
+    /// public values stream. On success, `offset` is advanced past the string.
+    pub fn read_committed_str(&self, offset: &mut usize) -> Result<String> {
+        let stream = self
+            .pv_stream
+            .as_ref()
+            .ok_or_else(|| anyhow!("proof has no public values stream"))?;
+
+        let len_end = offset
+            .checked_add(4)
+            .filter(|&end| end <= stream.len())
+            .ok_or_else(|| anyhow!("no string length at offset {}", offset))?;
+        let len = u32::from_le_bytes(stream[*offset..len_end].try_into().unwrap()) as usize;
+
+        let bytes = len_end
+            .checked_add(len)
+            .and_then(|end| stream.get(len_end..end))
+            .ok_or_else(|| {
+                anyhow!(
+                    "string of {} bytes at offset {} overruns the public values stream",
+                    len,
+                    offset
+                )
+            })?;
+        let s = String::from_utf8(bytes.to_vec())?;
+
+        *offset = len_end + len;
+        Ok(s)
+    }
+
+    /// Bundle the proof with the inputs it was proven from, so that it can be audited later with
+    /// [`ProofWithInputs::verify_with_inputs`].
+    ///
+    /// `elf` is the program the proof is for. Only its hash is kept.
+    pub fn attach_inputs(
+        self,
+        elf: &[u8],
+        stdin: &EmulatorStdin<Program, Vec<u8>>,
+    ) -> ProofWithInputs<SC> {
+        ProofWithInputs {
+            proof: self,
+            stdin_bytes: stdin.to_bytes(),
+            elf_hash: Sha256::digest(elf).into(),
+            attached_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            retain_inputs_for_days: None,
+        }
+    }
+}
+
+impl<SC> MetaProof<SC>
+where
+    SC: StarkGenericConfig,
+    Val<SC>: PrimeField32,
+{
+    /// Get the SHA-256 hash of the public values stream.
+    ///
+    /// This is the digest the guest computes with `PUBLIC_VALUES_HASHER` and commits at halt. The
+    /// on-chain verifier takes it as a public input after clearing its top 3 bits to fit in a
+    /// BN254 scalar. Guests built with the `coprocessor` feature also hash the coprocessor output
+    /// into the committed digest, so for them this does not match.
+    pub fn public_values_hash(&self) -> Result<[u8; 32]> {
+        let stream = self
+            .pv_stream
+            .as_ref()
+            .ok_or_else(|| anyhow!("proof has no public values stream"))?;
+        Ok(Sha256::digest(stream).into())
+    }
+
+    /// Get the public values digest committed in the proof, or `None` if the proof holds no
+    /// digest bytes.
+    pub fn committed_value_digest(&self) -> Option<[u8; 32]> {
+        // Riscv and recursion public values both start with the digest, one byte per element.
+        // Only the last riscv chunk is guaranteed to hold it.
+        let public_values = &self.proofs.last()?.public_values;
+        let bytes = public_values
+            .get(..PV_DIGEST_NUM_WORDS * WORD_SIZE)?
+            .iter()
+            .map(|byte| u8::try_from(byte.as_canonical_u32()).ok())
+            .collect::<Option<Vec<_>>>()?;
+        bytes.try_into().ok()
+    }
+
+    /// Check that the public values stream hashes to the digest committed in the proof.
+    ///
+    /// This only checks the proof against itself, and is meant as a quick sanity check before
+    /// running the full verifier.
+    pub fn verify_public_values_hash(&self) -> bool {
+        match (self.public_values_hash(), self.committed_value_digest()) {
+            (Ok(hash), Some(committed)) => hash == committed,
+            _ => false,
+        }
+    }
+}
+
+/// A proof stored together with the inputs it was proven from, as returned by
+/// [`MetaProof::attach_inputs`].
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "Dom<SC>: Serialize"))]
+#[serde(bound(deserialize = "Dom<SC>: DeserializeOwned"))]
+pub struct ProofWithInputs<SC>
+where
+    SC: StarkGenericConfig,
+{
+    pub proof: MetaProof<SC>,
+
+    /// The [`EmulatorStdin`] of the proof, in the format of [`EmulatorStdin::to_bytes`].
+    pub stdin_bytes: Vec<u8>,
+
+    /// The SHA-256 hash of the ELF the proof is for.
+    pub elf_hash: [u8; 32],
+
+    /// When the inputs were attached, in seconds since the Unix epoch.
+    pub attached_at: u64,
+
+    /// How many days the inputs may be stored for, or `None` to keep them indefinitely.
+    pub retain_inputs_for_days: Option<u32>,
+}
+
+impl<SC> ProofWithInputs<SC>
+where
+    SC: StarkGenericConfig,
+{
+    /// Only allow the inputs to be stored for `days` days after they were attached.
+    pub fn with_retention(mut self, days: u32) -> Self {
+        self.retain_inputs_for_days = Some(days);
+        self
+    }
+
+    /// Whether the retention period of the inputs is over, after which the storage should only
+    /// keep [`Self::into_proof`].
+    pub fn inputs_expired(&self) -> bool {
+        let Some(days) = self.retain_inputs_for_days else {
+            return false;
+        };
+        let expires_at =
+            UNIX_EPOCH + Duration::from_secs(self.attached_at + u64::from(days) * 24 * 60 * 60);
+        SystemTime::now() >= expires_at
+    }
+
+    /// Deserialize the stored inputs.
+    pub fn stdin(&self) -> Result<EmulatorStdin<Program, Vec<u8>>> {
+        EmulatorStdin::from_bytes(&self.stdin_bytes)
+    }
+
+    /// Save the stored inputs to `path`, to be replayed with
+    /// [`EmulatorStdin::replay_from_file`] or `cargo pico replay`.
+    pub fn save_inputs_to_file(&self, path: &Path) -> Result<()> {
+        fs::write(path, &self.stdin_bytes)
+            .with_context(|| format!("failed to write inputs to {}", path.display()))
+    }
+
+    /// Drop the inputs and keep the proof alone.
+    pub fn into_proof(self) -> MetaProof<SC> {
+        self.proof
+    }
+}
+
+impl<SC> ProofWithInputs<SC>
+where
+    SC: StarkGenericConfig,
+    Val<SC>: PrimeField32 + Poseidon2Init,
+    <Val<SC> as Poseidon2Init>::Poseidon2: Permutation<[Val<SC>; 16]>,
+{
+    /// Check that `elf` is the program the proof is for, and that running it on the stored
+    /// inputs commits the public values digest of the proof.
+    ///
+    /// This does not verify the proof itself, which is left to the verifier. Programs that read
+    /// their vk digest or verify proofs through hints cannot be re-run this way, as neither is
+    /// part of the stored inputs.
+    pub fn verify_with_inputs(&self, elf: &[u8]) -> bool {
+        match (
+            self.replayed_value_digest(elf),
+            self.proof.committed_value_digest(),
+        ) {
+            (Some(replayed), Some(committed)) => replayed == committed,
+            _ => false,
+        }
+    }
+
+    /// The public values digest committed by running `elf` on the stored inputs, or `None` if
+    /// `elf` is not the program of the proof or the run fails.
+    fn replayed_value_digest(&self, elf: &[u8]) -> Option<[u8; 32]> {
+        if <[u8; 32]>::from(Sha256::digest(elf)) != self.elf_hash {
+            return None;
+        }
+        let stdin = self.stdin().ok()?;
+
+        let program = Compiler::new(SourceType::RISCV, elf).compile();
+        let mut emulator = RiscvEmulator::new::<Val<SC>>(program, EmulatorOpts::default());
+        emulator.run_fast(Some(stdin)).ok()?;
+        let digest = emulator.record.public_values.committed_value_digest;
+        digest
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>()
+            .try_into()
+            .ok()
+    }
+}
+
+/// The stage of the proving pipeline a [`MetaProof`] comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProvingMode {
+    /// Riscv chunk proofs, as returned by `prove_fast`. They still need the whole recursion
+    /// chain before they can be verified on-chain.
+    Fast,
+    /// A convert, combine or compress proof. It still needs to be embedded.
+    Recursive,
+    /// An embed proof, ready to be wrapped by the gnark verifier for on-chain use.
+    Embedded,
+}
+
+impl ProvingMode {
+    /// Whether the proof can be wrapped for on-chain verification as is.
+    pub fn is_onchain_ready(&self) -> bool {
+        *self == ProvingMode::Embedded
+    }
+}
+
+/// A named output segment of the public values stream.
+///
+/// Each segment is framed as `magic || name || schema || data`, where the last three fields are
+/// prefixed with their little-endian `u32` length. The framing is committed like any other
+/// public value, so the segment boundaries are bound into the public values digest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputSegment {
+    /// The ABI type of `data`, e.g. the name of a `sol!` struct.
+    pub schema: String,
+    /// The encoded segment value.
+    pub data: Vec<u8>,
+}
+
+impl OutputSegment {
+    /// The bytes that open every segment.
+    pub const MAGIC: [u8; 4] = *b"PSEG";
+
+    /// The prefix of the names of the segments committed by `pico_sdk::io::span_commit`.
+    pub const SPAN_PREFIX: &str = "span:";
+
+    /// Decode the segment as an ABI-encoded `uint64`.
+    fn decode_uint64(&self) -> Option<u64> {
+        if self.schema != "uint64"
+            || self.data.len() != 32
+            || self.data[..24].iter().any(|&b| b != 0)
+        {
+            return None;
+        }
+        Some(u64::from_be_bytes(self.data[24..].try_into().unwrap()))
+    }
+
+    /// Frame a segment for the public values stream.
+    pub fn encode(name: &str, schema: &str, data: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(16 + name.len() + schema.len() + data.len());
+        buf.extend_from_slice(&Self::MAGIC);
+        for field in [name.as_bytes(), schema.as_bytes(), data] {
+            buf.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            buf.extend_from_slice(field);
+        }
+        buf
+    }
+
+    /// Split a public values stream into its segments, keyed by name.
+    pub fn decode_all(mut stream: &[u8]) -> Option<BTreeMap<String, Self>> {
+        fn take<'a>(stream: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+            if stream.len() < len {
+                return None;
+            }
+            let (head, tail) = stream.split_at(len);
+            *stream = tail;
+            Some(head)
+        }
+        fn take_field<'a>(stream: &mut &'a [u8]) -> Option<&'a [u8]> {
+            let len = u32::from_le_bytes(take(stream, 4)?.try_into().unwrap());
+            take(stream, len as usize)
+        }
+
+        let mut segments = BTreeMap::new();
+        while !stream.is_empty() {
+            if take(&mut stream, 4)? != Self::MAGIC {
+                return None;
+            }
+            let name = String::from_utf8(take_field(&mut stream)?.to_vec()).ok()?;
+            let schema = String::from_utf8(take_field(&mut stream)?.to_vec()).ok()?;
+            let data = take_field(&mut stream)?.to_vec();
+            if segments
+                .insert(name, OutputSegment { schema, data })
+                .is_some()
+            {
+                return None;
+            }
+        }
+        Some(segments)
+    }
 }
 
 /// Base proof produced by base prover
@@ -168,3 +527,89 @@ impl<SC: StarkGenericConfig> BaseProof<SC> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::stark_config::BabyBearPoseidon2;
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_public_values_hash() {
+        let proof =
+            MetaProof::<BabyBearPoseidon2>::new(Arc::new([]), Arc::new([]), Some(b"abc".to_vec()));
+        assert_eq!(
+            hex::encode(proof.public_values_hash().unwrap()),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        // There is no proof to hold a committed digest.
+        assert!(!proof.verify_public_values_hash());
+
+        let proof = MetaProof::<BabyBearPoseidon2>::new(Arc::new([]), Arc::new([]), None);
+        assert!(proof.public_values_hash().is_err());
+    }
+
+    #[test]
+    fn test_spans() {
+        let uint64 = |value: u64| {
+            let mut data = [0u8; 32];
+            data[24..].copy_from_slice(&value.to_be_bytes());
+            data
+        };
+        let mut stream = OutputSegment::encode("span:verify", "uint64", &uint64(1 << 40));
+        stream.extend(OutputSegment::encode("span:decode", "uint64", &uint64(7)));
+        stream.extend(OutputSegment::encode("result", "uint64", &uint64(1)));
+        let proof = MetaProof::<BabyBearPoseidon2>::new(Arc::new([]), Arc::new([]), Some(stream));
+        assert_eq!(
+            proof.spans().unwrap(),
+            BTreeMap::from([("verify".to_string(), 1 << 40), ("decode".to_string(), 7)])
+        );
+
+        let stream = OutputSegment::encode("span:verify", "string", b"fast");
+        let proof = MetaProof::<BabyBearPoseidon2>::new(Arc::new([]), Arc::new([]), Some(stream));
+        assert!(proof.spans().is_none());
+    }
+
+    #[test]
+    fn test_verify_with_inputs() {
+        const FIBONACCI_ELF: &[u8] =
+            include_bytes!("../compiler/test_elf/riscv32im-pico-fibonacci-elf");
+        const KECCAK_ELF: &[u8] = include_bytes!("../compiler/test_elf/riscv32im-pico-keccak-elf");
+
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        stdin.write(&10u32);
+        let stdin = stdin.finalize();
+
+        let program = Compiler::new(SourceType::RISCV, FIBONACCI_ELF).compile();
+        let mut emulator = RiscvEmulator::new::<BabyBear>(program, EmulatorOpts::default());
+        emulator.run_fast(Some(stdin.clone())).unwrap();
+        let pv_stream = emulator.state.public_values_stream.clone();
+        // The fibonacci guest commits the hash of its public values stream.
+        let digest: [u8; 32] = Sha256::digest(&pv_stream).into();
+
+        let proof =
+            MetaProof::<BabyBearPoseidon2>::new(Arc::new([]), Arc::new([]), Some(pv_stream))
+                .attach_inputs(FIBONACCI_ELF, &stdin)
+                .with_retention(30);
+        assert_eq!(proof.replayed_value_digest(FIBONACCI_ELF), Some(digest));
+        assert_eq!(proof.replayed_value_digest(KECCAK_ELF), None);
+        assert!(!proof.inputs_expired());
+        // Without chunk proofs there is no committed digest to check against.
+        assert!(!proof.verify_with_inputs(FIBONACCI_ELF));
+
+        // The stored inputs are a replayable stdin file.
+        let path = std::env::temp_dir().join("pico-test-verify-with-inputs.stdin");
+        proof.save_inputs_to_file(&path).unwrap();
+        let replayed = EmulatorStdin::<Program, Vec<u8>>::replay_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(replayed.to_bytes(), stdin.to_bytes());
+
+        // Other inputs give another digest.
+        let mut other = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        other.write(&11u32);
+        let proof = proof
+            .into_proof()
+            .attach_inputs(FIBONACCI_ELF, &other.finalize());
+        assert_ne!(proof.replayed_value_digest(FIBONACCI_ELF), Some(digest));
+    }
+}
@@ -1,3 +1,24 @@
+//! The septic curve parameters of each supported field.
+//!
+//! [`FieldSepticCurve`] has a blanket impl that picks the parameters by type with `same_field`,
+//! so that the symbolic and packed types of a field resolve to the same constants. A field that is
+//! not matched falls back to the all-zero constants of `dummy`, which only keeps the generic code
+//! compiling; proving with them fails, and `n_power` and `curve_slope` panic.
+//!
+//! Because of the blanket impl, a new field can not implement the trait itself and is added here
+//! instead. It needs a module like `babybear` that provides:
+//!
+//! - `EXT_COEFFS`, the coefficients `c` of an irreducible `z^7 = c_0 + c_1 z + ...` over the field,
+//!   and `EXT_GENERATOR`, a generator of the multiplicative group of that extension.
+//! - `Z_POW_P` and `Z_POW_P2`, the powers `z^(i p)` and `z^(i p^2)` for the Frobenius map.
+//! - `TOP_BITS`, the number of consecutive one bits of `p - 1` from bit 30 down, used to range
+//!   check the `y` coordinate of the global lookup points.
+//! - The witness dummy point, the cumulative sum start point and the digest sum start point, each
+//!   on the curve and of unknown discrete log.
+//!
+//! Each of these gets a `same_field` branch in the `const fn` of the same name below, and
+//! `n_power` and `curve_slope` get a branch for the field's curve `y^2 = x^3 + a x + b`.
+
 pub mod babybear;
 mod dummy;
 pub mod koalabear;
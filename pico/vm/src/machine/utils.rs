@@ -28,9 +28,10 @@ use crate::{
         keys::HashableKey,
         septic::SepticDigest,
     },
+    primitives::consts::{DIGEST_SIZE, RECURSION_NUM_PVS},
 };
 
-use super::proof::MetaProof;
+use super::proof::{BaseProof, MetaProof};
 
 pub fn type_name_of<T>(_: &T) -> String {
     type_name::<T>().to_string()
@@ -410,23 +411,65 @@ where
     builder.constraints()
 }
 
-pub fn assert_riscv_vk_digest<SC: StarkGenericConfig>(
+/// Check that `proof` attests to the program with the riscv vk `riscv_vk`.
+pub fn check_riscv_vk_digest<SC: StarkGenericConfig>(
     proof: &MetaProof<SC>,
     riscv_vk: &dyn HashableKey<SC::Val>,
-) {
-    let public_values: &RecursionPublicValues<_> = proof.proofs[0].public_values.as_ref().borrow();
-    assert_eq!(public_values.riscv_vk_digest, riscv_vk.hash_field());
+) -> anyhow::Result<()> {
+    let digest = recursion_riscv_vk_digest(proof)?;
+    if digest != riscv_vk.hash_field() {
+        anyhow::bail!(
+            "riscv vk digest {:?} does not match the expected vk",
+            digest
+        );
+    }
+    Ok(())
+}
+
+/// The riscv vk digest in the recursion public values of `proof`.
+pub fn recursion_riscv_vk_digest<SC: StarkGenericConfig>(
+    proof: &MetaProof<SC>,
+) -> anyhow::Result<[SC::Val; DIGEST_SIZE]> {
+    match proof.proofs.first() {
+        Some(first) if first.public_values.len() >= RECURSION_NUM_PVS => {
+            let public_values: &RecursionPublicValues<_> = first.public_values.as_ref().borrow();
+            Ok(public_values.riscv_vk_digest)
+        }
+        _ => anyhow::bail!("missing recursion public values"),
+    }
 }
 
-pub fn assert_recursion_public_values_valid<SC: StarkGenericConfig>(
+/// The recursion public values of a chunk proof of a recursion program.
+pub fn recursion_public_values<SC: StarkGenericConfig>(
+    proof: &BaseProof<SC>,
+) -> anyhow::Result<&RecursionPublicValues<SC::Val>> {
+    if proof.public_values.len() < RECURSION_NUM_PVS {
+        anyhow::bail!("missing recursion public values");
+    }
+    Ok(proof.public_values.as_ref().borrow())
+}
+
+/// The recursion public values of `proof`, which must hold a single chunk proof.
+pub fn single_recursion_public_values<SC: StarkGenericConfig>(
+    proof: &MetaProof<SC>,
+) -> anyhow::Result<&RecursionPublicValues<SC::Val>> {
+    match proof.proofs.as_ref() {
+        [single] => recursion_public_values(single),
+        proofs => anyhow::bail!("expected a single proof, got {}", proofs.len()),
+    }
+}
+
+/// Check that the digest in the recursion public values is the hash of the other values.
+pub fn check_recursion_public_values<SC: StarkGenericConfig>(
     config: &SC,
     public_values: &RecursionPublicValues<SC::Val>,
-) {
+) -> anyhow::Result<()> {
     let pv_array = public_values.as_array();
     let expected_digest = config.hash_slice(&pv_array[0..NUM_PV_ELMS_TO_HASH]);
-    for (value, expected) in public_values.digest.iter().copied().zip_eq(expected_digest) {
-        assert_eq!(value, expected);
+    if public_values.digest.iter().copied().ne(expected_digest) {
+        anyhow::bail!("recursion public values digest mismatch");
     }
+    Ok(())
 }
 
 fn compute_degree<F: Field>(expr: &SymbolicExpression<F>) -> usize {
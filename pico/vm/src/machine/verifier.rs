@@ -75,6 +75,11 @@ where
         } = proof;
 
         let chips = order_chips::<SC, C>(chips, main_chip_ordering).collect::<Vec<_>>();
+        // Every chip named by the proof must belong to this machine, so that the chip names can
+        // be trusted once the proof verifies.
+        if chips.len() != main_chip_ordering.len() {
+            bail!("proof names chips that are not in the machine");
+        }
 
         let pcs = config.pcs();
 
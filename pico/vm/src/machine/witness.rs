@@ -5,7 +5,9 @@ use crate::{
     },
     configs::config::{StarkGenericConfig, Val},
     emulator::{
-        opts::EmulatorOpts, recursion::emulator::RecursionRecord, riscv::record::EmulationRecord,
+        opts::EmulatorOpts,
+        recursion::emulator::RecursionRecord,
+        riscv::{record::EmulationRecord, syscalls::HostCallHandlers},
         stdin::EmulatorStdin,
     },
     instances::compiler::{
@@ -13,12 +15,13 @@ use crate::{
     },
     machine::{
         chip::ChipBehavior,
-        keys::{BaseProvingKey, BaseVerifyingKey},
+        keys::{BaseProvingKey, BaseVerifyingKey, HashableKey},
         proof::BaseProof,
     },
     primitives::consts::DIGEST_SIZE,
 };
 use alloc::sync::Arc;
+use p3_field::PrimeField32;
 
 #[derive(Default)]
 pub struct ProvingWitness<SC, C, I>
@@ -36,6 +39,12 @@ where
 
     pub vk_root: Option<[Val<SC>; DIGEST_SIZE]>,
 
+    /// Digest of the riscv vk, exposed to the guest through `SELF_VK_DIGEST`.
+    pub vk_digest: Option<[u32; DIGEST_SIZE]>,
+
+    /// Handlers for the requests the riscv guest makes through `HOST_CALL`.
+    pub host_call_handlers: HostCallHandlers,
+
     pub stdin: Option<EmulatorStdin<C::Program, I>>,
 
     pub flag_empty_stdin: bool,
@@ -59,6 +68,8 @@ where
             vk: None,
             proof: None,
             vk_root: None,
+            vk_digest: None,
+            host_call_handlers: Default::default(),
             stdin: None,
             flag_empty_stdin: false,
             opts: None,
@@ -78,6 +89,8 @@ where
             vk: Some(vk),
             proof: None,
             vk_root: None,
+            vk_digest: None,
+            host_call_handlers: Default::default(),
             stdin: None,
             flag_empty_stdin: false,
             opts: None,
@@ -111,13 +124,20 @@ where
         opts: EmulatorOpts,
         pk: BaseProvingKey<SC>,
         vk: BaseVerifyingKey<SC>,
-    ) -> Self {
+    ) -> Self
+    where
+        BaseVerifyingKey<SC>: HashableKey<Val<SC>>,
+        Val<SC>: PrimeField32,
+    {
+        let vk_digest = vk.hash_field().map(|x| x.as_canonical_u32());
         Self {
             program: Some(program),
             pk: Some(pk),
             vk: Some(vk),
             proof: None,
             vk_root: None,
+            vk_digest: Some(vk_digest),
+            host_call_handlers: Default::default(),
             stdin: Some(stdin),
             flag_empty_stdin: false,
             opts: Some(opts),
@@ -149,6 +169,8 @@ where
             vk: None,
             proof: None,
             vk_root: None,
+            vk_digest: None,
+            host_call_handlers: Default::default(),
             stdin: Some(stdin),
             flag_empty_stdin: false,
             opts: Some(opts),
@@ -188,6 +210,8 @@ where
             vk: last_vk,
             proof: last_proof,
             vk_root: Some(vk_root),
+            vk_digest: None,
+            host_call_handlers: Default::default(),
             stdin: Some(stdin),
             flag_empty_stdin,
             opts: Some(opts),
@@ -5,6 +5,7 @@ use crate::{
     configs::stark_config::{BabyBearPoseidon2, KoalaBearPoseidon2, M31Poseidon2},
     primitives::consts::{MERSENNE31_NUM_EXTERNAL_ROUNDS, MERSENNE31_NUM_INTERNAL_ROUNDS},
 };
+use anyhow::{ensure, Result};
 use consts::{
     BABYBEAR_NUM_EXTERNAL_ROUNDS, BABYBEAR_NUM_INTERNAL_ROUNDS, KOALABEAR_NUM_EXTERNAL_ROUNDS,
     KOALABEAR_NUM_INTERNAL_ROUNDS, PERMUTATION_WIDTH,
@@ -255,6 +256,18 @@ pub trait Poseidon2Init {
     fn init() -> Self::Poseidon2;
 }
 
+/// A field whose permutation can also be built from custom round constants, see
+/// `CustomPoseidon2::with_round_constants`.
+pub trait Poseidon2Constants: Poseidon2Init + Sized {
+    const NUM_EXTERNAL_ROUNDS: usize;
+    const NUM_INTERNAL_ROUNDS: usize;
+
+    fn new_poseidon2(
+        external_round_constants: ExternalLayerConstants<Self, PERMUTATION_WIDTH>,
+        internal_round_constants: Vec<Self>,
+    ) -> Self::Poseidon2;
+}
+
 impl Poseidon2Init for BabyBearPoseidon2 {
     type Poseidon2 = PicoPoseidon2BabyBear;
     fn init() -> Self::Poseidon2 {
@@ -297,65 +310,130 @@ impl Poseidon2Init for Mersenne31 {
     }
 }
 
+/// Splits a table of round constants into the external constants of the first and last half of
+/// the full rounds, and the first constant of each partial round in between.
+fn split_round_constants<F: Copy>(
+    round_constants: &[[F; PERMUTATION_WIDTH]],
+    rounds_f: usize,
+    rounds_p: usize,
+) -> (ExternalLayerConstants<F, PERMUTATION_WIDTH>, Vec<F>) {
+    let internal_start = rounds_f / 2;
+    let internal_end = internal_start + rounds_p;
+    let internal_round_constants = round_constants[internal_start..internal_end]
+        .iter()
+        .map(|constants| constants[0])
+        .collect();
+    let external_round_constants = ExternalLayerConstants::new(
+        round_constants[..internal_start].to_vec(),
+        round_constants[internal_end..internal_end + rounds_f - internal_start].to_vec(),
+    );
+    (external_round_constants, internal_round_constants)
+}
+
+/// Builds the permutation of `F` from custom round constants instead of the defaults, e.g. to
+/// match the Poseidon2 instance of another system. The number of constants must match the round
+/// numbers of `F`.
+pub(crate) fn pico_poseidon2_with_constants<F: Poseidon2Constants>(
+    external_round_constants: ExternalLayerConstants<F, PERMUTATION_WIDTH>,
+    internal_round_constants: Vec<F>,
+) -> Result<F::Poseidon2> {
+    let rounds_f = F::NUM_EXTERNAL_ROUNDS;
+    let rounds_p = F::NUM_INTERNAL_ROUNDS;
+    let num_external = external_round_constants.get_initial_constants().len()
+        + external_round_constants.get_terminal_constants().len();
+    ensure!(
+        num_external == rounds_f,
+        "expected {rounds_f} external round constants, got {num_external}"
+    );
+    ensure!(
+        internal_round_constants.len() == rounds_p,
+        "expected {rounds_p} internal round constants, got {}",
+        internal_round_constants.len()
+    );
+    Ok(F::new_poseidon2(
+        external_round_constants,
+        internal_round_constants,
+    ))
+}
+
 /*
 Poseidon2 on BabyBear
  */
 fn pico_poseidon2bb_init() -> PicoPoseidon2BabyBear {
-    const ROUNDS_F: usize = BABYBEAR_NUM_EXTERNAL_ROUNDS;
-    const ROUNDS_P: usize = BABYBEAR_NUM_INTERNAL_ROUNDS;
-
-    let mut round_constants = RC_16_30_BabyBear.to_vec();
-    let internal_start = ROUNDS_F / 2;
-    let internal_end = (ROUNDS_F / 2) + ROUNDS_P;
-    let internal_round_constants = round_constants
-        .drain(internal_start..internal_end)
-        .map(|vec| vec[0])
-        .collect::<Vec<_>>();
-
-    let external_round_constants = ExternalLayerConstants::new(
-        round_constants[..(ROUNDS_F / 2)].to_vec(),
-        round_constants[(ROUNDS_F / 2)..ROUNDS_F].to_vec(),
+    let (external_round_constants, internal_round_constants) = split_round_constants(
+        &*RC_16_30_BabyBear,
+        BABYBEAR_NUM_EXTERNAL_ROUNDS,
+        BABYBEAR_NUM_INTERNAL_ROUNDS,
     );
-
     PicoPoseidon2BabyBear::new(external_round_constants, internal_round_constants)
 }
 
+impl Poseidon2Constants for BabyBear {
+    const NUM_EXTERNAL_ROUNDS: usize = BABYBEAR_NUM_EXTERNAL_ROUNDS;
+    const NUM_INTERNAL_ROUNDS: usize = BABYBEAR_NUM_INTERNAL_ROUNDS;
+
+    fn new_poseidon2(
+        external_round_constants: ExternalLayerConstants<Self, PERMUTATION_WIDTH>,
+        internal_round_constants: Vec<Self>,
+    ) -> Self::Poseidon2 {
+        PicoPoseidon2BabyBear::new(external_round_constants, internal_round_constants)
+    }
+}
+
 /*
 Poseidon2 on KoalaBear
  */
 fn pico_poseidon2kb_init() -> PicoPoseidon2KoalaBear {
-    const ROUNDS_F: usize = KOALABEAR_NUM_EXTERNAL_ROUNDS;
-    const ROUNDS_P: usize = KOALABEAR_NUM_INTERNAL_ROUNDS;
+    let (external_round_constants, internal_round_constants) = split_round_constants(
+        &*RC_16_30_KoalaBear,
+        KOALABEAR_NUM_EXTERNAL_ROUNDS,
+        KOALABEAR_NUM_INTERNAL_ROUNDS,
+    );
+    PicoPoseidon2KoalaBear::new(external_round_constants, internal_round_constants)
+}
 
-    let mut round_constants = RC_16_30_KoalaBear.to_vec();
-    let internal_start = ROUNDS_F / 2;
-    let internal_end = (ROUNDS_F / 2) + ROUNDS_P;
-    let internal_round_constants = round_constants
-        .drain(internal_start..internal_end)
-        .map(|vec| vec[0])
-        .collect::<Vec<_>>();
+impl Poseidon2Constants for KoalaBear {
+    const NUM_EXTERNAL_ROUNDS: usize = KOALABEAR_NUM_EXTERNAL_ROUNDS;
+    const NUM_INTERNAL_ROUNDS: usize = KOALABEAR_NUM_INTERNAL_ROUNDS;
 
-    let external_round_constants = ExternalLayerConstants::new(
-        round_constants[..(ROUNDS_F / 2)].to_vec(),
-        round_constants[(ROUNDS_F / 2)..ROUNDS_F].to_vec(),
-    );
+    fn new_poseidon2(
+        external_round_constants: ExternalLayerConstants<Self, PERMUTATION_WIDTH>,
+        internal_round_constants: Vec<Self>,
+    ) -> Self::Poseidon2 {
+        PicoPoseidon2KoalaBear::new(external_round_constants, internal_round_constants)
+    }
+}
 
-    PicoPoseidon2KoalaBear::new(external_round_constants, internal_round_constants)
+/// A padding-free Poseidon2 sponge over the permutation of `P`, absorbing `RATE` elements per
+/// permutation and squeezing `OUT` elements. The capacity is the rest of the permutation width.
+///
+/// The split is checked at compile time: the rate and a non-empty capacity must add up to
+/// [`PERMUTATION_WIDTH`], and the output must fit in the state.
+pub fn poseidon2_hasher<P: Poseidon2Init, const RATE: usize, const OUT: usize>(
+) -> PaddingFreeSponge<P::Poseidon2, PERMUTATION_WIDTH, RATE, OUT> {
+    const {
+        assert!(
+            RATE > 0 && RATE < PERMUTATION_WIDTH,
+            "the sponge rate must leave a non-empty capacity"
+        );
+        assert!(
+            OUT > 0 && OUT <= PERMUTATION_WIDTH,
+            "the sponge output must fit in the permutation state"
+        );
+    }
+    PaddingFreeSponge::new(P::init())
 }
 
 pub fn poseidon2_bb_hasher() -> PaddingFreeSponge<PicoPoseidon2BabyBear, 16, 8, 8> {
-    let hasher = pico_poseidon2bb_init();
-    PaddingFreeSponge::<PicoPoseidon2BabyBear, 16, 8, 8>::new(hasher)
+    poseidon2_hasher::<BabyBear, 8, 8>()
 }
 
 pub fn poseidon2_kb_hasher() -> PaddingFreeSponge<PicoPoseidon2KoalaBear, 16, 8, 8> {
-    let hasher = pico_poseidon2kb_init();
-    PaddingFreeSponge::<PicoPoseidon2KoalaBear, 16, 8, 8>::new(hasher)
+    poseidon2_hasher::<KoalaBear, 8, 8>()
 }
 
 pub fn poseidon2_m31_hasher() -> PaddingFreeSponge<PicoPoseidon2Mersenne31, 16, 8, 8> {
-    let hasher = pico_poseidon2m31_init();
-    PaddingFreeSponge::<PicoPoseidon2Mersenne31, 16, 8, 8>::new(hasher)
+    poseidon2_hasher::<Mersenne31, 8, 8>()
 }
 
 lazy_static! {
@@ -371,25 +449,26 @@ lazy_static! {
 Poseidon2 on M31
  */
 fn pico_poseidon2m31_init() -> PicoPoseidon2Mersenne31 {
-    const ROUNDS_F: usize = MERSENNE31_NUM_EXTERNAL_ROUNDS;
-    const ROUNDS_P: usize = MERSENNE31_NUM_INTERNAL_ROUNDS;
-
-    let mut round_constants = RC_16_30_M31.to_vec();
-    let internal_start = ROUNDS_F / 2;
-    let internal_end = (ROUNDS_F / 2) + ROUNDS_P;
-    let internal_round_constants = round_constants
-        .drain(internal_start..internal_end)
-        .map(|vec| vec[0])
-        .collect::<Vec<_>>();
-
-    let external_round_constants = ExternalLayerConstants::new(
-        round_constants[..(ROUNDS_F / 2)].to_vec(),
-        round_constants[(ROUNDS_F / 2)..ROUNDS_F].to_vec(),
+    let (external_round_constants, internal_round_constants) = split_round_constants(
+        &*RC_16_30_M31,
+        MERSENNE31_NUM_EXTERNAL_ROUNDS,
+        MERSENNE31_NUM_INTERNAL_ROUNDS,
     );
-
     PicoPoseidon2Mersenne31::new(external_round_constants, internal_round_constants)
 }
 
+impl Poseidon2Constants for Mersenne31 {
+    const NUM_EXTERNAL_ROUNDS: usize = MERSENNE31_NUM_EXTERNAL_ROUNDS;
+    const NUM_INTERNAL_ROUNDS: usize = MERSENNE31_NUM_INTERNAL_ROUNDS;
+
+    fn new_poseidon2(
+        external_round_constants: ExternalLayerConstants<Self, PERMUTATION_WIDTH>,
+        internal_round_constants: Vec<Self>,
+    ) -> Self::Poseidon2 {
+        PicoPoseidon2Mersenne31::new(external_round_constants, internal_round_constants)
+    }
+}
+
 /*
 Poseidon2 on Bn254
  */
@@ -444,3 +523,46 @@ pub fn pico_poseidon2bn254_init() -> PicoPoseidon2Bn254 {
     // Pico Poseidon2 implementation.
     PicoPoseidon2Bn254::new(external_round_constants, internal_round_constants)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p3_symmetric::{CryptographicHasher, Permutation};
+
+    #[test]
+    fn test_poseidon2_hasher_rate() {
+        let input = (0..24)
+            .map(BabyBear::from_canonical_u32)
+            .collect::<Vec<_>>();
+
+        let preset = poseidon2_bb_hasher().hash_iter(input.clone());
+        let generic = poseidon2_hasher::<BabyBear, 8, 8>().hash_iter(input.clone());
+        assert_eq!(preset, generic);
+
+        // A wider rate absorbs the input in different blocks, so the digest changes.
+        let wide: [BabyBear; 8] = poseidon2_hasher::<BabyBear, 12, 8>().hash_iter(input);
+        assert_ne!(preset, wide);
+    }
+
+    #[test]
+    fn test_poseidon2_with_constants() {
+        let (external, internal) = split_round_constants(
+            &*RC_16_30_KoalaBear,
+            KOALABEAR_NUM_EXTERNAL_ROUNDS,
+            KOALABEAR_NUM_INTERNAL_ROUNDS,
+        );
+        let input: [KoalaBear; PERMUTATION_WIDTH] =
+            core::array::from_fn(KoalaBear::from_canonical_usize);
+
+        let custom = pico_poseidon2_with_constants::<KoalaBear>(external, internal).unwrap();
+        assert_eq!(custom.permute(input), KoalaBear::init().permute(input));
+
+        // BabyBear has fewer internal rounds than KoalaBear.
+        let (external, internal) = split_round_constants(
+            &*RC_16_30_BabyBear,
+            BABYBEAR_NUM_EXTERNAL_ROUNDS,
+            KOALABEAR_NUM_INTERNAL_ROUNDS,
+        );
+        assert!(pico_poseidon2_with_constants::<BabyBear>(external, internal).is_err());
+    }
+}
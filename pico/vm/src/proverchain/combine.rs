@@ -5,10 +5,15 @@ use crate::{
         field_config::{BabyBearSimple, KoalaBearSimple},
         stark_config::{BabyBearPoseidon2, KoalaBearPoseidon2},
     },
-    emulator::{opts::EmulatorOpts, stdin::EmulatorStdin},
+    emulator::{
+        opts::EmulatorOpts, recursion::public_values::RecursionPublicValues, stdin::EmulatorStdin,
+    },
     instances::{
         chiptype::recursion_chiptype::RecursionChipType,
-        compiler::{shapes::recursion_shape::RecursionShapeConfig, vk_merkle::HasStaticVkManager},
+        compiler::{
+            shapes::recursion_shape::{check_combine_arity, RecursionShapeConfig},
+            vk_merkle::HasStaticVkManager,
+        },
         machine::combine::CombineMachine,
     },
     machine::{
@@ -18,9 +23,12 @@ use crate::{
         proof::MetaProof,
         witness::ProvingWitness,
     },
-    primitives::consts::{COMBINE_SIZE, DIGEST_SIZE, EXTENSION_DEGREE, RECURSION_NUM_PVS},
+    primitives::consts::{DIGEST_SIZE, EXTENSION_DEGREE, RECURSION_NUM_PVS},
 };
+use alloc::sync::Arc;
 use p3_field::{extension::BinomiallyExtendable, FieldAlgebra, PrimeField32};
+use std::borrow::Borrow;
+use thiserror::Error;
 
 type ConvertChips<SC> = RecursionChipType<Val<SC>>;
 pub type CombineChips<SC> = RecursionChipType<Val<SC>>;
@@ -39,6 +47,80 @@ where
     prev_machine: BaseMachine<PrevSC, ConvertChips<PrevSC>>,
 }
 
+/// Why [`CombineProver::combine_many`] rejected its inputs.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombineManyError {
+    #[error("no proofs to combine")]
+    NoProofs,
+    #[error("input {0} has no chunk proofs")]
+    EmptyInput(usize),
+    #[error("input {0} proves a different program than input {prev}", prev = .0 - 1)]
+    ProgramMismatch(usize),
+    #[error("input {0} does not continue the execution proven by input {prev}", prev = .0 - 1)]
+    NotContinuous(usize),
+}
+
+/// Check that consecutive chunk ranges, given by the public values of their first and last chunk
+/// proofs, belong to the same program and that each range starts where the previous one ended.
+fn check_chunk_ranges<F: PartialEq>(
+    ranges: &[(&RecursionPublicValues<F>, &RecursionPublicValues<F>)],
+) -> Result<(), CombineManyError> {
+    for (i, ((_, prev), (next, _))) in ranges.iter().zip(ranges.iter().skip(1)).enumerate() {
+        if prev.riscv_vk_digest != next.riscv_vk_digest || prev.vk_root != next.vk_root {
+            return Err(CombineManyError::ProgramMismatch(i + 1));
+        }
+        if prev.next_pc != next.start_pc
+            || prev.next_chunk != next.start_chunk
+            || prev.next_execution_chunk != next.start_execution_chunk
+            || prev.last_initialize_addr_bits != next.previous_initialize_addr_bits
+            || prev.last_finalize_addr_bits != next.previous_finalize_addr_bits
+        {
+            return Err(CombineManyError::NotContinuous(i + 1));
+        }
+    }
+    Ok(())
+}
+
+/// Concatenate convert proofs of consecutive chunk ranges into one witness for the combine prover,
+/// checking on the host that each range picks up where the previous one stopped.
+fn concat_chunk_proofs<SC>(proofs: &[MetaProof<SC>]) -> Result<MetaProof<SC>, CombineManyError>
+where
+    SC: StarkGenericConfig,
+    Val<SC>: PrimeField32,
+{
+    if proofs.is_empty() {
+        return Err(CombineManyError::NoProofs);
+    }
+    let ranges = proofs
+        .iter()
+        .enumerate()
+        .map(
+            |(i, proof)| match (proof.proofs.first(), proof.proofs.last()) {
+                (Some(first), Some(last)) => Ok((
+                    first.public_values.as_ref().borrow(),
+                    last.public_values.as_ref().borrow(),
+                )),
+                _ => Err(CombineManyError::EmptyInput(i)),
+            },
+        )
+        .collect::<Result<Vec<(&RecursionPublicValues<_>, &RecursionPublicValues<_>)>, _>>()?;
+    check_chunk_ranges::<Val<SC>>(&ranges)?;
+
+    let chunk_proofs = proofs
+        .iter()
+        .flat_map(|proof| proof.proofs.iter().cloned())
+        .collect::<Arc<[_]>>();
+    let vks = proofs
+        .iter()
+        .flat_map(|proof| proof.vks.iter().cloned())
+        .collect::<Arc<[_]>>();
+    let pv_stream = proofs
+        .iter()
+        .rev()
+        .find_map(|proof| proof.pv_stream.clone());
+    Ok(MetaProof::new(chunk_proofs, vks, pv_stream))
+}
+
 macro_rules! impl_combine_vk_prover {
     ($recur_cc:ident, $recur_sc:ident) => {
         impl ProverChain<$recur_sc, ConvertChips<$recur_sc>, $recur_sc>
@@ -52,6 +134,12 @@ macro_rules! impl_combine_vk_prover {
                 opts: Self::Opts,
                 shape_config: Option<Self::ShapeConfig>,
             ) -> Self {
+                check_combine_arity(
+                    opts.combine_arity,
+                    <$recur_sc as HasStaticVkManager>::static_vk_manager()
+                        .vk_verification_enabled(),
+                    shape_config.as_ref(),
+                );
                 let machine = CombineMachine::new(
                     $recur_sc::new(),
                     CombineChips::<$recur_sc>::combine_chips(),
@@ -66,6 +154,24 @@ macro_rules! impl_combine_vk_prover {
             }
         }
 
+        impl CombineProver<$recur_sc, $recur_sc> {
+            /// Combine convert proofs that were produced separately, such as ranges of one RISC-V
+            /// proof converted by different workers, into a single proof. Each chunk is still
+            /// authorized against the vk merkle root when vk verification is enabled.
+            ///
+            /// The combine circuit chains the pc, chunk numbers, memory address bits and digests
+            /// of its inputs, so `proofs` must be in execution order and cover consecutive
+            /// chunks of the same program. Proofs of unrelated programs cannot be aggregated
+            /// this way; that needs a circuit whose public values commit to the list of programs
+            /// and their outputs, which the recursion shapes do not have yet.
+            pub fn combine_many(
+                &self,
+                proofs: &[MetaProof<$recur_sc>],
+            ) -> Result<MetaProof<$recur_sc>, CombineManyError> {
+                Ok(self.prove(concat_chunk_proofs(proofs)?))
+            }
+        }
+
         impl MachineProver<$recur_sc> for CombineProver<$recur_sc, $recur_sc> {
             type Witness = MetaProof<$recur_sc>;
             type Chips = CombineChips<$recur_sc>;
@@ -82,16 +188,14 @@ macro_rules! impl_combine_vk_prover {
                     [Val::<$recur_sc>::ZERO; DIGEST_SIZE]
                 };
 
-                // let shape_config = self.shape_config.as_ref().unwrap();
-
                 let (stdin, last_vk, last_proof) =
                     EmulatorStdin::setup_for_combine::<Val<$recur_sc>, $recur_cc>(
                         vk_root,
                         proofs.vks(),
                         &proofs.proofs(),
                         &self.prev_machine,
-                        COMBINE_SIZE,
-                        proofs.proofs.len() <= COMBINE_SIZE,
+                        self.opts.combine_arity,
+                        proofs.proofs.len() <= self.opts.combine_arity,
                         &vk_manager,
                         self.shape_config.as_ref(),
                     );
@@ -101,9 +205,10 @@ macro_rules! impl_combine_vk_prover {
                     last_vk,
                     last_proof,
                     self.machine.config(),
-                    self.opts,
+                    self.opts.clone(),
                 );
-                self.machine.prove(&witness)
+                self.machine
+                    .prove_with_shape(&witness, self.shape_config.as_ref())
             }
 
             fn verify(
@@ -119,3 +224,44 @@ macro_rules! impl_combine_vk_prover {
 
 impl_combine_vk_prover!(BabyBearSimple, BabyBearPoseidon2);
 impl_combine_vk_prover!(KoalaBearSimple, KoalaBearPoseidon2);
+
+#[cfg(test)]
+mod tests {
+    use super::{check_chunk_ranges, CombineManyError};
+    use crate::emulator::recursion::public_values::RecursionPublicValues;
+
+    /// The public values of a chunk range of the program with digest `program`.
+    fn range(program: u32, start_chunk: u32, next_chunk: u32) -> RecursionPublicValues<u32> {
+        RecursionPublicValues {
+            riscv_vk_digest: [program; 8],
+            start_pc: 0x1000 + start_chunk,
+            next_pc: 0x1000 + next_chunk,
+            start_chunk,
+            next_chunk,
+            start_execution_chunk: start_chunk,
+            next_execution_chunk: next_chunk,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_chunk_ranges() {
+        let ranges = [range(1, 1, 3), range(1, 3, 4), range(1, 4, 7)];
+        let pairs = ranges.iter().map(|pv| (pv, pv)).collect::<Vec<_>>();
+        assert_eq!(check_chunk_ranges(&pairs), Ok(()));
+
+        let ranges = [range(1, 1, 3), range(1, 3, 4), range(2, 4, 7)];
+        let pairs = ranges.iter().map(|pv| (pv, pv)).collect::<Vec<_>>();
+        assert_eq!(
+            check_chunk_ranges(&pairs),
+            Err(CombineManyError::ProgramMismatch(2))
+        );
+
+        let ranges = [range(1, 1, 3), range(1, 4, 5), range(1, 5, 7)];
+        let pairs = ranges.iter().map(|pv| (pv, pv)).collect::<Vec<_>>();
+        assert_eq!(
+            check_chunk_ranges(&pairs),
+            Err(CombineManyError::NotContinuous(1))
+        );
+    }
+}
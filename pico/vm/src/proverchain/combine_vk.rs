@@ -97,7 +97,7 @@ macro_rules! impl_combine_vk_prover {
                     last_vk,
                     last_proof,
                     self.machine.config(),
-                    self.opts,
+                    self.opts.clone(),
                 );
                 self.machine.prove(&witness)
             }
@@ -92,8 +92,11 @@ macro_rules! impl_convert_prover {
                     &proofs.proofs(),
                     &self.shape_config,
                 );
-                let witness =
-                    ProvingWitness::setup_for_convert(stdin, self.machine.config(), self.opts);
+                let witness = ProvingWitness::setup_for_convert(
+                    stdin,
+                    self.machine.config(),
+                    self.opts.clone(),
+                );
                 self.machine.prove(&witness)
             }
 
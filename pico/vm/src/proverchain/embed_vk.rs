@@ -11,10 +11,14 @@ use crate::{
     instances::{
         chiptype::recursion_chiptype::RecursionChipType,
         compiler::{
+            onchain_circuit::{
+                gnark::{builder::OnchainVerifierCircuit, inputs::GnarkInputs},
+                stdin::OnchainStdin,
+            },
             recursion_circuit::stdin::RecursionStdin,
             vk_merkle::{builder::EmbedVkVerifierCircuit, HasStaticVkManager},
         },
-        configs::{recur_config, recur_kb_config},
+        configs::{embed_config, embed_kb_config, recur_config, recur_kb_config},
         machine::embed::EmbedMachine,
     },
     machine::{
@@ -44,7 +48,28 @@ where
 }
 
 macro_rules! impl_embeded_prover {
-    ($mod_name:ident, $embed_sc:ident) => {
+    ($mod_name:ident, $embed_mod_name:ident, $embed_sc:ident) => {
+        impl<I> EmbedVkProver<$mod_name::StarkConfig, $embed_sc, I> {
+            /// Build the gnark verifier circuit and witness for an embed proof. The groth16
+            /// proof itself is produced by the gnark prover from the saved [`GnarkInputs`].
+            pub fn export_gnark(
+                &self,
+                proof: &MetaProof<$embed_sc>,
+            ) -> GnarkInputs<$embed_mod_name::FieldConfig> {
+                let onchain_stdin = OnchainStdin {
+                    machine: self.machine.base_machine().clone(),
+                    vk: proof.vks().first().unwrap().clone(),
+                    proof: proof.proofs().first().unwrap().clone(),
+                    flag_complete: true,
+                };
+                let (constraints, witness) = OnchainVerifierCircuit::<
+                    $embed_mod_name::FieldConfig,
+                    $embed_sc,
+                >::build(&onchain_stdin);
+                GnarkInputs::new(constraints, witness)
+            }
+        }
+
         impl<I>
             ProverChain<$mod_name::StarkConfig, CompressChips<$mod_name::StarkConfig>, $embed_sc>
             for EmbedVkProver<$mod_name::StarkConfig, $embed_sc, I>
@@ -125,5 +150,5 @@ macro_rules! impl_embeded_prover {
     };
 }
 
-impl_embeded_prover!(recur_config, BabyBearBn254Poseidon2);
-impl_embeded_prover!(recur_kb_config, KoalaBearBn254Poseidon2);
+impl_embeded_prover!(recur_config, embed_config, BabyBearBn254Poseidon2);
+impl_embeded_prover!(recur_kb_config, embed_kb_config, KoalaBearBn254Poseidon2);
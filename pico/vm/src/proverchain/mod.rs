@@ -3,6 +3,7 @@ mod compress;
 mod convert;
 mod embed;
 mod riscv;
+mod sink;
 
 use crate::{
     configs::config::{StarkGenericConfig, Val},
@@ -10,11 +11,12 @@ use crate::{
 };
 
 // re-exports
-pub use combine::CombineProver;
+pub use combine::{CombineManyError, CombineProver};
 pub use compress::CompressProver;
 pub use convert::ConvertProver;
 pub use embed::EmbedProver;
-pub use riscv::RiscvProver;
+pub use riscv::{ChunkProof, RiscvProver};
+pub use sink::{InMemoryProofSink, ProofSink};
 
 /// Trait to assist with inline proving
 pub trait ProverChain<PrevSC, PrevC, SC>
@@ -53,4 +55,10 @@ where
     fn machine(&self) -> &BaseMachine<SC, Self::Chips>;
     fn prove(&self, witness: Self::Witness) -> MetaProof<SC>;
     fn verify(&self, proof: &MetaProof<SC>, riscv_vk: &dyn HashableKey<SC::Val>) -> bool;
+
+    /// Dry-run the witness and return how many chunks `prove` would produce, without
+    /// generating any traces. Recursion provers fold their inputs into a single chunk.
+    fn estimate_chunk_count(&self, _witness: &Self::Witness) -> usize {
+        1
+    }
 }
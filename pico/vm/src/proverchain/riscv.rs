@@ -1,4 +1,4 @@
-use super::{InitialProverSetup, MachineProver};
+use super::{InitialProverSetup, MachineProver, ProofSink};
 use crate::{
     chips::{
         chips::riscv_poseidon2::FieldSpecificPoseidon2Chip,
@@ -9,7 +9,16 @@ use crate::{
         program::Program,
     },
     configs::config::{Com, Dom, PcsProverData, StarkGenericConfig, Val},
-    emulator::{emulator::MetaEmulator, opts::EmulatorOpts, stdin::EmulatorStdin},
+    emulator::{
+        emulator::MetaEmulator,
+        opts::EmulatorOpts,
+        riscv::{
+            record::EmulationRecord,
+            riscv_emulator::{RiscvEmulator, RiscvEmulatorMode},
+            syscalls::{HostCallHandler, HostCallHandlers},
+        },
+        stdin::EmulatorStdin,
+    },
     instances::{
         chiptype::riscv_chiptype::RiscvChipType,
         compiler::{shapes::riscv_shape::RiscvShapeConfig, vk_merkle::vk_verification_enabled},
@@ -20,15 +29,22 @@ use crate::{
         folder::{ProverConstraintFolder, VerifierConstraintFolder},
         keys::{BaseProvingKey, BaseVerifyingKey, HashableKey},
         machine::{BaseMachine, MachineBehavior},
+        progress::ProveProgress,
         proof::{BaseProof, MetaProof},
         witness::ProvingWitness,
     },
-    primitives::{consts::RISCV_NUM_PVS, Poseidon2Init},
+    primitives::{
+        consts::{DIGEST_SIZE, RISCV_NUM_PVS},
+        Poseidon2Init,
+    },
 };
 use alloc::sync::Arc;
+use anyhow::{anyhow, Result};
 use p3_air::Air;
 use p3_field::PrimeField32;
 use p3_symmetric::Permutation;
+use serde::de::DeserializeOwned;
+use std::{iter, sync::mpsc, thread};
 
 pub type RiscvChips<SC> = RiscvChipType<Val<SC>>;
 
@@ -43,6 +59,7 @@ where
     shape_config: Option<RiscvShapeConfig<Val<SC>>>,
     pk: BaseProvingKey<SC>,
     vk: BaseVerifyingKey<SC>,
+    host_call_handlers: HostCallHandlers,
 }
 
 impl<SC> RiscvProver<SC, Program>
@@ -55,32 +72,169 @@ where
     BaseVerifyingKey<SC>: HashableKey<Val<SC>>,
     Val<SC>: PrimeField32 + FieldSpecificPoseidon2Config + Poseidon2Init,
     <Val<SC> as Poseidon2Init>::Poseidon2: Permutation<[Val<SC>; 16]>,
-    FieldSpecificPoseidon2Chip<Val<SC>>: Air<ProverConstraintFolder<SC>>,
-    FieldSpecificPrecompilePoseidon2Chip<Val<SC>>: Air<ProverConstraintFolder<SC>>,
+    Dom<SC>: DeserializeOwned,
+    FieldSpecificPoseidon2Chip<Val<SC>>:
+        Air<ProverConstraintFolder<SC>> + for<'b> Air<VerifierConstraintFolder<'b, SC>>,
+    FieldSpecificPrecompilePoseidon2Chip<Val<SC>>:
+        Air<ProverConstraintFolder<SC>> + for<'b> Air<VerifierConstraintFolder<'b, SC>>,
+    RiscvMachine<SC, RiscvChips<SC>>: Send + Sync,
 {
     pub fn prove_cycles(&self, stdin: EmulatorStdin<Program, Vec<u8>>) -> (MetaProof<SC>, u64) {
-        let witness = ProvingWitness::setup_for_riscv(
+        self.prove_with_progress(stdin, &mut |_| {})
+    }
+
+    /// Prove like [`Self::prove_cycles`], reporting each emulated and proven chunk to `progress`.
+    pub fn prove_with_progress(
+        &self,
+        stdin: EmulatorStdin<Program, Vec<u8>>,
+        progress: &mut dyn FnMut(ProveProgress),
+    ) -> (MetaProof<SC>, u64) {
+        let mut witness = ProvingWitness::setup_for_riscv(
             self.program.clone(),
             stdin,
-            self.opts,
+            self.opts.clone(),
             self.pk.clone(),
             self.vk.clone(),
         );
-        if let Some(shape_config) = &self.shape_config {
-            self.machine.prove_with_shape(&witness, Some(shape_config))
-        } else {
-            self.machine.prove_cycles(&witness)
+        witness.host_call_handlers = self.host_call_handlers.clone();
+        self.machine
+            .prove_with_progress(&witness, self.shape_config.as_ref(), progress)
+    }
+
+    /// Prove like [`Self::prove_with_progress`], also returning the record of every chunk. See
+    /// [`RiscvMachine::prove_retaining_records`] for the memory this takes.
+    pub fn prove_with_records(
+        &self,
+        stdin: EmulatorStdin<Program, Vec<u8>>,
+        progress: &mut dyn FnMut(ProveProgress),
+    ) -> (MetaProof<SC>, u64, Vec<EmulationRecord>) {
+        let mut witness = ProvingWitness::setup_for_riscv(
+            self.program.clone(),
+            stdin,
+            self.opts.clone(),
+            self.pk.clone(),
+            self.vk.clone(),
+        );
+        witness.host_call_handlers = self.host_call_handlers.clone();
+        let mut records = Vec::new();
+        let (proof, cycles) = self.machine.prove_retaining_records(
+            &witness,
+            self.shape_config.as_ref(),
+            progress,
+            Some(&mut records),
+        );
+        (proof, cycles, records)
+    }
+
+    /// Prove chunk by chunk, yielding each chunk proof as soon as it is done instead of waiting
+    /// for the whole program.
+    ///
+    /// Emulation runs on a background thread and streams its records over a channel. Each call
+    /// to `next` proves the next record, so proving keeps pace with the consumer while
+    /// emulation keeps running ahead. Dropping the iterator stops emulation at the next record.
+    /// The public values stream is not part of the chunk proofs; use [`Self::prove_cycles`] for
+    /// a complete `MetaProof`.
+    pub fn prove_streamed(
+        &self,
+        stdin: EmulatorStdin<Program, Vec<u8>>,
+    ) -> impl Iterator<Item = Result<ChunkProof<SC>>> + '_ {
+        let mut witness = ProvingWitness::<SC, RiscvChips<SC>, _>::setup_for_riscv(
+            self.program.clone(),
+            stdin,
+            self.opts.clone(),
+            self.pk.clone(),
+            self.vk.clone(),
+        );
+        witness.host_call_handlers = self.host_call_handlers.clone();
+        let mut emulator = MetaEmulator::setup_riscv(&witness);
+
+        let (record_sender, record_receiver) =
+            mpsc::sync_channel(4 * self.opts.chunk_batch_size as usize);
+        let mut emulator_handle = Some(thread::spawn(move || loop {
+            let mut disconnected = false;
+            let done = emulator.next_record_batch(&mut |record| {
+                disconnected |= record_sender.send(record).is_err();
+            });
+            if done || disconnected {
+                break;
+            }
+        }));
+
+        let mut challenger = self.machine.config().challenger();
+        self.pk.observed_by(&mut challenger);
+
+        let mut chunk_index = 0;
+        iter::from_fn(move || match record_receiver.recv() {
+            Ok(record) => {
+                let proof = self
+                    .machine
+                    .prove_records(
+                        chunk_index,
+                        &self.pk,
+                        &challenger,
+                        self.shape_config.as_ref(),
+                        vec![record],
+                        None,
+                    )
+                    .pop()
+                    .unwrap();
+                chunk_index += 1;
+                Some(Ok(ChunkProof {
+                    chunk_index: chunk_index - 1,
+                    proof,
+                }))
+            }
+            // The sender is dropped when the emulator thread exits, report it if it panicked.
+            Err(_) => emulator_handle.take()?.join().err().map(|_| {
+                Err(anyhow!(
+                    "riscv emulation failed after {} chunks",
+                    chunk_index
+                ))
+            }),
+        })
+    }
+
+    /// Prove like [`Self::prove_streamed`], handing each chunk proof to `sink` as it completes.
+    /// Returns the number of chunks proven.
+    pub fn prove_to_sink(
+        &self,
+        stdin: EmulatorStdin<Program, Vec<u8>>,
+        sink: &mut dyn ProofSink,
+    ) -> Result<usize> {
+        let mut num_chunks = 0;
+        for chunk in self.prove_streamed(stdin) {
+            let chunk = chunk?;
+            let proof_bytes = bincode::serialize(&chunk.proof)?;
+            sink.emit_chunk(chunk.chunk_index, &proof_bytes);
+            num_chunks += 1;
         }
+        Ok(num_chunks)
+    }
+
+    /// Check the constraints and lookups of every chunk instead of proving, see
+    /// [`RiscvMachine::debug_with_shape`].
+    pub fn debug(&self, stdin: EmulatorStdin<Program, Vec<u8>>) -> Result<()> {
+        let mut witness = ProvingWitness::setup_for_riscv(
+            self.program.clone(),
+            stdin,
+            self.opts.clone(),
+            self.pk.clone(),
+            self.vk.clone(),
+        );
+        witness.host_call_handlers = self.host_call_handlers.clone();
+        self.machine
+            .debug_with_shape(&witness, self.shape_config.as_ref())
     }
 
     pub fn run_tracegen(&self, stdin: EmulatorStdin<Program, Vec<u8>>) -> u64 {
-        let witness = ProvingWitness::<SC, RiscvChips<SC>, _>::setup_for_riscv(
+        let mut witness = ProvingWitness::<SC, RiscvChips<SC>, _>::setup_for_riscv(
             self.program.clone(),
             stdin,
-            self.opts,
+            self.opts.clone(),
             self.pk.clone(),
             self.vk.clone(),
         );
+        witness.host_call_handlers = self.host_call_handlers.clone();
         let mut emulator = MetaEmulator::setup_riscv(&witness);
         loop {
             let done = emulator.next_record_batch(&mut |_| {});
@@ -91,6 +245,22 @@ where
         emulator.cycles()
     }
 
+    /// Emulate in simple mode without proving, returning the public values stream and the
+    /// number of cycles. The guest sees the same vk digest and host calls as when proving.
+    pub fn emulate(&self, stdin: &EmulatorStdin<Program, Vec<u8>>) -> Result<(Vec<u8>, u64)> {
+        let mut emulator = RiscvEmulator::new::<Val<SC>>(self.program.clone(), self.opts.clone());
+        emulator.write_stdin(stdin);
+        emulator.vk_digest = Some(self.vk_digest());
+        emulator.host_call_handlers = self.host_call_handlers.clone();
+        emulator.mode = RiscvEmulatorMode::Simple;
+
+        while !emulator.emulate_batch(&mut |_| {})? {}
+        Ok((
+            emulator.state.public_values_stream,
+            emulator.state.global_clk,
+        ))
+    }
+
     pub fn get_program(&self) -> Arc<Program> {
         self.program.clone()
     }
@@ -98,6 +268,17 @@ where
     pub fn vk(&self) -> &BaseVerifyingKey<SC> {
         &self.vk
     }
+
+    /// Answers the `HOST_CALL` requests of the guest with tag `tag` with `handler`, in every
+    /// emulation this prover runs. See [`RiscvEmulator::register_host_call`].
+    pub fn register_host_call(&mut self, tag: u32, handler: impl HostCallHandler + 'static) {
+        self.host_call_handlers.insert(tag, Arc::new(handler));
+    }
+
+    /// The vk digest as returned to the guest by `pico_sdk::io::self_vk_digest`.
+    pub fn vk_digest(&self) -> [u32; DIGEST_SIZE] {
+        self.vk.hash_field().map(|x| x.as_canonical_u32())
+    }
 }
 
 impl<SC> InitialProverSetup for RiscvProver<SC, Program>
@@ -141,6 +322,7 @@ where
             shape_config,
             pk,
             vk,
+            host_call_handlers: Default::default(),
         }
     }
 }
@@ -159,6 +341,8 @@ where
         Air<ProverConstraintFolder<SC>> + for<'b> Air<VerifierConstraintFolder<'b, SC>>,
     FieldSpecificPrecompilePoseidon2Chip<Val<SC>>:
         Air<ProverConstraintFolder<SC>> + for<'b> Air<VerifierConstraintFolder<'b, SC>>,
+    Dom<SC>: DeserializeOwned,
+    RiscvMachine<SC, RiscvChips<SC>>: Send + Sync,
 {
     type Witness = EmulatorStdin<Program, Vec<u8>>;
     type Chips = RiscvChips<SC>;
@@ -174,4 +358,78 @@ where
     fn verify(&self, proof: &MetaProof<SC>, riscv_vk: &dyn HashableKey<Val<SC>>) -> bool {
         self.machine.verify(proof, riscv_vk).is_ok()
     }
+
+    /// Emulate in simple mode and count the execution chunks from the cycles, plus the
+    /// deferred records split off at the end of emulation.
+    fn estimate_chunk_count(&self, stdin: &Self::Witness) -> usize {
+        let mut emulator = RiscvEmulator::new::<Val<SC>>(self.program.clone(), self.opts.clone());
+        emulator.write_stdin(stdin);
+        emulator.vk_digest = Some(self.vk_digest());
+        emulator.host_call_handlers = self.host_call_handlers.clone();
+        emulator.mode = RiscvEmulatorMode::Simple;
+
+        let mut num_records = 0;
+        loop {
+            let done = emulator
+                .emulate_batch(&mut |_| num_records += 1)
+                .expect("error emulating program");
+            if done {
+                break;
+            }
+        }
+
+        // Simple mode records no cpu events, so a record is only handed back when a chunk fills
+        // up, and the last partial chunk is never bumped. The other records are deferred ones.
+        let num_full_chunks = (emulator.state.current_chunk - 1) as usize;
+        let num_deferred = num_records - num_full_chunks;
+        let num_execution_chunks = emulator
+            .state
+            .global_clk
+            .div_ceil(self.opts.chunk_size as u64) as usize;
+        num_execution_chunks + num_deferred
+    }
+}
+
+/// The proof of a single riscv chunk, as yielded by [`RiscvProver::prove_streamed`].
+pub struct ChunkProof<SC: StarkGenericConfig> {
+    /// The index of the chunk, counting from 0 in emulation order.
+    pub chunk_index: usize,
+    pub proof: BaseProof<SC>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compiler::riscv::program::Program,
+        configs::{config::StarkGenericConfig, stark_config::BabyBearPoseidon2},
+        emulator::{
+            opts::EmulatorOpts, riscv::riscv_emulator::RiscvEmulator, stdin::EmulatorStdin,
+        },
+        proverchain::{InitialProverSetup, RiscvProver},
+    };
+    use p3_baby_bear::BabyBear;
+
+    #[test]
+    fn test_emulate() {
+        const FIBONACCI_ELF: &[u8] =
+            include_bytes!("../compiler/test_elf/riscv32im-pico-fibonacci-elf");
+
+        let prover = RiscvProver::new_initial_prover(
+            (BabyBearPoseidon2::new(), FIBONACCI_ELF),
+            EmulatorOpts::test_opts(),
+            None,
+        );
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        stdin.write(&10u32);
+        let stdin = stdin.finalize();
+
+        let (pv_stream, cycles) = prover.emulate(&stdin).unwrap();
+        assert_eq!(cycles, prover.run_tracegen(stdin.clone()));
+
+        let mut emulator =
+            RiscvEmulator::new::<BabyBear>(prover.get_program(), EmulatorOpts::test_opts());
+        emulator.run_fast(Some(stdin)).unwrap();
+        assert!(!pv_stream.is_empty());
+        assert_eq!(pv_stream, emulator.state.public_values_stream);
+    }
 }
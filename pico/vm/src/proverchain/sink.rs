@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+/// Receives chunk proofs as they are produced, see [`super::RiscvProver::prove_to_sink`].
+///
+/// A distributed prover implements this to forward each proof to a coordinator as soon as it is
+/// done, rather than waiting for the whole program.
+pub trait ProofSink {
+    /// Takes the bincode-encoded [`super::ChunkProof::proof`] of chunk `chunk_idx`. Chunks are
+    /// emitted in emulation order.
+    fn emit_chunk(&mut self, chunk_idx: usize, proof_bytes: &[u8]);
+}
+
+/// A [`ProofSink`] that keeps every chunk proof in memory, keyed by chunk index.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryProofSink {
+    pub chunks: BTreeMap<usize, Vec<u8>>,
+}
+
+impl ProofSink for InMemoryProofSink {
+    fn emit_chunk(&mut self, chunk_idx: usize, proof_bytes: &[u8]) {
+        self.chunks.insert(chunk_idx, proof_bytes.to_vec());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryProofSink;
+    use crate::{
+        compiler::riscv::program::Program,
+        configs::{config::StarkGenericConfig, stark_config::BabyBearPoseidon2},
+        emulator::{opts::EmulatorOpts, stdin::EmulatorStdin},
+        machine::proof::{BaseProof, MetaProof},
+        proverchain::{InitialProverSetup, MachineProver, RiscvProver},
+    };
+    use alloc::sync::Arc;
+
+    #[test]
+    fn test_prove_to_sink() {
+        const FIBONACCI_ELF: &[u8] =
+            include_bytes!("../compiler/test_elf/riscv32im-pico-fibonacci-elf");
+
+        let prover = RiscvProver::new_initial_prover(
+            (BabyBearPoseidon2::new(), FIBONACCI_ELF),
+            EmulatorOpts::test_opts(),
+            None,
+        );
+        let mut stdin = EmulatorStdin::<Program, Vec<u8>>::new_builder();
+        stdin.write(&10u32);
+
+        let mut sink = InMemoryProofSink::default();
+        let num_chunks = prover.prove_to_sink(stdin.finalize(), &mut sink).unwrap();
+        assert!(num_chunks > 0);
+        assert!(sink.chunks.keys().copied().eq(0..num_chunks));
+
+        // The collected chunks decode and verify as one proof.
+        let proofs = sink
+            .chunks
+            .values()
+            .map(|bytes| bincode::deserialize::<BaseProof<BabyBearPoseidon2>>(bytes).unwrap())
+            .collect::<Vec<_>>();
+        let proof = MetaProof::new(proofs.into(), Arc::new([prover.vk().clone()]), None);
+        assert!(prover.verify(&proof, prover.vk()));
+    }
+}